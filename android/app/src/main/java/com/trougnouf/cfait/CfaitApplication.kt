@@ -6,7 +6,14 @@ import android.app.Application
 import android.app.NotificationChannel
 import android.app.NotificationManager
 import android.os.Build
+import androidx.work.ExistingWorkPolicy
+import androidx.work.OneTimeWorkRequestBuilder
+import androidx.work.WorkManager
 import com.trougnouf.cfait.core.CfaitMobile
+import com.trougnouf.cfait.core.MobileAlarmInfo
+import com.trougnouf.cfait.core.MobileAlarmListener
+import com.trougnouf.cfait.workers.AlarmWorker
+import com.trougnouf.cfait.workers.PeriodicSyncWorker
 
 class CfaitApplication : Application() {
     lateinit var api: CfaitMobile
@@ -54,6 +61,29 @@ class CfaitApplication : Application() {
         // Preload data into memory immediately so UI is ready faster
         api.loadFromCache()
 
+        // While the process is alive, the in-process alarm actor posts reminders on time.
+        // AlarmManager + AlarmWorker still cover a killed process; both use the same
+        // notification ID per task, so an alarm caught by both shows once.
+        api.startAlarmActor(object : MobileAlarmListener {
+            override fun onAlarm(alarm: MobileAlarmInfo) {
+                AlarmWorker.showNotification(
+                    this@CfaitApplication,
+                    alarm.title,
+                    alarm.body,
+                    alarm.taskUid,
+                    alarm.alarmUid
+                )
+            }
+
+            override fun onSyncRequested() {
+                WorkManager.getInstance(this@CfaitApplication).enqueueUniqueWork(
+                    "cfait_jit_sync",
+                    ExistingWorkPolicy.KEEP,
+                    OneTimeWorkRequestBuilder<PeriodicSyncWorker>().build()
+                )
+            }
+        })
+
         // Detect saved language preference or fall back to Android system language,
         // then propagate it to the Rust backend so rust_i18n is initialized correctly.
         val prefs = getSharedPreferences("cfait_prefs", android.content.Context.MODE_PRIVATE)
@@ -17,27 +17,27 @@ package com.trougnouf.cfait.core
 // compile the Rust component. The easiest way to ensure this is to bundle the Kotlin
 // helpers directly inline like we're doing here.
 
-import com.sun.jna.Callback
-import com.sun.jna.IntegerType
 import com.sun.jna.Library
+import com.sun.jna.IntegerType
 import com.sun.jna.Native
 import com.sun.jna.Pointer
 import com.sun.jna.Structure
+import com.sun.jna.Callback
 import com.sun.jna.ptr.*
-import kotlinx.coroutines.CancellableContinuation
-import kotlinx.coroutines.DelicateCoroutinesApi
-import kotlinx.coroutines.GlobalScope
-import kotlinx.coroutines.Job
-import kotlinx.coroutines.launch
-import kotlinx.coroutines.suspendCancellableCoroutine
 import java.nio.ByteBuffer
 import java.nio.ByteOrder
 import java.nio.CharBuffer
 import java.nio.charset.CodingErrorAction
+import java.util.concurrent.atomic.AtomicLong
 import java.util.concurrent.ConcurrentHashMap
 import java.util.concurrent.atomic.AtomicBoolean
-import java.util.concurrent.atomic.AtomicLong
 import kotlin.coroutines.resume
+import kotlinx.coroutines.CancellableContinuation
+import kotlinx.coroutines.DelicateCoroutinesApi
+import kotlinx.coroutines.GlobalScope
+import kotlinx.coroutines.Job
+import kotlinx.coroutines.launch
+import kotlinx.coroutines.suspendCancellableCoroutine
 
 // This is a helper for safely working with byte buffers returned from the Rust code.
 // A rust-owned buffer is represented by its capacity, its current length, and a
@@ -51,41 +51,29 @@ open class RustBuffer : Structure() {
     // Note: `capacity` and `len` are actually `ULong` values, but JVM only supports signed values.
     // When dealing with these fields, make sure to call `toULong()`.
     @JvmField var capacity: Long = 0
-
     @JvmField var len: Long = 0
-
     @JvmField var data: Pointer? = null
 
-    class ByValue :
-        RustBuffer(),
-        Structure.ByValue
+    class ByValue: RustBuffer(), Structure.ByValue
+    class ByReference: RustBuffer(), Structure.ByReference
 
-    class ByReference :
-        RustBuffer(),
-        Structure.ByReference
-
-    internal fun setValue(other: RustBuffer) {
+   internal fun setValue(other: RustBuffer) {
         capacity = other.capacity
         len = other.len
         data = other.data
     }
 
     companion object {
-        internal fun alloc(size: ULong = 0UL) =
-            uniffiRustCall { status ->
-                // Note: need to convert the size to a `Long` value to make this work with JVM.
-                UniffiLib.ffi_cfait_rustbuffer_alloc(size.toLong(), status)
-            }.also {
-                if (it.data == null) {
-                    throw RuntimeException("RustBuffer.alloc() returned null data pointer (size=$size)")
-                }
-            }
+        internal fun alloc(size: ULong = 0UL) = uniffiRustCall() { status ->
+            // Note: need to convert the size to a `Long` value to make this work with JVM.
+            UniffiLib.ffi_cfait_rustbuffer_alloc(size.toLong(), status)
+        }.also {
+            if(it.data == null) {
+               throw RuntimeException("RustBuffer.alloc() returned null data pointer (size=${size})")
+           }
+        }
 
-        internal fun create(
-            capacity: ULong,
-            len: ULong,
-            data: Pointer?,
-        ): RustBuffer.ByValue {
+        internal fun create(capacity: ULong, len: ULong, data: Pointer?): RustBuffer.ByValue {
             var buf = RustBuffer.ByValue()
             buf.capacity = capacity.toLong()
             buf.len = len.toLong()
@@ -93,10 +81,9 @@ open class RustBuffer : Structure() {
             return buf
         }
 
-        internal fun free(buf: RustBuffer.ByValue) =
-            uniffiRustCall { status ->
-                UniffiLib.ffi_cfait_rustbuffer_free(buf, status)
-            }
+        internal fun free(buf: RustBuffer.ByValue) = uniffiRustCall() { status ->
+            UniffiLib.ffi_cfait_rustbuffer_free(buf, status)
+        }
     }
 
     @Suppress("TooGenericExceptionThrown")
@@ -115,12 +102,9 @@ open class RustBuffer : Structure() {
 @Structure.FieldOrder("len", "data")
 internal open class ForeignBytes : Structure() {
     @JvmField var len: Int = 0
-
     @JvmField var data: Pointer? = null
 
-    class ByValue :
-        ForeignBytes(),
-        Structure.ByValue
+    class ByValue : ForeignBytes(), Structure.ByValue
 }
 
 // Converter for `&[u8]` / `[ByRef] bytes` arguments.
@@ -143,13 +127,7 @@ internal object FfiConverterByRefBytes : FfiConverter<java.nio.ByteBuffer, Forei
         fb.len = remaining
         // Zero-length direct buffers: skip getDirectBufferPointer (platform-variable behavior)
         // and pass null. The Rust side treats (null, 0) as &[].
-        fb.data =
-            if (remaining == 0) {
-                null
-            } else {
-                com.sun.jna.Native
-                    .getDirectBufferPointer(value)
-            }
+        fb.data = if (remaining == 0) null else com.sun.jna.Native.getDirectBufferPointer(value)
         return fb
     }
 
@@ -157,24 +135,14 @@ internal object FfiConverterByRefBytes : FfiConverter<java.nio.ByteBuffer, Forei
         error("ByRef bytes cannot be lifted: zero-copy &[u8] only flows foreign->Rust")
 
     override fun read(buf: java.nio.ByteBuffer): java.nio.ByteBuffer =
-        error(
-            "ByRef bytes cannot be read from a buffer: zero-copy &[u8] is only supported in argument position, not nested in records/options/etc.",
-        )
+        error("ByRef bytes cannot be read from a buffer: zero-copy &[u8] is only supported in argument position, not nested in records/options/etc.")
 
-    override fun write(
-        value: java.nio.ByteBuffer,
-        buf: java.nio.ByteBuffer,
-    ): Unit =
-        error(
-            "ByRef bytes cannot be written to a buffer: zero-copy &[u8] is only supported in argument position, not nested in records/options/etc.",
-        )
+    override fun write(value: java.nio.ByteBuffer, buf: java.nio.ByteBuffer): Unit =
+        error("ByRef bytes cannot be written to a buffer: zero-copy &[u8] is only supported in argument position, not nested in records/options/etc.")
 
     override fun allocationSize(value: java.nio.ByteBuffer): ULong =
-        error(
-            "ByRef bytes have no RustBuffer allocation size: zero-copy &[u8] is only supported in argument position, not nested in records/options/etc.",
-        )
+        error("ByRef bytes have no RustBuffer allocation size: zero-copy &[u8] is only supported in argument position, not nested in records/options/etc.")
 }
-
 /**
  * The FfiConverter interface handles converter types to and from the FFI
  *
@@ -204,10 +172,7 @@ public interface FfiConverter<KotlinType, FfiType> {
     fun allocationSize(value: KotlinType): ULong
 
     // Write a Kotlin type to a `ByteBuffer`
-    fun write(
-        value: KotlinType,
-        buf: ByteBuffer,
-    )
+    fun write(value: KotlinType, buf: ByteBuffer)
 
     // Lower a value into a `RustBuffer`
     //
@@ -218,10 +183,9 @@ public interface FfiConverter<KotlinType, FfiType> {
     fun lowerIntoRustBuffer(value: KotlinType): RustBuffer.ByValue {
         val rbuf = RustBuffer.alloc(allocationSize(value))
         try {
-            val bbuf =
-                rbuf.data!!.getByteBuffer(0, rbuf.capacity).also {
-                    it.order(ByteOrder.BIG_ENDIAN)
-                }
+            val bbuf = rbuf.data!!.getByteBuffer(0, rbuf.capacity).also {
+                it.order(ByteOrder.BIG_ENDIAN)
+            }
             write(value, bbuf)
             rbuf.writeField("len", bbuf.position().toLong())
             return rbuf
@@ -238,11 +202,11 @@ public interface FfiConverter<KotlinType, FfiType> {
     fun liftFromRustBuffer(rbuf: RustBuffer.ByValue): KotlinType {
         val byteBuf = rbuf.asByteBuffer()!!
         try {
-            val item = read(byteBuf)
-            if (byteBuf.hasRemaining()) {
-                throw RuntimeException("junk remaining in buffer after lifting, something is very wrong!!")
-            }
-            return item
+           val item = read(byteBuf)
+           if (byteBuf.hasRemaining()) {
+               throw RuntimeException("junk remaining in buffer after lifting, something is very wrong!!")
+           }
+           return item
         } finally {
             RustBuffer.free(rbuf)
         }
@@ -254,9 +218,8 @@ public interface FfiConverter<KotlinType, FfiType> {
  *
  * @suppress
  */
-public interface FfiConverterRustBuffer<KotlinType> : FfiConverter<KotlinType, RustBuffer.ByValue> {
+public interface FfiConverterRustBuffer<KotlinType>: FfiConverter<KotlinType, RustBuffer.ByValue> {
     override fun lift(value: RustBuffer.ByValue) = liftFromRustBuffer(value)
-
     override fun lower(value: KotlinType) = lowerIntoRustBuffer(value)
 }
 // A handful of classes and functions to support the generated data structures.
@@ -269,24 +232,24 @@ internal const val UNIFFI_CALL_UNEXPECTED_ERROR = 2.toByte()
 @Structure.FieldOrder("code", "error_buf")
 internal open class UniffiRustCallStatus : Structure() {
     @JvmField var code: Byte = 0
-
     @JvmField var error_buf: RustBuffer.ByValue = RustBuffer.ByValue()
 
-    class ByValue :
-        UniffiRustCallStatus(),
-        Structure.ByValue
+    class ByValue: UniffiRustCallStatus(), Structure.ByValue
 
-    fun isSuccess(): Boolean = code == UNIFFI_CALL_SUCCESS
+    fun isSuccess(): Boolean {
+        return code == UNIFFI_CALL_SUCCESS
+    }
 
-    fun isError(): Boolean = code == UNIFFI_CALL_ERROR
+    fun isError(): Boolean {
+        return code == UNIFFI_CALL_ERROR
+    }
 
-    fun isPanic(): Boolean = code == UNIFFI_CALL_UNEXPECTED_ERROR
+    fun isPanic(): Boolean {
+        return code == UNIFFI_CALL_UNEXPECTED_ERROR
+    }
 
     companion object {
-        fun create(
-            code: Byte,
-            errorBuf: RustBuffer.ByValue,
-        ): UniffiRustCallStatus.ByValue {
+        fun create(code: Byte, errorBuf: RustBuffer.ByValue): UniffiRustCallStatus.ByValue {
             val callStatus = UniffiRustCallStatus.ByValue()
             callStatus.code = code
             callStatus.error_buf = errorBuf
@@ -295,9 +258,7 @@ internal open class UniffiRustCallStatus : Structure() {
     }
 }
 
-class InternalException(
-    message: String,
-) : kotlin.Exception(message)
+class InternalException(message: String) : kotlin.Exception(message)
 
 /**
  * Each top-level error class has a companion object that can lift the error from the call status's rust buffer
@@ -305,7 +266,7 @@ class InternalException(
  * @suppress
  */
 interface UniffiRustCallStatusErrorHandler<E> {
-    fun lift(error_buf: RustBuffer.ByValue): E
+    fun lift(error_buf: RustBuffer.ByValue): E;
 }
 
 // Helpers for calling Rust
@@ -313,10 +274,7 @@ interface UniffiRustCallStatusErrorHandler<E> {
 // synchronize itself
 
 // Call a rust function that returns a Result<>.  Pass in the Error class companion that corresponds to the Err
-private inline fun <U, E : kotlin.Exception> uniffiRustCallWithError(
-    errorHandler: UniffiRustCallStatusErrorHandler<E>,
-    callback: (UniffiRustCallStatus) -> U,
-): U {
+private inline fun <U, E: kotlin.Exception> uniffiRustCallWithError(errorHandler: UniffiRustCallStatusErrorHandler<E>, callback: (UniffiRustCallStatus) -> U): U {
     var status = UniffiRustCallStatus()
     val return_value = callback(status)
     uniffiCheckCallStatus(errorHandler, status)
@@ -324,10 +282,7 @@ private inline fun <U, E : kotlin.Exception> uniffiRustCallWithError(
 }
 
 // Check UniffiRustCallStatus and throw an error if the call wasn't successful
-private fun <E : kotlin.Exception> uniffiCheckCallStatus(
-    errorHandler: UniffiRustCallStatusErrorHandler<E>,
-    status: UniffiRustCallStatus,
-) {
+private fun<E: kotlin.Exception> uniffiCheckCallStatus(errorHandler: UniffiRustCallStatusErrorHandler<E>, status: UniffiRustCallStatus) {
     if (status.isSuccess()) {
         return
     } else if (status.isError()) {
@@ -351,7 +306,7 @@ private fun <E : kotlin.Exception> uniffiCheckCallStatus(
  *
  * @suppress
  */
-object UniffiNullRustCallStatusErrorHandler : UniffiRustCallStatusErrorHandler<InternalException> {
+object UniffiNullRustCallStatusErrorHandler: UniffiRustCallStatusErrorHandler<InternalException> {
     override fun lift(error_buf: RustBuffer.ByValue): InternalException {
         RustBuffer.free(error_buf)
         return InternalException("Unexpected CALL_ERROR")
@@ -359,54 +314,44 @@ object UniffiNullRustCallStatusErrorHandler : UniffiRustCallStatusErrorHandler<I
 }
 
 // Call a rust function that returns a plain value
-private inline fun <U> uniffiRustCall(callback: (UniffiRustCallStatus) -> U): U =
-    uniffiRustCallWithError(UniffiNullRustCallStatusErrorHandler, callback)
+private inline fun <U> uniffiRustCall(callback: (UniffiRustCallStatus) -> U): U {
+    return uniffiRustCallWithError(UniffiNullRustCallStatusErrorHandler, callback)
+}
 
-internal inline fun <T> uniffiTraitInterfaceCall(
+internal inline fun<T> uniffiTraitInterfaceCall(
     callStatus: UniffiRustCallStatus,
     makeCall: () -> T,
     writeReturn: (T) -> Unit,
 ) {
     try {
         writeReturn(makeCall())
-    } catch (e: kotlin.Exception) {
-        val err =
-            try {
-                e.stackTraceToString()
-            } catch (_: Throwable) {
-                ""
-            }
+    } catch(e: kotlin.Exception) {
+        val err = try { e.stackTraceToString() } catch(_: Throwable) { "" }
         callStatus.code = UNIFFI_CALL_UNEXPECTED_ERROR
         callStatus.error_buf = FfiConverterString.lower(err)
     }
 }
 
-internal inline fun <T, reified E : Throwable> uniffiTraitInterfaceCallWithError(
+internal inline fun<T, reified E: Throwable> uniffiTraitInterfaceCallWithError(
     callStatus: UniffiRustCallStatus,
     makeCall: () -> T,
     writeReturn: (T) -> Unit,
-    lowerError: (E) -> RustBuffer.ByValue,
+    lowerError: (E) -> RustBuffer.ByValue
 ) {
     try {
         writeReturn(makeCall())
-    } catch (e: kotlin.Exception) {
+    } catch(e: kotlin.Exception) {
         if (e is E) {
             callStatus.code = UNIFFI_CALL_ERROR
             callStatus.error_buf = lowerError(e)
         } else {
-            val err =
-                try {
-                    e.stackTraceToString()
-                } catch (_: Throwable) {
-                    ""
-                }
+            val err = try { e.stackTraceToString() } catch(_: Throwable) { "" }
             callStatus.code = UNIFFI_CALL_UNEXPECTED_ERROR
             callStatus.error_buf = FfiConverterString.lower(err)
         }
     }
 }
-
-// Initial value and increment amount for handles.
+// Initial value and increment amount for handles. 
 // These ensure that Kotlin-generated handles always have the lowest bit set
 private const val UNIFFI_HANDLEMAP_INITIAL = 1.toLong()
 private const val UNIFFI_HANDLEMAP_DELTA = 2.toLong()
@@ -414,13 +359,10 @@ private const val UNIFFI_HANDLEMAP_DELTA = 2.toLong()
 // Map handles to objects
 //
 // This is used pass an opaque 64-bit handle representing a foreign object to the Rust code.
-internal class UniffiHandleMap<T : Any> {
+internal class UniffiHandleMap<T: Any> {
     private val map = ConcurrentHashMap<Long, T>()
-
-    // Start
-    private val counter =
-        java.util.concurrent.atomic
-            .AtomicLong(UNIFFI_HANDLEMAP_INITIAL)
+    // Start 
+    private val counter = java.util.concurrent.atomic.AtomicLong(UNIFFI_HANDLEMAP_INITIAL)
 
     val size: Int
         get() = map.size
@@ -439,10 +381,14 @@ internal class UniffiHandleMap<T : Any> {
     }
 
     // Get an object from the handle map
-    fun get(handle: Long): T = map.get(handle) ?: throw InternalException("UniffiHandleMap.get: Invalid handle")
+    fun get(handle: Long): T {
+        return map.get(handle) ?: throw InternalException("UniffiHandleMap.get: Invalid handle")
+    }
 
     // Remove an entry from the handlemap and get the Kotlin object back
-    fun remove(handle: Long): T = map.remove(handle) ?: throw InternalException("UniffiHandleMap: Invalid handle")
+    fun remove(handle: Long): T {
+        return map.remove(handle) ?: throw InternalException("UniffiHandleMap: Invalid handle")
+    }
 }
 
 // Contains loading, initialization code,
@@ -458,24 +404,18 @@ private fun findLibraryName(componentName: String): String {
 
 // Define FFI callback types
 internal interface UniffiRustFutureContinuationCallback : com.sun.jna.Callback {
-    fun callback(
-        `data`: Long,
-        `pollResult`: Byte,
-    )
+    fun callback(`data`: Long,`pollResult`: Byte,)
 }
-
 internal interface UniffiForeignFutureDroppedCallback : com.sun.jna.Callback {
-    fun callback(`handle`: Long)
+    fun callback(`handle`: Long,)
 }
-
 internal interface UniffiCallbackInterfaceFree : com.sun.jna.Callback {
-    fun callback(`handle`: Long)
+    fun callback(`handle`: Long,)
 }
-
 internal interface UniffiCallbackInterfaceClone : com.sun.jna.Callback {
-    fun callback(`handle`: Long): Long
+    fun callback(`handle`: Long,)
+    : Long
 }
-
 @Structure.FieldOrder("handle", "free")
 internal open class UniffiForeignFutureDroppedCallbackStruct(
     @JvmField internal var `handle`: Long = 0.toLong(),
@@ -484,15 +424,14 @@ internal open class UniffiForeignFutureDroppedCallbackStruct(
     class UniffiByValue(
         `handle`: Long = 0.toLong(),
         `free`: UniffiForeignFutureDroppedCallback? = null,
-    ) : UniffiForeignFutureDroppedCallbackStruct(`handle`, `free`),
-        Structure.ByValue
+    ): UniffiForeignFutureDroppedCallbackStruct(`handle`,`free`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureDroppedCallbackStruct) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureDroppedCallbackStruct) {
         `handle` = other.`handle`
         `free` = other.`free`
     }
-}
 
+}
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureResultU8(
     @JvmField internal var `returnValue`: Byte = 0.toByte(),
@@ -501,22 +440,17 @@ internal open class UniffiForeignFutureResultU8(
     class UniffiByValue(
         `returnValue`: Byte = 0.toByte(),
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureResultU8(`returnValue`, `callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureResultU8(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureResultU8) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureResultU8) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteU8 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureResultU8.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureResultU8.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureResultI8(
     @JvmField internal var `returnValue`: Byte = 0.toByte(),
@@ -525,22 +459,17 @@ internal open class UniffiForeignFutureResultI8(
     class UniffiByValue(
         `returnValue`: Byte = 0.toByte(),
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureResultI8(`returnValue`, `callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureResultI8(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureResultI8) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureResultI8) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteI8 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureResultI8.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureResultI8.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureResultU16(
     @JvmField internal var `returnValue`: Short = 0.toShort(),
@@ -549,22 +478,17 @@ internal open class UniffiForeignFutureResultU16(
     class UniffiByValue(
         `returnValue`: Short = 0.toShort(),
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureResultU16(`returnValue`, `callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureResultU16(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureResultU16) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureResultU16) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteU16 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureResultU16.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureResultU16.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureResultI16(
     @JvmField internal var `returnValue`: Short = 0.toShort(),
@@ -573,22 +497,17 @@ internal open class UniffiForeignFutureResultI16(
     class UniffiByValue(
         `returnValue`: Short = 0.toShort(),
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureResultI16(`returnValue`, `callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureResultI16(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureResultI16) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureResultI16) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteI16 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureResultI16.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureResultI16.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureResultU32(
     @JvmField internal var `returnValue`: Int = 0,
@@ -597,22 +516,17 @@ internal open class UniffiForeignFutureResultU32(
     class UniffiByValue(
         `returnValue`: Int = 0,
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureResultU32(`returnValue`, `callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureResultU32(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureResultU32) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureResultU32) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteU32 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureResultU32.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureResultU32.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureResultI32(
     @JvmField internal var `returnValue`: Int = 0,
@@ -621,22 +535,17 @@ internal open class UniffiForeignFutureResultI32(
     class UniffiByValue(
         `returnValue`: Int = 0,
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureResultI32(`returnValue`, `callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureResultI32(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureResultI32) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureResultI32) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteI32 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureResultI32.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureResultI32.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureResultU64(
     @JvmField internal var `returnValue`: Long = 0.toLong(),
@@ -645,22 +554,17 @@ internal open class UniffiForeignFutureResultU64(
     class UniffiByValue(
         `returnValue`: Long = 0.toLong(),
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureResultU64(`returnValue`, `callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureResultU64(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureResultU64) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureResultU64) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteU64 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureResultU64.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureResultU64.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureResultI64(
     @JvmField internal var `returnValue`: Long = 0.toLong(),
@@ -669,22 +573,17 @@ internal open class UniffiForeignFutureResultI64(
     class UniffiByValue(
         `returnValue`: Long = 0.toLong(),
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureResultI64(`returnValue`, `callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureResultI64(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureResultI64) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureResultI64) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteI64 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureResultI64.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureResultI64.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureResultF32(
     @JvmField internal var `returnValue`: Float = 0.0f,
@@ -693,22 +592,17 @@ internal open class UniffiForeignFutureResultF32(
     class UniffiByValue(
         `returnValue`: Float = 0.0f,
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureResultF32(`returnValue`, `callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureResultF32(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureResultF32) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureResultF32) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteF32 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureResultF32.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureResultF32.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureResultF64(
     @JvmField internal var `returnValue`: Double = 0.0,
@@ -717,22 +611,17 @@ internal open class UniffiForeignFutureResultF64(
     class UniffiByValue(
         `returnValue`: Double = 0.0,
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureResultF64(`returnValue`, `callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureResultF64(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureResultF64) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureResultF64) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteF64 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureResultF64.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureResultF64.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureResultRustBuffer(
     @JvmField internal var `returnValue`: RustBuffer.ByValue = RustBuffer.ByValue(),
@@ -741,41 +630,60 @@ internal open class UniffiForeignFutureResultRustBuffer(
     class UniffiByValue(
         `returnValue`: RustBuffer.ByValue = RustBuffer.ByValue(),
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureResultRustBuffer(`returnValue`, `callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureResultRustBuffer(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureResultRustBuffer) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureResultRustBuffer) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteRustBuffer : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureResultRustBuffer.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureResultRustBuffer.UniffiByValue,)
 }
-
 @Structure.FieldOrder("callStatus")
 internal open class UniffiForeignFutureResultVoid(
     @JvmField internal var `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
 ) : Structure() {
     class UniffiByValue(
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureResultVoid(`callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureResultVoid(`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureResultVoid) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureResultVoid) {
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteVoid : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureResultVoid.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureResultVoid.UniffiByValue,)
+}
+internal interface UniffiCallbackInterfaceMobileAlarmListenerMethod0 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`alarm`: RustBuffer.ByValue,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+internal interface UniffiCallbackInterfaceMobileAlarmListenerMethod1 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+@Structure.FieldOrder("uniffiFree", "uniffiClone", "onAlarm", "onSyncRequested")
+internal open class UniffiVTableCallbackInterfaceMobileAlarmListener(
+    @JvmField internal var `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+    @JvmField internal var `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+    @JvmField internal var `onAlarm`: UniffiCallbackInterfaceMobileAlarmListenerMethod0? = null,
+    @JvmField internal var `onSyncRequested`: UniffiCallbackInterfaceMobileAlarmListenerMethod1? = null,
+) : Structure() {
+    class UniffiByValue(
+        `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+        `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+        `onAlarm`: UniffiCallbackInterfaceMobileAlarmListenerMethod0? = null,
+        `onSyncRequested`: UniffiCallbackInterfaceMobileAlarmListenerMethod1? = null,
+    ): UniffiVTableCallbackInterfaceMobileAlarmListener(`uniffiFree`,`uniffiClone`,`onAlarm`,`onSyncRequested`,), Structure.ByValue
+
+   internal fun uniffiSetValue(other: UniffiVTableCallbackInterfaceMobileAlarmListener) {
+        `uniffiFree` = other.`uniffiFree`
+        `uniffiClone` = other.`uniffiClone`
+        `onAlarm` = other.`onAlarm`
+        `onSyncRequested` = other.`onSyncRequested`
+    }
+
 }
 
 // A JNA Library to expose the extern-C FFI definitions.
@@ -800,836 +708,553 @@ internal object IntegrityCheckingUniffiLib {
         uniffiCheckContractApiVersion(this)
         uniffiCheckApiChecksums(this)
     }
+    external fun uniffi_cfait_checksum_func_init_panic_hook(
+    ): Int
+    external fun uniffi_cfait_checksum_func_init_tokio_runtime(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_add_alias(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_add_dependency(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_add_related_to(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_add_reminder(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_add_session(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_add_task_smart(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_add_task_with_description(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_bulk_tag(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_change_priority(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_compact_journal(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_complete_all(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_connect(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_create_debug_export(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_create_local_calendar(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_create_missing_calendar_events(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_create_remote_calendar(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_delete_all_calendar_events(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_delete_local_calendar(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_delete_session(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_delete_task(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_delete_task_tree(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_diagnose_connection(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_dismiss_alarm(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_dispatch(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_drop_journal_action(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_duplicate_task_tree(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_edit_session(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_export_all(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_export_local_ics(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_export_locations_gpx(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_get_all_locations(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_get_all_tags(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_get_available_locales(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_get_calendars(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_get_config(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_get_firing_alarms(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_get_help_data(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_get_journal_entries(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_get_next_alarm_timestamp(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_get_next_global_alarm_time(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_get_ongoing_tasks(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_get_random_task_uid(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_get_stale_unsynced_tasks(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_get_syntax_help(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_get_task_by_uid(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_get_task_tree_markdown(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_get_tasks_related_to(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_get_version_info(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_get_view_markdown(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_get_view_tasks(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_has_any_tasks(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_has_unsynced_changes(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_import_ics(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_import_local_ics(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_isolate_calendar(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_load_from_cache(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_log_message(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_migrate_local_to(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_move_calendar(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_move_task(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_parse_duration_string(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_parse_smart_string(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_pause_task(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_remove_alias(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_remove_dependency(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_remove_related_to(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_rename_tag(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_resolve_selection_aliases(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_retry_unsynced(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_save_config(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_set_calendar_color(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_set_calendar_override(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_set_calendar_visibility(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_set_default_calendar(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_set_locale(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_set_parent(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_set_progress(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_set_status_cancelled(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_set_status_process(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_should_keep_notification(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_skip_occurrence(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_snooze_alarm(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_start_alarm_actor(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_start_task(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_stop_task(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_sync(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_sync_journal(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_sync_task_tree_from_markdown(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_toggle_all_calendars(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_toggle_pin(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_toggle_task(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_toggle_task_shift(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_toggle_waiting(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_update_local_calendar(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_update_remote_calendar(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_update_task_description(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_update_task_smart(
+    ): Int
+    external fun uniffi_cfait_checksum_method_cfaitmobile_yank_task(
+    ): Int
+    external fun uniffi_cfait_checksum_method_mobilealarmlistener_on_alarm(
+    ): Int
+    external fun uniffi_cfait_checksum_method_mobilealarmlistener_on_sync_requested(
+    ): Int
+    external fun uniffi_cfait_checksum_constructor_cfaitmobile_new(
+    ): Int
+    external fun ffi_cfait_uniffi_contract_version(
+    ): Int
 
-    external fun uniffi_cfait_checksum_func_init_panic_hook(): Int
-
-    external fun uniffi_cfait_checksum_func_init_tokio_runtime(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_add_alias(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_add_dependency(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_add_related_to(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_add_session(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_add_task_smart(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_add_task_with_description(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_change_priority(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_connect(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_create_debug_export(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_create_local_calendar(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_create_missing_calendar_events(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_create_remote_calendar(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_delete_all_calendar_events(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_delete_local_calendar(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_delete_session(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_delete_task(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_delete_task_tree(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_dismiss_alarm(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_dispatch(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_duplicate_task_tree(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_edit_session(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_export_local_ics(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_export_locations_gpx(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_get_all_locations(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_get_all_tags(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_get_available_locales(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_get_calendars(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_get_config(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_get_firing_alarms(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_get_help_data(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_get_next_alarm_timestamp(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_get_next_global_alarm_time(): Int
+        
+}
 
-    external fun uniffi_cfait_checksum_method_cfaitmobile_get_ongoing_tasks(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_get_random_task_uid(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_get_syntax_help(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_get_task_by_uid(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_get_task_tree_markdown(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_get_tasks_related_to(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_get_version_info(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_get_view_tasks(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_has_any_tasks(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_has_unsynced_changes(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_import_local_ics(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_isolate_calendar(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_load_from_cache(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_log_message(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_migrate_local_to(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_move_calendar(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_move_task(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_parse_duration_string(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_parse_smart_string(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_pause_task(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_remove_alias(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_remove_dependency(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_remove_related_to(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_resolve_selection_aliases(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_save_config(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_set_calendar_visibility(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_set_default_calendar(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_set_locale(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_set_parent(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_set_status_cancelled(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_set_status_process(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_should_keep_notification(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_snooze_alarm(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_start_task(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_stop_task(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_sync(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_sync_journal(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_sync_task_tree_from_markdown(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_toggle_all_calendars(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_toggle_pin(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_toggle_task(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_toggle_task_shift(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_update_local_calendar(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_update_remote_calendar(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_update_task_description(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_update_task_smart(): Int
-
-    external fun uniffi_cfait_checksum_method_cfaitmobile_yank_task(): Int
-
-    external fun uniffi_cfait_checksum_constructor_cfaitmobile_new(): Int
-
-    external fun ffi_cfait_uniffi_contract_version(): Int
-}
-
-internal object UniffiLib {
-    // The Cleaner for the whole library
-    internal val CLEANER: UniffiCleaner by lazy {
-        UniffiCleaner.create()
-    }
+internal object UniffiLib {
+    
+    // The Cleaner for the whole library
+    internal val CLEANER: UniffiCleaner by lazy {
+        UniffiCleaner.create()
+    }
+    
 
     init {
         Native.register(UniffiLib::class.java, findLibraryName(componentName = "cfait"))
+        uniffiCallbackInterfaceMobileAlarmListener.register(this)
+        
     }
-
-    external fun uniffi_cfait_fn_clone_cfaitmobile(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun uniffi_cfait_fn_clone_cfaitmobile(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
     ): Long
-
-    external fun uniffi_cfait_fn_free_cfaitmobile(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun uniffi_cfait_fn_free_cfaitmobile(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
     ): Unit
-
-    external fun uniffi_cfait_fn_constructor_cfaitmobile_new(
-        `androidFilesDir`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun uniffi_cfait_fn_constructor_cfaitmobile_new(`androidFilesDir`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_add_alias(
-        `ptr`: Long,
-        `key`: RustBuffer.ByValue,
-        `tags`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_add_alias(`ptr`: Long,`key`: RustBuffer.ByValue,`tags`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_add_dependency(
-        `ptr`: Long,
-        `taskUid`: RustBuffer.ByValue,
-        `blockerUid`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_add_dependency(`ptr`: Long,`taskUid`: RustBuffer.ByValue,`blockerUid`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_add_related_to(
-        `ptr`: Long,
-        `taskUid`: RustBuffer.ByValue,
-        `relatedUid`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_add_related_to(`ptr`: Long,`taskUid`: RustBuffer.ByValue,`relatedUid`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_add_session(
-        `ptr`: Long,
-        `uid`: RustBuffer.ByValue,
-        `input`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_add_reminder(`ptr`: Long,`uid`: RustBuffer.ByValue,`spec`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_add_task_smart(
-        `ptr`: Long,
-        `input`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_add_session(`ptr`: Long,`uid`: RustBuffer.ByValue,`input`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_add_task_with_description(
-        `ptr`: Long,
-        `input`: RustBuffer.ByValue,
-        `description`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_add_task_smart(`ptr`: Long,`input`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_change_priority(
-        `ptr`: Long,
-        `uid`: RustBuffer.ByValue,
-        `delta`: Byte,
+    external fun uniffi_cfait_fn_method_cfaitmobile_add_task_with_description(`ptr`: Long,`input`: RustBuffer.ByValue,`description`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_connect(
-        `ptr`: Long,
-        `url`: RustBuffer.ByValue,
-        `user`: RustBuffer.ByValue,
-        `pass`: RustBuffer.ByValue,
-        `insecure`: Byte,
+    external fun uniffi_cfait_fn_method_cfaitmobile_bulk_tag(`ptr`: Long,`add`: Byte,`tag`: RustBuffer.ByValue,`filterTag`: RustBuffer.ByValue,`search`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_create_debug_export(
-        `ptr`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun uniffi_cfait_fn_method_cfaitmobile_change_priority(`ptr`: Long,`uid`: RustBuffer.ByValue,`delta`: Byte,
+    ): Long
+    external fun uniffi_cfait_fn_method_cfaitmobile_compact_journal(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): Int
+    external fun uniffi_cfait_fn_method_cfaitmobile_complete_all(`ptr`: Long,`filterTag`: RustBuffer.ByValue,`search`: RustBuffer.ByValue,
+    ): Long
+    external fun uniffi_cfait_fn_method_cfaitmobile_connect(`ptr`: Long,`url`: RustBuffer.ByValue,`user`: RustBuffer.ByValue,`pass`: RustBuffer.ByValue,`insecure`: Byte,
+    ): Long
+    external fun uniffi_cfait_fn_method_cfaitmobile_create_debug_export(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_create_local_calendar(
-        `ptr`: Long,
-        `name`: RustBuffer.ByValue,
-        `color`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_create_local_calendar(`ptr`: Long,`name`: RustBuffer.ByValue,`color`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_create_missing_calendar_events(`ptr`: Long): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_create_remote_calendar(
-        `ptr`: Long,
-        `name`: RustBuffer.ByValue,
-        `color`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_create_missing_calendar_events(`ptr`: Long,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_delete_all_calendar_events(`ptr`: Long): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_delete_local_calendar(
-        `ptr`: Long,
-        `href`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_create_remote_calendar(`ptr`: Long,`name`: RustBuffer.ByValue,`color`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_delete_session(
-        `ptr`: Long,
-        `uid`: RustBuffer.ByValue,
-        `index`: Int,
+    external fun uniffi_cfait_fn_method_cfaitmobile_delete_all_calendar_events(`ptr`: Long,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_delete_task(
-        `ptr`: Long,
-        `uid`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_delete_local_calendar(`ptr`: Long,`href`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_delete_task_tree(
-        `ptr`: Long,
-        `uid`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_delete_session(`ptr`: Long,`uid`: RustBuffer.ByValue,`index`: Int,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_dismiss_alarm(
-        `ptr`: Long,
-        `taskUid`: RustBuffer.ByValue,
-        `alarmUid`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_delete_task(`ptr`: Long,`uid`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_dispatch(
-        `ptr`: Long,
-        `intent`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_delete_task_tree(`ptr`: Long,`uid`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_duplicate_task_tree(
-        `ptr`: Long,
-        `uid`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_diagnose_connection(`ptr`: Long,`url`: RustBuffer.ByValue,`user`: RustBuffer.ByValue,`pass`: RustBuffer.ByValue,`insecure`: Byte,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_edit_session(
-        `ptr`: Long,
-        `uid`: RustBuffer.ByValue,
-        `index`: Int,
-        `input`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_dismiss_alarm(`ptr`: Long,`taskUid`: RustBuffer.ByValue,`alarmUid`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_export_local_ics(
-        `ptr`: Long,
-        `calendarHref`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun uniffi_cfait_fn_method_cfaitmobile_dispatch(`ptr`: Long,`intent`: RustBuffer.ByValue,
+    ): Long
+    external fun uniffi_cfait_fn_method_cfaitmobile_drop_journal_action(`ptr`: Long,`index`: Int,`uid`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Byte
+    external fun uniffi_cfait_fn_method_cfaitmobile_duplicate_task_tree(`ptr`: Long,`uid`: RustBuffer.ByValue,
+    ): Long
+    external fun uniffi_cfait_fn_method_cfaitmobile_edit_session(`ptr`: Long,`uid`: RustBuffer.ByValue,`index`: Int,`input`: RustBuffer.ByValue,
+    ): Long
+    external fun uniffi_cfait_fn_method_cfaitmobile_export_all(`ptr`: Long,`asJson`: Byte,uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_export_locations_gpx(
-        `ptr`: Long,
-        `uid`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun uniffi_cfait_fn_method_cfaitmobile_export_local_ics(`ptr`: Long,`calendarHref`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_get_all_locations(`ptr`: Long): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_get_all_tags(`ptr`: Long): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_get_available_locales(
-        `ptr`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun uniffi_cfait_fn_method_cfaitmobile_export_locations_gpx(`ptr`: Long,`uid`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_get_calendars(
-        `ptr`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun uniffi_cfait_fn_method_cfaitmobile_get_all_locations(`ptr`: Long,
+    ): Long
+    external fun uniffi_cfait_fn_method_cfaitmobile_get_all_tags(`ptr`: Long,
+    ): Long
+    external fun uniffi_cfait_fn_method_cfaitmobile_get_available_locales(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_get_config(
-        `ptr`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun uniffi_cfait_fn_method_cfaitmobile_get_calendars(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_get_firing_alarms(
-        `ptr`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun uniffi_cfait_fn_method_cfaitmobile_get_config(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_get_help_data(
-        `ptr`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun uniffi_cfait_fn_method_cfaitmobile_get_firing_alarms(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_get_next_alarm_timestamp(
-        `ptr`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun uniffi_cfait_fn_method_cfaitmobile_get_help_data(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_get_next_global_alarm_time(`ptr`: Long): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_get_ongoing_tasks(
-        `ptr`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun uniffi_cfait_fn_method_cfaitmobile_get_journal_entries(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_get_random_task_uid(
-        `ptr`: Long,
-        `filterTags`: RustBuffer.ByValue,
-        `filterLocations`: RustBuffer.ByValue,
-        `searchQuery`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_get_next_alarm_timestamp(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    external fun uniffi_cfait_fn_method_cfaitmobile_get_next_global_alarm_time(`ptr`: Long,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_get_syntax_help(
-        `ptr`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun uniffi_cfait_fn_method_cfaitmobile_get_ongoing_tasks(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_get_task_by_uid(
-        `ptr`: Long,
-        `uid`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_get_random_task_uid(`ptr`: Long,`filterTags`: RustBuffer.ByValue,`filterLocations`: RustBuffer.ByValue,`searchQuery`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_get_task_tree_markdown(
-        `ptr`: Long,
-        `uid`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun uniffi_cfait_fn_method_cfaitmobile_get_stale_unsynced_tasks(`ptr`: Long,
+    ): Long
+    external fun uniffi_cfait_fn_method_cfaitmobile_get_syntax_help(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_get_tasks_related_to(
-        `ptr`: Long,
-        `uid`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_get_task_by_uid(`ptr`: Long,`uid`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_get_version_info(
-        `ptr`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun uniffi_cfait_fn_method_cfaitmobile_get_task_tree_markdown(`ptr`: Long,`uid`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_get_view_tasks(
-        `ptr`: Long,
-        `options`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_get_tasks_related_to(`ptr`: Long,`uid`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_has_any_tasks(
-        `ptr`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun uniffi_cfait_fn_method_cfaitmobile_get_version_info(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    external fun uniffi_cfait_fn_method_cfaitmobile_get_view_markdown(`ptr`: Long,`options`: RustBuffer.ByValue,
+    ): Long
+    external fun uniffi_cfait_fn_method_cfaitmobile_get_view_tasks(`ptr`: Long,`options`: RustBuffer.ByValue,
+    ): Long
+    external fun uniffi_cfait_fn_method_cfaitmobile_has_any_tasks(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
     ): Byte
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_has_unsynced_changes(
-        `ptr`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun uniffi_cfait_fn_method_cfaitmobile_has_unsynced_changes(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
     ): Byte
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_import_local_ics(
-        `ptr`: Long,
-        `calendarHref`: RustBuffer.ByValue,
-        `icsContent`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun uniffi_cfait_fn_method_cfaitmobile_import_ics(`ptr`: Long,`data`: RustBuffer.ByValue,`targetHref`: RustBuffer.ByValue,`conflictCopies`: Byte,
+    ): Long
+    external fun uniffi_cfait_fn_method_cfaitmobile_import_local_ics(`ptr`: Long,`calendarHref`: RustBuffer.ByValue,`icsContent`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_isolate_calendar(
-        `ptr`: Long,
-        `href`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun uniffi_cfait_fn_method_cfaitmobile_isolate_calendar(`ptr`: Long,`href`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
     ): Unit
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_load_from_cache(
-        `ptr`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun uniffi_cfait_fn_method_cfaitmobile_load_from_cache(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
     ): Unit
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_log_message(
-        `ptr`: Long,
-        `level`: RustBuffer.ByValue,
-        `tag`: RustBuffer.ByValue,
-        `message`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun uniffi_cfait_fn_method_cfaitmobile_log_message(`ptr`: Long,`level`: RustBuffer.ByValue,`tag`: RustBuffer.ByValue,`message`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
     ): Unit
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_migrate_local_to(
-        `ptr`: Long,
-        `sourceHref`: RustBuffer.ByValue,
-        `targetHref`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_migrate_local_to(`ptr`: Long,`sourceHref`: RustBuffer.ByValue,`targetHref`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_move_calendar(
-        `ptr`: Long,
-        `href`: RustBuffer.ByValue,
-        `direction`: Byte,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun uniffi_cfait_fn_method_cfaitmobile_move_calendar(`ptr`: Long,`href`: RustBuffer.ByValue,`direction`: Byte,uniffi_out_err: UniffiRustCallStatus, 
     ): Unit
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_move_task(
-        `ptr`: Long,
-        `uid`: RustBuffer.ByValue,
-        `newCalHref`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_move_task(`ptr`: Long,`uid`: RustBuffer.ByValue,`newCalHref`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_parse_duration_string(
-        `ptr`: Long,
-        `val`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun uniffi_cfait_fn_method_cfaitmobile_parse_duration_string(`ptr`: Long,`val`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_parse_smart_string(
-        `ptr`: Long,
-        `input`: RustBuffer.ByValue,
-        `isSearch`: Byte,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun uniffi_cfait_fn_method_cfaitmobile_parse_smart_string(`ptr`: Long,`input`: RustBuffer.ByValue,`isSearch`: Byte,uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_pause_task(
-        `ptr`: Long,
-        `uid`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_pause_task(`ptr`: Long,`uid`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_remove_alias(
-        `ptr`: Long,
-        `key`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun uniffi_cfait_fn_method_cfaitmobile_remove_alias(`ptr`: Long,`key`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
     ): Unit
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_remove_dependency(
-        `ptr`: Long,
-        `taskUid`: RustBuffer.ByValue,
-        `blockerUid`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_remove_dependency(`ptr`: Long,`taskUid`: RustBuffer.ByValue,`blockerUid`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_remove_related_to(
-        `ptr`: Long,
-        `taskUid`: RustBuffer.ByValue,
-        `relatedUid`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_remove_related_to(`ptr`: Long,`taskUid`: RustBuffer.ByValue,`relatedUid`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_resolve_selection_aliases(
-        `ptr`: Long,
-        `selection`: RustBuffer.ByValue,
-        `isLocation`: Byte,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun uniffi_cfait_fn_method_cfaitmobile_rename_tag(`ptr`: Long,`from`: RustBuffer.ByValue,`to`: RustBuffer.ByValue,
+    ): Long
+    external fun uniffi_cfait_fn_method_cfaitmobile_resolve_selection_aliases(`ptr`: Long,`selection`: RustBuffer.ByValue,`isLocation`: Byte,uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_save_config(
-        `ptr`: Long,
-        `config`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun uniffi_cfait_fn_method_cfaitmobile_retry_unsynced(`ptr`: Long,`uid`: RustBuffer.ByValue,
+    ): Long
+    external fun uniffi_cfait_fn_method_cfaitmobile_save_config(`ptr`: Long,`config`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
     ): Unit
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_set_calendar_visibility(
-        `ptr`: Long,
-        `href`: RustBuffer.ByValue,
-        `visible`: Byte,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun uniffi_cfait_fn_method_cfaitmobile_set_calendar_color(`ptr`: Long,`href`: RustBuffer.ByValue,`color`: RustBuffer.ByValue,
+    ): Long
+    external fun uniffi_cfait_fn_method_cfaitmobile_set_calendar_override(`ptr`: Long,`href`: RustBuffer.ByValue,`name`: RustBuffer.ByValue,`color`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
     ): Unit
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_set_default_calendar(
-        `ptr`: Long,
-        `href`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun uniffi_cfait_fn_method_cfaitmobile_set_calendar_visibility(`ptr`: Long,`href`: RustBuffer.ByValue,`visible`: Byte,uniffi_out_err: UniffiRustCallStatus, 
     ): Unit
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_set_locale(
-        `ptr`: Long,
-        `locale`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun uniffi_cfait_fn_method_cfaitmobile_set_default_calendar(`ptr`: Long,`href`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
     ): Unit
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_set_parent(
-        `ptr`: Long,
-        `childUid`: RustBuffer.ByValue,
-        `parentUid`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_set_locale(`ptr`: Long,`locale`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    external fun uniffi_cfait_fn_method_cfaitmobile_set_parent(`ptr`: Long,`childUid`: RustBuffer.ByValue,`parentUid`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_set_status_cancelled(
-        `ptr`: Long,
-        `uid`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_set_progress(`ptr`: Long,`uid`: RustBuffer.ByValue,`percent`: Byte,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_set_status_process(
-        `ptr`: Long,
-        `uid`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_set_status_cancelled(`ptr`: Long,`uid`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_should_keep_notification(
-        `ptr`: Long,
-        `taskUid`: RustBuffer.ByValue,
-        `notifType`: RustBuffer.ByValue,
-        `alarmUid`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_set_status_process(`ptr`: Long,`uid`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_snooze_alarm(
-        `ptr`: Long,
-        `taskUid`: RustBuffer.ByValue,
-        `alarmUid`: RustBuffer.ByValue,
-        `minutes`: Int,
+    external fun uniffi_cfait_fn_method_cfaitmobile_should_keep_notification(`ptr`: Long,`taskUid`: RustBuffer.ByValue,`notifType`: RustBuffer.ByValue,`alarmUid`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_start_task(
-        `ptr`: Long,
-        `uid`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_skip_occurrence(`ptr`: Long,`uid`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_stop_task(
-        `ptr`: Long,
-        `uid`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_snooze_alarm(`ptr`: Long,`taskUid`: RustBuffer.ByValue,`alarmUid`: RustBuffer.ByValue,`minutes`: Int,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_sync(`ptr`: Long): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_sync_journal(`ptr`: Long): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_sync_task_tree_from_markdown(
-        `ptr`: Long,
-        `uid`: RustBuffer.ByValue,
-        `markdown`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_start_alarm_actor(`ptr`: Long,`listener`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    external fun uniffi_cfait_fn_method_cfaitmobile_start_task(`ptr`: Long,`uid`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_toggle_all_calendars(
-        `ptr`: Long,
-        `showAll`: Byte,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun uniffi_cfait_fn_method_cfaitmobile_stop_task(`ptr`: Long,`uid`: RustBuffer.ByValue,
+    ): Long
+    external fun uniffi_cfait_fn_method_cfaitmobile_sync(`ptr`: Long,
+    ): Long
+    external fun uniffi_cfait_fn_method_cfaitmobile_sync_journal(`ptr`: Long,
+    ): Long
+    external fun uniffi_cfait_fn_method_cfaitmobile_sync_task_tree_from_markdown(`ptr`: Long,`uid`: RustBuffer.ByValue,`markdown`: RustBuffer.ByValue,
+    ): Long
+    external fun uniffi_cfait_fn_method_cfaitmobile_toggle_all_calendars(`ptr`: Long,`showAll`: Byte,uniffi_out_err: UniffiRustCallStatus, 
     ): Unit
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_toggle_pin(
-        `ptr`: Long,
-        `uid`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_toggle_pin(`ptr`: Long,`uid`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_toggle_task(
-        `ptr`: Long,
-        `uid`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_toggle_task(`ptr`: Long,`uid`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_toggle_task_shift(
-        `ptr`: Long,
-        `uid`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_toggle_task_shift(`ptr`: Long,`uid`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_update_local_calendar(
-        `ptr`: Long,
-        `href`: RustBuffer.ByValue,
-        `name`: RustBuffer.ByValue,
-        `color`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_toggle_waiting(`ptr`: Long,`uid`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_update_remote_calendar(
-        `ptr`: Long,
-        `href`: RustBuffer.ByValue,
-        `name`: RustBuffer.ByValue,
-        `color`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_update_local_calendar(`ptr`: Long,`href`: RustBuffer.ByValue,`name`: RustBuffer.ByValue,`color`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_update_task_description(
-        `ptr`: Long,
-        `uid`: RustBuffer.ByValue,
-        `description`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_update_remote_calendar(`ptr`: Long,`href`: RustBuffer.ByValue,`name`: RustBuffer.ByValue,`color`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_update_task_smart(
-        `ptr`: Long,
-        `uid`: RustBuffer.ByValue,
-        `smartInput`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_update_task_description(`ptr`: Long,`uid`: RustBuffer.ByValue,`description`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_method_cfaitmobile_yank_task(
-        `ptr`: Long,
-        `uid`: RustBuffer.ByValue,
+    external fun uniffi_cfait_fn_method_cfaitmobile_update_task_smart(`ptr`: Long,`uid`: RustBuffer.ByValue,`smartInput`: RustBuffer.ByValue,
     ): Long
-
-    external fun uniffi_cfait_fn_func_init_panic_hook(
-        `cacheDir`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun uniffi_cfait_fn_method_cfaitmobile_yank_task(`ptr`: Long,`uid`: RustBuffer.ByValue,
+    ): Long
+    external fun uniffi_cfait_fn_clone_mobilealarmlistener(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): Long
+    external fun uniffi_cfait_fn_free_mobilealarmlistener(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
     ): Unit
-
-    external fun uniffi_cfait_fn_func_init_tokio_runtime(uniffi_out_err: UniffiRustCallStatus): Unit
-
-    external fun ffi_cfait_rustbuffer_alloc(
-        `size`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun uniffi_cfait_fn_init_callback_vtable_mobilealarmlistener(`vtable`: UniffiVTableCallbackInterfaceMobileAlarmListener,
+    ): Unit
+    external fun uniffi_cfait_fn_method_mobilealarmlistener_on_alarm(`ptr`: Long,`alarm`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    external fun uniffi_cfait_fn_method_mobilealarmlistener_on_sync_requested(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    external fun uniffi_cfait_fn_func_init_panic_hook(`cacheDir`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    external fun uniffi_cfait_fn_func_init_tokio_runtime(uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    external fun ffi_cfait_rustbuffer_alloc(`size`: Long,uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
-
-    external fun ffi_cfait_rustbuffer_from_bytes(
-        `bytes`: ForeignBytes.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun ffi_cfait_rustbuffer_from_bytes(`bytes`: ForeignBytes.ByValue,uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
-
-    external fun ffi_cfait_rustbuffer_free(
-        `buf`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun ffi_cfait_rustbuffer_free(`buf`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
     ): Unit
-
-    external fun ffi_cfait_rustbuffer_reserve(
-        `buf`: RustBuffer.ByValue,
-        `additional`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun ffi_cfait_rustbuffer_reserve(`buf`: RustBuffer.ByValue,`additional`: Long,uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
-
-    external fun ffi_cfait_rust_future_poll_u8(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
+    external fun ffi_cfait_rust_future_poll_u8(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
     ): Unit
-
-    external fun ffi_cfait_rust_future_cancel_u8(`handle`: Long): Unit
-
-    external fun ffi_cfait_rust_future_free_u8(`handle`: Long): Unit
-
-    external fun ffi_cfait_rust_future_complete_u8(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun ffi_cfait_rust_future_cancel_u8(`handle`: Long,
+    ): Unit
+    external fun ffi_cfait_rust_future_free_u8(`handle`: Long,
+    ): Unit
+    external fun ffi_cfait_rust_future_complete_u8(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
     ): Int
-
-    external fun ffi_cfait_rust_future_poll_i8(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
+    external fun ffi_cfait_rust_future_poll_i8(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
     ): Unit
-
-    external fun ffi_cfait_rust_future_cancel_i8(`handle`: Long): Unit
-
-    external fun ffi_cfait_rust_future_free_i8(`handle`: Long): Unit
-
-    external fun ffi_cfait_rust_future_complete_i8(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun ffi_cfait_rust_future_cancel_i8(`handle`: Long,
+    ): Unit
+    external fun ffi_cfait_rust_future_free_i8(`handle`: Long,
+    ): Unit
+    external fun ffi_cfait_rust_future_complete_i8(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
     ): Byte
-
-    external fun ffi_cfait_rust_future_poll_u16(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
+    external fun ffi_cfait_rust_future_poll_u16(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
     ): Unit
-
-    external fun ffi_cfait_rust_future_cancel_u16(`handle`: Long): Unit
-
-    external fun ffi_cfait_rust_future_free_u16(`handle`: Long): Unit
-
-    external fun ffi_cfait_rust_future_complete_u16(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun ffi_cfait_rust_future_cancel_u16(`handle`: Long,
+    ): Unit
+    external fun ffi_cfait_rust_future_free_u16(`handle`: Long,
+    ): Unit
+    external fun ffi_cfait_rust_future_complete_u16(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
     ): Int
-
-    external fun ffi_cfait_rust_future_poll_i16(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
+    external fun ffi_cfait_rust_future_poll_i16(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
     ): Unit
-
-    external fun ffi_cfait_rust_future_cancel_i16(`handle`: Long): Unit
-
-    external fun ffi_cfait_rust_future_free_i16(`handle`: Long): Unit
-
-    external fun ffi_cfait_rust_future_complete_i16(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun ffi_cfait_rust_future_cancel_i16(`handle`: Long,
+    ): Unit
+    external fun ffi_cfait_rust_future_free_i16(`handle`: Long,
+    ): Unit
+    external fun ffi_cfait_rust_future_complete_i16(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
     ): Short
-
-    external fun ffi_cfait_rust_future_poll_u32(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
+    external fun ffi_cfait_rust_future_poll_u32(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
     ): Unit
-
-    external fun ffi_cfait_rust_future_cancel_u32(`handle`: Long): Unit
-
-    external fun ffi_cfait_rust_future_free_u32(`handle`: Long): Unit
-
-    external fun ffi_cfait_rust_future_complete_u32(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun ffi_cfait_rust_future_cancel_u32(`handle`: Long,
+    ): Unit
+    external fun ffi_cfait_rust_future_free_u32(`handle`: Long,
+    ): Unit
+    external fun ffi_cfait_rust_future_complete_u32(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
     ): Int
-
-    external fun ffi_cfait_rust_future_poll_i32(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
+    external fun ffi_cfait_rust_future_poll_i32(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
     ): Unit
-
-    external fun ffi_cfait_rust_future_cancel_i32(`handle`: Long): Unit
-
-    external fun ffi_cfait_rust_future_free_i32(`handle`: Long): Unit
-
-    external fun ffi_cfait_rust_future_complete_i32(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun ffi_cfait_rust_future_cancel_i32(`handle`: Long,
+    ): Unit
+    external fun ffi_cfait_rust_future_free_i32(`handle`: Long,
+    ): Unit
+    external fun ffi_cfait_rust_future_complete_i32(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
     ): Int
-
-    external fun ffi_cfait_rust_future_poll_u64(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
+    external fun ffi_cfait_rust_future_poll_u64(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
     ): Unit
-
-    external fun ffi_cfait_rust_future_cancel_u64(`handle`: Long): Unit
-
-    external fun ffi_cfait_rust_future_free_u64(`handle`: Long): Unit
-
-    external fun ffi_cfait_rust_future_complete_u64(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun ffi_cfait_rust_future_cancel_u64(`handle`: Long,
+    ): Unit
+    external fun ffi_cfait_rust_future_free_u64(`handle`: Long,
+    ): Unit
+    external fun ffi_cfait_rust_future_complete_u64(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
     ): Long
-
-    external fun ffi_cfait_rust_future_poll_i64(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
+    external fun ffi_cfait_rust_future_poll_i64(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
     ): Unit
-
-    external fun ffi_cfait_rust_future_cancel_i64(`handle`: Long): Unit
-
-    external fun ffi_cfait_rust_future_free_i64(`handle`: Long): Unit
-
-    external fun ffi_cfait_rust_future_complete_i64(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun ffi_cfait_rust_future_cancel_i64(`handle`: Long,
+    ): Unit
+    external fun ffi_cfait_rust_future_free_i64(`handle`: Long,
+    ): Unit
+    external fun ffi_cfait_rust_future_complete_i64(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
     ): Long
-
-    external fun ffi_cfait_rust_future_poll_f32(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
+    external fun ffi_cfait_rust_future_poll_f32(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
     ): Unit
-
-    external fun ffi_cfait_rust_future_cancel_f32(`handle`: Long): Unit
-
-    external fun ffi_cfait_rust_future_free_f32(`handle`: Long): Unit
-
-    external fun ffi_cfait_rust_future_complete_f32(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun ffi_cfait_rust_future_cancel_f32(`handle`: Long,
+    ): Unit
+    external fun ffi_cfait_rust_future_free_f32(`handle`: Long,
+    ): Unit
+    external fun ffi_cfait_rust_future_complete_f32(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
     ): Float
-
-    external fun ffi_cfait_rust_future_poll_f64(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
+    external fun ffi_cfait_rust_future_poll_f64(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
     ): Unit
-
-    external fun ffi_cfait_rust_future_cancel_f64(`handle`: Long): Unit
-
-    external fun ffi_cfait_rust_future_free_f64(`handle`: Long): Unit
-
-    external fun ffi_cfait_rust_future_complete_f64(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun ffi_cfait_rust_future_cancel_f64(`handle`: Long,
+    ): Unit
+    external fun ffi_cfait_rust_future_free_f64(`handle`: Long,
+    ): Unit
+    external fun ffi_cfait_rust_future_complete_f64(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
     ): Double
-
-    external fun ffi_cfait_rust_future_poll_rust_buffer(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
+    external fun ffi_cfait_rust_future_poll_rust_buffer(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
     ): Unit
-
-    external fun ffi_cfait_rust_future_cancel_rust_buffer(`handle`: Long): Unit
-
-    external fun ffi_cfait_rust_future_free_rust_buffer(`handle`: Long): Unit
-
-    external fun ffi_cfait_rust_future_complete_rust_buffer(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun ffi_cfait_rust_future_cancel_rust_buffer(`handle`: Long,
+    ): Unit
+    external fun ffi_cfait_rust_future_free_rust_buffer(`handle`: Long,
+    ): Unit
+    external fun ffi_cfait_rust_future_complete_rust_buffer(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
-
-    external fun ffi_cfait_rust_future_poll_void(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
+    external fun ffi_cfait_rust_future_poll_void(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
     ): Unit
-
-    external fun ffi_cfait_rust_future_cancel_void(`handle`: Long): Unit
-
-    external fun ffi_cfait_rust_future_free_void(`handle`: Long): Unit
-
-    external fun ffi_cfait_rust_future_complete_void(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
+    external fun ffi_cfait_rust_future_cancel_void(`handle`: Long,
+    ): Unit
+    external fun ffi_cfait_rust_future_free_void(`handle`: Long,
     ): Unit
+    external fun ffi_cfait_rust_future_complete_void(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+
+        
 }
 
 private fun uniffiCheckContractApiVersion(lib: IntegrityCheckingUniffiLib) {
@@ -1641,7 +1266,6 @@ private fun uniffiCheckContractApiVersion(lib: IntegrityCheckingUniffiLib) {
         throw RuntimeException("UniFFI contract version mismatch: try cleaning and rebuilding your project")
     }
 }
-
 @Suppress("UNUSED_PARAMETER")
 private fun uniffiCheckApiChecksums(lib: IntegrityCheckingUniffiLib) {
     if (lib.uniffi_cfait_checksum_func_init_panic_hook() != 13852) {
@@ -1659,6 +1283,9 @@ private fun uniffiCheckApiChecksums(lib: IntegrityCheckingUniffiLib) {
     if (lib.uniffi_cfait_checksum_method_cfaitmobile_add_related_to() != 33443) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_cfait_checksum_method_cfaitmobile_add_reminder() != 996) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_cfait_checksum_method_cfaitmobile_add_session() != 34089) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
@@ -1668,16 +1295,25 @@ private fun uniffiCheckApiChecksums(lib: IntegrityCheckingUniffiLib) {
     if (lib.uniffi_cfait_checksum_method_cfaitmobile_add_task_with_description() != 63009) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_cfait_checksum_method_cfaitmobile_change_priority() != 5969) {
+    if (lib.uniffi_cfait_checksum_method_cfaitmobile_bulk_tag() != 21594) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_cfait_checksum_method_cfaitmobile_connect() != 2036) {
+    if (lib.uniffi_cfait_checksum_method_cfaitmobile_change_priority() != 5969) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_cfait_checksum_method_cfaitmobile_create_debug_export() != 43759) {
+    if (lib.uniffi_cfait_checksum_method_cfaitmobile_compact_journal() != 1127) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_cfait_checksum_method_cfaitmobile_create_local_calendar() != 55642) {
+    if (lib.uniffi_cfait_checksum_method_cfaitmobile_complete_all() != 5081) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_cfait_checksum_method_cfaitmobile_connect() != 2036) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_cfait_checksum_method_cfaitmobile_create_debug_export() != 43759) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_cfait_checksum_method_cfaitmobile_create_local_calendar() != 55642) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
     if (lib.uniffi_cfait_checksum_method_cfaitmobile_create_missing_calendar_events() != 25486) {
@@ -1701,28 +1337,37 @@ private fun uniffiCheckApiChecksums(lib: IntegrityCheckingUniffiLib) {
     if (lib.uniffi_cfait_checksum_method_cfaitmobile_delete_task_tree() != 2357) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_cfait_checksum_method_cfaitmobile_diagnose_connection() != 55928) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_cfait_checksum_method_cfaitmobile_dismiss_alarm() != 58482) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
     if (lib.uniffi_cfait_checksum_method_cfaitmobile_dispatch() != 15403) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_cfait_checksum_method_cfaitmobile_duplicate_task_tree() != 9064) {
+    if (lib.uniffi_cfait_checksum_method_cfaitmobile_drop_journal_action() != 10496) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_cfait_checksum_method_cfaitmobile_duplicate_task_tree() != 6609) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
     if (lib.uniffi_cfait_checksum_method_cfaitmobile_edit_session() != 60746) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_cfait_checksum_method_cfaitmobile_export_all() != 46081) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_cfait_checksum_method_cfaitmobile_export_local_ics() != 7714) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
     if (lib.uniffi_cfait_checksum_method_cfaitmobile_export_locations_gpx() != 38277) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_cfait_checksum_method_cfaitmobile_get_all_locations() != 33149) {
+    if (lib.uniffi_cfait_checksum_method_cfaitmobile_get_all_locations() != 18768) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_cfait_checksum_method_cfaitmobile_get_all_tags() != 17491) {
+    if (lib.uniffi_cfait_checksum_method_cfaitmobile_get_all_tags() != 44415) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
     if (lib.uniffi_cfait_checksum_method_cfaitmobile_get_available_locales() != 45059) {
@@ -1740,6 +1385,9 @@ private fun uniffiCheckApiChecksums(lib: IntegrityCheckingUniffiLib) {
     if (lib.uniffi_cfait_checksum_method_cfaitmobile_get_help_data() != 8542) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_cfait_checksum_method_cfaitmobile_get_journal_entries() != 25937) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_cfait_checksum_method_cfaitmobile_get_next_alarm_timestamp() != 39485) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
@@ -1752,6 +1400,9 @@ private fun uniffiCheckApiChecksums(lib: IntegrityCheckingUniffiLib) {
     if (lib.uniffi_cfait_checksum_method_cfaitmobile_get_random_task_uid() != 59673) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_cfait_checksum_method_cfaitmobile_get_stale_unsynced_tasks() != 55790) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_cfait_checksum_method_cfaitmobile_get_syntax_help() != 32429) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
@@ -1767,6 +1418,9 @@ private fun uniffiCheckApiChecksums(lib: IntegrityCheckingUniffiLib) {
     if (lib.uniffi_cfait_checksum_method_cfaitmobile_get_version_info() != 23909) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_cfait_checksum_method_cfaitmobile_get_view_markdown() != 39961) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_cfait_checksum_method_cfaitmobile_get_view_tasks() != 11383) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
@@ -1776,6 +1430,9 @@ private fun uniffiCheckApiChecksums(lib: IntegrityCheckingUniffiLib) {
     if (lib.uniffi_cfait_checksum_method_cfaitmobile_has_unsynced_changes() != 26396) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_cfait_checksum_method_cfaitmobile_import_ics() != 32018) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_cfait_checksum_method_cfaitmobile_import_local_ics() != 30933) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
@@ -1815,12 +1472,24 @@ private fun uniffiCheckApiChecksums(lib: IntegrityCheckingUniffiLib) {
     if (lib.uniffi_cfait_checksum_method_cfaitmobile_remove_related_to() != 592) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_cfait_checksum_method_cfaitmobile_rename_tag() != 5014) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_cfait_checksum_method_cfaitmobile_resolve_selection_aliases() != 37748) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_cfait_checksum_method_cfaitmobile_retry_unsynced() != 33527) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_cfait_checksum_method_cfaitmobile_save_config() != 10046) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_cfait_checksum_method_cfaitmobile_set_calendar_color() != 6336) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_cfait_checksum_method_cfaitmobile_set_calendar_override() != 16866) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_cfait_checksum_method_cfaitmobile_set_calendar_visibility() != 43912) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
@@ -1833,6 +1502,9 @@ private fun uniffiCheckApiChecksums(lib: IntegrityCheckingUniffiLib) {
     if (lib.uniffi_cfait_checksum_method_cfaitmobile_set_parent() != 47671) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_cfait_checksum_method_cfaitmobile_set_progress() != 9016) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_cfait_checksum_method_cfaitmobile_set_status_cancelled() != 64507) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
@@ -1842,16 +1514,22 @@ private fun uniffiCheckApiChecksums(lib: IntegrityCheckingUniffiLib) {
     if (lib.uniffi_cfait_checksum_method_cfaitmobile_should_keep_notification() != 49921) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_cfait_checksum_method_cfaitmobile_skip_occurrence() != 42979) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_cfait_checksum_method_cfaitmobile_snooze_alarm() != 32966) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_cfait_checksum_method_cfaitmobile_start_alarm_actor() != 56819) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_cfait_checksum_method_cfaitmobile_start_task() != 40116) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
     if (lib.uniffi_cfait_checksum_method_cfaitmobile_stop_task() != 37957) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_cfait_checksum_method_cfaitmobile_sync() != 34697) {
+    if (lib.uniffi_cfait_checksum_method_cfaitmobile_sync() != 63437) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
     if (lib.uniffi_cfait_checksum_method_cfaitmobile_sync_journal() != 39496) {
@@ -1872,6 +1550,9 @@ private fun uniffiCheckApiChecksums(lib: IntegrityCheckingUniffiLib) {
     if (lib.uniffi_cfait_checksum_method_cfaitmobile_toggle_task_shift() != 15294) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_cfait_checksum_method_cfaitmobile_toggle_waiting() != 51535) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_cfait_checksum_method_cfaitmobile_update_local_calendar() != 59729) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
@@ -1887,6 +1568,12 @@ private fun uniffiCheckApiChecksums(lib: IntegrityCheckingUniffiLib) {
     if (lib.uniffi_cfait_checksum_method_cfaitmobile_yank_task() != 29486) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_cfait_checksum_method_mobilealarmlistener_on_alarm() != 37457) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_cfait_checksum_method_mobilealarmlistener_on_sync_requested() != 1503) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_cfait_checksum_constructor_cfaitmobile_new() != 58247) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
@@ -1911,37 +1598,33 @@ internal const val UNIFFI_RUST_FUTURE_POLL_WAKE = 1.toByte()
 internal val uniffiContinuationHandleMap = UniffiHandleMap<CancellableContinuation<Byte>>()
 
 // FFI type for Rust future continuations
-internal object uniffiRustFutureContinuationCallbackImpl : UniffiRustFutureContinuationCallback {
-    override fun callback(
-        data: Long,
-        pollResult: Byte,
-    ) {
+internal object uniffiRustFutureContinuationCallbackImpl: UniffiRustFutureContinuationCallback {
+    override fun callback(data: Long, pollResult: Byte) {
         uniffiContinuationHandleMap.remove(data).resume(pollResult)
     }
 }
 
-internal suspend fun <T, F, E : kotlin.Exception> uniffiRustCallAsync(
+internal suspend fun<T, F, E: kotlin.Exception> uniffiRustCallAsync(
     rustFuture: Long,
     pollFunc: (Long, UniffiRustFutureContinuationCallback, Long) -> Unit,
     completeFunc: (Long, UniffiRustCallStatus) -> F,
     freeFunc: (Long) -> Unit,
     liftFunc: (F) -> T,
-    errorHandler: UniffiRustCallStatusErrorHandler<E>,
+    errorHandler: UniffiRustCallStatusErrorHandler<E>
 ): T {
     try {
         do {
-            val pollResult =
-                suspendCancellableCoroutine<Byte> { continuation ->
-                    pollFunc(
-                        rustFuture,
-                        uniffiRustFutureContinuationCallbackImpl,
-                        uniffiContinuationHandleMap.insert(continuation),
-                    )
-                }
-        } while (pollResult != UNIFFI_RUST_FUTURE_POLL_READY)
+            val pollResult = suspendCancellableCoroutine<Byte> { continuation ->
+                pollFunc(
+                    rustFuture,
+                    uniffiRustFutureContinuationCallbackImpl,
+                    uniffiContinuationHandleMap.insert(continuation)
+                )
+            }
+        } while (pollResult != UNIFFI_RUST_FUTURE_POLL_READY);
 
         return liftFunc(
-            uniffiRustCallWithError(errorHandler, { status -> completeFunc(rustFuture, status) }),
+            uniffiRustCallWithError(errorHandler, { status -> completeFunc(rustFuture, status) })
         )
     } finally {
         freeFunc(rustFuture)
@@ -1950,6 +1633,7 @@ internal suspend fun <T, F, E : kotlin.Exception> uniffiRustCallAsync(
 
 // Public interface members begin here.
 
+
 // Interface implemented by anything that can contain an object reference.
 //
 // Such types expose a `destroy()` method that must be called to cleanly
@@ -1960,15 +1644,11 @@ internal suspend fun <T, F, E : kotlin.Exception> uniffiRustCallAsync(
 // helper method to execute a block and destroy the object at the end.
 interface Disposable {
     fun destroy()
-
     companion object {
         fun destroy(vararg args: Any?) {
             for (arg in args) {
                 when (arg) {
-                    is Disposable -> {
-                        arg.destroy()
-                    }
-
+                    is Disposable -> arg.destroy()
                     is ArrayList<*> -> {
                         for (idx in arg.indices) {
                             val element = arg[idx]
@@ -1977,7 +1657,6 @@ interface Disposable {
                             }
                         }
                     }
-
                     is Map<*, *> -> {
                         for (element in arg.values) {
                             if (element is Disposable) {
@@ -1985,7 +1664,6 @@ interface Disposable {
                             }
                         }
                     }
-
                     is Iterable<*> -> {
                         for (element in arg) {
                             if (element is Disposable) {
@@ -2014,7 +1692,7 @@ inline fun <T : Disposable?, R> T.use(block: (T) -> R) =
         }
     }
 
-/**
+/** 
  * Placeholder object used to signal that we're constructing an interface with a FFI handle.
  *
  * This is the first argument for interface constructors that input a raw handle. It exists is that
@@ -2025,13 +1703,43 @@ inline fun <T : Disposable?, R> T.use(block: (T) -> R) =
  * */
 object UniffiWithHandle
 
-/**
+/** 
  * Used to instantiate an interface without an actual pointer, for fakes in tests, mostly.
  *
  * @suppress
  * */
-object NoHandle
+object NoHandle// Magic number for the Rust proxy to call using the same mechanism as every other method,
+// to free the callback once it's dropped by Rust.
+internal const val IDX_CALLBACK_FREE = 0
+// Callback return codes
+internal const val UNIFFI_CALLBACK_SUCCESS = 0
+internal const val UNIFFI_CALLBACK_ERROR = 1
+internal const val UNIFFI_CALLBACK_UNEXPECTED_ERROR = 2
+
+/**
+ * @suppress
+ */
+public abstract class FfiConverterCallbackInterface<CallbackInterface: Any>: FfiConverter<CallbackInterface, Long> {
+    internal val handleMap = UniffiHandleMap<CallbackInterface>()
+
+    internal fun drop(handle: Long) {
+        handleMap.remove(handle)
+    }
+
+    override fun lift(value: Long): CallbackInterface {
+        return handleMap.get(value)
+    }
+
+    override fun read(buf: ByteBuffer) = lift(buf.getLong())
+
+    override fun lower(value: CallbackInterface) = handleMap.insert(value)
 
+    override fun allocationSize(value: CallbackInterface) = 8UL
+
+    override fun write(value: CallbackInterface, buf: ByteBuffer) {
+        buf.putLong(lower(value))
+    }
+}
 /**
  * The cleaner interface for Object finalization code to run.
  * This is the entry point to any implementation that we're using.
@@ -2047,24 +1755,17 @@ interface UniffiCleaner {
         fun clean()
     }
 
-    fun register(
-        value: Any,
-        cleanUpTask: Runnable,
-    ): UniffiCleaner.Cleanable
+    fun register(value: Any, cleanUpTask: Runnable): UniffiCleaner.Cleanable
 
     companion object
 }
 
 // The fallback Jna cleaner, which is available for both Android, and the JVM.
 private class UniffiJnaCleaner : UniffiCleaner {
-    private val cleaner =
-        com.sun.jna.internal.Cleaner
-            .getCleaner()
+    private val cleaner = com.sun.jna.internal.Cleaner.getCleaner()
 
-    override fun register(
-        value: Any,
-        cleanUpTask: Runnable,
-    ): UniffiCleaner.Cleanable = UniffiJnaCleanable(cleaner.register(value, cleanUpTask))
+    override fun register(value: Any, cleanUpTask: Runnable): UniffiCleaner.Cleanable =
+        UniffiJnaCleanable(cleaner.register(value, cleanUpTask))
 }
 
 private class UniffiJnaCleanable(
@@ -2073,6 +1774,7 @@ private class UniffiJnaCleanable(
     override fun clean() = cleanable.clean()
 }
 
+
 // We decide at uniffi binding generation time whether we were
 // using Android or not.
 // There are further runtime checks to chose the correct implementation
@@ -2091,18 +1793,14 @@ private fun UniffiCleaner.Companion.create(): UniffiCleaner =
     }
 
 private class JavaLangRefCleaner : UniffiCleaner {
-    val cleaner =
-        java.lang.ref.Cleaner
-            .create()
+    val cleaner = java.lang.ref.Cleaner.create()
 
-    override fun register(
-        value: Any,
-        cleanUpTask: Runnable,
-    ): UniffiCleaner.Cleanable = JavaLangRefCleanable(cleaner.register(value, cleanUpTask))
+    override fun register(value: Any, cleanUpTask: Runnable): UniffiCleaner.Cleanable =
+        JavaLangRefCleanable(cleaner.register(value, cleanUpTask))
 }
 
 private class JavaLangRefCleanable(
-    val cleanable: java.lang.ref.Cleaner.Cleanable,
+    val cleanable: java.lang.ref.Cleaner.Cleanable
 ) : UniffiCleaner.Cleanable {
     override fun clean() = cleanable.clean()
 }
@@ -2110,21 +1808,26 @@ private class JavaLangRefCleanable(
 /**
  * @suppress
  */
-public object FfiConverterUByte : FfiConverter<UByte, Byte> {
-    override fun lift(value: Byte): UByte = value.toUByte()
+public object FfiConverterUByte: FfiConverter<UByte, Byte> {
+    override fun lift(value: Byte): UByte {
+        return value.toUByte()
+    }
 
-    fun lift(value: Int): UByte = value.toUByte()
+    fun lift(value: Int): UByte {
+        return value.toUByte()
+    }
 
-    override fun read(buf: ByteBuffer): UByte = lift(buf.get())
+    override fun read(buf: ByteBuffer): UByte {
+        return lift(buf.get())
+    }
 
-    override fun lower(value: UByte): Byte = value.toByte()
+    override fun lower(value: UByte): Byte {
+        return value.toByte()
+    }
 
     override fun allocationSize(value: UByte) = 1UL
 
-    override fun write(
-        value: UByte,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: UByte, buf: ByteBuffer) {
         buf.put(value.toByte())
     }
 }
@@ -2132,19 +1835,22 @@ public object FfiConverterUByte : FfiConverter<UByte, Byte> {
 /**
  * @suppress
  */
-public object FfiConverterByte : FfiConverter<Byte, Byte> {
-    override fun lift(value: Byte): Byte = value
+public object FfiConverterByte: FfiConverter<Byte, Byte> {
+    override fun lift(value: Byte): Byte {
+        return value
+    }
 
-    override fun read(buf: ByteBuffer): Byte = buf.get()
+    override fun read(buf: ByteBuffer): Byte {
+        return buf.get()
+    }
 
-    override fun lower(value: Byte): Byte = value
+    override fun lower(value: Byte): Byte {
+        return value
+    }
 
     override fun allocationSize(value: Byte) = 1UL
 
-    override fun write(
-        value: Byte,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: Byte, buf: ByteBuffer) {
         buf.put(value)
     }
 }
@@ -2152,19 +1858,49 @@ public object FfiConverterByte : FfiConverter<Byte, Byte> {
 /**
  * @suppress
  */
-public object FfiConverterUInt : FfiConverter<UInt, Int> {
-    override fun lift(value: Int): UInt = value.toUInt()
+public object FfiConverterUShort: FfiConverter<UShort, Short> {
+    override fun lift(value: Short): UShort {
+        return value.toUShort()
+    }
+
+    fun lift(value: Int): UShort {
+        return value.toUShort()
+    }
+
+    override fun read(buf: ByteBuffer): UShort {
+        return lift(buf.getShort())
+    }
+
+    override fun lower(value: UShort): Short {
+        return value.toShort()
+    }
+
+    override fun allocationSize(value: UShort) = 2UL
+
+    override fun write(value: UShort, buf: ByteBuffer) {
+        buf.putShort(value.toShort())
+    }
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterUInt: FfiConverter<UInt, Int> {
+    override fun lift(value: Int): UInt {
+        return value.toUInt()
+    }
 
-    override fun read(buf: ByteBuffer): UInt = lift(buf.getInt())
+    override fun read(buf: ByteBuffer): UInt {
+        return lift(buf.getInt())
+    }
 
-    override fun lower(value: UInt): Int = value.toInt()
+    override fun lower(value: UInt): Int {
+        return value.toInt()
+    }
 
     override fun allocationSize(value: UInt) = 4UL
 
-    override fun write(
-        value: UInt,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: UInt, buf: ByteBuffer) {
         buf.putInt(value.toInt())
     }
 }
@@ -2172,19 +1908,22 @@ public object FfiConverterUInt : FfiConverter<UInt, Int> {
 /**
  * @suppress
  */
-public object FfiConverterInt : FfiConverter<Int, Int> {
-    override fun lift(value: Int): Int = value
+public object FfiConverterInt: FfiConverter<Int, Int> {
+    override fun lift(value: Int): Int {
+        return value
+    }
 
-    override fun read(buf: ByteBuffer): Int = buf.getInt()
+    override fun read(buf: ByteBuffer): Int {
+        return buf.getInt()
+    }
 
-    override fun lower(value: Int): Int = value
+    override fun lower(value: Int): Int {
+        return value
+    }
 
     override fun allocationSize(value: Int) = 4UL
 
-    override fun write(
-        value: Int,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: Int, buf: ByteBuffer) {
         buf.putInt(value)
     }
 }
@@ -2192,19 +1931,22 @@ public object FfiConverterInt : FfiConverter<Int, Int> {
 /**
  * @suppress
  */
-public object FfiConverterULong : FfiConverter<ULong, Long> {
-    override fun lift(value: Long): ULong = value.toULong()
+public object FfiConverterULong: FfiConverter<ULong, Long> {
+    override fun lift(value: Long): ULong {
+        return value.toULong()
+    }
 
-    override fun read(buf: ByteBuffer): ULong = lift(buf.getLong())
+    override fun read(buf: ByteBuffer): ULong {
+        return lift(buf.getLong())
+    }
 
-    override fun lower(value: ULong): Long = value.toLong()
+    override fun lower(value: ULong): Long {
+        return value.toLong()
+    }
 
     override fun allocationSize(value: ULong) = 8UL
 
-    override fun write(
-        value: ULong,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: ULong, buf: ByteBuffer) {
         buf.putLong(value.toLong())
     }
 }
@@ -2212,19 +1954,22 @@ public object FfiConverterULong : FfiConverter<ULong, Long> {
 /**
  * @suppress
  */
-public object FfiConverterLong : FfiConverter<Long, Long> {
-    override fun lift(value: Long): Long = value
+public object FfiConverterLong: FfiConverter<Long, Long> {
+    override fun lift(value: Long): Long {
+        return value
+    }
 
-    override fun read(buf: ByteBuffer): Long = buf.getLong()
+    override fun read(buf: ByteBuffer): Long {
+        return buf.getLong()
+    }
 
-    override fun lower(value: Long): Long = value
+    override fun lower(value: Long): Long {
+        return value
+    }
 
     override fun allocationSize(value: Long) = 8UL
 
-    override fun write(
-        value: Long,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: Long, buf: ByteBuffer) {
         buf.putLong(value)
     }
 }
@@ -2232,19 +1977,22 @@ public object FfiConverterLong : FfiConverter<Long, Long> {
 /**
  * @suppress
  */
-public object FfiConverterFloat : FfiConverter<Float, Float> {
-    override fun lift(value: Float): Float = value
+public object FfiConverterFloat: FfiConverter<Float, Float> {
+    override fun lift(value: Float): Float {
+        return value
+    }
 
-    override fun read(buf: ByteBuffer): Float = buf.getFloat()
+    override fun read(buf: ByteBuffer): Float {
+        return buf.getFloat()
+    }
 
-    override fun lower(value: Float): Float = value
+    override fun lower(value: Float): Float {
+        return value
+    }
 
     override fun allocationSize(value: Float) = 4UL
 
-    override fun write(
-        value: Float,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: Float, buf: ByteBuffer) {
         buf.putFloat(value)
     }
 }
@@ -2252,19 +2000,22 @@ public object FfiConverterFloat : FfiConverter<Float, Float> {
 /**
  * @suppress
  */
-public object FfiConverterBoolean : FfiConverter<Boolean, Byte> {
-    override fun lift(value: Byte): Boolean = value.toInt() != 0
+public object FfiConverterBoolean: FfiConverter<Boolean, Byte> {
+    override fun lift(value: Byte): Boolean {
+        return value.toInt() != 0
+    }
 
-    override fun read(buf: ByteBuffer): Boolean = lift(buf.get())
+    override fun read(buf: ByteBuffer): Boolean {
+        return lift(buf.get())
+    }
 
-    override fun lower(value: Boolean): Byte = if (value) 1.toByte() else 0.toByte()
+    override fun lower(value: Boolean): Byte {
+        return if (value) 1.toByte() else 0.toByte()
+    }
 
     override fun allocationSize(value: Boolean) = 1UL
 
-    override fun write(
-        value: Boolean,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: Boolean, buf: ByteBuffer) {
         buf.put(lower(value))
     }
 }
@@ -2272,7 +2023,7 @@ public object FfiConverterBoolean : FfiConverter<Boolean, Byte> {
 /**
  * @suppress
  */
-public object FfiConverterString : FfiConverter<String, RustBuffer.ByValue> {
+public object FfiConverterString: FfiConverter<String, RustBuffer.ByValue> {
     // Note: we don't inherit from FfiConverterRustBuffer, because we use a
     // special encoding when lowering/lifting.  We can use `RustBuffer.len` to
     // store our length and avoid writing it out to the buffer.
@@ -2319,16 +2070,14 @@ public object FfiConverterString : FfiConverter<String, RustBuffer.ByValue> {
         return sizeForLength + sizeForString
     }
 
-    override fun write(
-        value: String,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: String, buf: ByteBuffer) {
         val byteBuf = toUtf8(value)
         buf.putInt(byteBuf.limit())
         buf.put(byteBuf)
     }
 }
 
+
 // This template implements a class for working with a Rust struct via a handle
 // to the live Rust struct on the other side of the FFI.
 //
@@ -2423,278 +2172,282 @@ public object FfiConverterString : FfiConverter<String, RustBuffer.ByValue> {
 // [1] https://stackoverflow.com/questions/24376768/can-java-finalize-an-object-when-it-is-still-in-scope/24380219
 //
 
-public interface CfaitMobileInterface {
-    suspend fun `addAlias`(
-        `key`: kotlin.String,
-        `tags`: List<kotlin.String>,
-    )
-
-    suspend fun `addDependency`(
-        `taskUid`: kotlin.String,
-        `blockerUid`: kotlin.String,
-    )
-
-    suspend fun `addRelatedTo`(
-        `taskUid`: kotlin.String,
-        `relatedUid`: kotlin.String,
-    )
-
-    suspend fun `addSession`(
-        `uid`: kotlin.String,
-        `input`: kotlin.String,
-    )
 
+public interface CfaitMobileInterface {
+    
+    suspend fun `addAlias`(`key`: kotlin.String, `tags`: List<kotlin.String>)
+    
+    suspend fun `addDependency`(`taskUid`: kotlin.String, `blockerUid`: kotlin.String)
+    
+    suspend fun `addRelatedTo`(`taskUid`: kotlin.String, `relatedUid`: kotlin.String)
+    
+    /**
+     * Adds a reminder from a `rem:` value (e.g. `10m`, `9am`, `tomorrow 8:00`).
+     */
+    suspend fun `addReminder`(`uid`: kotlin.String, `spec`: kotlin.String)
+    
+    suspend fun `addSession`(`uid`: kotlin.String, `input`: kotlin.String)
+    
     suspend fun `addTaskSmart`(`input`: kotlin.String): kotlin.String
-
-    suspend fun `addTaskWithDescription`(
-        `input`: kotlin.String,
-        `description`: kotlin.String,
-    ): kotlin.String
-
-    suspend fun `changePriority`(
-        `uid`: kotlin.String,
-        `delta`: kotlin.Byte,
-    )
-
-    suspend fun `connect`(
-        `url`: kotlin.String,
-        `user`: kotlin.String,
-        `pass`: kotlin.String,
-        `insecure`: kotlin.Boolean,
-    ): kotlin.String
-
+    
+    suspend fun `addTaskWithDescription`(`input`: kotlin.String, `description`: kotlin.String): kotlin.String
+    
+    /**
+     * Adds (`add`) or removes `tag` on every task in the view described by `filter_tag`
+     * and `search`. Returns how many tasks changed.
+     */
+    suspend fun `bulkTag`(`add`: kotlin.Boolean, `tag`: kotlin.String, `filterTag`: kotlin.String?, `search`: kotlin.String): kotlin.UInt
+    
+    suspend fun `changePriority`(`uid`: kotlin.String, `delta`: kotlin.Byte)
+    
+    /**
+     * Merges redundant queued actions without syncing; returns how many were merged away.
+     */
+    fun `compactJournal`(): kotlin.UInt
+    
+    /**
+     * Completes every open, unblocked task in the view described by `filter_tag` and
+     * `search`. Returns how many tasks were marked done.
+     */
+    suspend fun `completeAll`(`filterTag`: kotlin.String?, `search`: kotlin.String): kotlin.UInt
+    
+    suspend fun `connect`(`url`: kotlin.String, `user`: kotlin.String, `pass`: kotlin.String, `insecure`: kotlin.Boolean): kotlin.String
+    
     fun `createDebugExport`(): kotlin.String
-
-    suspend fun `createLocalCalendar`(
-        `name`: kotlin.String,
-        `color`: kotlin.String?,
-    ): kotlin.String
-
+    
+    suspend fun `createLocalCalendar`(`name`: kotlin.String, `color`: kotlin.String?): kotlin.String
+    
     suspend fun `createMissingCalendarEvents`(): kotlin.UInt
-
-    suspend fun `createRemoteCalendar`(
-        `name`: kotlin.String,
-        `color`: kotlin.String?,
-    ): kotlin.String
-
+    
+    suspend fun `createRemoteCalendar`(`name`: kotlin.String, `color`: kotlin.String?): kotlin.String
+    
     suspend fun `deleteAllCalendarEvents`(): kotlin.UInt
-
+    
     suspend fun `deleteLocalCalendar`(`href`: kotlin.String)
-
-    suspend fun `deleteSession`(
-        `uid`: kotlin.String,
-        `index`: kotlin.UInt,
-    )
-
+    
+    suspend fun `deleteSession`(`uid`: kotlin.String, `index`: kotlin.UInt)
+    
     suspend fun `deleteTask`(`uid`: kotlin.String)
-
+    
     suspend fun `deleteTaskTree`(`uid`: kotlin.String)
-
-    suspend fun `dismissAlarm`(
-        `taskUid`: kotlin.String,
-        `alarmUid`: kotlin.String,
-    )
-
+    
+    /**
+     * Runs the CalDAV discovery chain step by step against the given credentials without
+     * saving them, so the settings screen can show which step fails.
+     */
+    suspend fun `diagnoseConnection`(`url`: kotlin.String, `user`: kotlin.String, `pass`: kotlin.String, `insecure`: kotlin.Boolean): List<MobileDiagnosticStep>
+    
+    suspend fun `dismissAlarm`(`taskUid`: kotlin.String, `alarmUid`: kotlin.String)
+    
     suspend fun `dispatch`(`intent`: AppIntent)
-
-    suspend fun `duplicateTaskTree`(`uid`: kotlin.String)
-
-    suspend fun `editSession`(
-        `uid`: kotlin.String,
-        `index`: kotlin.UInt,
-        `input`: kotlin.String,
-    )
-
+    
+    /**
+     * Drops a stuck journal action. Returns false if the queue changed since it was listed.
+     */
+    fun `dropJournalAction`(`index`: kotlin.UInt, `uid`: kotlin.String): kotlin.Boolean
+    
+    /**
+     * Copies a task (and its subtasks) and returns the copy's UID so it can be opened for editing.
+     */
+    suspend fun `duplicateTaskTree`(`uid`: kotlin.String): kotlin.String?
+    
+    suspend fun `editSession`(`uid`: kotlin.String, `index`: kotlin.UInt, `input`: kotlin.String)
+    
+    /**
+     * Serializes every task across all calendars for backup.
+     * `as_json` selects a JSON array; otherwise a single re-importable `.ics` is returned.
+     */
+    fun `exportAll`(`asJson`: kotlin.Boolean): kotlin.String
+    
     fun `exportLocalIcs`(`calendarHref`: kotlin.String): kotlin.String
-
+    
     fun `exportLocationsGpx`(`uid`: kotlin.String): kotlin.String
-
+    
+    /**
+     * Every location with its task count, as in the sidebar with nothing selected.
+     * Pass a location's `name` in `MobileFilterOptions::filter_locations` to filter by it.
+     */
     suspend fun `getAllLocations`(): List<MobileLocation>
-
+    
+    /**
+     * Every tag with its task count, as in the sidebar with nothing selected.
+     */
     suspend fun `getAllTags`(): List<MobileTag>
-
+    
     fun `getAvailableLocales`(): List<kotlin.String>
-
+    
     fun `getCalendars`(): List<MobileCalendar>
-
+    
     fun `getConfig`(): MobileConfig
-
+    
     fun `getFiringAlarms`(): List<MobileAlarmInfo>
-
+    
     fun `getHelpData`(): List<MobileHelpCategoryData>
-
+    
+    fun `getJournalEntries`(): List<MobileJournalEntry>
+    
     fun `getNextAlarmTimestamp`(): kotlin.Long?
-
+    
     suspend fun `getNextGlobalAlarmTime`(): kotlin.Long?
-
+    
     fun `getOngoingTasks`(): List<MobileTask>
-
-    suspend fun `getRandomTaskUid`(
-        `filterTags`: List<kotlin.String>,
-        `filterLocations`: List<kotlin.String>,
-        `searchQuery`: kotlin.String,
-    ): kotlin.String?
-
+    
+    suspend fun `getRandomTaskUid`(`filterTags`: List<kotlin.String>, `filterLocations`: List<kotlin.String>, `searchQuery`: kotlin.String): kotlin.String?
+    
+    /**
+     * Remote tasks still unsynced past `unsynced_warning_hours` ("failed to sync"), oldest first.
+     * Discard them with `delete_task`.
+     */
+    suspend fun `getStaleUnsyncedTasks`(): List<MobileTask>
+    
     fun `getSyntaxHelp`(): List<MobileHelpSection>
-
+    
     suspend fun `getTaskByUid`(`uid`: kotlin.String): MobileTask?
-
+    
     fun `getTaskTreeMarkdown`(`uid`: kotlin.String): kotlin.String
-
+    
     suspend fun `getTasksRelatedTo`(`uid`: kotlin.String): List<MobileRelatedTask>
-
+    
     fun `getVersionInfo`(): MobileVersionInfo
-
+    
+    /**
+     * Renders the currently filtered view as a Markdown checklist (for sharing/printing).
+     */
+    suspend fun `getViewMarkdown`(`options`: MobileFilterOptions): kotlin.String
+    
     suspend fun `getViewTasks`(`options`: MobileFilterOptions): MobileViewData
-
+    
     fun `hasAnyTasks`(): kotlin.Boolean
-
+    
     fun `hasUnsyncedChanges`(): kotlin.Boolean
-
-    fun `importLocalIcs`(
-        `calendarHref`: kotlin.String,
-        `icsContent`: kotlin.String,
-    ): kotlin.String
-
+    
+    /**
+     * Bulk-restores an `.ics` file or JSON task array into `target_href`, journaling the
+     * creates so they sync. `conflict_copies` keeps UID duplicates as copies instead of
+     * skipping them. Returns a localized summary including any per-item errors.
+     */
+    suspend fun `importIcs`(`data`: kotlin.String, `targetHref`: kotlin.String, `conflictCopies`: kotlin.Boolean): kotlin.String
+    
+    fun `importLocalIcs`(`calendarHref`: kotlin.String, `icsContent`: kotlin.String): kotlin.String
+    
     fun `isolateCalendar`(`href`: kotlin.String)
-
+    
     fun `loadFromCache`()
-
-    fun `logMessage`(
-        `level`: kotlin.String,
-        `tag`: kotlin.String,
-        `message`: kotlin.String,
-    )
-
-    suspend fun `migrateLocalTo`(
-        `sourceHref`: kotlin.String,
-        `targetHref`: kotlin.String,
-    ): kotlin.String
-
-    fun `moveCalendar`(
-        `href`: kotlin.String,
-        `direction`: kotlin.Byte,
-    )
-
-    suspend fun `moveTask`(
-        `uid`: kotlin.String,
-        `newCalHref`: kotlin.String,
-    )
-
+    
+    fun `logMessage`(`level`: kotlin.String, `tag`: kotlin.String, `message`: kotlin.String)
+    
+    suspend fun `migrateLocalTo`(`sourceHref`: kotlin.String, `targetHref`: kotlin.String): kotlin.String
+    
+    fun `moveCalendar`(`href`: kotlin.String, `direction`: kotlin.Byte)
+    
+    suspend fun `moveTask`(`uid`: kotlin.String, `newCalHref`: kotlin.String)
+    
     fun `parseDurationString`(`val`: kotlin.String): kotlin.UInt?
-
-    fun `parseSmartString`(
-        `input`: kotlin.String,
-        `isSearch`: kotlin.Boolean,
-    ): List<MobileSyntaxToken>
-
+    
+    fun `parseSmartString`(`input`: kotlin.String, `isSearch`: kotlin.Boolean): List<MobileSyntaxToken>
+    
     suspend fun `pauseTask`(`uid`: kotlin.String)
-
+    
     fun `removeAlias`(`key`: kotlin.String)
-
-    suspend fun `removeDependency`(
-        `taskUid`: kotlin.String,
-        `blockerUid`: kotlin.String,
-    )
-
-    suspend fun `removeRelatedTo`(
-        `taskUid`: kotlin.String,
-        `relatedUid`: kotlin.String,
-    )
-
-    fun `resolveSelectionAliases`(
-        `selection`: kotlin.String,
-        `isLocation`: kotlin.Boolean,
-    ): List<kotlin.String>
-
+    
+    suspend fun `removeDependency`(`taskUid`: kotlin.String, `blockerUid`: kotlin.String)
+    
+    suspend fun `removeRelatedTo`(`taskUid`: kotlin.String, `relatedUid`: kotlin.String)
+    
+    /**
+     * Renames tag `from` (and its subtags) to `to` on every task and in the aliases.
+     */
+    suspend fun `renameTag`(`from`: kotlin.String, `to`: kotlin.String): MobileTagRename
+    
+    fun `resolveSelectionAliases`(`selection`: kotlin.String, `isLocation`: kotlin.Boolean): List<kotlin.String>
+    
+    /**
+     * Re-queues the creation of a stale unsynced task; call `sync` afterwards.
+     */
+    suspend fun `retryUnsynced`(`uid`: kotlin.String)
+    
     fun `saveConfig`(`config`: MobileConfig)
-
-    fun `setCalendarVisibility`(
-        `href`: kotlin.String,
-        `visible`: kotlin.Boolean,
-    )
-
+    
+    /**
+     * Changes only a remote calendar's color. Returns `false` when the server does not
+     * store `calendar-color` (or the app is offline); the color is then kept in the local
+     * `calendar_overrides` config instead.
+     */
+    suspend fun `setCalendarColor`(`href`: kotlin.String, `color`: kotlin.String?): kotlin.Boolean
+    
+    /**
+     * Sets the device-local display name and color for a calendar; `None` (or blank) falls
+     * back to the server value. `get_calendars` reports the overridden values.
+     */
+    fun `setCalendarOverride`(`href`: kotlin.String, `name`: kotlin.String?, `color`: kotlin.String?)
+    
+    fun `setCalendarVisibility`(`href`: kotlin.String, `visible`: kotlin.Boolean)
+    
     fun `setDefaultCalendar`(`href`: kotlin.String)
-
+    
     fun `setLocale`(`locale`: kotlin.String)
-
-    suspend fun `setParent`(
-        `childUid`: kotlin.String,
-        `parentUid`: kotlin.String?,
-    )
-
+    
+    suspend fun `setParent`(`childUid`: kotlin.String, `parentUid`: kotlin.String?)
+    
+    suspend fun `setProgress`(`uid`: kotlin.String, `percent`: kotlin.UByte)
+    
     suspend fun `setStatusCancelled`(`uid`: kotlin.String)
-
+    
     suspend fun `setStatusProcess`(`uid`: kotlin.String)
-
-    suspend fun `shouldKeepNotification`(
-        `taskUid`: kotlin.String,
-        `notifType`: kotlin.String,
-        `alarmUid`: kotlin.String?,
-    ): kotlin.Boolean
-
-    suspend fun `snoozeAlarm`(
-        `taskUid`: kotlin.String,
-        `alarmUid`: kotlin.String,
-        `minutes`: kotlin.UInt,
-    )
-
+    
+    suspend fun `shouldKeepNotification`(`taskUid`: kotlin.String, `notifType`: kotlin.String, `alarmUid`: kotlin.String?): kotlin.Boolean
+    
+    suspend fun `skipOccurrence`(`uid`: kotlin.String)
+    
+    suspend fun `snoozeAlarm`(`taskUid`: kotlin.String, `alarmUid`: kotlin.String, `minutes`: kotlin.UInt)
+    
+    /**
+     * Starts the in-process alarm actor (the same one the desktop clients run) and
+     * delivers due alarms to `listener`. The task set is refreshed after every sync
+     * and mutation. Calling it again replaces the previous actor and listener.
+     */
+    fun `startAlarmActor`(`listener`: MobileAlarmListener)
+    
     suspend fun `startTask`(`uid`: kotlin.String)
-
+    
     suspend fun `stopTask`(`uid`: kotlin.String)
-
-    suspend fun `sync`(): kotlin.String
-
+    
+    suspend fun `sync`(): MobileSyncResult
+    
     suspend fun `syncJournal`(): kotlin.Boolean
-
-    suspend fun `syncTaskTreeFromMarkdown`(
-        `uid`: kotlin.String,
-        `markdown`: kotlin.String,
-    )
-
+    
+    suspend fun `syncTaskTreeFromMarkdown`(`uid`: kotlin.String, `markdown`: kotlin.String)
+    
     fun `toggleAllCalendars`(`showAll`: kotlin.Boolean)
-
+    
     suspend fun `togglePin`(`uid`: kotlin.String)
-
+    
     suspend fun `toggleTask`(`uid`: kotlin.String)
-
+    
     suspend fun `toggleTaskShift`(`uid`: kotlin.String)
-
-    suspend fun `updateLocalCalendar`(
-        `href`: kotlin.String,
-        `name`: kotlin.String,
-        `color`: kotlin.String?,
-    )
-
-    suspend fun `updateRemoteCalendar`(
-        `href`: kotlin.String,
-        `name`: kotlin.String,
-        `color`: kotlin.String?,
-    )
-
-    suspend fun `updateTaskDescription`(
-        `uid`: kotlin.String,
-        `description`: kotlin.String,
-    )
-
-    suspend fun `updateTaskSmart`(
-        `uid`: kotlin.String,
-        `smartInput`: kotlin.String,
-    )
-
+    
+    suspend fun `toggleWaiting`(`uid`: kotlin.String)
+    
+    suspend fun `updateLocalCalendar`(`href`: kotlin.String, `name`: kotlin.String, `color`: kotlin.String?)
+    
+    suspend fun `updateRemoteCalendar`(`href`: kotlin.String, `name`: kotlin.String, `color`: kotlin.String?)
+    
+    suspend fun `updateTaskDescription`(`uid`: kotlin.String, `description`: kotlin.String)
+    
+    suspend fun `updateTaskSmart`(`uid`: kotlin.String, `smartInput`: kotlin.String)
+    
     suspend fun `yankTask`(`uid`: kotlin.String)
-
+    
     companion object
 }
 
-open class CfaitMobile :
-    Disposable,
-    AutoCloseable,
-    CfaitMobileInterface {
+open class CfaitMobile: Disposable, AutoCloseable, CfaitMobileInterface
+{
+
+    @Suppress("UNUSED_PARAMETER")
     /**
      * @suppress
      */
-    @Suppress("UNUSED_PARAMETER")
     constructor(withHandle: UniffiWithHandle, handle: Long) {
         this.handle = handle
         this.cleanable = UniffiLib.CLEANER.register(this, UniffiCleanAction(handle))
@@ -2713,12 +2466,14 @@ open class CfaitMobile :
         this.cleanable = null
     }
     constructor(`androidFilesDir`: kotlin.String) :
-        this(
-            UniffiWithHandle,
-            uniffiRustCall { _status ->
-                UniffiLib.uniffi_cfait_fn_constructor_cfaitmobile_new(FfiConverterString.lower(`androidFilesDir`), _status)
-            },
-        )
+        this(UniffiWithHandle, 
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_cfait_fn_constructor_cfaitmobile_new(
+    
+        
+        FfiConverterString.lower(`androidFilesDir`),_status)
+}
+    )
 
     protected val handle: Long
     protected val cleanable: UniffiCleaner.Cleanable?
@@ -2758,7 +2513,7 @@ open class CfaitMobile :
             if (c == Long.MAX_VALUE) {
                 throw IllegalStateException("${this.javaClass.simpleName} call counter would overflow")
             }
-        } while (!this.callCounter.compareAndSet(c, c + 1L))
+        } while (! this.callCounter.compareAndSet(c, c + 1L))
         // Now we can safely do the method call without the handle being freed concurrently.
         try {
             return block(this.uniffiCloneHandle())
@@ -2772,13 +2527,11 @@ open class CfaitMobile :
 
     // Use a static inner class instead of a closure so as not to accidentally
     // capture `this` as part of the cleanable's action.
-    private class UniffiCleanAction(
-        private val handle: Long,
-    ) : Runnable {
+    private class UniffiCleanAction(private val handle: Long) : Runnable {
         override fun run() {
             if (handle == 0.toLong()) {
                 // Fake object created with `NoHandle`, don't try to free.
-                return
+                return;
             }
             uniffiRustCall { status ->
                 UniffiLib.uniffi_cfait_fn_free_cfaitmobile(handle, status)
@@ -2791,24 +2544,24 @@ open class CfaitMobile :
      */
     fun uniffiCloneHandle(): Long {
         if (handle == 0.toLong()) {
-            throw InternalException("uniffiCloneHandle() called on NoHandle object")
+            throw InternalException("uniffiCloneHandle() called on NoHandle object");
         }
-        return uniffiRustCall { status ->
+        return uniffiRustCall() { status ->
             UniffiLib.uniffi_cfait_fn_clone_cfaitmobile(handle, status)
         }
     }
 
+    
     @Throws(MobileException::class)
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `addAlias`(
-        `key`: kotlin.String,
-        `tags`: List<kotlin.String>,
-    ) = uniffiRustCallAsync(
+    override suspend fun `addAlias`(`key`: kotlin.String, `tags`: List<kotlin.String>) {
+        return uniffiRustCallAsync(
         callWithHandle { uniffiHandle ->
             UniffiLib.uniffi_cfait_fn_method_cfaitmobile_add_alias(
                 uniffiHandle,
-                FfiConverterString.lower(`key`),
-                FfiConverterSequenceString.lower(`tags`),
+                
+        FfiConverterString.lower(`key`),
+        FfiConverterSequenceString.lower(`tags`),
             )
         },
         { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
@@ -2816,21 +2569,23 @@ open class CfaitMobile :
         { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
         // lift function
         { Unit },
+        
         // Error FFI converter
         MobileException.ErrorHandler,
     )
+    }
 
+    
     @Throws(MobileException::class)
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `addDependency`(
-        `taskUid`: kotlin.String,
-        `blockerUid`: kotlin.String,
-    ) = uniffiRustCallAsync(
+    override suspend fun `addDependency`(`taskUid`: kotlin.String, `blockerUid`: kotlin.String) {
+        return uniffiRustCallAsync(
         callWithHandle { uniffiHandle ->
             UniffiLib.uniffi_cfait_fn_method_cfaitmobile_add_dependency(
                 uniffiHandle,
-                FfiConverterString.lower(`taskUid`),
-                FfiConverterString.lower(`blockerUid`),
+                
+        FfiConverterString.lower(`taskUid`),
+        FfiConverterString.lower(`blockerUid`),
             )
         },
         { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
@@ -2838,21 +2593,23 @@ open class CfaitMobile :
         { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
         // lift function
         { Unit },
+        
         // Error FFI converter
         MobileException.ErrorHandler,
     )
+    }
 
+    
     @Throws(MobileException::class)
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `addRelatedTo`(
-        `taskUid`: kotlin.String,
-        `relatedUid`: kotlin.String,
-    ) = uniffiRustCallAsync(
+    override suspend fun `addRelatedTo`(`taskUid`: kotlin.String, `relatedUid`: kotlin.String) {
+        return uniffiRustCallAsync(
         callWithHandle { uniffiHandle ->
             UniffiLib.uniffi_cfait_fn_method_cfaitmobile_add_related_to(
                 uniffiHandle,
-                FfiConverterString.lower(`taskUid`),
-                FfiConverterString.lower(`relatedUid`),
+                
+        FfiConverterString.lower(`taskUid`),
+        FfiConverterString.lower(`relatedUid`),
             )
         },
         { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
@@ -2860,21 +2617,26 @@ open class CfaitMobile :
         { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
         // lift function
         { Unit },
+        
         // Error FFI converter
         MobileException.ErrorHandler,
     )
+    }
 
+    
+    /**
+     * Adds a reminder from a `rem:` value (e.g. `10m`, `9am`, `tomorrow 8:00`).
+     */
     @Throws(MobileException::class)
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `addSession`(
-        `uid`: kotlin.String,
-        `input`: kotlin.String,
-    ) = uniffiRustCallAsync(
+    override suspend fun `addReminder`(`uid`: kotlin.String, `spec`: kotlin.String) {
+        return uniffiRustCallAsync(
         callWithHandle { uniffiHandle ->
-            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_add_session(
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_add_reminder(
                 uniffiHandle,
-                FfiConverterString.lower(`uid`),
-                FfiConverterString.lower(`input`),
+                
+        FfiConverterString.lower(`uid`),
+        FfiConverterString.lower(`spec`),
             )
         },
         { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
@@ -2882,63 +2644,23 @@ open class CfaitMobile :
         { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
         // lift function
         { Unit },
+        
         // Error FFI converter
         MobileException.ErrorHandler,
     )
+    }
 
+    
     @Throws(MobileException::class)
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `addTaskSmart`(`input`: kotlin.String): kotlin.String =
-        uniffiRustCallAsync(
-            callWithHandle { uniffiHandle ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_add_task_smart(
-                    uniffiHandle,
-                    FfiConverterString.lower(`input`),
-                )
-            },
-            { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_rust_buffer(future, callback, continuation) },
-            { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_rust_buffer(future, continuation) },
-            { future -> UniffiLib.ffi_cfait_rust_future_free_rust_buffer(future) },
-            // lift function
-            { FfiConverterString.lift(it) },
-            // Error FFI converter
-            MobileException.ErrorHandler,
-        )
-
-    @Throws(MobileException::class)
-    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `addTaskWithDescription`(
-        `input`: kotlin.String,
-        `description`: kotlin.String,
-    ): kotlin.String =
-        uniffiRustCallAsync(
-            callWithHandle { uniffiHandle ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_add_task_with_description(
-                    uniffiHandle,
-                    FfiConverterString.lower(`input`),
-                    FfiConverterString.lower(`description`),
-                )
-            },
-            { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_rust_buffer(future, callback, continuation) },
-            { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_rust_buffer(future, continuation) },
-            { future -> UniffiLib.ffi_cfait_rust_future_free_rust_buffer(future) },
-            // lift function
-            { FfiConverterString.lift(it) },
-            // Error FFI converter
-            MobileException.ErrorHandler,
-        )
-
-    @Throws(MobileException::class)
-    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `changePriority`(
-        `uid`: kotlin.String,
-        `delta`: kotlin.Byte,
-    ) = uniffiRustCallAsync(
+    override suspend fun `addSession`(`uid`: kotlin.String, `input`: kotlin.String) {
+        return uniffiRustCallAsync(
         callWithHandle { uniffiHandle ->
-            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_change_priority(
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_add_session(
                 uniffiHandle,
-                FfiConverterString.lower(`uid`),
-                FfiConverterByte.lower(`delta`),
+                
+        FfiConverterString.lower(`uid`),
+        FfiConverterString.lower(`input`),
             )
         },
         { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
@@ -2946,162 +2668,97 @@ open class CfaitMobile :
         { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
         // lift function
         { Unit },
+        
         // Error FFI converter
         MobileException.ErrorHandler,
     )
+    }
 
+    
     @Throws(MobileException::class)
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `connect`(
-        `url`: kotlin.String,
-        `user`: kotlin.String,
-        `pass`: kotlin.String,
-        `insecure`: kotlin.Boolean,
-    ): kotlin.String =
-        uniffiRustCallAsync(
-            callWithHandle { uniffiHandle ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_connect(
-                    uniffiHandle,
-                    FfiConverterString.lower(`url`),
-                    FfiConverterString.lower(`user`),
-                    FfiConverterString.lower(`pass`),
-                    FfiConverterBoolean.lower(`insecure`),
-                )
-            },
-            { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_rust_buffer(future, callback, continuation) },
-            { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_rust_buffer(future, continuation) },
-            { future -> UniffiLib.ffi_cfait_rust_future_free_rust_buffer(future) },
-            // lift function
-            { FfiConverterString.lift(it) },
-            // Error FFI converter
-            MobileException.ErrorHandler,
-        )
-
-    @Throws(MobileException::class)
-    override fun `createDebugExport`(): kotlin.String =
-        FfiConverterString.lift(
-            callWithHandle {
-                uniffiRustCallWithError(MobileException) { _status ->
-                    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_create_debug_export(
-                        it,
-                        _status,
-                    )
-                }
-            },
-        )
-
-    @Throws(MobileException::class)
-    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `createLocalCalendar`(
-        `name`: kotlin.String,
-        `color`: kotlin.String?,
-    ): kotlin.String =
-        uniffiRustCallAsync(
-            callWithHandle { uniffiHandle ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_create_local_calendar(
-                    uniffiHandle,
-                    FfiConverterString.lower(`name`),
-                    FfiConverterOptionalString.lower(`color`),
-                )
-            },
-            { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_rust_buffer(future, callback, continuation) },
-            { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_rust_buffer(future, continuation) },
-            { future -> UniffiLib.ffi_cfait_rust_future_free_rust_buffer(future) },
-            // lift function
-            { FfiConverterString.lift(it) },
-            // Error FFI converter
-            MobileException.ErrorHandler,
-        )
-
-    @Throws(MobileException::class)
-    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `createMissingCalendarEvents`(): kotlin.UInt =
-        uniffiRustCallAsync(
-            callWithHandle { uniffiHandle ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_create_missing_calendar_events(
-                    uniffiHandle,
-                )
-            },
-            { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_u32(future, callback, continuation) },
-            { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_u32(future, continuation) },
-            { future -> UniffiLib.ffi_cfait_rust_future_free_u32(future) },
-            // lift function
-            { FfiConverterUInt.lift(it) },
-            // Error FFI converter
-            MobileException.ErrorHandler,
-        )
-
-    @Throws(MobileException::class)
-    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `createRemoteCalendar`(
-        `name`: kotlin.String,
-        `color`: kotlin.String?,
-    ): kotlin.String =
-        uniffiRustCallAsync(
-            callWithHandle { uniffiHandle ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_create_remote_calendar(
-                    uniffiHandle,
-                    FfiConverterString.lower(`name`),
-                    FfiConverterOptionalString.lower(`color`),
-                )
-            },
-            { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_rust_buffer(future, callback, continuation) },
-            { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_rust_buffer(future, continuation) },
-            { future -> UniffiLib.ffi_cfait_rust_future_free_rust_buffer(future) },
-            // lift function
-            { FfiConverterString.lift(it) },
-            // Error FFI converter
-            MobileException.ErrorHandler,
-        )
+    override suspend fun `addTaskSmart`(`input`: kotlin.String) : kotlin.String {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_add_task_smart(
+                uniffiHandle,
+                
+        FfiConverterString.lower(`input`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterString.lift(it) },
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
 
+    
     @Throws(MobileException::class)
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `deleteAllCalendarEvents`(): kotlin.UInt =
-        uniffiRustCallAsync(
-            callWithHandle { uniffiHandle ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_delete_all_calendar_events(
-                    uniffiHandle,
-                )
-            },
-            { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_u32(future, callback, continuation) },
-            { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_u32(future, continuation) },
-            { future -> UniffiLib.ffi_cfait_rust_future_free_u32(future) },
-            // lift function
-            { FfiConverterUInt.lift(it) },
-            // Error FFI converter
-            MobileException.ErrorHandler,
-        )
+    override suspend fun `addTaskWithDescription`(`input`: kotlin.String, `description`: kotlin.String) : kotlin.String {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_add_task_with_description(
+                uniffiHandle,
+                
+        FfiConverterString.lower(`input`),
+        FfiConverterString.lower(`description`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterString.lift(it) },
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
 
+    
+    /**
+     * Adds (`add`) or removes `tag` on every task in the view described by `filter_tag`
+     * and `search`. Returns how many tasks changed.
+     */
     @Throws(MobileException::class)
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `deleteLocalCalendar`(`href`: kotlin.String) =
-        uniffiRustCallAsync(
-            callWithHandle { uniffiHandle ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_delete_local_calendar(
-                    uniffiHandle,
-                    FfiConverterString.lower(`href`),
-                )
-            },
-            { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
-            { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
-            { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
-            // lift function
-            { Unit },
-            // Error FFI converter
-            MobileException.ErrorHandler,
-        )
+    override suspend fun `bulkTag`(`add`: kotlin.Boolean, `tag`: kotlin.String, `filterTag`: kotlin.String?, `search`: kotlin.String) : kotlin.UInt {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_bulk_tag(
+                uniffiHandle,
+                
+        FfiConverterBoolean.lower(`add`),
+        FfiConverterString.lower(`tag`),
+        FfiConverterOptionalString.lower(`filterTag`),
+        FfiConverterString.lower(`search`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_u32(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_u32(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_u32(future) },
+        // lift function
+        { FfiConverterUInt.lift(it) },
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
 
+    
     @Throws(MobileException::class)
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `deleteSession`(
-        `uid`: kotlin.String,
-        `index`: kotlin.UInt,
-    ) = uniffiRustCallAsync(
+    override suspend fun `changePriority`(`uid`: kotlin.String, `delta`: kotlin.Byte) {
+        return uniffiRustCallAsync(
         callWithHandle { uniffiHandle ->
-            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_delete_session(
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_change_priority(
                 uniffiHandle,
-                FfiConverterString.lower(`uid`),
-                FfiConverterUInt.lower(`index`),
+                
+        FfiConverterString.lower(`uid`),
+        FfiConverterByte.lower(`delta`),
             )
         },
         { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
@@ -3109,539 +2766,193 @@ open class CfaitMobile :
         { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
         // lift function
         { Unit },
+        
         // Error FFI converter
         MobileException.ErrorHandler,
     )
+    }
 
-    @Throws(MobileException::class)
-    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `deleteTask`(`uid`: kotlin.String) =
-        uniffiRustCallAsync(
-            callWithHandle { uniffiHandle ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_delete_task(
-                    uniffiHandle,
-                    FfiConverterString.lower(`uid`),
-                )
-            },
-            { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
-            { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
-            { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
-            // lift function
-            { Unit },
-            // Error FFI converter
-            MobileException.ErrorHandler,
-        )
+    
+    /**
+     * Merges redundant queued actions without syncing; returns how many were merged away.
+     */
+    @Throws(MobileException::class)override fun `compactJournal`(): kotlin.UInt {
+            return FfiConverterUInt.lift(
+    callWithHandle {
+    uniffiRustCallWithError(MobileException) { _status ->
+    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_compact_journal(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
 
+    
+    /**
+     * Completes every open, unblocked task in the view described by `filter_tag` and
+     * `search`. Returns how many tasks were marked done.
+     */
     @Throws(MobileException::class)
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `deleteTaskTree`(`uid`: kotlin.String) =
-        uniffiRustCallAsync(
-            callWithHandle { uniffiHandle ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_delete_task_tree(
-                    uniffiHandle,
-                    FfiConverterString.lower(`uid`),
-                )
-            },
-            { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
-            { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
-            { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
-            // lift function
-            { Unit },
-            // Error FFI converter
-            MobileException.ErrorHandler,
-        )
+    override suspend fun `completeAll`(`filterTag`: kotlin.String?, `search`: kotlin.String) : kotlin.UInt {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_complete_all(
+                uniffiHandle,
+                
+        FfiConverterOptionalString.lower(`filterTag`),
+        FfiConverterString.lower(`search`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_u32(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_u32(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_u32(future) },
+        // lift function
+        { FfiConverterUInt.lift(it) },
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
 
+    
     @Throws(MobileException::class)
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `dismissAlarm`(
-        `taskUid`: kotlin.String,
-        `alarmUid`: kotlin.String,
-    ) = uniffiRustCallAsync(
+    override suspend fun `connect`(`url`: kotlin.String, `user`: kotlin.String, `pass`: kotlin.String, `insecure`: kotlin.Boolean) : kotlin.String {
+        return uniffiRustCallAsync(
         callWithHandle { uniffiHandle ->
-            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_dismiss_alarm(
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_connect(
                 uniffiHandle,
-                FfiConverterString.lower(`taskUid`),
-                FfiConverterString.lower(`alarmUid`),
+                
+        FfiConverterString.lower(`url`),
+        FfiConverterString.lower(`user`),
+        FfiConverterString.lower(`pass`),
+        FfiConverterBoolean.lower(`insecure`),
             )
         },
-        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
-        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
-        { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_rust_buffer(future) },
         // lift function
-        { Unit },
+        { FfiConverterString.lift(it) },
         // Error FFI converter
         MobileException.ErrorHandler,
     )
+    }
 
-    @Throws(MobileException::class)
-    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `dispatch`(`intent`: AppIntent) =
-        uniffiRustCallAsync(
-            callWithHandle { uniffiHandle ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_dispatch(
-                    uniffiHandle,
-                    FfiConverterTypeAppIntent.lower(`intent`),
-                )
-            },
-            { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
-            { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
-            { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
-            // lift function
-            { Unit },
-            // Error FFI converter
-            MobileException.ErrorHandler,
-        )
+    
+    @Throws(MobileException::class)override fun `createDebugExport`(): kotlin.String {
+            return FfiConverterString.lift(
+    callWithHandle {
+    uniffiRustCallWithError(MobileException) { _status ->
+    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_create_debug_export(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
 
+    
     @Throws(MobileException::class)
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `duplicateTaskTree`(`uid`: kotlin.String) =
-        uniffiRustCallAsync(
-            callWithHandle { uniffiHandle ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_duplicate_task_tree(
-                    uniffiHandle,
-                    FfiConverterString.lower(`uid`),
-                )
-            },
-            { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
-            { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
-            { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
-            // lift function
-            { Unit },
-            // Error FFI converter
-            MobileException.ErrorHandler,
-        )
+    override suspend fun `createLocalCalendar`(`name`: kotlin.String, `color`: kotlin.String?) : kotlin.String {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_create_local_calendar(
+                uniffiHandle,
+                
+        FfiConverterString.lower(`name`),
+        FfiConverterOptionalString.lower(`color`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterString.lift(it) },
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
 
+    
     @Throws(MobileException::class)
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `editSession`(
-        `uid`: kotlin.String,
-        `index`: kotlin.UInt,
-        `input`: kotlin.String,
-    ) = uniffiRustCallAsync(
+    override suspend fun `createMissingCalendarEvents`() : kotlin.UInt {
+        return uniffiRustCallAsync(
         callWithHandle { uniffiHandle ->
-            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_edit_session(
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_create_missing_calendar_events(
                 uniffiHandle,
-                FfiConverterString.lower(`uid`),
-                FfiConverterUInt.lower(`index`),
-                FfiConverterString.lower(`input`),
+                
             )
         },
-        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
-        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
-        { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_u32(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_u32(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_u32(future) },
         // lift function
-        { Unit },
+        { FfiConverterUInt.lift(it) },
         // Error FFI converter
         MobileException.ErrorHandler,
     )
+    }
 
+    
     @Throws(MobileException::class)
-    override fun `exportLocalIcs`(`calendarHref`: kotlin.String): kotlin.String =
-        FfiConverterString.lift(
-            callWithHandle {
-                uniffiRustCallWithError(MobileException) { _status ->
-                    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_export_local_ics(
-                        it,
-                        FfiConverterString.lower(`calendarHref`),
-                        _status,
-                    )
-                }
-            },
-        )
-
-    @Throws(MobileException::class)
-    override fun `exportLocationsGpx`(`uid`: kotlin.String): kotlin.String =
-        FfiConverterString.lift(
-            callWithHandle {
-                uniffiRustCallWithError(MobileException) { _status ->
-                    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_export_locations_gpx(
-                        it,
-                        FfiConverterString.lower(`uid`),
-                        _status,
-                    )
-                }
-            },
-        )
-
-    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `getAllLocations`(): List<MobileLocation> =
-        uniffiRustCallAsync(
-            callWithHandle { uniffiHandle ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_all_locations(
-                    uniffiHandle,
-                )
-            },
-            { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_rust_buffer(future, callback, continuation) },
-            { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_rust_buffer(future, continuation) },
-            { future -> UniffiLib.ffi_cfait_rust_future_free_rust_buffer(future) },
-            // lift function
-            { FfiConverterSequenceTypeMobileLocation.lift(it) },
-            // Error FFI converter
-            UniffiNullRustCallStatusErrorHandler,
-        )
-
-    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `getAllTags`(): List<MobileTag> =
-        uniffiRustCallAsync(
-            callWithHandle { uniffiHandle ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_all_tags(
-                    uniffiHandle,
-                )
-            },
-            { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_rust_buffer(future, callback, continuation) },
-            { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_rust_buffer(future, continuation) },
-            { future -> UniffiLib.ffi_cfait_rust_future_free_rust_buffer(future) },
-            // lift function
-            { FfiConverterSequenceTypeMobileTag.lift(it) },
-            // Error FFI converter
-            UniffiNullRustCallStatusErrorHandler,
-        )
-
-    override fun `getAvailableLocales`(): List<kotlin.String> =
-        FfiConverterSequenceString.lift(
-            callWithHandle {
-                uniffiRustCall { _status ->
-                    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_available_locales(
-                        it,
-                        _status,
-                    )
-                }
-            },
-        )
-
-    override fun `getCalendars`(): List<MobileCalendar> =
-        FfiConverterSequenceTypeMobileCalendar.lift(
-            callWithHandle {
-                uniffiRustCall { _status ->
-                    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_calendars(
-                        it,
-                        _status,
-                    )
-                }
-            },
-        )
-
-    override fun `getConfig`(): MobileConfig =
-        FfiConverterTypeMobileConfig.lift(
-            callWithHandle {
-                uniffiRustCall { _status ->
-                    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_config(
-                        it,
-                        _status,
-                    )
-                }
-            },
-        )
-
-    override fun `getFiringAlarms`(): List<MobileAlarmInfo> =
-        FfiConverterSequenceTypeMobileAlarmInfo.lift(
-            callWithHandle {
-                uniffiRustCall { _status ->
-                    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_firing_alarms(
-                        it,
-                        _status,
-                    )
-                }
-            },
-        )
-
-    override fun `getHelpData`(): List<MobileHelpCategoryData> =
-        FfiConverterSequenceTypeMobileHelpCategoryData.lift(
-            callWithHandle {
-                uniffiRustCall { _status ->
-                    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_help_data(
-                        it,
-                        _status,
-                    )
-                }
-            },
-        )
-
-    override fun `getNextAlarmTimestamp`(): kotlin.Long? =
-        FfiConverterOptionalLong.lift(
-            callWithHandle {
-                uniffiRustCall { _status ->
-                    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_next_alarm_timestamp(
-                        it,
-                        _status,
-                    )
-                }
-            },
-        )
-
-    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `getNextGlobalAlarmTime`(): kotlin.Long? =
-        uniffiRustCallAsync(
-            callWithHandle { uniffiHandle ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_next_global_alarm_time(
-                    uniffiHandle,
-                )
-            },
-            { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_rust_buffer(future, callback, continuation) },
-            { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_rust_buffer(future, continuation) },
-            { future -> UniffiLib.ffi_cfait_rust_future_free_rust_buffer(future) },
-            // lift function
-            { FfiConverterOptionalLong.lift(it) },
-            // Error FFI converter
-            UniffiNullRustCallStatusErrorHandler,
-        )
-
-    override fun `getOngoingTasks`(): List<MobileTask> =
-        FfiConverterSequenceTypeMobileTask.lift(
-            callWithHandle {
-                uniffiRustCall { _status ->
-                    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_ongoing_tasks(
-                        it,
-                        _status,
-                    )
-                }
-            },
-        )
-
-    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `getRandomTaskUid`(
-        `filterTags`: List<kotlin.String>,
-        `filterLocations`: List<kotlin.String>,
-        `searchQuery`: kotlin.String,
-    ): kotlin.String? =
-        uniffiRustCallAsync(
-            callWithHandle { uniffiHandle ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_random_task_uid(
-                    uniffiHandle,
-                    FfiConverterSequenceString.lower(`filterTags`),
-                    FfiConverterSequenceString.lower(`filterLocations`),
-                    FfiConverterString.lower(`searchQuery`),
-                )
-            },
-            { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_rust_buffer(future, callback, continuation) },
-            { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_rust_buffer(future, continuation) },
-            { future -> UniffiLib.ffi_cfait_rust_future_free_rust_buffer(future) },
-            // lift function
-            { FfiConverterOptionalString.lift(it) },
-            // Error FFI converter
-            UniffiNullRustCallStatusErrorHandler,
-        )
-
-    override fun `getSyntaxHelp`(): List<MobileHelpSection> =
-        FfiConverterSequenceTypeMobileHelpSection.lift(
-            callWithHandle {
-                uniffiRustCall { _status ->
-                    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_syntax_help(
-                        it,
-                        _status,
-                    )
-                }
-            },
-        )
-
-    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `getTaskByUid`(`uid`: kotlin.String): MobileTask? =
-        uniffiRustCallAsync(
-            callWithHandle { uniffiHandle ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_task_by_uid(
-                    uniffiHandle,
-                    FfiConverterString.lower(`uid`),
-                )
-            },
-            { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_rust_buffer(future, callback, continuation) },
-            { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_rust_buffer(future, continuation) },
-            { future -> UniffiLib.ffi_cfait_rust_future_free_rust_buffer(future) },
-            // lift function
-            { FfiConverterOptionalTypeMobileTask.lift(it) },
-            // Error FFI converter
-            UniffiNullRustCallStatusErrorHandler,
-        )
-
-    override fun `getTaskTreeMarkdown`(`uid`: kotlin.String): kotlin.String =
-        FfiConverterString.lift(
-            callWithHandle {
-                uniffiRustCall { _status ->
-                    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_task_tree_markdown(
-                        it,
-                        FfiConverterString.lower(`uid`),
-                        _status,
-                    )
-                }
-            },
-        )
-
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `getTasksRelatedTo`(`uid`: kotlin.String): List<MobileRelatedTask> =
-        uniffiRustCallAsync(
-            callWithHandle { uniffiHandle ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_tasks_related_to(
-                    uniffiHandle,
-                    FfiConverterString.lower(`uid`),
-                )
-            },
-            { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_rust_buffer(future, callback, continuation) },
-            { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_rust_buffer(future, continuation) },
-            { future -> UniffiLib.ffi_cfait_rust_future_free_rust_buffer(future) },
-            // lift function
-            { FfiConverterSequenceTypeMobileRelatedTask.lift(it) },
-            // Error FFI converter
-            UniffiNullRustCallStatusErrorHandler,
-        )
-
-    override fun `getVersionInfo`(): MobileVersionInfo =
-        FfiConverterTypeMobileVersionInfo.lift(
-            callWithHandle {
-                uniffiRustCall { _status ->
-                    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_version_info(
-                        it,
-                        _status,
-                    )
-                }
-            },
-        )
-
-    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `getViewTasks`(`options`: MobileFilterOptions): MobileViewData =
-        uniffiRustCallAsync(
-            callWithHandle { uniffiHandle ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_view_tasks(
-                    uniffiHandle,
-                    FfiConverterTypeMobileFilterOptions.lower(`options`),
-                )
-            },
-            { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_rust_buffer(future, callback, continuation) },
-            { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_rust_buffer(future, continuation) },
-            { future -> UniffiLib.ffi_cfait_rust_future_free_rust_buffer(future) },
-            // lift function
-            { FfiConverterTypeMobileViewData.lift(it) },
-            // Error FFI converter
-            UniffiNullRustCallStatusErrorHandler,
-        )
-
-    override fun `hasAnyTasks`(): kotlin.Boolean =
-        FfiConverterBoolean.lift(
-            callWithHandle {
-                uniffiRustCall { _status ->
-                    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_has_any_tasks(
-                        it,
-                        _status,
-                    )
-                }
-            },
-        )
-
-    override fun `hasUnsyncedChanges`(): kotlin.Boolean =
-        FfiConverterBoolean.lift(
-            callWithHandle {
-                uniffiRustCall { _status ->
-                    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_has_unsynced_changes(
-                        it,
-                        _status,
-                    )
-                }
-            },
-        )
-
-    @Throws(MobileException::class)
-    override fun `importLocalIcs`(
-        `calendarHref`: kotlin.String,
-        `icsContent`: kotlin.String,
-    ): kotlin.String =
-        FfiConverterString.lift(
-            callWithHandle {
-                uniffiRustCallWithError(MobileException) { _status ->
-                    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_import_local_ics(
-                        it,
-                        FfiConverterString.lower(`calendarHref`),
-                        FfiConverterString.lower(`icsContent`),
-                        _status,
-                    )
-                }
-            },
-        )
-
-    @Throws(MobileException::class)
-    override fun `isolateCalendar`(`href`: kotlin.String) =
-        callWithHandle {
-            uniffiRustCallWithError(MobileException) { _status ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_isolate_calendar(
-                    it,
-                    FfiConverterString.lower(`href`),
-                    _status,
-                )
-            }
-        }
-
-    override fun `loadFromCache`() =
-        callWithHandle {
-            uniffiRustCall { _status ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_load_from_cache(
-                    it,
-                    _status,
-                )
-            }
-        }
-
-    override fun `logMessage`(
-        `level`: kotlin.String,
-        `tag`: kotlin.String,
-        `message`: kotlin.String,
-    ) = callWithHandle {
-        uniffiRustCall { _status ->
-            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_log_message(
-                it,
-                FfiConverterString.lower(`level`),
-                FfiConverterString.lower(`tag`),
-                FfiConverterString.lower(`message`),
-                _status,
+    override suspend fun `createRemoteCalendar`(`name`: kotlin.String, `color`: kotlin.String?) : kotlin.String {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_create_remote_calendar(
+                uniffiHandle,
+                
+        FfiConverterString.lower(`name`),
+        FfiConverterOptionalString.lower(`color`),
             )
-        }
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterString.lift(it) },
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
     }
 
+    
     @Throws(MobileException::class)
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `migrateLocalTo`(
-        `sourceHref`: kotlin.String,
-        `targetHref`: kotlin.String,
-    ): kotlin.String =
-        uniffiRustCallAsync(
-            callWithHandle { uniffiHandle ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_migrate_local_to(
-                    uniffiHandle,
-                    FfiConverterString.lower(`sourceHref`),
-                    FfiConverterString.lower(`targetHref`),
-                )
-            },
-            { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_rust_buffer(future, callback, continuation) },
-            { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_rust_buffer(future, continuation) },
-            { future -> UniffiLib.ffi_cfait_rust_future_free_rust_buffer(future) },
-            // lift function
-            { FfiConverterString.lift(it) },
-            // Error FFI converter
-            MobileException.ErrorHandler,
-        )
-
-    @Throws(MobileException::class)
-    override fun `moveCalendar`(
-        `href`: kotlin.String,
-        `direction`: kotlin.Byte,
-    ) = callWithHandle {
-        uniffiRustCallWithError(MobileException) { _status ->
-            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_move_calendar(
-                it,
-                FfiConverterString.lower(`href`),
-                FfiConverterByte.lower(`direction`),
-                _status,
+    override suspend fun `deleteAllCalendarEvents`() : kotlin.UInt {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_delete_all_calendar_events(
+                uniffiHandle,
+                
             )
-        }
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_u32(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_u32(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_u32(future) },
+        // lift function
+        { FfiConverterUInt.lift(it) },
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
     }
 
+    
     @Throws(MobileException::class)
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `moveTask`(
-        `uid`: kotlin.String,
-        `newCalHref`: kotlin.String,
-    ) = uniffiRustCallAsync(
+    override suspend fun `deleteLocalCalendar`(`href`: kotlin.String) {
+        return uniffiRustCallAsync(
         callWithHandle { uniffiHandle ->
-            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_move_task(
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_delete_local_calendar(
                 uniffiHandle,
-                FfiConverterString.lower(`uid`),
-                FfiConverterString.lower(`newCalHref`),
+                
+        FfiConverterString.lower(`href`),
             )
         },
         { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
@@ -3649,82 +2960,23 @@ open class CfaitMobile :
         { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
         // lift function
         { Unit },
+        
         // Error FFI converter
         MobileException.ErrorHandler,
     )
+    }
 
-    override fun `parseDurationString`(`val`: kotlin.String): kotlin.UInt? =
-        FfiConverterOptionalUInt.lift(
-            callWithHandle {
-                uniffiRustCall { _status ->
-                    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_parse_duration_string(
-                        it,
-                        FfiConverterString.lower(`val`),
-                        _status,
-                    )
-                }
-            },
-        )
-
-    override fun `parseSmartString`(
-        `input`: kotlin.String,
-        `isSearch`: kotlin.Boolean,
-    ): List<MobileSyntaxToken> =
-        FfiConverterSequenceTypeMobileSyntaxToken.lift(
-            callWithHandle {
-                uniffiRustCall { _status ->
-                    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_parse_smart_string(
-                        it,
-                        FfiConverterString.lower(`input`),
-                        FfiConverterBoolean.lower(`isSearch`),
-                        _status,
-                    )
-                }
-            },
-        )
-
-    @Throws(MobileException::class)
-    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `pauseTask`(`uid`: kotlin.String) =
-        uniffiRustCallAsync(
-            callWithHandle { uniffiHandle ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_pause_task(
-                    uniffiHandle,
-                    FfiConverterString.lower(`uid`),
-                )
-            },
-            { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
-            { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
-            { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
-            // lift function
-            { Unit },
-            // Error FFI converter
-            MobileException.ErrorHandler,
-        )
-
-    @Throws(MobileException::class)
-    override fun `removeAlias`(`key`: kotlin.String) =
-        callWithHandle {
-            uniffiRustCallWithError(MobileException) { _status ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_remove_alias(
-                    it,
-                    FfiConverterString.lower(`key`),
-                    _status,
-                )
-            }
-        }
-
+    
     @Throws(MobileException::class)
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `removeDependency`(
-        `taskUid`: kotlin.String,
-        `blockerUid`: kotlin.String,
-    ) = uniffiRustCallAsync(
+    override suspend fun `deleteSession`(`uid`: kotlin.String, `index`: kotlin.UInt) {
+        return uniffiRustCallAsync(
         callWithHandle { uniffiHandle ->
-            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_remove_dependency(
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_delete_session(
                 uniffiHandle,
-                FfiConverterString.lower(`taskUid`),
-                FfiConverterString.lower(`blockerUid`),
+                
+        FfiConverterString.lower(`uid`),
+        FfiConverterUInt.lower(`index`),
             )
         },
         { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
@@ -3732,21 +2984,22 @@ open class CfaitMobile :
         { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
         // lift function
         { Unit },
+        
         // Error FFI converter
         MobileException.ErrorHandler,
     )
+    }
 
+    
     @Throws(MobileException::class)
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `removeRelatedTo`(
-        `taskUid`: kotlin.String,
-        `relatedUid`: kotlin.String,
-    ) = uniffiRustCallAsync(
+    override suspend fun `deleteTask`(`uid`: kotlin.String) {
+        return uniffiRustCallAsync(
         callWithHandle { uniffiHandle ->
-            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_remove_related_to(
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_delete_task(
                 uniffiHandle,
-                FfiConverterString.lower(`taskUid`),
-                FfiConverterString.lower(`relatedUid`),
+                
+        FfiConverterString.lower(`uid`),
             )
         },
         { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
@@ -3754,88 +3007,22 @@ open class CfaitMobile :
         { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
         // lift function
         { Unit },
+        
         // Error FFI converter
         MobileException.ErrorHandler,
     )
+    }
 
-    override fun `resolveSelectionAliases`(
-        `selection`: kotlin.String,
-        `isLocation`: kotlin.Boolean,
-    ): List<kotlin.String> =
-        FfiConverterSequenceString.lift(
-            callWithHandle {
-                uniffiRustCall { _status ->
-                    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_resolve_selection_aliases(
-                        it,
-                        FfiConverterString.lower(`selection`),
-                        FfiConverterBoolean.lower(`isLocation`),
-                        _status,
-                    )
-                }
-            },
-        )
-
-    @Throws(MobileException::class)
-    override fun `saveConfig`(`config`: MobileConfig) =
-        callWithHandle {
-            uniffiRustCallWithError(MobileException) { _status ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_save_config(
-                    it,
-                    FfiConverterTypeMobileConfig.lower(`config`),
-                    _status,
-                )
-            }
-        }
-
-    @Throws(MobileException::class)
-    override fun `setCalendarVisibility`(
-        `href`: kotlin.String,
-        `visible`: kotlin.Boolean,
-    ) = callWithHandle {
-        uniffiRustCallWithError(MobileException) { _status ->
-            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_set_calendar_visibility(
-                it,
-                FfiConverterString.lower(`href`),
-                FfiConverterBoolean.lower(`visible`),
-                _status,
-            )
-        }
-    }
-
-    @Throws(MobileException::class)
-    override fun `setDefaultCalendar`(`href`: kotlin.String) =
-        callWithHandle {
-            uniffiRustCallWithError(MobileException) { _status ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_set_default_calendar(
-                    it,
-                    FfiConverterString.lower(`href`),
-                    _status,
-                )
-            }
-        }
-
-    override fun `setLocale`(`locale`: kotlin.String) =
-        callWithHandle {
-            uniffiRustCall { _status ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_set_locale(
-                    it,
-                    FfiConverterString.lower(`locale`),
-                    _status,
-                )
-            }
-        }
-
+    
     @Throws(MobileException::class)
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `setParent`(
-        `childUid`: kotlin.String,
-        `parentUid`: kotlin.String?,
-    ) = uniffiRustCallAsync(
+    override suspend fun `deleteTaskTree`(`uid`: kotlin.String) {
+        return uniffiRustCallAsync(
         callWithHandle { uniffiHandle ->
-            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_set_parent(
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_delete_task_tree(
                 uniffiHandle,
-                FfiConverterString.lower(`childUid`),
-                FfiConverterOptionalString.lower(`parentUid`),
+                
+        FfiConverterString.lower(`uid`),
             )
         },
         { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
@@ -3843,85 +3030,52 @@ open class CfaitMobile :
         { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
         // lift function
         { Unit },
+        
         // Error FFI converter
         MobileException.ErrorHandler,
     )
+    }
 
+    
+    /**
+     * Runs the CalDAV discovery chain step by step against the given credentials without
+     * saving them, so the settings screen can show which step fails.
+     */
     @Throws(MobileException::class)
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `setStatusCancelled`(`uid`: kotlin.String) =
-        uniffiRustCallAsync(
-            callWithHandle { uniffiHandle ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_set_status_cancelled(
-                    uniffiHandle,
-                    FfiConverterString.lower(`uid`),
-                )
-            },
-            { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
-            { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
-            { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
-            // lift function
-            { Unit },
-            // Error FFI converter
-            MobileException.ErrorHandler,
-        )
-
-    @Throws(MobileException::class)
-    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `setStatusProcess`(`uid`: kotlin.String) =
-        uniffiRustCallAsync(
-            callWithHandle { uniffiHandle ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_set_status_process(
-                    uniffiHandle,
-                    FfiConverterString.lower(`uid`),
-                )
-            },
-            { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
-            { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
-            { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
-            // lift function
-            { Unit },
-            // Error FFI converter
-            MobileException.ErrorHandler,
-        )
-
-    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `shouldKeepNotification`(
-        `taskUid`: kotlin.String,
-        `notifType`: kotlin.String,
-        `alarmUid`: kotlin.String?,
-    ): kotlin.Boolean =
-        uniffiRustCallAsync(
-            callWithHandle { uniffiHandle ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_should_keep_notification(
-                    uniffiHandle,
-                    FfiConverterString.lower(`taskUid`),
-                    FfiConverterString.lower(`notifType`),
-                    FfiConverterOptionalString.lower(`alarmUid`),
-                )
-            },
-            { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_i8(future, callback, continuation) },
-            { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_i8(future, continuation) },
-            { future -> UniffiLib.ffi_cfait_rust_future_free_i8(future) },
-            // lift function
-            { FfiConverterBoolean.lift(it) },
-            // Error FFI converter
-            UniffiNullRustCallStatusErrorHandler,
-        )
+    override suspend fun `diagnoseConnection`(`url`: kotlin.String, `user`: kotlin.String, `pass`: kotlin.String, `insecure`: kotlin.Boolean) : List<MobileDiagnosticStep> {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_diagnose_connection(
+                uniffiHandle,
+                
+        FfiConverterString.lower(`url`),
+        FfiConverterString.lower(`user`),
+        FfiConverterString.lower(`pass`),
+        FfiConverterBoolean.lower(`insecure`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterSequenceTypeMobileDiagnosticStep.lift(it) },
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
 
+    
     @Throws(MobileException::class)
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `snoozeAlarm`(
-        `taskUid`: kotlin.String,
-        `alarmUid`: kotlin.String,
-        `minutes`: kotlin.UInt,
-    ) = uniffiRustCallAsync(
+    override suspend fun `dismissAlarm`(`taskUid`: kotlin.String, `alarmUid`: kotlin.String) {
+        return uniffiRustCallAsync(
         callWithHandle { uniffiHandle ->
-            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_snooze_alarm(
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_dismiss_alarm(
                 uniffiHandle,
-                FfiConverterString.lower(`taskUid`),
-                FfiConverterString.lower(`alarmUid`),
-                FfiConverterUInt.lower(`minutes`),
+                
+        FfiConverterString.lower(`taskUid`),
+        FfiConverterString.lower(`alarmUid`),
             )
         },
         { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
@@ -3929,95 +3083,91 @@ open class CfaitMobile :
         { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
         // lift function
         { Unit },
+        
         // Error FFI converter
         MobileException.ErrorHandler,
     )
+    }
 
+    
     @Throws(MobileException::class)
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `startTask`(`uid`: kotlin.String) =
-        uniffiRustCallAsync(
-            callWithHandle { uniffiHandle ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_start_task(
-                    uniffiHandle,
-                    FfiConverterString.lower(`uid`),
-                )
-            },
-            { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
-            { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
-            { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
-            // lift function
-            { Unit },
-            // Error FFI converter
-            MobileException.ErrorHandler,
-        )
-
-    @Throws(MobileException::class)
-    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `stopTask`(`uid`: kotlin.String) =
-        uniffiRustCallAsync(
-            callWithHandle { uniffiHandle ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_stop_task(
-                    uniffiHandle,
-                    FfiConverterString.lower(`uid`),
-                )
-            },
-            { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
-            { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
-            { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
-            // lift function
-            { Unit },
-            // Error FFI converter
-            MobileException.ErrorHandler,
-        )
+    override suspend fun `dispatch`(`intent`: AppIntent) {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_dispatch(
+                uniffiHandle,
+                
+        FfiConverterTypeAppIntent.lower(`intent`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
+        // lift function
+        { Unit },
+        
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
 
-    @Throws(MobileException::class)
-    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `sync`(): kotlin.String =
-        uniffiRustCallAsync(
-            callWithHandle { uniffiHandle ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_sync(
-                    uniffiHandle,
-                )
-            },
-            { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_rust_buffer(future, callback, continuation) },
-            { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_rust_buffer(future, continuation) },
-            { future -> UniffiLib.ffi_cfait_rust_future_free_rust_buffer(future) },
-            // lift function
-            { FfiConverterString.lift(it) },
-            // Error FFI converter
-            MobileException.ErrorHandler,
-        )
+    
+    /**
+     * Drops a stuck journal action. Returns false if the queue changed since it was listed.
+     */
+    @Throws(MobileException::class)override fun `dropJournalAction`(`index`: kotlin.UInt, `uid`: kotlin.String): kotlin.Boolean {
+            return FfiConverterBoolean.lift(
+    callWithHandle {
+    uniffiRustCallWithError(MobileException) { _status ->
+    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_drop_journal_action(
+        it,
+        
+        FfiConverterUInt.lower(`index`),
+        FfiConverterString.lower(`uid`),_status)
+}
+    }
+    )
+    }
+    
 
+    
+    /**
+     * Copies a task (and its subtasks) and returns the copy's UID so it can be opened for editing.
+     */
     @Throws(MobileException::class)
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `syncJournal`(): kotlin.Boolean =
-        uniffiRustCallAsync(
-            callWithHandle { uniffiHandle ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_sync_journal(
-                    uniffiHandle,
-                )
-            },
-            { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_i8(future, callback, continuation) },
-            { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_i8(future, continuation) },
-            { future -> UniffiLib.ffi_cfait_rust_future_free_i8(future) },
-            // lift function
-            { FfiConverterBoolean.lift(it) },
-            // Error FFI converter
-            MobileException.ErrorHandler,
-        )
+    override suspend fun `duplicateTaskTree`(`uid`: kotlin.String) : kotlin.String? {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_duplicate_task_tree(
+                uniffiHandle,
+                
+        FfiConverterString.lower(`uid`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterOptionalString.lift(it) },
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
 
+    
     @Throws(MobileException::class)
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `syncTaskTreeFromMarkdown`(
-        `uid`: kotlin.String,
-        `markdown`: kotlin.String,
-    ) = uniffiRustCallAsync(
+    override suspend fun `editSession`(`uid`: kotlin.String, `index`: kotlin.UInt, `input`: kotlin.String) {
+        return uniffiRustCallAsync(
         callWithHandle { uniffiHandle ->
-            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_sync_task_tree_from_markdown(
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_edit_session(
                 uniffiHandle,
-                FfiConverterString.lower(`uid`),
-                FfiConverterString.lower(`markdown`),
+                
+        FfiConverterString.lower(`uid`),
+        FfiConverterUInt.lower(`index`),
+        FfiConverterString.lower(`input`),
             )
         },
         { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
@@ -4025,273 +3175,1919 @@ open class CfaitMobile :
         { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
         // lift function
         { Unit },
+        
         // Error FFI converter
         MobileException.ErrorHandler,
     )
+    }
+
+    
+    /**
+     * Serializes every task across all calendars for backup.
+     * `as_json` selects a JSON array; otherwise a single re-importable `.ics` is returned.
+     */override fun `exportAll`(`asJson`: kotlin.Boolean): kotlin.String {
+            return FfiConverterString.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_export_all(
+        it,
+        
+        FfiConverterBoolean.lower(`asJson`),_status)
+}
+    }
+    )
+    }
+    
 
-    @Throws(MobileException::class)
-    override fun `toggleAllCalendars`(`showAll`: kotlin.Boolean) =
-        callWithHandle {
-            uniffiRustCallWithError(MobileException) { _status ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_toggle_all_calendars(
-                    it,
-                    FfiConverterBoolean.lower(`showAll`),
-                    _status,
-                )
-            }
-        }
+    
+    @Throws(MobileException::class)override fun `exportLocalIcs`(`calendarHref`: kotlin.String): kotlin.String {
+            return FfiConverterString.lift(
+    callWithHandle {
+    uniffiRustCallWithError(MobileException) { _status ->
+    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_export_local_ics(
+        it,
+        
+        FfiConverterString.lower(`calendarHref`),_status)
+}
+    }
+    )
+    }
+    
 
-    @Throws(MobileException::class)
+    
+    @Throws(MobileException::class)override fun `exportLocationsGpx`(`uid`: kotlin.String): kotlin.String {
+            return FfiConverterString.lift(
+    callWithHandle {
+    uniffiRustCallWithError(MobileException) { _status ->
+    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_export_locations_gpx(
+        it,
+        
+        FfiConverterString.lower(`uid`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    /**
+     * Every location with its task count, as in the sidebar with nothing selected.
+     * Pass a location's `name` in `MobileFilterOptions::filter_locations` to filter by it.
+     */
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `togglePin`(`uid`: kotlin.String) =
-        uniffiRustCallAsync(
-            callWithHandle { uniffiHandle ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_toggle_pin(
-                    uniffiHandle,
-                    FfiConverterString.lower(`uid`),
-                )
-            },
-            { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
-            { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
-            { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
-            // lift function
-            { Unit },
-            // Error FFI converter
-            MobileException.ErrorHandler,
-        )
+    override suspend fun `getAllLocations`() : List<MobileLocation> {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_all_locations(
+                uniffiHandle,
+                
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterSequenceTypeMobileLocation.lift(it) },
+        // Error FFI converter
+        UniffiNullRustCallStatusErrorHandler,
+    )
+    }
 
-    @Throws(MobileException::class)
+    
+    /**
+     * Every tag with its task count, as in the sidebar with nothing selected.
+     */
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `toggleTask`(`uid`: kotlin.String) =
-        uniffiRustCallAsync(
-            callWithHandle { uniffiHandle ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_toggle_task(
-                    uniffiHandle,
-                    FfiConverterString.lower(`uid`),
-                )
-            },
-            { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
-            { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
-            { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
-            // lift function
-            { Unit },
-            // Error FFI converter
-            MobileException.ErrorHandler,
-        )
+    override suspend fun `getAllTags`() : List<MobileTag> {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_all_tags(
+                uniffiHandle,
+                
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterSequenceTypeMobileTag.lift(it) },
+        // Error FFI converter
+        UniffiNullRustCallStatusErrorHandler,
+    )
+    }
 
-    @Throws(MobileException::class)
+    override fun `getAvailableLocales`(): List<kotlin.String> {
+            return FfiConverterSequenceString.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_available_locales(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    override fun `getCalendars`(): List<MobileCalendar> {
+            return FfiConverterSequenceTypeMobileCalendar.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_calendars(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    override fun `getConfig`(): MobileConfig {
+            return FfiConverterTypeMobileConfig.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_config(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    override fun `getFiringAlarms`(): List<MobileAlarmInfo> {
+            return FfiConverterSequenceTypeMobileAlarmInfo.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_firing_alarms(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    override fun `getHelpData`(): List<MobileHelpCategoryData> {
+            return FfiConverterSequenceTypeMobileHelpCategoryData.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_help_data(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    override fun `getJournalEntries`(): List<MobileJournalEntry> {
+            return FfiConverterSequenceTypeMobileJournalEntry.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_journal_entries(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    override fun `getNextAlarmTimestamp`(): kotlin.Long? {
+            return FfiConverterOptionalLong.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_next_alarm_timestamp(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `toggleTaskShift`(`uid`: kotlin.String) =
-        uniffiRustCallAsync(
-            callWithHandle { uniffiHandle ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_toggle_task_shift(
-                    uniffiHandle,
-                    FfiConverterString.lower(`uid`),
-                )
-            },
-            { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
-            { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
-            { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
-            // lift function
-            { Unit },
-            // Error FFI converter
-            MobileException.ErrorHandler,
-        )
+    override suspend fun `getNextGlobalAlarmTime`() : kotlin.Long? {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_next_global_alarm_time(
+                uniffiHandle,
+                
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterOptionalLong.lift(it) },
+        // Error FFI converter
+        UniffiNullRustCallStatusErrorHandler,
+    )
+    }
 
-    @Throws(MobileException::class)
+    override fun `getOngoingTasks`(): List<MobileTask> {
+            return FfiConverterSequenceTypeMobileTask.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_ongoing_tasks(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `updateLocalCalendar`(
-        `href`: kotlin.String,
-        `name`: kotlin.String,
-        `color`: kotlin.String?,
-    ) = uniffiRustCallAsync(
+    override suspend fun `getRandomTaskUid`(`filterTags`: List<kotlin.String>, `filterLocations`: List<kotlin.String>, `searchQuery`: kotlin.String) : kotlin.String? {
+        return uniffiRustCallAsync(
         callWithHandle { uniffiHandle ->
-            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_update_local_calendar(
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_random_task_uid(
                 uniffiHandle,
-                FfiConverterString.lower(`href`),
-                FfiConverterString.lower(`name`),
-                FfiConverterOptionalString.lower(`color`),
+                
+        FfiConverterSequenceString.lower(`filterTags`),
+        FfiConverterSequenceString.lower(`filterLocations`),
+        FfiConverterString.lower(`searchQuery`),
             )
         },
-        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
-        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
-        { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_rust_buffer(future) },
         // lift function
-        { Unit },
+        { FfiConverterOptionalString.lift(it) },
         // Error FFI converter
-        MobileException.ErrorHandler,
+        UniffiNullRustCallStatusErrorHandler,
     )
+    }
 
-    @Throws(MobileException::class)
+    
+    /**
+     * Remote tasks still unsynced past `unsynced_warning_hours` ("failed to sync"), oldest first.
+     * Discard them with `delete_task`.
+     */
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `updateRemoteCalendar`(
-        `href`: kotlin.String,
-        `name`: kotlin.String,
-        `color`: kotlin.String?,
-    ) = uniffiRustCallAsync(
+    override suspend fun `getStaleUnsyncedTasks`() : List<MobileTask> {
+        return uniffiRustCallAsync(
         callWithHandle { uniffiHandle ->
-            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_update_remote_calendar(
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_stale_unsynced_tasks(
                 uniffiHandle,
-                FfiConverterString.lower(`href`),
-                FfiConverterString.lower(`name`),
-                FfiConverterOptionalString.lower(`color`),
+                
             )
         },
-        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
-        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
-        { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_rust_buffer(future) },
         // lift function
-        { Unit },
+        { FfiConverterSequenceTypeMobileTask.lift(it) },
         // Error FFI converter
-        MobileException.ErrorHandler,
+        UniffiNullRustCallStatusErrorHandler,
     )
+    }
 
-    @Throws(MobileException::class)
+    override fun `getSyntaxHelp`(): List<MobileHelpSection> {
+            return FfiConverterSequenceTypeMobileHelpSection.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_syntax_help(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `updateTaskDescription`(
-        `uid`: kotlin.String,
-        `description`: kotlin.String,
-    ) = uniffiRustCallAsync(
+    override suspend fun `getTaskByUid`(`uid`: kotlin.String) : MobileTask? {
+        return uniffiRustCallAsync(
         callWithHandle { uniffiHandle ->
-            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_update_task_description(
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_task_by_uid(
                 uniffiHandle,
-                FfiConverterString.lower(`uid`),
-                FfiConverterString.lower(`description`),
+                
+        FfiConverterString.lower(`uid`),
             )
         },
-        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
-        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
-        { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_rust_buffer(future) },
         // lift function
-        { Unit },
+        { FfiConverterOptionalTypeMobileTask.lift(it) },
         // Error FFI converter
-        MobileException.ErrorHandler,
+        UniffiNullRustCallStatusErrorHandler,
     )
+    }
 
-    @Throws(MobileException::class)
+    override fun `getTaskTreeMarkdown`(`uid`: kotlin.String): kotlin.String {
+            return FfiConverterString.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_task_tree_markdown(
+        it,
+        
+        FfiConverterString.lower(`uid`),_status)
+}
+    }
+    )
+    }
+    
+
+    
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `updateTaskSmart`(
-        `uid`: kotlin.String,
-        `smartInput`: kotlin.String,
-    ) = uniffiRustCallAsync(
+    override suspend fun `getTasksRelatedTo`(`uid`: kotlin.String) : List<MobileRelatedTask> {
+        return uniffiRustCallAsync(
         callWithHandle { uniffiHandle ->
-            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_update_task_smart(
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_tasks_related_to(
                 uniffiHandle,
-                FfiConverterString.lower(`uid`),
-                FfiConverterString.lower(`smartInput`),
+                
+        FfiConverterString.lower(`uid`),
             )
         },
-        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
-        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
-        { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_rust_buffer(future) },
         // lift function
-        { Unit },
+        { FfiConverterSequenceTypeMobileRelatedTask.lift(it) },
         // Error FFI converter
-        MobileException.ErrorHandler,
+        UniffiNullRustCallStatusErrorHandler,
+    )
+    }
+
+    override fun `getVersionInfo`(): MobileVersionInfo {
+            return FfiConverterTypeMobileVersionInfo.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_version_info(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    
+    /**
+     * Renders the currently filtered view as a Markdown checklist (for sharing/printing).
+     */
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `getViewMarkdown`(`options`: MobileFilterOptions) : kotlin.String {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_view_markdown(
+                uniffiHandle,
+                
+        FfiConverterTypeMobileFilterOptions.lower(`options`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterString.lift(it) },
+        // Error FFI converter
+        UniffiNullRustCallStatusErrorHandler,
     )
+    }
+
+    
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `getViewTasks`(`options`: MobileFilterOptions) : MobileViewData {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_get_view_tasks(
+                uniffiHandle,
+                
+        FfiConverterTypeMobileFilterOptions.lower(`options`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterTypeMobileViewData.lift(it) },
+        // Error FFI converter
+        UniffiNullRustCallStatusErrorHandler,
+    )
+    }
+
+    override fun `hasAnyTasks`(): kotlin.Boolean {
+            return FfiConverterBoolean.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_has_any_tasks(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    override fun `hasUnsyncedChanges`(): kotlin.Boolean {
+            return FfiConverterBoolean.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_has_unsynced_changes(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    
+    /**
+     * Bulk-restores an `.ics` file or JSON task array into `target_href`, journaling the
+     * creates so they sync. `conflict_copies` keeps UID duplicates as copies instead of
+     * skipping them. Returns a localized summary including any per-item errors.
+     */
+    @Throws(MobileException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `importIcs`(`data`: kotlin.String, `targetHref`: kotlin.String, `conflictCopies`: kotlin.Boolean) : kotlin.String {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_import_ics(
+                uniffiHandle,
+                
+        FfiConverterString.lower(`data`),
+        FfiConverterString.lower(`targetHref`),
+        FfiConverterBoolean.lower(`conflictCopies`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterString.lift(it) },
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
+
+    
+    @Throws(MobileException::class)override fun `importLocalIcs`(`calendarHref`: kotlin.String, `icsContent`: kotlin.String): kotlin.String {
+            return FfiConverterString.lift(
+    callWithHandle {
+    uniffiRustCallWithError(MobileException) { _status ->
+    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_import_local_ics(
+        it,
+        
+        FfiConverterString.lower(`calendarHref`),
+        FfiConverterString.lower(`icsContent`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(MobileException::class)override fun `isolateCalendar`(`href`: kotlin.String)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(MobileException) { _status ->
+    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_isolate_calendar(
+        it,
+        
+        FfiConverterString.lower(`href`),_status)
+}
+    }
+    
+    
+
+    override fun `loadFromCache`()
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_load_from_cache(
+        it,
+        _status)
+}
+    }
+    
+    
+
+    override fun `logMessage`(`level`: kotlin.String, `tag`: kotlin.String, `message`: kotlin.String)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_log_message(
+        it,
+        
+        FfiConverterString.lower(`level`),
+        FfiConverterString.lower(`tag`),
+        FfiConverterString.lower(`message`),_status)
+}
+    }
+    
+    
+
+    
+    @Throws(MobileException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `migrateLocalTo`(`sourceHref`: kotlin.String, `targetHref`: kotlin.String) : kotlin.String {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_migrate_local_to(
+                uniffiHandle,
+                
+        FfiConverterString.lower(`sourceHref`),
+        FfiConverterString.lower(`targetHref`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterString.lift(it) },
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
+
+    
+    @Throws(MobileException::class)override fun `moveCalendar`(`href`: kotlin.String, `direction`: kotlin.Byte)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(MobileException) { _status ->
+    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_move_calendar(
+        it,
+        
+        FfiConverterString.lower(`href`),
+        FfiConverterByte.lower(`direction`),_status)
+}
+    }
+    
+    
+
+    
+    @Throws(MobileException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `moveTask`(`uid`: kotlin.String, `newCalHref`: kotlin.String) {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_move_task(
+                uniffiHandle,
+                
+        FfiConverterString.lower(`uid`),
+        FfiConverterString.lower(`newCalHref`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
+        // lift function
+        { Unit },
+        
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
+
+    override fun `parseDurationString`(`val`: kotlin.String): kotlin.UInt? {
+            return FfiConverterOptionalUInt.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_parse_duration_string(
+        it,
+        
+        FfiConverterString.lower(`val`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `parseSmartString`(`input`: kotlin.String, `isSearch`: kotlin.Boolean): List<MobileSyntaxToken> {
+            return FfiConverterSequenceTypeMobileSyntaxToken.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_parse_smart_string(
+        it,
+        
+        FfiConverterString.lower(`input`),
+        FfiConverterBoolean.lower(`isSearch`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(MobileException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `pauseTask`(`uid`: kotlin.String) {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_pause_task(
+                uniffiHandle,
+                
+        FfiConverterString.lower(`uid`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
+        // lift function
+        { Unit },
+        
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
+
+    
+    @Throws(MobileException::class)override fun `removeAlias`(`key`: kotlin.String)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(MobileException) { _status ->
+    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_remove_alias(
+        it,
+        
+        FfiConverterString.lower(`key`),_status)
+}
+    }
+    
+    
+
+    
+    @Throws(MobileException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `removeDependency`(`taskUid`: kotlin.String, `blockerUid`: kotlin.String) {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_remove_dependency(
+                uniffiHandle,
+                
+        FfiConverterString.lower(`taskUid`),
+        FfiConverterString.lower(`blockerUid`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
+        // lift function
+        { Unit },
+        
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
+
+    
+    @Throws(MobileException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `removeRelatedTo`(`taskUid`: kotlin.String, `relatedUid`: kotlin.String) {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_remove_related_to(
+                uniffiHandle,
+                
+        FfiConverterString.lower(`taskUid`),
+        FfiConverterString.lower(`relatedUid`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
+        // lift function
+        { Unit },
+        
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Renames tag `from` (and its subtags) to `to` on every task and in the aliases.
+     */
+    @Throws(MobileException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `renameTag`(`from`: kotlin.String, `to`: kotlin.String) : MobileTagRename {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_rename_tag(
+                uniffiHandle,
+                
+        FfiConverterString.lower(`from`),
+        FfiConverterString.lower(`to`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterTypeMobileTagRename.lift(it) },
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
+
+    override fun `resolveSelectionAliases`(`selection`: kotlin.String, `isLocation`: kotlin.Boolean): List<kotlin.String> {
+            return FfiConverterSequenceString.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_resolve_selection_aliases(
+        it,
+        
+        FfiConverterString.lower(`selection`),
+        FfiConverterBoolean.lower(`isLocation`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    /**
+     * Re-queues the creation of a stale unsynced task; call `sync` afterwards.
+     */
+    @Throws(MobileException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `retryUnsynced`(`uid`: kotlin.String) {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_retry_unsynced(
+                uniffiHandle,
+                
+        FfiConverterString.lower(`uid`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
+        // lift function
+        { Unit },
+        
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
+
+    
+    @Throws(MobileException::class)override fun `saveConfig`(`config`: MobileConfig)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(MobileException) { _status ->
+    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_save_config(
+        it,
+        
+        FfiConverterTypeMobileConfig.lower(`config`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Changes only a remote calendar's color. Returns `false` when the server does not
+     * store `calendar-color` (or the app is offline); the color is then kept in the local
+     * `calendar_overrides` config instead.
+     */
+    @Throws(MobileException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `setCalendarColor`(`href`: kotlin.String, `color`: kotlin.String?) : kotlin.Boolean {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_set_calendar_color(
+                uniffiHandle,
+                
+        FfiConverterString.lower(`href`),
+        FfiConverterOptionalString.lower(`color`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_i8(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_i8(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_i8(future) },
+        // lift function
+        { FfiConverterBoolean.lift(it) },
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Sets the device-local display name and color for a calendar; `None` (or blank) falls
+     * back to the server value. `get_calendars` reports the overridden values.
+     */
+    @Throws(MobileException::class)override fun `setCalendarOverride`(`href`: kotlin.String, `name`: kotlin.String?, `color`: kotlin.String?)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(MobileException) { _status ->
+    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_set_calendar_override(
+        it,
+        
+        FfiConverterString.lower(`href`),
+        FfiConverterOptionalString.lower(`name`),
+        FfiConverterOptionalString.lower(`color`),_status)
+}
+    }
+    
+    
+
+    
+    @Throws(MobileException::class)override fun `setCalendarVisibility`(`href`: kotlin.String, `visible`: kotlin.Boolean)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(MobileException) { _status ->
+    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_set_calendar_visibility(
+        it,
+        
+        FfiConverterString.lower(`href`),
+        FfiConverterBoolean.lower(`visible`),_status)
+}
+    }
+    
+    
+
+    
+    @Throws(MobileException::class)override fun `setDefaultCalendar`(`href`: kotlin.String)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(MobileException) { _status ->
+    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_set_default_calendar(
+        it,
+        
+        FfiConverterString.lower(`href`),_status)
+}
+    }
+    
+    
+
+    override fun `setLocale`(`locale`: kotlin.String)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_set_locale(
+        it,
+        
+        FfiConverterString.lower(`locale`),_status)
+}
+    }
+    
+    
+
+    
+    @Throws(MobileException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `setParent`(`childUid`: kotlin.String, `parentUid`: kotlin.String?) {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_set_parent(
+                uniffiHandle,
+                
+        FfiConverterString.lower(`childUid`),
+        FfiConverterOptionalString.lower(`parentUid`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
+        // lift function
+        { Unit },
+        
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
+
+    
+    @Throws(MobileException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `setProgress`(`uid`: kotlin.String, `percent`: kotlin.UByte) {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_set_progress(
+                uniffiHandle,
+                
+        FfiConverterString.lower(`uid`),
+        FfiConverterUByte.lower(`percent`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
+        // lift function
+        { Unit },
+        
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
+
+    
+    @Throws(MobileException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `setStatusCancelled`(`uid`: kotlin.String) {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_set_status_cancelled(
+                uniffiHandle,
+                
+        FfiConverterString.lower(`uid`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
+        // lift function
+        { Unit },
+        
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
+
+    
+    @Throws(MobileException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `setStatusProcess`(`uid`: kotlin.String) {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_set_status_process(
+                uniffiHandle,
+                
+        FfiConverterString.lower(`uid`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
+        // lift function
+        { Unit },
+        
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
+
+    
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `shouldKeepNotification`(`taskUid`: kotlin.String, `notifType`: kotlin.String, `alarmUid`: kotlin.String?) : kotlin.Boolean {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_should_keep_notification(
+                uniffiHandle,
+                
+        FfiConverterString.lower(`taskUid`),
+        FfiConverterString.lower(`notifType`),
+        FfiConverterOptionalString.lower(`alarmUid`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_i8(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_i8(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_i8(future) },
+        // lift function
+        { FfiConverterBoolean.lift(it) },
+        // Error FFI converter
+        UniffiNullRustCallStatusErrorHandler,
+    )
+    }
+
+    
+    @Throws(MobileException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `skipOccurrence`(`uid`: kotlin.String) {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_skip_occurrence(
+                uniffiHandle,
+                
+        FfiConverterString.lower(`uid`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
+        // lift function
+        { Unit },
+        
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
+
+    
+    @Throws(MobileException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `snoozeAlarm`(`taskUid`: kotlin.String, `alarmUid`: kotlin.String, `minutes`: kotlin.UInt) {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_snooze_alarm(
+                uniffiHandle,
+                
+        FfiConverterString.lower(`taskUid`),
+        FfiConverterString.lower(`alarmUid`),
+        FfiConverterUInt.lower(`minutes`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
+        // lift function
+        { Unit },
+        
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
+
+    
+    /**
+     * Starts the in-process alarm actor (the same one the desktop clients run) and
+     * delivers due alarms to `listener`. The task set is refreshed after every sync
+     * and mutation. Calling it again replaces the previous actor and listener.
+     */override fun `startAlarmActor`(`listener`: MobileAlarmListener)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_start_alarm_actor(
+        it,
+        
+        FfiConverterTypeMobileAlarmListener.lower(`listener`),_status)
+}
+    }
+    
+    
+
+    
+    @Throws(MobileException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `startTask`(`uid`: kotlin.String) {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_start_task(
+                uniffiHandle,
+                
+        FfiConverterString.lower(`uid`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
+        // lift function
+        { Unit },
+        
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
+
+    
+    @Throws(MobileException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `stopTask`(`uid`: kotlin.String) {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_stop_task(
+                uniffiHandle,
+                
+        FfiConverterString.lower(`uid`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
+        // lift function
+        { Unit },
+        
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
+
+    
+    @Throws(MobileException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `sync`() : MobileSyncResult {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_sync(
+                uniffiHandle,
+                
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterTypeMobileSyncResult.lift(it) },
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
+
+    
+    @Throws(MobileException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `syncJournal`() : kotlin.Boolean {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_sync_journal(
+                uniffiHandle,
+                
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_i8(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_i8(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_i8(future) },
+        // lift function
+        { FfiConverterBoolean.lift(it) },
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
+
+    
+    @Throws(MobileException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `syncTaskTreeFromMarkdown`(`uid`: kotlin.String, `markdown`: kotlin.String) {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_sync_task_tree_from_markdown(
+                uniffiHandle,
+                
+        FfiConverterString.lower(`uid`),
+        FfiConverterString.lower(`markdown`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
+        // lift function
+        { Unit },
+        
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
+
+    
+    @Throws(MobileException::class)override fun `toggleAllCalendars`(`showAll`: kotlin.Boolean)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(MobileException) { _status ->
+    UniffiLib.uniffi_cfait_fn_method_cfaitmobile_toggle_all_calendars(
+        it,
+        
+        FfiConverterBoolean.lower(`showAll`),_status)
+}
+    }
+    
+    
+
+    
+    @Throws(MobileException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `togglePin`(`uid`: kotlin.String) {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_toggle_pin(
+                uniffiHandle,
+                
+        FfiConverterString.lower(`uid`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
+        // lift function
+        { Unit },
+        
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
+
+    
+    @Throws(MobileException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `toggleTask`(`uid`: kotlin.String) {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_toggle_task(
+                uniffiHandle,
+                
+        FfiConverterString.lower(`uid`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
+        // lift function
+        { Unit },
+        
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
+
+    
+    @Throws(MobileException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `toggleTaskShift`(`uid`: kotlin.String) {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_toggle_task_shift(
+                uniffiHandle,
+                
+        FfiConverterString.lower(`uid`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
+        // lift function
+        { Unit },
+        
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
+
+    
+    @Throws(MobileException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `toggleWaiting`(`uid`: kotlin.String) {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_toggle_waiting(
+                uniffiHandle,
+                
+        FfiConverterString.lower(`uid`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
+        // lift function
+        { Unit },
+        
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
+
+    
+    @Throws(MobileException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `updateLocalCalendar`(`href`: kotlin.String, `name`: kotlin.String, `color`: kotlin.String?) {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_update_local_calendar(
+                uniffiHandle,
+                
+        FfiConverterString.lower(`href`),
+        FfiConverterString.lower(`name`),
+        FfiConverterOptionalString.lower(`color`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
+        // lift function
+        { Unit },
+        
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
+
+    
+    @Throws(MobileException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `updateRemoteCalendar`(`href`: kotlin.String, `name`: kotlin.String, `color`: kotlin.String?) {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_update_remote_calendar(
+                uniffiHandle,
+                
+        FfiConverterString.lower(`href`),
+        FfiConverterString.lower(`name`),
+        FfiConverterOptionalString.lower(`color`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
+        // lift function
+        { Unit },
+        
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
+
+    
+    @Throws(MobileException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `updateTaskDescription`(`uid`: kotlin.String, `description`: kotlin.String) {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_update_task_description(
+                uniffiHandle,
+                
+        FfiConverterString.lower(`uid`),
+        FfiConverterString.lower(`description`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
+        // lift function
+        { Unit },
+        
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
+
+    
+    @Throws(MobileException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `updateTaskSmart`(`uid`: kotlin.String, `smartInput`: kotlin.String) {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_update_task_smart(
+                uniffiHandle,
+                
+        FfiConverterString.lower(`uid`),
+        FfiConverterString.lower(`smartInput`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
+        // lift function
+        { Unit },
+        
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
+
+    
+    @Throws(MobileException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `yankTask`(`uid`: kotlin.String) {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_cfait_fn_method_cfaitmobile_yank_task(
+                uniffiHandle,
+                
+        FfiConverterString.lower(`uid`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
+        { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
+        // lift function
+        { Unit },
+        
+        // Error FFI converter
+        MobileException.ErrorHandler,
+    )
+    }
+
+    
+
+    
+
+
+    
+    
+    /**
+     * @suppress
+     */
+    companion object
+    
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeCfaitMobile: FfiConverter<CfaitMobile, Long> {
+    override fun lower(value: CfaitMobile): Long {
+        return value.uniffiCloneHandle()
+    }
+
+    override fun lift(value: Long): CfaitMobile {
+        return CfaitMobile(UniffiWithHandle, value)
+    }
+
+    override fun read(buf: ByteBuffer): CfaitMobile {
+        return lift(buf.getLong())
+    }
+
+    override fun allocationSize(value: CfaitMobile) = 8UL
+
+    override fun write(value: CfaitMobile, buf: ByteBuffer) {
+        buf.putLong(lower(value))
+    }
+}
+
+
+// This template implements a class for working with a Rust struct via a handle
+// to the live Rust struct on the other side of the FFI.
+//
+// There's some subtlety here, because we have to be careful not to operate on a Rust
+// struct after it has been dropped, and because we must expose a public API for freeing
+// theq Kotlin wrapper object in lieu of reliable finalizers. The core requirements are:
+//
+//   * Each instance holds an opaque handle to the underlying Rust struct.
+//     Method calls need to read this handle from the object's state and pass it in to
+//     the Rust FFI.
+//
+//   * When an instance is no longer needed, its handle should be passed to a
+//     special destructor function provided by the Rust FFI, which will drop the
+//     underlying Rust struct.
+//
+//   * Given an instance, calling code is expected to call the special
+//     `destroy` method in order to free it after use, either by calling it explicitly
+//     or by using a higher-level helper like the `use` method. Failing to do so risks
+//     leaking the underlying Rust struct.
+//
+//   * We can't assume that calling code will do the right thing, and must be prepared
+//     to handle Kotlin method calls executing concurrently with or even after a call to
+//     `destroy`, and to handle multiple (possibly concurrent!) calls to `destroy`.
+//
+//   * We must never allow Rust code to operate on the underlying Rust struct after
+//     the destructor has been called, and must never call the destructor more than once.
+//     Doing so may trigger memory unsafety.
+//
+//   * To mitigate many of the risks of leaking memory and use-after-free unsafety, a `Cleaner`
+//     is implemented to call the destructor when the Kotlin object becomes unreachable.
+//     This is done in a background thread. This is not a panacea, and client code should be aware that
+//      1. the thread may starve if some there are objects that have poorly performing
+//     `drop` methods or do significant work in their `drop` methods.
+//      2. the thread is shared across the whole library. This can be tuned by using `android_cleaner = true`,
+//         or `android = true` in the [`kotlin` section of the `uniffi.toml` file](https://mozilla.github.io/uniffi-rs/kotlin/configuration.html).
+//
+// If we try to implement this with mutual exclusion on access to the handle, there is the
+// possibility of a race between a method call and a concurrent call to `destroy`:
+//
+//    * Thread A starts a method call, reads the value of the handle, but is interrupted
+//      before it can pass the handle over the FFI to Rust.
+//    * Thread B calls `destroy` and frees the underlying Rust struct.
+//    * Thread A resumes, passing the already-read handle value to Rust and triggering
+//      a use-after-free.
+//
+// One possible solution would be to use a `ReadWriteLock`, with each method call taking
+// a read lock (and thus allowed to run concurrently) and the special `destroy` method
+// taking a write lock (and thus blocking on live method calls). However, we aim not to
+// generate methods with any hidden blocking semantics, and a `destroy` method that might
+// block if called incorrectly seems to meet that bar.
+//
+// So, we achieve our goals by giving each instance an associated `AtomicLong` counter to track
+// the number of in-flight method calls, and an `AtomicBoolean` flag to indicate whether `destroy`
+// has been called. These are updated according to the following rules:
+//
+//    * The initial value of the counter is 1, indicating a live object with no in-flight calls.
+//      The initial value for the flag is false.
+//
+//    * At the start of each method call, we atomically check the counter.
+//      If it is 0 then the underlying Rust struct has already been destroyed and the call is aborted.
+//      If it is nonzero them we atomically increment it by 1 and proceed with the method call.
+//
+//    * At the end of each method call, we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+//    * When `destroy` is called, we atomically flip the flag from false to true.
+//      If the flag was already true we silently fail.
+//      Otherwise we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+// Astute readers may observe that this all sounds very similar to the way that Rust's `Arc<T>` works,
+// and indeed it is, with the addition of a flag to guard against multiple calls to `destroy`.
+//
+// The overall effect is that the underlying Rust struct is destroyed only when `destroy` has been
+// called *and* all in-flight method calls have completed, avoiding violating any of the expectations
+// of the underlying Rust code.
+//
+// This makes a cleaner a better alternative to _not_ calling `destroy()` as
+// and when the object is finished with, but the abstraction is not perfect: if the Rust object's `drop`
+// method is slow, and/or there are many objects to cleanup, and it's on a low end Android device, then the cleaner
+// thread may be starved, and the app will leak memory.
+//
+// In this case, `destroy`ing manually may be a better solution.
+//
+// The cleaner can live side by side with the manual calling of `destroy`. In the order of responsiveness, uniffi objects
+// with Rust peers are reclaimed:
+//
+// 1. By calling the `destroy` method of the object, which calls `rustObject.free()`. If that doesn't happen:
+// 2. When the object becomes unreachable, AND the Cleaner thread gets to call `rustObject.free()`. If the thread is starved then:
+// 3. The memory is reclaimed when the process terminates.
+//
+// [1] https://stackoverflow.com/questions/24376768/can-java-finalize-an-object-when-it-is-still-in-scope/24380219
+//
+
+
+/**
+ * Implemented by the app to post system notifications for alarms fired by the
+ * in-process alarm actor (see `CfaitMobile::start_alarm_actor`).
+ */
+public interface MobileAlarmListener {
+    
+    fun `onAlarm`(`alarm`: MobileAlarmInfo)
+    
+    /**
+     * The actor wants fresh data before an alarm fires (just-in-time sync).
+     */
+    fun `onSyncRequested`()
+    
+    companion object
+}
+
+/**
+ * Implemented by the app to post system notifications for alarms fired by the
+ * in-process alarm actor (see `CfaitMobile::start_alarm_actor`).
+ */
+open class MobileAlarmListenerImpl: Disposable, AutoCloseable, MobileAlarmListener
+{
+
+    @Suppress("UNUSED_PARAMETER")
+    /**
+     * @suppress
+     */
+    constructor(withHandle: UniffiWithHandle, handle: Long) {
+        this.handle = handle
+        this.cleanable = UniffiLib.CLEANER.register(this, UniffiCleanAction(handle))
+    }
+
+    /**
+     * @suppress
+     *
+     * This constructor can be used to instantiate a fake object. Only used for tests. Any
+     * attempt to actually use an object constructed this way will fail as there is no
+     * connected Rust object.
+     */
+    @Suppress("UNUSED_PARAMETER")
+    constructor(noHandle: NoHandle) {
+        this.handle = 0
+        this.cleanable = null
+    }
+
+    protected val handle: Long
+    protected val cleanable: UniffiCleaner.Cleanable?
+
+    private val wasDestroyed = AtomicBoolean(false)
+    private val callCounter = AtomicLong(1)
+
+    /**
+     * Whether the current object has been destroyed and its reference is gone in the Rust side.
+     */
+    val uniffiIsDestroyed: Boolean get() = wasDestroyed.get()
+
+    override fun destroy() {
+        // Only allow a single call to this method.
+        // TODO: maybe we should log a warning if called more than once?
+        if (this.wasDestroyed.compareAndSet(false, true)) {
+            // This decrement always matches the initial count of 1 given at creation time.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable?.clean()
+            }
+        }
+    }
+
+    @Synchronized
+    override fun close() {
+        this.destroy()
+    }
+
+    internal inline fun <R> callWithHandle(block: (handle: Long) -> R): R {
+        // Check and increment the call counter, to keep the object alive.
+        // This needs a compare-and-set retry loop in case of concurrent updates.
+        do {
+            val c = this.callCounter.get()
+            if (c == 0L) {
+                throw IllegalStateException("${this.javaClass.simpleName} object has already been destroyed")
+            }
+            if (c == Long.MAX_VALUE) {
+                throw IllegalStateException("${this.javaClass.simpleName} call counter would overflow")
+            }
+        } while (! this.callCounter.compareAndSet(c, c + 1L))
+        // Now we can safely do the method call without the handle being freed concurrently.
+        try {
+            return block(this.uniffiCloneHandle())
+        } finally {
+            // This decrement always matches the increment we performed above.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable?.clean()
+            }
+        }
+    }
+
+    // Use a static inner class instead of a closure so as not to accidentally
+    // capture `this` as part of the cleanable's action.
+    private class UniffiCleanAction(private val handle: Long) : Runnable {
+        override fun run() {
+            if (handle == 0.toLong()) {
+                // Fake object created with `NoHandle`, don't try to free.
+                return;
+            }
+            uniffiRustCall { status ->
+                UniffiLib.uniffi_cfait_fn_free_mobilealarmlistener(handle, status)
+            }
+        }
+    }
+
+    /**
+     * @suppress
+     */
+    fun uniffiCloneHandle(): Long {
+        if (handle == 0.toLong()) {
+            throw InternalException("uniffiCloneHandle() called on NoHandle object");
+        }
+        return uniffiRustCall() { status ->
+            UniffiLib.uniffi_cfait_fn_clone_mobilealarmlistener(handle, status)
+        }
+    }
+
+    override fun `onAlarm`(`alarm`: MobileAlarmInfo)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_cfait_fn_method_mobilealarmlistener_on_alarm(
+        it,
+        
+        FfiConverterTypeMobileAlarmInfo.lower(`alarm`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * The actor wants fresh data before an alarm fires (just-in-time sync).
+     */override fun `onSyncRequested`()
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_cfait_fn_method_mobilealarmlistener_on_sync_requested(
+        it,
+        _status)
+}
+    }
+    
+    
+
+    
+
+    
 
-    @Throws(MobileException::class)
-    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `yankTask`(`uid`: kotlin.String) =
-        uniffiRustCallAsync(
-            callWithHandle { uniffiHandle ->
-                UniffiLib.uniffi_cfait_fn_method_cfaitmobile_yank_task(
-                    uniffiHandle,
-                    FfiConverterString.lower(`uid`),
-                )
-            },
-            { future, callback, continuation -> UniffiLib.ffi_cfait_rust_future_poll_void(future, callback, continuation) },
-            { future, continuation -> UniffiLib.ffi_cfait_rust_future_complete_void(future, continuation) },
-            { future -> UniffiLib.ffi_cfait_rust_future_free_void(future) },
-            // lift function
-            { Unit },
-            // Error FFI converter
-            MobileException.ErrorHandler,
-        )
 
+    
+    
     /**
      * @suppress
      */
     companion object
+    
+}
+
+
+
+// Put the implementation in an object so we don't pollute the top-level namespace
+internal object uniffiCallbackInterfaceMobileAlarmListener {
+    internal object `onAlarm`: UniffiCallbackInterfaceMobileAlarmListenerMethod0 {
+        override fun callback(`uniffiHandle`: Long,`alarm`: RustBuffer.ByValue,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeMobileAlarmListener.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`onAlarm`(
+                    FfiConverterTypeMobileAlarmInfo.lift(`alarm`),
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+    internal object `onSyncRequested`: UniffiCallbackInterfaceMobileAlarmListenerMethod1 {
+        override fun callback(`uniffiHandle`: Long,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeMobileAlarmListener.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`onSyncRequested`(
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+
+    internal object uniffiFree: UniffiCallbackInterfaceFree {
+        override fun callback(handle: Long) {
+            FfiConverterTypeMobileAlarmListener.handleMap.remove(handle)
+        }
+    }
+
+    internal object uniffiClone: UniffiCallbackInterfaceClone {
+        override fun callback(handle: Long): Long {
+            return FfiConverterTypeMobileAlarmListener.handleMap.clone(handle)
+        }
+    }
+
+    internal var vtable = UniffiVTableCallbackInterfaceMobileAlarmListener.UniffiByValue(
+        uniffiFree,
+        uniffiClone,
+        `onAlarm`,
+        `onSyncRequested`,
+    )
+
+    // Registers the foreign callback with the Rust side.
+    // This method is generated for each callback interface.
+    internal fun register(lib: UniffiLib) {
+        lib.uniffi_cfait_fn_init_callback_vtable_mobilealarmlistener(vtable)
+    }
 }
 
 /**
  * @suppress
  */
-public object FfiConverterTypeCfaitMobile : FfiConverter<CfaitMobile, Long> {
-    override fun lower(value: CfaitMobile): Long = value.uniffiCloneHandle()
-
-    override fun lift(value: Long): CfaitMobile = CfaitMobile(UniffiWithHandle, value)
+public object FfiConverterTypeMobileAlarmListener: FfiConverter<MobileAlarmListener, Long> {
+    internal val handleMap = UniffiHandleMap<MobileAlarmListener>()
+
+    override fun lower(value: MobileAlarmListener): Long {
+        if (value is MobileAlarmListenerImpl) {
+             // Rust-implemented object.  Clone the handle and return it
+            return value.uniffiCloneHandle()
+         } else {
+            // Kotlin object, generate a new vtable handle and return that.
+            return handleMap.insert(value)
+         }
+    }
+
+    override fun lift(value: Long): MobileAlarmListener {
+        if ((value and 1.toLong()) == 0.toLong()) {
+            // Rust-generated handle, construct a new class that uses the handle to implement the
+            // interface
+            return MobileAlarmListenerImpl(UniffiWithHandle, value)
+        } else {
+            // Kotlin-generated handle, get the object from the handle map
+            return handleMap.remove(value)
+        }
+    }
 
-    override fun read(buf: ByteBuffer): CfaitMobile = lift(buf.getLong())
+    override fun read(buf: ByteBuffer): MobileAlarmListener {
+        return lift(buf.getLong())
+    }
 
-    override fun allocationSize(value: CfaitMobile) = 8UL
+    override fun allocationSize(value: MobileAlarmListener) = 8UL
 
-    override fun write(
-        value: CfaitMobile,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: MobileAlarmListener, buf: ByteBuffer) {
         buf.putLong(lower(value))
     }
 }
 
-data class MobileAlarmInfo(
-    var `taskUid`: kotlin.String,
-    var `alarmUid`: kotlin.String,
-    var `title`: kotlin.String,
-    var `body`: kotlin.String,
-) {
+
+
+data class MobileAlarm (
+    var `uid`: kotlin.String
+    , 
+    /**
+     * "relative" (offset from due/start) or "absolute".
+     */
+    var `triggerType`: kotlin.String
+    , 
+    var `offsetMinutes`: kotlin.Int?
+    , 
+    var `timeIso`: kotlin.String?
+    , 
+    var `description`: kotlin.String?
+    , 
+    var `isAcknowledged`: kotlin.Boolean
+    , 
+    var `isSnooze`: kotlin.Boolean
+    
+){
+    
+
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeMobileAlarm: FfiConverterRustBuffer<MobileAlarm> {
+    override fun read(buf: ByteBuffer): MobileAlarm {
+        return MobileAlarm(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterOptionalInt.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: MobileAlarm) = (
+            FfiConverterString.allocationSize(value.`uid`) +
+            FfiConverterString.allocationSize(value.`triggerType`) +
+            FfiConverterOptionalInt.allocationSize(value.`offsetMinutes`) +
+            FfiConverterOptionalString.allocationSize(value.`timeIso`) +
+            FfiConverterOptionalString.allocationSize(value.`description`) +
+            FfiConverterBoolean.allocationSize(value.`isAcknowledged`) +
+            FfiConverterBoolean.allocationSize(value.`isSnooze`)
+    )
+
+    override fun write(value: MobileAlarm, buf: ByteBuffer) {
+            FfiConverterString.write(value.`uid`, buf)
+            FfiConverterString.write(value.`triggerType`, buf)
+            FfiConverterOptionalInt.write(value.`offsetMinutes`, buf)
+            FfiConverterOptionalString.write(value.`timeIso`, buf)
+            FfiConverterOptionalString.write(value.`description`, buf)
+            FfiConverterBoolean.write(value.`isAcknowledged`, buf)
+            FfiConverterBoolean.write(value.`isSnooze`, buf)
+    }
+}
+
+
+
+data class MobileAlarmInfo (
+    var `taskUid`: kotlin.String
+    , 
+    var `alarmUid`: kotlin.String
+    , 
+    var `title`: kotlin.String
+    , 
+    var `body`: kotlin.String
+    
+){
+    
+
+    
+
+    
     companion object
 }
 
 /**
  * @suppress
  */
-public object FfiConverterTypeMobileAlarmInfo : FfiConverterRustBuffer<MobileAlarmInfo> {
-    override fun read(buf: ByteBuffer): MobileAlarmInfo =
-        MobileAlarmInfo(
+public object FfiConverterTypeMobileAlarmInfo: FfiConverterRustBuffer<MobileAlarmInfo> {
+    override fun read(buf: ByteBuffer): MobileAlarmInfo {
+        return MobileAlarmInfo(
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
         )
+    }
 
-    override fun allocationSize(value: MobileAlarmInfo) =
-        (
+    override fun allocationSize(value: MobileAlarmInfo) = (
             FfiConverterString.allocationSize(value.`taskUid`) +
-                FfiConverterString.allocationSize(value.`alarmUid`) +
-                FfiConverterString.allocationSize(value.`title`) +
-                FfiConverterString.allocationSize(value.`body`)
-        )
+            FfiConverterString.allocationSize(value.`alarmUid`) +
+            FfiConverterString.allocationSize(value.`title`) +
+            FfiConverterString.allocationSize(value.`body`)
+    )
 
-    override fun write(
-        value: MobileAlarmInfo,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`taskUid`, buf)
-        FfiConverterString.write(value.`alarmUid`, buf)
-        FfiConverterString.write(value.`title`, buf)
-        FfiConverterString.write(value.`body`, buf)
+    override fun write(value: MobileAlarmInfo, buf: ByteBuffer) {
+            FfiConverterString.write(value.`taskUid`, buf)
+            FfiConverterString.write(value.`alarmUid`, buf)
+            FfiConverterString.write(value.`title`, buf)
+            FfiConverterString.write(value.`body`, buf)
     }
 }
 
-data class MobileCalendar(
-    var `name`: kotlin.String,
-    var `href`: kotlin.String,
-    var `color`: kotlin.String?,
-    var `isVisible`: kotlin.Boolean,
-    var `isLocal`: kotlin.Boolean,
-    var `isDisabled`: kotlin.Boolean,
-) {
+
+
+data class MobileCalendar (
+    var `name`: kotlin.String
+    , 
+    var `href`: kotlin.String
+    , 
+    var `color`: kotlin.String?
+    , 
+    var `isVisible`: kotlin.Boolean
+    , 
+    var `isLocal`: kotlin.Boolean
+    , 
+    var `isDisabled`: kotlin.Boolean
+    
+){
+    
+
+    
+
+    
     companion object
 }
 
 /**
  * @suppress
  */
-public object FfiConverterTypeMobileCalendar : FfiConverterRustBuffer<MobileCalendar> {
-    override fun read(buf: ByteBuffer): MobileCalendar =
-        MobileCalendar(
+public object FfiConverterTypeMobileCalendar: FfiConverterRustBuffer<MobileCalendar> {
+    override fun read(buf: ByteBuffer): MobileCalendar {
+        return MobileCalendar(
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
             FfiConverterOptionalString.read(buf),
@@ -4299,82 +5095,131 @@ public object FfiConverterTypeMobileCalendar : FfiConverterRustBuffer<MobileCale
             FfiConverterBoolean.read(buf),
             FfiConverterBoolean.read(buf),
         )
+    }
 
-    override fun allocationSize(value: MobileCalendar) =
-        (
+    override fun allocationSize(value: MobileCalendar) = (
             FfiConverterString.allocationSize(value.`name`) +
-                FfiConverterString.allocationSize(value.`href`) +
-                FfiConverterOptionalString.allocationSize(value.`color`) +
-                FfiConverterBoolean.allocationSize(value.`isVisible`) +
-                FfiConverterBoolean.allocationSize(value.`isLocal`) +
-                FfiConverterBoolean.allocationSize(value.`isDisabled`)
-        )
+            FfiConverterString.allocationSize(value.`href`) +
+            FfiConverterOptionalString.allocationSize(value.`color`) +
+            FfiConverterBoolean.allocationSize(value.`isVisible`) +
+            FfiConverterBoolean.allocationSize(value.`isLocal`) +
+            FfiConverterBoolean.allocationSize(value.`isDisabled`)
+    )
 
-    override fun write(
-        value: MobileCalendar,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`name`, buf)
-        FfiConverterString.write(value.`href`, buf)
-        FfiConverterOptionalString.write(value.`color`, buf)
-        FfiConverterBoolean.write(value.`isVisible`, buf)
-        FfiConverterBoolean.write(value.`isLocal`, buf)
-        FfiConverterBoolean.write(value.`isDisabled`, buf)
-    }
-}
-
-data class MobileConfig(
-    var `url`: kotlin.String,
-    var `username`: kotlin.String,
-    var `password`: kotlin.String,
-    var `tlsClientCertPath`: kotlin.String?,
-    var `tlsClientKeyPath`: kotlin.String?,
-    var `defaultCalendar`: kotlin.String?,
-    var `allowInsecure`: kotlin.Boolean,
-    var `hideCompleted`: kotlin.Boolean,
-    var `hideAliasesInSidebar`: kotlin.Boolean,
-    var `tagAliases`: Map<kotlin.String, List<kotlin.String>>,
-    var `disabledCalendars`: List<kotlin.String>,
-    var `sortCutoffDays`: kotlin.UInt?,
-    var `sortStandardByPriority`: kotlin.Boolean,
-    var `sortPreset`: kotlin.String,
-    var `urgentDays`: kotlin.UInt,
-    var `urgentPrio`: kotlin.UByte,
-    var `defaultPriority`: kotlin.UByte,
-    var `startGracePeriodDays`: kotlin.UInt,
-    var `autoReminders`: kotlin.Boolean,
-    var `defaultReminderTime`: kotlin.String,
-    var `snoozeShort`: kotlin.UInt,
-    var `createEventsForTasks`: kotlin.Boolean,
-    var `deleteEventsOnCompletion`: kotlin.Boolean,
-    var `autoRefreshInterval`: kotlin.UInt,
-    var `trashRetention`: kotlin.UInt,
-    var `maxDoneRoots`: kotlin.UInt,
-    var `maxDoneSubtasks`: kotlin.UInt,
-    var `showOngoingNotifications`: kotlin.Boolean,
-    var `showQuickFilter`: kotlin.Boolean,
-    var `quickFilterTerm`: kotlin.String,
-    var `quickFilterIcon`: kotlin.String,
-    var `syncSettings`: kotlin.Boolean,
-    var `goals`: Map<kotlin.String, MobileGoal>,
-    var `defaultDurationGoalMins`: kotlin.UInt,
-    var `sessionsCountAsCompletions`: kotlin.Boolean,
-    var `showGoalsTab`: kotlin.Boolean,
-    var `showTaskGoalsInSidebar`: kotlin.Boolean,
-    var `sortCollectionsBySize`: kotlin.Boolean,
-    var `expandedTags`: List<kotlin.String>,
-    var `expandedLocations`: List<kotlin.String>,
-    var `expandedDoneGroups`: List<kotlin.String>,
-) {
+    override fun write(value: MobileCalendar, buf: ByteBuffer) {
+            FfiConverterString.write(value.`name`, buf)
+            FfiConverterString.write(value.`href`, buf)
+            FfiConverterOptionalString.write(value.`color`, buf)
+            FfiConverterBoolean.write(value.`isVisible`, buf)
+            FfiConverterBoolean.write(value.`isLocal`, buf)
+            FfiConverterBoolean.write(value.`isDisabled`, buf)
+    }
+}
+
+
+
+data class MobileConfig (
+    var `url`: kotlin.String
+    , 
+    var `username`: kotlin.String
+    , 
+    var `password`: kotlin.String
+    , 
+    var `tlsClientCertPath`: kotlin.String?
+    , 
+    var `tlsClientKeyPath`: kotlin.String?
+    , 
+    var `defaultCalendar`: kotlin.String?
+    , 
+    var `allowInsecure`: kotlin.Boolean
+    , 
+    var `limitFetchToCutoff`: kotlin.Boolean
+    , 
+    var `hideCompleted`: kotlin.Boolean
+    , 
+    var `hideAliasesInSidebar`: kotlin.Boolean
+    , 
+    var `tagAliases`: Map<kotlin.String, List<kotlin.String>>
+    , 
+    var `disabledCalendars`: List<kotlin.String>
+    , 
+    var `sortCutoffDays`: kotlin.UInt?
+    , 
+    var `sortStandardByPriority`: kotlin.Boolean
+    , 
+    var `sortPreset`: kotlin.String
+    , 
+    var `urgentDays`: kotlin.UInt
+    , 
+    var `urgentPrio`: kotlin.UByte
+    , 
+    var `defaultPriority`: kotlin.UByte
+    , 
+    var `startGracePeriodDays`: kotlin.UInt
+    , 
+    var `autoReminders`: kotlin.Boolean
+    , 
+    var `defaultReminderTime`: kotlin.String
+    , 
+    var `snoozeShort`: kotlin.UInt
+    , 
+    var `createEventsForTasks`: kotlin.Boolean
+    , 
+    var `deleteEventsOnCompletion`: kotlin.Boolean
+    , 
+    var `autoRefreshInterval`: kotlin.UInt
+    , 
+    var `trashRetention`: kotlin.UInt
+    , 
+    var `maxDoneRoots`: kotlin.UInt
+    , 
+    var `maxDoneSubtasks`: kotlin.UInt
+    , 
+    var `showOngoingNotifications`: kotlin.Boolean
+    , 
+    var `showQuickFilter`: kotlin.Boolean
+    , 
+    var `quickFilterTerm`: kotlin.String
+    , 
+    var `quickFilterIcon`: kotlin.String
+    , 
+    var `syncSettings`: kotlin.Boolean
+    , 
+    var `goals`: Map<kotlin.String, MobileGoal>
+    , 
+    var `defaultDurationGoalMins`: kotlin.UInt
+    , 
+    var `sessionsCountAsCompletions`: kotlin.Boolean
+    , 
+    var `archiveCompleted`: kotlin.Boolean
+    , 
+    var `showGoalsTab`: kotlin.Boolean
+    , 
+    var `showTaskGoalsInSidebar`: kotlin.Boolean
+    , 
+    var `sortCollectionsBySize`: kotlin.Boolean
+    , 
+    var `expandedTags`: List<kotlin.String>
+    , 
+    var `expandedLocations`: List<kotlin.String>
+    , 
+    var `expandedDoneGroups`: List<kotlin.String>
+    
+){
+    
+
+    
+
+    
     companion object
 }
 
 /**
  * @suppress
  */
-public object FfiConverterTypeMobileConfig : FfiConverterRustBuffer<MobileConfig> {
-    override fun read(buf: ByteBuffer): MobileConfig =
-        MobileConfig(
+public object FfiConverterTypeMobileConfig: FfiConverterRustBuffer<MobileConfig> {
+    override fun read(buf: ByteBuffer): MobileConfig {
+        return MobileConfig(
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
@@ -4384,6 +5229,7 @@ public object FfiConverterTypeMobileConfig : FfiConverterRustBuffer<MobileConfig
             FfiConverterBoolean.read(buf),
             FfiConverterBoolean.read(buf),
             FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
             FfiConverterMapStringSequenceString.read(buf),
             FfiConverterSequenceString.read(buf),
             FfiConverterOptionalUInt.read(buf),
@@ -4413,122 +5259,200 @@ public object FfiConverterTypeMobileConfig : FfiConverterRustBuffer<MobileConfig
             FfiConverterBoolean.read(buf),
             FfiConverterBoolean.read(buf),
             FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
             FfiConverterSequenceString.read(buf),
             FfiConverterSequenceString.read(buf),
             FfiConverterSequenceString.read(buf),
         )
+    }
 
-    override fun allocationSize(value: MobileConfig) =
-        (
+    override fun allocationSize(value: MobileConfig) = (
             FfiConverterString.allocationSize(value.`url`) +
-                FfiConverterString.allocationSize(value.`username`) +
-                FfiConverterString.allocationSize(value.`password`) +
-                FfiConverterOptionalString.allocationSize(value.`tlsClientCertPath`) +
-                FfiConverterOptionalString.allocationSize(value.`tlsClientKeyPath`) +
-                FfiConverterOptionalString.allocationSize(value.`defaultCalendar`) +
-                FfiConverterBoolean.allocationSize(value.`allowInsecure`) +
-                FfiConverterBoolean.allocationSize(value.`hideCompleted`) +
-                FfiConverterBoolean.allocationSize(value.`hideAliasesInSidebar`) +
-                FfiConverterMapStringSequenceString.allocationSize(value.`tagAliases`) +
-                FfiConverterSequenceString.allocationSize(value.`disabledCalendars`) +
-                FfiConverterOptionalUInt.allocationSize(value.`sortCutoffDays`) +
-                FfiConverterBoolean.allocationSize(value.`sortStandardByPriority`) +
-                FfiConverterString.allocationSize(value.`sortPreset`) +
-                FfiConverterUInt.allocationSize(value.`urgentDays`) +
-                FfiConverterUByte.allocationSize(value.`urgentPrio`) +
-                FfiConverterUByte.allocationSize(value.`defaultPriority`) +
-                FfiConverterUInt.allocationSize(value.`startGracePeriodDays`) +
-                FfiConverterBoolean.allocationSize(value.`autoReminders`) +
-                FfiConverterString.allocationSize(value.`defaultReminderTime`) +
-                FfiConverterUInt.allocationSize(value.`snoozeShort`) +
-                FfiConverterBoolean.allocationSize(value.`createEventsForTasks`) +
-                FfiConverterBoolean.allocationSize(value.`deleteEventsOnCompletion`) +
-                FfiConverterUInt.allocationSize(value.`autoRefreshInterval`) +
-                FfiConverterUInt.allocationSize(value.`trashRetention`) +
-                FfiConverterUInt.allocationSize(value.`maxDoneRoots`) +
-                FfiConverterUInt.allocationSize(value.`maxDoneSubtasks`) +
-                FfiConverterBoolean.allocationSize(value.`showOngoingNotifications`) +
-                FfiConverterBoolean.allocationSize(value.`showQuickFilter`) +
-                FfiConverterString.allocationSize(value.`quickFilterTerm`) +
-                FfiConverterString.allocationSize(value.`quickFilterIcon`) +
-                FfiConverterBoolean.allocationSize(value.`syncSettings`) +
-                FfiConverterMapStringTypeMobileGoal.allocationSize(value.`goals`) +
-                FfiConverterUInt.allocationSize(value.`defaultDurationGoalMins`) +
-                FfiConverterBoolean.allocationSize(value.`sessionsCountAsCompletions`) +
-                FfiConverterBoolean.allocationSize(value.`showGoalsTab`) +
-                FfiConverterBoolean.allocationSize(value.`showTaskGoalsInSidebar`) +
-                FfiConverterBoolean.allocationSize(value.`sortCollectionsBySize`) +
-                FfiConverterSequenceString.allocationSize(value.`expandedTags`) +
-                FfiConverterSequenceString.allocationSize(value.`expandedLocations`) +
-                FfiConverterSequenceString.allocationSize(value.`expandedDoneGroups`)
+            FfiConverterString.allocationSize(value.`username`) +
+            FfiConverterString.allocationSize(value.`password`) +
+            FfiConverterOptionalString.allocationSize(value.`tlsClientCertPath`) +
+            FfiConverterOptionalString.allocationSize(value.`tlsClientKeyPath`) +
+            FfiConverterOptionalString.allocationSize(value.`defaultCalendar`) +
+            FfiConverterBoolean.allocationSize(value.`allowInsecure`) +
+            FfiConverterBoolean.allocationSize(value.`limitFetchToCutoff`) +
+            FfiConverterBoolean.allocationSize(value.`hideCompleted`) +
+            FfiConverterBoolean.allocationSize(value.`hideAliasesInSidebar`) +
+            FfiConverterMapStringSequenceString.allocationSize(value.`tagAliases`) +
+            FfiConverterSequenceString.allocationSize(value.`disabledCalendars`) +
+            FfiConverterOptionalUInt.allocationSize(value.`sortCutoffDays`) +
+            FfiConverterBoolean.allocationSize(value.`sortStandardByPriority`) +
+            FfiConverterString.allocationSize(value.`sortPreset`) +
+            FfiConverterUInt.allocationSize(value.`urgentDays`) +
+            FfiConverterUByte.allocationSize(value.`urgentPrio`) +
+            FfiConverterUByte.allocationSize(value.`defaultPriority`) +
+            FfiConverterUInt.allocationSize(value.`startGracePeriodDays`) +
+            FfiConverterBoolean.allocationSize(value.`autoReminders`) +
+            FfiConverterString.allocationSize(value.`defaultReminderTime`) +
+            FfiConverterUInt.allocationSize(value.`snoozeShort`) +
+            FfiConverterBoolean.allocationSize(value.`createEventsForTasks`) +
+            FfiConverterBoolean.allocationSize(value.`deleteEventsOnCompletion`) +
+            FfiConverterUInt.allocationSize(value.`autoRefreshInterval`) +
+            FfiConverterUInt.allocationSize(value.`trashRetention`) +
+            FfiConverterUInt.allocationSize(value.`maxDoneRoots`) +
+            FfiConverterUInt.allocationSize(value.`maxDoneSubtasks`) +
+            FfiConverterBoolean.allocationSize(value.`showOngoingNotifications`) +
+            FfiConverterBoolean.allocationSize(value.`showQuickFilter`) +
+            FfiConverterString.allocationSize(value.`quickFilterTerm`) +
+            FfiConverterString.allocationSize(value.`quickFilterIcon`) +
+            FfiConverterBoolean.allocationSize(value.`syncSettings`) +
+            FfiConverterMapStringTypeMobileGoal.allocationSize(value.`goals`) +
+            FfiConverterUInt.allocationSize(value.`defaultDurationGoalMins`) +
+            FfiConverterBoolean.allocationSize(value.`sessionsCountAsCompletions`) +
+            FfiConverterBoolean.allocationSize(value.`archiveCompleted`) +
+            FfiConverterBoolean.allocationSize(value.`showGoalsTab`) +
+            FfiConverterBoolean.allocationSize(value.`showTaskGoalsInSidebar`) +
+            FfiConverterBoolean.allocationSize(value.`sortCollectionsBySize`) +
+            FfiConverterSequenceString.allocationSize(value.`expandedTags`) +
+            FfiConverterSequenceString.allocationSize(value.`expandedLocations`) +
+            FfiConverterSequenceString.allocationSize(value.`expandedDoneGroups`)
+    )
+
+    override fun write(value: MobileConfig, buf: ByteBuffer) {
+            FfiConverterString.write(value.`url`, buf)
+            FfiConverterString.write(value.`username`, buf)
+            FfiConverterString.write(value.`password`, buf)
+            FfiConverterOptionalString.write(value.`tlsClientCertPath`, buf)
+            FfiConverterOptionalString.write(value.`tlsClientKeyPath`, buf)
+            FfiConverterOptionalString.write(value.`defaultCalendar`, buf)
+            FfiConverterBoolean.write(value.`allowInsecure`, buf)
+            FfiConverterBoolean.write(value.`limitFetchToCutoff`, buf)
+            FfiConverterBoolean.write(value.`hideCompleted`, buf)
+            FfiConverterBoolean.write(value.`hideAliasesInSidebar`, buf)
+            FfiConverterMapStringSequenceString.write(value.`tagAliases`, buf)
+            FfiConverterSequenceString.write(value.`disabledCalendars`, buf)
+            FfiConverterOptionalUInt.write(value.`sortCutoffDays`, buf)
+            FfiConverterBoolean.write(value.`sortStandardByPriority`, buf)
+            FfiConverterString.write(value.`sortPreset`, buf)
+            FfiConverterUInt.write(value.`urgentDays`, buf)
+            FfiConverterUByte.write(value.`urgentPrio`, buf)
+            FfiConverterUByte.write(value.`defaultPriority`, buf)
+            FfiConverterUInt.write(value.`startGracePeriodDays`, buf)
+            FfiConverterBoolean.write(value.`autoReminders`, buf)
+            FfiConverterString.write(value.`defaultReminderTime`, buf)
+            FfiConverterUInt.write(value.`snoozeShort`, buf)
+            FfiConverterBoolean.write(value.`createEventsForTasks`, buf)
+            FfiConverterBoolean.write(value.`deleteEventsOnCompletion`, buf)
+            FfiConverterUInt.write(value.`autoRefreshInterval`, buf)
+            FfiConverterUInt.write(value.`trashRetention`, buf)
+            FfiConverterUInt.write(value.`maxDoneRoots`, buf)
+            FfiConverterUInt.write(value.`maxDoneSubtasks`, buf)
+            FfiConverterBoolean.write(value.`showOngoingNotifications`, buf)
+            FfiConverterBoolean.write(value.`showQuickFilter`, buf)
+            FfiConverterString.write(value.`quickFilterTerm`, buf)
+            FfiConverterString.write(value.`quickFilterIcon`, buf)
+            FfiConverterBoolean.write(value.`syncSettings`, buf)
+            FfiConverterMapStringTypeMobileGoal.write(value.`goals`, buf)
+            FfiConverterUInt.write(value.`defaultDurationGoalMins`, buf)
+            FfiConverterBoolean.write(value.`sessionsCountAsCompletions`, buf)
+            FfiConverterBoolean.write(value.`archiveCompleted`, buf)
+            FfiConverterBoolean.write(value.`showGoalsTab`, buf)
+            FfiConverterBoolean.write(value.`showTaskGoalsInSidebar`, buf)
+            FfiConverterBoolean.write(value.`sortCollectionsBySize`, buf)
+            FfiConverterSequenceString.write(value.`expandedTags`, buf)
+            FfiConverterSequenceString.write(value.`expandedLocations`, buf)
+            FfiConverterSequenceString.write(value.`expandedDoneGroups`, buf)
+    }
+}
+
+
+
+data class MobileDiagnosticStep (
+    /**
+     * `principal`, `home_set` or `calendars`.
+     */
+    var `step`: kotlin.String
+    , 
+    var `ok`: kotlin.Boolean
+    , 
+    var `status`: kotlin.UShort?
+    , 
+    var `detail`: kotlin.String
+    , 
+    var `elapsedMs`: kotlin.ULong
+    
+){
+    
+
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeMobileDiagnosticStep: FfiConverterRustBuffer<MobileDiagnosticStep> {
+    override fun read(buf: ByteBuffer): MobileDiagnosticStep {
+        return MobileDiagnosticStep(
+            FfiConverterString.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterOptionalUShort.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterULong.read(buf),
         )
+    }
 
-    override fun write(
-        value: MobileConfig,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`url`, buf)
-        FfiConverterString.write(value.`username`, buf)
-        FfiConverterString.write(value.`password`, buf)
-        FfiConverterOptionalString.write(value.`tlsClientCertPath`, buf)
-        FfiConverterOptionalString.write(value.`tlsClientKeyPath`, buf)
-        FfiConverterOptionalString.write(value.`defaultCalendar`, buf)
-        FfiConverterBoolean.write(value.`allowInsecure`, buf)
-        FfiConverterBoolean.write(value.`hideCompleted`, buf)
-        FfiConverterBoolean.write(value.`hideAliasesInSidebar`, buf)
-        FfiConverterMapStringSequenceString.write(value.`tagAliases`, buf)
-        FfiConverterSequenceString.write(value.`disabledCalendars`, buf)
-        FfiConverterOptionalUInt.write(value.`sortCutoffDays`, buf)
-        FfiConverterBoolean.write(value.`sortStandardByPriority`, buf)
-        FfiConverterString.write(value.`sortPreset`, buf)
-        FfiConverterUInt.write(value.`urgentDays`, buf)
-        FfiConverterUByte.write(value.`urgentPrio`, buf)
-        FfiConverterUByte.write(value.`defaultPriority`, buf)
-        FfiConverterUInt.write(value.`startGracePeriodDays`, buf)
-        FfiConverterBoolean.write(value.`autoReminders`, buf)
-        FfiConverterString.write(value.`defaultReminderTime`, buf)
-        FfiConverterUInt.write(value.`snoozeShort`, buf)
-        FfiConverterBoolean.write(value.`createEventsForTasks`, buf)
-        FfiConverterBoolean.write(value.`deleteEventsOnCompletion`, buf)
-        FfiConverterUInt.write(value.`autoRefreshInterval`, buf)
-        FfiConverterUInt.write(value.`trashRetention`, buf)
-        FfiConverterUInt.write(value.`maxDoneRoots`, buf)
-        FfiConverterUInt.write(value.`maxDoneSubtasks`, buf)
-        FfiConverterBoolean.write(value.`showOngoingNotifications`, buf)
-        FfiConverterBoolean.write(value.`showQuickFilter`, buf)
-        FfiConverterString.write(value.`quickFilterTerm`, buf)
-        FfiConverterString.write(value.`quickFilterIcon`, buf)
-        FfiConverterBoolean.write(value.`syncSettings`, buf)
-        FfiConverterMapStringTypeMobileGoal.write(value.`goals`, buf)
-        FfiConverterUInt.write(value.`defaultDurationGoalMins`, buf)
-        FfiConverterBoolean.write(value.`sessionsCountAsCompletions`, buf)
-        FfiConverterBoolean.write(value.`showGoalsTab`, buf)
-        FfiConverterBoolean.write(value.`showTaskGoalsInSidebar`, buf)
-        FfiConverterBoolean.write(value.`sortCollectionsBySize`, buf)
-        FfiConverterSequenceString.write(value.`expandedTags`, buf)
-        FfiConverterSequenceString.write(value.`expandedLocations`, buf)
-        FfiConverterSequenceString.write(value.`expandedDoneGroups`, buf)
-    }
-}
-
-data class MobileFilterOptions(
-    var `filterTags`: List<kotlin.String>,
-    var `filterLocations`: List<kotlin.String>,
-    var `searchQuery`: kotlin.String,
-    var `expandedGroups`: List<kotlin.String>,
-    var `matchAllCategories`: kotlin.Boolean,
-    var `expandedTags`: List<kotlin.String>,
-    var `expandedLocations`: List<kotlin.String>,
-) {
+    override fun allocationSize(value: MobileDiagnosticStep) = (
+            FfiConverterString.allocationSize(value.`step`) +
+            FfiConverterBoolean.allocationSize(value.`ok`) +
+            FfiConverterOptionalUShort.allocationSize(value.`status`) +
+            FfiConverterString.allocationSize(value.`detail`) +
+            FfiConverterULong.allocationSize(value.`elapsedMs`)
+    )
+
+    override fun write(value: MobileDiagnosticStep, buf: ByteBuffer) {
+            FfiConverterString.write(value.`step`, buf)
+            FfiConverterBoolean.write(value.`ok`, buf)
+            FfiConverterOptionalUShort.write(value.`status`, buf)
+            FfiConverterString.write(value.`detail`, buf)
+            FfiConverterULong.write(value.`elapsedMs`, buf)
+    }
+}
+
+
+
+data class MobileFilterOptions (
+    var `filterTags`: List<kotlin.String>
+    , 
+    var `filterLocations`: List<kotlin.String>
+    , 
+    var `searchQuery`: kotlin.String
+    , 
+    var `expandedGroups`: List<kotlin.String>
+    , 
+    var `matchAllCategories`: kotlin.Boolean
+    , 
+    var `expandedTags`: List<kotlin.String>
+    , 
+    var `expandedLocations`: List<kotlin.String>
+    , 
+    /**
+     * Restricts the view to the inbox (`is:inbox`): open tasks with no
+     * categories, no due date and no parent.
+     */
+    var `inboxOnly`: kotlin.Boolean
+    
+){
+    
+
+    
+
+    
     companion object
 }
 
 /**
  * @suppress
  */
-public object FfiConverterTypeMobileFilterOptions : FfiConverterRustBuffer<MobileFilterOptions> {
-    override fun read(buf: ByteBuffer): MobileFilterOptions =
-        MobileFilterOptions(
+public object FfiConverterTypeMobileFilterOptions: FfiConverterRustBuffer<MobileFilterOptions> {
+    override fun read(buf: ByteBuffer): MobileFilterOptions {
+        return MobileFilterOptions(
             FfiConverterSequenceString.read(buf),
             FfiConverterSequenceString.read(buf),
             FfiConverterString.read(buf),
@@ -4536,86 +5460,106 @@ public object FfiConverterTypeMobileFilterOptions : FfiConverterRustBuffer<Mobil
             FfiConverterBoolean.read(buf),
             FfiConverterSequenceString.read(buf),
             FfiConverterSequenceString.read(buf),
+            FfiConverterBoolean.read(buf),
         )
+    }
 
-    override fun allocationSize(value: MobileFilterOptions) =
-        (
+    override fun allocationSize(value: MobileFilterOptions) = (
             FfiConverterSequenceString.allocationSize(value.`filterTags`) +
-                FfiConverterSequenceString.allocationSize(value.`filterLocations`) +
-                FfiConverterString.allocationSize(value.`searchQuery`) +
-                FfiConverterSequenceString.allocationSize(value.`expandedGroups`) +
-                FfiConverterBoolean.allocationSize(value.`matchAllCategories`) +
-                FfiConverterSequenceString.allocationSize(value.`expandedTags`) +
-                FfiConverterSequenceString.allocationSize(value.`expandedLocations`)
-        )
+            FfiConverterSequenceString.allocationSize(value.`filterLocations`) +
+            FfiConverterString.allocationSize(value.`searchQuery`) +
+            FfiConverterSequenceString.allocationSize(value.`expandedGroups`) +
+            FfiConverterBoolean.allocationSize(value.`matchAllCategories`) +
+            FfiConverterSequenceString.allocationSize(value.`expandedTags`) +
+            FfiConverterSequenceString.allocationSize(value.`expandedLocations`) +
+            FfiConverterBoolean.allocationSize(value.`inboxOnly`)
+    )
 
-    override fun write(
-        value: MobileFilterOptions,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterSequenceString.write(value.`filterTags`, buf)
-        FfiConverterSequenceString.write(value.`filterLocations`, buf)
-        FfiConverterString.write(value.`searchQuery`, buf)
-        FfiConverterSequenceString.write(value.`expandedGroups`, buf)
-        FfiConverterBoolean.write(value.`matchAllCategories`, buf)
-        FfiConverterSequenceString.write(value.`expandedTags`, buf)
-        FfiConverterSequenceString.write(value.`expandedLocations`, buf)
+    override fun write(value: MobileFilterOptions, buf: ByteBuffer) {
+            FfiConverterSequenceString.write(value.`filterTags`, buf)
+            FfiConverterSequenceString.write(value.`filterLocations`, buf)
+            FfiConverterString.write(value.`searchQuery`, buf)
+            FfiConverterSequenceString.write(value.`expandedGroups`, buf)
+            FfiConverterBoolean.write(value.`matchAllCategories`, buf)
+            FfiConverterSequenceString.write(value.`expandedTags`, buf)
+            FfiConverterSequenceString.write(value.`expandedLocations`, buf)
+            FfiConverterBoolean.write(value.`inboxOnly`, buf)
     }
 }
 
-data class MobileGoal(
-    var `goalType`: MobileGoalType,
-    var `target`: kotlin.UInt,
-    var `interval`: MobileInterval,
-) {
+
+
+data class MobileGoal (
+    var `goalType`: MobileGoalType
+    , 
+    var `target`: kotlin.UInt
+    , 
+    var `interval`: MobileInterval
+    
+){
+    
+
+    
+
+    
     companion object
 }
 
 /**
  * @suppress
  */
-public object FfiConverterTypeMobileGoal : FfiConverterRustBuffer<MobileGoal> {
-    override fun read(buf: ByteBuffer): MobileGoal =
-        MobileGoal(
+public object FfiConverterTypeMobileGoal: FfiConverterRustBuffer<MobileGoal> {
+    override fun read(buf: ByteBuffer): MobileGoal {
+        return MobileGoal(
             FfiConverterTypeMobileGoalType.read(buf),
             FfiConverterUInt.read(buf),
             FfiConverterTypeMobileInterval.read(buf),
         )
+    }
 
-    override fun allocationSize(value: MobileGoal) =
-        (
+    override fun allocationSize(value: MobileGoal) = (
             FfiConverterTypeMobileGoalType.allocationSize(value.`goalType`) +
-                FfiConverterUInt.allocationSize(value.`target`) +
-                FfiConverterTypeMobileInterval.allocationSize(value.`interval`)
-        )
+            FfiConverterUInt.allocationSize(value.`target`) +
+            FfiConverterTypeMobileInterval.allocationSize(value.`interval`)
+    )
 
-    override fun write(
-        value: MobileGoal,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterTypeMobileGoalType.write(value.`goalType`, buf)
-        FfiConverterUInt.write(value.`target`, buf)
-        FfiConverterTypeMobileInterval.write(value.`interval`, buf)
+    override fun write(value: MobileGoal, buf: ByteBuffer) {
+            FfiConverterTypeMobileGoalType.write(value.`goalType`, buf)
+            FfiConverterUInt.write(value.`target`, buf)
+            FfiConverterTypeMobileInterval.write(value.`interval`, buf)
     }
 }
 
-data class MobileGoalProgress(
-    var `key`: kotlin.String,
-    var `progressStr`: kotlin.String,
-    var `targetStr`: kotlin.String,
-    var `periodStr`: kotlin.String,
-    var `pct`: kotlin.Float,
-    var `history`: List<kotlin.Float>,
-) {
+
+
+data class MobileGoalProgress (
+    var `key`: kotlin.String
+    , 
+    var `progressStr`: kotlin.String
+    , 
+    var `targetStr`: kotlin.String
+    , 
+    var `periodStr`: kotlin.String
+    , 
+    var `pct`: kotlin.Float
+    , 
+    var `history`: List<kotlin.Float>
+    
+){
+    
+
+    
+
+    
     companion object
 }
 
 /**
  * @suppress
  */
-public object FfiConverterTypeMobileGoalProgress : FfiConverterRustBuffer<MobileGoalProgress> {
-    override fun read(buf: ByteBuffer): MobileGoalProgress =
-        MobileGoalProgress(
+public object FfiConverterTypeMobileGoalProgress: FfiConverterRustBuffer<MobileGoalProgress> {
+    override fun read(buf: ByteBuffer): MobileGoalProgress {
+        return MobileGoalProgress(
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
@@ -4623,300 +5567,463 @@ public object FfiConverterTypeMobileGoalProgress : FfiConverterRustBuffer<Mobile
             FfiConverterFloat.read(buf),
             FfiConverterSequenceFloat.read(buf),
         )
+    }
 
-    override fun allocationSize(value: MobileGoalProgress) =
-        (
+    override fun allocationSize(value: MobileGoalProgress) = (
             FfiConverterString.allocationSize(value.`key`) +
-                FfiConverterString.allocationSize(value.`progressStr`) +
-                FfiConverterString.allocationSize(value.`targetStr`) +
-                FfiConverterString.allocationSize(value.`periodStr`) +
-                FfiConverterFloat.allocationSize(value.`pct`) +
-                FfiConverterSequenceFloat.allocationSize(value.`history`)
-        )
+            FfiConverterString.allocationSize(value.`progressStr`) +
+            FfiConverterString.allocationSize(value.`targetStr`) +
+            FfiConverterString.allocationSize(value.`periodStr`) +
+            FfiConverterFloat.allocationSize(value.`pct`) +
+            FfiConverterSequenceFloat.allocationSize(value.`history`)
+    )
 
-    override fun write(
-        value: MobileGoalProgress,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`key`, buf)
-        FfiConverterString.write(value.`progressStr`, buf)
-        FfiConverterString.write(value.`targetStr`, buf)
-        FfiConverterString.write(value.`periodStr`, buf)
-        FfiConverterFloat.write(value.`pct`, buf)
-        FfiConverterSequenceFloat.write(value.`history`, buf)
+    override fun write(value: MobileGoalProgress, buf: ByteBuffer) {
+            FfiConverterString.write(value.`key`, buf)
+            FfiConverterString.write(value.`progressStr`, buf)
+            FfiConverterString.write(value.`targetStr`, buf)
+            FfiConverterString.write(value.`periodStr`, buf)
+            FfiConverterFloat.write(value.`pct`, buf)
+            FfiConverterSequenceFloat.write(value.`history`, buf)
     }
 }
 
-data class MobileHelpCategoryData(
-    var `category`: HelpTab,
-    var `title`: kotlin.String,
-    var `sections`: List<MobileHelpSection>,
-) {
+
+
+data class MobileHelpCategoryData (
+    var `category`: HelpTab
+    , 
+    var `title`: kotlin.String
+    , 
+    var `sections`: List<MobileHelpSection>
+    
+){
+    
+
+    
+
+    
     companion object
 }
 
 /**
  * @suppress
  */
-public object FfiConverterTypeMobileHelpCategoryData : FfiConverterRustBuffer<MobileHelpCategoryData> {
-    override fun read(buf: ByteBuffer): MobileHelpCategoryData =
-        MobileHelpCategoryData(
+public object FfiConverterTypeMobileHelpCategoryData: FfiConverterRustBuffer<MobileHelpCategoryData> {
+    override fun read(buf: ByteBuffer): MobileHelpCategoryData {
+        return MobileHelpCategoryData(
             FfiConverterTypeHelpTab.read(buf),
             FfiConverterString.read(buf),
             FfiConverterSequenceTypeMobileHelpSection.read(buf),
         )
+    }
 
-    override fun allocationSize(value: MobileHelpCategoryData) =
-        (
+    override fun allocationSize(value: MobileHelpCategoryData) = (
             FfiConverterTypeHelpTab.allocationSize(value.`category`) +
-                FfiConverterString.allocationSize(value.`title`) +
-                FfiConverterSequenceTypeMobileHelpSection.allocationSize(value.`sections`)
-        )
+            FfiConverterString.allocationSize(value.`title`) +
+            FfiConverterSequenceTypeMobileHelpSection.allocationSize(value.`sections`)
+    )
 
-    override fun write(
-        value: MobileHelpCategoryData,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterTypeHelpTab.write(value.`category`, buf)
-        FfiConverterString.write(value.`title`, buf)
-        FfiConverterSequenceTypeMobileHelpSection.write(value.`sections`, buf)
+    override fun write(value: MobileHelpCategoryData, buf: ByteBuffer) {
+            FfiConverterTypeHelpTab.write(value.`category`, buf)
+            FfiConverterString.write(value.`title`, buf)
+            FfiConverterSequenceTypeMobileHelpSection.write(value.`sections`, buf)
     }
 }
 
-data class MobileHelpItem(
-    var `keys`: kotlin.String,
-    var `desc`: kotlin.String,
-    var `example`: kotlin.String,
-) {
+
+
+data class MobileHelpItem (
+    var `keys`: kotlin.String
+    , 
+    var `desc`: kotlin.String
+    , 
+    var `example`: kotlin.String
+    
+){
+    
+
+    
+
+    
     companion object
 }
 
 /**
  * @suppress
  */
-public object FfiConverterTypeMobileHelpItem : FfiConverterRustBuffer<MobileHelpItem> {
-    override fun read(buf: ByteBuffer): MobileHelpItem =
-        MobileHelpItem(
+public object FfiConverterTypeMobileHelpItem: FfiConverterRustBuffer<MobileHelpItem> {
+    override fun read(buf: ByteBuffer): MobileHelpItem {
+        return MobileHelpItem(
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
         )
+    }
 
-    override fun allocationSize(value: MobileHelpItem) =
-        (
+    override fun allocationSize(value: MobileHelpItem) = (
             FfiConverterString.allocationSize(value.`keys`) +
-                FfiConverterString.allocationSize(value.`desc`) +
-                FfiConverterString.allocationSize(value.`example`)
-        )
+            FfiConverterString.allocationSize(value.`desc`) +
+            FfiConverterString.allocationSize(value.`example`)
+    )
 
-    override fun write(
-        value: MobileHelpItem,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`keys`, buf)
-        FfiConverterString.write(value.`desc`, buf)
-        FfiConverterString.write(value.`example`, buf)
+    override fun write(value: MobileHelpItem, buf: ByteBuffer) {
+            FfiConverterString.write(value.`keys`, buf)
+            FfiConverterString.write(value.`desc`, buf)
+            FfiConverterString.write(value.`example`, buf)
     }
 }
 
-data class MobileHelpSection(
-    var `title`: kotlin.String,
-    var `items`: List<MobileHelpItem>,
-) {
+
+
+data class MobileHelpSection (
+    var `title`: kotlin.String
+    , 
+    var `items`: List<MobileHelpItem>
+    
+){
+    
+
+    
+
+    
     companion object
 }
 
 /**
  * @suppress
  */
-public object FfiConverterTypeMobileHelpSection : FfiConverterRustBuffer<MobileHelpSection> {
-    override fun read(buf: ByteBuffer): MobileHelpSection =
-        MobileHelpSection(
+public object FfiConverterTypeMobileHelpSection: FfiConverterRustBuffer<MobileHelpSection> {
+    override fun read(buf: ByteBuffer): MobileHelpSection {
+        return MobileHelpSection(
             FfiConverterString.read(buf),
             FfiConverterSequenceTypeMobileHelpItem.read(buf),
         )
+    }
 
-    override fun allocationSize(value: MobileHelpSection) =
-        (
+    override fun allocationSize(value: MobileHelpSection) = (
             FfiConverterString.allocationSize(value.`title`) +
-                FfiConverterSequenceTypeMobileHelpItem.allocationSize(value.`items`)
-        )
+            FfiConverterSequenceTypeMobileHelpItem.allocationSize(value.`items`)
+    )
 
-    override fun write(
-        value: MobileHelpSection,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`title`, buf)
-        FfiConverterSequenceTypeMobileHelpItem.write(value.`items`, buf)
+    override fun write(value: MobileHelpSection, buf: ByteBuffer) {
+            FfiConverterString.write(value.`title`, buf)
+            FfiConverterSequenceTypeMobileHelpItem.write(value.`items`, buf)
     }
 }
 
-data class MobileInterval(
-    var `amount`: kotlin.UInt,
-    var `unit`: MobileIntervalUnit,
-) {
+
+
+data class MobileInterval (
+    var `amount`: kotlin.UInt
+    , 
+    var `unit`: MobileIntervalUnit
+    
+){
+    
+
+    
+
+    
     companion object
 }
 
 /**
  * @suppress
  */
-public object FfiConverterTypeMobileInterval : FfiConverterRustBuffer<MobileInterval> {
-    override fun read(buf: ByteBuffer): MobileInterval =
-        MobileInterval(
+public object FfiConverterTypeMobileInterval: FfiConverterRustBuffer<MobileInterval> {
+    override fun read(buf: ByteBuffer): MobileInterval {
+        return MobileInterval(
             FfiConverterUInt.read(buf),
             FfiConverterTypeMobileIntervalUnit.read(buf),
         )
+    }
 
-    override fun allocationSize(value: MobileInterval) =
-        (
+    override fun allocationSize(value: MobileInterval) = (
             FfiConverterUInt.allocationSize(value.`amount`) +
-                FfiConverterTypeMobileIntervalUnit.allocationSize(value.`unit`)
+            FfiConverterTypeMobileIntervalUnit.allocationSize(value.`unit`)
+    )
+
+    override fun write(value: MobileInterval, buf: ByteBuffer) {
+            FfiConverterUInt.write(value.`amount`, buf)
+            FfiConverterTypeMobileIntervalUnit.write(value.`unit`, buf)
+    }
+}
+
+
+
+/**
+ * One pending journal action, in queue order.
+ */
+data class MobileJournalEntry (
+    var `index`: kotlin.UInt
+    , 
+    /**
+     * `create`, `update`, `delete` or `move`.
+     */
+    var `action`: kotlin.String
+    , 
+    var `uid`: kotlin.String
+    , 
+    var `summary`: kotlin.String
+    
+){
+    
+
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeMobileJournalEntry: FfiConverterRustBuffer<MobileJournalEntry> {
+    override fun read(buf: ByteBuffer): MobileJournalEntry {
+        return MobileJournalEntry(
+            FfiConverterUInt.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
         )
+    }
+
+    override fun allocationSize(value: MobileJournalEntry) = (
+            FfiConverterUInt.allocationSize(value.`index`) +
+            FfiConverterString.allocationSize(value.`action`) +
+            FfiConverterString.allocationSize(value.`uid`) +
+            FfiConverterString.allocationSize(value.`summary`)
+    )
 
-    override fun write(
-        value: MobileInterval,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterUInt.write(value.`amount`, buf)
-        FfiConverterTypeMobileIntervalUnit.write(value.`unit`, buf)
+    override fun write(value: MobileJournalEntry, buf: ByteBuffer) {
+            FfiConverterUInt.write(value.`index`, buf)
+            FfiConverterString.write(value.`action`, buf)
+            FfiConverterString.write(value.`uid`, buf)
+            FfiConverterString.write(value.`summary`, buf)
     }
 }
 
-data class MobileLocation(
-    var `name`: kotlin.String,
-    var `displayName`: kotlin.String,
-    var `count`: kotlin.UInt,
-    var `depth`: kotlin.UInt,
-    var `hasChildren`: kotlin.Boolean,
-    var `isExpanded`: kotlin.Boolean,
-) {
+
+
+data class MobileLocation (
+    var `name`: kotlin.String
+    , 
+    var `displayName`: kotlin.String
+    , 
+    var `count`: kotlin.UInt
+    , 
+    var `depth`: kotlin.UInt
+    , 
+    var `hasChildren`: kotlin.Boolean
+    , 
+    var `isExpanded`: kotlin.Boolean
+    , 
+    /**
+     * The "No location" bucket: selecting it shows tasks without a location.
+     */
+    var `isNoLocation`: kotlin.Boolean
+    
+){
+    
+
+    
+
+    
     companion object
 }
 
 /**
  * @suppress
  */
-public object FfiConverterTypeMobileLocation : FfiConverterRustBuffer<MobileLocation> {
-    override fun read(buf: ByteBuffer): MobileLocation =
-        MobileLocation(
+public object FfiConverterTypeMobileLocation: FfiConverterRustBuffer<MobileLocation> {
+    override fun read(buf: ByteBuffer): MobileLocation {
+        return MobileLocation(
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
             FfiConverterUInt.read(buf),
             FfiConverterUInt.read(buf),
             FfiConverterBoolean.read(buf),
             FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
         )
+    }
 
-    override fun allocationSize(value: MobileLocation) =
-        (
+    override fun allocationSize(value: MobileLocation) = (
             FfiConverterString.allocationSize(value.`name`) +
-                FfiConverterString.allocationSize(value.`displayName`) +
-                FfiConverterUInt.allocationSize(value.`count`) +
-                FfiConverterUInt.allocationSize(value.`depth`) +
-                FfiConverterBoolean.allocationSize(value.`hasChildren`) +
-                FfiConverterBoolean.allocationSize(value.`isExpanded`)
-        )
+            FfiConverterString.allocationSize(value.`displayName`) +
+            FfiConverterUInt.allocationSize(value.`count`) +
+            FfiConverterUInt.allocationSize(value.`depth`) +
+            FfiConverterBoolean.allocationSize(value.`hasChildren`) +
+            FfiConverterBoolean.allocationSize(value.`isExpanded`) +
+            FfiConverterBoolean.allocationSize(value.`isNoLocation`)
+    )
 
-    override fun write(
-        value: MobileLocation,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`name`, buf)
-        FfiConverterString.write(value.`displayName`, buf)
-        FfiConverterUInt.write(value.`count`, buf)
-        FfiConverterUInt.write(value.`depth`, buf)
-        FfiConverterBoolean.write(value.`hasChildren`, buf)
-        FfiConverterBoolean.write(value.`isExpanded`, buf)
+    override fun write(value: MobileLocation, buf: ByteBuffer) {
+            FfiConverterString.write(value.`name`, buf)
+            FfiConverterString.write(value.`displayName`, buf)
+            FfiConverterUInt.write(value.`count`, buf)
+            FfiConverterUInt.write(value.`depth`, buf)
+            FfiConverterBoolean.write(value.`hasChildren`, buf)
+            FfiConverterBoolean.write(value.`isExpanded`, buf)
+            FfiConverterBoolean.write(value.`isNoLocation`, buf)
     }
 }
 
-data class MobileRelatedTask(
-    var `uid`: kotlin.String,
-    var `summary`: kotlin.String,
-) {
+
+
+data class MobileRelatedTask (
+    var `uid`: kotlin.String
+    , 
+    var `summary`: kotlin.String
+    
+){
+    
+
+    
+
+    
     companion object
 }
 
 /**
  * @suppress
  */
-public object FfiConverterTypeMobileRelatedTask : FfiConverterRustBuffer<MobileRelatedTask> {
-    override fun read(buf: ByteBuffer): MobileRelatedTask =
-        MobileRelatedTask(
+public object FfiConverterTypeMobileRelatedTask: FfiConverterRustBuffer<MobileRelatedTask> {
+    override fun read(buf: ByteBuffer): MobileRelatedTask {
+        return MobileRelatedTask(
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
         )
+    }
 
-    override fun allocationSize(value: MobileRelatedTask) =
-        (
+    override fun allocationSize(value: MobileRelatedTask) = (
             FfiConverterString.allocationSize(value.`uid`) +
-                FfiConverterString.allocationSize(value.`summary`)
+            FfiConverterString.allocationSize(value.`summary`)
+    )
+
+    override fun write(value: MobileRelatedTask, buf: ByteBuffer) {
+            FfiConverterString.write(value.`uid`, buf)
+            FfiConverterString.write(value.`summary`, buf)
+    }
+}
+
+
+
+data class MobileSyncResult (
+    var `status`: kotlin.String
+    , 
+    /**
+     * Per-action warnings from the journal upload (conflict copies, dropped actions, ...).
+     */
+    var `warnings`: List<kotlin.String>
+    
+){
+    
+
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeMobileSyncResult: FfiConverterRustBuffer<MobileSyncResult> {
+    override fun read(buf: ByteBuffer): MobileSyncResult {
+        return MobileSyncResult(
+            FfiConverterString.read(buf),
+            FfiConverterSequenceString.read(buf),
         )
+    }
+
+    override fun allocationSize(value: MobileSyncResult) = (
+            FfiConverterString.allocationSize(value.`status`) +
+            FfiConverterSequenceString.allocationSize(value.`warnings`)
+    )
 
-    override fun write(
-        value: MobileRelatedTask,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`uid`, buf)
-        FfiConverterString.write(value.`summary`, buf)
+    override fun write(value: MobileSyncResult, buf: ByteBuffer) {
+            FfiConverterString.write(value.`status`, buf)
+            FfiConverterSequenceString.write(value.`warnings`, buf)
     }
 }
 
-data class MobileSyntaxToken(
-    var `kind`: MobileSyntaxType,
-    var `start`: kotlin.Int,
-    var `end`: kotlin.Int,
-) {
+
+
+data class MobileSyntaxToken (
+    var `kind`: MobileSyntaxType
+    , 
+    var `start`: kotlin.Int
+    , 
+    var `end`: kotlin.Int
+    
+){
+    
+
+    
+
+    
     companion object
 }
 
 /**
  * @suppress
  */
-public object FfiConverterTypeMobileSyntaxToken : FfiConverterRustBuffer<MobileSyntaxToken> {
-    override fun read(buf: ByteBuffer): MobileSyntaxToken =
-        MobileSyntaxToken(
+public object FfiConverterTypeMobileSyntaxToken: FfiConverterRustBuffer<MobileSyntaxToken> {
+    override fun read(buf: ByteBuffer): MobileSyntaxToken {
+        return MobileSyntaxToken(
             FfiConverterTypeMobileSyntaxType.read(buf),
             FfiConverterInt.read(buf),
             FfiConverterInt.read(buf),
         )
+    }
 
-    override fun allocationSize(value: MobileSyntaxToken) =
-        (
+    override fun allocationSize(value: MobileSyntaxToken) = (
             FfiConverterTypeMobileSyntaxType.allocationSize(value.`kind`) +
-                FfiConverterInt.allocationSize(value.`start`) +
-                FfiConverterInt.allocationSize(value.`end`)
-        )
+            FfiConverterInt.allocationSize(value.`start`) +
+            FfiConverterInt.allocationSize(value.`end`)
+    )
 
-    override fun write(
-        value: MobileSyntaxToken,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterTypeMobileSyntaxType.write(value.`kind`, buf)
-        FfiConverterInt.write(value.`start`, buf)
-        FfiConverterInt.write(value.`end`, buf)
+    override fun write(value: MobileSyntaxToken, buf: ByteBuffer) {
+            FfiConverterTypeMobileSyntaxType.write(value.`kind`, buf)
+            FfiConverterInt.write(value.`start`, buf)
+            FfiConverterInt.write(value.`end`, buf)
     }
 }
 
-data class MobileTag(
-    var `name`: kotlin.String,
-    var `displayName`: kotlin.String,
-    var `count`: kotlin.UInt,
-    var `depth`: kotlin.UInt,
-    var `hasChildren`: kotlin.Boolean,
-    var `isExpanded`: kotlin.Boolean,
-    var `isUncategorized`: kotlin.Boolean,
-) {
+
+
+data class MobileTag (
+    var `name`: kotlin.String
+    , 
+    var `displayName`: kotlin.String
+    , 
+    var `count`: kotlin.UInt
+    , 
+    var `depth`: kotlin.UInt
+    , 
+    var `hasChildren`: kotlin.Boolean
+    , 
+    var `isExpanded`: kotlin.Boolean
+    , 
+    var `isUncategorized`: kotlin.Boolean
+    
+){
+    
+
+    
+
+    
     companion object
 }
 
 /**
  * @suppress
  */
-public object FfiConverterTypeMobileTag : FfiConverterRustBuffer<MobileTag> {
-    override fun read(buf: ByteBuffer): MobileTag =
-        MobileTag(
+public object FfiConverterTypeMobileTag: FfiConverterRustBuffer<MobileTag> {
+    override fun read(buf: ByteBuffer): MobileTag {
+        return MobileTag(
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
             FfiConverterUInt.read(buf),
@@ -4925,101 +6032,213 @@ public object FfiConverterTypeMobileTag : FfiConverterRustBuffer<MobileTag> {
             FfiConverterBoolean.read(buf),
             FfiConverterBoolean.read(buf),
         )
+    }
 
-    override fun allocationSize(value: MobileTag) =
-        (
+    override fun allocationSize(value: MobileTag) = (
             FfiConverterString.allocationSize(value.`name`) +
-                FfiConverterString.allocationSize(value.`displayName`) +
-                FfiConverterUInt.allocationSize(value.`count`) +
-                FfiConverterUInt.allocationSize(value.`depth`) +
-                FfiConverterBoolean.allocationSize(value.`hasChildren`) +
-                FfiConverterBoolean.allocationSize(value.`isExpanded`) +
-                FfiConverterBoolean.allocationSize(value.`isUncategorized`)
+            FfiConverterString.allocationSize(value.`displayName`) +
+            FfiConverterUInt.allocationSize(value.`count`) +
+            FfiConverterUInt.allocationSize(value.`depth`) +
+            FfiConverterBoolean.allocationSize(value.`hasChildren`) +
+            FfiConverterBoolean.allocationSize(value.`isExpanded`) +
+            FfiConverterBoolean.allocationSize(value.`isUncategorized`)
+    )
+
+    override fun write(value: MobileTag, buf: ByteBuffer) {
+            FfiConverterString.write(value.`name`, buf)
+            FfiConverterString.write(value.`displayName`, buf)
+            FfiConverterUInt.write(value.`count`, buf)
+            FfiConverterUInt.write(value.`depth`, buf)
+            FfiConverterBoolean.write(value.`hasChildren`, buf)
+            FfiConverterBoolean.write(value.`isExpanded`, buf)
+            FfiConverterBoolean.write(value.`isUncategorized`, buf)
+    }
+}
+
+
+
+/**
+ * What a tag rename touched.
+ */
+data class MobileTagRename (
+    var `tasks`: kotlin.UInt
+    , 
+    var `aliases`: kotlin.UInt
+    
+){
+    
+
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeMobileTagRename: FfiConverterRustBuffer<MobileTagRename> {
+    override fun read(buf: ByteBuffer): MobileTagRename {
+        return MobileTagRename(
+            FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
         )
+    }
+
+    override fun allocationSize(value: MobileTagRename) = (
+            FfiConverterUInt.allocationSize(value.`tasks`) +
+            FfiConverterUInt.allocationSize(value.`aliases`)
+    )
 
-    override fun write(
-        value: MobileTag,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`name`, buf)
-        FfiConverterString.write(value.`displayName`, buf)
-        FfiConverterUInt.write(value.`count`, buf)
-        FfiConverterUInt.write(value.`depth`, buf)
-        FfiConverterBoolean.write(value.`hasChildren`, buf)
-        FfiConverterBoolean.write(value.`isExpanded`, buf)
-        FfiConverterBoolean.write(value.`isUncategorized`, buf)
-    }
-}
-
-data class MobileTask(
-    var `uid`: kotlin.String,
-    var `summary`: kotlin.String,
-    var `description`: kotlin.String,
-    var `isDone`: kotlin.Boolean,
-    var `percentComplete`: kotlin.UByte?,
-    var `priority`: kotlin.UByte,
-    var `dueDateIso`: kotlin.String?,
-    var `completedDateIso`: kotlin.String?,
-    var `isAlldayDue`: kotlin.Boolean,
-    var `startDateIso`: kotlin.String?,
-    var `isAlldayStart`: kotlin.Boolean,
-    var `hasAlarms`: kotlin.Boolean,
-    var `isFutureStart`: kotlin.Boolean,
-    var `durationMins`: kotlin.UInt?,
-    var `durationMaxMins`: kotlin.UInt?,
-    var `calendarHref`: kotlin.String,
-    var `categories`: List<kotlin.String>,
-    var `isRecurring`: kotlin.Boolean,
-    var `isRelativeRecurrence`: kotlin.Boolean,
-    var `parentUid`: kotlin.String?,
-    var `smartString`: kotlin.String,
-    var `depth`: kotlin.UInt,
-    var `isBlocked`: kotlin.Boolean,
-    var `statusString`: kotlin.String,
-    var `blockedByNames`: List<kotlin.String>,
-    var `blockedByUids`: List<kotlin.String>,
-    var `blockingUids`: List<kotlin.String>,
-    var `blockingNames`: List<kotlin.String>,
-    var `relatedToUids`: List<kotlin.String>,
-    var `relatedToNames`: List<kotlin.String>,
-    var `isPaused`: kotlin.Boolean,
-    var `hasSubtasks`: kotlin.Boolean,
-    var `hasBlockingTasks`: kotlin.Boolean,
-    var `hasRelatedTasks`: kotlin.Boolean,
-    var `hasVisibleSubtasks`: kotlin.Boolean,
-    var `treeLocationCount`: kotlin.UInt,
-    var `location`: kotlin.String?,
-    var `url`: kotlin.String?,
-    var `geo`: kotlin.String?,
-    var `timeSpentSeconds`: kotlin.ULong,
-    var `lastStartedAt`: kotlin.Long?,
-    var `sessions`: List<MobileWorkSession>,
-    var `virtualType`: kotlin.String,
-    var `virtualPayload`: kotlin.String,
-    var `isCollapsed`: kotlin.Boolean,
-    var `pinned`: kotlin.Boolean,
-    var `hasExtractableSubtasks`: kotlin.Boolean,
-    var `isPermanent`: kotlin.Boolean,
-    var `createdDateIso`: kotlin.String?,
-    var `lastModifiedDateIso`: kotlin.String?,
-    var `goalProgressStr`: kotlin.String?,
-    var `goalTargetStr`: kotlin.String?,
-    var `goalHistory`: List<kotlin.Float>,
-    var `rruleHistoryStat`: kotlin.String?,
-    var `visibleCategories`: List<kotlin.String>,
-    var `visibleLocation`: kotlin.String?,
-    var `isSearchContext`: kotlin.Boolean,
-    var `isNote`: kotlin.Boolean,
-) {
+    override fun write(value: MobileTagRename, buf: ByteBuffer) {
+            FfiConverterUInt.write(value.`tasks`, buf)
+            FfiConverterUInt.write(value.`aliases`, buf)
+    }
+}
+
+
+
+data class MobileTask (
+    var `uid`: kotlin.String
+    , 
+    var `summary`: kotlin.String
+    , 
+    var `description`: kotlin.String
+    , 
+    var `isDone`: kotlin.Boolean
+    , 
+    var `percentComplete`: kotlin.UByte?
+    , 
+    var `priority`: kotlin.UByte
+    , 
+    var `dueDateIso`: kotlin.String?
+    , 
+    var `completedDateIso`: kotlin.String?
+    , 
+    var `isAlldayDue`: kotlin.Boolean
+    , 
+    var `startDateIso`: kotlin.String?
+    , 
+    var `isAlldayStart`: kotlin.Boolean
+    , 
+    var `hasAlarms`: kotlin.Boolean
+    , 
+    var `alarms`: List<MobileAlarm>
+    , 
+    var `isFutureStart`: kotlin.Boolean
+    , 
+    var `durationMins`: kotlin.UInt?
+    , 
+    var `durationMaxMins`: kotlin.UInt?
+    , 
+    var `calendarHref`: kotlin.String
+    , 
+    var `categories`: List<kotlin.String>
+    , 
+    var `isRecurring`: kotlin.Boolean
+    , 
+    var `isRelativeRecurrence`: kotlin.Boolean
+    , 
+    var `parentUid`: kotlin.String?
+    , 
+    var `smartString`: kotlin.String
+    , 
+    var `depth`: kotlin.UInt
+    , 
+    var `isBlocked`: kotlin.Boolean
+    , 
+    var `statusString`: kotlin.String
+    , 
+    var `blockedByNames`: List<kotlin.String>
+    , 
+    var `blockedByUids`: List<kotlin.String>
+    , 
+    var `blockingUids`: List<kotlin.String>
+    , 
+    var `blockingNames`: List<kotlin.String>
+    , 
+    var `relatedToUids`: List<kotlin.String>
+    , 
+    var `relatedToNames`: List<kotlin.String>
+    , 
+    var `isPaused`: kotlin.Boolean
+    , 
+    var `hasSubtasks`: kotlin.Boolean
+    , 
+    var `hasBlockingTasks`: kotlin.Boolean
+    , 
+    var `hasRelatedTasks`: kotlin.Boolean
+    , 
+    var `hasVisibleSubtasks`: kotlin.Boolean
+    , 
+    var `treeLocationCount`: kotlin.UInt
+    , 
+    var `location`: kotlin.String?
+    , 
+    var `url`: kotlin.String?
+    , 
+    var `geo`: kotlin.String?
+    , 
+    var `timeSpentSeconds`: kotlin.ULong
+    , 
+    var `lastStartedAt`: kotlin.Long?
+    , 
+    var `sessions`: List<MobileWorkSession>
+    , 
+    var `virtualType`: kotlin.String
+    , 
+    var `virtualPayload`: kotlin.String
+    , 
+    var `isCollapsed`: kotlin.Boolean
+    , 
+    var `pinned`: kotlin.Boolean
+    , 
+    var `isWaiting`: kotlin.Boolean
+    , 
+    var `hasExtractableSubtasks`: kotlin.Boolean
+    , 
+    var `isPermanent`: kotlin.Boolean
+    , 
+    var `createdDateIso`: kotlin.String?
+    , 
+    var `lastModifiedDateIso`: kotlin.String?
+    , 
+    var `goalProgressStr`: kotlin.String?
+    , 
+    var `goalTargetStr`: kotlin.String?
+    , 
+    var `goalHistory`: List<kotlin.Float>
+    , 
+    var `rruleHistoryStat`: kotlin.String?
+    , 
+    /**
+     * "Done N times, last on X" from the completion log kept on recurring tasks.
+     */
+    var `completionLogStat`: kotlin.String?
+    , 
+    var `visibleCategories`: List<kotlin.String>
+    , 
+    var `visibleLocation`: kotlin.String?
+    , 
+    var `isSearchContext`: kotlin.Boolean
+    , 
+    var `isNote`: kotlin.Boolean
+    
+){
+    
+
+    
+
+    
     companion object
 }
 
 /**
  * @suppress
  */
-public object FfiConverterTypeMobileTask : FfiConverterRustBuffer<MobileTask> {
-    override fun read(buf: ByteBuffer): MobileTask =
-        MobileTask(
+public object FfiConverterTypeMobileTask: FfiConverterRustBuffer<MobileTask> {
+    override fun read(buf: ByteBuffer): MobileTask {
+        return MobileTask(
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
@@ -5032,6 +6251,7 @@ public object FfiConverterTypeMobileTask : FfiConverterRustBuffer<MobileTask> {
             FfiConverterOptionalString.read(buf),
             FfiConverterBoolean.read(buf),
             FfiConverterBoolean.read(buf),
+            FfiConverterSequenceTypeMobileAlarm.read(buf),
             FfiConverterBoolean.read(buf),
             FfiConverterOptionalUInt.read(buf),
             FfiConverterOptionalUInt.read(buf),
@@ -5068,277 +6288,336 @@ public object FfiConverterTypeMobileTask : FfiConverterRustBuffer<MobileTask> {
             FfiConverterBoolean.read(buf),
             FfiConverterBoolean.read(buf),
             FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
             FfiConverterOptionalString.read(buf),
             FfiConverterOptionalString.read(buf),
             FfiConverterOptionalString.read(buf),
             FfiConverterOptionalString.read(buf),
             FfiConverterSequenceFloat.read(buf),
             FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalString.read(buf),
             FfiConverterSequenceString.read(buf),
             FfiConverterOptionalString.read(buf),
             FfiConverterBoolean.read(buf),
             FfiConverterBoolean.read(buf),
         )
+    }
 
-    override fun allocationSize(value: MobileTask) =
-        (
+    override fun allocationSize(value: MobileTask) = (
             FfiConverterString.allocationSize(value.`uid`) +
-                FfiConverterString.allocationSize(value.`summary`) +
-                FfiConverterString.allocationSize(value.`description`) +
-                FfiConverterBoolean.allocationSize(value.`isDone`) +
-                FfiConverterOptionalUByte.allocationSize(value.`percentComplete`) +
-                FfiConverterUByte.allocationSize(value.`priority`) +
-                FfiConverterOptionalString.allocationSize(value.`dueDateIso`) +
-                FfiConverterOptionalString.allocationSize(value.`completedDateIso`) +
-                FfiConverterBoolean.allocationSize(value.`isAlldayDue`) +
-                FfiConverterOptionalString.allocationSize(value.`startDateIso`) +
-                FfiConverterBoolean.allocationSize(value.`isAlldayStart`) +
-                FfiConverterBoolean.allocationSize(value.`hasAlarms`) +
-                FfiConverterBoolean.allocationSize(value.`isFutureStart`) +
-                FfiConverterOptionalUInt.allocationSize(value.`durationMins`) +
-                FfiConverterOptionalUInt.allocationSize(value.`durationMaxMins`) +
-                FfiConverterString.allocationSize(value.`calendarHref`) +
-                FfiConverterSequenceString.allocationSize(value.`categories`) +
-                FfiConverterBoolean.allocationSize(value.`isRecurring`) +
-                FfiConverterBoolean.allocationSize(value.`isRelativeRecurrence`) +
-                FfiConverterOptionalString.allocationSize(value.`parentUid`) +
-                FfiConverterString.allocationSize(value.`smartString`) +
-                FfiConverterUInt.allocationSize(value.`depth`) +
-                FfiConverterBoolean.allocationSize(value.`isBlocked`) +
-                FfiConverterString.allocationSize(value.`statusString`) +
-                FfiConverterSequenceString.allocationSize(value.`blockedByNames`) +
-                FfiConverterSequenceString.allocationSize(value.`blockedByUids`) +
-                FfiConverterSequenceString.allocationSize(value.`blockingUids`) +
-                FfiConverterSequenceString.allocationSize(value.`blockingNames`) +
-                FfiConverterSequenceString.allocationSize(value.`relatedToUids`) +
-                FfiConverterSequenceString.allocationSize(value.`relatedToNames`) +
-                FfiConverterBoolean.allocationSize(value.`isPaused`) +
-                FfiConverterBoolean.allocationSize(value.`hasSubtasks`) +
-                FfiConverterBoolean.allocationSize(value.`hasBlockingTasks`) +
-                FfiConverterBoolean.allocationSize(value.`hasRelatedTasks`) +
-                FfiConverterBoolean.allocationSize(value.`hasVisibleSubtasks`) +
-                FfiConverterUInt.allocationSize(value.`treeLocationCount`) +
-                FfiConverterOptionalString.allocationSize(value.`location`) +
-                FfiConverterOptionalString.allocationSize(value.`url`) +
-                FfiConverterOptionalString.allocationSize(value.`geo`) +
-                FfiConverterULong.allocationSize(value.`timeSpentSeconds`) +
-                FfiConverterOptionalLong.allocationSize(value.`lastStartedAt`) +
-                FfiConverterSequenceTypeMobileWorkSession.allocationSize(value.`sessions`) +
-                FfiConverterString.allocationSize(value.`virtualType`) +
-                FfiConverterString.allocationSize(value.`virtualPayload`) +
-                FfiConverterBoolean.allocationSize(value.`isCollapsed`) +
-                FfiConverterBoolean.allocationSize(value.`pinned`) +
-                FfiConverterBoolean.allocationSize(value.`hasExtractableSubtasks`) +
-                FfiConverterBoolean.allocationSize(value.`isPermanent`) +
-                FfiConverterOptionalString.allocationSize(value.`createdDateIso`) +
-                FfiConverterOptionalString.allocationSize(value.`lastModifiedDateIso`) +
-                FfiConverterOptionalString.allocationSize(value.`goalProgressStr`) +
-                FfiConverterOptionalString.allocationSize(value.`goalTargetStr`) +
-                FfiConverterSequenceFloat.allocationSize(value.`goalHistory`) +
-                FfiConverterOptionalString.allocationSize(value.`rruleHistoryStat`) +
-                FfiConverterSequenceString.allocationSize(value.`visibleCategories`) +
-                FfiConverterOptionalString.allocationSize(value.`visibleLocation`) +
-                FfiConverterBoolean.allocationSize(value.`isSearchContext`) +
-                FfiConverterBoolean.allocationSize(value.`isNote`)
-        )
+            FfiConverterString.allocationSize(value.`summary`) +
+            FfiConverterString.allocationSize(value.`description`) +
+            FfiConverterBoolean.allocationSize(value.`isDone`) +
+            FfiConverterOptionalUByte.allocationSize(value.`percentComplete`) +
+            FfiConverterUByte.allocationSize(value.`priority`) +
+            FfiConverterOptionalString.allocationSize(value.`dueDateIso`) +
+            FfiConverterOptionalString.allocationSize(value.`completedDateIso`) +
+            FfiConverterBoolean.allocationSize(value.`isAlldayDue`) +
+            FfiConverterOptionalString.allocationSize(value.`startDateIso`) +
+            FfiConverterBoolean.allocationSize(value.`isAlldayStart`) +
+            FfiConverterBoolean.allocationSize(value.`hasAlarms`) +
+            FfiConverterSequenceTypeMobileAlarm.allocationSize(value.`alarms`) +
+            FfiConverterBoolean.allocationSize(value.`isFutureStart`) +
+            FfiConverterOptionalUInt.allocationSize(value.`durationMins`) +
+            FfiConverterOptionalUInt.allocationSize(value.`durationMaxMins`) +
+            FfiConverterString.allocationSize(value.`calendarHref`) +
+            FfiConverterSequenceString.allocationSize(value.`categories`) +
+            FfiConverterBoolean.allocationSize(value.`isRecurring`) +
+            FfiConverterBoolean.allocationSize(value.`isRelativeRecurrence`) +
+            FfiConverterOptionalString.allocationSize(value.`parentUid`) +
+            FfiConverterString.allocationSize(value.`smartString`) +
+            FfiConverterUInt.allocationSize(value.`depth`) +
+            FfiConverterBoolean.allocationSize(value.`isBlocked`) +
+            FfiConverterString.allocationSize(value.`statusString`) +
+            FfiConverterSequenceString.allocationSize(value.`blockedByNames`) +
+            FfiConverterSequenceString.allocationSize(value.`blockedByUids`) +
+            FfiConverterSequenceString.allocationSize(value.`blockingUids`) +
+            FfiConverterSequenceString.allocationSize(value.`blockingNames`) +
+            FfiConverterSequenceString.allocationSize(value.`relatedToUids`) +
+            FfiConverterSequenceString.allocationSize(value.`relatedToNames`) +
+            FfiConverterBoolean.allocationSize(value.`isPaused`) +
+            FfiConverterBoolean.allocationSize(value.`hasSubtasks`) +
+            FfiConverterBoolean.allocationSize(value.`hasBlockingTasks`) +
+            FfiConverterBoolean.allocationSize(value.`hasRelatedTasks`) +
+            FfiConverterBoolean.allocationSize(value.`hasVisibleSubtasks`) +
+            FfiConverterUInt.allocationSize(value.`treeLocationCount`) +
+            FfiConverterOptionalString.allocationSize(value.`location`) +
+            FfiConverterOptionalString.allocationSize(value.`url`) +
+            FfiConverterOptionalString.allocationSize(value.`geo`) +
+            FfiConverterULong.allocationSize(value.`timeSpentSeconds`) +
+            FfiConverterOptionalLong.allocationSize(value.`lastStartedAt`) +
+            FfiConverterSequenceTypeMobileWorkSession.allocationSize(value.`sessions`) +
+            FfiConverterString.allocationSize(value.`virtualType`) +
+            FfiConverterString.allocationSize(value.`virtualPayload`) +
+            FfiConverterBoolean.allocationSize(value.`isCollapsed`) +
+            FfiConverterBoolean.allocationSize(value.`pinned`) +
+            FfiConverterBoolean.allocationSize(value.`isWaiting`) +
+            FfiConverterBoolean.allocationSize(value.`hasExtractableSubtasks`) +
+            FfiConverterBoolean.allocationSize(value.`isPermanent`) +
+            FfiConverterOptionalString.allocationSize(value.`createdDateIso`) +
+            FfiConverterOptionalString.allocationSize(value.`lastModifiedDateIso`) +
+            FfiConverterOptionalString.allocationSize(value.`goalProgressStr`) +
+            FfiConverterOptionalString.allocationSize(value.`goalTargetStr`) +
+            FfiConverterSequenceFloat.allocationSize(value.`goalHistory`) +
+            FfiConverterOptionalString.allocationSize(value.`rruleHistoryStat`) +
+            FfiConverterOptionalString.allocationSize(value.`completionLogStat`) +
+            FfiConverterSequenceString.allocationSize(value.`visibleCategories`) +
+            FfiConverterOptionalString.allocationSize(value.`visibleLocation`) +
+            FfiConverterBoolean.allocationSize(value.`isSearchContext`) +
+            FfiConverterBoolean.allocationSize(value.`isNote`)
+    )
 
-    override fun write(
-        value: MobileTask,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`uid`, buf)
-        FfiConverterString.write(value.`summary`, buf)
-        FfiConverterString.write(value.`description`, buf)
-        FfiConverterBoolean.write(value.`isDone`, buf)
-        FfiConverterOptionalUByte.write(value.`percentComplete`, buf)
-        FfiConverterUByte.write(value.`priority`, buf)
-        FfiConverterOptionalString.write(value.`dueDateIso`, buf)
-        FfiConverterOptionalString.write(value.`completedDateIso`, buf)
-        FfiConverterBoolean.write(value.`isAlldayDue`, buf)
-        FfiConverterOptionalString.write(value.`startDateIso`, buf)
-        FfiConverterBoolean.write(value.`isAlldayStart`, buf)
-        FfiConverterBoolean.write(value.`hasAlarms`, buf)
-        FfiConverterBoolean.write(value.`isFutureStart`, buf)
-        FfiConverterOptionalUInt.write(value.`durationMins`, buf)
-        FfiConverterOptionalUInt.write(value.`durationMaxMins`, buf)
-        FfiConverterString.write(value.`calendarHref`, buf)
-        FfiConverterSequenceString.write(value.`categories`, buf)
-        FfiConverterBoolean.write(value.`isRecurring`, buf)
-        FfiConverterBoolean.write(value.`isRelativeRecurrence`, buf)
-        FfiConverterOptionalString.write(value.`parentUid`, buf)
-        FfiConverterString.write(value.`smartString`, buf)
-        FfiConverterUInt.write(value.`depth`, buf)
-        FfiConverterBoolean.write(value.`isBlocked`, buf)
-        FfiConverterString.write(value.`statusString`, buf)
-        FfiConverterSequenceString.write(value.`blockedByNames`, buf)
-        FfiConverterSequenceString.write(value.`blockedByUids`, buf)
-        FfiConverterSequenceString.write(value.`blockingUids`, buf)
-        FfiConverterSequenceString.write(value.`blockingNames`, buf)
-        FfiConverterSequenceString.write(value.`relatedToUids`, buf)
-        FfiConverterSequenceString.write(value.`relatedToNames`, buf)
-        FfiConverterBoolean.write(value.`isPaused`, buf)
-        FfiConverterBoolean.write(value.`hasSubtasks`, buf)
-        FfiConverterBoolean.write(value.`hasBlockingTasks`, buf)
-        FfiConverterBoolean.write(value.`hasRelatedTasks`, buf)
-        FfiConverterBoolean.write(value.`hasVisibleSubtasks`, buf)
-        FfiConverterUInt.write(value.`treeLocationCount`, buf)
-        FfiConverterOptionalString.write(value.`location`, buf)
-        FfiConverterOptionalString.write(value.`url`, buf)
-        FfiConverterOptionalString.write(value.`geo`, buf)
-        FfiConverterULong.write(value.`timeSpentSeconds`, buf)
-        FfiConverterOptionalLong.write(value.`lastStartedAt`, buf)
-        FfiConverterSequenceTypeMobileWorkSession.write(value.`sessions`, buf)
-        FfiConverterString.write(value.`virtualType`, buf)
-        FfiConverterString.write(value.`virtualPayload`, buf)
-        FfiConverterBoolean.write(value.`isCollapsed`, buf)
-        FfiConverterBoolean.write(value.`pinned`, buf)
-        FfiConverterBoolean.write(value.`hasExtractableSubtasks`, buf)
-        FfiConverterBoolean.write(value.`isPermanent`, buf)
-        FfiConverterOptionalString.write(value.`createdDateIso`, buf)
-        FfiConverterOptionalString.write(value.`lastModifiedDateIso`, buf)
-        FfiConverterOptionalString.write(value.`goalProgressStr`, buf)
-        FfiConverterOptionalString.write(value.`goalTargetStr`, buf)
-        FfiConverterSequenceFloat.write(value.`goalHistory`, buf)
-        FfiConverterOptionalString.write(value.`rruleHistoryStat`, buf)
-        FfiConverterSequenceString.write(value.`visibleCategories`, buf)
-        FfiConverterOptionalString.write(value.`visibleLocation`, buf)
-        FfiConverterBoolean.write(value.`isSearchContext`, buf)
-        FfiConverterBoolean.write(value.`isNote`, buf)
-    }
-}
-
-data class MobileVersionInfo(
-    var `version`: kotlin.String,
-    var `commit`: kotlin.String,
-) {
+    override fun write(value: MobileTask, buf: ByteBuffer) {
+            FfiConverterString.write(value.`uid`, buf)
+            FfiConverterString.write(value.`summary`, buf)
+            FfiConverterString.write(value.`description`, buf)
+            FfiConverterBoolean.write(value.`isDone`, buf)
+            FfiConverterOptionalUByte.write(value.`percentComplete`, buf)
+            FfiConverterUByte.write(value.`priority`, buf)
+            FfiConverterOptionalString.write(value.`dueDateIso`, buf)
+            FfiConverterOptionalString.write(value.`completedDateIso`, buf)
+            FfiConverterBoolean.write(value.`isAlldayDue`, buf)
+            FfiConverterOptionalString.write(value.`startDateIso`, buf)
+            FfiConverterBoolean.write(value.`isAlldayStart`, buf)
+            FfiConverterBoolean.write(value.`hasAlarms`, buf)
+            FfiConverterSequenceTypeMobileAlarm.write(value.`alarms`, buf)
+            FfiConverterBoolean.write(value.`isFutureStart`, buf)
+            FfiConverterOptionalUInt.write(value.`durationMins`, buf)
+            FfiConverterOptionalUInt.write(value.`durationMaxMins`, buf)
+            FfiConverterString.write(value.`calendarHref`, buf)
+            FfiConverterSequenceString.write(value.`categories`, buf)
+            FfiConverterBoolean.write(value.`isRecurring`, buf)
+            FfiConverterBoolean.write(value.`isRelativeRecurrence`, buf)
+            FfiConverterOptionalString.write(value.`parentUid`, buf)
+            FfiConverterString.write(value.`smartString`, buf)
+            FfiConverterUInt.write(value.`depth`, buf)
+            FfiConverterBoolean.write(value.`isBlocked`, buf)
+            FfiConverterString.write(value.`statusString`, buf)
+            FfiConverterSequenceString.write(value.`blockedByNames`, buf)
+            FfiConverterSequenceString.write(value.`blockedByUids`, buf)
+            FfiConverterSequenceString.write(value.`blockingUids`, buf)
+            FfiConverterSequenceString.write(value.`blockingNames`, buf)
+            FfiConverterSequenceString.write(value.`relatedToUids`, buf)
+            FfiConverterSequenceString.write(value.`relatedToNames`, buf)
+            FfiConverterBoolean.write(value.`isPaused`, buf)
+            FfiConverterBoolean.write(value.`hasSubtasks`, buf)
+            FfiConverterBoolean.write(value.`hasBlockingTasks`, buf)
+            FfiConverterBoolean.write(value.`hasRelatedTasks`, buf)
+            FfiConverterBoolean.write(value.`hasVisibleSubtasks`, buf)
+            FfiConverterUInt.write(value.`treeLocationCount`, buf)
+            FfiConverterOptionalString.write(value.`location`, buf)
+            FfiConverterOptionalString.write(value.`url`, buf)
+            FfiConverterOptionalString.write(value.`geo`, buf)
+            FfiConverterULong.write(value.`timeSpentSeconds`, buf)
+            FfiConverterOptionalLong.write(value.`lastStartedAt`, buf)
+            FfiConverterSequenceTypeMobileWorkSession.write(value.`sessions`, buf)
+            FfiConverterString.write(value.`virtualType`, buf)
+            FfiConverterString.write(value.`virtualPayload`, buf)
+            FfiConverterBoolean.write(value.`isCollapsed`, buf)
+            FfiConverterBoolean.write(value.`pinned`, buf)
+            FfiConverterBoolean.write(value.`isWaiting`, buf)
+            FfiConverterBoolean.write(value.`hasExtractableSubtasks`, buf)
+            FfiConverterBoolean.write(value.`isPermanent`, buf)
+            FfiConverterOptionalString.write(value.`createdDateIso`, buf)
+            FfiConverterOptionalString.write(value.`lastModifiedDateIso`, buf)
+            FfiConverterOptionalString.write(value.`goalProgressStr`, buf)
+            FfiConverterOptionalString.write(value.`goalTargetStr`, buf)
+            FfiConverterSequenceFloat.write(value.`goalHistory`, buf)
+            FfiConverterOptionalString.write(value.`rruleHistoryStat`, buf)
+            FfiConverterOptionalString.write(value.`completionLogStat`, buf)
+            FfiConverterSequenceString.write(value.`visibleCategories`, buf)
+            FfiConverterOptionalString.write(value.`visibleLocation`, buf)
+            FfiConverterBoolean.write(value.`isSearchContext`, buf)
+            FfiConverterBoolean.write(value.`isNote`, buf)
+    }
+}
+
+
+
+data class MobileVersionInfo (
+    var `version`: kotlin.String
+    , 
+    var `commit`: kotlin.String
+    
+){
+    
+
+    
+
+    
     companion object
 }
 
 /**
  * @suppress
  */
-public object FfiConverterTypeMobileVersionInfo : FfiConverterRustBuffer<MobileVersionInfo> {
-    override fun read(buf: ByteBuffer): MobileVersionInfo =
-        MobileVersionInfo(
+public object FfiConverterTypeMobileVersionInfo: FfiConverterRustBuffer<MobileVersionInfo> {
+    override fun read(buf: ByteBuffer): MobileVersionInfo {
+        return MobileVersionInfo(
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
         )
+    }
 
-    override fun allocationSize(value: MobileVersionInfo) =
-        (
+    override fun allocationSize(value: MobileVersionInfo) = (
             FfiConverterString.allocationSize(value.`version`) +
-                FfiConverterString.allocationSize(value.`commit`)
-        )
+            FfiConverterString.allocationSize(value.`commit`)
+    )
 
-    override fun write(
-        value: MobileVersionInfo,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`version`, buf)
-        FfiConverterString.write(value.`commit`, buf)
+    override fun write(value: MobileVersionInfo, buf: ByteBuffer) {
+            FfiConverterString.write(value.`version`, buf)
+            FfiConverterString.write(value.`commit`, buf)
     }
 }
 
-data class MobileViewData(
-    var `tasks`: List<MobileTask>,
-    var `tags`: List<MobileTag>,
-    var `locations`: List<MobileLocation>,
-    var `goals`: List<MobileGoalProgress>,
-    var `focusedTaskUid`: kotlin.String?,
-) {
+
+
+data class MobileViewData (
+    var `tasks`: List<MobileTask>
+    , 
+    var `tags`: List<MobileTag>
+    , 
+    var `locations`: List<MobileLocation>
+    , 
+    var `goals`: List<MobileGoalProgress>
+    , 
+    var `focusedTaskUid`: kotlin.String?
+    , 
+    /**
+     * Summed estimate of the open tasks in view (e.g. `~3h`), for the header.
+     */
+    var `estimatedTotal`: kotlin.String?
+    , 
+    /**
+     * Number of inbox tasks across the visible calendars, for the inbox filter chip.
+     */
+    var `inboxCount`: kotlin.UInt
+    
+){
+    
+
+    
+
+    
     companion object
 }
 
 /**
  * @suppress
  */
-public object FfiConverterTypeMobileViewData : FfiConverterRustBuffer<MobileViewData> {
-    override fun read(buf: ByteBuffer): MobileViewData =
-        MobileViewData(
+public object FfiConverterTypeMobileViewData: FfiConverterRustBuffer<MobileViewData> {
+    override fun read(buf: ByteBuffer): MobileViewData {
+        return MobileViewData(
             FfiConverterSequenceTypeMobileTask.read(buf),
             FfiConverterSequenceTypeMobileTag.read(buf),
             FfiConverterSequenceTypeMobileLocation.read(buf),
             FfiConverterSequenceTypeMobileGoalProgress.read(buf),
             FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterUInt.read(buf),
         )
+    }
 
-    override fun allocationSize(value: MobileViewData) =
-        (
+    override fun allocationSize(value: MobileViewData) = (
             FfiConverterSequenceTypeMobileTask.allocationSize(value.`tasks`) +
-                FfiConverterSequenceTypeMobileTag.allocationSize(value.`tags`) +
-                FfiConverterSequenceTypeMobileLocation.allocationSize(value.`locations`) +
-                FfiConverterSequenceTypeMobileGoalProgress.allocationSize(value.`goals`) +
-                FfiConverterOptionalString.allocationSize(value.`focusedTaskUid`)
-        )
+            FfiConverterSequenceTypeMobileTag.allocationSize(value.`tags`) +
+            FfiConverterSequenceTypeMobileLocation.allocationSize(value.`locations`) +
+            FfiConverterSequenceTypeMobileGoalProgress.allocationSize(value.`goals`) +
+            FfiConverterOptionalString.allocationSize(value.`focusedTaskUid`) +
+            FfiConverterOptionalString.allocationSize(value.`estimatedTotal`) +
+            FfiConverterUInt.allocationSize(value.`inboxCount`)
+    )
 
-    override fun write(
-        value: MobileViewData,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterSequenceTypeMobileTask.write(value.`tasks`, buf)
-        FfiConverterSequenceTypeMobileTag.write(value.`tags`, buf)
-        FfiConverterSequenceTypeMobileLocation.write(value.`locations`, buf)
-        FfiConverterSequenceTypeMobileGoalProgress.write(value.`goals`, buf)
-        FfiConverterOptionalString.write(value.`focusedTaskUid`, buf)
+    override fun write(value: MobileViewData, buf: ByteBuffer) {
+            FfiConverterSequenceTypeMobileTask.write(value.`tasks`, buf)
+            FfiConverterSequenceTypeMobileTag.write(value.`tags`, buf)
+            FfiConverterSequenceTypeMobileLocation.write(value.`locations`, buf)
+            FfiConverterSequenceTypeMobileGoalProgress.write(value.`goals`, buf)
+            FfiConverterOptionalString.write(value.`focusedTaskUid`, buf)
+            FfiConverterOptionalString.write(value.`estimatedTotal`, buf)
+            FfiConverterUInt.write(value.`inboxCount`, buf)
     }
 }
 
-data class MobileWorkSession(
-    var `startMs`: kotlin.Long,
-    var `endMs`: kotlin.Long,
-) {
+
+
+data class MobileWorkSession (
+    var `startMs`: kotlin.Long
+    , 
+    var `endMs`: kotlin.Long
+    
+){
+    
+
+    
+
+    
     companion object
 }
 
 /**
  * @suppress
  */
-public object FfiConverterTypeMobileWorkSession : FfiConverterRustBuffer<MobileWorkSession> {
-    override fun read(buf: ByteBuffer): MobileWorkSession =
-        MobileWorkSession(
+public object FfiConverterTypeMobileWorkSession: FfiConverterRustBuffer<MobileWorkSession> {
+    override fun read(buf: ByteBuffer): MobileWorkSession {
+        return MobileWorkSession(
             FfiConverterLong.read(buf),
             FfiConverterLong.read(buf),
         )
+    }
 
-    override fun allocationSize(value: MobileWorkSession) =
-        (
+    override fun allocationSize(value: MobileWorkSession) = (
             FfiConverterLong.allocationSize(value.`startMs`) +
-                FfiConverterLong.allocationSize(value.`endMs`)
-        )
+            FfiConverterLong.allocationSize(value.`endMs`)
+    )
 
-    override fun write(
-        value: MobileWorkSession,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterLong.write(value.`startMs`, buf)
-        FfiConverterLong.write(value.`endMs`, buf)
+    override fun write(value: MobileWorkSession, buf: ByteBuffer) {
+            FfiConverterLong.write(value.`startMs`, buf)
+            FfiConverterLong.write(value.`endMs`, buf)
     }
 }
 
+
+
 /**
  * Unified session state held by the Rust core for each active client.
  */
-data class SessionState(
-    var `searchTerm`: kotlin.String,
-    var `selectedCategories`: List<kotlin.String>,
-    var `selectedLocations`: List<kotlin.String>,
-    var `activeCalendarHref`: kotlin.String?,
-    var `matchAllCategories`: kotlin.Boolean,
-    var `expandedDoneGroups`: List<kotlin.String>,
-    var `expandedTags`: List<kotlin.String>,
-    var `expandedLocations`: List<kotlin.String>,
-    var `searchCollapsedTasks`: List<kotlin.String>,
-    var `focusedTaskUid`: kotlin.String?,
-) {
+data class SessionState (
+    var `searchTerm`: kotlin.String
+    , 
+    var `selectedCategories`: List<kotlin.String>
+    , 
+    var `selectedLocations`: List<kotlin.String>
+    , 
+    var `activeCalendarHref`: kotlin.String?
+    , 
+    var `matchAllCategories`: kotlin.Boolean
+    , 
+    var `expandedDoneGroups`: List<kotlin.String>
+    , 
+    var `expandedTags`: List<kotlin.String>
+    , 
+    var `expandedLocations`: List<kotlin.String>
+    , 
+    var `searchCollapsedTasks`: List<kotlin.String>
+    , 
+    var `focusedTaskUid`: kotlin.String?
+    
+){
+    
+
+    
+
+    
     companion object
 }
 
 /**
  * @suppress
  */
-public object FfiConverterTypeSessionState : FfiConverterRustBuffer<SessionState> {
-    override fun read(buf: ByteBuffer): SessionState =
-        SessionState(
+public object FfiConverterTypeSessionState: FfiConverterRustBuffer<SessionState> {
+    override fun read(buf: ByteBuffer): SessionState {
+        return SessionState(
             FfiConverterString.read(buf),
             FfiConverterSequenceString.read(buf),
             FfiConverterSequenceString.read(buf),
@@ -5350,918 +6629,1102 @@ public object FfiConverterTypeSessionState : FfiConverterRustBuffer<SessionState
             FfiConverterSequenceString.read(buf),
             FfiConverterOptionalString.read(buf),
         )
+    }
 
-    override fun allocationSize(value: SessionState) =
-        (
+    override fun allocationSize(value: SessionState) = (
             FfiConverterString.allocationSize(value.`searchTerm`) +
-                FfiConverterSequenceString.allocationSize(value.`selectedCategories`) +
-                FfiConverterSequenceString.allocationSize(value.`selectedLocations`) +
-                FfiConverterOptionalString.allocationSize(value.`activeCalendarHref`) +
-                FfiConverterBoolean.allocationSize(value.`matchAllCategories`) +
-                FfiConverterSequenceString.allocationSize(value.`expandedDoneGroups`) +
-                FfiConverterSequenceString.allocationSize(value.`expandedTags`) +
-                FfiConverterSequenceString.allocationSize(value.`expandedLocations`) +
-                FfiConverterSequenceString.allocationSize(value.`searchCollapsedTasks`) +
-                FfiConverterOptionalString.allocationSize(value.`focusedTaskUid`)
-        )
+            FfiConverterSequenceString.allocationSize(value.`selectedCategories`) +
+            FfiConverterSequenceString.allocationSize(value.`selectedLocations`) +
+            FfiConverterOptionalString.allocationSize(value.`activeCalendarHref`) +
+            FfiConverterBoolean.allocationSize(value.`matchAllCategories`) +
+            FfiConverterSequenceString.allocationSize(value.`expandedDoneGroups`) +
+            FfiConverterSequenceString.allocationSize(value.`expandedTags`) +
+            FfiConverterSequenceString.allocationSize(value.`expandedLocations`) +
+            FfiConverterSequenceString.allocationSize(value.`searchCollapsedTasks`) +
+            FfiConverterOptionalString.allocationSize(value.`focusedTaskUid`)
+    )
 
-    override fun write(
-        value: SessionState,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`searchTerm`, buf)
-        FfiConverterSequenceString.write(value.`selectedCategories`, buf)
-        FfiConverterSequenceString.write(value.`selectedLocations`, buf)
-        FfiConverterOptionalString.write(value.`activeCalendarHref`, buf)
-        FfiConverterBoolean.write(value.`matchAllCategories`, buf)
-        FfiConverterSequenceString.write(value.`expandedDoneGroups`, buf)
-        FfiConverterSequenceString.write(value.`expandedTags`, buf)
-        FfiConverterSequenceString.write(value.`expandedLocations`, buf)
-        FfiConverterSequenceString.write(value.`searchCollapsedTasks`, buf)
-        FfiConverterOptionalString.write(value.`focusedTaskUid`, buf)
+    override fun write(value: SessionState, buf: ByteBuffer) {
+            FfiConverterString.write(value.`searchTerm`, buf)
+            FfiConverterSequenceString.write(value.`selectedCategories`, buf)
+            FfiConverterSequenceString.write(value.`selectedLocations`, buf)
+            FfiConverterOptionalString.write(value.`activeCalendarHref`, buf)
+            FfiConverterBoolean.write(value.`matchAllCategories`, buf)
+            FfiConverterSequenceString.write(value.`expandedDoneGroups`, buf)
+            FfiConverterSequenceString.write(value.`expandedTags`, buf)
+            FfiConverterSequenceString.write(value.`expandedLocations`, buf)
+            FfiConverterSequenceString.write(value.`searchCollapsedTasks`, buf)
+            FfiConverterOptionalString.write(value.`focusedTaskUid`, buf)
     }
 }
 
+
+
 /**
  * A generic intent dispatched by any UI (Mobile, GUI, TUI).
  */
 sealed class AppIntent {
+    
     data class ToggleTask(
-        val `uid`: kotlin.String,
-    ) : AppIntent() {
+        val `uid`: kotlin.String) : AppIntent()
+        
+    {
+        
+
         companion object
     }
-
+    
     data class ToggleTaskShift(
-        val `uid`: kotlin.String,
-    ) : AppIntent() {
+        val `uid`: kotlin.String) : AppIntent()
+        
+    {
+        
+
         companion object
     }
-
+    
     data class DeleteTask(
-        val `uid`: kotlin.String,
-    ) : AppIntent() {
+        val `uid`: kotlin.String) : AppIntent()
+        
+    {
+        
+
         companion object
     }
-
+    
     data class DeleteTaskTree(
-        val `uid`: kotlin.String,
-    ) : AppIntent() {
+        val `uid`: kotlin.String) : AppIntent()
+        
+    {
+        
+
         companion object
     }
-
+    
     data class TogglePin(
-        val `uid`: kotlin.String,
-    ) : AppIntent() {
+        val `uid`: kotlin.String) : AppIntent()
+        
+    {
+        
+
         companion object
     }
+    
+    data class ToggleWaiting(
+        val `uid`: kotlin.String) : AppIntent()
+        
+    {
+        
 
-    data class CancelTask(
-        val `uid`: kotlin.String,
-    ) : AppIntent() {
         companion object
     }
+    
+    data class CancelTask(
+        val `uid`: kotlin.String) : AppIntent()
+        
+    {
+        
 
-    data class ChangePriority(
-        val `uid`: kotlin.String,
-        val `delta`: kotlin.Byte,
-    ) : AppIntent() {
         companion object
     }
+    
+    data class ChangePriority(
+        val `uid`: kotlin.String, 
+        val `delta`: kotlin.Byte) : AppIntent()
+        
+    {
+        
 
-    data class StartTask(
-        val `uid`: kotlin.String,
-    ) : AppIntent() {
         companion object
     }
+    
+    data class StartTask(
+        val `uid`: kotlin.String) : AppIntent()
+        
+    {
+        
 
-    data class PauseTask(
-        val `uid`: kotlin.String,
-    ) : AppIntent() {
         companion object
     }
+    
+    data class PauseTask(
+        val `uid`: kotlin.String) : AppIntent()
+        
+    {
+        
 
-    data class StopTask(
-        val `uid`: kotlin.String,
-    ) : AppIntent() {
         companion object
     }
+    
+    data class StopTask(
+        val `uid`: kotlin.String) : AppIntent()
+        
+    {
+        
 
-    data class MoveTask(
-        val `uid`: kotlin.String,
-        val `targetHref`: kotlin.String,
-    ) : AppIntent() {
         companion object
     }
+    
+    data class SetProgress(
+        val `uid`: kotlin.String, 
+        val `percent`: kotlin.UByte) : AppIntent()
+        
+    {
+        
 
-    data class DuplicateTaskTree(
-        val `uid`: kotlin.String,
-    ) : AppIntent() {
         companion object
     }
+    
+    /**
+     * Changes only the summary; the smart-string fields are left as they are.
+     */
+    data class RenameTask(
+        val `uid`: kotlin.String, 
+        val `summary`: kotlin.String) : AppIntent()
+        
+    {
+        
 
-    data class RemoveParent(
-        val `uid`: kotlin.String,
-    ) : AppIntent() {
         companion object
     }
+    
+    /**
+     * Moves the due date to a smart-date expression (`tomorrow`, `1w`, ...); `None` clears it.
+     */
+    data class RescheduleDue(
+        val `uid`: kotlin.String, 
+        val `when`: kotlin.String?) : AppIntent()
+        
+    {
+        
 
-    data class MakeChild(
-        val `uid`: kotlin.String,
-        val `parentUid`: kotlin.String,
-    ) : AppIntent() {
         companion object
     }
+    
+    /**
+     * Moves a recurring task to its next occurrence without completing the current one.
+     */
+    data class SkipOccurrence(
+        val `uid`: kotlin.String) : AppIntent()
+        
+    {
+        
 
-    data class AddDependency(
-        val `uid`: kotlin.String,
-        val `blockerUid`: kotlin.String,
-    ) : AppIntent() {
         companion object
     }
+    
+    /**
+     * Re-queues the creation of a remote task that never reached the server.
+     */
+    data class RetryUnsynced(
+        val `uid`: kotlin.String) : AppIntent()
+        
+    {
+        
 
-    data class RemoveDependency(
-        val `uid`: kotlin.String,
-        val `blockerUid`: kotlin.String,
-    ) : AppIntent() {
         companion object
     }
+    
+    data class CompleteTasks(
+        val `uids`: List<kotlin.String>) : AppIntent()
+        
+    {
+        
 
-    data class AddRelatedTo(
-        val `uid`: kotlin.String,
-        val `relatedUid`: kotlin.String,
-    ) : AppIntent() {
         companion object
     }
+    
+    /**
+     * Adds (`add`) or removes `tag` on each listed task (batch tag edit).
+     */
+    data class SetTagOnTasks(
+        val `uids`: List<kotlin.String>, 
+        val `tag`: kotlin.String, 
+        val `add`: kotlin.Boolean) : AppIntent()
+        
+    {
+        
 
-    data class RemoveRelatedTo(
-        val `uid`: kotlin.String,
-        val `relatedUid`: kotlin.String,
-    ) : AppIntent() {
         companion object
     }
+    
+    data class MoveTask(
+        val `uid`: kotlin.String, 
+        val `targetHref`: kotlin.String) : AppIntent()
+        
+    {
+        
 
-    data class SetSearchTerm(
-        val `term`: kotlin.String,
-    ) : AppIntent() {
         companion object
     }
+    
+    data class DuplicateTaskTree(
+        val `uid`: kotlin.String) : AppIntent()
+        
+    {
+        
 
-    data class ToggleTagFilter(
-        val `tag`: kotlin.String,
-    ) : AppIntent() {
         companion object
     }
+    
+    data class RemoveParent(
+        val `uid`: kotlin.String) : AppIntent()
+        
+    {
+        
 
-    data class ToggleLocationFilter(
-        val `location`: kotlin.String,
-    ) : AppIntent() {
         companion object
     }
+    
+    /**
+     * Detaches a task from its parent chain entirely (unlike `RemoveParent`, which moves up one level).
+     */
+    data class PromoteToRoot(
+        val `uid`: kotlin.String) : AppIntent()
+        
+    {
+        
 
-    object ClearFilters : AppIntent()
-
-    object ToggleMatchAllCategories : AppIntent()
-
-    data class SetSidebarCalendar(
-        val `href`: kotlin.String,
-    ) : AppIntent() {
         companion object
     }
+    
+    data class MakeChild(
+        val `uid`: kotlin.String, 
+        val `parentUid`: kotlin.String) : AppIntent()
+        
+    {
+        
 
-    object ClearTagFilters : AppIntent()
-
-    object ClearLocationFilters : AppIntent()
-
-    data class ToggleTreeCollapse(
-        val `uid`: kotlin.String,
-    ) : AppIntent() {
         companion object
     }
+    
+    data class AddDependency(
+        val `uid`: kotlin.String, 
+        val `blockerUid`: kotlin.String) : AppIntent()
+        
+    {
+        
 
-    data class SetTreeCollapse(
-        val `uid`: kotlin.String,
-        val `collapsed`: kotlin.Boolean,
-    ) : AppIntent() {
         companion object
     }
+    
+    data class RemoveDependency(
+        val `uid`: kotlin.String, 
+        val `blockerUid`: kotlin.String) : AppIntent()
+        
+    {
+        
 
-    data class ToggleDoneGroup(
-        val `key`: kotlin.String,
-    ) : AppIntent() {
         companion object
     }
+    
+    data class AddRelatedTo(
+        val `uid`: kotlin.String, 
+        val `relatedUid`: kotlin.String) : AppIntent()
+        
+    {
+        
 
-    data class ToggleTagCollapse(
-        val `tag`: kotlin.String,
-    ) : AppIntent() {
         companion object
     }
+    
+    data class RemoveRelatedTo(
+        val `uid`: kotlin.String, 
+        val `relatedUid`: kotlin.String) : AppIntent()
+        
+    {
+        
 
-    data class ToggleLocationCollapse(
-        val `location`: kotlin.String,
-    ) : AppIntent() {
         companion object
     }
+    
+    data class SetSearchTerm(
+        val `term`: kotlin.String) : AppIntent()
+        
+    {
+        
 
-    data class FocusTaskTree(
-        val `uid`: kotlin.String?,
-    ) : AppIntent() {
         companion object
     }
+    
+    data class ToggleTagFilter(
+        val `tag`: kotlin.String) : AppIntent()
+        
+    {
+        
 
-    data class CompleteTree(
-        val `uid`: kotlin.String,
-    ) : AppIntent() {
         companion object
     }
+    
+    data class ToggleLocationFilter(
+        val `location`: kotlin.String) : AppIntent()
+        
+    {
+        
 
-    companion object
-}
-
-/**
- * @suppress
- */
-public object FfiConverterTypeAppIntent : FfiConverterRustBuffer<AppIntent> {
-    override fun read(buf: ByteBuffer): AppIntent =
-        when (buf.getInt()) {
-            1 -> {
-                AppIntent.ToggleTask(
-                    FfiConverterString.read(buf),
-                )
-            }
-
-            2 -> {
-                AppIntent.ToggleTaskShift(
-                    FfiConverterString.read(buf),
-                )
-            }
-
-            3 -> {
-                AppIntent.DeleteTask(
-                    FfiConverterString.read(buf),
-                )
-            }
-
-            4 -> {
-                AppIntent.DeleteTaskTree(
-                    FfiConverterString.read(buf),
-                )
-            }
-
-            5 -> {
-                AppIntent.TogglePin(
-                    FfiConverterString.read(buf),
-                )
-            }
-
-            6 -> {
-                AppIntent.CancelTask(
-                    FfiConverterString.read(buf),
-                )
-            }
-
-            7 -> {
-                AppIntent.ChangePriority(
-                    FfiConverterString.read(buf),
-                    FfiConverterByte.read(buf),
-                )
-            }
-
-            8 -> {
-                AppIntent.StartTask(
-                    FfiConverterString.read(buf),
-                )
-            }
-
-            9 -> {
-                AppIntent.PauseTask(
-                    FfiConverterString.read(buf),
-                )
-            }
-
-            10 -> {
-                AppIntent.StopTask(
-                    FfiConverterString.read(buf),
-                )
-            }
-
-            11 -> {
-                AppIntent.MoveTask(
-                    FfiConverterString.read(buf),
-                    FfiConverterString.read(buf),
-                )
-            }
-
-            12 -> {
-                AppIntent.DuplicateTaskTree(
-                    FfiConverterString.read(buf),
-                )
-            }
-
-            13 -> {
-                AppIntent.RemoveParent(
-                    FfiConverterString.read(buf),
-                )
-            }
-
-            14 -> {
-                AppIntent.MakeChild(
-                    FfiConverterString.read(buf),
-                    FfiConverterString.read(buf),
-                )
-            }
-
-            15 -> {
-                AppIntent.AddDependency(
-                    FfiConverterString.read(buf),
-                    FfiConverterString.read(buf),
-                )
-            }
+        companion object
+    }
+    
+    object ClearFilters : AppIntent()
+    
+    
+    object ToggleMatchAllCategories : AppIntent()
+    
+    
+    data class SetSidebarCalendar(
+        val `href`: kotlin.String) : AppIntent()
+        
+    {
+        
 
-            16 -> {
-                AppIntent.RemoveDependency(
-                    FfiConverterString.read(buf),
-                    FfiConverterString.read(buf),
-                )
-            }
+        companion object
+    }
+    
+    object ClearTagFilters : AppIntent()
+    
+    
+    object ClearLocationFilters : AppIntent()
+    
+    
+    data class ToggleTreeCollapse(
+        val `uid`: kotlin.String) : AppIntent()
+        
+    {
+        
 
-            17 -> {
-                AppIntent.AddRelatedTo(
-                    FfiConverterString.read(buf),
-                    FfiConverterString.read(buf),
-                )
-            }
+        companion object
+    }
+    
+    data class SetTreeCollapse(
+        val `uid`: kotlin.String, 
+        val `collapsed`: kotlin.Boolean) : AppIntent()
+        
+    {
+        
 
-            18 -> {
-                AppIntent.RemoveRelatedTo(
-                    FfiConverterString.read(buf),
-                    FfiConverterString.read(buf),
-                )
-            }
+        companion object
+    }
+    
+    data class ToggleDoneGroup(
+        val `key`: kotlin.String) : AppIntent()
+        
+    {
+        
 
-            19 -> {
-                AppIntent.SetSearchTerm(
-                    FfiConverterString.read(buf),
-                )
-            }
+        companion object
+    }
+    
+    data class ToggleTagCollapse(
+        val `tag`: kotlin.String) : AppIntent()
+        
+    {
+        
 
-            20 -> {
-                AppIntent.ToggleTagFilter(
-                    FfiConverterString.read(buf),
-                )
-            }
+        companion object
+    }
+    
+    data class ToggleLocationCollapse(
+        val `location`: kotlin.String) : AppIntent()
+        
+    {
+        
 
-            21 -> {
-                AppIntent.ToggleLocationFilter(
-                    FfiConverterString.read(buf),
-                )
-            }
+        companion object
+    }
+    
+    data class FocusTaskTree(
+        val `uid`: kotlin.String?) : AppIntent()
+        
+    {
+        
 
-            22 -> {
-                AppIntent.ClearFilters
-            }
+        companion object
+    }
+    
+    data class CompleteTree(
+        val `uid`: kotlin.String) : AppIntent()
+        
+    {
+        
 
-            23 -> {
-                AppIntent.ToggleMatchAllCategories
-            }
+        companion object
+    }
+    
 
-            24 -> {
-                AppIntent.SetSidebarCalendar(
-                    FfiConverterString.read(buf),
-                )
-            }
+    
 
-            25 -> {
-                AppIntent.ClearTagFilters
-            }
+    
+    
 
-            26 -> {
-                AppIntent.ClearLocationFilters
-            }
 
-            27 -> {
-                AppIntent.ToggleTreeCollapse(
-                    FfiConverterString.read(buf),
-                )
-            }
+    companion object
+}
 
-            28 -> {
-                AppIntent.SetTreeCollapse(
-                    FfiConverterString.read(buf),
-                    FfiConverterBoolean.read(buf),
+/**
+ * @suppress
+ */
+public object FfiConverterTypeAppIntent : FfiConverterRustBuffer<AppIntent>{
+    override fun read(buf: ByteBuffer): AppIntent {
+        return when(buf.getInt()) {
+            1 -> AppIntent.ToggleTask(
+                FfiConverterString.read(buf),
                 )
-            }
-
-            29 -> {
-                AppIntent.ToggleDoneGroup(
-                    FfiConverterString.read(buf),
+            2 -> AppIntent.ToggleTaskShift(
+                FfiConverterString.read(buf),
                 )
-            }
-
-            30 -> {
-                AppIntent.ToggleTagCollapse(
-                    FfiConverterString.read(buf),
+            3 -> AppIntent.DeleteTask(
+                FfiConverterString.read(buf),
                 )
-            }
-
-            31 -> {
-                AppIntent.ToggleLocationCollapse(
-                    FfiConverterString.read(buf),
+            4 -> AppIntent.DeleteTaskTree(
+                FfiConverterString.read(buf),
                 )
-            }
-
-            32 -> {
-                AppIntent.FocusTaskTree(
-                    FfiConverterOptionalString.read(buf),
+            5 -> AppIntent.TogglePin(
+                FfiConverterString.read(buf),
                 )
-            }
-
-            33 -> {
-                AppIntent.CompleteTree(
-                    FfiConverterString.read(buf),
+            6 -> AppIntent.ToggleWaiting(
+                FfiConverterString.read(buf),
                 )
-            }
-
-            else -> {
-                throw RuntimeException("invalid enum value, something is very wrong!!")
-            }
-        }
-
-    override fun allocationSize(value: AppIntent): ULong =
-        when (value) {
-            is AppIntent.ToggleTask -> {
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                (
-                    4UL +
-                        FfiConverterString.allocationSize(value.`uid`)
+            7 -> AppIntent.CancelTask(
+                FfiConverterString.read(buf),
                 )
-            }
-
-            is AppIntent.ToggleTaskShift -> {
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                (
-                    4UL +
-                        FfiConverterString.allocationSize(value.`uid`)
+            8 -> AppIntent.ChangePriority(
+                FfiConverterString.read(buf),
+                FfiConverterByte.read(buf),
                 )
-            }
-
-            is AppIntent.DeleteTask -> {
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                (
-                    4UL +
-                        FfiConverterString.allocationSize(value.`uid`)
+            9 -> AppIntent.StartTask(
+                FfiConverterString.read(buf),
                 )
-            }
-
-            is AppIntent.DeleteTaskTree -> {
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                (
-                    4UL +
-                        FfiConverterString.allocationSize(value.`uid`)
+            10 -> AppIntent.PauseTask(
+                FfiConverterString.read(buf),
                 )
-            }
-
-            is AppIntent.TogglePin -> {
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                (
-                    4UL +
-                        FfiConverterString.allocationSize(value.`uid`)
+            11 -> AppIntent.StopTask(
+                FfiConverterString.read(buf),
                 )
-            }
-
-            is AppIntent.CancelTask -> {
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                (
-                    4UL +
-                        FfiConverterString.allocationSize(value.`uid`)
+            12 -> AppIntent.SetProgress(
+                FfiConverterString.read(buf),
+                FfiConverterUByte.read(buf),
                 )
-            }
-
-            is AppIntent.ChangePriority -> {
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                (
-                    4UL +
-                        FfiConverterString.allocationSize(value.`uid`) +
-                        FfiConverterByte.allocationSize(value.`delta`)
+            13 -> AppIntent.RenameTask(
+                FfiConverterString.read(buf),
+                FfiConverterString.read(buf),
                 )
-            }
-
-            is AppIntent.StartTask -> {
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                (
-                    4UL +
-                        FfiConverterString.allocationSize(value.`uid`)
+            14 -> AppIntent.RescheduleDue(
+                FfiConverterString.read(buf),
+                FfiConverterOptionalString.read(buf),
                 )
-            }
-
-            is AppIntent.PauseTask -> {
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                (
-                    4UL +
-                        FfiConverterString.allocationSize(value.`uid`)
+            15 -> AppIntent.SkipOccurrence(
+                FfiConverterString.read(buf),
                 )
-            }
-
-            is AppIntent.StopTask -> {
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                (
-                    4UL +
-                        FfiConverterString.allocationSize(value.`uid`)
+            16 -> AppIntent.RetryUnsynced(
+                FfiConverterString.read(buf),
                 )
-            }
-
-            is AppIntent.MoveTask -> {
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                (
-                    4UL +
-                        FfiConverterString.allocationSize(value.`uid`) +
-                        FfiConverterString.allocationSize(value.`targetHref`)
+            17 -> AppIntent.CompleteTasks(
+                FfiConverterSequenceString.read(buf),
                 )
-            }
-
-            is AppIntent.DuplicateTaskTree -> {
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                (
-                    4UL +
-                        FfiConverterString.allocationSize(value.`uid`)
+            18 -> AppIntent.SetTagOnTasks(
+                FfiConverterSequenceString.read(buf),
+                FfiConverterString.read(buf),
+                FfiConverterBoolean.read(buf),
                 )
-            }
-
-            is AppIntent.RemoveParent -> {
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                (
-                    4UL +
-                        FfiConverterString.allocationSize(value.`uid`)
+            19 -> AppIntent.MoveTask(
+                FfiConverterString.read(buf),
+                FfiConverterString.read(buf),
                 )
-            }
-
-            is AppIntent.MakeChild -> {
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                (
-                    4UL +
-                        FfiConverterString.allocationSize(value.`uid`) +
-                        FfiConverterString.allocationSize(value.`parentUid`)
+            20 -> AppIntent.DuplicateTaskTree(
+                FfiConverterString.read(buf),
                 )
-            }
-
-            is AppIntent.AddDependency -> {
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                (
-                    4UL +
-                        FfiConverterString.allocationSize(value.`uid`) +
-                        FfiConverterString.allocationSize(value.`blockerUid`)
+            21 -> AppIntent.RemoveParent(
+                FfiConverterString.read(buf),
                 )
-            }
-
-            is AppIntent.RemoveDependency -> {
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                (
-                    4UL +
-                        FfiConverterString.allocationSize(value.`uid`) +
-                        FfiConverterString.allocationSize(value.`blockerUid`)
+            22 -> AppIntent.PromoteToRoot(
+                FfiConverterString.read(buf),
                 )
-            }
-
-            is AppIntent.AddRelatedTo -> {
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                (
-                    4UL +
-                        FfiConverterString.allocationSize(value.`uid`) +
-                        FfiConverterString.allocationSize(value.`relatedUid`)
+            23 -> AppIntent.MakeChild(
+                FfiConverterString.read(buf),
+                FfiConverterString.read(buf),
                 )
-            }
-
-            is AppIntent.RemoveRelatedTo -> {
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                (
-                    4UL +
-                        FfiConverterString.allocationSize(value.`uid`) +
-                        FfiConverterString.allocationSize(value.`relatedUid`)
+            24 -> AppIntent.AddDependency(
+                FfiConverterString.read(buf),
+                FfiConverterString.read(buf),
                 )
-            }
-
-            is AppIntent.SetSearchTerm -> {
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                (
-                    4UL +
-                        FfiConverterString.allocationSize(value.`term`)
+            25 -> AppIntent.RemoveDependency(
+                FfiConverterString.read(buf),
+                FfiConverterString.read(buf),
                 )
-            }
-
-            is AppIntent.ToggleTagFilter -> {
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                (
-                    4UL +
-                        FfiConverterString.allocationSize(value.`tag`)
+            26 -> AppIntent.AddRelatedTo(
+                FfiConverterString.read(buf),
+                FfiConverterString.read(buf),
                 )
-            }
-
-            is AppIntent.ToggleLocationFilter -> {
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                (
-                    4UL +
-                        FfiConverterString.allocationSize(value.`location`)
+            27 -> AppIntent.RemoveRelatedTo(
+                FfiConverterString.read(buf),
+                FfiConverterString.read(buf),
                 )
-            }
-
-            is AppIntent.ClearFilters -> {
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                (
-                    4UL
+            28 -> AppIntent.SetSearchTerm(
+                FfiConverterString.read(buf),
                 )
-            }
-
-            is AppIntent.ToggleMatchAllCategories -> {
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                (
-                    4UL
+            29 -> AppIntent.ToggleTagFilter(
+                FfiConverterString.read(buf),
                 )
-            }
-
-            is AppIntent.SetSidebarCalendar -> {
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                (
-                    4UL +
-                        FfiConverterString.allocationSize(value.`href`)
+            30 -> AppIntent.ToggleLocationFilter(
+                FfiConverterString.read(buf),
                 )
-            }
-
-            is AppIntent.ClearTagFilters -> {
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                (
-                    4UL
+            31 -> AppIntent.ClearFilters
+            32 -> AppIntent.ToggleMatchAllCategories
+            33 -> AppIntent.SetSidebarCalendar(
+                FfiConverterString.read(buf),
                 )
-            }
-
-            is AppIntent.ClearLocationFilters -> {
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                (
-                    4UL
+            34 -> AppIntent.ClearTagFilters
+            35 -> AppIntent.ClearLocationFilters
+            36 -> AppIntent.ToggleTreeCollapse(
+                FfiConverterString.read(buf),
                 )
-            }
-
-            is AppIntent.ToggleTreeCollapse -> {
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                (
-                    4UL +
-                        FfiConverterString.allocationSize(value.`uid`)
+            37 -> AppIntent.SetTreeCollapse(
+                FfiConverterString.read(buf),
+                FfiConverterBoolean.read(buf),
                 )
-            }
-
-            is AppIntent.SetTreeCollapse -> {
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                (
-                    4UL +
-                        FfiConverterString.allocationSize(value.`uid`) +
-                        FfiConverterBoolean.allocationSize(value.`collapsed`)
+            38 -> AppIntent.ToggleDoneGroup(
+                FfiConverterString.read(buf),
                 )
-            }
-
-            is AppIntent.ToggleDoneGroup -> {
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                (
-                    4UL +
-                        FfiConverterString.allocationSize(value.`key`)
+            39 -> AppIntent.ToggleTagCollapse(
+                FfiConverterString.read(buf),
                 )
-            }
-
-            is AppIntent.ToggleTagCollapse -> {
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                (
-                    4UL +
-                        FfiConverterString.allocationSize(value.`tag`)
+            40 -> AppIntent.ToggleLocationCollapse(
+                FfiConverterString.read(buf),
                 )
-            }
-
-            is AppIntent.ToggleLocationCollapse -> {
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                (
-                    4UL +
-                        FfiConverterString.allocationSize(value.`location`)
+            41 -> AppIntent.FocusTaskTree(
+                FfiConverterOptionalString.read(buf),
                 )
-            }
-
-            is AppIntent.FocusTaskTree -> {
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                (
-                    4UL +
-                        FfiConverterOptionalString.allocationSize(value.`uid`)
+            42 -> AppIntent.CompleteTree(
+                FfiConverterString.read(buf),
                 )
-            }
+            else -> throw RuntimeException("invalid enum value, something is very wrong!!")
+        }
+    }
 
-            is AppIntent.CompleteTree -> {
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                (
-                    4UL +
-                        FfiConverterString.allocationSize(value.`uid`)
-                )
-            }
+    override fun allocationSize(value: AppIntent): ULong = when(value) {
+        is AppIntent.ToggleTask -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`uid`)
+            )
+        }
+        is AppIntent.ToggleTaskShift -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`uid`)
+            )
+        }
+        is AppIntent.DeleteTask -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`uid`)
+            )
+        }
+        is AppIntent.DeleteTaskTree -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`uid`)
+            )
+        }
+        is AppIntent.TogglePin -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`uid`)
+            )
+        }
+        is AppIntent.ToggleWaiting -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`uid`)
+            )
+        }
+        is AppIntent.CancelTask -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`uid`)
+            )
+        }
+        is AppIntent.ChangePriority -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`uid`)
+                + FfiConverterByte.allocationSize(value.`delta`)
+            )
+        }
+        is AppIntent.StartTask -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`uid`)
+            )
+        }
+        is AppIntent.PauseTask -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`uid`)
+            )
+        }
+        is AppIntent.StopTask -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`uid`)
+            )
+        }
+        is AppIntent.SetProgress -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`uid`)
+                + FfiConverterUByte.allocationSize(value.`percent`)
+            )
+        }
+        is AppIntent.RenameTask -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`uid`)
+                + FfiConverterString.allocationSize(value.`summary`)
+            )
+        }
+        is AppIntent.RescheduleDue -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`uid`)
+                + FfiConverterOptionalString.allocationSize(value.`when`)
+            )
+        }
+        is AppIntent.SkipOccurrence -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`uid`)
+            )
+        }
+        is AppIntent.RetryUnsynced -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`uid`)
+            )
+        }
+        is AppIntent.CompleteTasks -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterSequenceString.allocationSize(value.`uids`)
+            )
+        }
+        is AppIntent.SetTagOnTasks -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterSequenceString.allocationSize(value.`uids`)
+                + FfiConverterString.allocationSize(value.`tag`)
+                + FfiConverterBoolean.allocationSize(value.`add`)
+            )
+        }
+        is AppIntent.MoveTask -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`uid`)
+                + FfiConverterString.allocationSize(value.`targetHref`)
+            )
+        }
+        is AppIntent.DuplicateTaskTree -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`uid`)
+            )
+        }
+        is AppIntent.RemoveParent -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`uid`)
+            )
+        }
+        is AppIntent.PromoteToRoot -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`uid`)
+            )
+        }
+        is AppIntent.MakeChild -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`uid`)
+                + FfiConverterString.allocationSize(value.`parentUid`)
+            )
+        }
+        is AppIntent.AddDependency -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`uid`)
+                + FfiConverterString.allocationSize(value.`blockerUid`)
+            )
+        }
+        is AppIntent.RemoveDependency -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`uid`)
+                + FfiConverterString.allocationSize(value.`blockerUid`)
+            )
+        }
+        is AppIntent.AddRelatedTo -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`uid`)
+                + FfiConverterString.allocationSize(value.`relatedUid`)
+            )
+        }
+        is AppIntent.RemoveRelatedTo -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`uid`)
+                + FfiConverterString.allocationSize(value.`relatedUid`)
+            )
         }
+        is AppIntent.SetSearchTerm -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`term`)
+            )
+        }
+        is AppIntent.ToggleTagFilter -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`tag`)
+            )
+        }
+        is AppIntent.ToggleLocationFilter -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`location`)
+            )
+        }
+        is AppIntent.ClearFilters -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+            )
+        }
+        is AppIntent.ToggleMatchAllCategories -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+            )
+        }
+        is AppIntent.SetSidebarCalendar -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`href`)
+            )
+        }
+        is AppIntent.ClearTagFilters -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+            )
+        }
+        is AppIntent.ClearLocationFilters -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+            )
+        }
+        is AppIntent.ToggleTreeCollapse -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`uid`)
+            )
+        }
+        is AppIntent.SetTreeCollapse -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`uid`)
+                + FfiConverterBoolean.allocationSize(value.`collapsed`)
+            )
+        }
+        is AppIntent.ToggleDoneGroup -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`key`)
+            )
+        }
+        is AppIntent.ToggleTagCollapse -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`tag`)
+            )
+        }
+        is AppIntent.ToggleLocationCollapse -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`location`)
+            )
+        }
+        is AppIntent.FocusTaskTree -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterOptionalString.allocationSize(value.`uid`)
+            )
+        }
+        is AppIntent.CompleteTree -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`uid`)
+            )
+        }
+    }
 
-    override fun write(
-        value: AppIntent,
-        buf: ByteBuffer,
-    ) {
-        when (value) {
+    override fun write(value: AppIntent, buf: ByteBuffer) {
+        when(value) {
             is AppIntent.ToggleTask -> {
                 buf.putInt(1)
                 FfiConverterString.write(value.`uid`, buf)
                 Unit
             }
-
             is AppIntent.ToggleTaskShift -> {
                 buf.putInt(2)
                 FfiConverterString.write(value.`uid`, buf)
                 Unit
             }
-
             is AppIntent.DeleteTask -> {
                 buf.putInt(3)
                 FfiConverterString.write(value.`uid`, buf)
                 Unit
             }
-
             is AppIntent.DeleteTaskTree -> {
                 buf.putInt(4)
                 FfiConverterString.write(value.`uid`, buf)
                 Unit
             }
-
             is AppIntent.TogglePin -> {
                 buf.putInt(5)
                 FfiConverterString.write(value.`uid`, buf)
                 Unit
             }
-
-            is AppIntent.CancelTask -> {
+            is AppIntent.ToggleWaiting -> {
                 buf.putInt(6)
                 FfiConverterString.write(value.`uid`, buf)
                 Unit
             }
-
-            is AppIntent.ChangePriority -> {
+            is AppIntent.CancelTask -> {
                 buf.putInt(7)
                 FfiConverterString.write(value.`uid`, buf)
+                Unit
+            }
+            is AppIntent.ChangePriority -> {
+                buf.putInt(8)
+                FfiConverterString.write(value.`uid`, buf)
                 FfiConverterByte.write(value.`delta`, buf)
                 Unit
             }
-
             is AppIntent.StartTask -> {
-                buf.putInt(8)
+                buf.putInt(9)
                 FfiConverterString.write(value.`uid`, buf)
                 Unit
             }
-
             is AppIntent.PauseTask -> {
-                buf.putInt(9)
+                buf.putInt(10)
                 FfiConverterString.write(value.`uid`, buf)
                 Unit
             }
-
             is AppIntent.StopTask -> {
-                buf.putInt(10)
+                buf.putInt(11)
                 FfiConverterString.write(value.`uid`, buf)
                 Unit
             }
-
+            is AppIntent.SetProgress -> {
+                buf.putInt(12)
+                FfiConverterString.write(value.`uid`, buf)
+                FfiConverterUByte.write(value.`percent`, buf)
+                Unit
+            }
+            is AppIntent.RenameTask -> {
+                buf.putInt(13)
+                FfiConverterString.write(value.`uid`, buf)
+                FfiConverterString.write(value.`summary`, buf)
+                Unit
+            }
+            is AppIntent.RescheduleDue -> {
+                buf.putInt(14)
+                FfiConverterString.write(value.`uid`, buf)
+                FfiConverterOptionalString.write(value.`when`, buf)
+                Unit
+            }
+            is AppIntent.SkipOccurrence -> {
+                buf.putInt(15)
+                FfiConverterString.write(value.`uid`, buf)
+                Unit
+            }
+            is AppIntent.RetryUnsynced -> {
+                buf.putInt(16)
+                FfiConverterString.write(value.`uid`, buf)
+                Unit
+            }
+            is AppIntent.CompleteTasks -> {
+                buf.putInt(17)
+                FfiConverterSequenceString.write(value.`uids`, buf)
+                Unit
+            }
+            is AppIntent.SetTagOnTasks -> {
+                buf.putInt(18)
+                FfiConverterSequenceString.write(value.`uids`, buf)
+                FfiConverterString.write(value.`tag`, buf)
+                FfiConverterBoolean.write(value.`add`, buf)
+                Unit
+            }
             is AppIntent.MoveTask -> {
-                buf.putInt(11)
+                buf.putInt(19)
                 FfiConverterString.write(value.`uid`, buf)
                 FfiConverterString.write(value.`targetHref`, buf)
                 Unit
             }
-
             is AppIntent.DuplicateTaskTree -> {
-                buf.putInt(12)
+                buf.putInt(20)
                 FfiConverterString.write(value.`uid`, buf)
                 Unit
             }
-
             is AppIntent.RemoveParent -> {
-                buf.putInt(13)
+                buf.putInt(21)
+                FfiConverterString.write(value.`uid`, buf)
+                Unit
+            }
+            is AppIntent.PromoteToRoot -> {
+                buf.putInt(22)
                 FfiConverterString.write(value.`uid`, buf)
                 Unit
             }
-
             is AppIntent.MakeChild -> {
-                buf.putInt(14)
+                buf.putInt(23)
                 FfiConverterString.write(value.`uid`, buf)
                 FfiConverterString.write(value.`parentUid`, buf)
                 Unit
             }
-
             is AppIntent.AddDependency -> {
-                buf.putInt(15)
+                buf.putInt(24)
                 FfiConverterString.write(value.`uid`, buf)
                 FfiConverterString.write(value.`blockerUid`, buf)
                 Unit
             }
-
             is AppIntent.RemoveDependency -> {
-                buf.putInt(16)
+                buf.putInt(25)
                 FfiConverterString.write(value.`uid`, buf)
                 FfiConverterString.write(value.`blockerUid`, buf)
                 Unit
             }
-
             is AppIntent.AddRelatedTo -> {
-                buf.putInt(17)
+                buf.putInt(26)
                 FfiConverterString.write(value.`uid`, buf)
                 FfiConverterString.write(value.`relatedUid`, buf)
                 Unit
             }
-
             is AppIntent.RemoveRelatedTo -> {
-                buf.putInt(18)
+                buf.putInt(27)
                 FfiConverterString.write(value.`uid`, buf)
                 FfiConverterString.write(value.`relatedUid`, buf)
                 Unit
             }
-
             is AppIntent.SetSearchTerm -> {
-                buf.putInt(19)
+                buf.putInt(28)
                 FfiConverterString.write(value.`term`, buf)
                 Unit
             }
-
             is AppIntent.ToggleTagFilter -> {
-                buf.putInt(20)
+                buf.putInt(29)
                 FfiConverterString.write(value.`tag`, buf)
                 Unit
             }
-
             is AppIntent.ToggleLocationFilter -> {
-                buf.putInt(21)
+                buf.putInt(30)
                 FfiConverterString.write(value.`location`, buf)
                 Unit
             }
-
             is AppIntent.ClearFilters -> {
-                buf.putInt(22)
+                buf.putInt(31)
                 Unit
             }
-
             is AppIntent.ToggleMatchAllCategories -> {
-                buf.putInt(23)
+                buf.putInt(32)
                 Unit
             }
-
             is AppIntent.SetSidebarCalendar -> {
-                buf.putInt(24)
+                buf.putInt(33)
                 FfiConverterString.write(value.`href`, buf)
                 Unit
             }
-
             is AppIntent.ClearTagFilters -> {
-                buf.putInt(25)
+                buf.putInt(34)
                 Unit
             }
-
             is AppIntent.ClearLocationFilters -> {
-                buf.putInt(26)
+                buf.putInt(35)
                 Unit
             }
-
             is AppIntent.ToggleTreeCollapse -> {
-                buf.putInt(27)
+                buf.putInt(36)
                 FfiConverterString.write(value.`uid`, buf)
                 Unit
             }
-
             is AppIntent.SetTreeCollapse -> {
-                buf.putInt(28)
+                buf.putInt(37)
                 FfiConverterString.write(value.`uid`, buf)
                 FfiConverterBoolean.write(value.`collapsed`, buf)
                 Unit
             }
-
             is AppIntent.ToggleDoneGroup -> {
-                buf.putInt(29)
+                buf.putInt(38)
                 FfiConverterString.write(value.`key`, buf)
                 Unit
             }
-
             is AppIntent.ToggleTagCollapse -> {
-                buf.putInt(30)
+                buf.putInt(39)
                 FfiConverterString.write(value.`tag`, buf)
                 Unit
             }
-
             is AppIntent.ToggleLocationCollapse -> {
-                buf.putInt(31)
+                buf.putInt(40)
                 FfiConverterString.write(value.`location`, buf)
                 Unit
             }
-
             is AppIntent.FocusTaskTree -> {
-                buf.putInt(32)
+                buf.putInt(41)
                 FfiConverterOptionalString.write(value.`uid`, buf)
                 Unit
             }
-
             is AppIntent.CompleteTree -> {
-                buf.putInt(33)
+                buf.putInt(42)
                 FfiConverterString.write(value.`uid`, buf)
                 Unit
             }
@@ -6269,42 +7732,51 @@ public object FfiConverterTypeAppIntent : FfiConverterRustBuffer<AppIntent> {
     }
 }
 
+
+
+
+
+
 enum class HelpTab {
+    
     SYNTAX,
     SHORTCUTS,
-    ABOUT,
-    ;
+    ABOUT;
+
+    
+
 
     companion object
 }
 
+
 /**
  * @suppress
  */
-public object FfiConverterTypeHelpTab : FfiConverterRustBuffer<HelpTab> {
-    override fun read(buf: ByteBuffer) =
-        try {
-            HelpTab.values()[buf.getInt() - 1]
-        } catch (e: IndexOutOfBoundsException) {
-            throw RuntimeException("invalid enum value, something is very wrong!!", e)
-        }
+public object FfiConverterTypeHelpTab: FfiConverterRustBuffer<HelpTab> {
+    override fun read(buf: ByteBuffer) = try {
+        HelpTab.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
 
     override fun allocationSize(value: HelpTab) = 4UL
 
-    override fun write(
-        value: HelpTab,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: HelpTab, buf: ByteBuffer) {
         buf.putInt(value.ordinal + 1)
     }
 }
 
-sealed class MobileException(
-    message: String,
-) : kotlin.Exception(message) {
-    class Generic(
-        message: String,
-    ) : MobileException(message)
+
+
+
+
+
+
+sealed class MobileException(message: String): kotlin.Exception(message) {
+        
+        class Generic(message: String) : MobileException(message)
+        
 
     companion object ErrorHandler : UniffiRustCallStatusErrorHandler<MobileException> {
         override fun lift(error_buf: RustBuffer.ByValue): MobileException = FfiConverterTypeMobileError.lift(error_buf)
@@ -6315,88 +7787,105 @@ sealed class MobileException(
  * @suppress
  */
 public object FfiConverterTypeMobileError : FfiConverterRustBuffer<MobileException> {
-    override fun read(buf: ByteBuffer): MobileException =
-        when (buf.getInt()) {
+    override fun read(buf: ByteBuffer): MobileException {
+        
+            return when(buf.getInt()) {
             1 -> MobileException.Generic(FfiConverterString.read(buf))
             else -> throw RuntimeException("invalid error enum value, something is very wrong!!")
         }
+        
+    }
 
-    override fun allocationSize(value: MobileException): ULong = 4UL
+    override fun allocationSize(value: MobileException): ULong {
+        return 4UL
+    }
 
-    override fun write(
-        value: MobileException,
-        buf: ByteBuffer,
-    ) {
-        when (value) {
+    override fun write(value: MobileException, buf: ByteBuffer) {
+        when(value) {
             is MobileException.Generic -> {
                 buf.putInt(1)
                 Unit
             }
         }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
     }
+
 }
 
+
+
+
 enum class MobileGoalType {
+    
     COUNT,
-    DURATION,
-    ;
+    DURATION;
+
+    
+
 
     companion object
 }
 
+
 /**
  * @suppress
  */
-public object FfiConverterTypeMobileGoalType : FfiConverterRustBuffer<MobileGoalType> {
-    override fun read(buf: ByteBuffer) =
-        try {
-            MobileGoalType.values()[buf.getInt() - 1]
-        } catch (e: IndexOutOfBoundsException) {
-            throw RuntimeException("invalid enum value, something is very wrong!!", e)
-        }
+public object FfiConverterTypeMobileGoalType: FfiConverterRustBuffer<MobileGoalType> {
+    override fun read(buf: ByteBuffer) = try {
+        MobileGoalType.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
 
     override fun allocationSize(value: MobileGoalType) = 4UL
 
-    override fun write(
-        value: MobileGoalType,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: MobileGoalType, buf: ByteBuffer) {
         buf.putInt(value.ordinal + 1)
     }
 }
 
+
+
+
+
+
 enum class MobileIntervalUnit {
+    
     DAYS,
     WEEKS,
     MONTHS,
-    YEARS,
-    ;
+    YEARS;
+
+    
+
 
     companion object
 }
 
+
 /**
  * @suppress
  */
-public object FfiConverterTypeMobileIntervalUnit : FfiConverterRustBuffer<MobileIntervalUnit> {
-    override fun read(buf: ByteBuffer) =
-        try {
-            MobileIntervalUnit.values()[buf.getInt() - 1]
-        } catch (e: IndexOutOfBoundsException) {
-            throw RuntimeException("invalid enum value, something is very wrong!!", e)
-        }
+public object FfiConverterTypeMobileIntervalUnit: FfiConverterRustBuffer<MobileIntervalUnit> {
+    override fun read(buf: ByteBuffer) = try {
+        MobileIntervalUnit.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
 
     override fun allocationSize(value: MobileIntervalUnit) = 4UL
 
-    override fun write(
-        value: MobileIntervalUnit,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: MobileIntervalUnit, buf: ByteBuffer) {
         buf.putInt(value.ordinal + 1)
     }
 }
 
+
+
+
+
+
 enum class MobileSyntaxType {
+    
     TEXT,
     PRIORITY,
     DUE_DATE,
@@ -6418,37 +7907,41 @@ enum class MobileSyntaxType {
     WIKI_LINK,
     DEPENDENCY,
     RELATION,
-    NOTE,
-    ;
+    NOTE;
+
+    
+
 
     companion object
 }
 
+
 /**
  * @suppress
  */
-public object FfiConverterTypeMobileSyntaxType : FfiConverterRustBuffer<MobileSyntaxType> {
-    override fun read(buf: ByteBuffer) =
-        try {
-            MobileSyntaxType.values()[buf.getInt() - 1]
-        } catch (e: IndexOutOfBoundsException) {
-            throw RuntimeException("invalid enum value, something is very wrong!!", e)
-        }
+public object FfiConverterTypeMobileSyntaxType: FfiConverterRustBuffer<MobileSyntaxType> {
+    override fun read(buf: ByteBuffer) = try {
+        MobileSyntaxType.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
 
     override fun allocationSize(value: MobileSyntaxType) = 4UL
 
-    override fun write(
-        value: MobileSyntaxType,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: MobileSyntaxType, buf: ByteBuffer) {
         buf.putInt(value.ordinal + 1)
     }
 }
 
+
+
+
+
+
 /**
  * @suppress
  */
-public object FfiConverterOptionalUByte : FfiConverterRustBuffer<kotlin.UByte?> {
+public object FfiConverterOptionalUByte: FfiConverterRustBuffer<kotlin.UByte?> {
     override fun read(buf: ByteBuffer): kotlin.UByte? {
         if (buf.get().toInt() == 0) {
             return null
@@ -6464,10 +7957,7 @@ public object FfiConverterOptionalUByte : FfiConverterRustBuffer<kotlin.UByte?>
         }
     }
 
-    override fun write(
-        value: kotlin.UByte?,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: kotlin.UByte?, buf: ByteBuffer) {
         if (value == null) {
             buf.put(0)
         } else {
@@ -6477,10 +7967,45 @@ public object FfiConverterOptionalUByte : FfiConverterRustBuffer<kotlin.UByte?>
     }
 }
 
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalUShort: FfiConverterRustBuffer<kotlin.UShort?> {
+    override fun read(buf: ByteBuffer): kotlin.UShort? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterUShort.read(buf)
+    }
+
+    override fun allocationSize(value: kotlin.UShort?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterUShort.allocationSize(value)
+        }
+    }
+
+    override fun write(value: kotlin.UShort?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterUShort.write(value, buf)
+        }
+    }
+}
+
+
+
+
 /**
  * @suppress
  */
-public object FfiConverterOptionalUInt : FfiConverterRustBuffer<kotlin.UInt?> {
+public object FfiConverterOptionalUInt: FfiConverterRustBuffer<kotlin.UInt?> {
     override fun read(buf: ByteBuffer): kotlin.UInt? {
         if (buf.get().toInt() == 0) {
             return null
@@ -6496,10 +8021,7 @@ public object FfiConverterOptionalUInt : FfiConverterRustBuffer<kotlin.UInt?> {
         }
     }
 
-    override fun write(
-        value: kotlin.UInt?,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: kotlin.UInt?, buf: ByteBuffer) {
         if (value == null) {
             buf.put(0)
         } else {
@@ -6509,10 +8031,45 @@ public object FfiConverterOptionalUInt : FfiConverterRustBuffer<kotlin.UInt?> {
     }
 }
 
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalInt: FfiConverterRustBuffer<kotlin.Int?> {
+    override fun read(buf: ByteBuffer): kotlin.Int? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterInt.read(buf)
+    }
+
+    override fun allocationSize(value: kotlin.Int?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterInt.allocationSize(value)
+        }
+    }
+
+    override fun write(value: kotlin.Int?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterInt.write(value, buf)
+        }
+    }
+}
+
+
+
+
 /**
  * @suppress
  */
-public object FfiConverterOptionalLong : FfiConverterRustBuffer<kotlin.Long?> {
+public object FfiConverterOptionalLong: FfiConverterRustBuffer<kotlin.Long?> {
     override fun read(buf: ByteBuffer): kotlin.Long? {
         if (buf.get().toInt() == 0) {
             return null
@@ -6528,10 +8085,7 @@ public object FfiConverterOptionalLong : FfiConverterRustBuffer<kotlin.Long?> {
         }
     }
 
-    override fun write(
-        value: kotlin.Long?,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: kotlin.Long?, buf: ByteBuffer) {
         if (value == null) {
             buf.put(0)
         } else {
@@ -6541,10 +8095,13 @@ public object FfiConverterOptionalLong : FfiConverterRustBuffer<kotlin.Long?> {
     }
 }
 
+
+
+
 /**
  * @suppress
  */
-public object FfiConverterOptionalString : FfiConverterRustBuffer<kotlin.String?> {
+public object FfiConverterOptionalString: FfiConverterRustBuffer<kotlin.String?> {
     override fun read(buf: ByteBuffer): kotlin.String? {
         if (buf.get().toInt() == 0) {
             return null
@@ -6560,10 +8117,7 @@ public object FfiConverterOptionalString : FfiConverterRustBuffer<kotlin.String?
         }
     }
 
-    override fun write(
-        value: kotlin.String?,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: kotlin.String?, buf: ByteBuffer) {
         if (value == null) {
             buf.put(0)
         } else {
@@ -6573,10 +8127,13 @@ public object FfiConverterOptionalString : FfiConverterRustBuffer<kotlin.String?
     }
 }
 
+
+
+
 /**
  * @suppress
  */
-public object FfiConverterOptionalTypeMobileTask : FfiConverterRustBuffer<MobileTask?> {
+public object FfiConverterOptionalTypeMobileTask: FfiConverterRustBuffer<MobileTask?> {
     override fun read(buf: ByteBuffer): MobileTask? {
         if (buf.get().toInt() == 0) {
             return null
@@ -6592,10 +8149,7 @@ public object FfiConverterOptionalTypeMobileTask : FfiConverterRustBuffer<Mobile
         }
     }
 
-    override fun write(
-        value: MobileTask?,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: MobileTask?, buf: ByteBuffer) {
         if (value == null) {
             buf.put(0)
         } else {
@@ -6605,10 +8159,13 @@ public object FfiConverterOptionalTypeMobileTask : FfiConverterRustBuffer<Mobile
     }
 }
 
+
+
+
 /**
  * @suppress
  */
-public object FfiConverterSequenceFloat : FfiConverterRustBuffer<List<kotlin.Float>> {
+public object FfiConverterSequenceFloat: FfiConverterRustBuffer<List<kotlin.Float>> {
     override fun read(buf: ByteBuffer): List<kotlin.Float> {
         val len = buf.getInt()
         return List<kotlin.Float>(len) {
@@ -6622,10 +8179,7 @@ public object FfiConverterSequenceFloat : FfiConverterRustBuffer<List<kotlin.Flo
         return sizeForLength + sizeForItems
     }
 
-    override fun write(
-        value: List<kotlin.Float>,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: List<kotlin.Float>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
             FfiConverterFloat.write(it, buf)
@@ -6633,10 +8187,13 @@ public object FfiConverterSequenceFloat : FfiConverterRustBuffer<List<kotlin.Flo
     }
 }
 
+
+
+
 /**
  * @suppress
  */
-public object FfiConverterSequenceString : FfiConverterRustBuffer<List<kotlin.String>> {
+public object FfiConverterSequenceString: FfiConverterRustBuffer<List<kotlin.String>> {
     override fun read(buf: ByteBuffer): List<kotlin.String> {
         val len = buf.getInt()
         return List<kotlin.String>(len) {
@@ -6650,10 +8207,7 @@ public object FfiConverterSequenceString : FfiConverterRustBuffer<List<kotlin.St
         return sizeForLength + sizeForItems
     }
 
-    override fun write(
-        value: List<kotlin.String>,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: List<kotlin.String>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
             FfiConverterString.write(it, buf)
@@ -6661,10 +8215,41 @@ public object FfiConverterSequenceString : FfiConverterRustBuffer<List<kotlin.St
     }
 }
 
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceTypeMobileAlarm: FfiConverterRustBuffer<List<MobileAlarm>> {
+    override fun read(buf: ByteBuffer): List<MobileAlarm> {
+        val len = buf.getInt()
+        return List<MobileAlarm>(len) {
+            FfiConverterTypeMobileAlarm.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<MobileAlarm>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeMobileAlarm.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<MobileAlarm>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeMobileAlarm.write(it, buf)
+        }
+    }
+}
+
+
+
+
 /**
  * @suppress
  */
-public object FfiConverterSequenceTypeMobileAlarmInfo : FfiConverterRustBuffer<List<MobileAlarmInfo>> {
+public object FfiConverterSequenceTypeMobileAlarmInfo: FfiConverterRustBuffer<List<MobileAlarmInfo>> {
     override fun read(buf: ByteBuffer): List<MobileAlarmInfo> {
         val len = buf.getInt()
         return List<MobileAlarmInfo>(len) {
@@ -6678,10 +8263,7 @@ public object FfiConverterSequenceTypeMobileAlarmInfo : FfiConverterRustBuffer<L
         return sizeForLength + sizeForItems
     }
 
-    override fun write(
-        value: List<MobileAlarmInfo>,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: List<MobileAlarmInfo>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
             FfiConverterTypeMobileAlarmInfo.write(it, buf)
@@ -6689,10 +8271,13 @@ public object FfiConverterSequenceTypeMobileAlarmInfo : FfiConverterRustBuffer<L
     }
 }
 
+
+
+
 /**
  * @suppress
  */
-public object FfiConverterSequenceTypeMobileCalendar : FfiConverterRustBuffer<List<MobileCalendar>> {
+public object FfiConverterSequenceTypeMobileCalendar: FfiConverterRustBuffer<List<MobileCalendar>> {
     override fun read(buf: ByteBuffer): List<MobileCalendar> {
         val len = buf.getInt()
         return List<MobileCalendar>(len) {
@@ -6706,10 +8291,7 @@ public object FfiConverterSequenceTypeMobileCalendar : FfiConverterRustBuffer<Li
         return sizeForLength + sizeForItems
     }
 
-    override fun write(
-        value: List<MobileCalendar>,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: List<MobileCalendar>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
             FfiConverterTypeMobileCalendar.write(it, buf)
@@ -6717,10 +8299,41 @@ public object FfiConverterSequenceTypeMobileCalendar : FfiConverterRustBuffer<Li
     }
 }
 
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceTypeMobileDiagnosticStep: FfiConverterRustBuffer<List<MobileDiagnosticStep>> {
+    override fun read(buf: ByteBuffer): List<MobileDiagnosticStep> {
+        val len = buf.getInt()
+        return List<MobileDiagnosticStep>(len) {
+            FfiConverterTypeMobileDiagnosticStep.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<MobileDiagnosticStep>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeMobileDiagnosticStep.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<MobileDiagnosticStep>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeMobileDiagnosticStep.write(it, buf)
+        }
+    }
+}
+
+
+
+
 /**
  * @suppress
  */
-public object FfiConverterSequenceTypeMobileGoalProgress : FfiConverterRustBuffer<List<MobileGoalProgress>> {
+public object FfiConverterSequenceTypeMobileGoalProgress: FfiConverterRustBuffer<List<MobileGoalProgress>> {
     override fun read(buf: ByteBuffer): List<MobileGoalProgress> {
         val len = buf.getInt()
         return List<MobileGoalProgress>(len) {
@@ -6734,10 +8347,7 @@ public object FfiConverterSequenceTypeMobileGoalProgress : FfiConverterRustBuffe
         return sizeForLength + sizeForItems
     }
 
-    override fun write(
-        value: List<MobileGoalProgress>,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: List<MobileGoalProgress>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
             FfiConverterTypeMobileGoalProgress.write(it, buf)
@@ -6745,10 +8355,13 @@ public object FfiConverterSequenceTypeMobileGoalProgress : FfiConverterRustBuffe
     }
 }
 
+
+
+
 /**
  * @suppress
  */
-public object FfiConverterSequenceTypeMobileHelpCategoryData : FfiConverterRustBuffer<List<MobileHelpCategoryData>> {
+public object FfiConverterSequenceTypeMobileHelpCategoryData: FfiConverterRustBuffer<List<MobileHelpCategoryData>> {
     override fun read(buf: ByteBuffer): List<MobileHelpCategoryData> {
         val len = buf.getInt()
         return List<MobileHelpCategoryData>(len) {
@@ -6762,10 +8375,7 @@ public object FfiConverterSequenceTypeMobileHelpCategoryData : FfiConverterRustB
         return sizeForLength + sizeForItems
     }
 
-    override fun write(
-        value: List<MobileHelpCategoryData>,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: List<MobileHelpCategoryData>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
             FfiConverterTypeMobileHelpCategoryData.write(it, buf)
@@ -6773,10 +8383,13 @@ public object FfiConverterSequenceTypeMobileHelpCategoryData : FfiConverterRustB
     }
 }
 
+
+
+
 /**
  * @suppress
  */
-public object FfiConverterSequenceTypeMobileHelpItem : FfiConverterRustBuffer<List<MobileHelpItem>> {
+public object FfiConverterSequenceTypeMobileHelpItem: FfiConverterRustBuffer<List<MobileHelpItem>> {
     override fun read(buf: ByteBuffer): List<MobileHelpItem> {
         val len = buf.getInt()
         return List<MobileHelpItem>(len) {
@@ -6790,10 +8403,7 @@ public object FfiConverterSequenceTypeMobileHelpItem : FfiConverterRustBuffer<Li
         return sizeForLength + sizeForItems
     }
 
-    override fun write(
-        value: List<MobileHelpItem>,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: List<MobileHelpItem>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
             FfiConverterTypeMobileHelpItem.write(it, buf)
@@ -6801,10 +8411,13 @@ public object FfiConverterSequenceTypeMobileHelpItem : FfiConverterRustBuffer<Li
     }
 }
 
+
+
+
 /**
  * @suppress
  */
-public object FfiConverterSequenceTypeMobileHelpSection : FfiConverterRustBuffer<List<MobileHelpSection>> {
+public object FfiConverterSequenceTypeMobileHelpSection: FfiConverterRustBuffer<List<MobileHelpSection>> {
     override fun read(buf: ByteBuffer): List<MobileHelpSection> {
         val len = buf.getInt()
         return List<MobileHelpSection>(len) {
@@ -6818,10 +8431,7 @@ public object FfiConverterSequenceTypeMobileHelpSection : FfiConverterRustBuffer
         return sizeForLength + sizeForItems
     }
 
-    override fun write(
-        value: List<MobileHelpSection>,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: List<MobileHelpSection>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
             FfiConverterTypeMobileHelpSection.write(it, buf)
@@ -6829,10 +8439,41 @@ public object FfiConverterSequenceTypeMobileHelpSection : FfiConverterRustBuffer
     }
 }
 
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceTypeMobileJournalEntry: FfiConverterRustBuffer<List<MobileJournalEntry>> {
+    override fun read(buf: ByteBuffer): List<MobileJournalEntry> {
+        val len = buf.getInt()
+        return List<MobileJournalEntry>(len) {
+            FfiConverterTypeMobileJournalEntry.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<MobileJournalEntry>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeMobileJournalEntry.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<MobileJournalEntry>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeMobileJournalEntry.write(it, buf)
+        }
+    }
+}
+
+
+
+
 /**
  * @suppress
  */
-public object FfiConverterSequenceTypeMobileLocation : FfiConverterRustBuffer<List<MobileLocation>> {
+public object FfiConverterSequenceTypeMobileLocation: FfiConverterRustBuffer<List<MobileLocation>> {
     override fun read(buf: ByteBuffer): List<MobileLocation> {
         val len = buf.getInt()
         return List<MobileLocation>(len) {
@@ -6846,10 +8487,7 @@ public object FfiConverterSequenceTypeMobileLocation : FfiConverterRustBuffer<Li
         return sizeForLength + sizeForItems
     }
 
-    override fun write(
-        value: List<MobileLocation>,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: List<MobileLocation>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
             FfiConverterTypeMobileLocation.write(it, buf)
@@ -6857,10 +8495,13 @@ public object FfiConverterSequenceTypeMobileLocation : FfiConverterRustBuffer<Li
     }
 }
 
+
+
+
 /**
  * @suppress
  */
-public object FfiConverterSequenceTypeMobileRelatedTask : FfiConverterRustBuffer<List<MobileRelatedTask>> {
+public object FfiConverterSequenceTypeMobileRelatedTask: FfiConverterRustBuffer<List<MobileRelatedTask>> {
     override fun read(buf: ByteBuffer): List<MobileRelatedTask> {
         val len = buf.getInt()
         return List<MobileRelatedTask>(len) {
@@ -6874,10 +8515,7 @@ public object FfiConverterSequenceTypeMobileRelatedTask : FfiConverterRustBuffer
         return sizeForLength + sizeForItems
     }
 
-    override fun write(
-        value: List<MobileRelatedTask>,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: List<MobileRelatedTask>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
             FfiConverterTypeMobileRelatedTask.write(it, buf)
@@ -6885,10 +8523,13 @@ public object FfiConverterSequenceTypeMobileRelatedTask : FfiConverterRustBuffer
     }
 }
 
+
+
+
 /**
  * @suppress
  */
-public object FfiConverterSequenceTypeMobileSyntaxToken : FfiConverterRustBuffer<List<MobileSyntaxToken>> {
+public object FfiConverterSequenceTypeMobileSyntaxToken: FfiConverterRustBuffer<List<MobileSyntaxToken>> {
     override fun read(buf: ByteBuffer): List<MobileSyntaxToken> {
         val len = buf.getInt()
         return List<MobileSyntaxToken>(len) {
@@ -6902,10 +8543,7 @@ public object FfiConverterSequenceTypeMobileSyntaxToken : FfiConverterRustBuffer
         return sizeForLength + sizeForItems
     }
 
-    override fun write(
-        value: List<MobileSyntaxToken>,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: List<MobileSyntaxToken>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
             FfiConverterTypeMobileSyntaxToken.write(it, buf)
@@ -6913,10 +8551,13 @@ public object FfiConverterSequenceTypeMobileSyntaxToken : FfiConverterRustBuffer
     }
 }
 
+
+
+
 /**
  * @suppress
  */
-public object FfiConverterSequenceTypeMobileTag : FfiConverterRustBuffer<List<MobileTag>> {
+public object FfiConverterSequenceTypeMobileTag: FfiConverterRustBuffer<List<MobileTag>> {
     override fun read(buf: ByteBuffer): List<MobileTag> {
         val len = buf.getInt()
         return List<MobileTag>(len) {
@@ -6930,10 +8571,7 @@ public object FfiConverterSequenceTypeMobileTag : FfiConverterRustBuffer<List<Mo
         return sizeForLength + sizeForItems
     }
 
-    override fun write(
-        value: List<MobileTag>,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: List<MobileTag>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
             FfiConverterTypeMobileTag.write(it, buf)
@@ -6941,10 +8579,13 @@ public object FfiConverterSequenceTypeMobileTag : FfiConverterRustBuffer<List<Mo
     }
 }
 
+
+
+
 /**
  * @suppress
  */
-public object FfiConverterSequenceTypeMobileTask : FfiConverterRustBuffer<List<MobileTask>> {
+public object FfiConverterSequenceTypeMobileTask: FfiConverterRustBuffer<List<MobileTask>> {
     override fun read(buf: ByteBuffer): List<MobileTask> {
         val len = buf.getInt()
         return List<MobileTask>(len) {
@@ -6958,10 +8599,7 @@ public object FfiConverterSequenceTypeMobileTask : FfiConverterRustBuffer<List<M
         return sizeForLength + sizeForItems
     }
 
-    override fun write(
-        value: List<MobileTask>,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: List<MobileTask>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
             FfiConverterTypeMobileTask.write(it, buf)
@@ -6969,10 +8607,13 @@ public object FfiConverterSequenceTypeMobileTask : FfiConverterRustBuffer<List<M
     }
 }
 
+
+
+
 /**
  * @suppress
  */
-public object FfiConverterSequenceTypeMobileWorkSession : FfiConverterRustBuffer<List<MobileWorkSession>> {
+public object FfiConverterSequenceTypeMobileWorkSession: FfiConverterRustBuffer<List<MobileWorkSession>> {
     override fun read(buf: ByteBuffer): List<MobileWorkSession> {
         val len = buf.getInt()
         return List<MobileWorkSession>(len) {
@@ -6986,10 +8627,7 @@ public object FfiConverterSequenceTypeMobileWorkSession : FfiConverterRustBuffer
         return sizeForLength + sizeForItems
     }
 
-    override fun write(
-        value: List<MobileWorkSession>,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: List<MobileWorkSession>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
             FfiConverterTypeMobileWorkSession.write(it, buf)
@@ -6997,10 +8635,13 @@ public object FfiConverterSequenceTypeMobileWorkSession : FfiConverterRustBuffer
     }
 }
 
+
+
+
 /**
  * @suppress
  */
-public object FfiConverterMapStringTypeMobileGoal : FfiConverterRustBuffer<Map<kotlin.String, MobileGoal>> {
+public object FfiConverterMapStringTypeMobileGoal: FfiConverterRustBuffer<Map<kotlin.String, MobileGoal>> {
     override fun read(buf: ByteBuffer): Map<kotlin.String, MobileGoal> {
         val len = buf.getInt()
         return buildMap<kotlin.String, MobileGoal>(len) {
@@ -7014,19 +8655,14 @@ public object FfiConverterMapStringTypeMobileGoal : FfiConverterRustBuffer<Map<k
 
     override fun allocationSize(value: Map<kotlin.String, MobileGoal>): ULong {
         val spaceForMapSize = 4UL
-        val spaceForChildren =
-            value
-                .map { (k, v) ->
-                    FfiConverterString.allocationSize(k) +
-                        FfiConverterTypeMobileGoal.allocationSize(v)
-                }.sum()
+        val spaceForChildren = value.map { (k, v) ->
+            FfiConverterString.allocationSize(k) +
+            FfiConverterTypeMobileGoal.allocationSize(v)
+        }.sum()
         return spaceForMapSize + spaceForChildren
     }
 
-    override fun write(
-        value: Map<kotlin.String, MobileGoal>,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: Map<kotlin.String, MobileGoal>, buf: ByteBuffer) {
         buf.putInt(value.size)
         // The parens on `(k, v)` here ensure we're calling the right method,
         // which is important for compatibility with older android devices.
@@ -7038,10 +8674,13 @@ public object FfiConverterMapStringTypeMobileGoal : FfiConverterRustBuffer<Map<k
     }
 }
 
+
+
+
 /**
  * @suppress
  */
-public object FfiConverterMapStringSequenceString : FfiConverterRustBuffer<Map<kotlin.String, List<kotlin.String>>> {
+public object FfiConverterMapStringSequenceString: FfiConverterRustBuffer<Map<kotlin.String, List<kotlin.String>>> {
     override fun read(buf: ByteBuffer): Map<kotlin.String, List<kotlin.String>> {
         val len = buf.getInt()
         return buildMap<kotlin.String, List<kotlin.String>>(len) {
@@ -7055,19 +8694,14 @@ public object FfiConverterMapStringSequenceString : FfiConverterRustBuffer<Map<k
 
     override fun allocationSize(value: Map<kotlin.String, List<kotlin.String>>): ULong {
         val spaceForMapSize = 4UL
-        val spaceForChildren =
-            value
-                .map { (k, v) ->
-                    FfiConverterString.allocationSize(k) +
-                        FfiConverterSequenceString.allocationSize(v)
-                }.sum()
+        val spaceForChildren = value.map { (k, v) ->
+            FfiConverterString.allocationSize(k) +
+            FfiConverterSequenceString.allocationSize(v)
+        }.sum()
         return spaceForMapSize + spaceForChildren
     }
 
-    override fun write(
-        value: Map<kotlin.String, List<kotlin.String>>,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: Map<kotlin.String, List<kotlin.String>>, buf: ByteBuffer) {
         buf.putInt(value.size)
         // The parens on `(k, v)` here ensure we're calling the right method,
         // which is important for compatibility with older android devices.
@@ -7079,13 +8713,31 @@ public object FfiConverterMapStringSequenceString : FfiConverterRustBuffer<Map<k
     }
 }
 
-fun `initPanicHook`(`cacheDir`: kotlin.String) =
-    uniffiRustCall { _status ->
-        UniffiLib.uniffi_cfait_fn_func_init_panic_hook(FfiConverterString.lower(`cacheDir`), _status)
-    }
 
-@Throws(MobileException::class)
-fun `initTokioRuntime`() =
+
+
+
+
+
+ fun `initPanicHook`(`cacheDir`: kotlin.String)
+        = 
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_cfait_fn_func_init_panic_hook(
+    
+        
+        FfiConverterString.lower(`cacheDir`),_status)
+}
+    
+    
+
+    @Throws(MobileException::class) fun `initTokioRuntime`()
+        = 
     uniffiRustCallWithError(MobileException) { _status ->
-        UniffiLib.uniffi_cfait_fn_func_init_tokio_runtime(_status)
-    }
+    UniffiLib.uniffi_cfait_fn_func_init_tokio_runtime(
+    
+        _status)
+}
+    
+    
+
+
@@ -706,7 +706,10 @@ fun HomeScreen(
         scope.launch {
             isManualSyncing = true
             try {
-                api.sync()
+                val result = api.sync()
+                if (result.warnings.isNotEmpty()) {
+                    Toast.makeText(context, result.warnings.joinToString("\n"), Toast.LENGTH_LONG).show()
+                }
                 lastSyncFailed = false
                 onDataChanged()
                 updateTaskList()
@@ -728,7 +731,10 @@ fun HomeScreen(
         scope.launch {
             isPullRefreshing = true
             try {
-                api.sync()
+                val result = api.sync()
+                if (result.warnings.isNotEmpty()) {
+                    Toast.makeText(context, result.warnings.joinToString("\n"), Toast.LENGTH_LONG).show()
+                }
                 lastSyncFailed = false
                 onDataChanged()
                 updateTaskList()
@@ -71,116 +71,119 @@ class AlarmWorker(
 
     // formatMins removed — snooze preset is no longer provided by the notification UI
 
-    private fun showNotification(
-        context: Context,
-        title: String,
-        body: String,
-        taskUid: String,
-        alarmUid: String
-    ) {
-        if (ActivityCompat.checkSelfPermission(
-                context,
-                Manifest.permission.POST_NOTIFICATIONS
-            ) != PackageManager.PERMISSION_GRANTED
+    companion object {
+        /** Posts the alarm notification. Also used for alarms fired by the in-process actor. */
+        fun showNotification(
+            context: Context,
+            title: String,
+            body: String,
+            taskUid: String,
+            alarmUid: String
         ) {
-            return
-        }
+            if (ActivityCompat.checkSelfPermission(
+                    context,
+                    Manifest.permission.POST_NOTIFICATIONS
+                ) != PackageManager.PERMISSION_GRANTED
+            ) {
+                return
+            }
 
-        val tapIntent = Intent(context, MainActivity::class.java).apply {
-            flags = Intent.FLAG_ACTIVITY_NEW_TASK or Intent.FLAG_ACTIVITY_CLEAR_TASK
-            putExtra("focus_task_uid", taskUid)
-        }
-        val tapPending = PendingIntent.getActivity(
-            context,
-            taskUid.hashCode(),
-            tapIntent,
-            PendingIntent.FLAG_IMMUTABLE
-        )
-
-        // 1. Snooze Custom (Inline Reply) - Primary snooze action
-        val snoozeCustomKey = "snooze_custom_duration"
-        val remoteInput = RemoteInput.Builder(snoozeCustomKey)
-            .setLabel(context.getString(R.string.snooze_hint))
-            .build()
-
-        val snoozeCustomIntent = Intent(context, NotificationActionReceiver::class.java).apply {
-            action = NotificationActionWorker.ACTION_SNOOZE_CUSTOM
-            putExtra("T_UID", taskUid)
-            putExtra("A_UID", alarmUid)
-        }
-        val snoozeCustomPending = PendingIntent.getBroadcast(
-            context,
-            (taskUid + "SC").hashCode(),
-            snoozeCustomIntent,
-            PendingIntent.FLAG_UPDATE_CURRENT or PendingIntent.FLAG_MUTABLE
-        )
-
-        val customSnoozeAction = NotificationCompat.Action.Builder(
-            R.drawable.ic_launcher_foreground,
-            context.getString(R.string.snooze),
-            snoozeCustomPending
-        ).addRemoteInput(remoteInput).build()
-
-        // 2. Start Action (Replaces preset snooze)
-        val startIntent = Intent(context, NotificationActionReceiver::class.java).apply {
-            action = NotificationActionWorker.ACTION_START
-            putExtra("T_UID", taskUid)
-            putExtra("A_UID", alarmUid)
-        }
-        val startPending = PendingIntent.getBroadcast(
-            context,
-            (taskUid + "START").hashCode(),
-            startIntent,
-            PendingIntent.FLAG_UPDATE_CURRENT or PendingIntent.FLAG_IMMUTABLE
-        )
-
-        // 3. Done Action
-        val doneIntent = Intent(context, NotificationActionReceiver::class.java).apply {
-            action = NotificationActionWorker.ACTION_DONE
-            putExtra("T_UID", taskUid)
-            putExtra("A_UID", alarmUid)
-        }
-        val donePending = PendingIntent.getBroadcast(
-            context,
-            (taskUid + "DONE").hashCode(),
-            doneIntent,
-            PendingIntent.FLAG_UPDATE_CURRENT or PendingIntent.FLAG_IMMUTABLE
-        )
-
-        // Dismiss action (swiping away)
-        val deleteIntent = Intent(context, NotificationActionReceiver::class.java).apply {
-            action = NotificationActionWorker.ACTION_DISMISS
-            putExtra("T_UID", taskUid)
-            putExtra("A_UID", alarmUid)
+            val tapIntent = Intent(context, MainActivity::class.java).apply {
+                flags = Intent.FLAG_ACTIVITY_NEW_TASK or Intent.FLAG_ACTIVITY_CLEAR_TASK
+                putExtra("focus_task_uid", taskUid)
+            }
+            val tapPending = PendingIntent.getActivity(
+                context,
+                taskUid.hashCode(),
+                tapIntent,
+                PendingIntent.FLAG_IMMUTABLE
+            )
+
+            // 1. Snooze Custom (Inline Reply) - Primary snooze action
+            val snoozeCustomKey = "snooze_custom_duration"
+            val remoteInput = RemoteInput.Builder(snoozeCustomKey)
+                .setLabel(context.getString(R.string.snooze_hint))
+                .build()
+
+            val snoozeCustomIntent = Intent(context, NotificationActionReceiver::class.java).apply {
+                action = NotificationActionWorker.ACTION_SNOOZE_CUSTOM
+                putExtra("T_UID", taskUid)
+                putExtra("A_UID", alarmUid)
+            }
+            val snoozeCustomPending = PendingIntent.getBroadcast(
+                context,
+                (taskUid + "SC").hashCode(),
+                snoozeCustomIntent,
+                PendingIntent.FLAG_UPDATE_CURRENT or PendingIntent.FLAG_MUTABLE
+            )
+
+            val customSnoozeAction = NotificationCompat.Action.Builder(
+                R.drawable.ic_launcher_foreground,
+                context.getString(R.string.snooze),
+                snoozeCustomPending
+            ).addRemoteInput(remoteInput).build()
+
+            // 2. Start Action (Replaces preset snooze)
+            val startIntent = Intent(context, NotificationActionReceiver::class.java).apply {
+                action = NotificationActionWorker.ACTION_START
+                putExtra("T_UID", taskUid)
+                putExtra("A_UID", alarmUid)
+            }
+            val startPending = PendingIntent.getBroadcast(
+                context,
+                (taskUid + "START").hashCode(),
+                startIntent,
+                PendingIntent.FLAG_UPDATE_CURRENT or PendingIntent.FLAG_IMMUTABLE
+            )
+
+            // 3. Done Action
+            val doneIntent = Intent(context, NotificationActionReceiver::class.java).apply {
+                action = NotificationActionWorker.ACTION_DONE
+                putExtra("T_UID", taskUid)
+                putExtra("A_UID", alarmUid)
+            }
+            val donePending = PendingIntent.getBroadcast(
+                context,
+                (taskUid + "DONE").hashCode(),
+                doneIntent,
+                PendingIntent.FLAG_UPDATE_CURRENT or PendingIntent.FLAG_IMMUTABLE
+            )
+
+            // Dismiss action (swiping away)
+            val deleteIntent = Intent(context, NotificationActionReceiver::class.java).apply {
+                action = NotificationActionWorker.ACTION_DISMISS
+                putExtra("T_UID", taskUid)
+                putExtra("A_UID", alarmUid)
+            }
+            val deletePending = PendingIntent.getBroadcast(
+                context,
+                (taskUid + "DEL").hashCode(),
+                deleteIntent,
+                PendingIntent.FLAG_UPDATE_CURRENT or PendingIntent.FLAG_IMMUTABLE
+            )
+
+            val notification = NotificationCompat.Builder(context, "CFAIT_ALARMS")
+                .setSmallIcon(R.drawable.ic_launcher_foreground)
+                .setContentTitle(title)
+                .setContentText(body)
+                .setPriority(NotificationCompat.PRIORITY_HIGH)
+                .setContentIntent(tapPending)
+                .setDeleteIntent(deletePending) // Swipe = Dismiss
+                .setAutoCancel(true)
+                // ACTION ORDER: Snooze..., Start, Done
+                .addAction(customSnoozeAction)
+                .addAction(R.drawable.ic_launcher_foreground, context.getString(R.string.start), startPending)
+                .addAction(R.drawable.ic_launcher_foreground, context.getString(R.string.done), donePending)
+                .addExtras(android.os.Bundle().apply {
+                    putString("cfait_task_uid", taskUid)
+                    putString("cfait_alarm_uid", alarmUid)
+                    putString("cfait_notif_type", "alarm")
+                })
+                .build()
+
+            // Derive notification ID from taskUid so that new events (like Due) overwrite old ones (like Start)
+            val notificationId = (taskUid + "_alarm").hashCode()
+            NotificationManagerCompat.from(context).notify(notificationId, notification)
         }
-        val deletePending = PendingIntent.getBroadcast(
-            context,
-            (taskUid + "DEL").hashCode(),
-            deleteIntent,
-            PendingIntent.FLAG_UPDATE_CURRENT or PendingIntent.FLAG_IMMUTABLE
-        )
-
-        val notification = NotificationCompat.Builder(context, "CFAIT_ALARMS")
-            .setSmallIcon(R.drawable.ic_launcher_foreground)
-            .setContentTitle(title)
-            .setContentText(body)
-            .setPriority(NotificationCompat.PRIORITY_HIGH)
-            .setContentIntent(tapPending)
-            .setDeleteIntent(deletePending) // Swipe = Dismiss
-            .setAutoCancel(true)
-            // ACTION ORDER: Snooze..., Start, Done
-            .addAction(customSnoozeAction)
-            .addAction(R.drawable.ic_launcher_foreground, context.getString(R.string.start), startPending)
-            .addAction(R.drawable.ic_launcher_foreground, context.getString(R.string.done), donePending)
-            .addExtras(android.os.Bundle().apply {
-                putString("cfait_task_uid", taskUid)
-                putString("cfait_alarm_uid", alarmUid)
-                putString("cfait_notif_type", "alarm")
-            })
-            .build()
-
-        // Derive notification ID from taskUid so that new events (like Due) overwrite old ones (like Start)
-        val notificationId = (taskUid + "_alarm").hashCode()
-        NotificationManagerCompat.from(context).notify(notificationId, notification)
     }
 }
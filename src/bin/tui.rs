@@ -142,7 +142,7 @@ async fn apply_markdown_update(
             new_content,
             &config.tag_aliases,
             def_time,
-            config.trash_retention_days,
+            config,
             &cals,
         ) {
             Ok(acts) => {
@@ -159,7 +159,14 @@ async fn apply_markdown_update(
 
         let mut task = store.get_task_ref(full_uid).unwrap().clone();
         task.description = clean_desc.to_string();
-        task.apply_smart_input(smart_input, &config.tag_aliases, def_time);
+        task.apply_smart_input(
+            smart_input,
+            &config.tag_aliases,
+            def_time,
+            &config.holidays,
+            config.week_start,
+        );
+        task.apply_default_reminder(config.default_reminder_minutes_for(&task.calendar_href));
 
         store.resolve_dependencies(&mut task)?;
 
@@ -178,7 +185,13 @@ async fn apply_markdown_update(
         );
 
         for ext in extracted {
-            let mut sub = cfait::model::Task::new(&ext.raw_text, &config.tag_aliases, def_time);
+            let mut sub = cfait::model::Task::new_with_date_settings(
+                &ext.raw_text,
+                &config.tag_aliases,
+                def_time,
+                &config.holidays,
+                config.week_start,
+            );
             sub.uid = ext.uid;
             let p_uid_str = ext
                 .parent_uid
@@ -481,6 +494,16 @@ async fn main() -> Result<()> {
             return Ok(());
         }
         "export" => {
+            if args.iter().skip(2).any(|a| a == "--all") {
+                let format = if args.iter().skip(2).any(|a| a == "--json") {
+                    cfait::store::ExportFormat::Json
+                } else {
+                    cfait::store::ExportFormat::Ics
+                };
+                let store = build_store_cli(&ctx).await;
+                println!("{}", store.export_all(format));
+                return Ok(());
+            }
             let collection_id = if args.len() > 3 && args[2] == "--collection" {
                 Some(args[3].clone())
             } else {
@@ -574,12 +597,17 @@ async fn main() -> Result<()> {
             let mut parent_uid_arg = None;
             let mut no_wait = false;
             let mut wait = false;
+            let mut print_uid = false;
             let mut i = 2;
             let mut task_args = Vec::new();
             while i < args.len() {
                 if args[i] == "--no-wait" || args[i] == "-n" {
                     no_wait = true;
                     i += 1;
+                } else if args[i] == "--print-uid" {
+                    // Script-friendly output: only the full UID goes to stdout
+                    print_uid = true;
+                    i += 1;
                 } else if args[i] == "--wait" || args[i] == "-w" {
                     wait = true;
                     i += 1;
@@ -670,7 +698,13 @@ async fn main() -> Result<()> {
                 None
             };
 
-            let mut task = Task::new(&clean_input, &config.tag_aliases, def_time);
+            let mut task = Task::new_with_date_settings(
+                &clean_input,
+                &config.tag_aliases,
+                def_time,
+                &config.holidays,
+                config.week_start,
+            );
             if let Err(e) = temp_store.resolve_dependencies(&mut task) {
                 eprintln!("{}", e);
                 std::process::exit(1);
@@ -713,13 +747,17 @@ async fn main() -> Result<()> {
                 .create_task(task)
                 .await
                 .map_err(|e| anyhow::anyhow!(e))?;
-            println!(
-                "{}",
-                rust_i18n::t!(
-                    "task_added_successfully",
-                    uid = &uid[..std::cmp::min(8, uid.len())]
-                )
-            );
+            if print_uid {
+                println!("{}", uid);
+            } else {
+                println!(
+                    "{}",
+                    rust_i18n::t!(
+                        "task_added_successfully",
+                        uid = &uid[..std::cmp::min(8, uid.len())]
+                    )
+                );
+            }
 
             let (effective_no_wait, is_auto) = get_sync_strategy(no_wait, wait, &ctx);
 
@@ -731,10 +769,18 @@ async fn main() -> Result<()> {
                         rust_i18n::t!("warning_background_sync_failed", error = e.to_string())
                     );
                 }
-            } else if is_auto {
-                println!("{}", rust_i18n::t!("cli_action_queued_auto"));
             } else {
-                println!("{}", rust_i18n::t!("cli_action_queued"));
+                let msg = if is_auto {
+                    rust_i18n::t!("cli_action_queued_auto")
+                } else {
+                    rust_i18n::t!("cli_action_queued")
+                };
+                // Keep stdout clean for `uid=$(cfait add ... --print-uid)`
+                if print_uid {
+                    eprintln!("{}", msg);
+                } else {
+                    println!("{}", msg);
+                }
             }
             return Ok(());
         }
@@ -1021,7 +1067,14 @@ async fn main() -> Result<()> {
                         t.dependencies.clear();
                     }
 
-                    t.apply_smart_input(&input_to_apply, &config.tag_aliases, def_time);
+                    t.apply_smart_input(
+                        &input_to_apply,
+                        &config.tag_aliases,
+                        def_time,
+                        &config.holidays,
+                        config.week_start,
+                    );
+                    t.apply_default_reminder(config.default_reminder_minutes_for(&t.calendar_href));
                     temp_task = Some(t);
                 }
                 if let Some(mut t) = temp_task {
@@ -1258,6 +1311,7 @@ async fn main() -> Result<()> {
             let mut as_json = false;
             let mut col_href = None;
             let mut parent_uid_arg = None;
+            let mut tag_filters: HashSet<String> = HashSet::new();
             let mut query_parts: Vec<String> = Vec::new();
 
             let mut i = 2;
@@ -1284,6 +1338,18 @@ async fn main() -> Result<()> {
                         eprintln!("Error: Missing value for --parent");
                         std::process::exit(1);
                     }
+                } else if args[i] == "--tag" || args[i] == "-t" {
+                    if i + 1 < args.len() {
+                        // Accept both `groceries` and `#groceries`
+                        let tag = args[i + 1].trim_start_matches('#').to_string();
+                        if !tag.is_empty() {
+                            tag_filters.insert(tag);
+                        }
+                        i += 2;
+                    } else {
+                        eprintln!("Error: Missing value for --tag");
+                        std::process::exit(1);
+                    }
                 } else {
                     query_parts.push(args[i].clone());
                     i += 1;
@@ -1302,6 +1368,7 @@ async fn main() -> Result<()> {
 
             let mut hidden: HashSet<String> = HashSet::new();
             let mut hide_completed = config.hide_completed;
+            let hide_deferred = config.hide_deferred && !show_all;
             if !show_all {
                 hidden.extend(config.hidden_calendars.into_iter());
                 hidden.extend(config.disabled_calendars.into_iter());
@@ -1339,33 +1406,45 @@ async fn main() -> Result<()> {
             };
 
             // Local empty sets to satisfy FilterOptions references
-            let selected_categories: HashSet<String> = HashSet::new();
             let selected_locations: HashSet<String> = HashSet::new();
             let expanded_done_groups: HashSet<String> = HashSet::new();
             let expanded_tags: HashSet<String> = HashSet::new();
             let expanded_locations: HashSet<String> = HashSet::new();
             let search_collapsed_tasks: HashSet<String> = HashSet::new();
 
+            let exclusions = cfait::model::matcher::extract_filter_exclusions(&query);
+
             let res = store.filter(FilterOptions {
                 active_cal_href: target_href.as_deref(),
                 hidden_calendars: &hidden,
-                selected_categories: &selected_categories,
+                selected_categories: &tag_filters,
                 selected_locations: &selected_locations,
-                match_all_categories: false,
+                match_all_categories: true,
                 search_term: &query,
                 hide_completed_global: hide_completed,
+                hide_deferred,
                 hide_fully_completed_tags: !show_all && config.hide_fully_completed_tags,
                 hide_aliases_in_sidebar: config.hide_aliases_in_sidebar,
                 cutoff_date,
                 min_duration: None,
                 max_duration: None,
                 include_unset_duration: true,
+                exact_priority: None,
+                require_unset_duration: false,
+                excluded_categories: &exclusions.categories,
+                excluded_locations: &exclusions.locations,
+                excluded_priorities: &exclusions.priorities,
+                excluded_statuses: &exclusions.statuses,
                 urgent_days: config.urgent_days_horizon,
                 urgent_prio: config.urgent_priority_threshold,
                 default_priority: config.default_priority,
                 start_grace_period_days: config.start_grace_period_days,
                 sort_standard_by_priority: config.sort_standard_by_priority,
                 sort_preset: config.sort_preset,
+                sort_mode: config.sort_mode,
+                all_day_sort: config.all_day_sort,
+                week_start: config.week_start,
+                sort_urgency_boost: config.sort_urgency_boost,
                 expanded_done_groups: &expanded_done_groups,
                 expanded_tags: &expanded_tags,
                 expanded_locations: &expanded_locations,
@@ -1374,6 +1453,7 @@ async fn main() -> Result<()> {
                 tag_aliases: &config.tag_aliases,
                 search_collapsed_tasks: &search_collapsed_tasks,
                 focused_task_uid: full_parent_uid.as_deref(),
+                fuzzy_search: config.fuzzy_search,
             });
 
             if as_json {
@@ -1422,6 +1502,27 @@ async fn main() -> Result<()> {
             }
             return Ok(());
         }
+        "parse" => {
+            // Dry run: show how the smart input would be understood, create nothing
+            let input = args[2..].join(" ");
+            if input.trim().is_empty() {
+                eprintln!("{}", rust_i18n::t!("cli_usage_parse"));
+                std::process::exit(1);
+            }
+            let def_time =
+                chrono::NaiveTime::parse_from_str(&config.default_reminder_time, "%H:%M").ok();
+            let preview = cfait::model::ParsePreview::parse(
+                &input,
+                &config.tag_aliases,
+                def_time,
+                &config.holidays,
+                config.week_start,
+            );
+            for (name, value) in preview.fields(config.date_format, config.time_format) {
+                println!("{:12} {}", name, value);
+            }
+            return Ok(());
+        }
         "tree" => {
             let mut partial = String::new();
             for arg in args.iter().skip(2) {
@@ -1483,7 +1584,7 @@ async fn main() -> Result<()> {
                 println!(
                     "{}:      {}",
                     rust_i18n::t!("cli_view_due"),
-                    d.format_smart()
+                    d.format_with(config.date_format, config.time_format)
                 );
             }
             if !t.categories.is_empty() {
@@ -19,6 +19,14 @@ struct CalendarCache {
     tasks: Vec<Task>,
 }
 
+/// Result of principal/home-set discovery, keyed by the server URL it was found on.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DiscoveryCache {
+    pub server_url: String,
+    pub principal: String,
+    pub home_set: String,
+}
+
 pub struct Cache;
 
 impl Cache {
@@ -26,6 +34,10 @@ impl Cache {
         ctx.get_cache_dir().ok().map(|p| p.join("calendars.json"))
     }
 
+    fn get_discovery_path(ctx: &dyn AppContext) -> Option<PathBuf> {
+        ctx.get_cache_dir().ok().map(|p| p.join("discovery.json"))
+    }
+
     fn get_path(ctx: &dyn AppContext, key: &str) -> Option<PathBuf> {
         ctx.get_cache_dir().ok().map(|dir| {
             let mut hasher = DefaultHasher::new();
@@ -96,4 +108,42 @@ impl Cache {
         }
         Ok(vec![])
     }
+
+    pub fn save_discovery(ctx: &dyn AppContext, discovery: &DiscoveryCache) -> Result<()> {
+        if let Some(path) = Self::get_discovery_path(ctx) {
+            LocalStorage::with_lock(&path, || {
+                let json = serde_json::to_string_pretty(discovery)?;
+                LocalStorage::atomic_write(&path, json)?;
+                Ok(())
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Returns the cached discovery only if it was recorded for `server_url`.
+    pub fn load_discovery(ctx: &dyn AppContext, server_url: &str) -> Option<DiscoveryCache> {
+        let path = Self::get_discovery_path(ctx)?;
+        if !path.exists() {
+            return None;
+        }
+        LocalStorage::with_lock(&path, || {
+            let json = fs::read_to_string(&path)?;
+            Ok(serde_json::from_str::<DiscoveryCache>(&json).ok())
+        })
+        .ok()
+        .flatten()
+        .filter(|d| d.server_url == server_url)
+    }
+
+    pub fn clear_discovery(ctx: &dyn AppContext) -> Result<()> {
+        if let Some(path) = Self::get_discovery_path(ctx)
+            && path.exists()
+        {
+            LocalStorage::with_lock(&path, || {
+                fs::remove_file(&path)?;
+                Ok(())
+            })?;
+        }
+        Ok(())
+    }
 }
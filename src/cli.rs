@@ -26,6 +26,7 @@ pub fn print_help(binary_name: &str) {
     } else {
         println!("    {} [--root <path>]", binary_name);
         println!("    {} export [--collection <id>]", binary_name);
+        println!("    {} export --all [--json]", binary_name);
         println!(
             "    {} import <{}> [--collection <id>]",
             binary_name,
@@ -113,6 +114,10 @@ pub fn print_help(binary_name: &str) {
             &format!("{binary_name} export --collection <id>"),
             rust_i18n::t!("cli_desc_export_specific").to_string(),
         );
+        print_cmd(
+            &format!("{binary_name} export --all [--json]"),
+            rust_i18n::t!("cli_desc_export_all").to_string(),
+        );
         print_cmd(
             &format!("{binary_name} export > backup.ics"),
             rust_i18n::t!("cli_desc_export_file").to_string(),
@@ -127,7 +132,7 @@ pub fn print_help(binary_name: &str) {
         println!("{}", rust_i18n::t!("cli_action_commands_heading"));
         print_cmd(
             &format!(
-                "{} add <{}> [-c <id>] [--desc <text>] [-p <uid>] [-n] [-w]",
+                "{} add <{}> [-c <id>] [--desc <text>] [-p <uid>] [--print-uid] [-n] [-w]",
                 binary_name,
                 rust_i18n::t!("cli_task_placeholder")
             ),
@@ -153,12 +158,23 @@ pub fn print_help(binary_name: &str) {
             rust_i18n::t!("cli_desc_append").to_string(),
         );
         print_cmd(
-            &format!("{} list [--all] [--json] [-c <id>] [-p <uid>]", binary_name),
+            &format!(
+                "{} parse <{}>",
+                binary_name,
+                rust_i18n::t!("cli_task_placeholder")
+            ),
+            rust_i18n::t!("cli_desc_parse").to_string(),
+        );
+        print_cmd(
+            &format!(
+                "{} list [--all] [--json] [-c <id>] [-p <uid>] [-t <tag>]",
+                binary_name
+            ),
             rust_i18n::t!("cli_desc_list").to_string(),
         );
         print_cmd(
             &format!(
-                "{} search <{}> [--all] [--json] [-c <id>] [-p <uid>]",
+                "{} search <{}> [--all] [--json] [-c <id>] [-p <uid>] [-t <tag>]",
                 binary_name,
                 rust_i18n::t!("cli_query_placeholder")
             ),
@@ -211,6 +227,13 @@ pub fn print_help(binary_name: &str) {
     } else {
         println!("{}", rust_i18n::t!("cli_keybindings_heading"));
         println!("    {}", rust_i18n::t!("cli_press_question"));
+        #[cfg(feature = "tui")]
+        {
+            println!("    {}", rust_i18n::t!("cli_keybindings_config"));
+            for action in crate::tui::keymap::KeyAction::ALL {
+                println!("        {:12} {}", action.name(), action.default_keys());
+            }
+        }
         println!();
         println!("{}", rust_i18n::t!("cli_smart_input_heading"));
 
@@ -1,23 +1,126 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
-// Implements HTTP authentication logic (Basic/Digest) for the client.
+// Implements HTTP authentication logic (Basic/Digest/Bearer) for the client.
 use base64::Engine;
 use base64::engine::general_purpose::STANDARD;
 use digest_auth::{AuthContext, HttpMethod};
 use http::{HeaderValue, Request, Response, StatusCode};
 use std::future::Future;
 use std::pin::Pin;
+use std::sync::{Arc, RwLock};
 use std::task::{Context, Poll};
 use tower_service::Service;
 
+/// Fetches a fresh access token after a 401 (e.g. an OAuth2 refresh-token grant).
+/// Returns `None` when no new token could be obtained.
+pub type TokenRefresher =
+    Arc<dyn Fn() -> Pin<Box<dyn Future<Output = Option<String>> + Send>> + Send + Sync>;
+
+/// Boxed response future returned by `DynamicAuthService`.
+type ResponseFuture<ResBody, E> =
+    Pin<Box<dyn Future<Output = Result<Response<ResBody>, E>> + Send>>;
+
+/// Bearer credentials shared by every clone of the service, so a refreshed token
+/// is used by all subsequent requests.
+#[derive(Clone)]
+pub struct BearerAuth {
+    token: Arc<RwLock<String>>,
+    refresher: Option<TokenRefresher>,
+    // Held while refreshing, so concurrent 401s trigger a single refresh (providers that
+    // rotate refresh tokens invalidate all but one of parallel refreshes)
+    refresh_lock: Arc<tokio::sync::Mutex<()>>,
+}
+
+impl BearerAuth {
+    pub fn new(token: String, refresher: Option<TokenRefresher>) -> Self {
+        Self {
+            token: Arc::new(RwLock::new(token)),
+            refresher,
+            refresh_lock: Arc::new(tokio::sync::Mutex::new(())),
+        }
+    }
+
+    fn current(&self) -> Option<String> {
+        self.token.read().ok().map(|t| t.clone())
+    }
+
+    fn header(&self) -> Option<HeaderValue> {
+        bearer_header(&self.current()?)
+    }
+
+    /// Gets a new token after `rejected` drew a 401. Only one refresh runs at a time;
+    /// callers that waited for it reuse its token instead of refreshing again.
+    async fn refresh(&self, rejected: Option<&str>) -> bool {
+        let Some(refresher) = &self.refresher else {
+            return false;
+        };
+        let _guard = self.refresh_lock.lock().await;
+        if self.current().as_deref() != rejected {
+            return true;
+        }
+        let Some(new_token) = refresher().await else {
+            return false;
+        };
+        if let Ok(mut token) = self.token.write() {
+            *token = new_token;
+        }
+        true
+    }
+}
+
+fn bearer_header(token: &str) -> Option<HeaderValue> {
+    HeaderValue::from_str(&format!("Bearer {}", token)).ok()
+}
+
+impl std::fmt::Debug for BearerAuth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BearerAuth")
+            .field("token", &"<redacted>")
+            .field("refresher", &self.refresher.is_some())
+            .finish()
+    }
+}
+
+/// Extracts `access_token` and the optional rotated `refresh_token` from an OAuth2
+/// token endpoint's JSON response.
+pub fn parse_oauth_token_response(body: &[u8]) -> Option<(String, Option<String>)> {
+    let json: serde_json::Value = serde_json::from_slice(body).ok()?;
+    let access = json.get("access_token")?.as_str()?.to_string();
+    if access.is_empty() {
+        return None;
+    }
+    let refresh = json
+        .get("refresh_token")
+        .and_then(|v| v.as_str())
+        .filter(|s| !s.is_empty())
+        .map(str::to_string);
+    Some((access, refresh))
+}
+
 #[derive(Clone, Debug)]
 pub struct DynamicAuthLayer {
     pub user: String,
     pub pass: String,
+    /// When set, requests carry `Authorization: Bearer …` instead of Basic/Digest.
+    pub bearer: Option<BearerAuth>,
 }
 
 impl DynamicAuthLayer {
     pub fn new(user: String, pass: String) -> Self {
-        Self { user, pass }
+        Self {
+            user,
+            pass,
+            bearer: None,
+        }
+    }
+
+    /// OAuth2-style auth: sends `token` as a bearer token and, on a 401, asks `refresher`
+    /// for a new one and retries the request once.
+    pub fn bearer(token: String, refresher: Option<TokenRefresher>) -> Self {
+        Self {
+            user: String::new(),
+            pass: String::new(),
+            bearer: Some(BearerAuth::new(token, refresher)),
+        }
     }
 }
 
@@ -29,6 +132,7 @@ impl<S> tower_layer::Layer<S> for DynamicAuthLayer {
             inner,
             user: self.user.clone(),
             pass: self.pass.clone(),
+            bearer: self.bearer.clone(),
         }
     }
 }
@@ -38,6 +142,45 @@ pub struct DynamicAuthService<S> {
     inner: S,
     user: String,
     pass: String,
+    bearer: Option<BearerAuth>,
+}
+
+impl<S> DynamicAuthService<S> {
+    fn call_bearer<ReqBody, ResBody>(
+        &mut self,
+        bearer: BearerAuth,
+        mut req: Request<ReqBody>,
+    ) -> ResponseFuture<ResBody, S::Error>
+    where
+        S: Service<Request<ReqBody>, Response = Response<ResBody>> + Clone + Send + 'static,
+        S::Future: Send + 'static,
+        ReqBody: Clone + Send + 'static,
+        ResBody: Send + 'static,
+    {
+        let sent = bearer.current();
+        if let Some(val) = sent.as_deref().and_then(bearer_header) {
+            req.headers_mut().insert(http::header::AUTHORIZATION, val);
+        }
+        let req_clone = req.clone();
+        let mut inner = self.inner.clone();
+
+        Box::pin(async move {
+            let response = inner.call(req).await?;
+            if response.status() != StatusCode::UNAUTHORIZED {
+                return Ok(response);
+            }
+            if !bearer.refresh(sent.as_deref()).await {
+                return Ok(response);
+            }
+            let mut new_req = req_clone;
+            if let Some(val) = bearer.header() {
+                new_req
+                    .headers_mut()
+                    .insert(http::header::AUTHORIZATION, val);
+            }
+            inner.call(new_req).await
+        })
+    }
 }
 
 impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for DynamicAuthService<S>
@@ -57,6 +200,10 @@ where
     }
 
     fn call(&mut self, mut req: Request<ReqBody>) -> Self::Future {
+        if let Some(bearer) = self.bearer.clone() {
+            return self.call_bearer(bearer, req);
+        }
+
         // 1. Optimistic Basic Auth
         let basic_header_val = format!(
             "Basic {}",
@@ -4,7 +4,7 @@
 //! This module contains the implementation for processing the offline action queue.
 use crate::client::core::{HttpsClient, RustyClient, strip_host};
 use crate::journal::{Action, Journal};
-use crate::model::merge::three_way_merge;
+use crate::model::merge::{ConflictResolution, resolve_conflict};
 use crate::model::{CalendarListEntry, IcsAdapter, Task};
 use crate::storage::{LocalCalendarRegistry, LocalStorage};
 
@@ -33,6 +33,57 @@ const PATH_ENCODE_SET: &AsciiSet = &CONTROLS
     .add(b'{')
     .add(b'}');
 
+/// How many times a transiently failing action is retried before `sync_journal` gives up.
+pub const SYNC_MAX_RETRIES: u32 = 3;
+/// Delay before the first retry; doubled on each subsequent attempt.
+const SYNC_RETRY_BASE_DELAY_MS: u64 = 250;
+
+/// A failed sync step. `status` is the HTTP status when the server answered with an
+/// error code, so retry decisions don't depend on the message text.
+struct StepError {
+    message: String,
+    status: Option<StatusCode>,
+}
+
+impl StepError {
+    fn from_webdav<E>(message: String, err: &WebDavError<E>) -> Self {
+        let status = match err {
+            WebDavError::BadStatusCode(status) => Some(*status),
+            _ => None,
+        };
+        Self { message, status }
+    }
+}
+
+impl From<String> for StepError {
+    fn from(message: String) -> Self {
+        Self {
+            message,
+            status: None,
+        }
+    }
+}
+
+/// Errors worth retrying in place: 5xx responses, timeouts and dropped connections.
+/// 4xx responses are deliberately excluded; they go through the recovery/discard paths.
+fn is_transient_error(err: &StepError) -> bool {
+    if let Some(status) = err.status {
+        return matches!(
+            status,
+            StatusCode::INTERNAL_SERVER_ERROR
+                | StatusCode::BAD_GATEWAY
+                | StatusCode::SERVICE_UNAVAILABLE
+                | StatusCode::GATEWAY_TIMEOUT
+        );
+    }
+    let lower = err.message.to_lowercase();
+    lower.contains("timed out")
+        || lower.contains("timeout")
+        || lower.contains("connection reset")
+        || lower.contains("connection closed")
+        || lower.contains("broken pipe")
+}
+
 // --- Internal Sync Outcome Types ---
 enum StepOutcome {
     Success {
@@ -141,7 +192,7 @@ impl RustyClient {
         &self,
         client: &CalDavClient<HttpsClient>,
         task: &Task,
-    ) -> Result<StepResult, String> {
+    ) -> Result<StepResult, StepError> {
         let path = fix_and_encode_path(
             client,
             &task.calendar_href,
@@ -217,7 +268,7 @@ impl RustyClient {
                     if code == 413 {
                         return Ok(StepResult::new(StepOutcome::Discard).with_warning(msg));
                     } else if code == 401 || code == 403 {
-                        return Err(rust_i18n::t!("error_auth_failed").to_string());
+                        return Err(rust_i18n::t!("error_auth_failed").to_string().into());
                     }
                 } else if msg.contains("413") {
                     return Ok(StepResult::new(StepOutcome::Discard).with_warning(msg));
@@ -226,10 +277,10 @@ impl RustyClient {
                     || msg.contains("Unauthorized")
                     || msg.contains("Forbidden")
                 {
-                    return Err(rust_i18n::t!("error_auth_failed").to_string());
+                    return Err(rust_i18n::t!("error_auth_failed").to_string().into());
                 }
 
-                Err(msg)
+                Err(StepError::from_webdav(msg, &e))
             }
         }
     }
@@ -238,7 +289,7 @@ impl RustyClient {
         &self,
         client: &CalDavClient<HttpsClient>,
         task: &Task,
-    ) -> Result<StepResult, String> {
+    ) -> Result<StepResult, StepError> {
         let config = crate::config::Config::load(self.ctx.as_ref()).unwrap_or_default();
         let events_enabled = config.create_events_for_tasks;
         let delete_on_completion = config.delete_events_on_completion;
@@ -303,11 +354,8 @@ impl RustyClient {
                     );
                 }
 
-                if let Some((resolution, msg)) = self.attempt_conflict_resolution(task).await {
-                    Ok(
-                        StepResult::new(StepOutcome::RetryWith(Box::new(resolution)))
-                            .with_warning(msg),
-                    )
+                if let Some(resolution) = self.attempt_conflict_resolution(task).await {
+                    Ok(resolution)
                 } else {
                     let mut conflict_copy = task.clone();
                     conflict_copy.uid = uuid::Uuid::new_v4().to_string();
@@ -375,9 +423,9 @@ impl RustyClient {
                         if code == 413 {
                             Ok(StepResult::new(StepOutcome::Discard).with_warning(msg))
                         } else if code == 401 || code == 403 {
-                            Err(rust_i18n::t!("error_auth_failed").to_string())
+                            Err(rust_i18n::t!("error_auth_failed").to_string().into())
                         } else {
-                            Err(msg)
+                            Err(StepError::from_webdav(msg, &e))
                         }
                     } else if msg.contains("413") {
                         Ok(StepResult::new(StepOutcome::Discard).with_warning(msg))
@@ -386,9 +434,9 @@ impl RustyClient {
                         || msg.contains("Unauthorized")
                         || msg.contains("Forbidden")
                     {
-                        Err(rust_i18n::t!("error_auth_failed").to_string())
+                        Err(rust_i18n::t!("error_auth_failed").to_string().into())
                     } else {
-                        Err(msg)
+                        Err(StepError::from_webdav(msg, &e))
                     }
                 }
             }
@@ -399,7 +447,7 @@ impl RustyClient {
         &self,
         client: &CalDavClient<HttpsClient>,
         task: &Task,
-    ) -> Result<StepResult, String> {
+    ) -> Result<StepResult, StepError> {
         let config = crate::config::Config::load(self.ctx.as_ref()).unwrap_or_default();
         let events_enabled = config.create_events_for_tasks;
         let delete_on_completion = config.delete_events_on_completion;
@@ -463,24 +511,24 @@ impl RustyClient {
                 } else if let WebDavError::BadStatusCode(status) = &e {
                     let code = status.as_u16();
                     if code == 401 || code == 403 {
-                        Err(rust_i18n::t!("error_auth_failed").to_string())
+                        Err(rust_i18n::t!("error_auth_failed").to_string().into())
                     } else {
-                        Err(msg)
+                        Err(StepError::from_webdav(msg, &e))
                     }
                 } else if msg.contains("401")
                     || msg.contains("403")
                     || msg.contains("Unauthorized")
                     || msg.contains("Forbidden")
                 {
-                    Err(rust_i18n::t!("error_auth_failed").to_string())
+                    Err(rust_i18n::t!("error_auth_failed").to_string().into())
                 } else {
-                    Err(msg)
+                    Err(StepError::from_webdav(msg, &e))
                 }
             }
         }
     }
 
-    async fn handle_move(&self, task: &Task, new_cal: &str) -> Result<StepResult, String> {
+    async fn handle_move(&self, task: &Task, new_cal: &str) -> Result<StepResult, StepError> {
         let config = crate::config::Config::load(self.ctx.as_ref()).unwrap_or_default();
         let events_enabled = config.create_events_for_tasks;
         let delete_on_completion = config.delete_events_on_completion;
@@ -547,6 +595,63 @@ impl RustyClient {
         }
     }
 
+    /// Runs a single journal action against the server (or the test hook, when installed).
+    async fn execute_step(
+        &self,
+        client: &CalDavClient<HttpsClient>,
+        action: &Action,
+    ) -> Result<StepResult, StepError> {
+        let test_forced_err: Option<anyhow::Error> = {
+            #[cfg(any(test, feature = "test_hooks"))]
+            {
+                if let Some(h) = TEST_FORCE_SYNC_ERROR.get() {
+                    if let Some(cb) = &*h.lock().unwrap() {
+                        cb(action)
+                    } else {
+                        None
+                    }
+                } else {
+                    None
+                }
+            }
+            #[cfg(not(any(test, feature = "test_hooks")))]
+            {
+                None
+            }
+        };
+
+        if let Some(err) = test_forced_err {
+            let err_msg = err.to_string();
+            if err_msg.contains("400")
+                || err_msg.contains("403")
+                || err_msg.contains("404")
+                || err_msg.contains("405")
+                || err_msg.contains("409")
+                || err_msg.contains("415")
+                || err_msg.contains("Forbidden")
+            {
+                Ok(StepResult::new(StepOutcome::RecoveryNeeded(err_msg)))
+            } else if err_msg.contains("413") {
+                Ok(StepResult::new(StepOutcome::Discard).with_warning(err_msg))
+            } else if err_msg.contains("401") || err_msg.contains("Unauthorized") {
+                Err(rust_i18n::t!("error_auth_failed").to_string().into())
+            } else {
+                // Hooks signal a server status with `WebDavError<Infallible>`
+                match err.downcast_ref::<WebDavError<std::convert::Infallible>>() {
+                    Some(webdav) => Err(StepError::from_webdav(format!("{:?}", webdav), webdav)),
+                    None => Err(err_msg.into()),
+                }
+            }
+        } else {
+            match action {
+                Action::Create(t) => self.handle_create(client, t).await,
+                Action::Update(t) => self.handle_update(client, t).await,
+                Action::Delete(t) => self.handle_delete(client, t).await,
+                Action::Move(t, new_cal) => self.handle_move(t, new_cal).await,
+            }
+        }
+    }
+
     pub async fn sync_journal(&self) -> Result<(Vec<String>, Vec<Task>), String> {
         // 1. Serialize sync loops process-wide to protect the physical journal file
         let lock = SYNC_LOCK.get_or_init(|| AsyncMutex::new(()));
@@ -587,49 +692,27 @@ impl RustyClient {
             let mut path_for_refresh: Option<String> = None;
             let mut synced_task: Option<Task> = None;
 
-            let test_forced_err: Option<anyhow::Error> = {
-                #[cfg(any(test, feature = "test_hooks"))]
-                {
-                    if let Some(h) = TEST_FORCE_SYNC_ERROR.get() {
-                        if let Some(cb) = &*h.lock().unwrap() {
-                            cb(&next_action)
-                        } else {
-                            None
+            // Transient failures (timeouts, 5xx, dropped connections) are retried in place with
+            // exponential backoff; anything else is handled by the outcome logic below.
+            let mut attempt = 0;
+            let step_result = loop {
+                match self.execute_step(client, &next_action).await {
+                    Err(err) if is_transient_error(&err) => {
+                        if attempt >= SYNC_MAX_RETRIES {
+                            break Err(rust_i18n::t!(
+                                "sync_retries_exhausted",
+                                attempts = attempt + 1,
+                                error = err.message
+                            )
+                            .to_string());
                         }
-                    } else {
-                        None
+                        tokio::time::sleep(std::time::Duration::from_millis(
+                            SYNC_RETRY_BASE_DELAY_MS << attempt,
+                        ))
+                        .await;
+                        attempt += 1;
                     }
-                }
-                #[cfg(not(any(test, feature = "test_hooks")))]
-                {
-                    None
-                }
-            };
-
-            let step_result = if let Some(err) = test_forced_err {
-                let err_msg = err.to_string();
-                if err_msg.contains("400")
-                    || err_msg.contains("403")
-                    || err_msg.contains("404")
-                    || err_msg.contains("405")
-                    || err_msg.contains("409")
-                    || err_msg.contains("415")
-                    || err_msg.contains("Forbidden")
-                {
-                    Ok(StepResult::new(StepOutcome::RecoveryNeeded(err_msg)))
-                } else if err_msg.contains("413") {
-                    Ok(StepResult::new(StepOutcome::Discard).with_warning(err_msg))
-                } else if err_msg.contains("401") || err_msg.contains("Unauthorized") {
-                    Err(rust_i18n::t!("error_auth_failed").to_string())
-                } else {
-                    Err(err_msg)
-                }
-            } else {
-                match &next_action {
-                    Action::Create(t) => self.handle_create(client, t).await,
-                    Action::Update(t) => self.handle_update(client, t).await,
-                    Action::Delete(t) => self.handle_delete(client, t).await,
-                    Action::Move(t, new_cal) => self.handle_move(t, new_cal).await,
+                    other => break other.map_err(|err| err.message),
                 }
             };
 
@@ -842,11 +925,9 @@ impl RustyClient {
         Ok((warnings, synced_tasks))
     }
 
-    async fn attempt_conflict_resolution(&self, local_task: &Task) -> Option<(Action, String)> {
-        let (cached_tasks, _) =
-            crate::cache::Cache::load(self.ctx.as_ref(), &local_task.calendar_href).ok()?;
-        let base_task = cached_tasks.iter().find(|t| t.uid == local_task.uid)?;
-
+    /// Resolves a 412 on update using the configured `ConflictStrategy`.
+    /// `None` means the caller should upload a conflict copy.
+    async fn attempt_conflict_resolution(&self, local_task: &Task) -> Option<StepResult> {
         let server_task = self.fetch_remote_task(&local_task.href).await?;
 
         let clean_etag = |e: &str| e.trim_start_matches("W/").trim_matches('"').to_string();
@@ -854,16 +935,35 @@ impl RustyClient {
             return None;
         }
 
-        if let Some(merged) = three_way_merge(base_task, local_task, &server_task) {
-            let msg = rust_i18n::t!(
-                "sync_conflict_resolved",
-                summary = local_task.summary.clone()
-            )
-            .to_string();
-            return Some((Action::Update(merged), msg));
+        let strategy = crate::config::Config::load(self.ctx.as_ref())
+            .unwrap_or_default()
+            .conflict_strategy;
+        let cached_tasks = crate::cache::Cache::load(self.ctx.as_ref(), &local_task.calendar_href)
+            .map(|(tasks, _)| tasks)
+            .unwrap_or_default();
+        let base_task = cached_tasks.iter().find(|t| t.uid == local_task.uid);
+        let summary = local_task.summary.clone();
+
+        match resolve_conflict(base_task, local_task, &server_task, strategy) {
+            ConflictResolution::Merged(merged) => Some(
+                StepResult::new(StepOutcome::RetryWith(Box::new(Action::Update(merged))))
+                    .with_warning(
+                        rust_i18n::t!("sync_conflict_resolved", summary = summary).to_string(),
+                    ),
+            ),
+            ConflictResolution::KeepLocal(kept) => Some(
+                StepResult::new(StepOutcome::RetryWith(Box::new(Action::Update(kept))))
+                    .with_warning(
+                        rust_i18n::t!("sync_conflict_kept_local", summary = summary).to_string(),
+                    ),
+            ),
+            ConflictResolution::KeepServer => Some(
+                StepResult::new(StepOutcome::ServerWins(Box::new(server_task))).with_warning(
+                    rust_i18n::t!("sync_conflict_kept_server", summary = summary).to_string(),
+                ),
+            ),
+            ConflictResolution::Copy => None,
         }
-
-        None
     }
 
     async fn execute_move(
@@ -6,14 +6,58 @@
 // It intentionally has NO dependencies on iced or other GUI crates so it can be
 // used from non-GUI code (TUI, core logic, tests, etc).
 
+use crate::config::TagColorMode;
 use std::hash::{Hash, Hasher};
 
+/// Okabe-Ito palette (minus black), distinguishable under the common forms of color blindness.
+pub const COLORBLIND_PALETTE: [(u8, u8, u8); 7] = [
+    (0xE6, 0x9F, 0x00), // Orange
+    (0x56, 0xB4, 0xE9), // Sky Blue
+    (0x00, 0x9E, 0x73), // Bluish Green
+    (0xF0, 0xE4, 0x42), // Yellow
+    (0x00, 0x72, 0xB2), // Blue
+    (0xD5, 0x5E, 0x00), // Vermillion
+    (0xCC, 0x79, 0xA7), // Reddish Purple
+];
+
+/// Color of a tag or location chip in the given `TagColorMode` (`Config::tag_color_mode`).
+/// Every view should go through this rather than calling a generator directly.
+pub fn tag_color(tag: &str, mode: TagColorMode, is_dark_theme: bool) -> (f32, f32, f32) {
+    match mode {
+        TagColorMode::Hashed => generate_tui_color(tag, is_dark_theme),
+        TagColorMode::ColorblindSafe => palette_color(tag, is_dark_theme),
+    }
+}
+
 /// Generates a deterministic color tuple (r, g, b) in [0.0, 1.0] range based on the input string.
 /// Ranges selected produce reasonably saturated and bright colors suitable for UI accents.
 pub fn generate_color(tag: &str) -> (f32, f32, f32) {
     generate_tui_color(tag, true)
 }
 
+/// FNV-1a hash. Unlike `DefaultHasher`, its output is fixed across Rust releases and platforms.
+fn stable_hash(s: &str) -> u64 {
+    s.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Index into `COLORBLIND_PALETTE` for a tag. Stable across runs and builds.
+pub fn palette_index(tag: &str) -> usize {
+    (stable_hash(tag) % COLORBLIND_PALETTE.len() as u64) as usize
+}
+
+/// Picks a color from `COLORBLIND_PALETTE`, darkened on light themes to keep contrast.
+pub fn palette_color(tag: &str, is_dark_theme: bool) -> (f32, f32, f32) {
+    let (r, g, b) = COLORBLIND_PALETTE[palette_index(tag)];
+    let scale = if is_dark_theme { 1.0 } else { 0.7 };
+    (
+        r as f32 / 255.0 * scale,
+        g as f32 / 255.0 * scale,
+        b as f32 / 255.0 * scale,
+    )
+}
+
 /// Generates a deterministic color tuple (r, g, b) in [0.0, 1.0] range specifically tailored for the terminal's theme.
 pub fn generate_tui_color(tag: &str, is_dark_theme: bool) -> (f32, f32, f32) {
     let mut hasher = std::collections::hash_map::DefaultHasher::new();
@@ -4,7 +4,7 @@
 use crate::context::AppContext;
 use crate::storage::LocalStorage;
 use anyhow::{Error, Result};
-use chrono;
+use chrono::{self, NaiveDate, Weekday};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt;
@@ -321,9 +321,11 @@ pub enum TaskAction {
     OpenLocations,
     OpenCoordinates,
     TogglePin,
+    ToggleWaiting,
     Focus,
     EditTree,
     CompleteTree,
+    SkipOccurrence,
 }
 
 impl TaskAction {
@@ -333,6 +335,7 @@ impl TaskAction {
         TaskAction::OpenLocations,   // Third - multiple coordinates (GPX)
         TaskAction::ToggleDetails,
         TaskAction::CompleteAndShift,
+        TaskAction::SkipOccurrence,
         TaskAction::ToggleTimer,
         TaskAction::StopTimer,
         TaskAction::AddSession,
@@ -343,6 +346,7 @@ impl TaskAction {
         TaskAction::Yank,
         TaskAction::CreateSubtask,
         TaskAction::TogglePin,
+        TaskAction::ToggleWaiting,
         TaskAction::DuplicateTree,
         TaskAction::Promote,
         TaskAction::Move,
@@ -375,9 +379,11 @@ impl TaskAction {
             TaskAction::OpenCoordinates => rust_i18n::t!("open_coordinates").to_string(),
             TaskAction::OpenUrl => rust_i18n::t!("open_url").to_string(),
             TaskAction::TogglePin => rust_i18n::t!("action_toggle_pin").to_string(),
+            TaskAction::ToggleWaiting => rust_i18n::t!("action_toggle_waiting").to_string(),
             TaskAction::Focus => rust_i18n::t!("focus_hide_others").to_string(),
             TaskAction::EditTree => "Edit tree".to_string(),
             TaskAction::CompleteTree => rust_i18n::t!("action_complete_tree").to_string(),
+            TaskAction::SkipOccurrence => rust_i18n::t!("action_skip_occurrence").to_string(),
         }
     }
 }
@@ -417,6 +423,220 @@ impl std::str::FromStr for SortPreset {
     }
 }
 
+/// Primary key for ordering the task list.
+/// - `Smart`: the rank-based urgency ordering (default, see `SortPreset`)
+/// - `DueDate`, `Priority`, `Created`, `Alphabetical`: sort by that key alone; pinned and
+///   urgent tasks only stay on top when `sort_urgency_boost` is on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, EnumIter)]
+pub enum SortMode {
+    #[default]
+    Smart,
+    DueDate,
+    Priority,
+    Created,
+    Alphabetical,
+}
+
+impl fmt::Display for SortMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SortMode::Smart => write!(f, "{}", rust_i18n::t!("sort_mode_smart")),
+            SortMode::DueDate => write!(f, "{}", rust_i18n::t!("sort_mode_due_date")),
+            SortMode::Priority => write!(f, "{}", rust_i18n::t!("sort_mode_priority")),
+            SortMode::Created => write!(f, "{}", rust_i18n::t!("sort_mode_created")),
+            SortMode::Alphabetical => write!(f, "{}", rust_i18n::t!("sort_mode_alphabetical")),
+        }
+    }
+}
+
+/// Where a date-only (all-day) due date sits within its day when tasks are sorted.
+/// - `EndOfDay`: 23:59:59, after timed tasks due the same day (default)
+/// - `StartOfDay`: 00:00, before timed tasks due the same day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, EnumIter)]
+pub enum AllDaySort {
+    #[default]
+    EndOfDay,
+    StartOfDay,
+}
+
+impl fmt::Display for AllDaySort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AllDaySort::EndOfDay => write!(f, "{}", rust_i18n::t!("all_day_sort_end_of_day")),
+            AllDaySort::StartOfDay => write!(f, "{}", rust_i18n::t!("all_day_sort_start_of_day")),
+        }
+    }
+}
+
+/// Whether the GUI task list is split into agenda sections (Overdue, Today, Tomorrow,
+/// This week, Later, No date) and which date places a task.
+/// - `Off`: one flat list (default)
+/// - `Due`: by due date
+/// - `Start`: by start date, falling back to the due date; started tasks that are not
+///   overdue count as today
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, EnumIter)]
+pub enum AgendaGrouping {
+    #[default]
+    Off,
+    Due,
+    Start,
+}
+
+impl fmt::Display for AgendaGrouping {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AgendaGrouping::Off => write!(f, "{}", rust_i18n::t!("agenda_grouping_off")),
+            AgendaGrouping::Due => write!(f, "{}", rust_i18n::t!("agenda_grouping_due")),
+            AgendaGrouping::Start => write!(f, "{}", rust_i18n::t!("agenda_grouping_start")),
+        }
+    }
+}
+
+/// What happens to a parent task when the status of one of its subtasks changes.
+/// - `Off`: nothing (default)
+/// - `Percent`: the parent's `percent_complete` follows the share of completed subtasks
+/// - `Complete`: as `Percent`, and the parent completes once every subtask is done
+///   (and reopens if one of them is reopened)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, EnumIter)]
+pub enum ParentProgress {
+    #[default]
+    Off,
+    Percent,
+    Complete,
+}
+
+impl fmt::Display for ParentProgress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParentProgress::Off => write!(f, "{}", rust_i18n::t!("parent_progress_off")),
+            ParentProgress::Percent => write!(f, "{}", rust_i18n::t!("parent_progress_percent")),
+            ParentProgress::Complete => {
+                write!(f, "{}", rust_i18n::t!("parent_progress_complete"))
+            }
+        }
+    }
+}
+
+/// How requests to the CalDAV server are authenticated.
+/// - `Basic`: username/password, answering Digest challenges (default)
+/// - `Bearer`: an OAuth2 access token (stored in the password slot), refreshed on 401
+///   through `oauth_token_url` when a refresh token is available
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, EnumIter)]
+pub enum AuthMode {
+    #[default]
+    Basic,
+    Bearer,
+}
+
+/// How a sync edit conflict (`412 Precondition Failed`) is resolved.
+/// - `Merge`: three-way merge, falling back to a "(Conflict Copy)" (default)
+/// - `PreferLocal` / `PreferServer`: three-way merge, falling back to that side's version
+/// - `CreateCopy`: always keep both, as a "(Conflict Copy)"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, EnumIter)]
+pub enum ConflictStrategy {
+    #[default]
+    Merge,
+    PreferLocal,
+    PreferServer,
+    CreateCopy,
+}
+
+/// How dates are shown in task rows and details (the smart-string stays ISO).
+/// - `Iso`: `2025-12-31` (default)
+/// - `Us`: `12/31/2025`
+/// - `Eu`: `31/12/2025`
+/// - `IsoWeek`: `2025-W01-3` (ISO year, week and weekday)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, EnumIter)]
+pub enum DateFormat {
+    #[default]
+    Iso,
+    Us,
+    Eu,
+    IsoWeek,
+}
+
+impl fmt::Display for DateFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateFormat::Iso => write!(f, "YYYY-MM-DD"),
+            DateFormat::Us => write!(f, "MM/DD/YYYY"),
+            DateFormat::Eu => write!(f, "DD/MM/YYYY"),
+            DateFormat::IsoWeek => write!(f, "YYYY-Www-D"),
+        }
+    }
+}
+
+/// Clock used when a displayed date carries a time.
+/// - `H24`: `14:30` (default)
+/// - `H12`: `2:30 PM`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, EnumIter)]
+pub enum TimeFormat {
+    #[default]
+    H24,
+    H12,
+}
+
+impl fmt::Display for TimeFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimeFormat::H24 => write!(f, "{}", rust_i18n::t!("time_format_24h")),
+            TimeFormat::H12 => write!(f, "{}", rust_i18n::t!("time_format_12h")),
+        }
+    }
+}
+
+/// How tag and location chips are colored.
+/// - `Hashed`: a hue derived from the tag name (default)
+/// - `ColorblindSafe`: a fixed high-contrast palette (Okabe-Ito), indexed by a stable hash
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, EnumIter)]
+pub enum TagColorMode {
+    #[default]
+    Hashed,
+    ColorblindSafe,
+}
+
+impl fmt::Display for TagColorMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TagColorMode::Hashed => write!(f, "{}", rust_i18n::t!("tag_color_mode_hashed")),
+            TagColorMode::ColorblindSafe => {
+                write!(f, "{}", rust_i18n::t!("tag_color_mode_colorblind"))
+            }
+        }
+    }
+}
+
+/// A user-defined quick-add button: `smart` is dropped into the input as-is.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuickTemplate {
+    pub label: String,
+    pub smart: String,
+}
+
+/// A device-local display name and/or color for one calendar.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CalendarOverride {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+}
+
+/// Maximum number of entries kept in `Config::search_history`.
+pub const SEARCH_HISTORY_LIMIT: usize = 20;
+
+/// Moves `term` to the front of a recent-search list, dropping an earlier copy of it and
+/// anything past `SEARCH_HISTORY_LIMIT`. Blank terms are ignored.
+pub fn push_search_history(history: &mut Vec<String>, term: &str) {
+    let term = term.trim();
+    if term.is_empty() {
+        return;
+    }
+    history.retain(|h| h != term);
+    history.insert(0, term.to_string());
+    history.truncate(SEARCH_HISTORY_LIMIT);
+}
+
 fn default_pinned_actions() -> Vec<TaskAction> {
     vec![
         TaskAction::OpenUrl, // First action - open URL
@@ -464,6 +684,10 @@ pub fn init_locale(ctx: &dyn crate::context::AppContext) {
     }
 }
 
+fn default_week_start() -> Weekday {
+    Weekday::Mon
+}
+
 fn default_urgent_days() -> u32 {
     1
 }
@@ -486,9 +710,35 @@ fn default_priority() -> u8 {
 fn default_auto_remind() -> bool {
     true
 }
+fn default_unsynced_warning_hours() -> u32 {
+    24
+}
+fn default_map_url_template() -> String {
+    "https://www.openstreetmap.org/?mlat={lat}&mlon={lon}#map=16/{lat}/{lon}".to_string()
+}
 fn default_remind_time() -> String {
     "08:00".to_string()
 }
+/// Snooze durations (minutes) for the alarm modal: the configured presets, or the short/long
+/// pair when none are set. Never empty: zero values are skipped and the defaults fill in.
+pub fn snooze_options(presets: &[u32], short: u32, long: u32) -> Vec<u32> {
+    let mut out: Vec<u32> = Vec::new();
+    let source = if presets.iter().any(|m| *m > 0) {
+        presets.to_vec()
+    } else {
+        vec![short, long]
+    };
+    for m in source {
+        if m > 0 && !out.contains(&m) {
+            out.push(m);
+        }
+    }
+    if out.is_empty() {
+        out = vec![default_snooze_1(), default_snooze_2()];
+    }
+    out
+}
+
 fn default_snooze_1() -> u32 {
     60
 }
@@ -544,6 +794,14 @@ fn default_window_height() -> f32 {
     768.0
 }
 
+fn default_custom_theme_primary() -> String {
+    "#FFA500".to_string()
+}
+
+fn default_custom_theme_background() -> String {
+    "#211E1E".to_string()
+}
+
 fn default_log_level() -> LogLevel {
     LogLevel::Warn
 }
@@ -575,10 +833,18 @@ pub enum AppTheme {
     Nightfly,
     Oxocarbon,
     Ferra,
+    /// Built from `custom_theme_primary` / `custom_theme_background`.
+    Custom,
 }
 
 impl AppTheme {
-    pub fn is_dark(&self) -> bool {
+    /// `custom_background` is the `custom_theme_background` hex color; the custom theme
+    /// counts as dark when that color is, and falls back to dark when it can't be parsed.
+    pub fn is_dark(&self, custom_background: &str) -> bool {
+        if *self == AppTheme::Custom {
+            return crate::color_utils::parse_hex_to_floats(custom_background)
+                .is_none_or(|(r, g, b)| crate::color_utils::is_dark(r, g, b));
+        }
         !matches!(
             self,
             AppTheme::Light
@@ -617,6 +883,7 @@ impl fmt::Display for AppTheme {
             AppTheme::Nightfly => write!(f, "Nightfly"),
             AppTheme::Oxocarbon => write!(f, "Oxocarbon"),
             AppTheme::Ferra => write!(f, "Ferra"),
+            AppTheme::Custom => write!(f, "Custom"),
         }
     }
 }
@@ -638,6 +905,17 @@ pub struct Config {
     #[serde(skip_serializing, default)]
     pub password: String,
 
+    #[serde(default)]
+    pub auth_mode: AuthMode,
+    /// OAuth2 token endpoint used to refresh the bearer token after a 401.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oauth_token_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oauth_client_id: Option<String>,
+    // Vaulted in the OS keyring like `password`; only read from disk for migration.
+    #[serde(skip_serializing, default)]
+    pub oauth_refresh_token: String,
+
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tls_client_cert_path: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -645,6 +923,9 @@ pub struct Config {
 
     #[serde(default)]
     pub allow_insecure_certs: bool,
+    /// Skip downloading tasks completed (or due) before the `sort_cutoff_days` window.
+    #[serde(default)]
+    pub limit_fetch_to_cutoff: bool,
     #[serde(default)]
     pub disabled_calendars: Vec<String>,
 
@@ -653,8 +934,27 @@ pub struct Config {
     pub enable_local_mode: bool,
     #[serde(default)]
     pub hide_completed: bool,
+    /// Hide tasks whose start date is still in the future (revealed by `is:deferred`).
+    #[serde(default)]
+    pub hide_deferred: bool,
     #[serde(default)]
     pub strikethrough_completed: bool,
+    /// Ask before deleting tasks or applying a bulk edit to the visible tasks.
+    #[serde(default)]
+    pub confirm_delete: bool,
+    /// Derive a parent's progress (or completion) from its subtasks. See `ParentProgress`.
+    #[serde(default)]
+    pub parent_progress: ParentProgress,
+    /// GUI: show how the typed smart input parses (dates, tags, reminders...) under the input.
+    #[serde(default)]
+    pub show_parse_preview: bool,
+    /// GUI: closing the window hides it to the system tray and keeps alarms running.
+    #[serde(default)]
+    pub minimize_to_tray: bool,
+    /// GUI: system-wide shortcut that brings the window up with the input focused
+    /// (e.g. `ctrl+alt+space`). `None` disables it.
+    #[serde(default)]
+    pub quick_add_hotkey: Option<String>,
     #[serde(default = "default_true")]
     pub hide_fully_completed_tags: bool,
     #[serde(default = "default_true")]
@@ -673,8 +973,30 @@ pub struct Config {
     /// See `SortPreset` enum for available options.
     #[serde(default)]
     pub sort_preset: SortPreset,
+    /// Primary sort key for the task list. See `SortMode`.
+    #[serde(default)]
+    pub sort_mode: SortMode,
+    /// Keep pinned and urgent tasks above the rest when `sort_mode` is not `Smart`.
+    #[serde(default)]
+    pub sort_urgency_boost: bool,
+    /// Where all-day due dates sort relative to timed ones on the same day. See `AllDaySort`.
+    #[serde(default)]
+    pub all_day_sort: AllDaySort,
+    /// GUI: group the task list into agenda sections. See `AgendaGrouping`.
+    #[serde(default)]
+    pub agenda_grouping: AgendaGrouping,
     #[serde(default)]
     pub theme: AppTheme,
+    #[serde(default = "default_custom_theme_primary")]
+    pub custom_theme_primary: String,
+    #[serde(default = "default_custom_theme_background")]
+    pub custom_theme_background: String,
+    #[serde(default)]
+    pub tag_color_mode: TagColorMode,
+    #[serde(default)]
+    pub date_format: DateFormat,
+    #[serde(default)]
+    pub time_format: TimeFormat,
 
     // Optional language/locale selection. None = use system default.
     #[serde(default)]
@@ -688,16 +1010,34 @@ pub struct Config {
     pub default_priority: u8,
     #[serde(default = "default_start_grace_period")]
     pub start_grace_period_days: u32,
+    /// First day of the week for `eow`, `is:week` and other week-based logic.
+    #[serde(default = "default_week_start")]
+    pub week_start: Weekday,
+    /// Dates skipped by `in N business days`, on top of weekends.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub holidays: Vec<NaiveDate>,
 
     #[serde(default = "default_auto_remind")]
     pub auto_reminders: bool,
     #[serde(default = "default_remind_time")]
     pub default_reminder_time: String, // Format "HH:MM"
+    /// Lead time (minutes before due) of the reminder auto-attached to tasks with a timed
+    /// due date and no explicit `rem:`. `None` disables it.
+    #[serde(default)]
+    pub default_reminder_minutes: Option<u32>,
 
     #[serde(default = "default_snooze_1")]
     pub snooze_short_mins: u32,
     #[serde(default = "default_snooze_2")]
     pub snooze_long_mins: u32,
+    /// Snooze buttons of the alarm modal, in minutes and in order (e.g. `[5, 15, 60, 1440]`).
+    /// Empty uses `snooze_short_mins` and `snooze_long_mins`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub snooze_presets: Vec<u32>,
+    /// Link opened for a task's coordinates on desktop. `{lat}` and `{lon}` are substituted;
+    /// leave empty to hand a raw `geo:` URI to the system instead.
+    #[serde(default = "default_map_url_template")]
+    pub map_url_template: String,
 
     #[serde(default = "default_create_events")]
     pub create_events_for_tasks: bool,
@@ -713,12 +1053,29 @@ pub struct Config {
     #[serde(default = "default_trash_retention")]
     pub trash_retention_days: u32, // Integer: Days to keep items in local trash before permanent delete. 0 to disable trash.
 
+    /// Remote tasks still unsynced this many hours after creation are listed as
+    /// "failed to sync" (retry or discard). 0 disables the check.
+    #[serde(default = "default_unsynced_warning_hours")]
+    pub unsynced_warning_hours: u32,
+
+    #[serde(default)]
+    pub conflict_strategy: ConflictStrategy,
+
+    /// Match plain search words as subsequences of summaries and rank by score.
+    #[serde(default)]
+    pub fuzzy_search: bool,
+
     #[serde(default = "default_duration_goal_mins")]
     pub default_duration_goal_mins: u32,
 
     #[serde(default)]
     pub sessions_count_as_completions: bool,
 
+    /// Keep tasks completed more than `sort_cutoff_days` ago out of the main list
+    /// (still reachable with `is:archived`).
+    #[serde(default)]
+    pub archive_completed: bool,
+
     #[serde(default = "default_max_done_roots")]
     pub max_done_roots: usize,
     #[serde(default = "default_max_done_subtasks")]
@@ -728,6 +1085,9 @@ pub struct Config {
     pub show_ongoing_notifications: bool,
     #[serde(default = "default_true")]
     pub show_priority_numbers: bool,
+    /// Denser task list and sidebar rows (smaller fonts, less padding).
+    #[serde(default)]
+    pub compact_layout: bool,
 
     #[serde(default = "default_pinned_actions")]
     pub pinned_actions: Vec<TaskAction>,
@@ -738,6 +1098,9 @@ pub struct Config {
     pub quick_filter_icon: String,
     #[serde(default = "default_true")]
     pub show_quick_filter: bool,
+    /// Recent GUI searches, most recent first (see `push_search_history`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub search_history: Vec<String>,
 
     #[serde(default = "default_true")]
     pub show_goals_tab: bool,
@@ -762,8 +1125,31 @@ pub struct Config {
     pub collection_order: Vec<String>,
     #[serde(default)]
     pub tag_aliases: HashMap<String, Vec<String>>,
+    /// Aliases taken out of `tag_aliases` on load because they form a cycle.
+    /// They are never expanded, but are written back on save so no user data is lost.
+    #[serde(skip)]
+    pub disabled_aliases: HashMap<String, Vec<String>>,
+    /// Problems found while validating `tag_aliases` on load, for display in settings.
+    #[serde(skip)]
+    pub alias_problems: Vec<String>,
     #[serde(default)]
     pub goals: HashMap<String, Goal>,
+    /// Quick-add templates shown as buttons above the GUI input.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub quick_templates: Vec<QuickTemplate>,
+    /// Per-calendar overrides for `default_reminder_minutes` (calendar href -> minutes).
+    /// A value of 0 disables the default reminder for that calendar.
+    #[serde(default)]
+    pub calendar_reminder_minutes: HashMap<String, u32>,
+    /// Device-local display name and color per calendar href, shown instead of the server's
+    /// `displayname` / `calendar-color`. Also holds the fallback color for servers that refuse
+    /// to store `calendar-color`.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub calendar_overrides: HashMap<String, CalendarOverride>,
+    /// TUI: action name -> keys (e.g. `next = "n down"`). Actions left out keep their
+    /// default keys; see `tui::keymap::KeyAction` for the names.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub keybindings: HashMap<String, String>,
 
     // UI State
     #[serde(default)]
@@ -785,7 +1171,7 @@ pub struct Config {
     pub sort_collections_by_size: bool,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct SyncableConfig {
     #[serde(default)]
     pub default_calendar: Option<String>,
@@ -800,6 +1186,8 @@ pub struct SyncableConfig {
     #[serde(default)]
     pub hide_completed: bool,
     #[serde(default)]
+    pub hide_deferred: bool,
+    #[serde(default)]
     pub hide_fully_completed_tags: bool,
     #[serde(default)]
     pub hide_aliases_in_sidebar: bool,
@@ -819,14 +1207,24 @@ pub struct SyncableConfig {
     pub default_priority: u8,
     #[serde(default = "default_start_grace_period")]
     pub start_grace_period_days: u32,
+    #[serde(default = "default_week_start")]
+    pub week_start: Weekday,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub holidays: Vec<NaiveDate>,
     #[serde(default = "default_auto_remind")]
     pub auto_reminders: bool,
     #[serde(default = "default_remind_time")]
     pub default_reminder_time: String,
+    #[serde(default)]
+    pub default_reminder_minutes: Option<u32>,
+    #[serde(default)]
+    pub calendar_reminder_minutes: HashMap<String, u32>,
     #[serde(default = "default_snooze_1")]
     pub snooze_short_mins: u32,
     #[serde(default = "default_snooze_2")]
     pub snooze_long_mins: u32,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub snooze_presets: Vec<u32>,
     #[serde(default = "default_create_events")]
     pub create_events_for_tasks: bool,
     #[serde(default = "default_delete_events_on_completion")]
@@ -861,6 +1259,12 @@ pub struct SyncableConfig {
     pub sort_collections_by_size: bool,
 }
 
+impl Default for SyncableConfig {
+    fn default() -> Self {
+        Config::default().get_syncable()
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct SettingsPayload {
     #[serde(default)]
@@ -876,15 +1280,21 @@ impl Default for Config {
             url: String::new(),
             username: String::new(),
             password: String::new(),
+            auth_mode: AuthMode::Basic,
+            oauth_token_url: None,
+            oauth_client_id: None,
+            oauth_refresh_token: String::new(),
             tls_client_cert_path: None,
             tls_client_key_path: None,
             default_calendar: None,
             enable_local_mode: true,
             allow_insecure_certs: false,
+            limit_fetch_to_cutoff: false,
             hidden_calendars: Vec::new(),
             collection_order: Vec::new(),
             disabled_calendars: Vec::new(),
             hide_completed: false,
+            hide_deferred: false,
             hide_fully_completed_tags: true,
             hide_aliases_in_sidebar: true,
             show_inline_descriptions: true,
@@ -892,32 +1302,63 @@ impl Default for Config {
             sort_cutoff_days: Some(30),
             sort_standard_by_priority: false,
             sort_preset: SortPreset::default(),
+            sort_mode: SortMode::default(),
+            sort_urgency_boost: false,
+            all_day_sort: AllDaySort::default(),
+            agenda_grouping: AgendaGrouping::default(),
             tag_aliases: HashMap::new(),
+            disabled_aliases: HashMap::new(),
+            alias_problems: Vec::new(),
+            quick_templates: Vec::new(),
             language: None,
             theme: AppTheme::default(),
+            custom_theme_primary: default_custom_theme_primary(),
+            custom_theme_background: default_custom_theme_background(),
+            tag_color_mode: TagColorMode::default(),
+            date_format: DateFormat::default(),
+            time_format: TimeFormat::default(),
             urgent_days_horizon: 1,
             urgent_priority_threshold: 1,
             default_priority: 5,
             start_grace_period_days: 1,
+            week_start: Weekday::Mon,
+            holidays: Vec::new(),
             auto_reminders: true,
             default_reminder_time: "08:00".to_string(),
+            default_reminder_minutes: None,
+            calendar_reminder_minutes: HashMap::new(),
+            calendar_overrides: HashMap::new(),
+            keybindings: HashMap::new(),
             snooze_short_mins: 60,
             snooze_long_mins: 1440,
+            snooze_presets: Vec::new(),
+            map_url_template: default_map_url_template(),
             create_events_for_tasks: false,
             delete_events_on_completion: false,
             auto_refresh_interval_mins: 30,
             trash_retention_days: 14,
+            unsynced_warning_hours: 24,
+            conflict_strategy: ConflictStrategy::Merge,
+            fuzzy_search: false,
             default_duration_goal_mins: 60,
             sessions_count_as_completions: false,
+            archive_completed: false,
             strikethrough_completed: false,
+            confirm_delete: false,
+            parent_progress: ParentProgress::default(),
+            show_parse_preview: false,
+            minimize_to_tray: false,
+            quick_add_hotkey: None,
             max_done_roots: 20,
             max_done_subtasks: 5,
             show_ongoing_notifications: true,
             show_priority_numbers: true,
+            compact_layout: false,
             pinned_actions: default_pinned_actions(),
             quick_filter_term: default_quick_filter_term(),
             quick_filter_icon: default_quick_filter_icon(),
             show_quick_filter: true,
+            search_history: Vec::new(),
             show_goals_tab: true,
             show_task_goals_in_sidebar: true,
             sidebar_is_hidden: false,
@@ -936,14 +1377,68 @@ impl Default for Config {
 }
 
 impl Config {
+    /// Effective default reminder lead time for a calendar, honoring per-calendar overrides.
+    pub fn default_reminder_minutes_for(&self, calendar_href: &str) -> Option<u32> {
+        match self.calendar_reminder_minutes.get(calendar_href) {
+            Some(0) => None,
+            Some(&mins) => Some(mins),
+            None => self.default_reminder_minutes,
+        }
+    }
+
+    /// Overlays `calendar_overrides` onto a calendar list. Fields without an override keep
+    /// the server (or local registry) value.
+    pub fn apply_calendar_overrides(&self, cals: &mut [crate::model::CalendarListEntry]) {
+        for cal in cals {
+            if let Some(ov) = self.calendar_overrides.get(&cal.href) {
+                if let Some(name) = &ov.name {
+                    cal.name = name.clone();
+                }
+                if let Some(color) = &ov.color {
+                    cal.color = Some(color.clone());
+                }
+            }
+        }
+    }
+
+    /// Sets or clears one field of a calendar's override; `None` or a blank value clears it.
+    /// Entries left without any field are removed. Returns whether anything changed.
+    pub fn set_calendar_override(
+        &mut self,
+        href: &str,
+        name: Option<Option<String>>,
+        color: Option<Option<String>>,
+    ) -> bool {
+        let clean = |v: Option<String>| v.filter(|s| !s.trim().is_empty());
+        let mut ov = self
+            .calendar_overrides
+            .get(href)
+            .cloned()
+            .unwrap_or_default();
+        if let Some(name) = name {
+            ov.name = clean(name);
+        }
+        if let Some(color) = color {
+            ov.color = clean(color);
+        }
+        let before = self.calendar_overrides.get(href).cloned();
+        if ov.name.is_none() && ov.color.is_none() {
+            self.calendar_overrides.remove(href);
+        } else {
+            self.calendar_overrides.insert(href.to_string(), ov);
+        }
+        before != self.calendar_overrides.get(href).cloned()
+    }
+
     pub fn get_syncable(&self) -> SyncableConfig {
         SyncableConfig {
             default_calendar: self.default_calendar.clone(),
             disabled_calendars: self.disabled_calendars.clone(),
             collection_order: self.collection_order.clone(),
-            tag_aliases: self.tag_aliases.clone(),
+            tag_aliases: self.all_tag_aliases(),
             goals: self.goals.clone(),
             hide_completed: self.hide_completed,
+            hide_deferred: self.hide_deferred,
             hide_fully_completed_tags: self.hide_fully_completed_tags,
             hide_aliases_in_sidebar: self.hide_aliases_in_sidebar,
             show_inline_descriptions: self.show_inline_descriptions,
@@ -954,10 +1449,15 @@ impl Config {
             urgent_priority_threshold: self.urgent_priority_threshold,
             default_priority: self.default_priority,
             start_grace_period_days: self.start_grace_period_days,
+            week_start: self.week_start,
+            holidays: self.holidays.clone(),
             auto_reminders: self.auto_reminders,
             default_reminder_time: self.default_reminder_time.clone(),
+            default_reminder_minutes: self.default_reminder_minutes,
+            calendar_reminder_minutes: self.calendar_reminder_minutes.clone(),
             snooze_short_mins: self.snooze_short_mins,
             snooze_long_mins: self.snooze_long_mins,
+            snooze_presets: self.snooze_presets.clone(),
             create_events_for_tasks: self.create_events_for_tasks,
             delete_events_on_completion: self.delete_events_on_completion,
             trash_retention_days: self.trash_retention_days,
@@ -986,8 +1486,11 @@ impl Config {
         self.disabled_calendars = sync.disabled_calendars;
         self.collection_order = sync.collection_order;
         self.tag_aliases = sync.tag_aliases;
+        self.disabled_aliases.clear();
+        self.quarantine_alias_cycles();
         self.goals = sync.goals;
         self.hide_completed = sync.hide_completed;
+        self.hide_deferred = sync.hide_deferred;
         self.hide_fully_completed_tags = sync.hide_fully_completed_tags;
         self.hide_aliases_in_sidebar = sync.hide_aliases_in_sidebar;
         self.show_inline_descriptions = sync.show_inline_descriptions;
@@ -998,10 +1501,16 @@ impl Config {
         self.urgent_priority_threshold = sync.urgent_priority_threshold;
         self.default_priority = sync.default_priority;
         self.start_grace_period_days = sync.start_grace_period_days;
+        self.week_start = sync.week_start;
+        self.holidays = sync.holidays;
         self.auto_reminders = sync.auto_reminders;
         self.default_reminder_time = sync.default_reminder_time;
+        self.default_reminder_minutes = sync.default_reminder_minutes;
+        self.calendar_reminder_minutes = sync.calendar_reminder_minutes;
         self.snooze_short_mins = sync.snooze_short_mins;
         self.snooze_long_mins = sync.snooze_long_mins;
+        self.snooze_presets = sync.snooze_presets;
+        self.sanitize_snooze_presets();
         self.create_events_for_tasks = sync.create_events_for_tasks;
         self.delete_events_on_completion = sync.delete_events_on_completion;
         self.trash_retention_days = sync.trash_retention_days;
@@ -1060,9 +1569,60 @@ impl Config {
             config.config_version = 1;
         }
 
+        config.quarantine_alias_cycles();
+        config.sanitize_snooze_presets();
+
         Ok(config)
     }
 
+    /// Drops zero and repeated entries from `snooze_presets`, keeping the user's order.
+    pub fn sanitize_snooze_presets(&mut self) {
+        let before = self.snooze_presets.len();
+        let mut seen = std::collections::HashSet::new();
+        self.snooze_presets.retain(|m| *m > 0 && seen.insert(*m));
+        if self.snooze_presets.len() != before {
+            log::warn!(
+                "Ignoring {} invalid snooze preset(s)",
+                before - self.snooze_presets.len()
+            );
+        }
+    }
+
+    /// The snooze durations offered when an alarm rings (see `snooze_options`).
+    pub fn snooze_options(&self) -> Vec<u32> {
+        snooze_options(
+            &self.snooze_presets,
+            self.snooze_short_mins,
+            self.snooze_long_mins,
+        )
+    }
+
+    /// Validates the whole alias map and moves every alias that is part of a cycle
+    /// into `disabled_aliases`, recording the problems in `alias_problems`.
+    /// Without this, a cycle written directly to `config.toml` would only be stopped
+    /// by the expander's visited-set and expand partially.
+    pub fn quarantine_alias_cycles(&mut self) {
+        self.alias_problems.clear();
+        let problems = crate::model::parser::validate_all_aliases(&self.tag_aliases);
+        for (key, problem) in problems {
+            log::warn!("Disabling alias '{}': {}", key, problem);
+            if let Some(values) = self.tag_aliases.remove(&key) {
+                self.disabled_aliases.insert(key, values);
+            }
+            self.alias_problems.push(problem);
+        }
+    }
+
+    /// Active aliases merged with the quarantined ones, as they should be persisted.
+    /// An active alias takes precedence if the user redefined a disabled key.
+    pub fn all_tag_aliases(&self) -> HashMap<String, Vec<String>> {
+        let mut all = self.tag_aliases.clone();
+        for (key, values) in &self.disabled_aliases {
+            all.entry(key.clone()).or_insert_with(|| values.clone());
+        }
+        all
+    }
+
     /// Load the configuration from disk and fetch the password from the OS keyring.
     /// Use this ONLY during app startup, explicit syncing, or opening the settings panel.
     pub fn load_with_credentials(ctx: &dyn AppContext) -> Result<Self> {
@@ -1112,9 +1672,66 @@ impl Config {
             }
         }
 
+        match keyring_core::Entry::new("cfait", &Self::refresh_token_key(user_key)) {
+            Ok(entry) => {
+                if !config.oauth_refresh_token.is_empty() {
+                    // Migration: plaintext refresh token found in config.toml.
+                    if let Err(err) = entry.set_password(&config.oauth_refresh_token) {
+                        log::warn!(
+                            "Failed to migrate OAuth refresh token into keyring for user '{}': {}",
+                            user_key,
+                            err
+                        );
+                    }
+                } else if let Ok(token) = entry.get_password() {
+                    config.oauth_refresh_token = token;
+                }
+            }
+            Err(err) => {
+                log::warn!(
+                    "Failed to initialize OAuth keyring entry for user '{}': {}",
+                    user_key,
+                    err
+                );
+            }
+        }
+
         Ok(config)
     }
 
+    fn refresh_token_key(user_key: &str) -> String {
+        format!("{}#oauth-refresh", user_key)
+    }
+
+    /// Stores tokens returned by an OAuth2 refresh in the OS keyring, without touching
+    /// `config.toml` (which other parts of the app may be writing concurrently).
+    pub fn save_oauth_tokens(username: &str, access_token: &str, refresh_token: Option<&str>) {
+        let user_key = if username.is_empty() {
+            "default"
+        } else {
+            username
+        };
+        if let Ok(entry) = keyring_core::Entry::new("cfait", user_key)
+            && let Err(err) = entry.set_password(access_token)
+        {
+            log::warn!(
+                "Failed to save refreshed access token for user '{}': {}",
+                user_key,
+                err
+            );
+        }
+        if let Some(refresh) = refresh_token
+            && let Ok(entry) = keyring_core::Entry::new("cfait", &Self::refresh_token_key(user_key))
+            && let Err(err) = entry.set_password(refresh)
+        {
+            log::warn!(
+                "Failed to save refreshed OAuth token for user '{}': {}",
+                user_key,
+                err
+            );
+        }
+    }
+
     pub fn is_missing_config_error(err: &Error) -> bool {
         if err.to_string().contains("Config file not found") {
             return true;
@@ -1139,8 +1756,21 @@ impl Config {
     pub fn save(&self, ctx: &dyn AppContext) -> Result<()> {
         let path = ctx.get_config_file_path()?;
 
+        // A different server invalidates the cached principal/home-set discovery.
+        if let Ok(previous) = Self::load(ctx)
+            && previous.url != self.url
+        {
+            let _ = crate::cache::Cache::clear_discovery(ctx);
+        }
+
         LocalStorage::with_lock(&path, || {
-            let toml_str = toml::to_string_pretty(self)?;
+            let toml_str = if self.disabled_aliases.is_empty() {
+                toml::to_string_pretty(self)?
+            } else {
+                let mut persisted = self.clone();
+                persisted.tag_aliases = self.all_tag_aliases();
+                toml::to_string_pretty(&persisted)?
+            };
             let documented_toml = Self::inject_documentation(&toml_str);
             LocalStorage::atomic_write(&path, documented_toml)?;
             Ok(())
@@ -1214,6 +1844,14 @@ impl Config {
                 out.push_str("\n# --- UI & Behavior ---\n");
             } else if trimmed.starts_with("sort_cutoff_days =") {
                 out.push_str("\n# --- Sorting & Ranking Logic ---\n");
+            } else if trimmed.starts_with("week_start =") {
+                out.push_str(line);
+                out.push_str(
+                    " # String (Mon..Sun): First day of the week for 'eow' and 'is:week'.",
+                );
+            } else if trimmed.starts_with("holidays =") {
+                out.push_str(line);
+                out.push_str(" # List of dates (YYYY-MM-DD): Skipped by 'in N business days'.");
             } else if trimmed.starts_with("auto_reminders =") {
                 out.push_str("\n# --- Notifications & Reminders ---\n");
             } else if trimmed.starts_with("create_events_for_tasks =") {
@@ -1231,6 +1869,25 @@ impl Config {
                 out.push_str("#          goal_type = \"count\" # or \"duration\"\n");
                 out.push_str("#          target = 5\n");
                 out.push_str("#          period = \"weekly\" # daily, weekly, monthly, yearly\n");
+            } else if trimmed.starts_with("[calendar_reminder_minutes]") {
+                out.push_str("\n# --- Per-Collection Default Reminders ---\n");
+                out.push_str(
+                    "# Override default_reminder_minutes per collection href. 0 disables.\n",
+                );
+                out.push_str("# Example: \"local://default\" = 15\n");
+            } else if trimmed.starts_with("[calendar_overrides]") {
+                out.push_str("\n# --- Local Collection Names & Colors ---\n");
+                out.push_str("# Shown instead of the server's name/color on this device only.\n");
+                out.push_str("# Example: [calendar_overrides.\"/dav/calendars/user/work/\"]\n");
+                out.push_str("#          name = \"Work\"\n");
+                out.push_str("#          color = \"#FF8800\"\n");
+            } else if trimmed.starts_with("[keybindings]") {
+                out.push_str("\n# --- TUI Keybindings ---\n");
+                out.push_str(
+                    "# Space-separated keys per action; unlisted actions keep their defaults.\n",
+                );
+                out.push_str("# Actions: quit, next, previous, complete, edit, delete, refresh\n");
+                out.push_str("# Example: next = \"n down\"   delete = \"ctrl+x\"\n");
             }
 
             // -- Inline or Block Comments for specific keys --
@@ -1238,6 +1895,17 @@ impl Config {
             if trimmed.starts_with("url =") {
                 out.push_str("# URL: The full address to your CalDAV server endpoint.\n");
                 out.push_str(line);
+            } else if trimmed.starts_with("auth_mode =") {
+                out.push_str(line);
+                out.push_str(" # \"Basic\" (username/password) or \"Bearer\" (OAuth2 access token as the password).");
+            } else if trimmed.starts_with("oauth_token_url =") {
+                out.push_str(line);
+                out.push_str(" # String (Optional): OAuth2 token endpoint used to refresh an expired bearer token.");
+            } else if trimmed.starts_with("oauth_client_id =") {
+                out.push_str(line);
+                out.push_str(
+                    " # String (Optional): OAuth2 client ID sent with the refresh request.",
+                );
             } else if trimmed.starts_with("tls_client_cert_path =") {
                 out.push_str(line);
                 out.push_str(
@@ -1251,6 +1919,11 @@ impl Config {
                 out.push_str(
                     " # Boolean: Set true to bypass SSL verification (e.g. self-signed certs).",
                 );
+            } else if trimmed.starts_with("limit_fetch_to_cutoff =") {
+                out.push_str(line);
+                out.push_str(
+                    " # Boolean: Only download tasks open or dated within sort_cutoff_days (faster load for long histories).",
+                );
             } else if trimmed.starts_with("sync_settings =") {
                 out.push_str(line);
                 out.push_str(
@@ -1272,9 +1945,31 @@ impl Config {
             } else if trimmed.starts_with("hide_completed =") {
                 out.push_str(line);
                 out.push_str(" # Boolean: If true, Completed/Cancelled tasks are hidden globally.");
+            } else if trimmed.starts_with("hide_deferred =") {
+                out.push_str(line);
+                out.push_str(" # Boolean: Hide tasks that start in the future until their start date ('is:deferred' shows them).");
             } else if trimmed.starts_with("strikethrough_completed =") {
                 out.push_str(line);
                 out.push_str(" # Boolean: Apply strikethrough styling to completed task titles.");
+            } else if trimmed.starts_with("minimize_to_tray =") {
+                out.push_str(line);
+                out.push_str(" # Boolean: GUI closes to the system tray so reminders keep firing (needs a build with the 'tray' feature).");
+            } else if trimmed.starts_with("quick_add_hotkey =") {
+                out.push_str(line);
+                out.push_str(" # String: Global shortcut to bring up the GUI for a quick add, e.g. 'ctrl+alt+space' (remove to disable; X11/Windows/macOS, needs the 'global-hotkey' feature).");
+            } else if trimmed.starts_with("show_parse_preview =") {
+                out.push_str(line);
+                out.push_str(" # Boolean: GUI shows the parsed fields of the typed smart input below the input box.");
+            } else if trimmed.starts_with("confirm_delete =") {
+                out.push_str(line);
+                out.push_str(
+                    " # Boolean: Ask before deleting tasks or bulk-editing the visible tasks.",
+                );
+            } else if trimmed.starts_with("parent_progress =") {
+                out.push_str(line);
+                out.push_str(
+                    " # Enum: Off (default), Percent or Complete. Parent progress follows completed subtasks; Complete also completes the parent.",
+                );
             } else if trimmed.starts_with("hide_fully_completed_tags =") {
                 out.push_str(line);
                 out.push_str(" # Boolean: Hide tags in sidebar if all their tasks are completed.");
@@ -1292,6 +1987,23 @@ impl Config {
             } else if trimmed.starts_with("theme =") {
                 out.push_str(line);
                 out.push_str(" # String: App Theme (RustyDark, Light, Dark, etc). In the TUI, light themes adapt text contrast for light terminal backgrounds.");
+            } else if trimmed.starts_with("custom_theme_primary =") {
+                out.push_str(line);
+                out.push_str(" # String: Hex accent color (#RRGGBB) used when theme = \"Custom\".");
+            } else if trimmed.starts_with("custom_theme_background =") {
+                out.push_str(line);
+                out.push_str(
+                    " # String: Hex background color (#RRGGBB) used when theme = \"Custom\".",
+                );
+            } else if trimmed.starts_with("tag_color_mode =") {
+                out.push_str(line);
+                out.push_str(" # String: Hashed (default) or ColorblindSafe (Okabe-Ito palette).");
+            } else if trimmed.starts_with("date_format =") {
+                out.push_str(line);
+                out.push_str(" # String: Iso (default), Us, Eu or IsoWeek. Display only.");
+            } else if trimmed.starts_with("time_format =") {
+                out.push_str(line);
+                out.push_str(" # String: H24 (default) or H12. Display only.");
             } else if trimmed.starts_with("sort_cutoff_days =") {
                 out.push_str(line);
                 out.push_str(" # Integer/None: Tasks due beyond this many days are ranked lower.");
@@ -1303,6 +2015,26 @@ impl Config {
             } else if trimmed.starts_with("sort_preset =") {
                 out.push_str(line);
                 out.push_str(" # Enum: Order of urgent buckets (UrgentStartedDue, UrgentDueStarted, StartedUrgentDue).");
+            } else if trimmed.starts_with("all_day_sort =") {
+                out.push_str(line);
+                out.push_str(
+                    " # Enum: EndOfDay (default) or StartOfDay. Where all-day due dates sort among timed ones.",
+                );
+            } else if trimmed.starts_with("agenda_grouping =") {
+                out.push_str(line);
+                out.push_str(
+                    " # Enum: Off (default), Due or Start. GUI splits the list into Overdue/Today/Tomorrow/This week/Later/No date sections.",
+                );
+            } else if trimmed.starts_with("sort_mode =") {
+                out.push_str(line);
+                out.push_str(
+                    " # Enum: Primary sort key (Smart, DueDate, Priority, Created, Alphabetical).",
+                );
+            } else if trimmed.starts_with("sort_urgency_boost =") {
+                out.push_str(line);
+                out.push_str(
+                    " # Boolean: Keep pinned/urgent tasks on top when sort_mode is not Smart.",
+                );
             } else if trimmed.starts_with("urgent_days_horizon =") {
                 out.push_str(line);
                 out.push_str(
@@ -1327,12 +2059,21 @@ impl Config {
             } else if trimmed.starts_with("default_reminder_time =") {
                 out.push_str(line);
                 out.push_str(" # String (HH:MM): Default time for date-only auto-reminders.");
+            } else if trimmed.starts_with("default_reminder_minutes =") {
+                out.push_str(line);
+                out.push_str(" # Integer: Auto-add a reminder this many minutes before a timed due date (remove to disable).");
             } else if trimmed.starts_with("snooze_short_mins =") {
                 out.push_str(line);
                 out.push_str(" # Integer: Minutes for the 'Short Snooze' button.");
             } else if trimmed.starts_with("snooze_long_mins =") {
                 out.push_str(line);
                 out.push_str(" # Integer: Minutes for the 'Long Snooze' button.");
+            } else if trimmed.starts_with("snooze_presets =") {
+                out.push_str(line);
+                out.push_str(" # List of integers: Minutes for each snooze button of the alarm popup, in order (e.g. [5, 15, 60, 1440]). Replaces the short/long pair in the GUI.");
+            } else if trimmed.starts_with("map_url_template =") {
+                out.push_str(line);
+                out.push_str(" # String: Map link for coordinates, with {lat} and {lon} placeholders (e.g. 'https://www.google.com/maps?q={lat},{lon}'). Empty opens a raw geo: URI.");
             } else if trimmed.starts_with("auto_refresh_interval_mins =") {
                 out.push_str(line);
                 out.push_str(" # Integer: Background sync interval in minutes. 0 to disable.");
@@ -1360,6 +2101,9 @@ impl Config {
             } else if trimmed.starts_with("quick_filter_term =") {
                 out.push_str(line);
                 out.push_str(" # String: The search term toggled by the quick filter button.");
+            } else if trimmed.starts_with("search_history =") {
+                out.push_str(line);
+                out.push_str(" # List of strings: Recent GUI searches, most recent first.");
             } else if trimmed.starts_with("quick_filter_icon =") {
                 out.push_str(line);
                 out.push_str(" # String: Hex code or character for the quick filter button icon.");
@@ -1381,6 +2125,9 @@ impl Config {
             } else if trimmed.starts_with("show_priority_numbers =") {
                 out.push_str(line);
                 out.push_str(" # Boolean: Render priority numbers (!X) visually next to tags.");
+            } else if trimmed.starts_with("compact_layout =") {
+                out.push_str(line);
+                out.push_str(" # Boolean: Dense GUI rows to fit more tasks on screen.");
             } else if trimmed.starts_with("hidden_calendars =") {
                 out.push_str("# List of calendar HREFs currently toggled 'off' in the sidebar.\n");
                 out.push_str(line);
@@ -1396,12 +2143,24 @@ impl Config {
             } else if trimmed.starts_with("trash_retention_days =") {
                 out.push_str(line);
                 out.push_str(" # Integer: Days to keep deleted items in local trash before permanent delete. 0 disables trash.");
+            } else if trimmed.starts_with("unsynced_warning_hours =") {
+                out.push_str(line);
+                out.push_str(" # Integer: Flag remote tasks still unsynced this many hours after creation. 0 disables.");
+            } else if trimmed.starts_with("fuzzy_search =") {
+                out.push_str(line);
+                out.push_str(" # Boolean: Fuzzy search (\"bmlk\" finds \"buy milk\"), ranked by match. Tags and other tokens stay exact.");
+            } else if trimmed.starts_with("conflict_strategy =") {
+                out.push_str(line);
+                out.push_str(" # \"Merge\", \"PreferLocal\", \"PreferServer\" or \"CreateCopy\": what to do when an edit conflicts with the server.");
             } else if trimmed.starts_with("default_duration_goal_mins =") {
                 out.push_str(line);
                 out.push_str(" # Integer: Implicit duration added to goals when completing a task without a timer.");
             } else if trimmed.starts_with("sessions_count_as_completions =") {
                 out.push_str(line);
                 out.push_str(" # Boolean: If true, logging a time session counts as a completion for 'Count' goals.");
+            } else if trimmed.starts_with("archive_completed =") {
+                out.push_str(line);
+                out.push_str(" # Boolean: Hide tasks completed more than sort_cutoff_days ago (search 'is:archived' to see them).");
             } else if trimmed.starts_with("log_level =") {
                 out.push_str(line);
                 out.push_str(" # String: Logging verbosity level (Error, Warn, Info, Debug, Trace). Applies to both log file and terminal.");
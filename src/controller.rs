@@ -6,7 +6,7 @@ use crate::config::Config;
 use crate::context::AppContext;
 use crate::journal::{Action, Journal};
 use crate::model::Task;
-use crate::storage::{LocalCalendarRegistry, LocalStorage};
+use crate::storage::{DuplicatePolicy, ImportSummary, LocalCalendarRegistry, LocalStorage};
 use crate::store::TaskStore;
 use chrono::{DateTime, Utc};
 use serde_json;
@@ -448,6 +448,8 @@ impl TaskController {
         {
             task.calendar_href = crate::storage::LOCAL_CALENDAR_HREF.to_string();
         }
+        let config = Config::load(self.ctx.as_ref()).unwrap_or_default();
+        task.apply_default_reminder(config.default_reminder_minutes_for(&task.calendar_href));
         if !task.calendar_href.starts_with("local://") {
             let cal_path = task.calendar_href.clone();
             let filename = format!("{}.ics", task.uid);
@@ -468,6 +470,26 @@ impl TaskController {
         Ok(task.uid)
     }
 
+    /// Bulk-restores tasks from an `.ics` file or JSON array into `target_href`.
+    /// See [`TaskStore::import_tasks`]; the resulting creates go through `persist_changes`,
+    /// so remote imports are journaled and sync normally.
+    pub async fn import_tasks(
+        &self,
+        data: &str,
+        target_href: &str,
+        policy: DuplicatePolicy,
+    ) -> Result<ImportSummary, String> {
+        let (actions, summary) = self
+            .store
+            .lock()
+            .await
+            .import_tasks(data, target_href, policy);
+        if !actions.is_empty() {
+            self.persist_changes(actions).await?;
+        }
+        Ok(summary)
+    }
+
     pub async fn update_task(&self, mut task: Task) -> Result<Vec<String>, String> {
         task.sequence += 1;
 
@@ -95,6 +95,14 @@ pub async fn async_create_remote_calendar_wrapper(
     client.create_calendar(&name, color.as_deref()).await
 }
 
+pub async fn async_set_calendar_color_wrapper(
+    client: RustyClient,
+    href: String,
+    color: Option<String>,
+) -> anyhow::Result<bool> {
+    client.set_calendar_color(&href, color.as_deref()).await
+}
+
 pub async fn async_update_remote_calendar_wrapper(
     client: RustyClient,
     href: String,
@@ -161,9 +169,12 @@ pub fn spawn_background_worker(
                 _ = sleep(Duration::from_millis(500)), if sync_pending => {
                     sync_pending = false;
                     match controller.sync_and_update_store().await {
-                        Ok((_warns, synced_tasks, config_changed)) => {
+                        Ok((warns, synced_tasks, config_changed)) => {
+                            // Without a client the only "warning" is the offline placeholder,
+                            // which the unsynced badge already conveys.
+                            let warns = if client_container.lock().await.is_some() { warns } else { Vec::new() };
                             // Always send the success message to allow the GUI to update the unsynced badge
-                            let _ = ui_tx.send(crate::gui::message::Message::BackgroundSyncComplete(synced_tasks)).await;
+                            let _ = ui_tx.send(crate::gui::message::Message::BackgroundSyncComplete(synced_tasks, warns)).await;
 
                             if config_changed {
                                 let ctx_ref = ctx.clone();
@@ -0,0 +1,118 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// File: ./src/gui/hotkey.rs
+/*
+System-wide quick-add shortcut (`quick_add_hotkey`).
+
+Pressing the shortcut anywhere brings the window to front and focuses the main input.
+Built with the `global-hotkey` cargo feature. Platform limits: Windows and macOS work
+out of the box, Linux only under X11 (Wayland compositors don't let applications grab
+keys, bind a desktop shortcut to `cfait-gui` there instead).
+*/
+
+use crate::gui::message::Message;
+use iced::Subscription;
+
+/// Registers `spec` (e.g. `ctrl+alt+space`) as the quick-add shortcut, replacing any
+/// previous one. `None` or an empty string only unregisters. On error the previous
+/// shortcut stays active. Must be called from the GUI thread.
+pub fn register(spec: Option<&str>) -> Result<(), String> {
+    imp::register(spec.map(str::trim).filter(|s| !s.is_empty()))
+}
+
+/// Shortcut presses as `Message::QuickAddHotkey`. Empty when nothing is registered.
+pub fn subscription() -> Subscription<Message> {
+    imp::subscription()
+}
+
+#[cfg(feature = "global-hotkey")]
+mod imp {
+    use crate::gui::message::Message;
+    use global_hotkey::hotkey::HotKey;
+    use global_hotkey::{GlobalHotKeyEvent, GlobalHotKeyManager, HotKeyState};
+    use iced::Subscription;
+    use iced::futures::SinkExt;
+    use iced::futures::channel::mpsc::Sender;
+    use std::cell::RefCell;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    // 0 = nothing registered
+    static ACTIVE_ID: AtomicU32 = AtomicU32::new(0);
+
+    thread_local! {
+        static MANAGER: RefCell<Option<(GlobalHotKeyManager, Option<HotKey>)>> =
+            const { RefCell::new(None) };
+    }
+
+    pub fn register(spec: Option<&str>) -> Result<(), String> {
+        let hotkey = spec
+            .map(|s| s.parse::<HotKey>().map_err(|e| e.to_string()))
+            .transpose()?;
+
+        MANAGER.with(|cell| {
+            let mut slot = cell.borrow_mut();
+            if slot.is_none() {
+                if hotkey.is_none() {
+                    return Ok(());
+                }
+                let manager = GlobalHotKeyManager::new().map_err(|e| e.to_string())?;
+                *slot = Some((manager, None));
+            }
+            let (manager, current) = slot.as_mut().expect("manager initialized above");
+
+            if hotkey.map(|h| h.id()) == current.map(|h| h.id()) {
+                return Ok(());
+            }
+            // The new key goes in before the old one is released, so a key that is taken
+            // leaves the working shortcut in place
+            if let Some(hotkey) = hotkey {
+                manager.register(hotkey).map_err(|e| e.to_string())?;
+            }
+            if let Some(old) = current.take() {
+                let _ = manager.unregister(old);
+            }
+            ACTIVE_ID.store(hotkey.map_or(0, |h| h.id()), Ordering::Relaxed);
+            *current = hotkey;
+            Ok(())
+        })
+    }
+
+    pub fn subscription() -> Subscription<Message> {
+        if ACTIVE_ID.load(Ordering::Relaxed) != 0 {
+            Subscription::run(hotkey_events)
+        } else {
+            Subscription::none()
+        }
+    }
+
+    fn hotkey_events() -> impl iced::futures::Stream<Item = Message> {
+        iced::stream::channel(16, |mut output: Sender<Message>| async move {
+            let receiver = GlobalHotKeyEvent::receiver();
+            while let Ok(Ok(event)) = tokio::task::spawn_blocking(move || receiver.recv()).await {
+                if event.state == HotKeyState::Pressed
+                    && event.id == ACTIVE_ID.load(Ordering::Relaxed)
+                {
+                    let _ = output.send(Message::QuickAddHotkey).await;
+                }
+            }
+
+            std::future::pending::<()>().await;
+        })
+    }
+}
+
+#[cfg(not(feature = "global-hotkey"))]
+mod imp {
+    use crate::gui::message::Message;
+    use iced::Subscription;
+
+    pub fn register(spec: Option<&str>) -> Result<(), String> {
+        match spec {
+            Some(_) => Err(rust_i18n::t!("quick_add_hotkey_unsupported").to_string()),
+            None => Ok(()),
+        }
+    }
+
+    pub fn subscription() -> Subscription<Message> {
+        Subscription::none()
+    }
+}
@@ -95,9 +95,11 @@ pub const CREATE_CHILD: char = '\u{f0014}'; // nf-md-account_plus
 pub const CLEAR_ALL: char = '\u{eabf}'; // nf-cod-clear_all
 pub const MAP_PIN: char = '\u{f276}'; // nf-fa-map_pin
 pub const MAP_MARKER_MULTIPLE: char = '\u{f1277}'; // nf-md-map_marker_multiple_outline
+pub const MAP_MARKER_OFF: char = '\u{f0647}'; // nf-md-map_marker_off
 pub const ELEVATOR_UP: char = '\u{f12c1}'; // nf-md-elevator_up
 pub const ELLIPSIS: char = '\u{f0d32}'; // nf-md-selection_ellipse
 pub const MOVE: char = '\u{ef0c}';
+pub const DRAG: char = '\u{f01dd}'; // nf-md-drag
 
 pub const SETTINGS_GEAR: char = '\u{e690}'; // nf-seti-settings
 pub const KEYBOARD: char = '\u{f0313}'; // nf-md-keyboard_variant
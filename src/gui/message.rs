@@ -6,7 +6,7 @@ Defines all messages used for the Elm architecture in the GUI.
 */
 
 use crate::client::RustyClient;
-use crate::config::{AppTheme, Config, LogLevel};
+use crate::config::{AppTheme, Config, DateFormat, LogLevel, TagColorMode, TimeFormat};
 use crate::gui::state::{ResizeDirection, SidebarMode};
 use crate::model::{CalendarListEntry, Task as TodoTask};
 use crate::system::{AlarmMessage, SystemEvent};
@@ -42,12 +42,16 @@ pub enum Message {
     OpenSettings,
     CancelSettings,
     ObSubmitOffline,
+    TestConnection,
+    ConnectionDiagnosed(Vec<crate::client::core::DiagnosticStep>),
 
     // --- Input & Editing ---
     InputChanged(text_editor::Action),
     StartCreateWithDescription,
     DescriptionChanged(text_editor::Action),
     SearchChanged(text_editor::Action),
+    /// Re-runs a search picked from the recent-search dropdown.
+    RecallSearch(String),
     ApplySearch(usize),
     ClearSearch,
     SetSearchTerm(String),
@@ -55,17 +59,31 @@ pub enum Message {
     SaveAndSwitchEditor,
     ToggleTask(usize, bool),
     ToggleTaskShift(String),
+    SkipOccurrence(String),
     ShiftSpaceSelected,
     CompleteTree(String),
     DeleteTask(usize),
     EditTaskStart(usize),
     EditTaskTree(String),
+    InlineEditStart(String),
+    InlineEditSelected,
+    InlineEditChanged(String),
+    InlineEditCommit(String, String),
+    InlineEditCancel,
     CancelEdit,
     ChangePriority(usize, i8),
     SetTaskStatus(usize, crate::model::TaskStatus),
     StartTask(String),
     PauseTask(String),
     StopTask(String),
+    SetProgress(String, u8),
+    CompleteAllVisible,
+    CompleteAllVisibleConfirm,
+    CompleteAllVisibleCancel,
+    /// Runs the action held in `pending_confirm`; `true` also turns `confirm_delete` off.
+    ConfirmPendingAction(bool),
+    CancelPendingAction,
+    Undo,
 
     // --- Keyboard Shortcuts (Stateless / Context-Aware) ---
     SelectNext,
@@ -121,11 +139,18 @@ pub enum Message {
     FocusInput,
     FocusSearch,
     Refresh,
+    /// Periodic background refresh; skipped while a sync is in flight or backing off.
+    AutoRefresh,
 
     ZoomIn,
     ZoomOut,
     ZoomReset,
     ToggleQuickFilter,
+    ToggleFocusMode,
+    ToggleInboxFilter,
+    ToggleDueHeatmap,
+    ShiftHeatmapMonth(i32),
+    FilterDueDate(chrono::NaiveDate),
     ToggleSidebar,
 
     // --- View & Filter ---
@@ -136,6 +161,11 @@ pub enum Message {
     SetMinDuration(Option<u32>),
     SetMaxDuration(Option<u32>),
     ToggleIncludeUnsetDuration(bool),
+    BulkTagInputChanged(String),
+    BulkTagRenameInputChanged(String),
+    /// Adds (`true`) or removes the batch tag on every visible task.
+    BulkTagVisible(bool),
+    RenameTagEverywhere,
     ToggleDetails(String),
     SidebarModeChanged(SidebarMode),
     SelectCalendar(String),
@@ -147,9 +177,15 @@ pub enum Message {
     CategoryMatchModeChanged(bool),
     ToggleHideCompleted(bool),
     ToggleHideFullyCompletedTags(bool),
+    ToggleHideDeferred(bool),
+    ToggleFuzzySearch(bool),
     ToggleHideAliasesInSidebar(bool),
     ToggleSortStandardByPriority(bool),
     SetSortPreset(crate::config::SortPreset),
+    SetSortMode(crate::config::SortMode),
+    SetAllDaySort(crate::config::AllDaySort),
+    SetAgendaGrouping(crate::config::AgendaGrouping),
+    SetSortUrgencyBoost(bool),
     CycleFocus(bool),
     OpenHelp(crate::help::HelpTab),
     CloseHelp,
@@ -163,13 +199,21 @@ pub enum Message {
 
     // --- Navigation & Actions ---
     CopyToClipboard(String),
+    CopyViewAsMarkdown,
     TogglePin(String),
+    ToggleWaiting(String),
     YankTask(String),
     StartCreateChild(String),
     AddDependency(String),
     AddRelatedTo(String),
     MakeChild(String),
     RemoveParent(String),
+    DragStart(String),
+    DragEnter(String),
+    DragLeave(String),
+    DropOnTask(String),
+    DropOnRoot,
+    DragEnd,
     RemoveDependency(String, String),
     RemoveRelatedTo(String, String),
     AliasKeyInput(String),
@@ -178,6 +222,13 @@ pub enum Message {
     EditAlias(String, String),
     CancelEditAlias,
     RemoveAlias(String),
+    TemplateLabelInput(String),
+    TemplateSmartInput(String),
+    AddTemplate,
+    EditTemplate(usize),
+    CancelEditTemplate,
+    RemoveTemplate(usize),
+    ApplyTemplate(String),
     GoalKeyInput(String),
     GoalTargetInput(String),
     GoalTypeChanged(crate::config::GoalType),
@@ -198,7 +249,9 @@ pub enum Message {
     JumpToRandomTask,
     FocusSelected,
     ClearFocus,
-    SnapToSelected { focus: bool },
+    SnapToSelected {
+        focus: bool,
+    },
     TagHovered(Option<String>),
     FocusTag(String),
     FocusLocation(String),
@@ -212,16 +265,27 @@ pub enum Message {
     ConfigUpdated(Box<Config>),
     ObSortDaysChanged(String),
     ThemeChanged(AppTheme),
+    SetCustomThemePrimary(String),
+    SetCustomThemeBackground(String),
+    SetTagColorMode(TagColorMode),
+    SetDateFormat(DateFormat),
+    SetTimeFormat(TimeFormat),
     Loaded(LoadedResult),
     TasksRefreshed(Result<(String, Vec<TodoTask>), String>),
     RefreshedAll(Result<Vec<(String, Vec<TodoTask>)>, String>),
     MigrationComplete(Result<usize, String>),
     FontLoaded(Result<(), String>),
     DismissError,
+    DismissSyncWarnings,
+    RetryUnsynced(String),
+    DiscardUnsynced(String),
+    /// Drops the journal action at the given index if it still targets the given UID.
+    DropJournalAction(usize, String),
+    CompactJournal,
     ToggleAllCalendars(bool),
     Tick,
     InitBackgroundWorker(mpsc::Sender<crate::gui::async_ops::WorkerCommand>),
-    BackgroundSyncComplete(Vec<TodoTask>),
+    BackgroundSyncComplete(Vec<TodoTask>, Vec<String>),
     BackgroundSyncFailed,
     JournalSaved,
 
@@ -229,6 +293,8 @@ pub enum Message {
     WindowDragged,
     MinimizeWindow,
     CloseWindow,
+    TrayCommand(crate::gui::tray::TrayCommand),
+    QuickAddHotkey,
     WindowResized(iced::Size),
     ApplyWindowResize(usize),
     ResizeStart(ResizeDirection),
@@ -241,6 +307,7 @@ pub enum Message {
     SetTrashRetention(String),
     SetDefaultDurationGoalMins(String),
     SetSessionsCountAsCompletions(bool),
+    SetArchiveCompleted(bool),
     SetLanguage(String),
 
     // --- Alarms & Reminders ---
@@ -250,12 +317,17 @@ pub enum Message {
     CancelTaskFromAlarm(String, String),
     SnoozeAlarm(String, String, u32),
     DismissAlarm(String, String),
+    SnoozeAllAlarms(u32),
+    DismissAllAlarms,
     SnoozeCustomInput(String),
     SnoozeCustomSubmit(String, String),
+    SnoozeUntilInput(String),
+    SnoozeUntilSubmit(String, String),
     SetAutoReminders(bool),
     SetDefaultReminderTime(String),
     SetSnoozeShort(String),
     SetSnoozeLong(String),
+    SetSnoozePresets(String),
     SetAutoRefreshInterval(String),
 
     SetShowQuickFilter(bool),
@@ -271,27 +343,38 @@ pub enum Message {
     TogglePinnedAction(crate::config::TaskAction, bool),
     SetLogLevel(LogLevel),
     SetSyncSettings(bool),
+    SetLimitFetchToCutoff(bool),
 
     // --- Advanced Settings ---
     ToggleAdvancedSettings(bool),
     SetMaxDoneRoots(String),
     SetMaxDoneSubtasks(String),
     SetShowPriorityNumbers(bool),
+    SetCompactLayout(bool),
 
     SetCreateEventsForTasks(bool),
     SetDeleteEventsOnCompletion(bool),
     SetStrikethroughCompleted(bool),
+    SetMinimizeToTray(bool),
+    QuickAddHotkeyInput(String),
+    ApplyQuickAddHotkey,
+    SetConfirmDelete(bool),
+    SetParentProgress(crate::config::ParentProgress),
+    SetShowParsePreview(bool),
     DeleteAllCalendarEvents,
     BackfillEventsComplete(Result<usize, String>),
 
     // --- Local Calendar & ICS ---
     ExportLocalIcs(String),
+    ExportBackup(crate::store::ExportFormat),
     ExportSaved(Result<std::path::PathBuf, String>),
     ImportLocalIcs(String),
     ImportCompleted(Result<String, String>),
     IcsFileLoaded(Result<(String, String), String>),
     IcsImportDialogCalendarSelected(String),
     IcsImportDialogCancel,
+    IcsImportDialogToggleConflictCopies(bool),
+    RestoreBackup,
     IcsImportDialogConfirm,
     AddLocalCalendar,
     DeleteLocalCalendar(String),
@@ -302,7 +385,14 @@ pub enum Message {
     MoveCalendar(String, i8),
     RemoteCalendarUpdated(String, Result<(), String>),
     RemoteCalendarCreated(String, Result<String, String>),
+    ToggleNewCalendarInput,
+    NewCalendarInputChanged(String),
+    CreateCalendarFromSidebar,
     OpenColorPicker(String, iced::Color),
     CancelColorPicker,
     SubmitColorPicker(iced::Color),
+    CalendarOverrideNameChanged(String, String),
+    ClearCalendarOverride(String),
+    /// Sidebar color change finished: (href, hex, Ok(stored on server) / Err).
+    CalendarColorSaved(String, String, Result<bool, String>),
 }
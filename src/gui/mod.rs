@@ -5,10 +5,12 @@ Entry point and setup for the GUI application.
 */
 
 pub mod async_ops;
+pub mod hotkey;
 pub mod icon;
 pub mod message;
 pub mod state;
 pub mod subscription;
+pub mod tray;
 pub mod update;
 pub mod view;
 
@@ -73,6 +75,8 @@ pub fn run_with_ics_file(
         icon: window_icon,
         decorations: force_ssd,
         transparent: !force_ssd,
+        // With the tray, the close button hides the window instead (see `Message::CloseWindow`)
+        exit_on_close_request: !init_config.minimize_to_tray,
         platform_specific: window::settings::PlatformSpecific {
             #[cfg(target_os = "linux")]
             application_id: String::from("cfait"),
@@ -179,6 +183,22 @@ impl GuiApp {
             Theme::custom("Rusty Dark", palette)
         }
 
+        // User palette from the hex strings in settings; invalid input falls back per color.
+        fn create_custom_theme(primary: &str, background: &str) -> Theme {
+            use crate::color_utils::{is_dark, parse_hex_to_floats};
+            let (br, bg, bb) = parse_hex_to_floats(background).unwrap_or((0.13, 0.12, 0.12));
+            let mut palette = if is_dark(br, bg, bb) {
+                iced::Theme::Dark.palette()
+            } else {
+                iced::Theme::Light.palette()
+            };
+            palette.background = iced::Color::from_rgb(br, bg, bb);
+            if let Some((r, g, b)) = parse_hex_to_floats(primary) {
+                palette.primary = iced::Color::from_rgb(r, g, b);
+            }
+            Theme::custom("Custom", palette)
+        }
+
         // Determine which theme to actually render
         let effective_theme = if self.current_theme == AppTheme::Random {
             self.resolved_random_theme
@@ -210,6 +230,9 @@ impl GuiApp {
             AppTheme::Oxocarbon => Theme::Oxocarbon,
             AppTheme::Ferra => Theme::Ferra,
             AppTheme::RustyDark => create_rusty_dark_theme(),
+            AppTheme::Custom => {
+                create_custom_theme(&self.custom_theme_primary, &self.custom_theme_background)
+            }
             // Fallback: If for some reason resolved_random_theme was Random (shouldn't happen), default to RustyDark
             AppTheme::Random => create_rusty_dark_theme(),
         }
@@ -218,7 +241,12 @@ impl GuiApp {
     fn subscription(&self) -> Subscription<Message> {
         let subs = subscription::subscription(self);
         let alarm_sub = Subscription::run(alarm_stream);
-        Subscription::batch(vec![subs, alarm_sub])
+        Subscription::batch(vec![
+            subs,
+            alarm_sub,
+            tray::subscription(),
+            hotkey::subscription(),
+        ])
     }
 
     fn update(&mut self, message: Message) -> Task<Message> {
@@ -3,7 +3,7 @@
 // Manages the application state for the GUI (Iced).
 
 use crate::client::RustyClient;
-use crate::config::{AppTheme, Config, LogLevel};
+use crate::config::{AppTheme, Config, LogLevel, TagColorMode};
 use crate::context::AppContext;
 use crate::gui::icon;
 use crate::model::{Alarm, CalendarListEntry, Task as TodoTask};
@@ -25,6 +25,18 @@ pub enum AppState {
     Help(crate::help::HelpTab, u8),
 }
 
+/// An action held back by `confirm_delete` until the user answers the confirmation dialog.
+#[derive(PartialEq, Clone, Debug)]
+pub enum PendingConfirm {
+    DeleteTask(String),
+    DeleteTaskTree(String),
+    BulkTag {
+        uids: Vec<String>,
+        tag: String,
+        add: bool,
+    },
+}
+
 #[derive(Default, PartialEq, Clone, Copy, Debug)]
 pub enum SidebarMode {
     #[default]
@@ -66,11 +78,21 @@ pub struct GuiApp {
     pub calendars: Vec<CalendarListEntry>,
     pub client: Option<RustyClient>,
     pub tag_aliases: HashMap<String, Vec<String>>,
+    /// Alias cycles found when the config was loaded; those aliases are disabled.
+    pub alias_problems: Vec<String>,
     pub bg_tx: Option<tokio::sync::mpsc::Sender<crate::gui::async_ops::WorkerCommand>>,
 
     // Cached Sidebar Data (computed once, not in view())
     pub cached_categories: Vec<crate::store::AggregateItem>,
     pub cached_locations: Vec<crate::store::AggregateItem>,
+    pub inbox_count: usize,
+    /// Name from a `cal:` search token that matches no calendar (shown as a hint).
+    pub unknown_search_calendar: Option<String>,
+    /// Due-date heat map above the task list (header toggle), the month it shows, and
+    /// the open tasks per due day of the current filter (ignoring its own date token).
+    pub show_due_heatmap: bool,
+    pub heatmap_month: chrono::NaiveDate,
+    pub due_counts: HashMap<chrono::NaiveDate, usize>,
 
     // --- Stable ID Cache ---
     // Maps Task UID -> Iced Widget ID. Ensures the View and Update loops use the exact same ID instance.
@@ -84,6 +106,10 @@ pub struct GuiApp {
     pub yanked_uid: Option<String>,
     pub yank_lock_active: bool,
 
+    // Drag-and-drop reparenting: the task being dragged and the row under the cursor
+    pub dragging_uid: Option<String>,
+    pub drag_target_uid: Option<String>,
+
     pub hovered_tag_uid: Option<String>,
 
     // Track selected task for highlighting
@@ -94,6 +120,7 @@ pub struct GuiApp {
 
     // Preferences
     pub hide_completed: bool,
+    pub hide_deferred: bool,
     pub strikethrough_completed: bool,
     pub hide_fully_completed_tags: bool,
     pub hide_aliases_in_sidebar: bool,
@@ -101,7 +128,12 @@ pub struct GuiApp {
     pub sort_cutoff_days: Option<u32>,
     pub sort_standard_by_priority: bool,
     pub sort_preset: crate::config::SortPreset,
+    pub sort_mode: crate::config::SortMode,
+    pub sort_urgency_boost: bool,
     pub current_theme: AppTheme,
+    pub custom_theme_primary: String,
+    pub custom_theme_background: String,
+    pub tag_color_mode: TagColorMode,
 
     // Store the resolved random theme for this session
     pub resolved_random_theme: AppTheme,
@@ -111,6 +143,10 @@ pub struct GuiApp {
     pub filter_max_duration: Option<u32>,
     pub filter_include_unset_duration: bool,
 
+    // Batch tag edit (tag sidebar)
+    pub bulk_tag_input: String,
+    pub bulk_tag_rename_input: String,
+
     pub quick_filter_term: String,
     pub quick_filter_icon: String,
     pub show_quick_filter: bool,
@@ -124,10 +160,19 @@ pub struct GuiApp {
 
     // Inputs - Main
     pub input_value: text_editor::Content,
+    // Smart input dry-run for the preview line, recomputed when the input text changes
+    pub parse_preview: Option<crate::model::ParsePreview>,
+    pub parse_preview_input: String,
     pub description_value: text_editor::Content,
     pub search_value: text_editor::Content,
     pub search_debounce_version: usize,
+    pub search_history: Vec<String>,
+    // Position while recalling history with the arrow keys; None = editing freely
+    pub search_history_idx: Option<usize>,
     pub editing_uid: Option<String>,
+    // Task whose summary is being renamed in place in its row, and the draft title
+    pub inline_edit_uid: Option<String>,
+    pub inline_edit_value: String,
     pub editing_tree_uid: Option<String>,
     pub creating_child_of: Option<String>,
     pub moving_task_uid: Option<String>,
@@ -139,7 +184,17 @@ pub struct GuiApp {
     pub help_expanded_sections: HashSet<String>,
     pub unsynced_changes: bool,
     pub unsynced_tooltip: String,
+    /// Pending journal actions in queue order, as (verb, summary, uid).
+    pub journal_items: Vec<(String, String, String)>,
+    /// Result of the last manual journal compaction, shown in settings.
+    pub journal_compact_note: Option<String>,
     pub last_sync_failed: bool,
+    /// Time of the last successful sync, shown in the header.
+    pub last_sync_at: Option<chrono::DateTime<chrono::Local>>,
+    /// Consecutive failed syncs; drives auto-refresh backoff.
+    pub sync_failure_streak: u32,
+    /// Auto-refresh ticks still to skip before the next background attempt.
+    pub auto_refresh_skips: u32,
 
     // Session UI state
     pub adding_session_uid: Option<String>,
@@ -162,6 +217,10 @@ pub struct GuiApp {
     pub alias_input_key: String,
     pub alias_input_values: String,
     pub editing_alias_key: Option<String>,
+    pub quick_templates: Vec<crate::config::QuickTemplate>,
+    pub template_input_label: String,
+    pub template_input_smart: String,
+    pub editing_template_idx: Option<usize>,
 
     // Inputs - Settings (Goals)
     pub goal_input_key: String,
@@ -180,12 +239,18 @@ pub struct GuiApp {
     // System
     pub loading: bool,
     pub error_msg: Option<String>,
+    /// Warnings from the most recent sync that produced any, shown until dismissed.
+    pub sync_warnings: Vec<String>,
+    /// Remote tasks still unsynced past `unsynced_warning_hours`, as (uid, summary).
+    pub stale_unsynced: Vec<(String, String)>,
 
     // Onboarding / Config
     pub ob_url: String,
     pub ob_user: String,
     pub ob_pass: String,
     pub ob_password_visible: bool,
+    /// Result of the last "Test connection" run; `Some(empty)` while it is in flight.
+    pub connection_diagnostics: Option<Vec<crate::client::core::DiagnosticStep>>,
     pub ob_default_cal: Option<String>,
     pub ob_sort_days_input: String,
     pub ob_insecure: bool,
@@ -197,6 +262,10 @@ pub struct GuiApp {
     // Local Calendar Management
     pub local_cals_editing: Vec<CalendarListEntry>,
     pub remote_cals_editing: Vec<CalendarListEntry>,
+    /// Server-side values of the remote calendars being edited, to tell when a row has changes.
+    pub remote_cals_saved: Vec<CalendarListEntry>,
+    /// Name typed into the calendars sidebar "+ New calendar" field; `None` while collapsed.
+    pub new_calendar_input: Option<String>,
     pub color_picker_active_href: Option<String>,
     pub temp_color: iced::Color,
     pub scrollable_id: iced::widget::Id,
@@ -216,9 +285,21 @@ pub struct GuiApp {
     pub start_grace_period_days: u32,
     pub alarm_tx: Option<mpsc::Sender<SystemEvent>>, // Send tasks to actor
     pub ringing_tasks: Vec<(TodoTask, Alarm)>,       // Stack of firing alarms
+    pub tray_active: bool,                           // Tray icon showing (`minimize_to_tray`)
+    pub window_hidden: bool,                         // Window hidden to the tray
+    pub hide_after_quick_add: bool,                  // Re-hide after the hotkey's task is submitted
+    pub ob_quick_add_hotkey_input: String,
 
     // Snooze Custom Input
     pub snooze_custom_input: String,
+    pub snooze_until_input: String,
+
+    // Recent delete/complete/move actions, newest last
+    pub undo_history: crate::store::UndoHistory,
+
+    // Bulk completion confirmation: the tasks it will complete, collected when it opened
+    pub complete_all_uids: Option<Vec<String>>,
+    pub pending_confirm: Option<PendingConfirm>,
 
     // ICS Import Dialog State
     pub ics_import_dialog_open: bool,
@@ -226,6 +307,7 @@ pub struct GuiApp {
     pub ics_import_content: Option<String>,
     pub ics_import_selected_calendar: Option<String>,
     pub ics_import_task_count: Option<usize>,
+    pub ics_import_conflict_copies: bool,
 
     // Double click tracking
     pub last_click: Option<(std::time::Instant, String)>, // Added
@@ -241,6 +323,7 @@ pub struct GuiApp {
     pub default_reminder_time: String,
     pub snooze_short_mins: u32,
     pub snooze_long_mins: u32,
+    pub snooze_presets: Vec<u32>,
     pub create_events_for_tasks: bool,
     pub delete_events_on_completion: bool,
     pub deleting_events: bool,
@@ -248,6 +331,7 @@ pub struct GuiApp {
     // Settings input buffers for duration strings
     pub ob_snooze_short_input: String,
     pub ob_snooze_long_input: String,
+    pub ob_snooze_presets_input: String,
     pub ob_auto_refresh_input: String, // Added
 
     // Advanced Settings Inputs
@@ -256,7 +340,9 @@ pub struct GuiApp {
     pub ob_max_done_subtasks_input: String,
 
     pub show_priority_numbers: bool,
+    pub compact_layout: bool,
     pub sync_settings: bool,
+    pub limit_fetch_to_cutoff: bool,
 
     // Logging level
     pub log_level: LogLevel,
@@ -271,7 +357,45 @@ pub struct GuiApp {
     pub ui_scale: f32,
 }
 
+/// Prefix on `color_picker_active_href` when the picker edits a calendar's local color override.
+pub const OVERRIDE_PICKER_PREFIX: &str = "override:";
+
 impl GuiApp {
+    /// Remote calendars in sidebar order, with the server's name and color rather than any
+    /// local override, for the settings editor.
+    pub fn remote_calendars_for_editing(&self) -> Vec<CalendarListEntry> {
+        let cached = crate::cache::Cache::load_calendars(self.ctx.as_ref()).unwrap_or_default();
+        self.calendars
+            .iter()
+            .filter(|c| !c.href.starts_with("local://"))
+            .map(|c| {
+                cached
+                    .iter()
+                    .find(|s| s.href == c.href)
+                    .cloned()
+                    .unwrap_or_else(|| c.clone())
+            })
+            .collect()
+    }
+
+    /// Re-reads calendar names and colors from the cache and local registry, then applies
+    /// `calendar_overrides`. Call after changing an override.
+    pub fn reapply_calendar_overrides(&mut self) {
+        let mut base = crate::cache::Cache::load_calendars(self.ctx.as_ref()).unwrap_or_default();
+        base.extend(
+            crate::storage::LocalCalendarRegistry::load(self.ctx.as_ref()).unwrap_or_default(),
+        );
+        for cal in &mut self.calendars {
+            if let Some(b) = base.iter().find(|b| b.href == cal.href) {
+                cal.name = b.name.clone();
+                cal.color = b.color.clone();
+            }
+        }
+        self.core_config
+            .apply_calendar_overrides(&mut self.calendars);
+        self.sort_calendars();
+    }
+
     pub fn sort_calendars(&mut self) {
         let order = self.core_config.collection_order.clone();
         let sort_by_size = self.sort_collections_by_size;
@@ -382,7 +506,7 @@ impl Default for GuiApp {
 
         // Select a random theme (excluding Random itself)
         let themes: Vec<AppTheme> = AppTheme::iter()
-            .filter(|&t| t != AppTheme::Random)
+            .filter(|&t| t != AppTheme::Random && t != AppTheme::Custom)
             .collect();
         let resolved_random_theme = if !themes.is_empty() {
             themes[rng.usize(..themes.len())]
@@ -409,10 +533,17 @@ impl Default for GuiApp {
             calendars: vec![],
             client: None,
             tag_aliases: HashMap::new(),
+            alias_problems: Vec::new(),
             bg_tx: None,
 
             cached_categories: Vec::new(),
             cached_locations: Vec::new(),
+            inbox_count: 0,
+            unknown_search_calendar: None,
+            show_due_heatmap: false,
+            heatmap_month: chrono::Datelike::with_day(&chrono::Local::now().date_naive(), 1)
+                .unwrap_or_default(),
+            due_counts: HashMap::new(),
 
             task_ids: HashMap::new(),
 
@@ -422,6 +553,8 @@ impl Default for GuiApp {
             disabled_calendars: HashSet::new(),
             yanked_uid: None,
             yank_lock_active: false,
+            dragging_uid: None,
+            drag_target_uid: None,
             selected_uid: None,
 
             active_focus: Focus::MainList,
@@ -430,20 +563,29 @@ impl Default for GuiApp {
             hovered_tag_uid: None,
 
             hide_completed: false,
+            hide_deferred: false,
             hide_fully_completed_tags: true,
             hide_aliases_in_sidebar: true,
             show_inline_descriptions: true,
             sort_cutoff_days: Some(30),
             sort_standard_by_priority: false,
             sort_preset: crate::config::SortPreset::default(),
+            sort_mode: crate::config::SortMode::default(),
+            sort_urgency_boost: false,
             ob_sort_days_input: "30".to_string(),
             current_theme: AppTheme::default(),
+            custom_theme_primary: "#FFA500".to_string(),
+            custom_theme_background: "#211E1E".to_string(),
+            tag_color_mode: TagColorMode::default(),
             resolved_random_theme,
 
             filter_min_duration: None,
             filter_max_duration: None,
             filter_include_unset_duration: true,
 
+            bulk_tag_input: String::new(),
+            bulk_tag_rename_input: String::new(),
+
             quick_filter_term: "is:ready".to_string(),
             quick_filter_icon: "f0fa9".to_string(),
             show_quick_filter: true,
@@ -456,10 +598,16 @@ impl Default for GuiApp {
             ob_quick_filter_icon_input: "f0fa9".to_string(),
 
             input_value: text_editor::Content::new(),
+            parse_preview: None,
+            parse_preview_input: String::new(),
             description_value: text_editor::Content::new(),
             search_value: text_editor::Content::new(),
             search_debounce_version: 0,
+            search_history: Vec::new(),
+            search_history_idx: None,
             editing_uid: None,
+            inline_edit_uid: None,
+            inline_edit_value: String::new(),
             editing_tree_uid: None,
             creating_child_of: None,
             moving_task_uid: None,
@@ -471,7 +619,12 @@ impl Default for GuiApp {
             help_expanded_sections: HashSet::new(),
             unsynced_changes: false,
             unsynced_tooltip: String::new(),
+            journal_items: Vec::new(),
+            journal_compact_note: None,
             last_sync_failed: false,
+            last_sync_at: None,
+            sync_failure_streak: 0,
+            auto_refresh_skips: 0,
 
             // Session UI defaults
             adding_session_uid: None,
@@ -490,6 +643,10 @@ impl Default for GuiApp {
             alias_input_key: String::new(),
             alias_input_values: String::new(),
             editing_alias_key: None,
+            quick_templates: Vec::new(),
+            template_input_label: String::new(),
+            template_input_smart: String::new(),
+            editing_template_idx: None,
             goal_input_key: String::new(),
             goal_input_type: crate::config::GoalType::Count,
             goal_input_target: String::new(),
@@ -504,10 +661,13 @@ impl Default for GuiApp {
 
             loading: true,
             error_msg: None,
+            sync_warnings: Vec::new(),
+            stale_unsynced: Vec::new(),
             ob_url: String::new(),
             ob_user: String::new(),
             ob_pass: String::new(),
             ob_password_visible: false,
+            connection_diagnostics: None,
             ob_default_cal: None,
             ob_insecure: false,
             ob_tls_client_cert_path: String::new(),
@@ -516,6 +676,8 @@ impl Default for GuiApp {
 
             local_cals_editing: vec![],
             remote_cals_editing: vec![],
+            remote_cals_saved: vec![],
+            new_calendar_input: None,
             color_picker_active_href: None,
             temp_color: iced::Color::WHITE,
             scrollable_id: iced::widget::Id::unique(),
@@ -534,26 +696,35 @@ impl Default for GuiApp {
             start_grace_period_days: 1,
             alarm_tx: None,
             ringing_tasks: Vec::new(),
+            tray_active: false,
+            window_hidden: false,
+            hide_after_quick_add: false,
+            ob_quick_add_hotkey_input: String::new(),
             snooze_custom_input: String::new(),
+            snooze_until_input: String::new(),
 
             language: None,
             auto_reminders: true,
             default_reminder_time: "08:00".to_string(),
             snooze_short_mins: 60,
             snooze_long_mins: 1440,
+            snooze_presets: Vec::new(),
             create_events_for_tasks: false,
             delete_events_on_completion: false,
             strikethrough_completed: false,
             deleting_events: false,
             ob_snooze_short_input: "1h".to_string(),
             ob_snooze_long_input: "1d".to_string(),
+            ob_snooze_presets_input: String::new(),
             ob_auto_refresh_input: "30m".to_string(),
 
             show_advanced_settings: false,
             ob_max_done_roots_input: "20".to_string(),
             ob_max_done_subtasks_input: "5".to_string(),
             show_priority_numbers: true,
+            compact_layout: false,
             sync_settings: true,
+            limit_fetch_to_cutoff: false,
             log_level: LogLevel::Info,
 
             force_ssd: {
@@ -580,11 +751,16 @@ impl Default for GuiApp {
             last_click: None,
             last_title_click: None,
 
+            undo_history: crate::store::UndoHistory::default(),
+            complete_all_uids: None,
+            pending_confirm: None,
+
             ics_import_dialog_open: false,
             ics_import_file_path: None,
             ics_import_content: None,
             ics_import_selected_calendar: None,
             ics_import_task_count: None,
+            ics_import_conflict_copies: false,
         }
     }
 }
@@ -39,6 +39,9 @@ pub fn subscription(app: &GuiApp) -> Subscription<Message> {
         _ => {}
     }
 
+    // Native close button when `exit_on_close_request` is off (tray mode)
+    subs.push(window::close_requests().map(|_| Message::CloseWindow));
+
     // Track window metrics
     subs.push(event::listen_with(|evt, _status, _window_id| match evt {
         iced::Event::Window(window::Event::Resized(size)) => Some(Message::WindowResized(size)),
@@ -51,11 +54,11 @@ pub fn subscription(app: &GuiApp) -> Subscription<Message> {
             iced::time::every(std::time::Duration::from_secs(
                 app.auto_refresh_interval_mins as u64 * 60,
             ))
-            .map(|_| Message::Refresh),
+            .map(|_| Message::AutoRefresh),
         );
     }
 
-    // Tick every minute if there is an active task running, so the timer updates visually
+    // Tick every second while a task is running, so its session clock updates live
     let has_running_tasks = app.tasks.iter().any(|item| {
         if let crate::store::TaskListItem::Task(t) = item {
             t.last_started_at.is_some()
@@ -64,7 +67,7 @@ pub fn subscription(app: &GuiApp) -> Subscription<Message> {
         }
     });
     if has_running_tasks {
-        subs.push(iced::time::every(std::time::Duration::from_secs(60)).map(|_| Message::Tick));
+        subs.push(iced::time::every(std::time::Duration::from_secs(1)).map(|_| Message::Tick));
     }
 
     Subscription::batch(subs)
@@ -247,6 +250,7 @@ fn handle_hotkey(
                     "0" => return Some(Message::ZoomReset),
                     "b" => return Some(Message::ToggleSidebar),
                     "d" => return Some(Message::KeyboardDuplicateTask),
+                    "z" => return Some(Message::Undo),
                     "s" => return Some(Message::SubmitTask),
                     "n" => return Some(Message::StartCreateWithDescription),
                     "e" => {
@@ -284,9 +288,11 @@ fn handle_hotkey(
                     ("s", false) => Some(Message::ToggleActiveSelected),
                     ("s", true) => Some(Message::StopSelected),
                     ("x", false) => Some(Message::CancelSelected),
+                    ("x", true) => Some(Message::CompleteAllVisible),
                     ("y", false) => Some(Message::YankSelected),
                     ("y", true) => Some(Message::ToggleYankLock),
                     ("f", false) => Some(Message::FocusSelected),
+                    ("f", true) => Some(Message::ToggleFocusMode),
                     ("c", false) => Some(Message::KeyboardLinkChild),
                     ("c", true) => Some(Message::KeyboardCreateChild),
                     ("t", false) => Some(Message::KeyboardAddSession),
@@ -332,7 +338,13 @@ fn handle_hotkey(
             keyboard::Key::Named(Named::ArrowLeft) => Some(Message::ArrowLeft),
             keyboard::Key::Named(Named::PageDown) => Some(Message::SelectNextPage),
             keyboard::Key::Named(Named::PageUp) => Some(Message::SelectPrevPage),
-            keyboard::Key::Named(Named::Enter) => Some(Message::EnterPressed),
+            keyboard::Key::Named(Named::Enter) => {
+                if modifiers.shift() {
+                    Some(Message::KeyboardOpenContextMenu)
+                } else {
+                    Some(Message::EnterPressed)
+                }
+            }
             keyboard::Key::Named(Named::Space) => {
                 if modifiers.shift() {
                     Some(Message::ShiftSpaceSelected)
@@ -341,6 +353,7 @@ fn handle_hotkey(
                 }
             }
             keyboard::Key::Named(Named::Escape) => Some(Message::EscapePressed),
+            keyboard::Key::Named(Named::F2) => Some(Message::InlineEditSelected),
             keyboard::Key::Named(Named::Delete) => {
                 // Handled in is_cmd block for Ctrl+Delete, so here it's just Delete
                 Some(Message::DeleteSelected)
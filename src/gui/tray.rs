@@ -0,0 +1,152 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// File: ./src/gui/tray.rs
+/*
+System tray icon for the background-resident mode (`minimize_to_tray`).
+
+With the tray showing, closing the window only hides it, so the alarm actor keeps
+running and reminders still fire. The tray menu offers Open, Add task and Quit.
+Built with the `tray` cargo feature; without it (or if the desktop refuses the icon)
+`install` returns false and closing the window quits as before.
+*/
+
+use crate::gui::message::Message;
+use iced::Subscription;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrayCommand {
+    Open,
+    AddTask,
+    Quit,
+}
+
+/// Creates the tray icon (once per process). Returns whether a tray icon is showing.
+/// Must be called from the GUI thread.
+pub fn install() -> bool {
+    imp::install()
+}
+
+/// Tray menu clicks as messages. Empty when no tray was installed.
+pub fn subscription() -> Subscription<Message> {
+    imp::subscription()
+}
+
+#[cfg(feature = "tray")]
+mod imp {
+    use super::TrayCommand;
+    use crate::gui::message::Message;
+    use iced::Subscription;
+    use iced::futures::SinkExt;
+    use iced::futures::channel::mpsc::Sender;
+    use std::sync::OnceLock;
+    use tray_icon::menu::{Menu, MenuEvent, MenuItem, PredefinedMenuItem};
+    use tray_icon::{Icon, TrayIcon, TrayIconBuilder};
+
+    const MENU_OPEN: &str = "cfait-tray-open";
+    const MENU_ADD_TASK: &str = "cfait-tray-add-task";
+    const MENU_QUIT: &str = "cfait-tray-quit";
+
+    static INSTALLED: OnceLock<bool> = OnceLock::new();
+
+    fn build() -> Option<TrayIcon> {
+        let menu = Menu::new();
+        menu.append_items(&[
+            &MenuItem::with_id(MENU_OPEN, rust_i18n::t!("tray_open"), true, None),
+            &MenuItem::with_id(MENU_ADD_TASK, rust_i18n::t!("tray_add_task"), true, None),
+            &PredefinedMenuItem::separator(),
+            &MenuItem::with_id(MENU_QUIT, rust_i18n::t!("tray_quit"), true, None),
+        ])
+        .ok()?;
+
+        let (rgba, size) = iced::window::icon::from_file_data(
+            include_bytes!("../../assets/autogen/cfait.png"),
+            None,
+        )
+        .ok()?
+        .into_raw();
+        let icon = Icon::from_rgba(rgba, size.width, size.height).ok()?;
+
+        TrayIconBuilder::new()
+            .with_menu(Box::new(menu))
+            .with_tooltip("Cfait")
+            .with_icon(icon)
+            .build()
+            .map_err(|e| log::warn!("Tray icon unavailable: {}", e))
+            .ok()
+    }
+
+    // GTK owns the tray on Linux, so it lives on its own thread with a GTK main loop.
+    #[cfg(target_os = "linux")]
+    pub fn install() -> bool {
+        *INSTALLED.get_or_init(|| {
+            let (tx, rx) = std::sync::mpsc::channel();
+            let spawned = std::thread::Builder::new()
+                .name("cfait-tray".to_string())
+                .spawn(move || {
+                    if gtk::init().is_err() {
+                        log::warn!("Tray icon unavailable: GTK failed to initialize");
+                        let _ = tx.send(false);
+                        return;
+                    }
+                    let tray = build();
+                    let _ = tx.send(tray.is_some());
+                    if tray.is_some() {
+                        gtk::main();
+                    }
+                });
+            spawned.is_ok() && rx.recv().unwrap_or(false)
+        })
+    }
+
+    // Elsewhere the icon belongs to the thread running the window's event loop.
+    #[cfg(not(target_os = "linux"))]
+    pub fn install() -> bool {
+        thread_local! {
+            static TRAY: std::cell::RefCell<Option<TrayIcon>> = const { std::cell::RefCell::new(None) };
+        }
+        *INSTALLED.get_or_init(|| {
+            let tray = build();
+            let ok = tray.is_some();
+            TRAY.with(|t| *t.borrow_mut() = tray);
+            ok
+        })
+    }
+
+    pub fn subscription() -> Subscription<Message> {
+        if INSTALLED.get().copied().unwrap_or(false) {
+            Subscription::run(menu_events)
+        } else {
+            Subscription::none()
+        }
+    }
+
+    fn menu_events() -> impl iced::futures::Stream<Item = Message> {
+        iced::stream::channel(16, |mut output: Sender<Message>| async move {
+            let receiver = MenuEvent::receiver();
+            while let Ok(Ok(event)) = tokio::task::spawn_blocking(move || receiver.recv()).await {
+                let command = match event.id.0.as_str() {
+                    MENU_OPEN => TrayCommand::Open,
+                    MENU_ADD_TASK => TrayCommand::AddTask,
+                    MENU_QUIT => TrayCommand::Quit,
+                    _ => continue,
+                };
+                let _ = output.send(Message::TrayCommand(command)).await;
+            }
+
+            std::future::pending::<()>().await;
+        })
+    }
+}
+
+#[cfg(not(feature = "tray"))]
+mod imp {
+    use crate::gui::message::Message;
+    use iced::Subscription;
+
+    pub fn install() -> bool {
+        false
+    }
+
+    pub fn subscription() -> Subscription<Message> {
+        Subscription::none()
+    }
+}
@@ -30,6 +30,30 @@ use iced::widget::operation;
 use iced::widget::scrollable::RelativeOffset;
 use std::time::Duration as StdDuration;
 
+/// Re-runs the smart input parser for the input preview when the text changed since
+/// the last update, so the view does not parse on every redraw. Tree editing has no preview.
+pub fn refresh_parse_preview(app: &mut GuiApp) {
+    let input_text = if app.editing_tree_uid.is_none() {
+        app.input_value.text()
+    } else {
+        String::new()
+    };
+    if input_text == app.parse_preview_input {
+        return;
+    }
+    app.parse_preview = (!input_text.trim().is_empty()).then(|| {
+        let def_time = chrono::NaiveTime::parse_from_str(&app.default_reminder_time, "%H:%M").ok();
+        crate::model::ParsePreview::parse(
+            &input_text,
+            &app.tag_aliases,
+            def_time,
+            &app.core_config.holidays,
+            app.core_config.week_start,
+        )
+    });
+    app.parse_preview_input = input_text;
+}
+
 /// Build the visible task list and update UI caches.
 ///
 /// Strategy:
@@ -48,13 +72,65 @@ pub fn refresh_filtered_tasks(app: &mut GuiApp) {
     // Sync specific Iced state to SessionState
     app.session.active_calendar_href = app.active_cal_href.clone();
     app.session.search_term = app.search_value.text();
+    app.store
+        .apply_archive_setting(config, &app.session.search_term);
+
+    // `cal:Name` in the search narrows to that calendar; an unknown name matches nothing
+    app.unknown_search_calendar = None;
+    let calendar_href = crate::model::matcher::extract_calendar_filter(&app.session.search_term)
+        .map(|name| {
+            let lower = name.to_lowercase();
+            match app
+                .calendars
+                .iter()
+                .find(|c| c.name.to_lowercase() == lower)
+            {
+                Some(cal) => cal.href.clone(),
+                None => {
+                    app.unknown_search_calendar = Some(name);
+                    String::new() // No calendar has an empty href
+                }
+            }
+        });
 
     // Delegate entirely to session state
-    let filter_res = app.session.get_filtered_view(&app.store, config);
-
-    app.tasks = filter_res.items;
+    let filter_res = app
+        .session
+        .get_filtered_view_in(&app.store, config, calendar_href.as_deref());
+
+    app.tasks = crate::store::group_by_agenda(
+        filter_res.items,
+        config.agenda_grouping,
+        chrono::Local::now().date_naive(),
+        config.week_start,
+    );
     app.cached_categories = filter_res.categories;
+
+    // The heat map counts the filter without its own day, so every day stays clickable
+    app.due_counts = if !app.show_due_heatmap {
+        std::collections::HashMap::new()
+    } else {
+        let without_day = crate::model::matcher::without_due_date_tokens(&app.session.search_term);
+        if without_day == app.session.search_term.trim() {
+            crate::store::due_date_counts(&app.tasks)
+        } else {
+            let mut session = app.session.clone();
+            session.search_term = without_day;
+            let items = session
+                .get_filtered_view_in(&app.store, config, calendar_href.as_deref())
+                .items;
+            crate::store::due_date_counts(&items)
+        }
+    };
     app.cached_locations = filter_res.locations;
+    app.inbox_count = filter_res.inbox_count;
+
+    app.stale_unsynced = app
+        .store
+        .stale_unsynced_tasks(config.unsynced_warning_hours)
+        .into_iter()
+        .map(|t| (t.uid.clone(), t.summary.clone()))
+        .collect();
 
     for item in &mut app.tasks {
         if let crate::store::TaskListItem::Task(task) = item {
@@ -149,16 +225,24 @@ pub fn save_config(app: &mut GuiApp) -> Config {
     cfg.hidden_calendars = app.hidden_calendars.iter().cloned().collect();
     cfg.disabled_calendars = app.disabled_calendars.iter().cloned().collect();
     cfg.hide_completed = app.hide_completed;
+    cfg.hide_deferred = app.hide_deferred;
     cfg.hide_fully_completed_tags = app.hide_fully_completed_tags;
     cfg.hide_aliases_in_sidebar = app.hide_aliases_in_sidebar;
     cfg.show_inline_descriptions = app.show_inline_descriptions;
     cfg.sort_standard_by_priority = app.sort_standard_by_priority;
     cfg.sort_preset = app.sort_preset;
+    cfg.sort_mode = app.sort_mode;
+    cfg.sort_urgency_boost = app.sort_urgency_boost;
     cfg.ui_scale = app.ui_scale;
     cfg.show_priority_numbers = app.show_priority_numbers;
+    cfg.compact_layout = app.compact_layout;
     cfg.tag_aliases = app.tag_aliases.clone();
+    cfg.quick_templates = app.quick_templates.clone();
     cfg.sort_cutoff_days = app.sort_cutoff_days;
     cfg.theme = app.current_theme;
+    cfg.custom_theme_primary = app.custom_theme_primary.clone();
+    cfg.custom_theme_background = app.custom_theme_background.clone();
+    cfg.tag_color_mode = app.tag_color_mode;
     cfg.urgent_days_horizon = app.urgent_days;
     cfg.urgent_priority_threshold = app.urgent_prio;
     cfg.default_priority = app.default_priority;
@@ -167,6 +251,7 @@ pub fn save_config(app: &mut GuiApp) -> Config {
     cfg.default_reminder_time = app.default_reminder_time.clone();
     cfg.snooze_short_mins = app.snooze_short_mins;
     cfg.snooze_long_mins = app.snooze_long_mins;
+    cfg.snooze_presets = app.snooze_presets.clone();
     cfg.create_events_for_tasks = app.create_events_for_tasks;
     cfg.delete_events_on_completion = app.delete_events_on_completion;
     cfg.strikethrough_completed = app.strikethrough_completed;
@@ -180,11 +265,13 @@ pub fn save_config(app: &mut GuiApp) -> Config {
     cfg.quick_filter_term = app.quick_filter_term.clone();
     cfg.quick_filter_icon = app.quick_filter_icon.clone();
     cfg.show_quick_filter = app.show_quick_filter;
+    cfg.search_history = app.search_history.clone();
     cfg.show_goals_tab = app.show_goals_tab;
     cfg.sidebar_is_hidden = app.sidebar_is_hidden;
     cfg.sort_collections_by_size = app.sort_collections_by_size;
     cfg.log_level = app.log_level;
     cfg.sync_settings = app.sync_settings;
+    cfg.limit_fetch_to_cutoff = app.limit_fetch_to_cutoff;
 
     cfg.expanded_tags = app.session.expanded_tags.clone();
     cfg.expanded_locations = app.session.expanded_locations.clone();
@@ -196,6 +283,8 @@ pub fn save_config(app: &mut GuiApp) -> Config {
 
     // Cache the updated config in memory
     app.core_config = cfg.clone();
+    // Aliases, holidays or the reminder time may have changed: re-parse the input preview
+    app.parse_preview_input.clear();
 
     if timestamp_changed && let Some(tx) = &app.bg_tx {
         let _ = tx.try_send(crate::gui::async_ops::WorkerCommand::SyncNow);
@@ -453,13 +542,28 @@ pub fn scroll_to_selected_delayed(_app: &GuiApp, focus: bool) -> Task<Message> {
 use crate::model::AppIntent;
 
 pub fn dispatch_intent(app: &mut GuiApp, intent: AppIntent) {
+    dispatch_intent_collecting_created(app, intent);
+}
+
+/// `dispatch_intent`, returning the UIDs of the tasks the intent created.
+pub fn dispatch_intent_collecting_created(app: &mut GuiApp, intent: AppIntent) -> Vec<String> {
     let config = &app.core_config;
 
     // 1. Update UI filters synchronously
     app.session.apply_session_intent(&intent);
 
     // 2. Mutate in-memory store synchronously & extract persistence actions
-    let actions = app.store.apply_task_intent(&intent, config);
+    let actions = app
+        .store
+        .apply_task_intent_with_undo(&intent, config, &mut app.undo_history);
+
+    let created = actions
+        .iter()
+        .filter_map(|a| match a {
+            crate::journal::Action::Create(t) => Some(t.uid.clone()),
+            _ => None,
+        })
+        .collect();
 
     // 3. Update the UI rendering
     refresh_filtered_tasks(app);
@@ -470,41 +574,40 @@ pub fn dispatch_intent(app: &mut GuiApp, intent: AppIntent) {
     {
         let _ = tx.try_send(crate::gui::async_ops::WorkerCommand::Batch(actions));
     }
+
+    created
 }
 
-/// Reloads the journal from disk and updates the unsynced UI state & tooltip.
+/// Reloads the journal from disk and updates the unsynced UI state, tooltip & queue listing.
 pub fn update_journal_state(app: &mut GuiApp) {
     let journal = crate::journal::Journal::load(app.ctx.as_ref());
     app.unsynced_changes = !journal.is_empty();
 
+    app.journal_items = journal
+        .queue
+        .iter()
+        .map(|action| {
+            let verb = match action {
+                crate::journal::Action::Create(_) => rust_i18n::t!("unsynced_action_create"),
+                crate::journal::Action::Update(_) => rust_i18n::t!("unsynced_action_update"),
+                crate::journal::Action::Delete(_) => rust_i18n::t!("calendar_action_deleted"),
+                crate::journal::Action::Move(_, _) => rust_i18n::t!("unsynced_action_move"),
+            };
+            let task = action.task();
+            (verb.to_string(), task.summary.clone(), task.uid.clone())
+        })
+        .collect();
+
     if app.unsynced_changes {
         let mut lines = vec![rust_i18n::t!("unsynced").to_string()];
-        for (i, action) in journal.queue.iter().enumerate() {
+        for (i, (verb, summary, _)) in app.journal_items.iter().enumerate() {
             if i >= 10 {
                 lines.push(
-                    rust_i18n::t!("unsynced_and_more", count = journal.queue.len() - 10)
+                    rust_i18n::t!("unsynced_and_more", count = app.journal_items.len() - 10)
                         .to_string(),
                 );
                 break;
             }
-            let (verb, summary) = match action {
-                crate::journal::Action::Create(t) => (
-                    rust_i18n::t!("unsynced_action_create").to_string(),
-                    &t.summary,
-                ),
-                crate::journal::Action::Update(t) => (
-                    rust_i18n::t!("unsynced_action_update").to_string(),
-                    &t.summary,
-                ),
-                crate::journal::Action::Delete(t) => (
-                    rust_i18n::t!("calendar_action_deleted").to_string(),
-                    &t.summary,
-                ),
-                crate::journal::Action::Move(t, _) => (
-                    rust_i18n::t!("unsynced_action_move").to_string(),
-                    &t.summary,
-                ),
-            };
             let trunc_summary = if summary.chars().count() > 40 {
                 format!("{}...", summary.chars().take(37).collect::<String>())
             } else {
@@ -32,7 +32,7 @@ pub fn update(app: &mut GuiApp, message: Message) -> Task<Message> {
         Message::FontLoaded(_) => Task::none(),
         Message::Tick => Task::none(), // Just forces a view redraw
         Message::InitBackgroundWorker(_)
-        | Message::BackgroundSyncComplete(_)
+        | Message::BackgroundSyncComplete(_, _)
         | Message::BackgroundSyncFailed => network::handle(app, message),
 
         Message::JournalSaved => {
@@ -54,12 +54,20 @@ pub fn update(app: &mut GuiApp, message: Message) -> Task<Message> {
         | Message::OpenSettings
         | Message::CancelSettings
         | Message::ObSubmitOffline
+        | Message::TestConnection
+        | Message::ConnectionDiagnosed(_)
         | Message::AliasKeyInput(_)
         | Message::AliasValueInput(_)
         | Message::AddAlias
         | Message::EditAlias(_, _)
         | Message::CancelEditAlias
         | Message::RemoveAlias(_)
+        | Message::TemplateLabelInput(_)
+        | Message::TemplateSmartInput(_)
+        | Message::AddTemplate
+        | Message::EditTemplate(_)
+        | Message::CancelEditTemplate
+        | Message::RemoveTemplate(_)
         | Message::GoalKeyInput(_)
         | Message::GoalTargetInput(_)
         | Message::GoalTypeChanged(_)
@@ -75,25 +83,35 @@ pub fn update(app: &mut GuiApp, message: Message) -> Task<Message> {
         | Message::ObDefaultPriorityChanged(_)
         | Message::ObStartGraceChanged(_)
         | Message::ThemeChanged(_)
+        | Message::SetCustomThemePrimary(_)
+        | Message::SetCustomThemeBackground(_)
+        | Message::SetTagColorMode(_)
+        | Message::SetDateFormat(_)
+        | Message::SetTimeFormat(_)
         | Message::SetAutoReminders(_)
         | Message::SetDefaultReminderTime(_)
         | Message::SetSnoozeShort(_)
         | Message::SetSnoozeLong(_)
+        | Message::SetSnoozePresets(_)
         | Message::SetTrashRetention(_)
         | Message::SetDefaultDurationGoalMins(_)
         | Message::SetSessionsCountAsCompletions(_)
+        | Message::SetArchiveCompleted(_)
         | Message::SetAutoRefreshInterval(_)
         | Message::SetCreateEventsForTasks(_)
         | Message::SetDeleteEventsOnCompletion(_)
         | Message::DeleteAllCalendarEvents
         | Message::BackfillEventsComplete(_)
         | Message::ExportLocalIcs(_)
+        | Message::ExportBackup(_)
         | Message::ExportSaved(_)
         | Message::ImportLocalIcs(_)
         | Message::ImportCompleted(_)
         | Message::IcsFileLoaded(_)
         | Message::IcsImportDialogCalendarSelected(_)
         | Message::IcsImportDialogCancel
+        | Message::IcsImportDialogToggleConflictCopies(_)
+        | Message::RestoreBackup
         | Message::IcsImportDialogConfirm
         | Message::AddLocalCalendar
         | Message::DeleteLocalCalendar(_)
@@ -101,12 +119,22 @@ pub fn update(app: &mut GuiApp, message: Message) -> Task<Message> {
         | Message::OpenColorPicker(_, _)
         | Message::CancelColorPicker
         | Message::SubmitColorPicker(_)
+        | Message::CalendarColorSaved(_, _, _)
+        | Message::CalendarOverrideNameChanged(_, _)
+        | Message::ClearCalendarOverride(_)
         | Message::ToggleAdvancedSettings(_)
         | Message::SetMaxDoneRoots(_)
         | Message::SetMaxDoneSubtasks(_)
         | Message::SetShowPriorityNumbers(_)
+        | Message::SetCompactLayout(_)
         | Message::SetLanguage(_)
         | Message::SetStrikethroughCompleted(_)
+        | Message::SetMinimizeToTray(_)
+        | Message::QuickAddHotkeyInput(_)
+        | Message::ApplyQuickAddHotkey
+        | Message::SetConfirmDelete(_)
+        | Message::SetParentProgress(_)
+        | Message::SetShowParsePreview(_)
         | Message::TogglePinnedAction(_, _)
         | Message::SetShowQuickFilter(_)
         | Message::SetQuickFilterTerm(_)
@@ -116,11 +144,15 @@ pub fn update(app: &mut GuiApp, message: Message) -> Task<Message> {
         | Message::SetSortCollectionsBySize(_)
         | Message::SetLogLevel(_)
         | Message::SetSyncSettings(_)
+        | Message::SetLimitFetchToCutoff(_)
         | Message::AddRemoteCalendar
         | Message::RemoteCalendarNameChanged(_, _)
         | Message::SubmitRemoteCalendar(_)
         | Message::RemoteCalendarUpdated(_, _)
         | Message::RemoteCalendarCreated(_, _)
+        | Message::ToggleNewCalendarInput
+        | Message::NewCalendarInputChanged(_)
+        | Message::CreateCalendarFromSidebar
         | Message::MoveCalendar(_, _)
         | Message::SetShowInlineDescriptions(_) => settings::handle(app, message),
 
@@ -128,21 +160,38 @@ pub fn update(app: &mut GuiApp, message: Message) -> Task<Message> {
         | Message::DescriptionChanged(_)
         | Message::StartCreateWithDescription
         | Message::StartCreateChild(_)
+        | Message::ApplyTemplate(_)
         | Message::SubmitTask
         | Message::SaveAndSwitchEditor
         | Message::ToggleTask(_, _)
         | Message::EditTaskStart(_)
         | Message::CancelEdit
+        | Message::InlineEditStart(_)
+        | Message::InlineEditSelected
+        | Message::InlineEditChanged(_)
+        | Message::InlineEditCommit(_, _)
+        | Message::InlineEditCancel
         | Message::DeleteTask(_)
         | Message::ChangePriority(_, _)
         | Message::SetTaskStatus(_, _)
         | Message::YankTask(_)
         | Message::CopyToClipboard(_)
+        | Message::CopyViewAsMarkdown
+        | Message::RetryUnsynced(_)
+        | Message::DiscardUnsynced(_)
+        | Message::DropJournalAction(_, _)
+        | Message::CompactJournal
         | Message::ClearYank
         | Message::EscapePressed
         | Message::EscCaptured
         | Message::MakeChild(_)
         | Message::RemoveParent(_)
+        | Message::DragStart(_)
+        | Message::DragEnter(_)
+        | Message::DragLeave(_)
+        | Message::DropOnTask(_)
+        | Message::DropOnRoot
+        | Message::DragEnd
         | Message::RemoveDependency(_, _)
         | Message::RemoveRelatedTo(_, _)
         | Message::AddDependency(_)
@@ -152,8 +201,19 @@ pub fn update(app: &mut GuiApp, message: Message) -> Task<Message> {
         | Message::StartTask(_)
         | Message::PauseTask(_)
         | Message::StopTask(_)
+        | Message::SetProgress(_, _)
+        | Message::CompleteAllVisible
+        | Message::CompleteAllVisibleConfirm
+        | Message::CompleteAllVisibleCancel
+        | Message::ConfirmPendingAction(_)
+        | Message::CancelPendingAction
+        | Message::Undo
         | Message::SnoozeCustomInput(_)
         | Message::SnoozeCustomSubmit(_, _)
+        | Message::SnoozeAllAlarms(_)
+        | Message::DismissAllAlarms
+        | Message::SnoozeUntilInput(_)
+        | Message::SnoozeUntilSubmit(_, _)
         | Message::EditSelectedDescription
         | Message::PromoteSelected
         | Message::DemoteSelected
@@ -167,6 +227,11 @@ pub fn update(app: &mut GuiApp, message: Message) -> Task<Message> {
         | Message::KeyboardOpenLocations
         | Message::KeyboardOpenUrl
         | Message::DuplicateTask(_)
+        | Message::BulkTagInputChanged(_)
+        | Message::BulkTagRenameInputChanged(_)
+        | Message::BulkTagVisible(_)
+        | Message::RenameTagEverywhere
+        | Message::SkipOccurrence(_)
         | Message::DeleteTaskTree(_)
         | Message::ToggleActiveSelected
         | Message::StopSelected
@@ -192,6 +257,7 @@ pub fn update(app: &mut GuiApp, message: Message) -> Task<Message> {
         | Message::StartMoveTask(_)
         | Message::CancelMoveTask
         | Message::TogglePin(_)
+        | Message::ToggleWaiting(_)
         | Message::SetTreeCollapse(_, _)
         | Message::EditTaskTree(_)
         | Message::KeyboardEditTree => tasks::handle(app, message),
@@ -199,6 +265,7 @@ pub fn update(app: &mut GuiApp, message: Message) -> Task<Message> {
         Message::FocusInput
         | Message::FocusSearch
         | Message::DismissError
+        | Message::DismissSyncWarnings
         | Message::ToggleAllCalendars(_)
         | Message::ToggleCalendarVisibility(_, _)
         | Message::IsolateCalendar(_)
@@ -211,13 +278,20 @@ pub fn update(app: &mut GuiApp, message: Message) -> Task<Message> {
         | Message::CategoryMatchModeChanged(_)
         | Message::ToggleHideCompleted(_)
         | Message::ToggleHideFullyCompletedTags(_)
+        | Message::ToggleHideDeferred(_)
+        | Message::ToggleFuzzySearch(_)
         | Message::ToggleHideAliasesInSidebar(_)
         | Message::ToggleSortStandardByPriority(_)
         | Message::SetSortPreset(_)
+        | Message::SetSortMode(_)
+        | Message::SetAllDaySort(_)
+        | Message::SetAgendaGrouping(_)
+        | Message::SetSortUrgencyBoost(_)
         | Message::ToggleSortStandardByPriorityToggle
         | Message::SelectCalendar(_)
         | Message::ToggleCalendarDisabled(_, _)
         | Message::SearchChanged(_)
+        | Message::RecallSearch(_)
         | Message::ApplySearch(_)
         | Message::ClearSearch
         | Message::SetSearchTerm(_)
@@ -231,6 +305,8 @@ pub fn update(app: &mut GuiApp, message: Message) -> Task<Message> {
         | Message::WindowDragged
         | Message::MinimizeWindow
         | Message::CloseWindow
+        | Message::TrayCommand(_)
+        | Message::QuickAddHotkey
         | Message::ResizeStart(_)
         | Message::WindowResized(_)
         | Message::ApplyWindowResize(_)
@@ -263,6 +339,11 @@ pub fn update(app: &mut GuiApp, message: Message) -> Task<Message> {
         | Message::ZoomOut
         | Message::ZoomReset
         | Message::ToggleQuickFilter
+        | Message::ToggleFocusMode
+        | Message::ToggleInboxFilter
+        | Message::ToggleDueHeatmap
+        | Message::ShiftHeatmapMonth(_)
+        | Message::FilterDueDate(_)
         | Message::ToggleSidebar
         | Message::ToggleTagCollapse(_)
         | Message::ToggleLocationCollapse(_)
@@ -275,6 +356,7 @@ pub fn update(app: &mut GuiApp, message: Message) -> Task<Message> {
         | Message::OpenWikiLink(_) => view::handle(app, message),
 
         Message::Refresh
+        | Message::AutoRefresh
         | Message::Loaded(_)
         | Message::RefreshedAll(_)
         | Message::TasksRefreshed(_)
@@ -329,6 +411,8 @@ pub fn update(app: &mut GuiApp, message: Message) -> Task<Message> {
                                         acknowledged: None,
                                         related_to_uid: None,
                                         relation_type: None,
+                                        is_default: false,
+                                        extra_lines: Vec::new(),
                                     }
                                 };
                             app.ringing_tasks.push((task.clone(), alarm_obj));
@@ -452,5 +536,7 @@ pub fn update(app: &mut GuiApp, message: Message) -> Task<Message> {
     app.search_placeholder = rust_i18n::t!("search_placeholder").to_string();
     app.notes_placeholder = rust_i18n::t!("notes_placeholder").to_string();
 
+    common::refresh_parse_preview(app);
+
     task
 }
@@ -13,6 +13,16 @@ use iced::Task;
 
 pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
     match message {
+        Message::AutoRefresh => {
+            if app.loading {
+                return Task::none();
+            }
+            if app.auto_refresh_skips > 0 {
+                app.auto_refresh_skips -= 1;
+                return Task::none();
+            }
+            handle(app, Message::Refresh)
+        }
         Message::Refresh => {
             if app.loading {
                 return Task::none();
@@ -78,10 +88,16 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             }
             Task::none()
         }
-        Message::BackgroundSyncComplete(synced_tasks) => {
+        Message::BackgroundSyncComplete(synced_tasks, warnings) => {
             app.last_sync_failed = false;
+            record_sync_result(app, true);
             crate::gui::update::common::update_journal_state(app);
 
+            // Keep the latest non-empty batch on screen until the user dismisses it
+            if !warnings.is_empty() {
+                app.sync_warnings = warnings;
+            }
+
             // The TaskController updated the shared TaskStore in the background.
             // We only need to trigger a heavy UI rebuild if a completely new task
             // was introduced (like a Conflict Copy), otherwise ETags updating in the
@@ -97,6 +113,7 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
         }
         Message::BackgroundSyncFailed => {
             app.last_sync_failed = true;
+            record_sync_result(app, false);
             crate::gui::update::common::update_journal_state(app);
             Task::none()
         }
@@ -137,6 +154,7 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             }
 
             app.calendars = cals.clone();
+            app.core_config.apply_calendar_overrides(&mut app.calendars);
             app.sort_calendars();
 
             app.store.clear();
@@ -194,8 +212,10 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
 
             let cfg = &app.core_config;
             app.hide_completed = cfg.hide_completed;
+            app.hide_deferred = cfg.hide_deferred;
             app.hide_fully_completed_tags = cfg.hide_fully_completed_tags;
             app.tag_aliases = cfg.tag_aliases.clone();
+            app.alias_problems = cfg.alias_problems.clone();
             app.disabled_calendars = cfg.disabled_calendars.iter().cloned().collect();
 
             app.state = AppState::Active;
@@ -226,6 +246,7 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             log::error!("Connection Failed: {}", e);
             app.error_msg = Some(rust_i18n::t!("connection_failed", error = e).to_string());
             app.last_sync_failed = true;
+            record_sync_result(app, false);
             crate::gui::update::common::update_journal_state(app);
 
             if let Some(tx) = &app.alarm_tx {
@@ -238,6 +259,7 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
                     app.state = AppState::Active;
                     let cals = Cache::load_calendars(app.ctx.as_ref()).unwrap_or_default();
                     app.calendars = cals;
+                    app.core_config.apply_calendar_overrides(&mut app.calendars);
                     app.sort_calendars();
                     app.store.clear();
                     for cal in &app.calendars {
@@ -279,6 +301,7 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             }
 
             app.last_sync_failed = false;
+            record_sync_result(app, true);
             refresh_filtered_tasks(app);
             app.loading = false;
 
@@ -293,6 +316,7 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             log::error!("Sync warning (RefreshedAll): {}", e);
             app.error_msg = Some(rust_i18n::t!("sync_warning", msg = e).to_string());
             app.last_sync_failed = true;
+            record_sync_result(app, false);
             app.loading = false;
             Task::none()
         }
@@ -340,3 +364,16 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
         _ => Task::none(),
     }
 }
+
+/// Tracks sync outcomes for the header timestamp and auto-refresh backoff.
+/// Each consecutive failure doubles the number of skipped ticks (1, 3, 7, 15).
+fn record_sync_result(app: &mut GuiApp, ok: bool) {
+    if ok {
+        app.last_sync_at = Some(chrono::Local::now());
+        app.sync_failure_streak = 0;
+        app.auto_refresh_skips = 0;
+    } else {
+        app.sync_failure_streak = app.sync_failure_streak.saturating_add(1);
+        app.auto_refresh_skips = (1u32 << app.sync_failure_streak.min(4)) - 1;
+    }
+}
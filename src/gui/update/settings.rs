@@ -6,7 +6,7 @@ use crate::cache::Cache;
 
 use crate::gui::async_ops::*;
 use crate::gui::message::Message;
-use crate::gui::state::{AppState, GuiApp};
+use crate::gui::state::{AppState, GuiApp, OVERRIDE_PICKER_PREFIX};
 use crate::gui::update::common::{apply_alias_retroactively, refresh_filtered_tasks, save_config};
 use crate::model::parser::{format_duration_compact, parse_duration, validate_alias_integrity};
 use crate::storage::{LOCAL_CALENDAR_HREF, LocalCalendarRegistry, LocalStorage};
@@ -18,15 +18,20 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             let config = *config_box;
             app.core_config = config.clone();
             app.ob_password_visible = false;
+            if config.minimize_to_tray {
+                app.tray_active = crate::gui::tray::install();
+            }
+            app.ob_quick_add_hotkey_input = config.quick_add_hotkey.clone().unwrap_or_default();
+            if let Err(e) = crate::gui::hotkey::register(config.quick_add_hotkey.as_deref()) {
+                log::warn!("Quick-add hotkey not registered: {}", e);
+                app.error_msg =
+                    Some(rust_i18n::t!("quick_add_hotkey_error", error = e).to_string());
+            }
             let locals = LocalCalendarRegistry::load(app.ctx.as_ref()).unwrap_or_default();
             app.local_cals_editing = locals.clone();
 
-            app.remote_cals_editing = app
-                .calendars
-                .iter()
-                .filter(|c| !c.href.starts_with("local://"))
-                .cloned()
-                .collect();
+            app.remote_cals_editing = app.remote_calendars_for_editing();
+            app.remote_cals_saved = app.remote_cals_editing.clone();
 
             app.hidden_calendars = config.hidden_calendars.clone().into_iter().collect();
             app.disabled_calendars = config.disabled_calendars.clone().into_iter().collect();
@@ -37,18 +42,29 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             };
             app.ob_insecure = config.allow_insecure_certs;
             app.tag_aliases = config.tag_aliases.clone();
+            app.alias_problems = config.alias_problems.clone();
+            app.quick_templates = config.quick_templates.clone();
             app.hide_completed = config.hide_completed;
+            app.hide_deferred = config.hide_deferred;
             app.hide_fully_completed_tags = config.hide_fully_completed_tags;
             app.hide_aliases_in_sidebar = config.hide_aliases_in_sidebar;
             app.show_inline_descriptions = config.show_inline_descriptions;
             app.sort_standard_by_priority = config.sort_standard_by_priority;
             app.sort_preset = config.sort_preset;
+            app.sort_mode = config.sort_mode;
+            app.sort_urgency_boost = config.sort_urgency_boost;
             app.ui_scale = config.ui_scale;
             app.show_priority_numbers = config.show_priority_numbers;
+            app.compact_layout = config.compact_layout;
             app.sync_settings = config.sync_settings;
+            app.limit_fetch_to_cutoff = config.limit_fetch_to_cutoff;
             app.current_theme = config.theme;
+            app.custom_theme_primary = config.custom_theme_primary.clone();
+            app.custom_theme_background = config.custom_theme_background.clone();
+            app.tag_color_mode = config.tag_color_mode;
 
             app.quick_filter_term = config.quick_filter_term.clone();
+            app.search_history = config.search_history.clone();
             app.quick_filter_icon = config.quick_filter_icon.clone();
             app.show_quick_filter = config.show_quick_filter;
             app.show_goals_tab = config.show_goals_tab;
@@ -87,6 +103,8 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
 
             app.ob_snooze_short_input = format_duration_compact(config.snooze_short_mins);
             app.ob_snooze_long_input = format_duration_compact(config.snooze_long_mins);
+            app.snooze_presets = config.snooze_presets.clone();
+            app.ob_snooze_presets_input = format_snooze_presets(&config.snooze_presets);
             app.ob_auto_refresh_input = format_duration_compact(config.auto_refresh_interval_mins);
             app.ob_trash_retention_input = config.trash_retention_days.to_string();
 
@@ -112,6 +130,7 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
                 }
             }
             app.calendars = cached_cals;
+            app.core_config.apply_calendar_overrides(&mut app.calendars);
             app.sort_calendars();
 
             app.store.clear();
@@ -172,18 +191,29 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             app.disabled_calendars = config.disabled_calendars.clone().into_iter().collect();
             app.sort_cutoff_days = config.sort_cutoff_days;
             app.tag_aliases = config.tag_aliases.clone();
+            app.alias_problems = config.alias_problems.clone();
+            app.quick_templates = config.quick_templates.clone();
             app.hide_completed = config.hide_completed;
+            app.hide_deferred = config.hide_deferred;
             app.hide_fully_completed_tags = config.hide_fully_completed_tags;
             app.hide_aliases_in_sidebar = config.hide_aliases_in_sidebar;
             app.show_inline_descriptions = config.show_inline_descriptions;
             app.sort_standard_by_priority = config.sort_standard_by_priority;
             app.sort_preset = config.sort_preset;
+            app.sort_mode = config.sort_mode;
+            app.sort_urgency_boost = config.sort_urgency_boost;
             app.ui_scale = config.ui_scale;
             app.show_priority_numbers = config.show_priority_numbers;
+            app.compact_layout = config.compact_layout;
             app.sync_settings = config.sync_settings;
+            app.limit_fetch_to_cutoff = config.limit_fetch_to_cutoff;
             app.current_theme = config.theme;
+            app.custom_theme_primary = config.custom_theme_primary.clone();
+            app.custom_theme_background = config.custom_theme_background.clone();
+            app.tag_color_mode = config.tag_color_mode;
 
             app.quick_filter_term = config.quick_filter_term.clone();
+            app.search_history = config.search_history.clone();
             app.quick_filter_icon = config.quick_filter_icon.clone();
             app.show_quick_filter = config.show_quick_filter;
             app.show_goals_tab = config.show_goals_tab;
@@ -226,6 +256,8 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
                 crate::model::parser::format_duration_compact(config.snooze_short_mins);
             app.ob_snooze_long_input =
                 crate::model::parser::format_duration_compact(config.snooze_long_mins);
+            app.snooze_presets = config.snooze_presets.clone();
+            app.ob_snooze_presets_input = format_snooze_presets(&config.snooze_presets);
             app.ob_auto_refresh_input =
                 crate::model::parser::format_duration_compact(config.auto_refresh_interval_mins);
             app.ob_trash_retention_input = config.trash_retention_days.to_string();
@@ -280,6 +312,36 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             app.ob_insecure = val;
             Task::none()
         }
+        Message::TestConnection => {
+            app.connection_diagnostics = Some(Vec::new());
+            match crate::client::RustyClient::new(
+                app.ctx.clone(),
+                &app.ob_url,
+                &app.ob_user,
+                &app.ob_pass,
+                app.ob_insecure,
+                Some("GUI"),
+            ) {
+                Ok(client) => Task::perform(
+                    async move { client.diagnose().await },
+                    Message::ConnectionDiagnosed,
+                ),
+                Err(e) => {
+                    app.connection_diagnostics = Some(vec![crate::client::core::DiagnosticStep {
+                        step: "principal".to_string(),
+                        ok: false,
+                        status: None,
+                        detail: e.to_string(),
+                        elapsed_ms: 0,
+                    }]);
+                    Task::none()
+                }
+            }
+        }
+        Message::ConnectionDiagnosed(steps) => {
+            app.connection_diagnostics = Some(steps);
+            Task::none()
+        }
         Message::SetTlsClientCertPath(val) => {
             app.ob_tls_client_cert_path = val;
             Task::none()
@@ -293,6 +355,31 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             save_config(app);
             Task::none()
         }
+        Message::SetCustomThemePrimary(val) => {
+            app.custom_theme_primary = val;
+            save_config(app);
+            Task::none()
+        }
+        Message::SetCustomThemeBackground(val) => {
+            app.custom_theme_background = val;
+            save_config(app);
+            Task::none()
+        }
+        Message::SetTagColorMode(mode) => {
+            app.tag_color_mode = mode;
+            save_config(app);
+            Task::none()
+        }
+        Message::SetDateFormat(fmt) => {
+            app.core_config.date_format = fmt;
+            save_config(app);
+            Task::none()
+        }
+        Message::SetTimeFormat(fmt) => {
+            app.core_config.time_format = fmt;
+            save_config(app);
+            Task::none()
+        }
         Message::ObSubmit => {
             app.ob_password_visible = false;
             app.calendars.retain(|c| !c.href.starts_with("local://"));
@@ -318,16 +405,13 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
         Message::OpenSettings => {
             let cfg = &app.core_config;
             app.ob_password_visible = false;
+            app.connection_diagnostics = None;
             app.ob_url = cfg.url.clone();
             app.ob_user = cfg.username.clone();
             // app.ob_pass is already securely held in memory from startup
 
-            app.remote_cals_editing = app
-                .calendars
-                .iter()
-                .filter(|c| !c.href.starts_with("local://"))
-                .cloned()
-                .collect();
+            app.remote_cals_editing = app.remote_calendars_for_editing();
+            app.remote_cals_saved = app.remote_cals_editing.clone();
 
             let mut target_href = cfg.default_calendar.clone();
             if let Some(ref def) = target_href
@@ -340,6 +424,7 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             }
             app.ob_default_cal = target_href;
             app.hide_completed = cfg.hide_completed;
+            app.hide_deferred = cfg.hide_deferred;
             app.hide_fully_completed_tags = cfg.hide_fully_completed_tags;
             app.hide_aliases_in_sidebar = cfg.hide_aliases_in_sidebar;
             app.ob_insecure = cfg.allow_insecure_certs;
@@ -347,8 +432,13 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             app.ob_tls_client_key_path = cfg.tls_client_key_path.clone().unwrap_or_default();
             app.hidden_calendars = cfg.hidden_calendars.iter().cloned().collect();
             app.tag_aliases = cfg.tag_aliases.clone();
+            app.alias_problems = cfg.alias_problems.clone();
+            app.quick_templates = cfg.quick_templates.clone();
             app.sort_cutoff_days = cfg.sort_cutoff_days;
             app.current_theme = cfg.theme;
+            app.custom_theme_primary = cfg.custom_theme_primary.clone();
+            app.custom_theme_background = cfg.custom_theme_background.clone();
+            app.tag_color_mode = cfg.tag_color_mode;
             app.ob_sort_days_input = match cfg.sort_cutoff_days {
                 Some(d) => d.to_string(),
                 None => "".to_string(),
@@ -497,6 +587,64 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             save_config(app);
             Task::none()
         }
+        Message::TemplateLabelInput(v) => {
+            app.template_input_label = v;
+            Task::none()
+        }
+        Message::TemplateSmartInput(v) => {
+            app.template_input_smart = v;
+            Task::none()
+        }
+        Message::EditTemplate(idx) => {
+            if let Some(tpl) = app.quick_templates.get(idx) {
+                app.template_input_label = tpl.label.clone();
+                app.template_input_smart = tpl.smart.clone();
+                app.editing_template_idx = Some(idx);
+            }
+            Task::none()
+        }
+        Message::CancelEditTemplate => {
+            app.editing_template_idx = None;
+            app.template_input_label.clear();
+            app.template_input_smart.clear();
+            Task::none()
+        }
+        Message::AddTemplate => {
+            let smart = app.template_input_smart.trim().to_string();
+            if smart.is_empty() {
+                return Task::none();
+            }
+            // Without a label the button shows the smart string itself
+            let label = match app.template_input_label.trim() {
+                "" => smart.clone(),
+                l => l.to_string(),
+            };
+            let tpl = crate::config::QuickTemplate { label, smart };
+            match app
+                .editing_template_idx
+                .take()
+                .filter(|&i| i < app.quick_templates.len())
+            {
+                Some(idx) => app.quick_templates[idx] = tpl,
+                None => app.quick_templates.push(tpl),
+            }
+            app.template_input_label.clear();
+            app.template_input_smart.clear();
+            save_config(app);
+            Task::none()
+        }
+        Message::RemoveTemplate(idx) => {
+            if idx < app.quick_templates.len() {
+                app.quick_templates.remove(idx);
+                if app.editing_template_idx == Some(idx) {
+                    app.editing_template_idx = None;
+                    app.template_input_label.clear();
+                    app.template_input_smart.clear();
+                }
+                save_config(app);
+            }
+            Task::none()
+        }
         Message::GoalKeyInput(v) => {
             app.goal_input_key = v;
             Task::none()
@@ -701,6 +849,23 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             }
             Task::none()
         }
+        Message::SetSnoozePresets(val) => {
+            app.ob_snooze_presets_input = val.clone();
+            // Only commit once every entry parses, so half-typed input doesn't drop presets
+            let parsed: Option<Vec<u32>> = val
+                .split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(|s| parse_duration(s).filter(|m| *m > 0))
+                .collect();
+            if let Some(mut presets) = parsed {
+                let mut seen = std::collections::HashSet::new();
+                presets.retain(|m| seen.insert(*m));
+                app.snooze_presets = presets;
+                save_config(app);
+            }
+            Task::none()
+        }
         Message::SetTrashRetention(val) => {
             if val.is_empty() || val.chars().all(|c| c.is_numeric()) {
                 app.ob_trash_retention_input = val.clone();
@@ -729,6 +894,12 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             crate::gui::update::common::refresh_filtered_tasks(app);
             Task::none()
         }
+        Message::SetArchiveCompleted(val) => {
+            app.core_config.archive_completed = val;
+            save_config(app);
+            crate::gui::update::common::refresh_filtered_tasks(app);
+            Task::none()
+        }
         Message::SetAutoRefreshInterval(val) => {
             app.ob_auto_refresh_input = val.clone();
             if let Some(n) = parse_duration(&val) {
@@ -792,6 +963,11 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             refresh_filtered_tasks(app);
             Task::none()
         }
+        Message::SetCompactLayout(val) => {
+            app.compact_layout = val;
+            save_config(app);
+            Task::none()
+        }
         Message::SetShowInlineDescriptions(val) => {
             app.show_inline_descriptions = val;
             save_config(app);
@@ -802,6 +978,11 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             save_config(app);
             Task::none()
         }
+        Message::SetLimitFetchToCutoff(val) => {
+            app.limit_fetch_to_cutoff = val;
+            save_config(app);
+            Task::none()
+        }
         Message::MoveCalendar(href, direction) => {
             let mut current_order = app.core_config.collection_order.clone();
             for cal in &app.calendars {
@@ -878,6 +1059,48 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             save_config(app);
             Task::none()
         }
+        Message::SetMinimizeToTray(val) => {
+            app.core_config.minimize_to_tray = val;
+            app.tray_active = val && crate::gui::tray::install();
+            if val && !app.tray_active {
+                app.error_msg = Some(rust_i18n::t!("tray_unavailable").to_string());
+            }
+            save_config(app);
+            Task::none()
+        }
+        Message::QuickAddHotkeyInput(val) => {
+            app.ob_quick_add_hotkey_input = val;
+            Task::none()
+        }
+        Message::ApplyQuickAddHotkey => {
+            let spec = app.ob_quick_add_hotkey_input.trim().to_string();
+            match crate::gui::hotkey::register(Some(&spec)) {
+                Ok(()) => {
+                    app.core_config.quick_add_hotkey = (!spec.is_empty()).then_some(spec);
+                    save_config(app);
+                }
+                Err(e) => {
+                    app.error_msg =
+                        Some(rust_i18n::t!("quick_add_hotkey_error", error = e).to_string());
+                }
+            }
+            Task::none()
+        }
+        Message::SetConfirmDelete(val) => {
+            app.core_config.confirm_delete = val;
+            save_config(app);
+            Task::none()
+        }
+        Message::SetParentProgress(val) => {
+            app.core_config.parent_progress = val;
+            save_config(app);
+            Task::none()
+        }
+        Message::SetShowParsePreview(val) => {
+            app.core_config.show_parse_preview = val;
+            save_config(app);
+            Task::none()
+        }
         Message::TogglePinnedAction(action, enabled) => {
             if enabled {
                 if !app.pinned_actions.contains(&action) {
@@ -1026,6 +1249,43 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
                 Message::ExportSaved,
             )
         }
+        Message::ExportBackup(format) => {
+            let content = app.store.export_all(format);
+            let ext = format.extension();
+            let filename = format!(
+                "cfait_backup_{}.{}",
+                chrono::Local::now().format("%Y-%m-%d"),
+                ext
+            );
+
+            Task::perform(
+                async move {
+                    let file_handle = rfd::AsyncFileDialog::new()
+                        .add_filter("Backup", &[ext])
+                        .set_file_name(&filename)
+                        .save_file()
+                        .await;
+
+                    if let Some(handle) = file_handle {
+                        let path = handle.path().to_path_buf();
+                        #[cfg(not(target_arch = "wasm32"))]
+                        {
+                            use tokio::io::AsyncWriteExt;
+                            let mut file = tokio::fs::File::create(&path)
+                                .await
+                                .map_err(|e| e.to_string())?;
+                            file.write_all(content.as_bytes())
+                                .await
+                                .map_err(|e| e.to_string())?;
+                        }
+                        Ok(path)
+                    } else {
+                        Err(rust_i18n::t!("export_cancelled").to_string())
+                    }
+                },
+                Message::ExportSaved,
+            )
+        }
         Message::ExportSaved(Ok(path)) => {
             app.error_msg = Some(
                 rust_i18n::t!(
@@ -1175,6 +1435,107 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             app.color_picker_active_href = None;
             Task::none()
         }
+        Message::SubmitColorPicker(color)
+            if app
+                .color_picker_active_href
+                .as_deref()
+                .is_some_and(|h| h.starts_with(OVERRIDE_PICKER_PREFIX)) =>
+        {
+            let key = app.color_picker_active_href.take().unwrap_or_default();
+            let href = &key[OVERRIDE_PICKER_PREFIX.len()..];
+            let hex = format!(
+                "#{:02X}{:02X}{:02X}",
+                (color.r * 255.0) as u8,
+                (color.g * 255.0) as u8,
+                (color.b * 255.0) as u8
+            );
+            if app
+                .core_config
+                .set_calendar_override(href, None, Some(Some(hex)))
+            {
+                save_config(app);
+                app.reapply_calendar_overrides();
+            }
+            Task::none()
+        }
+        Message::CalendarOverrideNameChanged(href, name) => {
+            if app
+                .core_config
+                .set_calendar_override(&href, Some(Some(name)), None)
+            {
+                save_config(app);
+                app.reapply_calendar_overrides();
+            }
+            Task::none()
+        }
+        Message::ClearCalendarOverride(href) => {
+            if app
+                .core_config
+                .set_calendar_override(&href, Some(None), Some(None))
+            {
+                save_config(app);
+                app.reapply_calendar_overrides();
+            }
+            Task::none()
+        }
+        Message::SubmitColorPicker(color) if app.state != AppState::Settings => {
+            // Sidebar picker: apply right away instead of waiting for the settings form.
+            let Some(href) = app.color_picker_active_href.take() else {
+                return Task::none();
+            };
+            let hex = format!(
+                "#{:02X}{:02X}{:02X}",
+                (color.r * 255.0) as u8,
+                (color.g * 255.0) as u8,
+                (color.b * 255.0) as u8
+            );
+            if let Some(cal) = app.calendars.iter_mut().find(|c| c.href == href) {
+                cal.color = Some(hex.clone());
+            }
+            if href.starts_with("local://") {
+                let mut locals = LocalCalendarRegistry::load(app.ctx.as_ref()).unwrap_or_default();
+                if let Some(cal) = locals.iter_mut().find(|c| c.href == href) {
+                    cal.color = Some(hex);
+                    let _ = LocalCalendarRegistry::save(app.ctx.as_ref(), &locals);
+                }
+                return Task::none();
+            }
+            match &app.client {
+                Some(client) => Task::perform(
+                    async_set_calendar_color_wrapper(
+                        client.clone(),
+                        href.clone(),
+                        Some(hex.clone()),
+                    ),
+                    move |res| {
+                        Message::CalendarColorSaved(
+                            href.clone(),
+                            hex.clone(),
+                            res.map_err(|e| e.to_string()),
+                        )
+                    },
+                ),
+                // Offline: keep the color locally rather than dropping the change.
+                None => Task::done(Message::CalendarColorSaved(href, hex, Ok(false))),
+            }
+        }
+        Message::CalendarColorSaved(href, hex, Ok(stored)) => {
+            let local_color = (!stored).then_some(hex);
+            if !stored {
+                app.error_msg = Some(rust_i18n::t!("calendar_color_local_only").to_string());
+            }
+            if app
+                .core_config
+                .set_calendar_override(&href, None, Some(local_color))
+            {
+                save_config(app);
+            }
+            Task::none()
+        }
+        Message::CalendarColorSaved(_, _, Err(e)) => {
+            app.error_msg = Some(e);
+            Task::none()
+        }
         Message::SubmitColorPicker(color) => {
             if let Some(active_href) = &app.color_picker_active_href.clone() {
                 if let Some(cal) = app
@@ -1221,6 +1582,36 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             app.remote_cals_editing.push(new_cal);
             Task::none()
         }
+        Message::ToggleNewCalendarInput => {
+            app.new_calendar_input = match app.new_calendar_input {
+                Some(_) => None,
+                None => Some(String::new()),
+            };
+            Task::none()
+        }
+        Message::NewCalendarInputChanged(name) => {
+            app.new_calendar_input = Some(name);
+            Task::none()
+        }
+        Message::CreateCalendarFromSidebar => {
+            let Some(name) = app
+                .new_calendar_input
+                .take()
+                .map(|n| n.trim().to_string())
+                .filter(|n| !n.is_empty())
+            else {
+                return Task::none();
+            };
+            // Reuse the settings flow so creation, caching and refresh stay in one place.
+            let href = format!("new_remote_{}", uuid::Uuid::new_v4());
+            app.remote_cals_editing
+                .push(crate::model::CalendarListEntry {
+                    name,
+                    href: href.clone(),
+                    color: None,
+                });
+            Task::done(Message::SubmitRemoteCalendar(href))
+        }
         Message::RemoteCalendarNameChanged(href, name) => {
             if let Some(cal) = app.remote_cals_editing.iter_mut().find(|c| c.href == href) {
                 cal.name = name;
@@ -1270,8 +1661,12 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             {
                 cal.href = new_href.clone();
                 app.calendars.push(cal.clone()); // Optimistic update
+                app.remote_cals_saved.push(cal.clone());
             }
             app.sort_calendars();
+            // Make the new collection the write target straight away.
+            app.active_cal_href = Some(new_href.clone());
+            app.hidden_calendars.remove(&new_href);
             Task::perform(async { Ok::<(), String>(()) }, |_| Message::Refresh)
         }
         Message::RemoteCalendarUpdated(href, Ok(_)) => {
@@ -1283,6 +1678,19 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
                 main_cal.name = cal.name.clone();
                 main_cal.color = cal.color.clone();
             }
+            if let Some(saved) = app.remote_cals_saved.iter_mut().find(|c| c.href == href)
+                && let Some(cal) = app.remote_cals_editing.iter().find(|c| c.href == href)
+            {
+                *saved = cal.clone();
+            }
+            // The server now holds the color chosen in settings; drop any local fallback color.
+            if app
+                .core_config
+                .set_calendar_override(&href, None, Some(None))
+            {
+                save_config(app);
+            }
+            app.core_config.apply_calendar_overrides(&mut app.calendars);
             Task::perform(async { Ok::<(), String>(()) }, |_| Message::Refresh)
         }
         Message::RemoteCalendarCreated(_, Err(e)) | Message::RemoteCalendarUpdated(_, Err(e)) => {
@@ -1291,8 +1699,36 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             Task::none()
         }
 
+        Message::RestoreBackup => Task::perform(
+            async move {
+                if let Some(file) = rfd::AsyncFileDialog::new()
+                    .add_filter("Backup", &["ics", "json"])
+                    .pick_file()
+                    .await
+                {
+                    let name = file.file_name();
+                    String::from_utf8(file.read().await)
+                        .map(|content| (name, content))
+                        .map_err(|e| e.to_string())
+                } else {
+                    Err(rust_i18n::t!("import_cancelled").to_string())
+                }
+            },
+            |result| match result {
+                Err(e) if e == rust_i18n::t!("import_cancelled") => {
+                    Message::ImportCompleted(Err(e))
+                }
+                other => Message::IcsFileLoaded(other),
+            },
+        ),
         Message::IcsFileLoaded(Ok((file_path, content))) => {
-            let task_count = content.split("BEGIN:VTODO").count().saturating_sub(1);
+            let task_count = if content.trim_start().starts_with('[') {
+                serde_json::from_str::<Vec<serde_json::Value>>(&content)
+                    .map(|items| items.len())
+                    .unwrap_or(0)
+            } else {
+                content.split("BEGIN:VTODO").count().saturating_sub(1)
+            };
 
             app.ics_import_dialog_open = true;
             app.ics_import_file_path = Some(file_path);
@@ -1330,6 +1766,10 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             app.ics_import_task_count = None;
             Task::none()
         }
+        Message::IcsImportDialogToggleConflictCopies(enabled) => {
+            app.ics_import_conflict_copies = enabled;
+            Task::none()
+        }
         Message::IcsImportDialogConfirm => {
             if let Some(calendar_href) = &app.ics_import_selected_calendar.clone()
                 && let Some(ics_content) = &app.ics_import_content.clone()
@@ -1340,36 +1780,25 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
                 app.ics_import_selected_calendar = None;
                 app.ics_import_task_count = None;
 
-                let href = calendar_href.clone();
-                let content = ics_content.clone();
-                let ctx = app.ctx.clone();
-                return Task::perform(
-                    async move {
-                        match LocalStorage::import_from_ics(ctx.as_ref(), &href, &content) {
-                            Ok(count) => {
-                                let file_name = file_path
-                                    .as_ref()
-                                    .and_then(|p| std::path::Path::new(p).file_name())
-                                    .and_then(|n| n.to_str())
-                                    .unwrap_or("file");
-                                let msg = if count == 1 {
-                                    rust_i18n::t!("import_success_from_file.one", file = file_name)
-                                        .to_string()
-                                } else {
-                                    rust_i18n::t!(
-                                        "import_success_from_file.other",
-                                        count = count,
-                                        file = file_name
-                                    )
-                                    .to_string()
-                                };
-                                Ok(msg)
-                            }
-                            Err(e) => Err(format!("{}", rust_i18n::t!("import_failed", error = e))),
-                        }
-                    },
-                    Message::ImportCompleted,
-                );
+                let policy = if app.ics_import_conflict_copies {
+                    crate::storage::DuplicatePolicy::ConflictCopy
+                } else {
+                    crate::storage::DuplicatePolicy::Skip
+                };
+                let (actions, summary) = app.store.import_tasks(ics_content, calendar_href, policy);
+                refresh_filtered_tasks(app);
+                if !actions.is_empty()
+                    && let Some(tx) = &app.bg_tx
+                {
+                    let _ = tx.try_send(crate::gui::async_ops::WorkerCommand::Batch(actions));
+                }
+
+                let file_name = file_path
+                    .as_ref()
+                    .and_then(|p| std::path::Path::new(p).file_name())
+                    .and_then(|n| n.to_str())
+                    .unwrap_or("file");
+                app.error_msg = Some(format!("{}: {}", file_name, summary.message()));
             }
             Task::none()
         }
@@ -1377,3 +1806,12 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
         _ => Task::none(),
     }
 }
+
+/// Comma-separated compact durations for the snooze presets field (`5m, 15m, 1h, 1d`).
+fn format_snooze_presets(presets: &[u32]) -> String {
+    presets
+        .iter()
+        .map(|m| format_duration_compact(*m))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -4,7 +4,7 @@
 // DispatchIntent which routes to the TaskController.
 
 use crate::gui::message::Message;
-use crate::gui::state::{Focus, GuiApp, SidebarMode};
+use crate::gui::state::{Focus, GuiApp, PendingConfirm, SidebarMode};
 use crate::gui::subscription::ACTIVE_FOCUS;
 use crate::gui::update::common;
 use crate::model::AppIntent;
@@ -44,6 +44,12 @@ fn dispatch_and_maintain_selection(app: &mut GuiApp, intent: AppIntent, focus_ui
     }
 }
 
+fn delete_task_tree(app: &mut GuiApp, uid: String) {
+    app.yanked_uid = None;
+    app.yank_lock_active = false;
+    dispatch_and_maintain_selection(app, AppIntent::DeleteTaskTree { uid: uid.clone() }, &uid);
+}
+
 /// Dispatch an intent that re-sorts the focused task, then keep selection on the row below it instead of following that task.
 fn dispatch_and_select_next_row(app: &mut GuiApp, intent: AppIntent, uid: String) {
     let was_selected = app.selected_uid.as_ref() == Some(&uid);
@@ -74,6 +80,38 @@ fn dispatch_and_select_next_row(app: &mut GuiApp, intent: AppIntent, uid: String
     }
 }
 
+/// Apply one snooze/dismiss to every ringing alarm, sending a single update per changed task.
+fn acknowledge_ringing(
+    app: &mut GuiApp,
+    ringing: Vec<(crate::model::Task, crate::model::Alarm)>,
+    mut ack: impl FnMut(&mut crate::model::Task, &str) -> bool,
+) {
+    let mut changed: Vec<String> = Vec::new();
+    for (t, alarm) in &ringing {
+        if let Some((task, _)) = app.store.get_task_mut(&t.uid)
+            && ack(task, &alarm.uid)
+            && !changed.contains(&t.uid)
+        {
+            changed.push(t.uid.clone());
+        }
+    }
+    if changed.is_empty() {
+        return;
+    }
+
+    let mut actions = Vec::with_capacity(changed.len());
+    for uid in &changed {
+        if let Some((task, _)) = app.store.get_task_mut(uid) {
+            task.sequence += 1;
+            actions.push(crate::journal::Action::Update(task.clone()));
+        }
+    }
+    common::refresh_filtered_tasks(app);
+    if let Some(tx) = &app.bg_tx {
+        let _ = tx.try_send(crate::gui::async_ops::WorkerCommand::Batch(actions));
+    }
+}
+
 pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
     match message {
         Message::InputChanged(action) => {
@@ -163,6 +201,23 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             iced::widget::operation::focus(iced::widget::Id::new("main_input"))
         }
 
+        Message::ApplyTemplate(smart) => {
+            // Populate rather than submit, so the date or tags can still be adjusted
+            let mut value = smart;
+            if !value.ends_with(' ') {
+                value.push(' ');
+            }
+            app.input_value = text_editor::Content::with_text(&value);
+            app.input_value
+                .perform(text_editor::Action::Move(text_editor::Motion::DocumentEnd));
+
+            app.active_focus = Focus::AddTaskInput;
+            if let Ok(mut focus) = ACTIVE_FOCUS.write() {
+                *focus = Focus::AddTaskInput;
+            }
+            iced::widget::operation::focus(iced::widget::Id::new("main_input"))
+        }
+
         Message::StartCreateWithDescription => {
             app.creating_with_desc = true;
 
@@ -178,7 +233,14 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             }
         }
 
-        Message::SubmitTask => handle_submit(app),
+        Message::SubmitTask => {
+            let submitted = handle_submit(app);
+            if app.hide_after_quick_add && app.input_value.text().trim().is_empty() {
+                app.hide_after_quick_add = false;
+                return submitted.chain(Task::done(Message::CloseWindow));
+            }
+            submitted
+        }
 
         Message::SaveAndSwitchEditor => {
             let to_tree = app.editing_tree_uid.clone();
@@ -264,6 +326,57 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             common::scroll_to_selected(app, true)
         }
 
+        Message::InlineEditStart(uid) => {
+            let Some(summary) = app.store.get_summary(&uid) else {
+                return Task::none();
+            };
+            app.selected_uid = Some(uid.clone());
+            app.inline_edit_uid = Some(uid);
+            app.inline_edit_value = summary;
+            // Keystrokes belong to the text field now, not to list navigation
+            app.active_focus = Focus::AddTaskInput;
+            if let Ok(mut focus) = ACTIVE_FOCUS.write() {
+                *focus = Focus::AddTaskInput;
+            }
+            iced::widget::operation::focus(iced::widget::Id::new("inline_edit_input"))
+        }
+        Message::InlineEditSelected => {
+            if let Some(uid) = app.selected_uid.clone() {
+                return handle(app, Message::InlineEditStart(uid));
+            }
+            Task::none()
+        }
+        Message::InlineEditChanged(value) => {
+            app.inline_edit_value = value;
+            Task::none()
+        }
+        Message::InlineEditCommit(uid, summary) => {
+            app.inline_edit_uid = None;
+            app.inline_edit_value.clear();
+            app.active_focus = Focus::MainList;
+            if let Ok(mut focus) = ACTIVE_FOCUS.write() {
+                *focus = Focus::MainList;
+            }
+            dispatch_and_maintain_selection(
+                app,
+                AppIntent::RenameTask {
+                    uid: uid.clone(),
+                    summary,
+                },
+                &uid,
+            );
+            common::scroll_to_selected(app, false)
+        }
+        Message::InlineEditCancel => {
+            app.inline_edit_uid = None;
+            app.inline_edit_value.clear();
+            app.active_focus = Focus::MainList;
+            if let Ok(mut focus) = ACTIVE_FOCUS.write() {
+                *focus = Focus::MainList;
+            }
+            common::scroll_to_selected(app, false)
+        }
+
         Message::ToggleTaskShift(uid) => {
             dispatch_and_select_next_row(app, AppIntent::ToggleTaskShift { uid: uid.clone() }, uid);
             Task::none()
@@ -348,11 +461,15 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
         Message::DeleteTask(index) => {
             if let Some(uid) = app.get_task_at_index(index).map(|t| t.uid.clone()) {
                 app.selected_uid = Some(uid.clone());
-                dispatch_and_maintain_selection(
-                    app,
-                    AppIntent::DeleteTask { uid: uid.clone() },
-                    &uid,
-                );
+                if app.core_config.confirm_delete {
+                    app.pending_confirm = Some(PendingConfirm::DeleteTask(uid));
+                } else {
+                    dispatch_and_maintain_selection(
+                        app,
+                        AppIntent::DeleteTask { uid: uid.clone() },
+                        &uid,
+                    );
+                }
             }
             Task::none()
         }
@@ -437,7 +554,11 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             if let Some(blocker_uid) = app.yanked_uid.clone()
                 && let Some(uid) = app.selected_uid.clone()
             {
-                common::dispatch_intent(app, AppIntent::AddDependency { uid, blocker_uid });
+                if app.store.would_create_dependency_cycle(&uid, &blocker_uid) {
+                    app.error_msg = Some(rust_i18n::t!("error_dependency_cycle").to_string());
+                } else {
+                    common::dispatch_intent(app, AppIntent::AddDependency { uid, blocker_uid });
+                }
             }
             Task::none()
         }
@@ -512,10 +633,105 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             Task::none()
         }
 
+        Message::SkipOccurrence(uid) => {
+            app.selected_uid = Some(uid.clone());
+            common::dispatch_intent(app, AppIntent::SkipOccurrence { uid });
+            Task::none()
+        }
+
         Message::DuplicateTask(uid) => {
             app.yanked_uid = None;
             app.yank_lock_active = false;
-            common::dispatch_intent(app, AppIntent::DuplicateTaskTree { uid });
+            let created = common::dispatch_intent_collecting_created(
+                app,
+                AppIntent::DuplicateTaskTree { uid },
+            );
+            // Open the copy for editing straight away
+            if let Some(new_uid) = created.first() {
+                app.selected_uid = Some(new_uid.clone());
+                if let Some(idx) = app.find_task_index_by_uid(new_uid) {
+                    return handle(app, Message::EditTaskStart(idx));
+                }
+            }
+            Task::none()
+        }
+
+        Message::BulkTagInputChanged(val) => {
+            app.bulk_tag_input = val;
+            Task::none()
+        }
+
+        Message::BulkTagRenameInputChanged(val) => {
+            app.bulk_tag_rename_input = val;
+            Task::none()
+        }
+
+        Message::BulkTagVisible(add) => {
+            let tag = clean_tag_input(&app.bulk_tag_input);
+            if tag.is_empty() {
+                return Task::none();
+            }
+            let uids: Vec<String> = app
+                .tasks
+                .iter()
+                .filter_map(|item| match item {
+                    crate::store::TaskListItem::Task(t) if t.categories.contains(&tag) != add => {
+                        Some(t.uid.clone())
+                    }
+                    _ => None,
+                })
+                .collect();
+            if uids.is_empty() {
+                return Task::none();
+            }
+            if app.core_config.confirm_delete {
+                app.pending_confirm = Some(PendingConfirm::BulkTag { uids, tag, add });
+            } else {
+                common::dispatch_intent(app, AppIntent::SetTagOnTasks { uids, tag, add });
+            }
+            Task::none()
+        }
+
+        Message::RenameTagEverywhere => {
+            let from = clean_tag_input(&app.bulk_tag_input);
+            let to = clean_tag_input(&app.bulk_tag_rename_input);
+            if from.is_empty() || to.is_empty() || from == to {
+                return Task::none();
+            }
+            let (modified, alias_count) = app.store.rename_tag_everywhere_with_undo(
+                &mut app.tag_aliases,
+                &from,
+                &to,
+                &mut app.undo_history,
+            );
+            if alias_count > 0 {
+                common::save_config(app);
+            }
+            for cat in app.session.selected_categories.iter_mut() {
+                if let Some(renamed) = crate::model::parser::rename_tag_path(cat, &from, &to) {
+                    *cat = renamed;
+                }
+            }
+            let mut seen = std::collections::HashSet::new();
+            app.session
+                .selected_categories
+                .retain(|cat| seen.insert(cat.clone()));
+            app.error_msg = Some(
+                rust_i18n::t!("tag_renamed", tasks = modified.len(), aliases = alias_count)
+                    .to_string(),
+            );
+            common::refresh_filtered_tasks(app);
+            if !modified.is_empty()
+                && let Some(tx) = &app.bg_tx
+            {
+                let actions = modified
+                    .into_iter()
+                    .map(crate::journal::Action::Update)
+                    .collect();
+                let _ = tx.try_send(crate::gui::async_ops::WorkerCommand::Batch(actions));
+            }
+            app.bulk_tag_input = to;
+            app.bulk_tag_rename_input.clear();
             Task::none()
         }
 
@@ -551,13 +767,11 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
         }
 
         Message::DeleteTaskTree(uid) => {
-            app.yanked_uid = None;
-            app.yank_lock_active = false;
-            dispatch_and_maintain_selection(
-                app,
-                AppIntent::DeleteTaskTree { uid: uid.clone() },
-                &uid,
-            );
+            if app.core_config.confirm_delete {
+                app.pending_confirm = Some(PendingConfirm::DeleteTaskTree(uid));
+                return Task::none();
+            }
+            delete_task_tree(app, uid);
             Task::none()
         }
 
@@ -653,6 +867,74 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             Task::none()
         }
 
+        Message::SetProgress(uid, percent) => {
+            common::dispatch_intent(app, AppIntent::SetProgress { uid, percent });
+            Task::none()
+        }
+
+        Message::CompleteAllVisible => {
+            let uids = app.store.completable_uids(&app.tasks);
+            app.complete_all_uids = (!uids.is_empty()).then_some(uids);
+            Task::none()
+        }
+
+        Message::CompleteAllVisibleConfirm => {
+            if let Some(uids) = app.complete_all_uids.take()
+                && !uids.is_empty()
+            {
+                common::dispatch_intent(app, AppIntent::CompleteTasks { uids });
+            }
+            Task::none()
+        }
+
+        Message::CompleteAllVisibleCancel => {
+            app.complete_all_uids = None;
+            Task::none()
+        }
+
+        Message::ConfirmPendingAction(dont_ask_again) => {
+            if dont_ask_again {
+                app.core_config.confirm_delete = false;
+                common::save_config(app);
+            }
+            match app.pending_confirm.take() {
+                Some(PendingConfirm::DeleteTask(uid)) => {
+                    dispatch_and_maintain_selection(
+                        app,
+                        AppIntent::DeleteTask { uid: uid.clone() },
+                        &uid,
+                    );
+                }
+                Some(PendingConfirm::DeleteTaskTree(uid)) => delete_task_tree(app, uid),
+                Some(PendingConfirm::BulkTag { uids, tag, add }) => {
+                    common::dispatch_intent(app, AppIntent::SetTagOnTasks { uids, tag, add });
+                }
+                None => {}
+            }
+            Task::none()
+        }
+
+        Message::CancelPendingAction => {
+            app.pending_confirm = None;
+            Task::none()
+        }
+
+        Message::Undo => {
+            let Some(entry) = app.undo_history.pop() else {
+                app.error_msg = Some(rust_i18n::t!("nothing_to_undo").to_string());
+                return Task::none();
+            };
+            let actions = app.store.undo(entry);
+            common::refresh_filtered_tasks(app);
+            app.error_msg = Some(rust_i18n::t!("undone").to_string());
+            if !actions.is_empty()
+                && let Some(tx) = &app.bg_tx
+            {
+                let _ = tx.try_send(crate::gui::async_ops::WorkerCommand::Batch(actions));
+            }
+            Task::none()
+        }
+
         Message::YankTask(uid) => {
             app.yanked_uid = Some(uid.clone());
             app.selected_uid = Some(uid.clone());
@@ -672,11 +954,56 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
 
         Message::CopyToClipboard(text) => Task::batch(vec![iced::clipboard::write(text)]),
 
+        Message::CopyViewAsMarkdown => {
+            let text = crate::model::extractor::serialize_view_checklist(&app.tasks);
+            Task::batch(vec![iced::clipboard::write(text)])
+        }
+
+        Message::RetryUnsynced(uid) => {
+            common::dispatch_intent(app, AppIntent::RetryUnsynced { uid });
+            if let Some(tx) = &app.bg_tx {
+                let _ = tx.try_send(crate::gui::async_ops::WorkerCommand::SyncNow);
+            }
+            Task::none()
+        }
+
+        Message::DropJournalAction(index, uid) => {
+            if let Err(e) = crate::journal::Journal::drop_action(app.ctx.as_ref(), index, &uid) {
+                app.error_msg = Some(e.to_string());
+            }
+            common::update_journal_state(app);
+            Task::none()
+        }
+
+        Message::CompactJournal => {
+            match crate::journal::Journal::compact_on_disk(app.ctx.as_ref()) {
+                Ok(merged) => {
+                    app.journal_compact_note =
+                        Some(rust_i18n::t!("sync_queue_compacted", count = merged).to_string());
+                }
+                Err(e) => app.error_msg = Some(e.to_string()),
+            }
+            common::update_journal_state(app);
+            Task::none()
+        }
+
+        Message::DiscardUnsynced(uid) => {
+            // Soft delete: the queued create is cancelled by journal compaction and the
+            // task stays recoverable from the trash.
+            common::dispatch_intent(app, AppIntent::DeleteTask { uid });
+            Task::none()
+        }
+
         Message::TogglePin(uid) => {
             common::dispatch_intent(app, AppIntent::TogglePin { uid });
             Task::none()
         }
 
+        Message::ToggleWaiting(uid) => {
+            common::dispatch_intent(app, AppIntent::ToggleWaiting { uid });
+            Task::none()
+        }
+
         Message::ClearYank => {
             app.yanked_uid = None;
             app.yank_lock_active = false;
@@ -684,6 +1011,9 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
         }
 
         Message::EscCaptured => {
+            if app.inline_edit_uid.is_some() {
+                return handle(app, Message::InlineEditCancel);
+            }
             app.active_focus = Focus::MainList;
             if let Ok(mut focus) = ACTIVE_FOCUS.write() {
                 *focus = Focus::MainList;
@@ -711,9 +1041,19 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             let mut needs_refresh = false;
             let mut captured_action = false;
 
-            if app.moving_task_uid.is_some() {
+            if app.inline_edit_uid.is_some() {
+                app.inline_edit_uid = None;
+                app.inline_edit_value.clear();
+                captured_action = true;
+            } else if app.moving_task_uid.is_some() {
                 app.moving_task_uid = None;
                 captured_action = true;
+            } else if app.complete_all_uids.is_some() {
+                app.complete_all_uids = None;
+                captured_action = true;
+            } else if app.pending_confirm.is_some() {
+                app.pending_confirm = None;
+                captured_action = true;
             } else if app.ics_import_dialog_open {
                 app.ics_import_dialog_open = false;
                 app.ics_import_file_path = None;
@@ -800,6 +1140,67 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             Task::none()
         }
 
+        Message::DragStart(uid) => {
+            app.dragging_uid = Some(uid);
+            app.drag_target_uid = None;
+            Task::none()
+        }
+        Message::DragEnter(uid) => {
+            if app.dragging_uid.is_some() {
+                app.drag_target_uid = Some(uid);
+            }
+            Task::none()
+        }
+        Message::DragLeave(uid) => {
+            if app.drag_target_uid.as_deref() == Some(uid.as_str()) {
+                app.drag_target_uid = None;
+            }
+            Task::none()
+        }
+        // A release publishes DropOnTask (row), then DropOnRoot (list), then DragEnd (window),
+        // so whichever runs first takes the dragged uid and the others become no-ops.
+        Message::DropOnTask(target_uid) => {
+            app.drag_target_uid = None;
+            if let Some(uid) = app.dragging_uid.take()
+                && app.store.can_set_parent(&uid, &target_uid)
+                && app
+                    .store
+                    .get_task_ref(&uid)
+                    .is_some_and(|t| t.parent_uid.as_deref() != Some(target_uid.as_str()))
+            {
+                dispatch_and_maintain_selection(
+                    app,
+                    AppIntent::MakeChild {
+                        uid: uid.clone(),
+                        parent_uid: target_uid,
+                    },
+                    &uid,
+                );
+            }
+            Task::none()
+        }
+        Message::DropOnRoot => {
+            app.drag_target_uid = None;
+            if let Some(uid) = app.dragging_uid.take()
+                && app
+                    .store
+                    .get_task_ref(&uid)
+                    .is_some_and(|t| t.parent_uid.is_some())
+            {
+                dispatch_and_maintain_selection(
+                    app,
+                    AppIntent::PromoteToRoot { uid: uid.clone() },
+                    &uid,
+                );
+            }
+            Task::none()
+        }
+        Message::DragEnd => {
+            app.dragging_uid = None;
+            app.drag_target_uid = None;
+            Task::none()
+        }
+
         Message::RemoveDependency(uid, blocker_uid) => {
             dispatch_and_maintain_selection(
                 app,
@@ -829,6 +1230,13 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
                 if !app.yank_lock_active {
                     app.yanked_uid = None;
                 }
+                if app
+                    .store
+                    .would_create_dependency_cycle(&target_uid, &blocker_uid)
+                {
+                    app.error_msg = Some(rust_i18n::t!("error_dependency_cycle").to_string());
+                    return Task::none();
+                }
                 dispatch_and_maintain_selection(
                     app,
                     AppIntent::AddDependency {
@@ -915,6 +1323,52 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             handle(app, Message::SnoozeAlarm(t_uid, a_uid, mins))
         }
 
+        Message::SnoozeUntilInput(val) => {
+            app.snooze_until_input = val;
+            Task::none()
+        }
+
+        Message::SnoozeUntilSubmit(t_uid, a_uid) => {
+            let default_time =
+                chrono::NaiveTime::parse_from_str(&app.default_reminder_time, "%H:%M")
+                    .unwrap_or_else(|_| chrono::NaiveTime::from_hms_opt(9, 0, 0).unwrap());
+            let Some(until) = crate::model::parser::parse_snooze_until(
+                &app.snooze_until_input,
+                default_time,
+                app.core_config.week_start,
+            ) else {
+                app.error_msg = Some(
+                    rust_i18n::t!("error_invalid_snooze_time", val = app.snooze_until_input)
+                        .to_string(),
+                );
+                return Task::none();
+            };
+            if until <= chrono::Utc::now() {
+                app.error_msg = Some(
+                    rust_i18n::t!("error_snooze_time_in_past", val = app.snooze_until_input)
+                        .to_string(),
+                );
+                return Task::none();
+            }
+
+            if let Some((task, _)) = app.store.get_task_mut(&t_uid)
+                && task.snooze_alarm_until(&a_uid, until)
+            {
+                task.sequence += 1;
+                let cloned = task.clone();
+                app.ringing_tasks
+                    .retain(|(t, a)| !(t.uid == t_uid && a.uid == a_uid));
+                app.snooze_until_input.clear();
+                common::refresh_filtered_tasks(app);
+                if let Some(tx) = &app.bg_tx {
+                    let _ = tx.try_send(crate::gui::async_ops::WorkerCommand::Batch(vec![
+                        crate::journal::Action::Update(cloned),
+                    ]));
+                }
+            }
+            Task::none()
+        }
+
         Message::CompleteTaskFromAlarm(t_uid, a_uid) => {
             app.ringing_tasks
                 .retain(|(t, a)| !(t.uid == t_uid && a.uid == a_uid));
@@ -981,6 +1435,18 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             Task::none()
         }
 
+        Message::SnoozeAllAlarms(mins) => {
+            let ringing = std::mem::take(&mut app.ringing_tasks);
+            acknowledge_ringing(app, ringing, |task, a_uid| task.handle_snooze(a_uid, mins));
+            Task::none()
+        }
+
+        Message::DismissAllAlarms => {
+            let ringing = std::mem::take(&mut app.ringing_tasks);
+            acknowledge_ringing(app, ringing, |task, a_uid| task.handle_dismiss(a_uid));
+            Task::none()
+        }
+
         Message::StartAddSession(uid) => {
             app.adding_session_uid = Some(uid.clone());
             app.editing_session_idx = None;
@@ -1126,6 +1592,11 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
     }
 }
 
+/// A tag typed into the batch tag fields, without its `#` or quotes.
+fn clean_tag_input(input: &str) -> String {
+    crate::model::parser::strip_quotes(input.trim().trim_start_matches('#'))
+}
+
 fn handle_submit(app: &mut GuiApp) -> Task<Message> {
     use crate::gui::update::common::{
         apply_alias_retroactively, refresh_filtered_tasks, save_config,
@@ -1195,6 +1666,15 @@ fn handle_submit(app: &mut GuiApp) -> Task<Message> {
         return Task::none();
     }
 
+    // Only a pasted block with several non-empty lines takes this path; single-line entry
+    // (including tag/location jumps below) is unchanged.
+    if app.editing_uid.is_none() && app.editing_tree_uid.is_none() {
+        let outline = crate::model::parse_input_outline(&clean_input);
+        if outline.len() > 1 {
+            return submit_outline(app, outline, retroactive_sync_batch);
+        }
+    }
+
     if clean_input.starts_with('#')
         && !clean_input.trim().contains(' ')
         && app.editing_uid.is_none()
@@ -1275,7 +1755,7 @@ fn handle_submit(app: &mut GuiApp) -> Task<Message> {
             &desc_text,
             &app.tag_aliases,
             config_time,
-            app.core_config.trash_retention_days,
+            &app.core_config,
             &app.calendars,
         ) {
             Ok(acts) => acts,
@@ -1310,7 +1790,13 @@ fn handle_submit(app: &mut GuiApp) -> Task<Message> {
             let old_href = task_ref.calendar_href.clone();
             let mut task = task_ref.clone();
             task.description = cleaned_desc;
-            task.apply_smart_input(&clean_input, &app.tag_aliases, config_time);
+            task.apply_smart_input(
+                &clean_input,
+                &app.tag_aliases,
+                config_time,
+                &app.core_config.holidays,
+                app.core_config.week_start,
+            );
 
             if let Err(e) = app.store.resolve_dependencies(&mut task) {
                 app.error_msg = Some(e);
@@ -1321,6 +1807,10 @@ fn handle_submit(app: &mut GuiApp) -> Task<Message> {
                 task.calendar_href =
                     crate::model::resolve_collection(&target, &app.calendars, &old_href);
             }
+            task.apply_default_reminder(
+                app.core_config
+                    .default_reminder_minutes_for(&task.calendar_href),
+            );
             let new_href = task.calendar_href.clone();
 
             task.sequence += 1;
@@ -1352,7 +1842,13 @@ fn handle_submit(app: &mut GuiApp) -> Task<Message> {
             );
 
             for ext in extracted_subtasks {
-                let mut sub = TodoTask::new(&ext.raw_text, &app.tag_aliases, config_time);
+                let mut sub = TodoTask::new_with_date_settings(
+                    &ext.raw_text,
+                    &app.tag_aliases,
+                    config_time,
+                    &app.core_config.holidays,
+                    app.core_config.week_start,
+                );
                 sub.uid = ext.uid;
 
                 let p_uid_str = ext.parent_uid.clone().unwrap_or_else(|| edit_uid.clone());
@@ -1430,7 +1926,13 @@ fn handle_submit(app: &mut GuiApp) -> Task<Message> {
             return Task::none();
         }
     } else if !clean_input.is_empty() {
-        let mut new_task = TodoTask::new(&clean_input, &app.tag_aliases, config_time);
+        let mut new_task = TodoTask::new_with_date_settings(
+            &clean_input,
+            &app.tag_aliases,
+            config_time,
+            &app.core_config.holidays,
+            app.core_config.week_start,
+        );
 
         if let Err(e) = app.store.resolve_dependencies(&mut new_task) {
             app.error_msg = Some(e);
@@ -1461,9 +1963,13 @@ fn handle_submit(app: &mut GuiApp) -> Task<Message> {
             }
         }
 
-        let target_href = app
-            .active_cal_href
-            .clone()
+        // A new child lives next to its parent, whichever calendar is active
+        let target_href = new_task
+            .parent_uid
+            .as_deref()
+            .and_then(|p| app.store.get_task_ref(p))
+            .map(|p| p.calendar_href.clone())
+            .or_else(|| app.active_cal_href.clone())
             .or_else(|| app.calendars.first().map(|c| c.href.clone()))
             .unwrap_or_default();
 
@@ -1494,7 +2000,13 @@ fn handle_submit(app: &mut GuiApp) -> Task<Message> {
             let mut tasks_to_create = vec![new_task];
 
             for ext in extracted_subtasks {
-                let mut sub = TodoTask::new(&ext.raw_text, &app.tag_aliases, config_time);
+                let mut sub = TodoTask::new_with_date_settings(
+                    &ext.raw_text,
+                    &app.tag_aliases,
+                    config_time,
+                    &app.core_config.holidays,
+                    app.core_config.week_start,
+                );
                 sub.uid = ext.uid;
 
                 let p_uid_str = ext.parent_uid.clone().unwrap_or_else(|| parent_uid.clone());
@@ -1571,6 +2083,10 @@ fn handle_submit(app: &mut GuiApp) -> Task<Message> {
                     app.error_msg = Some(e);
                     return Task::none();
                 }
+                t.apply_default_reminder(
+                    app.core_config
+                        .default_reminder_minutes_for(&t.calendar_href),
+                );
             }
 
             app.task_ids
@@ -1626,3 +2142,115 @@ fn handle_submit(app: &mut GuiApp) -> Task<Message> {
 
     Task::none()
 }
+
+/// Creates one task per outline line, each parsed on its own. Indented lines become children
+/// of their outline parent and inherit its tags, location, priority and collection.
+fn submit_outline(
+    app: &mut GuiApp,
+    outline: Vec<crate::model::OutlineLine>,
+    retroactive_sync_batch: Vec<crate::model::Task>,
+) -> Task<Message> {
+    use crate::gui::update::common::refresh_filtered_tasks;
+    use crate::model::Task as TodoTask;
+
+    let config_time = NaiveTime::parse_from_str(&app.default_reminder_time, "%H:%M").ok();
+    let default_href = app
+        .active_cal_href
+        .clone()
+        .or_else(|| app.calendars.first().map(|c| c.href.clone()))
+        .unwrap_or_default();
+    if default_href.is_empty() {
+        return Task::none();
+    }
+
+    let root_parent = app.creating_child_of.clone();
+    if !app.child_lock_active {
+        app.creating_child_of = None;
+    }
+    let root_href = root_parent
+        .as_deref()
+        .and_then(|p| app.store.get_task_ref(p))
+        .map(|p| p.calendar_href.clone())
+        .unwrap_or(default_href);
+
+    let mut tasks_to_create: Vec<TodoTask> = Vec::with_capacity(outline.len());
+    for line in &outline {
+        let mut task = TodoTask::new_with_date_settings(
+            &line.text,
+            &app.tag_aliases,
+            config_time,
+            &app.core_config.holidays,
+            app.core_config.week_start,
+        );
+        if task.summary.trim().is_empty() {
+            // Keep indices aligned with the outline; empty summaries are dropped below
+            tasks_to_create.push(task);
+            continue;
+        }
+
+        let parent = line
+            .parent
+            .and_then(|p| tasks_to_create.get(p))
+            .filter(|p| !p.summary.trim().is_empty());
+        let base_href = match parent {
+            Some(parent) => {
+                task.inherit_properties(&parent.categories, &parent.location, parent.priority);
+                task.parent_uid = Some(parent.uid.clone());
+                parent.calendar_href.clone()
+            }
+            None => {
+                task.parent_uid = root_parent.clone();
+                root_href.clone()
+            }
+        };
+        task.calendar_href = match task.target_collection.take() {
+            Some(target) => crate::model::resolve_collection(&target, &app.calendars, &base_href),
+            None => base_href,
+        };
+
+        if let Err(e) = app.store.resolve_dependencies(&mut task) {
+            app.error_msg = Some(e);
+            return Task::none();
+        }
+        task.apply_default_reminder(
+            app.core_config
+                .default_reminder_minutes_for(&task.calendar_href),
+        );
+        tasks_to_create.push(task);
+    }
+    tasks_to_create.retain(|t| !t.summary.trim().is_empty());
+
+    let Some(first_uid) = tasks_to_create.first().map(|t| t.uid.clone()) else {
+        return Task::none();
+    };
+    for t in &tasks_to_create {
+        app.task_ids
+            .entry(t.uid.clone())
+            .or_insert_with(iced::widget::Id::unique);
+        app.store.add_task(t.clone());
+    }
+
+    app.selected_uid = Some(first_uid);
+    refresh_filtered_tasks(app);
+    app.input_value = text_editor::Content::new();
+    app.description_value = text_editor::Content::new();
+    app.creating_with_desc = false;
+
+    let mut actions: Vec<_> = tasks_to_create
+        .into_iter()
+        .map(crate::journal::Action::Create)
+        .collect();
+    actions.extend(
+        retroactive_sync_batch
+            .into_iter()
+            .map(crate::journal::Action::Update),
+    );
+    if let Some(tx) = &app.bg_tx {
+        let _ = tx.try_send(crate::gui::async_ops::WorkerCommand::Batch(actions));
+    }
+
+    Task::batch(vec![
+        common::scroll_to_selected_delayed(app, false),
+        iced::widget::operation::focus(iced::widget::Id::new("main_input")),
+    ])
+}
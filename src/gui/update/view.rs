@@ -4,6 +4,7 @@ use crate::gui::async_ops::*;
 use crate::gui::message::Message;
 use crate::gui::state::{AppState, Focus, GuiApp, ResizeDirection, SidebarMode};
 use crate::gui::subscription::ACTIVE_FOCUS;
+use crate::gui::tray::TrayCommand;
 use crate::gui::update::common::{
     refresh_filtered_tasks, save_config, scroll_to_selected, scroll_to_selected_delayed,
 };
@@ -157,7 +158,13 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
                     let def_time =
                         chrono::NaiveTime::parse_from_str(&config.default_reminder_time, "%H:%M")
                             .ok();
-                    let mut new_task = crate::model::Task::new(&title, &app.tag_aliases, def_time);
+                    let mut new_task = crate::model::Task::new_with_date_settings(
+                        &title,
+                        &app.tag_aliases,
+                        def_time,
+                        &config.holidays,
+                        config.week_start,
+                    );
 
                     if let Err(e) = app.store.resolve_dependencies(&mut new_task) {
                         app.error_msg = Some(e);
@@ -455,6 +462,17 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
                 return Task::none();
             }
 
+            if app.complete_all_uids.is_some() {
+                return crate::gui::update::tasks::handle(app, Message::CompleteAllVisibleConfirm);
+            }
+
+            if app.pending_confirm.is_some() {
+                return crate::gui::update::tasks::handle(
+                    app,
+                    Message::ConfirmPendingAction(false),
+                );
+            }
+
             if app.ics_import_dialog_open {
                 if app.ics_import_selected_calendar.is_some()
                     && app.ics_import_task_count.unwrap_or(0) > 0
@@ -471,8 +489,10 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
                 return handle(app, Message::SidebarInteractEnter);
             }
 
+            // Enter on a task row expands/collapses its details; Shift+Enter opens the
+            // action menu instead.
             if let Some(uid) = app.selected_uid.clone() {
-                return crate::gui::update::view::handle(app, Message::OpenContextMenu(uid, true));
+                return handle(app, Message::ToggleDetails(uid));
             }
 
             Task::none()
@@ -736,6 +756,10 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             app.error_msg = None;
             Task::none()
         }
+        Message::DismissSyncWarnings => {
+            app.sync_warnings.clear();
+            Task::none()
+        }
         Message::ToggleAllCalendars(show_all) => {
             if show_all {
                 app.hidden_calendars.clear();
@@ -865,6 +889,18 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             refresh_filtered_tasks(app);
             Task::none()
         }
+        Message::ToggleHideDeferred(val) => {
+            app.hide_deferred = val;
+            save_config(app);
+            refresh_filtered_tasks(app);
+            Task::none()
+        }
+        Message::ToggleFuzzySearch(val) => {
+            app.core_config.fuzzy_search = val;
+            save_config(app);
+            refresh_filtered_tasks(app);
+            Task::none()
+        }
         Message::ToggleHideFullyCompletedTags(val) => {
             app.hide_fully_completed_tags = val;
             save_config(app);
@@ -905,6 +941,30 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             refresh_filtered_tasks(app);
             Task::none()
         }
+        Message::SetSortMode(val) => {
+            app.sort_mode = val;
+            save_config(app);
+            refresh_filtered_tasks(app);
+            Task::none()
+        }
+        Message::SetAllDaySort(val) => {
+            app.core_config.all_day_sort = val;
+            save_config(app);
+            refresh_filtered_tasks(app);
+            Task::none()
+        }
+        Message::SetAgendaGrouping(val) => {
+            app.core_config.agenda_grouping = val;
+            save_config(app);
+            refresh_filtered_tasks(app);
+            Task::none()
+        }
+        Message::SetSortUrgencyBoost(val) => {
+            app.sort_urgency_boost = val;
+            save_config(app);
+            refresh_filtered_tasks(app);
+            Task::none()
+        }
         Message::ToggleSortStandardByPriorityToggle => {
             let new_val = !app.sort_standard_by_priority;
             handle(app, Message::ToggleSortStandardByPriority(new_val))
@@ -965,11 +1025,42 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             if let Ok(mut focus) = ACTIVE_FOCUS.write() {
                 *focus = Focus::SearchInput;
             }
-            if let iced::widget::text_editor::Action::Edit(
-                iced::widget::text_editor::Edit::Insert('\t'),
-            ) = &action
-            {
-                return Task::none();
+            use iced::widget::text_editor::{Action, Edit, Motion};
+            match &action {
+                Action::Edit(Edit::Insert('\t')) => return Task::none(),
+                // The search box is single-line: Enter commits the term to the history
+                Action::Edit(Edit::Enter) => {
+                    let term = app.search_value.text();
+                    if !term.trim().is_empty() {
+                        crate::config::push_search_history(&mut app.search_history, &term);
+                        app.search_history_idx = None;
+                        save_config(app);
+                    }
+                    return Task::none();
+                }
+                // Up/Down walk the history (older/newer); Down past the newest clears the box
+                Action::Move(Motion::Up) | Action::Move(Motion::Down) => {
+                    let older = matches!(action, Action::Move(Motion::Up));
+                    let next_idx = match (app.search_history_idx, older) {
+                        (None, true) if !app.search_history.is_empty() => Some(0),
+                        (None, _) => return Task::none(),
+                        (Some(i), true) => Some((i + 1).min(app.search_history.len() - 1)),
+                        (Some(0), false) => None,
+                        (Some(i), false) => Some(i - 1),
+                    };
+                    app.search_history_idx = next_idx;
+                    let term = next_idx
+                        .and_then(|i| app.search_history.get(i).cloned())
+                        .unwrap_or_default();
+                    app.search_value = iced::widget::text_editor::Content::with_text(&term);
+                    app.search_value.perform(Action::Move(Motion::DocumentEnd));
+                    app.session.search_term = term;
+                    app.session.search_collapsed_tasks.clear();
+                    refresh_filtered_tasks(app);
+                    return Task::none();
+                }
+                Action::Edit(_) => app.search_history_idx = None,
+                _ => {}
             }
             app.search_value.perform(action);
             app.session.search_term = app.search_value.text();
@@ -1002,6 +1093,12 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             refresh_filtered_tasks(app);
             Task::none()
         }
+        Message::RecallSearch(term) => {
+            crate::config::push_search_history(&mut app.search_history, &term);
+            app.search_history_idx = None;
+            save_config(app);
+            handle(app, Message::SetSearchTerm(term))
+        }
         Message::SetSearchTerm(term) => {
             app.search_value = iced::widget::text_editor::Content::with_text(&term);
             app.session.search_term = term;
@@ -1019,29 +1116,34 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             Task::none()
         }
         Message::ToggleQuickFilter => {
-            let current = app.search_value.text();
-            let new_text = if current.contains(&app.quick_filter_term) {
-                current
-                    .replace(&app.quick_filter_term, "")
-                    .trim()
-                    .to_string()
+            let term = app.quick_filter_term.clone();
+            toggle_search_token(app, &term)
+        }
+        Message::ToggleFocusMode => toggle_search_token(app, "is:focus"),
+        Message::ToggleInboxFilter => toggle_search_token(app, "is:inbox"),
+        Message::ToggleDueHeatmap => {
+            app.show_due_heatmap = !app.show_due_heatmap;
+            refresh_filtered_tasks(app);
+            Task::none()
+        }
+        Message::ShiftHeatmapMonth(delta) => {
+            let month = if delta < 0 {
+                app.heatmap_month
+                    .checked_sub_months(chrono::Months::new(delta.unsigned_abs()))
             } else {
-                if current.is_empty() {
-                    app.quick_filter_term.clone()
-                } else {
-                    format!("{} {}", app.quick_filter_term, current)
-                }
+                app.heatmap_month
+                    .checked_add_months(chrono::Months::new(delta as u32))
             };
-            app.search_value = iced::widget::text_editor::Content::with_text(&new_text);
-            app.session.search_term = new_text.clone();
-            app.search_value
-                .perform(iced::widget::text_editor::Action::Move(
-                    iced::widget::text_editor::Motion::DocumentEnd,
-                ));
-            app.search_debounce_version = app.search_debounce_version.wrapping_add(1);
-            refresh_filtered_tasks(app);
+            if let Some(month) = month {
+                app.heatmap_month = month;
+            }
             Task::none()
         }
+        Message::FilterDueDate(date) => {
+            let new_text =
+                crate::model::matcher::toggle_due_date_token(&app.search_value.text(), date);
+            set_search_text(app, new_text)
+        }
         Message::SetMinDuration(val) => {
             app.filter_min_duration = val;
             refresh_filtered_tasks(app);
@@ -1134,13 +1236,30 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
                 Task::none()
             }
         }),
-        Message::CloseWindow => window::latest().then(|id| {
-            if let Some(id) = id {
-                window::close(id)
-            } else {
-                Task::none()
+        Message::CloseWindow => {
+            // Stay resident in the tray so the alarm actor keeps running
+            let hide = app.tray_active && app.core_config.minimize_to_tray;
+            app.window_hidden = hide;
+            window::latest().then(move |id| match id {
+                Some(id) if hide => window::set_mode(id, window::Mode::Hidden),
+                Some(id) => window::close(id),
+                None => Task::none(),
+            })
+        }
+        Message::TrayCommand(command) => {
+            app.window_hidden = false;
+            match command {
+                TrayCommand::Open => show_window(),
+                TrayCommand::AddTask => show_window().chain(Task::done(Message::FocusInput)),
+                TrayCommand::Quit => iced::exit(),
             }
-        }),
+        }
+        Message::QuickAddHotkey => {
+            // Summoned from the tray: hide again once the task is submitted
+            app.hide_after_quick_add = app.window_hidden;
+            app.window_hidden = false;
+            show_window().chain(Task::done(Message::FocusInput))
+        }
         Message::ResizeStart(direction) => {
             let dir = match direction {
                 ResizeDirection::North => window::Direction::North,
@@ -1365,8 +1484,8 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
             if let Some(task) = app.store.get_task_ref(&uid)
                 && let Some(geo) = &task.geo
             {
-                let geo_target = format!("geo:{}", geo);
-                let target_url = geo_target.clone();
+                let target_url =
+                    crate::model::parser::geo_to_map_url(geo, &app.core_config.map_url_template);
                 #[cfg(not(target_os = "android"))]
                 std::thread::spawn(move || {
                     #[cfg(target_os = "linux")]
@@ -1457,3 +1576,32 @@ pub fn handle(app: &mut GuiApp, message: Message) -> Task<Message> {
         _ => Task::none(),
     }
 }
+
+/// Restores a hidden or minimized window and brings it to front.
+fn show_window() -> Task<Message> {
+    window::latest().then(|id| {
+        if let Some(id) = id {
+            window::set_mode(id, window::Mode::Windowed).chain(window::gain_focus(id))
+        } else {
+            Task::none()
+        }
+    })
+}
+
+/// Adds or removes a token in the search box and refreshes the list.
+fn toggle_search_token(app: &mut GuiApp, token: &str) -> Task<Message> {
+    let new_text = crate::model::matcher::toggle_search_token(&app.search_value.text(), token);
+    set_search_text(app, new_text)
+}
+
+fn set_search_text(app: &mut GuiApp, new_text: String) -> Task<Message> {
+    app.search_value = iced::widget::text_editor::Content::with_text(&new_text);
+    app.session.search_term = new_text;
+    app.search_value
+        .perform(iced::widget::text_editor::Action::Move(
+            iced::widget::text_editor::Motion::DocumentEnd,
+        ));
+    app.search_debounce_version = app.search_debounce_version.wrapping_add(1);
+    refresh_filtered_tasks(app);
+    Task::none()
+}
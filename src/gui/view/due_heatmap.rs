@@ -0,0 +1,170 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// File: ./src/gui/view/due_heatmap.rs
+//! Month grid above the task list: each day is shaded by the number of open tasks due
+//! that day (`GuiApp::due_counts`). Clicking a day narrows the search to it.
+use crate::gui::icon;
+use crate::gui::message::Message;
+use crate::gui::state::GuiApp;
+use chrono::{Datelike, Duration, Local};
+use iced::widget::{Space, button, column, container, row, text};
+use iced::{Color, Element, Length, Theme};
+
+const CELL_WIDTH: f32 = 34.0;
+const CELL_HEIGHT: f32 = 30.0;
+
+pub fn view_due_heatmap(app: &GuiApp) -> Element<'_, Message> {
+    let month = app.heatmap_month;
+    let today = Local::now().date_naive();
+    let selected = crate::model::matcher::due_date_in_query(&app.search_value.text());
+    let grid_start = crate::model::parser::start_of_week(month, app.core_config.week_start);
+
+    let max = app
+        .due_counts
+        .iter()
+        .filter(|(d, _)| d.year() == month.year() && d.month() == month.month())
+        .map(|(_, c)| *c)
+        .max()
+        .unwrap_or(0);
+
+    let nav_btn = |glyph: char, delta: i32| {
+        button(icon::icon(glyph).size(12))
+            .style(button::text)
+            .padding(4)
+            .on_press(Message::ShiftHeatmapMonth(delta))
+    };
+    let header = row![
+        nav_btn(icon::ARROW_LEFT, -1),
+        text(month_title(month))
+            .size(14)
+            .width(Length::Fill)
+            .align_x(iced::alignment::Horizontal::Center),
+        nav_btn(icon::ARROW_RIGHT, 1),
+    ]
+    .align_y(iced::Alignment::Center)
+    .width(Length::Fixed(CELL_WIDTH * 7.0 + 12.0));
+
+    let weekday_names = rust_i18n::t!("due_heatmap_weekdays");
+    let weekday_names: Vec<&str> = weekday_names.split(',').collect();
+    let mut weekdays = row![].spacing(2);
+    for i in 0..7 {
+        let day = grid_start + Duration::days(i);
+        let idx = day.weekday().num_days_from_monday() as usize;
+        weekdays = weekdays.push(
+            text(
+                weekday_names
+                    .get(idx)
+                    .copied()
+                    .unwrap_or_default()
+                    .to_string(),
+            )
+            .size(10)
+            .width(Length::Fixed(CELL_WIDTH))
+            .align_x(iced::alignment::Horizontal::Center),
+        );
+    }
+
+    let mut grid = column![header, weekdays].spacing(2);
+    let mut week_start = grid_start;
+    while week_start.year() < month.year()
+        || (week_start.year() == month.year() && week_start.month() <= month.month())
+    {
+        let mut week = row![].spacing(2);
+        for i in 0..7 {
+            let day = week_start + Duration::days(i);
+            if day.month() != month.month() {
+                week = week.push(Space::new().width(CELL_WIDTH).height(CELL_HEIGHT));
+                continue;
+            }
+            let count = app.due_counts.get(&day).copied().unwrap_or(0);
+            week = week.push(day_cell(
+                day,
+                count,
+                max,
+                day == today,
+                selected == Some(day),
+            ));
+        }
+        grid = grid.push(week);
+        week_start += Duration::days(7);
+    }
+
+    container(grid)
+        .padding(iced::Padding {
+            left: 10.0,
+            right: 10.0,
+            bottom: 6.0,
+            ..Default::default()
+        })
+        .into()
+}
+
+/// Localized "<month> <year>" heading for the grid.
+fn month_title(month: chrono::NaiveDate) -> String {
+    let months = rust_i18n::t!("due_heatmap_months");
+    let name = months
+        .split(',')
+        .nth(month.month0() as usize)
+        .unwrap_or_default();
+    rust_i18n::t!("due_heatmap_title", month = name, year = month.year()).to_string()
+}
+
+fn day_cell<'a>(
+    day: chrono::NaiveDate,
+    count: usize,
+    max: usize,
+    is_today: bool,
+    is_selected: bool,
+) -> Element<'a, Message> {
+    let label: Element<'a, Message> = if count > 0 {
+        column![
+            text(day.day().to_string()).size(11),
+            text(count.to_string()).size(9)
+        ]
+        .align_x(iced::Alignment::Center)
+        .into()
+    } else {
+        text(day.day().to_string()).size(11).into()
+    };
+
+    // Busier days get a stronger tint, relative to the busiest day of the month
+    let intensity = if count > 0 && max > 0 {
+        0.2 + 0.7 * count as f32 / max as f32
+    } else {
+        0.0
+    };
+
+    button(
+        container(label)
+            .center_x(Length::Fill)
+            .center_y(Length::Fill),
+    )
+    .width(Length::Fixed(CELL_WIDTH))
+    .height(Length::Fixed(CELL_HEIGHT))
+    .padding(0)
+    .style(move |theme: &Theme, status| {
+        let palette = theme.extended_palette();
+        let mut tint = palette.primary.base.color;
+        tint.a = intensity;
+        if status == button::Status::Hovered {
+            tint.a = (intensity + 0.15).min(1.0);
+        }
+        button::Style {
+            background: Some(tint.into()),
+            text_color: palette.background.base.text,
+            border: iced::Border {
+                color: if is_selected {
+                    palette.primary.strong.color
+                } else if is_today {
+                    Color::from_rgb(0.5, 0.5, 0.5)
+                } else {
+                    Color::TRANSPARENT
+                },
+                width: if is_selected { 2.0 } else { 1.0 },
+                radius: 4.0.into(),
+            },
+            ..button::Style::default()
+        }
+    })
+    .on_press(Message::FilterDueDate(day))
+    .into()
+}
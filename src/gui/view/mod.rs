@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 // File: ./src/gui/view/mod.rs
 use std::time::Duration;
+pub mod due_heatmap;
 pub mod focusable;
 pub mod help;
 pub mod settings;
@@ -9,7 +10,7 @@ pub mod syntax;
 pub mod task_row;
 use crate::gui::icon;
 use crate::gui::message::Message;
-use crate::gui::state::{AppState, Focus, GuiApp, ResizeDirection, SidebarMode};
+use crate::gui::state::{AppState, Focus, GuiApp, PendingConfirm, ResizeDirection, SidebarMode};
 use crate::gui::view::help::view_help;
 use crate::gui::view::settings::view_settings;
 use crate::gui::view::sidebar::{view_sidebar_calendars, view_sidebar_categories};
@@ -26,6 +27,66 @@ use iced::{Color, Element, Length, Theme, Vector};
 pub const COLOR_LOCATION: Color = Color::from_rgb(0.4, 0.4, 0.6);
 pub const CONTEXT_MENU_WIDTH: f32 = 190.0;
 
+/// Vertical space taken by the window chrome around the sidebar list (header, tabs, footer).
+const SIDEBAR_CHROME: f32 = 110.0;
+/// Free sidebar height needed before the logo is shown.
+const SIDEBAR_LOGO_HEIGHT: f32 = 140.0;
+/// Goal cards keep their layout in both densities.
+const ITEM_HEIGHT_GOAL: f32 = 60.0;
+
+/// Row metrics of the task list, input bar and sidebar, selected by `Config::compact_layout`.
+/// The sidebar item heights drive the logo-hiding heuristic in `root_view`, so they must
+/// follow what the sidebar actually renders at each density.
+#[derive(Debug, Clone, Copy)]
+pub struct Density {
+    pub task_font_size: u32,
+    pub note_font_size: u32,
+    pub row_padding_y: f32,
+    pub row_spacing: f32,
+    pub input_height: f32,
+    pub input_padding: f32,
+    pub sidebar_font_size: u32,
+    pub calendar_padding: f32,
+    pub item_height_calendar: f32,
+    pub item_height_tag: f32,
+}
+
+impl Density {
+    pub const COMFORTABLE: Density = Density {
+        task_font_size: 20,
+        note_font_size: 22,
+        row_padding_y: 2.0,
+        row_spacing: 10.0,
+        input_height: 45.0,
+        input_padding: 10.0,
+        sidebar_font_size: 16,
+        calendar_padding: 10.0,
+        item_height_calendar: 44.0,
+        item_height_tag: 34.0,
+    };
+
+    pub const COMPACT: Density = Density {
+        task_font_size: 16,
+        note_font_size: 18,
+        row_padding_y: 0.0,
+        row_spacing: 6.0,
+        input_height: 34.0,
+        input_padding: 6.0,
+        sidebar_font_size: 14,
+        calendar_padding: 4.0,
+        item_height_calendar: 30.0,
+        item_height_tag: 28.0,
+    };
+
+    pub fn of(app: &GuiApp) -> Density {
+        if app.compact_layout {
+            Density::COMPACT
+        } else {
+            Density::COMFORTABLE
+        }
+    }
+}
+
 pub fn is_action_available(
     action: &crate::config::TaskAction,
     task: &crate::model::Task,
@@ -38,6 +99,7 @@ pub fn is_action_available(
                 | crate::config::TaskAction::StopTimer
                 | crate::config::TaskAction::AddSession
                 | crate::config::TaskAction::CompleteAndShift
+                | crate::config::TaskAction::SkipOccurrence
         )
     {
         return false;
@@ -79,8 +141,16 @@ pub fn is_action_available(
         crate::config::TaskAction::CompleteAndShift => {
             task.rrule.is_some() && !is_done_or_cancelled && !task.is_relative_recurrence()
         }
+        crate::config::TaskAction::SkipOccurrence => {
+            task.rrule.is_some()
+                && !is_done_or_cancelled
+                && (task.dtstart.is_some() || task.due.is_some())
+        }
         crate::config::TaskAction::EditTree => true,
         crate::config::TaskAction::TogglePin => true,
+        crate::config::TaskAction::ToggleWaiting => {
+            task.status == crate::model::TaskStatus::NeedsAction
+        }
         crate::config::TaskAction::Promote => task.parent_uid.is_some(),
         crate::config::TaskAction::Yank => app.yanked_uid.is_none(),
         crate::config::TaskAction::StopTimer => {
@@ -136,14 +206,21 @@ pub fn root_view(app: &GuiApp) -> Element<'_, Message> {
         AppState::Onboarding | AppState::Settings => view_settings(app),
         AppState::Help(tab, _) => view_help(tab, app),
         AppState::Active => {
+            let density = Density::of(app);
             let content_height = match app.sidebar_mode {
-                SidebarMode::Calendars => app.get_filtered_calendars().len() as f32 * 44.0,
-                SidebarMode::Categories => app.cached_categories.len() as f32 * 34.0,
-                SidebarMode::Locations => app.cached_locations.len() as f32 * 34.0,
-                SidebarMode::Goals => app.core_config.goals.len() as f32 * 60.0,
+                SidebarMode::Calendars => {
+                    app.get_filtered_calendars().len() as f32 * density.item_height_calendar
+                }
+                SidebarMode::Categories => {
+                    app.cached_categories.len() as f32 * density.item_height_tag
+                }
+                SidebarMode::Locations => {
+                    app.cached_locations.len() as f32 * density.item_height_tag
+                }
+                SidebarMode::Goals => app.core_config.goals.len() as f32 * ITEM_HEIGHT_GOAL,
             };
-            let available_height = app.current_window_size.height - 110.0;
-            let show_logo = (available_height - content_height) > 140.0;
+            let available_height = app.current_window_size.height - SIDEBAR_CHROME;
+            let show_logo = (available_height - content_height) > SIDEBAR_LOGO_HEIGHT;
 
             let content_layout = if app.sidebar_is_hidden {
                 row![
@@ -161,16 +238,27 @@ pub fn root_view(app: &GuiApp) -> Element<'_, Message> {
                 ]
             };
 
-            container(content_layout)
-                .width(Length::Fill)
-                .height(Length::Fill)
-                .into()
+            let mut active_area = MouseArea::new(
+                container(content_layout)
+                    .width(Length::Fill)
+                    .height(Length::Fill),
+            );
+            if app.dragging_uid.is_some() {
+                active_area = active_area
+                    .interaction(mouse::Interaction::Grabbing)
+                    .on_release(Message::DragEnd);
+            }
+            active_area.into()
         }
     };
 
     let mut stack_children: Vec<Element<'_, Message>> = vec![base_content];
 
-    if app.ics_import_dialog_open {
+    if let Some(uids) = &app.complete_all_uids {
+        stack_children.push(view_complete_all_overlay(uids.len()));
+    } else if let Some(pending) = &app.pending_confirm {
+        stack_children.push(view_confirm_overlay(app, pending));
+    } else if app.ics_import_dialog_open {
         stack_children.push(view_ics_import_overlay(app));
     } else if !app.ringing_tasks.is_empty() {
         let (task, alarm) = &app.ringing_tasks[0];
@@ -208,15 +296,14 @@ pub fn root_view(app: &GuiApp) -> Element<'_, Message> {
             column![]
         };
 
-        let s1 = app.snooze_short_mins;
-        let s2 = app.snooze_long_mins;
+        let snooze_mins = crate::config::snooze_options(
+            &app.snooze_presets,
+            app.snooze_short_mins,
+            app.snooze_long_mins,
+        );
 
         let snooze_btn = |mins: u32| {
-            let label = if mins >= 60 {
-                format!("{}h", mins / 60)
-            } else {
-                format!("{}m", mins)
-            };
+            let label = crate::model::parser::format_duration_compact(mins);
             button(text(label).size(12))
                 .style(iced::widget::button::secondary)
                 .padding([6, 12])
@@ -255,6 +342,27 @@ pub fn root_view(app: &GuiApp) -> Element<'_, Message> {
         .spacing(5)
         .align_y(iced::Alignment::Center);
 
+        let snooze_until_row = row![
+            text_input(&rust_i18n::t!("snooze_until_hint"), &app.snooze_until_input)
+                .on_input(Message::SnoozeUntilInput)
+                .on_submit(Message::SnoozeUntilSubmit(
+                    task.uid.clone(),
+                    alarm.uid.clone()
+                ))
+                .padding(5)
+                .size(12)
+                .width(Length::Fixed(220.0)),
+            button(icon::icon(icon::CHECK).size(12))
+                .style(iced::widget::button::secondary)
+                .padding(6)
+                .on_press(Message::SnoozeUntilSubmit(
+                    task.uid.clone(),
+                    alarm.uid.clone()
+                ))
+        ]
+        .spacing(5)
+        .align_y(iced::Alignment::Center);
+
         let done_btn = button(text(rust_i18n::t!("done")).size(14).font(iced::Font {
             weight: iced::font::Weight::Bold,
             ..Default::default()
@@ -282,15 +390,48 @@ pub fn root_view(app: &GuiApp) -> Element<'_, Message> {
         .padding([8, 16])
         .on_press(Message::DismissAlarm(task.uid.clone(), alarm.uid.clone()));
 
-        let buttons = column![
-            row![snooze_btn(s1), snooze_btn(s2), custom_snooze_row]
-                .spacing(10)
-                .align_y(iced::Alignment::Center),
+        let preset_row = row(snooze_mins.iter().map(|m| snooze_btn(*m).into()))
+            .spacing(10)
+            .align_y(iced::Alignment::Center);
+
+        let mut buttons = column![
+            preset_row,
+            Space::new().height(6),
+            custom_snooze_row,
+            Space::new().height(6),
+            snooze_until_row,
             Space::new().height(10),
             row![done_btn, cancel_btn, dismiss_btn].spacing(10)
         ]
         .align_x(iced::Alignment::Center);
 
+        // Several alarms at once: act on the whole stack
+        if app.ringing_tasks.len() > 1 {
+            let snooze_all_btn = |mins: u32| {
+                let label = crate::model::parser::format_duration_compact(mins);
+                button(text(label).size(12))
+                    .style(iced::widget::button::secondary)
+                    .padding([6, 12])
+                    .on_press(Message::SnoozeAllAlarms(mins))
+            };
+            let dismiss_all_btn = button(text(rust_i18n::t!("dismiss_all")).size(12))
+                .style(iced::widget::button::secondary)
+                .padding([6, 12])
+                .on_press(Message::DismissAllAlarms);
+            let all_label = text(rust_i18n::t!(
+                "reminders_all_ringing",
+                count = app.ringing_tasks.len()
+            ))
+            .size(12);
+            buttons = buttons.push(Space::new().height(10)).push(
+                row![all_label]
+                    .extend(snooze_mins.iter().map(|m| snooze_all_btn(*m).into()))
+                    .push(dismiss_all_btn)
+                    .spacing(10)
+                    .align_y(iced::Alignment::Center),
+            );
+        }
+
         let modal_content = scrollable(
             column![
                 icon_header,
@@ -306,36 +447,12 @@ pub fn root_view(app: &GuiApp) -> Element<'_, Message> {
         )
         .height(Length::Shrink);
 
-        let modal_card = container(modal_content)
-            .padding(20)
+        let card = modal_card(modal_content)
             .width(Length::Fixed(380.0))
-            .max_height(500.0)
-            .style(|theme: &Theme| {
-                let palette = theme.extended_palette();
-                container::Style {
-                    background: Some(
-                        Color {
-                            a: 0.95,
-                            ..palette.background.weak.color
-                        }
-                        .into(),
-                    ),
-                    border: iced::Border {
-                        color: palette.background.strong.color,
-                        width: 1.0,
-                        radius: 12.0.into(),
-                    },
-                    shadow: iced::Shadow {
-                        color: Color::BLACK.scale_alpha(0.5),
-                        offset: Vector::new(0.0, 4.0),
-                        blur_radius: 10.0,
-                    },
-                    ..Default::default()
-                }
-            });
+            .max_height(500.0);
 
         stack_children.push(
-            container(modal_card)
+            container(card)
                 .width(Length::Fill)
                 .height(Length::Fill)
                 .center_x(Length::Fill)
@@ -596,11 +713,21 @@ pub fn root_view(app: &GuiApp) -> Element<'_, Message> {
                     Message::ToggleTaskShift(uid.clone()),
                     false,
                 ),
+                TaskAction::SkipOccurrence => (
+                    icon::icon(icon::ARROW_RIGHT).size(14).into(),
+                    Message::SkipOccurrence(uid.clone()),
+                    false,
+                ),
                 TaskAction::TogglePin => (
                     icon::icon(icon::THUMB_TACK).size(14).into(),
                     Message::TogglePin(uid.clone()),
                     false,
                 ),
+                TaskAction::ToggleWaiting => (
+                    icon::icon(icon::HOURGLASS_START).size(14).into(),
+                    Message::ToggleWaiting(uid.clone()),
+                    false,
+                ),
             };
 
             let btn = button(
@@ -628,6 +755,7 @@ pub fn root_view(app: &GuiApp) -> Element<'_, Message> {
                 TaskAction::OpenLocations,   // GPX export second
                 TaskAction::ToggleDetails,
                 TaskAction::CompleteAndShift,
+                TaskAction::SkipOccurrence,
                 TaskAction::ToggleTimer,
                 TaskAction::StopTimer,
                 TaskAction::AddSession,
@@ -869,36 +997,12 @@ pub fn root_view(app: &GuiApp) -> Element<'_, Message> {
         .spacing(5)
         .align_x(iced::Alignment::Center);
 
-        let modal_card = container(modal_content)
-            .padding(20)
+        let card = modal_card(modal_content)
             .width(Length::Fixed(350.0))
-            .max_height(450.0)
-            .style(|theme: &Theme| {
-                let palette = theme.extended_palette();
-                container::Style {
-                    background: Some(
-                        Color {
-                            a: 0.98,
-                            ..palette.background.weak.color
-                        }
-                        .into(),
-                    ),
-                    border: iced::Border {
-                        color: palette.background.strong.color,
-                        width: 1.0,
-                        radius: 12.0.into(),
-                    },
-                    shadow: iced::Shadow {
-                        color: Color::BLACK.scale_alpha(0.5),
-                        offset: Vector::new(0.0, 4.0),
-                        blur_radius: 10.0,
-                    },
-                    ..Default::default()
-                }
-            });
+            .max_height(450.0);
 
         stack_children.push(
-            container(modal_card)
+            container(card)
                 .width(Length::Fill)
                 .height(Length::Fill)
                 .center_x(Length::Fill)
@@ -1365,9 +1469,22 @@ fn view_main_content(app: &GuiApp, show_logo: bool, is_expanded: bool) -> Elemen
         _ => rust_i18n::t!("tasks_count.other", count = active_count).to_string(),
     };
 
+    if let Some((min, max)) = crate::store::TaskStore::estimated_duration_total(&app.tasks) {
+        subtitle.push_str(&format!(
+            " [{}]",
+            crate::model::parser::format_estimate_range(min, max)
+        ));
+    }
+
     let search_text = app.search_value.text();
     if !search_text.is_empty() {
         subtitle.push_str(&format!(" | Search: '{}'", search_text));
+        if let Some(name) = &app.unknown_search_calendar {
+            subtitle.push_str(&format!(
+                " ({})",
+                rust_i18n::t!("search_calendar_unknown", name = name)
+            ));
+        }
     } else if !app.session.selected_categories.is_empty() {
         let tag_count = app.session.selected_categories.len();
         if tag_count == 1 {
@@ -1481,11 +1598,25 @@ fn view_main_content(app: &GuiApp, show_logo: bool, is_expanded: bool) -> Elemen
         )
     };
 
-    let refresh_btn =
-        iced::widget::button(icon::icon(sync_icon_char).size(16).color(sync_icon_color))
-            .style(iced::widget::button::text)
-            .padding(4)
-            .on_press(Message::Refresh);
+    let sync_icon = icon::icon(sync_icon_char).size(16).color(sync_icon_color);
+    let refresh_content: Element<'_, Message> = if app.journal_items.is_empty() {
+        sync_icon.into()
+    } else {
+        // Badge: number of pending journal actions
+        row![
+            sync_icon,
+            text(app.journal_items.len().to_string())
+                .size(12)
+                .color(sync_icon_color)
+        ]
+        .spacing(2)
+        .align_y(iced::Alignment::Center)
+        .into()
+    };
+    let refresh_btn = iced::widget::button(refresh_content)
+        .style(iced::widget::button::text)
+        .padding(4)
+        .on_press(Message::Refresh);
 
     left_section = left_section.push(
         tooltip(
@@ -1497,6 +1628,17 @@ fn view_main_content(app: &GuiApp, show_logo: bool, is_expanded: bool) -> Elemen
         .delay(Duration::from_millis(700)),
     );
 
+    if let Some(at) = app.last_sync_at {
+        left_section = left_section.push(
+            text(rust_i18n::t!(
+                "last_synced_at",
+                time = at.format("%H:%M").to_string()
+            ))
+            .size(12)
+            .color(Color::from_rgb(0.6, 0.6, 0.6)),
+        );
+    }
+
     let subtitle_text = text(subtitle)
         .size(14)
         .color(Color::from_rgb(0.6, 0.6, 0.6));
@@ -1513,7 +1655,7 @@ fn view_main_content(app: &GuiApp, show_logo: bool, is_expanded: bool) -> Elemen
         .placeholder(&app.search_placeholder)
         .on_action(Message::SearchChanged)
         .highlight_with::<self::syntax::SmartInputHighlighter>(
-            (is_dark_mode, true),
+            (is_dark_mode, true, app.tag_color_mode),
             |highlight, _theme| *highlight,
         )
         .padding(5)
@@ -1541,6 +1683,80 @@ fn view_main_content(app: &GuiApp, show_logo: bool, is_expanded: bool) -> Elemen
         .delay(Duration::from_millis(700)),
     );
 
+    let complete_all_btn = iced::widget::button(icon::icon(icon::CHECK_SQUARE).size(16))
+        .style(iced::widget::button::text)
+        .padding(6)
+        .on_press(Message::CompleteAllVisible);
+
+    search_row = search_row.push(
+        tooltip(
+            complete_all_btn,
+            text(format!(
+                "{} (Shift+X)",
+                rust_i18n::t!("complete_all_visible")
+            ))
+            .size(12),
+            tooltip::Position::Bottom,
+        )
+        .style(tooltip_style)
+        .delay(Duration::from_millis(700)),
+    );
+
+    let copy_view_btn = iced::widget::button(icon::icon(icon::COPY).size(16))
+        .style(iced::widget::button::text)
+        .padding(6)
+        .on_press_maybe((!app.tasks.is_empty()).then_some(Message::CopyViewAsMarkdown));
+
+    search_row = search_row.push(
+        tooltip(
+            copy_view_btn,
+            text(rust_i18n::t!("copy_view_as_markdown")).size(12),
+            tooltip::Position::Bottom,
+        )
+        .style(tooltip_style)
+        .delay(Duration::from_millis(700)),
+    );
+
+    let heatmap_color = if app.show_due_heatmap {
+        app.theme().extended_palette().primary.base.color
+    } else {
+        app.theme().extended_palette().background.base.text
+    };
+    let heatmap_btn =
+        iced::widget::button(icon::icon(icon::CALENDAR).size(16).color(heatmap_color))
+            .style(iced::widget::button::text)
+            .padding(6)
+            .on_press(Message::ToggleDueHeatmap);
+    search_row = search_row.push(
+        tooltip(
+            heatmap_btn,
+            text(rust_i18n::t!("due_heatmap_toggle")).size(12),
+            tooltip::Position::Bottom,
+        )
+        .style(tooltip_style)
+        .delay(Duration::from_millis(700)),
+    );
+
+    let focus_active = search_text.contains("is:focus");
+    let focus_color = if focus_active {
+        app.theme().extended_palette().primary.base.color
+    } else {
+        app.theme().extended_palette().background.base.text
+    };
+    let focus_btn = iced::widget::button(icon::icon(icon::FOCUS_FIELD).size(16).color(focus_color))
+        .style(iced::widget::button::text)
+        .padding(6)
+        .on_press(Message::ToggleFocusMode);
+    search_row = search_row.push(
+        tooltip(
+            focus_btn,
+            text(format!("{} (Shift+F)", rust_i18n::t!("focus_mode"))).size(12),
+            tooltip::Position::Bottom,
+        )
+        .style(tooltip_style)
+        .delay(Duration::from_millis(700)),
+    );
+
     if app.show_quick_filter {
         let is_active = search_text.contains(&app.quick_filter_term);
         let qf_icon_char = crate::gui::icon::parse_icon(&app.quick_filter_icon);
@@ -1737,6 +1953,61 @@ fn view_main_content(app: &GuiApp, show_logo: bool, is_expanded: bool) -> Elemen
     let input_area = view_input_area(app);
     let mut main_col = column![header_drag_area, export_ui];
 
+    // Recent searches, shown under the header while the search box is focused and either
+    // empty or being walked with the arrow keys.
+    if !is_expanded
+        && app.active_focus == Focus::SearchInput
+        && !app.search_history.is_empty()
+        && (search_text.is_empty() || app.search_history_idx.is_some())
+    {
+        let mut history_col = column![
+            text(rust_i18n::t!("recent_searches"))
+                .size(12)
+                .color(Color::from_rgb(0.5, 0.5, 0.5))
+        ]
+        .spacing(2);
+        for (i, term) in app.search_history.iter().enumerate() {
+            let is_current = app.search_history_idx == Some(i);
+            history_col = history_col.push(
+                iced::widget::button(
+                    row![icon::icon(icon::SEARCH).size(12), text(term).size(13)]
+                        .spacing(6)
+                        .align_y(iced::Alignment::Center),
+                )
+                .style(if is_current {
+                    iced::widget::button::secondary
+                } else {
+                    iced::widget::button::text
+                })
+                .padding([2, 6])
+                .width(Length::Fill)
+                .on_press(Message::RecallSearch(term.clone())),
+            );
+        }
+        main_col = main_col.push(
+            container(
+                container(history_col)
+                    .width(Length::Fixed(300.0))
+                    .padding(5)
+                    .style(|theme: &Theme| container::Style {
+                        background: Some(theme.extended_palette().background.weak.color.into()),
+                        border: iced::Border {
+                            radius: 4.0.into(),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    }),
+            )
+            .width(Length::Fill)
+            .align_x(Horizontal::Right)
+            .padding(iced::Padding {
+                right: 10.0,
+                bottom: 5.0,
+                ..Default::default()
+            }),
+        );
+    }
+
     if let Some(err) = &app.error_msg {
         let error_content = row![
             text(err)
@@ -1766,6 +2037,92 @@ fn view_main_content(app: &GuiApp, show_logo: bool, is_expanded: bool) -> Elemen
         );
     }
 
+    if !app.sync_warnings.is_empty() {
+        let lines = app
+            .sync_warnings
+            .iter()
+            .fold(column![].spacing(2), |col, w| {
+                col.push(
+                    text(w)
+                        .style(|theme: &Theme| text::Style {
+                            color: Some(theme.extended_palette().background.base.text),
+                        })
+                        .size(14),
+                )
+            });
+        let warning_content = row![
+            icon::icon(icon::SYNC_ALERT)
+                .size(14)
+                .color(app.theme().extended_palette().background.base.text),
+            lines.width(Length::Fill),
+            iced::widget::button(
+                icon::icon(icon::CROSS)
+                    .size(14)
+                    .color(app.theme().extended_palette().background.base.text)
+            )
+            .style(iced::widget::button::text)
+            .padding(2)
+            .on_press(Message::DismissSyncWarnings)
+        ]
+        .spacing(8)
+        .align_y(iced::Alignment::Center);
+        main_col = main_col.push(
+            container(warning_content)
+                .width(Length::Fill)
+                .padding(5)
+                .style(|_| container::Style {
+                    background: Some(Color::from_rgb(0.85, 0.6, 0.1).into()),
+                    ..Default::default()
+                }),
+        );
+    }
+
+    if !app.stale_unsynced.is_empty() {
+        let fg = app.theme().extended_palette().background.base.text;
+        let mut lines = column![
+            text(rust_i18n::t!(
+                "unsynced_stale_title",
+                hours = app.core_config.unsynced_warning_hours
+            ))
+            .color(fg)
+            .size(14)
+        ]
+        .spacing(2);
+        for (uid, summary) in &app.stale_unsynced {
+            lines = lines.push(
+                row![
+                    text(summary).color(fg).size(14).width(Length::Fill),
+                    iced::widget::button(text(rust_i18n::t!("unsynced_retry")).size(12))
+                        .style(iced::widget::button::secondary)
+                        .padding([2, 8])
+                        .on_press(Message::RetryUnsynced(uid.clone())),
+                    iced::widget::button(text(rust_i18n::t!("unsynced_discard")).size(12))
+                        .style(iced::widget::button::danger)
+                        .padding([2, 8])
+                        .on_press(Message::DiscardUnsynced(uid.clone())),
+                ]
+                .spacing(6)
+                .align_y(iced::Alignment::Center),
+            );
+        }
+        main_col = main_col.push(
+            container(
+                row![
+                    icon::icon(icon::SYNC_ALERT).size(14).color(fg),
+                    lines.width(Length::Fill)
+                ]
+                .spacing(8)
+                .align_y(iced::Alignment::Start),
+            )
+            .width(Length::Fill)
+            .padding(5)
+            .style(|_| container::Style {
+                background: Some(Color::from_rgb(0.8, 0.2, 0.2).into()),
+                ..Default::default()
+            }),
+        );
+    }
+
     main_col = main_col.push(input_area);
 
     if !is_expanded
@@ -1947,11 +2304,25 @@ fn view_main_content(app: &GuiApp, show_logo: bool, is_expanded: bool) -> Elemen
         }
     }
 
+    if app.show_due_heatmap && !is_expanded {
+        main_col = main_col.push(due_heatmap::view_due_heatmap(app));
+    }
+
     // We use a hasher to create a stable, `Copy`-able u64 key for the keyed_column
     use std::hash::{Hash, Hasher};
 
+    let agenda_headers: std::collections::HashMap<usize, crate::store::AgendaGroup> =
+        crate::store::agenda_headers(
+            &app.tasks,
+            app.core_config.agenda_grouping,
+            chrono::Local::now().date_naive(),
+            app.core_config.week_start,
+        )
+        .into_iter()
+        .collect();
+
     let tasks_view =
-        iced::widget::keyed_column(app.tasks.iter().enumerate().map(|(real_index, item)| {
+        iced::widget::keyed_column(app.tasks.iter().enumerate().flat_map(|(real_index, item)| {
             let row_id = match item {
                 crate::store::TaskListItem::Task(t) => app
                     .task_ids
@@ -1984,18 +2355,31 @@ fn view_main_content(app: &GuiApp, show_logo: bool, is_expanded: bool) -> Elemen
             };
             let key = hasher.finish();
 
-            (key, view_task_row(app, real_index, item, row_id))
+            let mut rows = Vec::with_capacity(2);
+            if let Some(group) = agenda_headers.get(&real_index) {
+                let mut hasher = std::collections::hash_map::DefaultHasher::new();
+                3u8.hash(&mut hasher);
+                group.hash(&mut hasher);
+                rows.push((hasher.finish(), view_agenda_header(*group)));
+            }
+            rows.push((key, view_task_row(app, real_index, item, row_id)));
+            rows
         }))
         .spacing(1);
 
-    main_col = main_col.push(
-        scrollable(tasks_view)
-            .height(Length::Fill)
-            .id(app.scrollable_id.clone())
-            .direction(Direction::Vertical(
-                Scrollbar::new().width(10).scroller_width(10).margin(0),
-            )),
-    );
+    let tasks_scroll = scrollable(tasks_view)
+        .height(Length::Fill)
+        .id(app.scrollable_id.clone())
+        .direction(Direction::Vertical(
+            Scrollbar::new().width(10).scroller_width(10).margin(0),
+        ));
+    // While dragging, a release that no task row claimed promotes the task to the root.
+    // The MouseArea is always present so the scrollable keeps its state when a drag starts.
+    let mut tasks_area = MouseArea::new(tasks_scroll);
+    if app.dragging_uid.is_some() {
+        tasks_area = tasks_area.on_release(Message::DropOnRoot);
+    }
+    main_col = main_col.push(tasks_area);
 
     container(main_col)
         .width(Length::Fill)
@@ -2007,22 +2391,50 @@ fn view_main_content(app: &GuiApp, show_logo: bool, is_expanded: bool) -> Elemen
         .into()
 }
 
+/// Section title above the first task of an agenda group (`agenda_grouping`).
+fn view_agenda_header<'a>(group: crate::store::AgendaGroup) -> Element<'a, Message> {
+    let color = match group {
+        crate::store::AgendaGroup::Overdue => Some(Color::from_rgb(0.85, 0.3, 0.3)),
+        _ => None,
+    };
+    container(
+        text(group.label())
+            .size(13)
+            .font(iced::Font {
+                weight: iced::font::Weight::Bold,
+                ..Default::default()
+            })
+            .style(move |theme: &Theme| text::Style {
+                color: Some(color.unwrap_or(theme.extended_palette().primary.base.color)),
+            }),
+    )
+    .padding(iced::Padding {
+        top: 8.0,
+        bottom: 2.0,
+        left: 6.0,
+        right: 0.0,
+    })
+    .into()
+}
+
 fn view_input_area(app: &GuiApp) -> Element<'_, Message> {
     let is_dark_mode = app.theme().extended_palette().is_dark;
 
     let is_expanded =
         app.editing_uid.is_some() || app.editing_tree_uid.is_some() || app.creating_with_desc;
 
+    let density = Density::of(app);
+
     let input_title = text_editor(&app.input_value)
         .id("main_input")
         .placeholder(&app.current_placeholder)
         .on_action(Message::InputChanged)
         .highlight_with::<self::syntax::SmartInputHighlighter>(
-            (is_dark_mode, false),
+            (is_dark_mode, false, app.tag_color_mode),
             |highlight, _theme| *highlight,
         )
-        .padding(10)
-        .height(Length::Fixed(45.0))
+        .padding(density.input_padding)
+        .height(Length::Fixed(density.input_height))
         .font(iced::Font::DEFAULT);
 
     let expand_btn = iced::widget::button(icon::icon(icon::DETAILED_TRIANGLE).size(16))
@@ -2066,7 +2478,7 @@ fn view_input_area(app: &GuiApp) -> Element<'_, Message> {
             .placeholder(placeholder)
             .on_action(Message::DescriptionChanged)
             .highlight_with::<self::syntax::MarkdownHighlighter>(
-                is_dark_mode,
+                (is_dark_mode, app.tag_color_mode),
                 |highlight, _theme| *highlight,
             )
             .padding(10)
@@ -2203,10 +2615,89 @@ fn view_input_area(app: &GuiApp) -> Element<'_, Message> {
                 .height(Length::Shrink)
                 .into()
         }
+    } else if !app.quick_templates.is_empty() {
+        let mut templates_row = row![].spacing(5);
+        for tpl in &app.quick_templates {
+            templates_row = templates_row.push(
+                tooltip(
+                    iced::widget::button(text(tpl.label.clone()).size(12))
+                        .style(iced::widget::button::secondary)
+                        .padding([2, 8])
+                        .on_press(Message::ApplyTemplate(tpl.smart.clone())),
+                    text(tpl.smart.clone()).size(12),
+                    tooltip::Position::Top,
+                )
+                .style(tooltip_style)
+                .delay(Duration::from_millis(700)),
+            );
+        }
+        column![templates_row.wrap(), title_row].spacing(5).into()
     } else {
         column![title_row].spacing(5).into()
     };
 
+    // Live preview of what the typed #tags / @@locations will expand to via aliases.
+    let alias_preview = if app.editing_tree_uid.is_none() {
+        crate::model::parser::preview_alias_expansions(&app.input_value.text(), &app.tag_aliases)
+    } else {
+        Vec::new()
+    };
+
+    // Dry-run of the smart input parser (computed in update): the parsed fields when
+    // enabled in settings, and always a warning for directive-looking tokens that were
+    // kept as plain text.
+    let mut preview_lines: Vec<Element<'_, Message>> = Vec::new();
+    if let Some(preview) = &app.parse_preview {
+        if app.core_config.show_parse_preview {
+            let rows: Vec<String> = preview
+                .fields(app.core_config.date_format, app.core_config.time_format)
+                .into_iter()
+                .map(|(name, value)| format!("{}: {}", name, value))
+                .collect();
+            preview_lines.push(
+                text(rows.join("  ·  "))
+                    .size(12)
+                    .color(Color::from_rgb(0.6, 0.6, 0.6))
+                    .into(),
+            );
+        }
+        if !preview.unrecognized.is_empty() {
+            preview_lines.push(
+                text(rust_i18n::t!(
+                    "unrecognized_tokens_kept",
+                    tokens = preview.unrecognized.join(" ")
+                ))
+                .size(12)
+                .color(Color::from_rgb(0.9, 0.6, 0.2))
+                .into(),
+            );
+        }
+    }
+
+    let inner_content: Element<'_, Message> = if preview_lines.is_empty() {
+        inner_content
+    } else {
+        column![inner_content, column(preview_lines).spacing(3)]
+            .spacing(3)
+            .into()
+    };
+
+    let inner_content: Element<'_, Message> = if alias_preview.is_empty() {
+        inner_content
+    } else {
+        column![
+            inner_content,
+            text(rust_i18n::t!(
+                "alias_preview",
+                tags = alias_preview.join(" ")
+            ))
+            .size(12)
+            .color(Color::from_rgb(0.6, 0.6, 0.6))
+        ]
+        .spacing(3)
+        .into()
+    };
+
     container(inner_content)
         .padding(iced::Padding {
             top: 5.0,
@@ -2218,6 +2709,210 @@ fn view_input_area(app: &GuiApp) -> Element<'_, Message> {
         .into()
 }
 
+/// The rounded, shadowed card a modal dialog's content sits in. Callers set its size.
+fn modal_card<'a>(
+    content: impl Into<Element<'a, Message>>,
+) -> iced::widget::Container<'a, Message> {
+    container(content).padding(20).style(|theme: &Theme| {
+        let palette = theme.extended_palette();
+        container::Style {
+            background: Some(
+                Color {
+                    a: 0.98,
+                    ..palette.background.weak.color
+                }
+                .into(),
+            ),
+            border: iced::Border {
+                color: palette.background.strong.color,
+                width: 1.0,
+                radius: 12.0.into(),
+            },
+            shadow: iced::Shadow {
+                color: Color::BLACK.scale_alpha(0.5),
+                offset: Vector::new(0.0, 4.0),
+                blur_radius: 10.0,
+            },
+            ..Default::default()
+        }
+    })
+}
+
+fn view_complete_all_overlay<'a>(count: usize) -> Element<'a, Message> {
+    let icon_header = container(
+        icon::icon(icon::CHECK_SQUARE)
+            .size(30)
+            .color(Color::from_rgb(0.3, 0.8, 0.4)),
+    )
+    .padding(5)
+    .center_x(Length::Fill);
+
+    let title = text(rust_i18n::t!("complete_all_visible"))
+        .size(24)
+        .font(iced::Font {
+            weight: iced::font::Weight::Bold,
+            ..Default::default()
+        })
+        .width(Length::Fill)
+        .align_x(Horizontal::Center);
+
+    let summary = text(if count == 1 {
+        rust_i18n::t!("complete_all_visible_confirm.one").to_string()
+    } else {
+        rust_i18n::t!("complete_all_visible_confirm.other", count = count).to_string()
+    })
+    .size(14)
+    .color(Color::from_rgb(0.7, 0.7, 0.7))
+    .width(Length::Fill)
+    .align_x(Horizontal::Center);
+
+    let cancel_btn = button(text(rust_i18n::t!("cancel")).size(14))
+        .style(iced::widget::button::secondary)
+        .padding([8, 16])
+        .on_press(Message::CompleteAllVisibleCancel);
+
+    let confirm_btn = button(text(rust_i18n::t!("complete_all_action")).size(14).font(
+        iced::Font {
+            weight: iced::font::Weight::Bold,
+            ..Default::default()
+        },
+    ))
+    .style(iced::widget::button::primary)
+    .padding([8, 16])
+    .on_press_maybe((count > 0).then_some(Message::CompleteAllVisibleConfirm));
+
+    let buttons = row![cancel_btn, confirm_btn]
+        .spacing(10)
+        .align_y(iced::Alignment::Center);
+
+    let modal_content = column![
+        icon_header,
+        title,
+        Space::new().height(Length::Fixed(10.0)),
+        summary,
+        Space::new().height(Length::Fixed(20.0)),
+        buttons
+    ]
+    .spacing(5)
+    .align_x(iced::Alignment::Center);
+
+    let card = modal_card(modal_content).width(Length::Fixed(450.0));
+
+    container(card)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x(Length::Fill)
+        .center_y(Length::Fill)
+        .style(|_| container::Style {
+            background: Some(Color::from_rgba(0.0, 0.0, 0.0, 0.7).into()),
+            ..Default::default()
+        })
+        .into()
+}
+
+/// Confirmation dialog for an action held back by `confirm_delete`.
+fn view_confirm_overlay<'a>(app: &'a GuiApp, pending: &'a PendingConfirm) -> Element<'a, Message> {
+    let summary_of = |uid: &str| {
+        app.store
+            .get_task_ref(uid)
+            .map(|t| t.summary.clone())
+            .unwrap_or_default()
+    };
+    let (icon_char, title, detail, confirm_label) = match pending {
+        PendingConfirm::DeleteTask(uid) => (
+            icon::TRASH,
+            rust_i18n::t!("confirm_delete_task_title").to_string(),
+            summary_of(uid),
+            rust_i18n::t!("delete").to_string(),
+        ),
+        PendingConfirm::DeleteTaskTree(uid) => (
+            icon::TRASH,
+            rust_i18n::t!("confirm_delete_tree_title").to_string(),
+            summary_of(uid),
+            rust_i18n::t!("delete").to_string(),
+        ),
+        PendingConfirm::BulkTag { uids, tag, add } => (
+            icon::TAG,
+            rust_i18n::t!("bulk_tag_edit").to_string(),
+            if *add {
+                rust_i18n::t!("confirm_bulk_tag_add", tag = tag, count = uids.len()).to_string()
+            } else {
+                rust_i18n::t!("confirm_bulk_tag_remove", tag = tag, count = uids.len()).to_string()
+            },
+            rust_i18n::t!("confirm").to_string(),
+        ),
+    };
+
+    let icon_header = container(
+        icon::icon(icon_char)
+            .size(30)
+            .color(Color::from_rgb(0.9, 0.3, 0.3)),
+    )
+    .padding(5)
+    .center_x(Length::Fill);
+
+    let title = text(title)
+        .size(24)
+        .font(iced::Font {
+            weight: iced::font::Weight::Bold,
+            ..Default::default()
+        })
+        .width(Length::Fill)
+        .align_x(Horizontal::Center);
+
+    let summary = text(detail)
+        .size(14)
+        .color(Color::from_rgb(0.7, 0.7, 0.7))
+        .width(Length::Fill)
+        .align_x(Horizontal::Center);
+
+    let cancel_btn = button(text(rust_i18n::t!("cancel")).size(14))
+        .style(iced::widget::button::secondary)
+        .padding([8, 16])
+        .on_press(Message::CancelPendingAction);
+
+    let dont_ask_btn = button(text(rust_i18n::t!("confirm_dont_ask_again")).size(14))
+        .style(iced::widget::button::secondary)
+        .padding([8, 16])
+        .on_press(Message::ConfirmPendingAction(true));
+
+    let confirm_btn = button(text(confirm_label).size(14).font(iced::Font {
+        weight: iced::font::Weight::Bold,
+        ..Default::default()
+    }))
+    .style(iced::widget::button::danger)
+    .padding([8, 16])
+    .on_press(Message::ConfirmPendingAction(false));
+
+    let buttons = row![cancel_btn, dont_ask_btn, confirm_btn]
+        .spacing(10)
+        .align_y(iced::Alignment::Center);
+
+    let modal_content = column![
+        icon_header,
+        title,
+        Space::new().height(Length::Fixed(10.0)),
+        summary,
+        Space::new().height(Length::Fixed(20.0)),
+        buttons
+    ]
+    .spacing(5)
+    .align_x(iced::Alignment::Center);
+
+    let card = modal_card(modal_content).width(Length::Fixed(450.0));
+
+    container(card)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x(Length::Fill)
+        .center_y(Length::Fill)
+        .style(|_| container::Style {
+            background: Some(Color::from_rgba(0.0, 0.0, 0.0, 0.7).into()),
+            ..Default::default()
+        })
+        .into()
+}
+
 fn view_ics_import_overlay<'a>(app: &'a GuiApp) -> Element<'a, Message> {
     let file_name = app
         .ics_import_file_path
@@ -2362,41 +3057,21 @@ fn view_ics_import_overlay<'a>(app: &'a GuiApp) -> Element<'a, Message> {
         select_label,
         Space::new().height(Length::Fixed(10.0)),
         calendar_scroll,
-        Space::new().height(Length::Fixed(20.0)),
+        Space::new().height(Length::Fixed(10.0)),
+        iced::widget::checkbox::<Message, Theme, iced::Renderer>(app.ics_import_conflict_copies)
+            .label(rust_i18n::t!("import_duplicates_as_conflict_copies"))
+            .on_toggle(Message::IcsImportDialogToggleConflictCopies),
+        Space::new().height(Length::Fixed(10.0)),
         buttons
     ]
     .spacing(5)
     .align_x(iced::Alignment::Center);
 
-    let modal_card = container(modal_content)
-        .padding(20)
+    let card = modal_card(modal_content)
         .width(Length::Fixed(450.0))
-        .max_height(600.0)
-        .style(|theme: &Theme| {
-            let palette = theme.extended_palette();
-            container::Style {
-                background: Some(
-                    Color {
-                        a: 0.98,
-                        ..palette.background.weak.color
-                    }
-                    .into(),
-                ),
-                border: iced::Border {
-                    color: palette.background.strong.color,
-                    width: 1.0,
-                    radius: 12.0.into(),
-                },
-                shadow: iced::Shadow {
-                    color: Color::BLACK.scale_alpha(0.5),
-                    offset: Vector::new(0.0, 4.0),
-                    blur_radius: 10.0,
-                },
-                ..Default::default()
-            }
-        });
+        .max_height(600.0);
 
-    container(modal_card)
+    container(card)
         .width(Length::Fill)
         .height(Length::Fill)
         .center_x(Length::Fill)
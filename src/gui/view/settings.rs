@@ -1,10 +1,10 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 // Renders the settings and onboarding screens.
 // File: ./src/gui/view/settings.rs
-use crate::config::{AppTheme, LogLevel};
+use crate::config::{AppTheme, DateFormat, LogLevel, TagColorMode, TimeFormat};
 use crate::gui::icon;
 use crate::gui::message::Message;
-use crate::gui::state::{AppState, GuiApp};
+use crate::gui::state::{AppState, GuiApp, OVERRIDE_PICKER_PREFIX};
 use crate::storage::LOCAL_CALENDAR_HREF;
 
 use iced::widget::{
@@ -223,22 +223,66 @@ pub fn view_settings(app: &GuiApp) -> Element<'_, Message> {
 
     // Theme selection UI
     let theme_picker: Element<_> = if is_settings {
-        container(
-            column![
+        let mut theme_col = column![
+            row![
+                text(rust_i18n::t!("app_theme")),
+                iced::widget::pick_list(
+                    AppTheme::iter().collect::<Vec<_>>(),
+                    Some(app.current_theme),
+                    Message::ThemeChanged
+                )
+            ]
+            .spacing(10)
+            .align_y(iced::Alignment::Center),
+        ]
+        .spacing(10);
+        if app.current_theme == AppTheme::Custom {
+            theme_col = theme_col.push(
                 row![
-                    text(rust_i18n::t!("app_theme")),
-                    iced::widget::pick_list(
-                        AppTheme::iter().collect::<Vec<_>>(),
-                        Some(app.current_theme),
-                        Message::ThemeChanged
-                    )
+                    text(rust_i18n::t!("custom_theme_primary")),
+                    text_input("#FFA500", &app.custom_theme_primary)
+                        .on_input(Message::SetCustomThemePrimary)
+                        .width(Length::Fixed(100.0)),
+                    text(rust_i18n::t!("custom_theme_background")),
+                    text_input("#211E1E", &app.custom_theme_background)
+                        .on_input(Message::SetCustomThemeBackground)
+                        .width(Length::Fixed(100.0)),
                 ]
                 .spacing(10)
                 .align_y(iced::Alignment::Center),
+            );
+        }
+        theme_col = theme_col.push(
+            row![
+                text(rust_i18n::t!("tag_color_mode")),
+                iced::widget::pick_list(
+                    TagColorMode::iter().collect::<Vec<_>>(),
+                    Some(app.tag_color_mode),
+                    Message::SetTagColorMode
+                )
             ]
-            .spacing(10),
-        )
-        .into()
+            .spacing(10)
+            .align_y(iced::Alignment::Center),
+        );
+        theme_col = theme_col.push(
+            row![
+                text(rust_i18n::t!("date_format")),
+                iced::widget::pick_list(
+                    DateFormat::iter().collect::<Vec<_>>(),
+                    Some(app.core_config.date_format),
+                    Message::SetDateFormat
+                ),
+                text(rust_i18n::t!("time_format")),
+                iced::widget::pick_list(
+                    TimeFormat::iter().collect::<Vec<_>>(),
+                    Some(app.core_config.time_format),
+                    Message::SetTimeFormat
+                )
+            ]
+            .spacing(10)
+            .align_y(iced::Alignment::Center),
+        );
+        container(theme_col).into()
     } else {
         Space::new().width(0).into()
     };
@@ -273,6 +317,15 @@ pub fn view_settings(app: &GuiApp) -> Element<'_, Message> {
             ]
             .spacing(10)
             .align_y(iced::Alignment::Center),
+            row![
+                text(rust_i18n::t!("snooze_presets_list_label")),
+                text_input("5m, 15m, 1h, 1d", &app.ob_snooze_presets_input)
+                    .on_input(Message::SetSnoozePresets)
+                    .width(Length::Fixed(200.0))
+                    .padding(5)
+            ]
+            .spacing(10)
+            .align_y(iced::Alignment::Center),
             row![
                 text(rust_i18n::t!("sync_interval_label")).width(Length::Fixed(200.0)),
                 text_input("30m", &app.ob_auto_refresh_input)
@@ -353,6 +406,12 @@ pub fn view_settings(app: &GuiApp) -> Element<'_, Message> {
                     .label(rust_i18n::t!("hide_completed_and_canceled_tasks"))
                     .on_toggle(Message::ToggleHideCompleted),
                 hide_fully_ui,
+                checkbox::<Message, iced::Theme, iced::Renderer>(app.hide_deferred)
+                    .label(rust_i18n::t!("hide_deferred_tasks"))
+                    .on_toggle(Message::ToggleHideDeferred),
+                checkbox::<Message, iced::Theme, iced::Renderer>(app.core_config.fuzzy_search)
+                    .label(rust_i18n::t!("fuzzy_search"))
+                    .on_toggle(Message::ToggleFuzzySearch),
                 tooltip(
                     checkbox::<Message, iced::Theme, iced::Renderer>(app.hide_aliases_in_sidebar)
                         .label(rust_i18n::t!("hide_aliases_in_sidebar"))
@@ -368,13 +427,88 @@ pub fn view_settings(app: &GuiApp) -> Element<'_, Message> {
                 checkbox::<Message, iced::Theme, iced::Renderer>(app.show_priority_numbers)
                     .label(rust_i18n::t!("show_priority_numbers"))
                     .on_toggle(Message::SetShowPriorityNumbers),
+                checkbox::<Message, iced::Theme, iced::Renderer>(app.compact_layout)
+                    .label(rust_i18n::t!("compact_layout"))
+                    .on_toggle(Message::SetCompactLayout),
                 checkbox::<Message, iced::Theme, iced::Renderer>(app.strikethrough_completed)
                     .label(rust_i18n::t!("strikethrough_completed"))
                     .on_toggle(Message::SetStrikethroughCompleted),
+                checkbox::<Message, iced::Theme, iced::Renderer>(app.core_config.confirm_delete)
+                    .label(rust_i18n::t!("confirm_delete"))
+                    .on_toggle(Message::SetConfirmDelete),
+                row![
+                    text(rust_i18n::t!("parent_progress_label")).width(Length::Fixed(200.0)),
+                    iced::widget::pick_list(
+                        crate::config::ParentProgress::iter().collect::<Vec<_>>(),
+                        Some(app.core_config.parent_progress),
+                        Message::SetParentProgress
+                    )
+                    .width(Length::Fill)
+                    .padding(5)
+                ]
+                .spacing(10)
+                .align_y(iced::Alignment::Center),
+                checkbox::<Message, iced::Theme, iced::Renderer>(
+                    app.core_config.show_parse_preview
+                )
+                .label(rust_i18n::t!("show_parse_preview"))
+                .on_toggle(Message::SetShowParsePreview),
+                checkbox::<Message, iced::Theme, iced::Renderer>(app.core_config.minimize_to_tray)
+                    .label(rust_i18n::t!("minimize_to_tray"))
+                    .on_toggle(Message::SetMinimizeToTray),
+                row![
+                    text(rust_i18n::t!("quick_add_hotkey_label")),
+                    text_input("ctrl+alt+space", &app.ob_quick_add_hotkey_input)
+                        .on_input(Message::QuickAddHotkeyInput)
+                        .on_submit(Message::ApplyQuickAddHotkey)
+                        .width(Length::Fixed(160.0))
+                        .padding(5)
+                ]
+                .spacing(10)
+                .align_y(iced::Alignment::Center),
                 checkbox::<Message, iced::Theme, iced::Renderer>(app.sort_standard_by_priority)
                     .label(rust_i18n::t!("sort_standard_by_priority_label"))
                     .on_toggle(Message::ToggleSortStandardByPriority),
                 text(rust_i18n::t!("settings_sort_behavior")).size(18),
+                row![
+                    text(rust_i18n::t!("sort_mode_label")).width(Length::Fixed(200.0)),
+                    iced::widget::pick_list(
+                        crate::config::SortMode::iter().collect::<Vec<_>>(),
+                        Some(app.sort_mode),
+                        Message::SetSortMode
+                    )
+                    .width(Length::Fill)
+                    .padding(5)
+                ]
+                .spacing(10)
+                .align_y(iced::Alignment::Center),
+                checkbox::<Message, iced::Theme, iced::Renderer>(app.sort_urgency_boost)
+                    .label(rust_i18n::t!("sort_urgency_boost_label"))
+                    .on_toggle(Message::SetSortUrgencyBoost),
+                row![
+                    text(rust_i18n::t!("all_day_sort_label")).width(Length::Fixed(200.0)),
+                    iced::widget::pick_list(
+                        crate::config::AllDaySort::iter().collect::<Vec<_>>(),
+                        Some(app.core_config.all_day_sort),
+                        Message::SetAllDaySort
+                    )
+                    .width(Length::Fill)
+                    .padding(5)
+                ]
+                .spacing(10)
+                .align_y(iced::Alignment::Center),
+                row![
+                    text(rust_i18n::t!("agenda_grouping_label")).width(Length::Fixed(200.0)),
+                    iced::widget::pick_list(
+                        crate::config::AgendaGrouping::iter().collect::<Vec<_>>(),
+                        Some(app.core_config.agenda_grouping),
+                        Message::SetAgendaGrouping
+                    )
+                    .width(Length::Fill)
+                    .padding(5)
+                ]
+                .spacing(10)
+                .align_y(iced::Alignment::Center),
                 row![
                     text(rust_i18n::t!("sorting_preset_label")).width(Length::Fixed(200.0)),
                     iced::widget::pick_list(
@@ -490,6 +624,7 @@ pub fn view_settings(app: &GuiApp) -> Element<'_, Message> {
 
                         let icon_char = match action_val {
                             crate::config::TaskAction::CompleteAndShift => icon::REPEAT,
+                            crate::config::TaskAction::SkipOccurrence => icon::ARROW_RIGHT,
                             crate::config::TaskAction::ToggleDetails => icon::INFO,
                             crate::config::TaskAction::ToggleTimer => icon::PLAY,
                             crate::config::TaskAction::StopTimer => icon::DEBUG_STOP,
@@ -504,6 +639,7 @@ pub fn view_settings(app: &GuiApp) -> Element<'_, Message> {
                             crate::config::TaskAction::Yank => icon::LINK,
                             crate::config::TaskAction::Focus => icon::FOCUS_FIELD,
                             crate::config::TaskAction::TogglePin => icon::THUMB_TACK,
+                            crate::config::TaskAction::ToggleWaiting => icon::HOURGLASS_START,
                             crate::config::TaskAction::CreateSubtask => icon::CREATE_CHILD,
                             crate::config::TaskAction::DuplicateTree => icon::CLONE,
                             crate::config::TaskAction::CompleteTree => icon::LIST_CHECK,
@@ -553,6 +689,34 @@ pub fn view_settings(app: &GuiApp) -> Element<'_, Message> {
                     .size(12)
                     .color(Color::from_rgb(0.6, 0.6, 0.6)),
                 Space::new().height(10),
+                row![
+                    text(rust_i18n::t!("export_backup")).width(Length::Fixed(200.0)),
+                    button(text(".ics").size(12))
+                        .padding(5)
+                        .style(button::secondary)
+                        .on_press(Message::ExportBackup(crate::store::ExportFormat::Ics)),
+                    button(text(".json").size(12))
+                        .padding(5)
+                        .style(button::secondary)
+                        .on_press(Message::ExportBackup(crate::store::ExportFormat::Json)),
+                    button(
+                        row![
+                            icon::icon(icon::IMPORT).size(14),
+                            text(rust_i18n::t!("restore_backup")).size(12)
+                        ]
+                        .spacing(3)
+                        .align_y(iced::Alignment::Center),
+                    )
+                    .padding(5)
+                    .style(button::secondary)
+                    .on_press(Message::RestoreBackup),
+                ]
+                .spacing(10)
+                .align_y(iced::Alignment::Center),
+                text(rust_i18n::t!("export_backup_explain"))
+                    .size(12)
+                    .color(Color::from_rgb(0.6, 0.6, 0.6)),
+                Space::new().height(10),
                 row![
                     text(rust_i18n::t!("implicit_goal_duration")).width(Length::Fixed(200.0)),
                     text_input("60", &app.ob_default_duration_goal_mins_input)
@@ -569,6 +733,9 @@ pub fn view_settings(app: &GuiApp) -> Element<'_, Message> {
                 checkbox::<Message, iced::Theme, iced::Renderer>(app.sessions_count_as_completions)
                     .label(rust_i18n::t!("sessions_count_as_completions"))
                     .on_toggle(Message::SetSessionsCountAsCompletions),
+                checkbox::<Message, iced::Theme, iced::Renderer>(app.core_config.archive_completed)
+                    .label(rust_i18n::t!("archive_completed"))
+                    .on_toggle(Message::SetArchiveCompleted),
                 Space::new().height(10),
                 text(rust_i18n::t!("logging_label")).size(18),
                 row![
@@ -849,6 +1016,19 @@ pub fn view_settings(app: &GuiApp) -> Element<'_, Message> {
         ]
         .spacing(10);
 
+        if !app.alias_problems.is_empty() {
+            let danger = Color::from_rgb(0.9, 0.4, 0.4);
+            list_col = list_col.push(
+                text(rust_i18n::t!("alias_problems_disabled"))
+                    .size(14)
+                    .color(danger),
+            );
+            for problem in &app.alias_problems {
+                list_col = list_col.push(text(problem.clone()).size(12).color(danger));
+            }
+            list_col = list_col.push(iced::widget::rule::horizontal(1));
+        }
+
         let mut sorted_aliases: Vec<_> = app.tag_aliases.iter().collect();
         sorted_aliases.sort_by_key(|(k, _)| k.to_string());
 
@@ -913,6 +1093,158 @@ pub fn view_settings(app: &GuiApp) -> Element<'_, Message> {
         Space::new().width(0).into()
     };
 
+    let journal_ui: Element<_> = if is_settings {
+        let mut list_col = column![
+            text(rust_i18n::t!("sync_queue_title")).size(20),
+            text(rust_i18n::t!("sync_queue_explain"))
+                .size(12)
+                .color(Color::from_rgb(0.6, 0.6, 0.6)),
+            iced::widget::rule::horizontal(1)
+        ]
+        .spacing(10);
+
+        if app.journal_items.is_empty() {
+            list_col = list_col.push(text(rust_i18n::t!("sync_queue_empty")).size(14));
+        } else {
+            let mut compact_row = row![
+                button(text(rust_i18n::t!("sync_queue_compact")).size(12))
+                    .padding(5)
+                    .style(button::secondary)
+                    .on_press(Message::CompactJournal)
+            ]
+            .spacing(10)
+            .align_y(iced::Alignment::Center);
+            if let Some(note) = &app.journal_compact_note {
+                compact_row = compact_row.push(
+                    text(note.clone())
+                        .size(12)
+                        .color(Color::from_rgb(0.6, 0.6, 0.6)),
+                );
+            }
+            list_col = list_col.push(compact_row);
+        }
+
+        for (index, (verb, summary, uid)) in app.journal_items.iter().enumerate() {
+            let row_item = row![
+                text(verb.clone())
+                    .size(14)
+                    .width(Length::Fixed(80.0))
+                    .color(Color::from_rgb(0.6, 0.6, 0.6)),
+                text(summary.clone()).size(14).width(Length::Fill),
+                button(icon::icon(icon::TRASH).size(14))
+                    .style(button::danger)
+                    .padding(6)
+                    .on_press(Message::DropJournalAction(index, uid.clone()))
+            ]
+            .spacing(10)
+            .align_y(iced::Alignment::Center);
+            list_col = list_col.push(row_item);
+        }
+
+        let area = container(list_col).padding(10).style(|_| container::Style {
+            border: iced::Border {
+                radius: 4.0.into(),
+                width: 1.0,
+                color: Color::from_rgb(0.3, 0.3, 0.3),
+            },
+            ..Default::default()
+        });
+        area.into()
+    } else {
+        Space::new().width(0).into()
+    };
+
+    let templates_ui: Element<_> = if is_settings {
+        let input_row = row![
+            text_input(&rust_i18n::t!("template_label"), &app.template_input_label)
+                .on_input(Message::TemplateLabelInput)
+                .padding(5)
+                .width(Length::FillPortion(1)),
+            text_input(
+                &rust_i18n::t!("template_smart_label"),
+                &app.template_input_smart
+            )
+            .on_input(Message::TemplateSmartInput)
+            .on_submit(Message::AddTemplate)
+            .padding(5)
+            .width(Length::FillPortion(2)),
+            if app.editing_template_idx.is_some() {
+                row![
+                    button(icon::icon(icon::CHECK).size(14))
+                        .style(button::success)
+                        .padding(6)
+                        .on_press(Message::AddTemplate),
+                    button(icon::icon(icon::CROSS).size(14))
+                        .style(button::danger)
+                        .padding(6)
+                        .on_press(Message::CancelEditTemplate)
+                ]
+                .spacing(5)
+            } else {
+                row![
+                    button(text(rust_i18n::t!("add")))
+                        .padding(5)
+                        .on_press(Message::AddTemplate)
+                ]
+            }
+        ]
+        .spacing(10)
+        .align_y(iced::Alignment::Center);
+
+        let mut list_col = column![
+            text(rust_i18n::t!("quick_templates")).size(20),
+            input_row,
+            iced::widget::rule::horizontal(1)
+        ]
+        .spacing(10);
+
+        for (idx, tpl) in app.quick_templates.iter().enumerate() {
+            let is_editing_this = app.editing_template_idx == Some(idx);
+            let style = if is_editing_this {
+                |theme: &Theme| text::Style {
+                    color: Some(theme.extended_palette().primary.base.color),
+                }
+            } else {
+                |_: &Theme| text::Style::default()
+            };
+
+            let row_item = row![
+                text(tpl.label.clone())
+                    .width(Length::FillPortion(1))
+                    .wrapping(iced::widget::text::Wrapping::Glyph)
+                    .style(style),
+                text("->").width(Length::Fixed(20.0)),
+                text(tpl.smart.clone())
+                    .width(Length::FillPortion(2))
+                    .wrapping(iced::widget::text::Wrapping::Glyph)
+                    .style(style),
+                button(icon::icon(icon::EDIT).size(12))
+                    .style(button::secondary)
+                    .padding(5)
+                    .on_press(Message::EditTemplate(idx)),
+                button(icon::icon(icon::CROSS).size(12))
+                    .style(button::danger)
+                    .padding(5)
+                    .on_press(Message::RemoveTemplate(idx))
+            ]
+            .spacing(10)
+            .align_y(iced::Alignment::Center);
+            list_col = list_col.push(row_item);
+        }
+
+        let area = container(list_col).padding(10).style(|_| container::Style {
+            border: iced::Border {
+                radius: 4.0.into(),
+                width: 1.0,
+                color: Color::from_rgb(0.3, 0.3, 0.3),
+            },
+            ..Default::default()
+        });
+        area.into()
+    } else {
+        Space::new().width(0).into()
+    };
+
     let collections_ui: Element<_> = if is_settings {
         let mut col = column![
             text(rust_i18n::t!("manage_collections")).size(20),
@@ -1085,7 +1417,7 @@ pub fn view_settings(app: &GuiApp) -> Element<'_, Message> {
             };
 
             let save_btn: Element<_> = if !is_local {
-                let original_cal = app.calendars.iter().find(|c| c.href == cal_href);
+                let original_cal = app.remote_cals_saved.iter().find(|c| c.href == cal_href);
                 let has_changes = original_cal.is_none_or(|orig| orig.name != cal.name);
                 if has_changes {
                     button(
@@ -1174,6 +1506,81 @@ pub fn view_settings(app: &GuiApp) -> Element<'_, Message> {
 
         col = col.push(add_buttons_row);
 
+        // Device-local names and colors, shown instead of what the server (or local
+        // registry) reports. Handy for shared calendars with unhelpful names.
+        col = col.push(text(rust_i18n::t!("calendar_overrides")).size(16));
+        col = col.push(
+            text(rust_i18n::t!("calendar_overrides_hint"))
+                .size(12)
+                .color(Color::from_rgb(0.6, 0.6, 0.6)),
+        );
+        for cal in &app.calendars {
+            if cal.href == crate::storage::LOCAL_TRASH_HREF || cal.href == "local://recovery" {
+                continue;
+            }
+            let base_name = app
+                .remote_cals_saved
+                .iter()
+                .chain(app.local_cals_editing.iter())
+                .find(|c| c.href == cal.href)
+                .map(|c| c.name.as_str())
+                .unwrap_or(cal.name.as_str());
+            let ov = app.core_config.calendar_overrides.get(&cal.href);
+            let override_name = ov.and_then(|o| o.name.as_deref()).unwrap_or("");
+
+            let name_input = text_input(base_name, override_name)
+                .on_input({
+                    let h = cal.href.clone();
+                    move |s| Message::CalendarOverrideNameChanged(h.clone(), s)
+                })
+                .padding(5)
+                .width(Length::Fill);
+
+            let current_color = cal
+                .color
+                .as_ref()
+                .and_then(|h| crate::color_utils::parse_hex_to_floats(h))
+                .map(|(r, g, b)| Color::from_rgb(r, g, b))
+                .unwrap_or(Color::from_rgb(0.5, 0.5, 0.5));
+            let picker_key = format!("{}{}", OVERRIDE_PICKER_PREFIX, cal.href);
+            let color_btn = button(
+                text(icon::PALETTE_COLOR.to_string())
+                    .font(icon::FONT)
+                    .size(16)
+                    .color(current_color),
+            )
+            .padding(5)
+            .style(button::text)
+            .on_press(Message::OpenColorPicker(picker_key.clone(), current_color));
+            let color_widget: Element<_> =
+                if app.color_picker_active_href.as_ref() == Some(&picker_key) {
+                    color_picker::ColorPicker::new(
+                        true,
+                        current_color,
+                        color_btn,
+                        Message::CancelColorPicker,
+                        Message::SubmitColorPicker,
+                    )
+                    .into()
+                } else {
+                    color_btn.into()
+                };
+
+            let reset_btn = button(icon::icon(icon::CROSS).size(14))
+                .style(button::text)
+                .padding(5)
+                .on_press_maybe(
+                    ov.is_some()
+                        .then(|| Message::ClearCalendarOverride(cal.href.clone())),
+                );
+
+            col = col.push(
+                row![name_input, color_widget, reset_btn]
+                    .spacing(5)
+                    .align_y(iced::Alignment::Center),
+            );
+        }
+
         container(col)
             .padding(10)
             .style(|_| container::Style {
@@ -1198,6 +1605,55 @@ pub fn view_settings(app: &GuiApp) -> Element<'_, Message> {
     .width(Length::Fill)
     .on_press(Message::ObSubmit);
 
+    let testing = matches!(&app.connection_diagnostics, Some(steps) if steps.is_empty());
+    let test_connection_btn = button(text(if testing {
+        rust_i18n::t!("testing_connection")
+    } else {
+        rust_i18n::t!("test_connection")
+    }))
+    .padding(10)
+    .width(Length::Fill)
+    .style(button::secondary)
+    .on_press_maybe((!testing && !app.ob_url.trim().is_empty()).then_some(Message::TestConnection));
+
+    let diagnostics_ui: Element<_> = match &app.connection_diagnostics {
+        Some(steps) if !steps.is_empty() => steps
+            .iter()
+            .fold(column![].spacing(4), |col, s| {
+                let label = match s.step.as_str() {
+                    "principal" => rust_i18n::t!("diagnose_step_principal"),
+                    "home_set" => rust_i18n::t!("diagnose_step_home_set"),
+                    _ => rust_i18n::t!("diagnose_step_calendars"),
+                };
+                let mut line = format!("{} ({} ms)", label, s.elapsed_ms);
+                if let Some(code) = s.status {
+                    line.push_str(&format!(" — HTTP {}", code));
+                }
+                let color = if s.ok {
+                    Color::from_rgb(0.3, 0.7, 0.4)
+                } else {
+                    Color::from_rgb(0.85, 0.3, 0.3)
+                };
+                col.push(
+                    row![
+                        icon::icon(if s.ok { icon::CHECK } else { icon::CROSS })
+                            .size(14)
+                            .color(color),
+                        column![
+                            text(line).size(14),
+                            text(&s.detail)
+                                .size(12)
+                                .color(Color::from_rgb(0.6, 0.6, 0.6)),
+                        ]
+                        .spacing(2)
+                    ]
+                    .spacing(8),
+                )
+            })
+            .into(),
+        _ => Space::new().height(0).into(),
+    };
+
     let insecure_check = checkbox::<Message, iced::Theme, iced::Renderer>(app.ob_insecure)
         .label(rust_i18n::t!("allow_insecure_ssl"))
         .on_toggle(Message::ObInsecureToggled)
@@ -1253,7 +1709,11 @@ pub fn view_settings(app: &GuiApp) -> Element<'_, Message> {
                 checkbox::<Message, iced::Theme, iced::Renderer>(app.sync_settings)
                     .label(rust_i18n::t!("sync_settings"))
                     .on_toggle(Message::SetSyncSettings),
-                save_connect_btn
+                checkbox::<Message, iced::Theme, iced::Renderer>(app.limit_fetch_to_cutoff)
+                    .label(rust_i18n::t!("limit_fetch_to_cutoff"))
+                    .on_toggle(Message::SetLimitFetchToCutoff),
+                row![test_connection_btn, save_connect_btn].spacing(10),
+                diagnostics_ui
             ]
             .spacing(15)
         )
@@ -1273,8 +1733,10 @@ pub fn view_settings(app: &GuiApp) -> Element<'_, Message> {
         collections_ui,
         notifications_ui,
         aliases_ui,
+        templates_ui,
         goals_ui,
         advanced_ui,
+        journal_ui,
         // 3. Bottom Actions
         offline_button_or_space,
     ]
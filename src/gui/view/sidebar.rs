@@ -2,25 +2,27 @@
 // File: ./src/gui/view/sidebar.rs
 // Renders the sidebar (calendars, tags, locations) for the GUI.
 
-use super::tooltip_style;
+use super::{Density, tooltip_style};
 use crate::color_utils;
 use crate::gui::icon;
 use crate::gui::message::Message;
 use crate::gui::state::GuiApp;
 use crate::storage::LOCAL_TRASH_HREF;
 
-use crate::store::UNCATEGORIZED_ID;
+use crate::store::{NO_LOCATION_ID, UNCATEGORIZED_ID};
 use iced::never;
 use iced::widget::{
-    MouseArea, Space, button, column, container, rich_text, row, scrollable, span, text, toggler,
-    tooltip,
+    MouseArea, Space, button, column, container, rich_text, row, scrollable, span, text,
+    text_input, toggler, tooltip,
 };
 use iced::{Color, Element, Length, Theme};
+use iced_aw::color_picker;
 use std::time::Duration;
 
 // --- CALENDARS ---
 pub fn view_sidebar_calendars(app: &GuiApp) -> Element<'_, Message> {
     let visible_calendars = app.get_filtered_calendars();
+    let density = Density::of(app);
 
     let are_all_visible = visible_calendars
         .iter()
@@ -34,7 +36,7 @@ pub fn view_sidebar_calendars(app: &GuiApp) -> Element<'_, Message> {
             toggler::Status::Active { is_toggled } | toggler::Status::Hovered { is_toggled }
                 if is_toggled =>
             {
-                style.background = Color::from_rgb(1.0, 0.6, 0.0).into();
+                style.background = theme.palette().primary.into();
                 style.foreground = theme.extended_palette().background.base.text.into();
             }
             _ => {}
@@ -96,7 +98,7 @@ pub fn view_sidebar_calendars(app: &GuiApp) -> Element<'_, Message> {
                     color: Some(icon_color),
                 }))
                 .style(button::text)
-                .padding(8)
+                .padding(density.calendar_padding - 2.0)
                 .on_press(Message::ToggleCalendarVisibility(
                     cal.href.clone(),
                     !is_visible,
@@ -115,18 +117,24 @@ pub fn view_sidebar_calendars(app: &GuiApp) -> Element<'_, Message> {
                 .style(tooltip_style)
                 .delay(Duration::from_millis(700));
 
-                let mut label = button(text(&cal.name).size(16))
+                let mut label = button(text(&cal.name).size(density.sidebar_font_size))
                     .width(Length::Fill)
-                    .padding(10)
+                    .padding(density.calendar_padding)
                     .on_press(Message::SelectCalendar(cal.href.clone()));
                 if is_target {
-                    label = label.style(move |_theme: &Theme, _status| button::Style {
-                        text_color: Color::from_rgb(1.0, 0.6, 0.0),
-                        background: Some(Color::from_rgba(1.0, 0.6, 0.0, 0.05).into()),
+                    label = label.style(move |theme: &Theme, _status| button::Style {
+                        text_color: theme.palette().primary,
+                        background: Some(
+                            Color {
+                                a: 0.05,
+                                ..theme.palette().primary
+                            }
+                            .into(),
+                        ),
                         border: if is_kb_selected {
                             iced::Border {
                                 width: 1.0,
-                                color: Color::from_rgb(1.0, 0.6, 0.0),
+                                color: theme.palette().primary,
                                 radius: 4.0.into(),
                             }
                         } else {
@@ -165,7 +173,7 @@ pub fn view_sidebar_calendars(app: &GuiApp) -> Element<'_, Message> {
 
                 let focus_btn = button(icon::icon(icon::ARROW_RIGHT).size(14))
                     .style(button::text)
-                    .padding(10)
+                    .padding(density.calendar_padding)
                     .on_press(Message::IsolateCalendar(cal.href.clone()));
 
                 let focus_tooltip = tooltip(
@@ -176,7 +184,34 @@ pub fn view_sidebar_calendars(app: &GuiApp) -> Element<'_, Message> {
                 .style(tooltip_style)
                 .delay(Duration::from_millis(700));
 
-                row![vis_tooltip, label, focus_tooltip]
+                let picker_color =
+                    cal_color.unwrap_or(theme.extended_palette().background.weak.text);
+                let color_btn =
+                    button(
+                        icon::icon(icon::PALETTE_COLOR)
+                            .size(12)
+                            .style(move |_| text::Style {
+                                color: Some(picker_color),
+                            }),
+                    )
+                    .style(button::text)
+                    .padding(density.calendar_padding)
+                    .on_press(Message::OpenColorPicker(cal.href.clone(), picker_color));
+                let color_widget: Element<'_, Message> =
+                    if app.color_picker_active_href.as_ref() == Some(&cal.href) {
+                        color_picker::ColorPicker::new(
+                            true,
+                            picker_color,
+                            color_btn,
+                            Message::CancelColorPicker,
+                            Message::SubmitColorPicker,
+                        )
+                        .into()
+                    } else {
+                        color_btn.into()
+                    };
+
+                row![vis_tooltip, label, color_widget, focus_tooltip]
                     .spacing(0)
                     .align_y(iced::Alignment::Center)
                     .into()
@@ -186,19 +221,54 @@ pub fn view_sidebar_calendars(app: &GuiApp) -> Element<'_, Message> {
     .spacing(2)
     .width(Length::Fill);
 
-    column![
+    let mut content = column![
         toggle_container,
         scrollable(list)
             .height(Length::Fill)
             .id(app.sidebar_scrollable_id.clone())
     ]
-    .spacing(5)
-    .into()
+    .spacing(5);
+
+    // MKCALENDAR needs a live server connection, so only offer it while online.
+    if app.client.is_some() {
+        let new_cal: Element<'_, Message> = match &app.new_calendar_input {
+            Some(name) => row![
+                text_input(&rust_i18n::t!("new_calendar_name"), name)
+                    .on_input(Message::NewCalendarInputChanged)
+                    .on_submit(Message::CreateCalendarFromSidebar)
+                    .size(12)
+                    .padding(5)
+                    .width(Length::Fill),
+                button(icon::icon(icon::CHECK).size(14))
+                    .style(button::primary)
+                    .padding(5)
+                    .on_press_maybe(
+                        (!name.trim().is_empty()).then_some(Message::CreateCalendarFromSidebar)
+                    ),
+                button(icon::icon(icon::CROSS).size(14))
+                    .style(button::text)
+                    .padding(5)
+                    .on_press(Message::ToggleNewCalendarInput),
+            ]
+            .spacing(5)
+            .align_y(iced::Alignment::Center)
+            .into(),
+            None => button(text(rust_i18n::t!("new_calendar_button")).size(12))
+                .style(button::text)
+                .padding(5)
+                .on_press_maybe((!app.loading).then_some(Message::ToggleNewCalendarInput))
+                .into(),
+        };
+        content = content.push(new_cal);
+    }
+
+    content.into()
 }
 
 // --- CATEGORIES ---
 pub fn view_sidebar_categories(app: &GuiApp) -> Element<'_, Message> {
     let all_cats = &app.cached_categories;
+    let density = Density::of(app);
 
     let is_filter_empty = app.tasks.is_empty() && app.store.has_any_tasks();
     let has_selection = !app.session.selected_categories.is_empty();
@@ -261,9 +331,32 @@ pub fn view_sidebar_categories(app: &GuiApp) -> Element<'_, Message> {
     .style(tooltip_style)
     .delay(Duration::from_millis(700));
 
+    let inbox_active = app
+        .search_value
+        .text()
+        .split_whitespace()
+        .any(|w| w.eq_ignore_ascii_case("is:inbox"));
+    let inbox_btn = button(text(rust_i18n::t!("inbox_count", count = app.inbox_count)).size(12))
+        .style(if inbox_active {
+            button::primary
+        } else {
+            button::secondary
+        })
+        .padding(5)
+        .on_press(Message::ToggleInboxFilter);
+
+    let inbox_tooltip = tooltip(
+        inbox_btn,
+        text(rust_i18n::t!("inbox_tooltip")).size(12),
+        tooltip::Position::Top,
+    )
+    .style(tooltip_style)
+    .delay(Duration::from_millis(700));
+
     let header = row![
         clear_tooltip,
         Space::new().width(Length::Fill),
+        inbox_tooltip,
         logic_tooltip
     ]
     .spacing(5)
@@ -374,7 +467,7 @@ pub fn view_sidebar_categories(app: &GuiApp) -> Element<'_, Message> {
                     let cat_clone_toggle = cat.clone();
                     let cat_clone_focus = cat.clone();
 
-                    let (r, g, b) = color_utils::generate_color(cat);
+                    let (r, g, b) = color_utils::tag_color(cat, app.tag_color_mode, true);
                     let tag_color = Color::from_rgb(r, g, b);
 
                     let icon_char = if is_selected {
@@ -383,7 +476,7 @@ pub fn view_sidebar_categories(app: &GuiApp) -> Element<'_, Message> {
                         icon::TAG_OUTLINE
                     };
 
-                    let icon_content = icon::icon(icon_char).size(16);
+                    let icon_content = icon::icon(icon_char).size(density.sidebar_font_size);
 
                     let icon_btn = button(icon_content)
                         .style(move |_theme: &Theme, status: button::Status| {
@@ -413,7 +506,7 @@ pub fn view_sidebar_categories(app: &GuiApp) -> Element<'_, Message> {
                             app.theme().extended_palette().background.base.text
                         };
                         text(format!("{} ({})", item.display_name, count))
-                            .size(16)
+                            .size(density.sidebar_font_size)
                             .color(color)
                             .into()
                     } else {
@@ -431,7 +524,7 @@ pub fn view_sidebar_categories(app: &GuiApp) -> Element<'_, Message> {
                             span(prefix).color(tag_color),
                             span(format!("{} ({})", item.display_name, count)).color(text_color)
                         ]
-                        .size(16)
+                        .size(density.sidebar_font_size)
                         .on_link_click(never)
                         .into()
                     };
@@ -524,7 +617,57 @@ pub fn view_sidebar_categories(app: &GuiApp) -> Element<'_, Message> {
         .spacing(2)
     };
 
-    let scroll_content = tags_column.push(Space::new().height(10)).push(dur_filters);
+    let has_bulk_tag = !app.bulk_tag_input.trim().trim_start_matches('#').is_empty();
+    let has_rename = has_bulk_tag && !app.bulk_tag_rename_input.trim().is_empty();
+    let bulk_tags = column![
+        iced::widget::rule::horizontal(1),
+        text(rust_i18n::t!("bulk_tag_edit"))
+            .size(14)
+            .color(Color::from_rgb(0.7, 0.7, 0.7)),
+        text_input(&rust_i18n::t!("bulk_tag_placeholder"), &app.bulk_tag_input)
+            .on_input(Message::BulkTagInputChanged)
+            .size(12)
+            .padding(5),
+        row![
+            button(text(rust_i18n::t!("bulk_tag_add_visible")).size(12))
+                .style(button::secondary)
+                .padding(5)
+                .on_press_maybe(has_bulk_tag.then_some(Message::BulkTagVisible(true))),
+            button(text(rust_i18n::t!("bulk_tag_remove_visible")).size(12))
+                .style(button::secondary)
+                .padding(5)
+                .on_press_maybe(has_bulk_tag.then_some(Message::BulkTagVisible(false))),
+        ]
+        .spacing(5),
+        row![
+            text_input(
+                &rust_i18n::t!("bulk_tag_rename_placeholder"),
+                &app.bulk_tag_rename_input
+            )
+            .on_input(Message::BulkTagRenameInputChanged)
+            .on_submit(Message::RenameTagEverywhere)
+            .size(12)
+            .padding(5)
+            .width(Length::Fill),
+            button(text(rust_i18n::t!("bulk_tag_rename")).size(12))
+                .style(button::secondary)
+                .padding(5)
+                .on_press_maybe(has_rename.then_some(Message::RenameTagEverywhere)),
+        ]
+        .spacing(5)
+        .align_y(iced::Alignment::Center),
+    ]
+    .spacing(8)
+    .padding(iced::Padding {
+        top: 10.0,
+        right: 14.0,
+        ..Default::default()
+    });
+
+    let scroll_content = tags_column
+        .push(Space::new().height(10))
+        .push(dur_filters)
+        .push(bulk_tags);
 
     column![
         header,
@@ -539,6 +682,7 @@ pub fn view_sidebar_categories(app: &GuiApp) -> Element<'_, Message> {
 // --- LOCATIONS ---
 pub fn view_sidebar_locations(app: &GuiApp) -> Element<'_, Message> {
     let all_locs = &app.cached_locations;
+    let accent = app.theme().palette().primary;
     let has_selection = !app.session.selected_locations.is_empty();
 
     let is_filter_empty = app.tasks.is_empty() && app.store.has_any_tasks();
@@ -614,8 +758,11 @@ pub fn view_sidebar_locations(app: &GuiApp) -> Element<'_, Message> {
                     let loc_clone_toggle = loc.clone();
                     let loc_clone_focus = loc.clone();
 
+                    let is_no_location = loc == NO_LOCATION_ID;
                     let (icon_char, icon_color) = if is_selected {
-                        (icon::CHECK_CIRCLE, Color::from_rgb(1.0, 0.6, 0.0))
+                        (icon::CHECK_CIRCLE, accent)
+                    } else if is_no_location {
+                        (icon::MAP_MARKER_OFF, Color::from_rgb(0.5, 0.5, 0.5))
                     } else {
                         (icon::MAP_PIN, Color::from_rgb(0.5, 0.5, 0.5))
                     };
@@ -625,7 +772,12 @@ pub fn view_sidebar_locations(app: &GuiApp) -> Element<'_, Message> {
                         .padding(2)
                         .on_press(Message::LocationToggled(loc_clone_toggle.clone()));
 
-                    let label = rich_text![span(format!("{} ({})", item.display_name, count))]
+                    let display_name = if is_no_location {
+                        rust_i18n::t!("no_location").to_string()
+                    } else {
+                        item.display_name.clone()
+                    };
+                    let label = rich_text![span(format!("{} ({})", display_name, count))]
                         .size(14)
                         .on_link_click(never);
 
@@ -2,6 +2,7 @@
 // File: ./src/gui/view/syntax.rs
 // Implements syntax highlighting for the smart input editor.
 use crate::color_utils;
+use crate::config::TagColorMode;
 use crate::model::parser::{SyntaxType, tokenize_smart_input};
 use iced::advanced::text::highlighter::{self, Highlighter};
 use iced::{Color, Font};
@@ -11,6 +12,7 @@ use std::ops::Range;
 pub struct SmartInputHighlighter {
     is_dark: bool,
     is_search: bool,
+    tag_color_mode: TagColorMode,
 }
 
 impl Default for SmartInputHighlighter {
@@ -18,13 +20,14 @@ impl Default for SmartInputHighlighter {
         Self {
             is_dark: true,
             is_search: false,
+            tag_color_mode: TagColorMode::default(),
         } // Default: dark=true, search=false
     }
 }
 
 impl Highlighter for SmartInputHighlighter {
-    // Settings: (is_dark, is_search)
-    type Settings = (bool, bool); // (is_dark, is_search)
+    // Settings: (is_dark, is_search, tag_color_mode)
+    type Settings = (bool, bool, TagColorMode); // (is_dark, is_search, tag_color_mode)
     type Highlight = highlighter::Format<Font>;
     type Iterator<'a> = std::vec::IntoIter<(Range<usize>, Self::Highlight)>;
 
@@ -32,12 +35,14 @@ impl Highlighter for SmartInputHighlighter {
         Self {
             is_dark: settings.0,
             is_search: settings.1,
+            tag_color_mode: settings.2,
         }
     }
 
     fn update(&mut self, settings: &Self::Settings) {
         self.is_dark = settings.0;
         self.is_search = settings.1;
+        self.tag_color_mode = settings.2;
     }
 
     fn highlight_line(&mut self, line: &str) -> Self::Iterator<'_> {
@@ -82,7 +87,7 @@ impl Highlighter for SmartInputHighlighter {
                     SyntaxType::Tag => {
                         let text = &line[t.start..t.end];
                         let tag_name = text.trim_start_matches('#');
-                        let (r, g, b) = color_utils::generate_color(tag_name);
+                        let (r, g, b) = color_utils::tag_color(tag_name, self.tag_color_mode, true);
                         highlighter::Format {
                             color: Some(Color::from_rgb(r, g, b)),
                             font: Some(Font {
@@ -255,7 +260,7 @@ impl Highlighter for SessionHighlighter {
                     color: Some(Color::from_rgb(0.6, 0.6, 0.6)),
                     font: None,
                 }
-            } else if crate::model::parser::parse_smart_date_with_lex(&lower, lex).is_some()
+            } else if crate::model::parser::is_smart_date_with_lex(&lower, lex)
                 || crate::model::parser::parse_weekday_code_with_lex(&lower, lex).is_some()
             {
                 // Date matches
@@ -297,25 +302,34 @@ impl Highlighter for SessionHighlighter {
 
 pub struct MarkdownHighlighter {
     is_dark: bool,
+    tag_color_mode: TagColorMode,
 }
 
 impl Default for MarkdownHighlighter {
     fn default() -> Self {
-        Self { is_dark: true }
+        Self {
+            is_dark: true,
+            tag_color_mode: TagColorMode::default(),
+        }
     }
 }
 
 impl Highlighter for MarkdownHighlighter {
-    type Settings = bool;
+    // Settings: (is_dark, tag_color_mode)
+    type Settings = (bool, TagColorMode);
     type Highlight = highlighter::Format<Font>;
     type Iterator<'a> = std::vec::IntoIter<(Range<usize>, Self::Highlight)>;
 
     fn new(settings: &Self::Settings) -> Self {
-        Self { is_dark: *settings }
+        Self {
+            is_dark: settings.0,
+            tag_color_mode: settings.1,
+        }
     }
 
     fn update(&mut self, settings: &Self::Settings) {
-        self.is_dark = *settings;
+        self.is_dark = settings.0;
+        self.tag_color_mode = settings.1;
     }
 
     fn change_line(&mut self, _line: usize) {}
@@ -716,7 +730,8 @@ impl Highlighter for MarkdownHighlighter {
                     },
                     crate::model::parser::SyntaxType::Tag => {
                         let tag_name = text.trim_start_matches('#');
-                        let (r, g, b) = crate::color_utils::generate_color(tag_name);
+                        let (r, g, b) =
+                            crate::color_utils::tag_color(tag_name, self.tag_color_mode, true);
                         highlighter::Format {
                             color: Some(Color::from_rgb(r, g, b)),
                             font: Some(Font {
@@ -5,8 +5,8 @@ use crate::color_utils;
 use crate::gui::icon;
 use crate::gui::message::Message;
 use crate::gui::state::GuiApp;
-use crate::gui::view::COLOR_LOCATION;
 use crate::gui::view::focusable::focusable;
+use crate::gui::view::{COLOR_LOCATION, Density};
 
 use crate::model::display::random_related_icon;
 use chrono::Utc;
@@ -16,9 +16,46 @@ use std::time::Duration;
 
 use super::tooltip_style;
 use iced::widget::{
-    Space, button, column, container, rich_text, row, span, text, text_editor, tooltip,
+    Space, button, column, container, rich_text, row, span, text, text_editor, text_input, tooltip,
 };
 
+/// Splits spans so the parts matching a plain search word render bold and underlined.
+fn highlight_matches(
+    spans: Vec<iced::widget::text::Span<'static, String>>,
+    words: &[String],
+) -> Vec<iced::widget::text::Span<'static, String>> {
+    let mut out = Vec::with_capacity(spans.len());
+    for sp in spans {
+        let text_str = sp.text.to_string();
+        let ranges = crate::model::matcher::match_ranges(&text_str, words);
+        if ranges.is_empty() {
+            out.push(sp);
+            continue;
+        }
+        let mut pos = 0;
+        for r in ranges {
+            if r.start > pos {
+                let mut plain = sp.clone();
+                plain.text = text_str[pos..r.start].to_string().into();
+                out.push(plain);
+            }
+            let mut hit = sp.clone().underline(true).font(iced::Font {
+                weight: iced::font::Weight::Bold,
+                ..sp.font.unwrap_or_default()
+            });
+            hit.text = text_str[r.clone()].to_string().into();
+            out.push(hit);
+            pos = r.end;
+        }
+        if pos < text_str.len() {
+            let mut rest = sp;
+            rest.text = text_str[pos..].to_string().into();
+            out.push(rest);
+        }
+    }
+    out
+}
+
 pub fn parse_inline_markdown(
     text_str: &str,
     base_color: Color,
@@ -231,6 +268,32 @@ use iced::{Color, Element, Length, Theme};
 
 // Helper inside the file to provide generic action styles
 
+/// Thin bar under the summary for a partially done task; the filled part's share of the
+/// width follows `percent_complete`.
+fn progress_bar<'a>(percent: u8, dim_factor: f32) -> Element<'a, Message> {
+    let filled = container(Space::new().height(3))
+        .width(Length::FillPortion(percent as u16))
+        .style(move |_| container::Style {
+            background: Some(Color::from_rgba(0.3, 0.7, 0.4, 0.8 * dim_factor).into()),
+            border: iced::Border {
+                radius: 1.5.into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+    let track = container(Space::new().height(3))
+        .width(Length::FillPortion(100 - percent as u16))
+        .style(move |_| container::Style {
+            background: Some(Color::from_rgba(0.5, 0.5, 0.5, 0.2 * dim_factor).into()),
+            border: iced::Border {
+                radius: 1.5.into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+    row![filled, track].width(Length::Fill).into()
+}
+
 /// Generate a random example for session logging syntax
 fn random_session_example() -> String {
     const DURATIONS: &[&str] = &["30m", "1h", "2h", "6h", "14:00-15:30", "09:00-10:15"];
@@ -287,6 +350,7 @@ pub fn view_task_row<'a>(
     item: &'a crate::store::TaskListItem,
     row_id: iced::widget::Id,
 ) -> Element<'a, Message> {
+    let (date_fmt, time_fmt) = (app.core_config.date_format, app.core_config.time_format);
     match item {
         crate::store::TaskListItem::ExpandGroup(key, depth) => {
             let indent_size = if app.active_cal_href.is_some() {
@@ -365,9 +429,18 @@ pub fn view_task_row<'a>(
             let visible_tags = &task.visible_categories;
             let visible_location = &task.visible_location;
 
-            let mut font_size = 20;
-            if task.is_note {
-                font_size = if task.parent_uid.is_none() { 22 } else { 20 };
+            // Across-calendar views (no active collection) get a badge naming the task's
+            // calendar; with a single collection selected it would only add clutter.
+            let calendar_badge = if app.active_cal_href.is_none() {
+                app.calendars.iter().find(|c| c.href == task.calendar_href)
+            } else {
+                None
+            };
+
+            let density = Density::of(app);
+            let mut font_size = density.task_font_size;
+            if task.is_note && task.parent_uid.is_none() {
+                font_size = density.note_font_size;
             }
 
             let is_paused = task.is_paused();
@@ -418,9 +491,13 @@ pub fn view_task_row<'a>(
                             button(
                                 row![
                                     icon::icon(done_icon).size(12).color(done_color),
-                                    text(local_done.format("%Y-%m-%d %H:%M").to_string())
-                                        .size(14)
-                                        .color(done_color)
+                                    text(crate::model::item::format_timestamp(
+                                        &local_done,
+                                        date_fmt,
+                                        time_fmt
+                                    ))
+                                    .size(14)
+                                    .color(done_color)
                                 ]
                                 .spacing(3)
                                 .align_y(iced::Alignment::Center),
@@ -432,7 +509,7 @@ pub fn view_task_row<'a>(
                     }
                 } else if is_future_start {
                     let start_ref = task.dtstart.as_ref().unwrap();
-                    let start_str = start_ref.format_smart();
+                    let start_str = start_ref.format_with(date_fmt, time_fmt);
                     let start_date_str = start_ref.to_date_naive().format("%Y-%m-%d").to_string();
                     let start_btn = button(
                         row![
@@ -451,17 +528,26 @@ pub fn view_task_row<'a>(
                         let due_str = if is_same_day {
                             match due {
                                 crate::model::DateType::Specific(dt) => {
-                                    dt.with_timezone(&chrono::Local).format("%H:%M").to_string()
+                                    crate::model::item::format_clock(
+                                        dt.with_timezone(&chrono::Local).time(),
+                                        time_fmt,
+                                    )
+                                }
+                                crate::model::DateType::AllDay(_) => {
+                                    due.format_with(date_fmt, time_fmt)
+                                }
+                                crate::model::DateType::Month(_, _) => {
+                                    due.format_with(date_fmt, time_fmt)
+                                }
+                                crate::model::DateType::Year(_) => {
+                                    due.format_with(date_fmt, time_fmt)
                                 }
-                                crate::model::DateType::AllDay(_) => due.format_smart(),
-                                crate::model::DateType::Month(_, _) => due.format_smart(),
-                                crate::model::DateType::Year(_) => due.format_smart(),
                             }
                         } else {
-                            due.format_smart()
+                            due.format_with(date_fmt, time_fmt)
                         };
 
-                        if start_str == due.format_smart() {
+                        if start_str == due.format_with(date_fmt, time_fmt) {
                             row_content = row_content.push(start_btn);
                         } else {
                             let due_date_str = due.to_date_naive().format("%Y-%m-%d").to_string();
@@ -488,7 +574,9 @@ pub fn view_task_row<'a>(
                         button(
                             row![
                                 icon::icon(icon::CALENDAR).size(12).color(due_color),
-                                text(d.format_smart()).size(14).color(due_color)
+                                text(d.format_with(date_fmt, time_fmt))
+                                    .size(14)
+                                    .color(due_color)
                             ]
                             .spacing(3)
                             .align_y(iced::Alignment::Center),
@@ -508,8 +596,10 @@ pub fn view_task_row<'a>(
             let has_related = !task.related_to.is_empty();
             let has_incoming_related = task.has_related_tasks;
             let has_blocking = task.has_blocking_tasks;
+            let has_attachments = !task.attachments.is_empty();
 
             let has_info = has_desc
+                || has_attachments
                 || has_deps
                 || has_blocking
                 || has_related
@@ -523,16 +613,24 @@ pub fn view_task_row<'a>(
                 || task.created_date().is_some()
                 || task.last_modified_date().is_some();
 
+            let blocks_count = if task.status.is_done() {
+                0
+            } else {
+                app.store.open_blocked_count(&task.uid)
+            };
+
             let has_metadata = !task.categories.is_empty()
                 || task.rrule.is_some()
                 || is_blocked
+                || blocks_count > 0
                 || task.estimated_duration.is_some()
                 || task.location.is_some()
                 || task.url.is_some()
                 || task.geo.is_some()
                 || task.time_spent_seconds > 0
                 || task.last_started_at.is_some()
-                || (app.show_priority_numbers && task.priority > 0);
+                || (app.show_priority_numbers && task.priority > 0)
+                || calendar_badge.is_some();
 
             // Accurate overhead estimate:
             // ~130px (dates) + ~120px (actions) + 24px (checkbox) + 40px (spacing) + 22px (padding) + 10px (scrollbar)
@@ -550,9 +648,15 @@ pub fn view_task_row<'a>(
                 if is_blocked {
                     tags_width += 65.0;
                 }
+                if blocks_count > 0 {
+                    tags_width += 60.0;
+                }
                 if app.show_priority_numbers && task.priority > 0 {
                     tags_width += 25.0;
                 }
+                if let Some(cal) = calendar_badge {
+                    tags_width += (cal.name.chars().count() as f32 * 7.0) + 10.0;
+                }
                 for cat in visible_tags {
                     tags_width += (cat.len() as f32 + 1.0) * 7.0 + 10.0;
                 }
@@ -591,6 +695,41 @@ pub fn view_task_row<'a>(
                     );
                 }
 
+                if let Some(cal) = calendar_badge {
+                    let (r, g, b) = cal
+                        .color
+                        .as_deref()
+                        .and_then(color_utils::parse_hex_to_floats)
+                        .unwrap_or((0.5, 0.5, 0.5));
+                    let badge_bg = Color::from_rgba(r, g, b, 0.25 * dim_factor);
+                    let badge_border = Color::from_rgba(r, g, b, dim_factor);
+                    tags_row = tags_row.push(
+                        button(text(cal.name.clone()).size(11).style(move |theme: &Theme| {
+                            text::Style {
+                                color: Some(
+                                    theme
+                                        .extended_palette()
+                                        .background
+                                        .base
+                                        .text
+                                        .scale_alpha(dim_factor),
+                                ),
+                            }
+                        }))
+                        .style(move |_theme, _status| button::Style {
+                            background: Some(badge_bg.into()),
+                            border: iced::Border {
+                                color: badge_border,
+                                width: 1.0,
+                                radius: 4.0.into(),
+                            },
+                            ..button::Style::default()
+                        })
+                        .padding([2, 4])
+                        .on_press(Message::SelectCalendar(cal.href.clone())),
+                    );
+                }
+
                 if is_blocked {
                     tags_row = tags_row.push(
                         container(text(rust_i18n::t!("blocked")).size(12).style(
@@ -610,6 +749,25 @@ pub fn view_task_row<'a>(
                     );
                 }
 
+                if blocks_count > 0 {
+                    tags_row = tags_row.push(
+                        container(
+                            text(rust_i18n::t!("blocks_count", count = blocks_count))
+                                .size(11)
+                                .color(Color::WHITE),
+                        )
+                        .style(|_| container::Style {
+                            background: Some(Color::from_rgb(0.5, 0.35, 0.75).into()),
+                            border: iced::Border {
+                                radius: 4.0.into(),
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        })
+                        .padding(3),
+                    );
+                }
+
                 if app.show_priority_numbers && task.priority > 0 {
                     let priority_text = text(format!("!{}", task.priority)).size(11).color(color);
                     tags_row =
@@ -626,7 +784,7 @@ pub fn view_task_row<'a>(
                 }
 
                 for cat in visible_tags {
-                    let (r, g, b) = color_utils::generate_color(cat);
+                    let (r, g, b) = color_utils::tag_color(cat, app.tag_color_mode, true);
                     let bg_color = Color::from_rgba(r, g, b, dim_factor);
 
                     let mut text_color = if color_utils::is_dark(r, g, b) {
@@ -752,7 +910,26 @@ pub fn view_task_row<'a>(
                         String::new()
                     };
 
-                    let time_label = if total_mins > 0 || task.last_started_at.is_some() {
+                    let time_label = if task.last_started_at.is_some() {
+                        // Live clock for the running session, then the accumulated total
+                        let clock = crate::model::parser::format_elapsed_clock(current_session);
+                        if !est_label.is_empty() {
+                            format!(
+                                "▶ {} · {} / {}",
+                                clock,
+                                crate::model::parser::format_duration_compact(total_mins),
+                                est_label
+                            )
+                        } else if total_mins > 0 {
+                            format!(
+                                "▶ {} · {}",
+                                clock,
+                                crate::model::parser::format_duration_compact(total_mins)
+                            )
+                        } else {
+                            format!("▶ {}", clock)
+                        }
+                    } else if total_mins > 0 {
                         if !est_label.is_empty() {
                             format!(
                                 "{} / {}",
@@ -843,19 +1020,41 @@ pub fn view_task_row<'a>(
             let is_strikethrough = (app.strikethrough_completed && task.status.is_done())
                 || task.calendar_href == "local://trash";
 
-            let summary_spans = parse_inline_markdown(&task.summary, title_color, is_strikethrough);
+            // Only plain words are highlighted; #tag, @@location and is: tokens are not
+            let search_words = crate::model::matcher::plain_search_words(&app.session.search_term);
+            let mut summary_spans =
+                parse_inline_markdown(&task.summary, title_color, is_strikethrough);
+            if !search_words.is_empty() {
+                summary_spans = highlight_matches(summary_spans, &search_words);
+            }
 
-            let summary_text: Element<'a, Message> = rich_text(summary_spans)
-                .size(font_size)
-                .width(Length::Fill)
-                .on_link_click(|target: String| {
-                    if target.contains("://") || target.starts_with("mailto:") {
-                        Message::OpenUrl(target)
-                    } else {
-                        Message::OpenWikiLink(target)
-                    }
-                })
-                .into();
+            let summary_text: Element<'a, Message> =
+                if app.inline_edit_uid.as_deref() == Some(task.uid.as_str()) {
+                    let uid = task.uid.clone();
+                    text_input(&task.summary, &app.inline_edit_value)
+                        .id(iced::widget::Id::new("inline_edit_input"))
+                        .on_input(Message::InlineEditChanged)
+                        .on_submit(Message::InlineEditCommit(
+                            uid,
+                            app.inline_edit_value.clone(),
+                        ))
+                        .size(font_size)
+                        .padding(2)
+                        .width(Length::Fill)
+                        .into()
+                } else {
+                    rich_text(summary_spans)
+                        .size(font_size)
+                        .width(Length::Fill)
+                        .on_link_click(|target: String| {
+                            if target.contains("://") || target.starts_with("mailto:") {
+                                Message::OpenUrl(target)
+                            } else {
+                                Message::OpenWikiLink(target)
+                            }
+                        })
+                        .into()
+                };
 
             let main_text_col: Element<'a, Message> = if place_inline {
                 row![summary_text, tags_element]
@@ -875,6 +1074,17 @@ pub fn view_task_row<'a>(
                 .into()
             };
 
+            // Only meaningfully partial work gets a bar; 0 and 100 say nothing a glance needs
+            let main_text_col: Element<'a, Message> = match task
+                .percent_complete
+                .filter(|pc| (1..100).contains(pc) && !task.status.is_done())
+            {
+                Some(pc) => column![main_text_col, progress_bar(pc, dim_factor)]
+                    .spacing(2)
+                    .into(),
+                None => main_text_col,
+            };
+
             let main_text_col = container(main_text_col)
                 .width(Length::Fill)
                 .height(Length::Shrink);
@@ -1066,6 +1276,11 @@ pub fn view_task_row<'a>(
                             Message::ToggleTaskShift(task.uid.clone()),
                             0,
                         ),
+                        TaskAction::SkipOccurrence => (
+                            icon::icon(icon::ARROW_RIGHT).size(14).into(),
+                            Message::SkipOccurrence(task.uid.clone()),
+                            0,
+                        ),
                         TaskAction::ToggleDetails => {
                             let mut icon_row = row![].spacing(2).align_y(iced::Alignment::Center);
                             if has_info {
@@ -1147,6 +1362,11 @@ pub fn view_task_row<'a>(
                             Message::TogglePin(task.uid.clone()),
                             0,
                         ),
+                        TaskAction::ToggleWaiting => (
+                            icon::icon(icon::HOURGLASS_START).size(14).into(),
+                            Message::ToggleWaiting(task.uid.clone()),
+                            0,
+                        ),
                         TaskAction::CreateSubtask => (
                             icon::icon(icon::CREATE_CHILD).size(14).into(),
                             Message::StartCreateChild(task.uid.clone()),
@@ -1289,7 +1509,13 @@ pub fn view_task_row<'a>(
             actions = actions.push(ellipsis_btn);
 
             // Restore the Native Checkbox
-            let (icon_char, mut bg_color, mut default_border_color) = if is_paused {
+            let (icon_char, mut bg_color, mut default_border_color) = if task.is_waiting() {
+                (
+                    icon::HOURGLASS_START,
+                    Color::from_rgb(0.55, 0.65, 0.8),
+                    Color::from_rgb(0.4, 0.45, 0.6),
+                )
+            } else if is_paused {
                 (
                     icon::PAUSE,
                     Color::from_rgb(0.9, 0.7, 0.2),
@@ -1401,26 +1627,66 @@ pub fn view_task_row<'a>(
                 .into()
             };
 
+            // Drag handle: pressing it starts a reparenting drag (see Message::DragStart)
+            let is_dragged = app.dragging_uid.as_deref() == Some(task.uid.as_str());
+            let is_drop_target = !is_dragged
+                && app.drag_target_uid.as_deref() == Some(task.uid.as_str())
+                && app
+                    .dragging_uid
+                    .as_deref()
+                    .is_some_and(|d| app.store.can_set_parent(d, &task.uid));
+            let drag_handle = tooltip(
+                iced::widget::MouseArea::new(
+                    icon::icon(icon::DRAG)
+                        .size(12)
+                        .color(Color::from_rgb(0.5, 0.5, 0.5)),
+                )
+                .interaction(iced::mouse::Interaction::Grab)
+                .on_press(Message::DragStart(task.uid.clone())),
+                text(rust_i18n::t!("drag_to_reparent")).size(12),
+                tooltip::Position::Top,
+            )
+            .style(tooltip_style)
+            .delay(Duration::from_millis(700));
+
             let row_main = row![
                 indent,
+                drag_handle,
                 status_btn_element,
                 main_text_col,
                 date_and_alarm_section,
                 actions
             ]
-            .spacing(10)
+            .spacing(density.row_spacing)
             .align_y(iced::Alignment::Center);
 
             let task_button = button(row_main)
                 .on_press(Message::TaskClick(index, task.uid.clone()))
                 .padding(iced::Padding {
-                    top: 2.0,
+                    top: density.row_padding_y,
                     right: 16.0,
-                    bottom: 2.0,
+                    bottom: density.row_padding_y,
                     left: 6.0,
                 })
                 .style(move |theme: &Theme, status: button::Status| {
                     let palette = theme.extended_palette();
+                    if is_drop_target {
+                        return button::Style {
+                            background: Some(
+                                Color {
+                                    a: 0.15,
+                                    ..palette.primary.base.color
+                                }
+                                .into(),
+                            ),
+                            border: iced::Border {
+                                width: 1.0,
+                                color: palette.primary.base.color,
+                                radius: 4.0.into(),
+                            },
+                            ..button::Style::default()
+                        };
+                    }
                     if is_selected {
                         return button::Style {
                             background: Some(
@@ -1476,6 +1742,28 @@ pub fn view_task_row<'a>(
                     details_col = details_col.push(desc_col);
                 }
 
+                if has_attachments {
+                    let mut links_col = column![
+                        text(rust_i18n::t!("links_label"))
+                            .size(12)
+                            .color(Color::from_rgb(0.4, 0.6, 0.9))
+                    ]
+                    .spacing(2);
+                    for link in task.url.iter().chain(task.attachments.iter()) {
+                        links_col = links_col.push(
+                            button(
+                                text(link.clone())
+                                    .size(12)
+                                    .color(Color::from_rgb(0.5, 0.7, 1.0)),
+                            )
+                            .style(button::text)
+                            .padding(0)
+                            .on_press(Message::OpenUrl(link.clone())),
+                        );
+                    }
+                    details_col = details_col.push(links_col);
+                }
+
                 if has_valid_parent {
                     let p_uid = task.parent_uid.as_ref().unwrap();
                     let mut p_name = app
@@ -1571,8 +1859,13 @@ pub fn view_task_row<'a>(
                             if rel_task.status.is_done() {
                                 if let Some(comp_date) = rel_task.completion_date() {
                                     let local = comp_date.with_timezone(&chrono::Local);
-                                    name =
-                                        format!("{} (✓ {})", name, local.format("%Y-%m-%d %H:%M"));
+                                    name = format!(
+                                        "{} (✓ {})",
+                                        name,
+                                        crate::model::item::format_timestamp(
+                                            &local, date_fmt, time_fmt
+                                        )
+                                    );
                                 } else {
                                     name = format!("{} (✓)", name);
                                 }
@@ -1681,7 +1974,9 @@ pub fn view_task_row<'a>(
                                 related_name = format!(
                                     "{} (✓ {})",
                                     related_name,
-                                    local.format("%Y-%m-%d %H:%M")
+                                    crate::model::item::format_timestamp(
+                                        &local, date_fmt, time_fmt
+                                    )
                                 );
                             } else {
                                 related_name = format!("{} (✓)", related_name);
@@ -1720,6 +2015,34 @@ pub fn view_task_row<'a>(
                     }
                 }
 
+                // --- Progress (PERCENT-COMPLETE) ---
+                if !task.is_note {
+                    let pc = if task.status == crate::model::TaskStatus::Completed {
+                        100
+                    } else {
+                        task.percent_complete.unwrap_or(0)
+                    };
+                    let step_btn = |label: &'static str, target: u8| {
+                        button(text(label).size(11))
+                            .style(|theme, status| action_style(theme, status, 0))
+                            .padding([1, 5])
+                            .on_press_maybe(
+                                (target != pc)
+                                    .then(|| Message::SetProgress(task.uid.clone(), target)),
+                            )
+                    };
+                    let progress_row = row![
+                        text(t!("progress_percent", pc = pc))
+                            .size(12)
+                            .color(Color::from_rgb(0.6, 0.7, 0.9)),
+                        step_btn("-10%", pc.saturating_sub(10)),
+                        step_btn("+10%", pc.saturating_add(10).min(100)),
+                    ]
+                    .spacing(5)
+                    .align_y(iced::Alignment::Center);
+                    details_col = details_col.push(progress_row);
+                }
+
                 // --- Work Sessions Rendering ---
                 if !task.sessions.is_empty()
                     || app.adding_session_uid.as_ref() == Some(&task.uid)
@@ -1913,6 +2236,13 @@ pub fn view_task_row<'a>(
                                     .color(Color::from_rgb(0.7, 0.7, 0.7)),
                             );
                         }
+                        if let Some(summary) = task.completion_log_summary(date_fmt, time_fmt) {
+                            details_col = details_col.push(
+                                text(format!("• {}", summary))
+                                    .size(12)
+                                    .color(Color::from_rgb(0.7, 0.7, 0.7)),
+                            );
+                        }
                     }
 
                     if let Some(goal) = &task.goal {
@@ -1975,7 +2305,7 @@ pub fn view_task_row<'a>(
                     date_infos.push(format!(
                         "{}: {}",
                         rust_i18n::t!("created_label"),
-                        local.format("%Y-%m-%d %H:%M")
+                        crate::model::item::format_timestamp(&local, date_fmt, time_fmt)
                     ));
                 }
                 if let Some(modified) = modified_opt
@@ -1985,7 +2315,7 @@ pub fn view_task_row<'a>(
                     date_infos.push(format!(
                         "{}: {}",
                         rust_i18n::t!("last_modified_label"),
-                        local.format("%Y-%m-%d %H:%M")
+                        crate::model::item::format_timestamp(&local, date_fmt, time_fmt)
                     ));
                 }
                 if !date_infos.is_empty() {
@@ -2026,7 +2356,9 @@ pub fn view_task_row<'a>(
                     if !desc_lines.is_empty() {
                         let inline_txt = desc_lines.join("\n");
                         let inline_desc = row![
-                            Space::new().width(Length::Fixed(indent_size as f32 + 34.0)),
+                            Space::new().width(Length::Fixed(
+                                indent_size as f32 + 36.0 + 2.0 * density.row_spacing
+                            )),
                             rich_text(parse_inline_markdown(
                                 &inline_txt,
                                 Color::from_rgb(0.6, 0.6, 0.6),
@@ -2047,9 +2379,15 @@ pub fn view_task_row<'a>(
                 base_col
             };
 
-            let container_content: Element<'a, Message> = iced::widget::MouseArea::new(col_content)
-                .on_right_press(Message::OpenContextMenu(task.uid.clone(), true))
-                .into();
+            let mut row_area = iced::widget::MouseArea::new(col_content)
+                .on_right_press(Message::OpenContextMenu(task.uid.clone(), true));
+            if app.dragging_uid.is_some() {
+                row_area = row_area
+                    .on_enter(Message::DragEnter(task.uid.clone()))
+                    .on_exit(Message::DragLeave(task.uid.clone()))
+                    .on_release(Message::DropOnTask(task.uid.clone()));
+            }
+            let container_content: Element<'a, Message> = row_area.into();
 
             focusable(container_content).id(row_id).into()
         }
@@ -64,6 +64,10 @@ pub fn get_syntax_help() -> Vec<HelpSection> {
     let e_tomorrow = get_first("parser_tomorrow");
     let e_yesterday = get_first("parser_yesterday");
     let e_now = get_first("parser_now");
+    let e_eod = get_first("parser_eod");
+    let e_eow = get_first("parser_eow");
+    let e_eom = get_first("parser_eom");
+    let e_eoy = get_first("parser_eoy");
     let e_next = get_first("parser_next");
     let e_in = get_first("parser_in");
     let e_every = get_first("parser_every");
@@ -95,10 +99,15 @@ pub fn get_syntax_help() -> Vec<HelpSection> {
                     keys: format!("{p_due}date"),
                     desc: rust_i18n::t!("help_timeline_due_date").to_string(),
                     example: format!(
-                        "{} {p_due}{e_tomorrow}, {p_due}2025-01-01",
+                        "{} {p_due}{e_tomorrow} 1430, {p_due}2025-01-01",
                         rust_i18n::t!("example_meeting")
                     ),
                 },
+                HelpItem {
+                    keys: format!("{p_due}{e_eod}/{e_eow}/{e_eom}/{e_eoy}"),
+                    desc: rust_i18n::t!("help_timeline_end_of_period").to_string(),
+                    example: format!("{p_due}{e_eom}, {p_start}{e_eow}, {p_rem}{e_eod} 17:00"),
+                },
                 HelpItem {
                     keys: format!("{p_start}date"),
                     desc: rust_i18n::t!("help_timeline_start_date").to_string(),
@@ -168,6 +177,11 @@ pub fn get_syntax_help() -> Vec<HelpSection> {
                     desc: rust_i18n::t!("help_timeline_next_day").to_string(),
                     example: format!("{p_due}next 8, {p_start}next 15"),
                 },
+                HelpItem {
+                    keys: format!("{e_in} N {}", get_first("parser_business_days")),
+                    desc: rust_i18n::t!("help_timeline_business_days").to_string(),
+                    example: format!("{p_due}{e_in} 3 business days, {p_start}{e_in} 5bd"),
+                },
                 HelpItem {
                     keys: rust_i18n::t!("help_key_dates").to_string(),
                     desc: rust_i18n::t!("help_timeline_dates_desc").to_string(),
@@ -192,9 +206,21 @@ pub fn get_syntax_help() -> Vec<HelpSection> {
                     keys: format!("{e_every} X"),
                     desc: rust_i18n::t!("help_recurrence_custom_intervals").to_string(),
                     example: format!(
-                        "{e_every} 3 {u_d}, {e_every} 2 {u_w}, {e_every} tuesday, {e_every} sat,sun"
+                        "{e_every} 3 {u_d}, {e_every} 2 {u_w}, {e_every} tuesday, {e_every} mon,wed,fri, {e_every} mwf, {p_due}weekdays"
                     ),
                 },
+                HelpItem {
+                    keys: format!("{e_every} other / last ..."),
+                    desc: rust_i18n::t!("help_recurrence_positions").to_string(),
+                    example: format!(
+                        "{e_every} other week, {e_every} last friday, {e_every} 2nd tuesday, {e_every} first weekday"
+                    ),
+                },
+                HelpItem {
+                    keys: format!("{p_due}daily*N"),
+                    desc: rust_i18n::t!("help_recurrence_count").to_string(),
+                    example: format!("{p_due}daily*5, {e_every} 2 {u_w}*3"),
+                },
                 HelpItem {
                     keys: format!("{e_until} <date>"),
                     desc: rust_i18n::t!("help_recurrence_until").to_string(),
@@ -274,6 +300,11 @@ pub fn get_syntax_help() -> Vec<HelpSection> {
                     desc: rust_i18n::t!("help_metadata_pin_task").to_string(),
                     example: format!("{} is:pinned", rust_i18n::t!("example_important_task")),
                 },
+                HelpItem {
+                    keys: "is:waiting".to_string(),
+                    desc: rust_i18n::t!("help_metadata_waiting").to_string(),
+                    example: format!("{} is:waiting", rust_i18n::t!("example_task")),
+                },
             ],
         },
         HelpSection {
@@ -299,11 +330,51 @@ pub fn get_syntax_help() -> Vec<HelpSection> {
                     desc: rust_i18n::t!("help_search_operators").to_string(),
                     example: format!("{p_duration}<20{u_m}, !<4"),
                 },
+                HelpItem {
+                    keys: "!=N ~none".to_string(),
+                    desc: rust_i18n::t!("help_search_exact_and_unset").to_string(),
+                    example: format!("!=3, {p_duration}none"),
+                },
                 HelpItem {
                     keys: "Dates".to_string(),
                     desc: rust_i18n::t!("help_search_dates").to_string(),
                     example: format!("{p_due}<{e_today}, {p_start}>1{u_w}"),
                 },
+                HelpItem {
+                    keys: "is:overdue is:today is:week".to_string(),
+                    desc: rust_i18n::t!("help_search_date_windows").to_string(),
+                    example: format!("{} #work", rust_i18n::t!("search_is_overdue")),
+                },
+                HelpItem {
+                    keys: "is:deferred".to_string(),
+                    desc: rust_i18n::t!("help_search_deferred").to_string(),
+                    example: format!("{} #home", rust_i18n::t!("search_is_deferred")),
+                },
+                HelpItem {
+                    keys: "is:inbox".to_string(),
+                    desc: rust_i18n::t!("help_search_inbox").to_string(),
+                    example: "is:inbox".to_string(),
+                },
+                HelpItem {
+                    keys: "cal:".to_string(),
+                    desc: rust_i18n::t!("help_search_calendar").to_string(),
+                    example: "cal:\"Shared Family\" #groceries".to_string(),
+                },
+                HelpItem {
+                    keys: "is:focus".to_string(),
+                    desc: rust_i18n::t!("help_search_focus").to_string(),
+                    example: "is:focus #work".to_string(),
+                },
+                HelpItem {
+                    keys: "completed:today / this-week / this-month".to_string(),
+                    desc: rust_i18n::t!("help_search_completed_windows").to_string(),
+                    example: "completed:this-week #work".to_string(),
+                },
+                HelpItem {
+                    keys: "is:archived".to_string(),
+                    desc: rust_i18n::t!("help_search_archived").to_string(),
+                    example: format!("{} #work", rust_i18n::t!("search_is_archived")),
+                },
                 HelpItem {
                     keys: "(A | B) -C".to_string(),
                     desc: rust_i18n::t!("help_search_combine").to_string(),
@@ -522,6 +593,11 @@ pub fn get_shortcuts_help(is_gui: bool) -> Vec<HelpSection> {
     });
 
     if is_gui {
+        nav_items.push(HelpItem {
+            keys: "Shift + x".to_string(),
+            desc: rust_i18n::t!("complete_all_visible").to_string(),
+            example: "".to_string(),
+        });
         nav_items.push(HelpItem {
             keys: "Ctrl + ,".to_string(),
             desc: rust_i18n::t!("settings").to_string(),
@@ -529,6 +605,85 @@ pub fn get_shortcuts_help(is_gui: bool) -> Vec<HelpSection> {
         });
     }
 
+    if !is_gui {
+        nav_items.push(HelpItem {
+            keys: "i".to_string(),
+            desc: rust_i18n::t!("help_keyboard_toggle_details_pane").to_string(),
+            example: "".to_string(),
+        });
+        nav_items.push(HelpItem {
+            keys: "Shift + Dn / Up".to_string(),
+            desc: rust_i18n::t!("help_keyboard_scroll_details").to_string(),
+            example: "".to_string(),
+        });
+    }
+
+    // Quick due-date rescheduling is bound in the TUI only.
+    let reschedule_items = if is_gui {
+        vec![]
+    } else {
+        vec![HelpItem {
+            keys: "Alt + t / w / d".to_string(),
+            desc: rust_i18n::t!("help_keyboard_reschedule").to_string(),
+            example: "".to_string(),
+        }]
+    };
+
+    // The recent-search history lives in the GUI search box.
+    let search_history_items = if is_gui {
+        vec![HelpItem {
+            keys: "Enter / ↑ / ↓".to_string(),
+            desc: rust_i18n::t!("help_search_history").to_string(),
+            example: "".to_string(),
+        }]
+    } else {
+        vec![]
+    };
+
+    // Renaming in place happens in the GUI task row.
+    let inline_edit_items = if is_gui {
+        vec![HelpItem {
+            keys: "F2".to_string(),
+            desc: rust_i18n::t!("help_inline_rename").to_string(),
+            example: "".to_string(),
+        }]
+    } else {
+        vec![]
+    };
+
+    // The GUI keeps Enter for the details pane and moves the action menu to Shift+Enter.
+    let enter_items = if is_gui {
+        vec![
+            HelpItem {
+                keys: "Enter".to_string(),
+                desc: rust_i18n::t!("help_keyboard_toggle_details").to_string(),
+                example: "".to_string(),
+            },
+            HelpItem {
+                keys: "Shift + Enter".to_string(),
+                desc: "Action Menu / Context Menu".to_string(),
+                example: "".to_string(),
+            },
+        ]
+    } else {
+        vec![HelpItem {
+            keys: "Enter".to_string(),
+            desc: "Action Menu / Context Menu".to_string(),
+            example: "".to_string(),
+        }]
+    };
+
+    // Reparenting by drag-and-drop is a GUI gesture.
+    let drag_items = if is_gui {
+        vec![HelpItem {
+            keys: rust_i18n::t!("help_drag_keys").to_string(),
+            desc: rust_i18n::t!("drag_to_reparent").to_string(),
+            example: rust_i18n::t!("help_drag_to_root").to_string(),
+        }]
+    } else {
+        vec![]
+    };
+
     vec![
         HelpSection {
             title: "Navigation & general".to_string(),
@@ -587,6 +742,11 @@ pub fn get_shortcuts_help(is_gui: bool) -> Vec<HelpSection> {
                     desc: rust_i18n::t!("delete_task_tree").to_string(),
                     example: "".to_string(),
                 },
+                HelpItem {
+                    keys: "Ctrl + z".to_string(),
+                    desc: rust_i18n::t!("help_keyboard_undo").to_string(),
+                    example: "".to_string(),
+                },
                 HelpItem {
                     keys: "Ctrl + d".to_string(),
                     desc: rust_i18n::t!("duplicate_task").to_string(),
@@ -626,7 +786,11 @@ pub fn get_shortcuts_help(is_gui: bool) -> Vec<HelpSection> {
                     desc: rust_i18n::t!("open_url").to_string(),
                     example: "".to_string(),
                 },
-            ],
+            ]
+            .into_iter()
+            .chain(inline_edit_items)
+            .chain(reschedule_items)
+            .collect(),
         },
         HelpSection {
             title: rust_i18n::t!("metadata").to_string(),
@@ -661,12 +825,11 @@ pub fn get_shortcuts_help(is_gui: bool) -> Vec<HelpSection> {
                     desc: "Browse relationships / Toggle details".to_string(),
                     example: "".to_string(),
                 },
-                HelpItem {
-                    keys: "Enter".to_string(),
-                    desc: "Action Menu / Context Menu".to_string(),
-                    example: "".to_string(),
-                },
-            ],
+            ]
+            .into_iter()
+            .chain(enter_items)
+            .chain(drag_items)
+            .collect(),
         },
         HelpSection {
             title: rust_i18n::t!("search_and_filtering").to_string(),
@@ -706,7 +869,15 @@ pub fn get_shortcuts_help(is_gui: bool) -> Vec<HelpSection> {
                     desc: "Toggle Quick Filter (is:ready)".to_string(),
                     example: "".to_string(),
                 },
-            ],
+                HelpItem {
+                    keys: "F".to_string(),
+                    desc: rust_i18n::t!("focus_mode").to_string(),
+                    example: "".to_string(),
+                },
+            ]
+            .into_iter()
+            .chain(search_history_items)
+            .collect(),
         },
     ]
 }
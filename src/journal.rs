@@ -28,6 +28,15 @@ pub enum Action {
     Move(Task, String),
 }
 
+impl Action {
+    /// The task snapshot carried by the action.
+    pub fn task(&self) -> &Task {
+        match self {
+            Action::Create(t) | Action::Update(t) | Action::Delete(t) | Action::Move(t, _) => t,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Default)]
 pub struct Journal {
     pub queue: Vec<Action>,
@@ -84,6 +93,34 @@ impl Journal {
         Self::modify(ctx, |queue| queue.push(action))
     }
 
+    /// Drop the action at `index`, but only if it still targets `uid` (the queue may have
+    /// been compacted or synced since it was displayed). Returns whether it was removed.
+    pub fn drop_action(ctx: &dyn AppContext, index: usize, uid: &str) -> Result<bool> {
+        let mut removed = false;
+        Self::modify(ctx, |queue| {
+            if queue.get(index).is_some_and(|a| a.task().uid == uid) {
+                queue.remove(index);
+                removed = true;
+            }
+        })?;
+        Ok(removed)
+    }
+
+    /// Compact the on-disk queue without syncing. Returns how many actions were merged away.
+    pub fn compact_on_disk(ctx: &dyn AppContext) -> Result<usize> {
+        let mut merged = 0;
+        Self::modify(ctx, |queue| {
+            let before = queue.len();
+            let mut tmp_j = Journal {
+                queue: std::mem::take(queue),
+            };
+            tmp_j.compact();
+            merged = before - tmp_j.queue.len();
+            *queue = tmp_j.queue;
+        })?;
+        Ok(merged)
+    }
+
     /// Is the in-memory journal empty?
     pub fn is_empty(&self) -> bool {
         self.queue.is_empty()
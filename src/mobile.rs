@@ -12,7 +12,8 @@ use crate::help::HelpTab;
 use crate::model::parser::{SyntaxType, tokenize_smart_input};
 use crate::model::{AlarmTrigger, DateType, Task};
 use crate::storage::{LOCAL_CALENDAR_HREF, LocalCalendarRegistry, LocalStorage};
-use crate::store::{FilterOptions, TaskStore, UNCATEGORIZED_ID};
+use crate::store::{FilterOptions, NO_LOCATION_ID, TaskStore, UNCATEGORIZED_ID};
+use crate::system::{AlarmMessage, SystemEvent, spawn_alarm_actor_with};
 use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
 use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
@@ -185,6 +186,40 @@ pub struct MobileSyntaxToken {
     pub end: i32,
 }
 
+/// One pending journal action, in queue order.
+#[derive(uniffi::Record)]
+pub struct MobileJournalEntry {
+    pub index: u32,
+    /// `create`, `update`, `delete` or `move`.
+    pub action: String,
+    pub uid: String,
+    pub summary: String,
+}
+
+#[derive(uniffi::Record)]
+pub struct MobileDiagnosticStep {
+    /// `principal`, `home_set` or `calendars`.
+    pub step: String,
+    pub ok: bool,
+    pub status: Option<u16>,
+    pub detail: String,
+    pub elapsed_ms: u64,
+}
+
+/// What a tag rename touched.
+#[derive(uniffi::Record)]
+pub struct MobileTagRename {
+    pub tasks: u32,
+    pub aliases: u32,
+}
+
+#[derive(uniffi::Record)]
+pub struct MobileSyncResult {
+    pub status: String,
+    /// Per-action warnings from the journal upload (conflict copies, dropped actions, ...).
+    pub warnings: Vec<String>,
+}
+
 #[derive(uniffi::Record)]
 pub struct MobileFilterOptions {
     pub filter_tags: Vec<String>,
@@ -194,6 +229,9 @@ pub struct MobileFilterOptions {
     pub match_all_categories: bool,
     pub expanded_tags: Vec<String>,
     pub expanded_locations: Vec<String>,
+    /// Restricts the view to the inbox (`is:inbox`): open tasks with no
+    /// categories, no due date and no parent.
+    pub inbox_only: bool,
 }
 
 #[derive(uniffi::Record)]
@@ -202,6 +240,18 @@ pub struct MobileWorkSession {
     pub end_ms: i64,
 }
 
+#[derive(uniffi::Record)]
+pub struct MobileAlarm {
+    pub uid: String,
+    /// "relative" (offset from due/start) or "absolute".
+    pub trigger_type: String,
+    pub offset_minutes: Option<i32>,
+    pub time_iso: Option<String>,
+    pub description: Option<String>,
+    pub is_acknowledged: bool,
+    pub is_snooze: bool,
+}
+
 #[derive(uniffi::Record)]
 pub struct MobileTask {
     pub uid: String,
@@ -216,6 +266,7 @@ pub struct MobileTask {
     pub start_date_iso: Option<String>,
     pub is_allday_start: bool,
     pub has_alarms: bool,
+    pub alarms: Vec<MobileAlarm>,
     pub is_future_start: bool,
     pub duration_mins: Option<u32>,
     pub duration_max_mins: Option<u32>,
@@ -250,6 +301,7 @@ pub struct MobileTask {
     pub virtual_payload: String,
     pub is_collapsed: bool,
     pub pinned: bool,
+    pub is_waiting: bool,
     pub has_extractable_subtasks: bool,
     pub is_permanent: bool,
     pub created_date_iso: Option<String>,
@@ -259,6 +311,8 @@ pub struct MobileTask {
     pub goal_target_str: Option<String>,
     pub goal_history: Vec<f32>,
     pub rrule_history_stat: Option<String>,
+    /// "Done N times, last on X" from the completion log kept on recurring tasks.
+    pub completion_log_stat: Option<String>,
 
     // UI Visual resolution fields
     pub visible_categories: Vec<String>,
@@ -282,6 +336,7 @@ impl MobileTask {
             start_date_iso: None,
             is_allday_start: false,
             has_alarms: false,
+            alarms: vec![],
             is_future_start: false,
             duration_mins: None,
             duration_max_mins: None,
@@ -316,6 +371,7 @@ impl MobileTask {
             virtual_payload: payload.to_string(),
             is_collapsed: false,
             pinned: false,
+            is_waiting: false,
             has_extractable_subtasks: false,
             is_permanent: false,
             created_date_iso: None,
@@ -324,6 +380,7 @@ impl MobileTask {
             goal_target_str: None,
             goal_history: vec![],
             rrule_history_stat: None,
+            completion_log_stat: None,
             visible_categories: vec![],
             visible_location: None,
             is_search_context: false,
@@ -367,6 +424,8 @@ pub struct MobileLocation {
     pub depth: u32,
     pub has_children: bool,
     pub is_expanded: bool,
+    /// The "No location" bucket: selecting it shows tasks without a location.
+    pub is_no_location: bool,
 }
 
 #[derive(uniffi::Record)]
@@ -376,6 +435,10 @@ pub struct MobileViewData {
     pub locations: Vec<MobileLocation>,
     pub goals: Vec<MobileGoalProgress>,
     pub focused_task_uid: Option<String>,
+    /// Summed estimate of the open tasks in view (e.g. `~3h`), for the header.
+    pub estimated_total: Option<String>,
+    /// Number of inbox tasks across the visible calendars, for the inbox filter chip.
+    pub inbox_count: u32,
 }
 
 #[derive(uniffi::Record)]
@@ -386,6 +449,15 @@ pub struct MobileAlarmInfo {
     pub body: String,
 }
 
+/// Implemented by the app to post system notifications for alarms fired by the
+/// in-process alarm actor (see `CfaitMobile::start_alarm_actor`).
+#[uniffi::export(with_foreign)]
+pub trait MobileAlarmListener: Send + Sync {
+    fn on_alarm(&self, alarm: MobileAlarmInfo);
+    /// The actor wants fresh data before an alarm fires (just-in-time sync).
+    fn on_sync_requested(&self);
+}
+
 #[derive(uniffi::Enum)]
 pub enum MobileGoalType {
     Count,
@@ -432,6 +504,7 @@ pub struct MobileConfig {
     pub tls_client_key_path: Option<String>,
     pub default_calendar: Option<String>,
     pub allow_insecure: bool,
+    pub limit_fetch_to_cutoff: bool,
     pub hide_completed: bool,
     pub hide_aliases_in_sidebar: bool,
     pub tag_aliases: HashMap<String, Vec<String>>,
@@ -460,6 +533,7 @@ pub struct MobileConfig {
     pub goals: HashMap<String, MobileGoal>,
     pub default_duration_goal_mins: u32,
     pub sessions_count_as_completions: bool,
+    pub archive_completed: bool,
     pub show_goals_tab: bool,
     pub show_task_goals_in_sidebar: bool,
     pub sort_collections_by_size: bool,
@@ -648,7 +722,162 @@ fn populate_transient(
     t.visible_location = visible_location;
 }
 
-fn task_to_mobile(t: &Task, store: &TaskStore) -> MobileTask {
+/// Resolves an `AlarmMessage::Fire` into notification text. Implicit alarm ids
+/// (`implicit_due:|<ts>|<uid>`) have no stored `VALARM`, so their body is synthesized.
+fn fired_alarm_info(store: &TaskStore, task_uid: &str, alarm_uid: &str) -> Option<MobileAlarmInfo> {
+    let task = store.get_task_ref(task_uid)?;
+    let body = if alarm_uid.starts_with("implicit_") {
+        if alarm_uid.contains("due") {
+            rust_i18n::t!("alarm_due_now").to_string()
+        } else {
+            rust_i18n::t!("alarm_task_starting").to_string()
+        }
+    } else {
+        task.alarms
+            .iter()
+            .find(|a| a.uid == alarm_uid)?
+            .description
+            .clone()
+            .unwrap_or_else(|| rust_i18n::t!("reminder").to_string())
+    };
+    Some(MobileAlarmInfo {
+        task_uid: task_uid.to_string(),
+        alarm_uid: alarm_uid.to_string(),
+        title: task.summary.clone(),
+        body,
+    })
+}
+
+fn tag_to_mobile(item: crate::store::AggregateItem) -> MobileTag {
+    MobileTag {
+        is_uncategorized: item.full_key == UNCATEGORIZED_ID,
+        name: item.full_key,
+        display_name: item.display_name,
+        count: item.count,
+        depth: item.depth,
+        has_children: item.has_children,
+        is_expanded: item.is_expanded,
+    }
+}
+
+fn location_to_mobile(item: crate::store::AggregateItem) -> MobileLocation {
+    MobileLocation {
+        is_no_location: item.full_key == NO_LOCATION_ID,
+        name: item.full_key,
+        display_name: item.display_name,
+        count: item.count,
+        depth: item.depth,
+        has_children: item.has_children,
+        is_expanded: item.is_expanded,
+    }
+}
+
+/// The search query with `is:inbox` appended when the inbox filter flag is set.
+fn effective_search_query(options: &MobileFilterOptions) -> String {
+    if options.inbox_only
+        && !options
+            .search_query
+            .split_whitespace()
+            .any(|w| w.eq_ignore_ascii_case("is:inbox"))
+    {
+        format!("{} is:inbox", options.search_query)
+            .trim()
+            .to_string()
+    } else {
+        options.search_query.clone()
+    }
+}
+
+/// Per-view selection for `filter_view`. Everything else (hidden calendars, sorting,
+/// done-group limits, ...) comes from `Config`.
+#[derive(Default)]
+struct ViewFilter<'a> {
+    tags: HashSet<String>,
+    locations: HashSet<String>,
+    match_all_categories: bool,
+    search: &'a str,
+    expanded_groups: HashSet<String>,
+    expanded_tags: HashSet<String>,
+    expanded_locations: HashSet<String>,
+    search_collapsed: HashSet<String>,
+    focused_task_uid: Option<String>,
+}
+
+/// Calendars left out of mobile views: the hidden ones plus the disabled ones.
+fn hidden_calendars(config: &Config) -> HashSet<String> {
+    let mut hidden: HashSet<String> = config.hidden_calendars.iter().cloned().collect();
+    hidden.extend(config.disabled_calendars.iter().cloned());
+    hidden
+}
+
+/// Filters and sorts the store for a mobile view, skipping hidden and disabled calendars.
+fn filter_view(
+    store: &TaskStore,
+    config: &Config,
+    view: &ViewFilter,
+) -> crate::store::FilterResult {
+    let hidden = hidden_calendars(config);
+    let cutoff_date = config
+        .sort_cutoff_days
+        .map(|d| Utc::now() + chrono::Duration::days(d as i64));
+    let (exact_priority, require_unset_duration) =
+        crate::model::matcher::extract_filter_shortcuts(view.search);
+    let exclusions = crate::model::matcher::extract_filter_exclusions(view.search);
+    store.filter(FilterOptions {
+        active_cal_href: None,
+        hidden_calendars: &hidden,
+        selected_categories: &view.tags,
+        selected_locations: &view.locations,
+        match_all_categories: view.match_all_categories,
+        search_term: view.search,
+        hide_completed_global: config.hide_completed,
+        hide_deferred: config.hide_deferred,
+        hide_fully_completed_tags: config.hide_fully_completed_tags,
+        hide_aliases_in_sidebar: config.hide_aliases_in_sidebar,
+        cutoff_date,
+        min_duration: None,
+        max_duration: None,
+        include_unset_duration: true,
+        exact_priority,
+        require_unset_duration,
+        excluded_categories: &exclusions.categories,
+        excluded_locations: &exclusions.locations,
+        excluded_priorities: &exclusions.priorities,
+        excluded_statuses: &exclusions.statuses,
+        urgent_days: config.urgent_days_horizon,
+        urgent_prio: config.urgent_priority_threshold,
+        default_priority: config.default_priority,
+        start_grace_period_days: config.start_grace_period_days,
+        sort_standard_by_priority: config.sort_standard_by_priority,
+        sort_preset: config.sort_preset,
+        sort_mode: config.sort_mode,
+        all_day_sort: config.all_day_sort,
+        week_start: config.week_start,
+        sort_urgency_boost: config.sort_urgency_boost,
+        expanded_done_groups: &view.expanded_groups,
+        expanded_tags: &view.expanded_tags,
+        expanded_locations: &view.expanded_locations,
+        max_done_roots: config.max_done_roots,
+        max_done_subtasks: config.max_done_subtasks,
+        tag_aliases: &config.tag_aliases,
+        search_collapsed_tasks: &view.search_collapsed,
+        focused_task_uid: view.focused_task_uid.as_deref(),
+        fuzzy_search: config.fuzzy_search,
+    })
+}
+
+/// Unfiltered sidebar aggregates: tags and locations over every calendar that is
+/// neither hidden nor disabled, with the configured expansion state.
+fn sidebar_aggregates(store: &TaskStore, config: &Config) -> crate::store::FilterResult {
+    let view = ViewFilter {
+        expanded_tags: config.expanded_tags.iter().cloned().collect(),
+        expanded_locations: config.expanded_locations.iter().cloned().collect(),
+        ..Default::default()
+    };
+    filter_view(store, config, &view)
+}
+
+fn task_to_mobile(t: &Task, store: &TaskStore, config: &Config) -> MobileTask {
     let smart = t.to_smart_string();
     let status_str = format!("{:?}", t.status);
 
@@ -754,6 +983,25 @@ fn task_to_mobile(t: &Task, store: &TaskStore) -> MobileTask {
         start_date_iso: start_iso,
         is_allday_start: start_allday,
         has_alarms,
+        alarms: t
+            .alarms
+            .iter()
+            .map(|a| {
+                let (trigger_type, offset_minutes, time_iso) = match &a.trigger {
+                    AlarmTrigger::Relative(mins) => ("relative", Some(*mins), None),
+                    AlarmTrigger::Absolute(dt) => ("absolute", None, Some(dt.to_rfc3339())),
+                };
+                MobileAlarm {
+                    uid: a.uid.clone(),
+                    trigger_type: trigger_type.to_string(),
+                    offset_minutes,
+                    time_iso,
+                    description: a.description.clone(),
+                    is_acknowledged: a.acknowledged.is_some(),
+                    is_snooze: a.is_snooze(),
+                }
+            })
+            .collect(),
         is_future_start: t.is_future_start,
         duration_mins: t.estimated_duration,
         duration_max_mins: t.estimated_duration_max,
@@ -795,6 +1043,7 @@ fn task_to_mobile(t: &Task, store: &TaskStore) -> MobileTask {
         virtual_payload: v_payload,
         is_collapsed: t.collapsed,
         pinned: t.pinned,
+        is_waiting: t.is_waiting(),
         has_extractable_subtasks: t.has_extractable_subtasks(),
         is_permanent: t.permanent,
         created_date_iso,
@@ -803,6 +1052,10 @@ fn task_to_mobile(t: &Task, store: &TaskStore) -> MobileTask {
         goal_target_str,
         goal_history,
         rrule_history_stat,
+        completion_log_stat: t
+            .rrule
+            .as_ref()
+            .and_then(|_| t.completion_log_summary(config.date_format, config.time_format)),
         visible_categories: t.visible_categories.clone(),
         visible_location: t.visible_location.clone(),
         is_search_context: t.is_search_context,
@@ -814,6 +1067,7 @@ fn task_to_mobile(t: &Task, store: &TaskStore) -> MobileTask {
 pub struct CfaitMobile {
     controller: TaskController,
     alarm_index_cache: Arc<Mutex<Option<AlarmIndex>>>,
+    alarm_actor: std::sync::Mutex<Option<tokio::sync::mpsc::Sender<SystemEvent>>>,
     ctx: Arc<dyn AppContext>,
     session: Arc<Mutex<crate::model::SessionState>>,
 }
@@ -887,6 +1141,7 @@ impl CfaitMobile {
         Self {
             controller,
             alarm_index_cache: Arc::new(Mutex::new(None)),
+            alarm_actor: std::sync::Mutex::new(None),
             ctx,
             session: Arc::new(Mutex::new(session)),
         }
@@ -904,6 +1159,40 @@ impl CfaitMobile {
         !crate::journal::Journal::load(self.ctx.as_ref()).is_empty()
     }
 
+    pub fn get_journal_entries(&self) -> Vec<MobileJournalEntry> {
+        crate::journal::Journal::load(self.ctx.as_ref())
+            .queue
+            .iter()
+            .enumerate()
+            .map(|(i, action)| MobileJournalEntry {
+                index: i as u32,
+                action: match action {
+                    crate::journal::Action::Create(_) => "create",
+                    crate::journal::Action::Update(_) => "update",
+                    crate::journal::Action::Delete(_) => "delete",
+                    crate::journal::Action::Move(_, _) => "move",
+                }
+                .to_string(),
+                uid: action.task().uid.clone(),
+                summary: action.task().summary.clone(),
+            })
+            .collect()
+    }
+
+    /// Merges redundant queued actions without syncing; returns how many were merged away.
+    pub fn compact_journal(&self) -> Result<u32, MobileError> {
+        Ok(crate::journal::Journal::compact_on_disk(self.ctx.as_ref())? as u32)
+    }
+
+    /// Drops a stuck journal action. Returns false if the queue changed since it was listed.
+    pub fn drop_journal_action(&self, index: u32, uid: String) -> Result<bool, MobileError> {
+        Ok(crate::journal::Journal::drop_action(
+            self.ctx.as_ref(),
+            index as usize,
+            &uid,
+        )?)
+    }
+
     pub fn has_any_tasks(&self) -> bool {
         self.controller.store.blocking_lock().has_any_tasks()
     }
@@ -914,6 +1203,17 @@ impl CfaitMobile {
         Ok(LocalStorage::to_ics_string(&tasks))
     }
 
+    /// Serializes every task across all calendars for backup.
+    /// `as_json` selects a JSON array; otherwise a single re-importable `.ics` is returned.
+    pub fn export_all(&self, as_json: bool) -> String {
+        let format = if as_json {
+            crate::store::ExportFormat::Json
+        } else {
+            crate::store::ExportFormat::Ics
+        };
+        self.controller.store.blocking_lock().export_all(format)
+    }
+
     pub fn import_local_ics(
         &self,
         calendar_href: String,
@@ -972,6 +1272,7 @@ impl CfaitMobile {
             tls_client_key_path: c.tls_client_key_path,
             default_calendar: c.default_calendar,
             allow_insecure: c.allow_insecure_certs,
+            limit_fetch_to_cutoff: c.limit_fetch_to_cutoff,
             hide_completed: c.hide_completed,
             hide_aliases_in_sidebar: c.hide_aliases_in_sidebar,
             tag_aliases: c.tag_aliases,
@@ -1026,6 +1327,7 @@ impl CfaitMobile {
                 .collect(),
             default_duration_goal_mins: c.default_duration_goal_mins,
             sessions_count_as_completions: c.sessions_count_as_completions,
+            archive_completed: c.archive_completed,
             show_goals_tab: c.show_goals_tab,
             show_task_goals_in_sidebar: c.show_task_goals_in_sidebar,
             sort_collections_by_size: c.sort_collections_by_size,
@@ -1127,6 +1429,7 @@ impl CfaitMobile {
         c.tls_client_cert_path = config.tls_client_cert_path;
         c.tls_client_key_path = config.tls_client_key_path;
         c.allow_insecure_certs = config.allow_insecure;
+        c.limit_fetch_to_cutoff = config.limit_fetch_to_cutoff;
         c.hide_completed = config.hide_completed;
         c.hide_aliases_in_sidebar = config.hide_aliases_in_sidebar;
         c.tag_aliases = config.tag_aliases;
@@ -1183,6 +1486,7 @@ impl CfaitMobile {
             .collect();
         c.default_duration_goal_mins = config.default_duration_goal_mins;
         c.sessions_count_as_completions = config.sessions_count_as_completions;
+        c.archive_completed = config.archive_completed;
         c.show_goals_tab = config.show_goals_tab;
         c.sort_collections_by_size = config.sort_collections_by_size;
 
@@ -1227,8 +1531,10 @@ impl CfaitMobile {
         let mut result = Vec::new();
 
         // Load all disk data BEFORE acquiring the store lock
-        let locals = LocalCalendarRegistry::load(self.ctx.as_ref()).unwrap_or_default();
-        let cals = crate::cache::Cache::load_calendars(self.ctx.as_ref()).unwrap_or_default();
+        let mut locals = LocalCalendarRegistry::load(self.ctx.as_ref()).unwrap_or_default();
+        let mut cals = crate::cache::Cache::load_calendars(self.ctx.as_ref()).unwrap_or_default();
+        config.apply_calendar_overrides(&mut locals);
+        config.apply_calendar_overrides(&mut cals);
 
         // Now acquire the store lock once
         let store = self.controller.store.blocking_lock();
@@ -1313,7 +1619,7 @@ impl CfaitMobile {
                 if t.status == crate::model::TaskStatus::InProcess {
                     let mut cloned = t.clone();
                     populate_transient(&mut cloned, &store, &config.tag_aliases, &parent_uids);
-                    results.push(task_to_mobile(&cloned, &store));
+                    results.push(task_to_mobile(&cloned, &store, &config));
                 }
             }
         }
@@ -1350,6 +1656,21 @@ impl CfaitMobile {
         config.save(self.ctx.as_ref()).map_err(MobileError::from)
     }
 
+    /// Sets the device-local display name and color for a calendar; `None` (or blank) falls
+    /// back to the server value. `get_calendars` reports the overridden values.
+    pub fn set_calendar_override(
+        &self,
+        href: String,
+        name: Option<String>,
+        color: Option<String>,
+    ) -> Result<(), MobileError> {
+        let mut config = Config::load(self.ctx.as_ref()).unwrap_or_default();
+        if config.set_calendar_override(&href, Some(name), Some(color)) {
+            config.save(self.ctx.as_ref()).map_err(MobileError::from)?;
+        }
+        Ok(())
+    }
+
     pub fn set_calendar_visibility(&self, href: String, visible: bool) -> Result<(), MobileError> {
         let mut config = Config::load(self.ctx.as_ref()).unwrap_or_default();
         if visible {
@@ -1546,6 +1867,55 @@ impl CfaitMobile {
         global_earliest
     }
 
+    /// Starts the in-process alarm actor (the same one the desktop clients run) and
+    /// delivers due alarms to `listener`. The task set is refreshed after every sync
+    /// and mutation. Calling it again replaces the previous actor and listener.
+    pub fn start_alarm_actor(&self, listener: Arc<dyn MobileAlarmListener>) {
+        let Some(runtime) = TOKIO_RUNTIME.get() else {
+            #[cfg(target_os = "android")]
+            log::error!("Tokio runtime not initialized before start_alarm_actor()!");
+            return;
+        };
+        let _guard = runtime.enter();
+        let (ui_tx, mut ui_rx) = tokio::sync::mpsc::channel(16);
+        let actor_tx = spawn_alarm_actor_with(self.ctx.as_ref(), Some(ui_tx), false);
+
+        let store = self.controller.store.clone();
+        let seed_tx = actor_tx.clone();
+        runtime.spawn(async move {
+            let all_tasks: Vec<Task> = {
+                let store = store.lock().await;
+                store
+                    .calendars
+                    .values()
+                    .flat_map(|m| m.values())
+                    .cloned()
+                    .collect()
+            };
+            let _ = seed_tx.send(SystemEvent::UpdateTasks(all_tasks)).await;
+            let _ = seed_tx.send(SystemEvent::EnableAlarms).await;
+            // Only `alarm_actor` may keep the actor alive, so a restart can retire it
+            drop(seed_tx);
+
+            while let Some(msg) = ui_rx.recv().await {
+                match msg {
+                    AlarmMessage::Fire(task_uid, alarm_uid) => {
+                        let info = fired_alarm_info(&*store.lock().await, &task_uid, &alarm_uid);
+                        if let Some(info) = info {
+                            listener.on_alarm(info);
+                        }
+                    }
+                    AlarmMessage::TriggerSync => listener.on_sync_requested(),
+                    AlarmMessage::FocusTask(_) => {}
+                }
+            }
+        });
+
+        // Replacing the stored sender closes the old actor's channel; it exits
+        // and ends its relay task with it.
+        *self.alarm_actor.lock().unwrap() = Some(actor_tx);
+    }
+
     pub fn get_firing_alarms(&self) -> Vec<MobileAlarmInfo> {
         let mut firing_entries = Vec::new();
         {
@@ -1719,6 +2089,28 @@ impl CfaitMobile {
 // Block 2: Asynchronous functions
 #[uniffi::export(async_runtime = "tokio")]
 impl CfaitMobile {
+    /// Bulk-restores an `.ics` file or JSON task array into `target_href`, journaling the
+    /// creates so they sync. `conflict_copies` keeps UID duplicates as copies instead of
+    /// skipping them. Returns a localized summary including any per-item errors.
+    pub async fn import_ics(
+        &self,
+        data: String,
+        target_href: String,
+        conflict_copies: bool,
+    ) -> Result<String, MobileError> {
+        let policy = if conflict_copies {
+            crate::storage::DuplicatePolicy::ConflictCopy
+        } else {
+            crate::storage::DuplicatePolicy::Skip
+        };
+        let summary = self
+            .controller
+            .import_tasks(&data, &target_href, policy)
+            .await
+            .map_err(MobileError::from)?;
+        Ok(summary.message())
+    }
+
     pub async fn add_alias(&self, key: String, tags: Vec<String>) -> Result<(), MobileError> {
         let mut c = Config::load(self.ctx.as_ref()).unwrap_or_default();
         let tags_str = tags.join(",");
@@ -1751,8 +2143,23 @@ impl CfaitMobile {
                 rust_i18n::t!("error_cannot_depend_on_self").to_string(),
             ));
         }
-        self.apply_store_mutation(&task_uid, |store, id| store.add_dependency(id, blocker_uid))
-            .await
+        let mut err_msg = None;
+        let res = self
+            .apply_store_mutation(&task_uid, |store, id| {
+                match store.add_dependency(id, blocker_uid) {
+                    Ok(t) => t,
+                    Err(e) => {
+                        err_msg = Some(e);
+                        None
+                    }
+                }
+            })
+            .await;
+
+        if let Some(e) = err_msg {
+            return Err(MobileError::from(e));
+        }
+        res
     }
 
     pub async fn remove_dependency(
@@ -1837,14 +2244,19 @@ impl CfaitMobile {
             .any(|t| t.summary.ends_with("(Conflict Copy)")))
     }
 
-    pub async fn sync(&self) -> Result<String, MobileError> {
+    pub async fn sync(&self) -> Result<MobileSyncResult, MobileError> {
         let config = Config::load_with_credentials(self.ctx.as_ref()).map_err(MobileError::from)?;
 
         let client_opt = self.controller.client.lock().await.clone();
         if let Some(client) = client_opt {
             // FAST PATH: Already connected
             // 1. Push local changes to the server
-            let _ = self.controller.sync_and_update_store().await;
+            let warnings = self
+                .controller
+                .sync_and_update_store()
+                .await
+                .map(|(w, _, _)| w)
+                .unwrap_or_default();
 
             // 2. Fetch remote tasks
             let cals = crate::cache::Cache::load_calendars(self.ctx.as_ref()).unwrap_or_default();
@@ -1861,7 +2273,10 @@ impl CfaitMobile {
                     }
                     drop(store);
                     self.rebuild_alarm_index().await;
-                    return Ok(rust_i18n::t!("status_connected").to_string());
+                    return Ok(MobileSyncResult {
+                        status: rust_i18n::t!("status_connected").to_string(),
+                        warnings,
+                    });
                 }
                 Err(e) => {
                     #[cfg(target_os = "android")]
@@ -1872,7 +2287,11 @@ impl CfaitMobile {
             }
         }
 
-        self.apply_connection(config).await
+        let status = self.apply_connection(config).await?;
+        Ok(MobileSyncResult {
+            status,
+            warnings: Vec::new(),
+        })
     }
 
     pub async fn connect(
@@ -1889,12 +2308,59 @@ impl CfaitMobile {
         self.apply_connection(config).await
     }
 
+    /// Runs the CalDAV discovery chain step by step against the given credentials without
+    /// saving them, so the settings screen can show which step fails.
+    pub async fn diagnose_connection(
+        &self,
+        url: String,
+        user: String,
+        pass: String,
+        insecure: bool,
+    ) -> Result<Vec<MobileDiagnosticStep>, MobileError> {
+        let client = RustyClient::new(
+            self.ctx.clone(),
+            &url,
+            &user,
+            &pass,
+            insecure,
+            Some("Android"),
+        )
+        .map_err(MobileError::from)?;
+        Ok(client
+            .diagnose()
+            .await
+            .into_iter()
+            .map(|s| MobileDiagnosticStep {
+                step: s.step,
+                ok: s.ok,
+                status: s.status,
+                detail: s.detail,
+                elapsed_ms: s.elapsed_ms,
+            })
+            .collect())
+    }
+
+    /// Every tag with its task count, as in the sidebar with nothing selected.
     pub async fn get_all_tags(&self) -> Vec<MobileTag> {
-        Vec::new()
+        let store = self.controller.store.lock().await;
+        let config = Config::load(self.ctx.as_ref()).unwrap_or_default();
+        sidebar_aggregates(&store, &config)
+            .categories
+            .into_iter()
+            .map(tag_to_mobile)
+            .collect()
     }
 
+    /// Every location with its task count, as in the sidebar with nothing selected.
+    /// Pass a location's `name` in `MobileFilterOptions::filter_locations` to filter by it.
     pub async fn get_all_locations(&self) -> Vec<MobileLocation> {
-        Vec::new()
+        let store = self.controller.store.lock().await;
+        let config = Config::load(self.ctx.as_ref()).unwrap_or_default();
+        sidebar_aggregates(&store, &config)
+            .locations
+            .into_iter()
+            .map(location_to_mobile)
+            .collect()
     }
 
     pub async fn get_task_by_uid(&self, uid: String) -> Option<MobileTask> {
@@ -1905,7 +2371,7 @@ impl CfaitMobile {
         if let Some(task) = store.get_task_ref(&uid) {
             let mut cloned = task.clone();
             populate_transient(&mut cloned, &store, &config.tag_aliases, &parent_uids);
-            Some(task_to_mobile(&cloned, &store))
+            Some(task_to_mobile(&cloned, &store, &config))
         } else {
             None
         }
@@ -1914,54 +2380,31 @@ impl CfaitMobile {
     pub async fn get_view_tasks(&self, options: MobileFilterOptions) -> MobileViewData {
         // Acquire session lock first to match the order in dispatch()
         let session = self.session.lock().await;
-        let search_collapsed_set: HashSet<String> =
+        let search_collapsed: HashSet<String> =
             session.search_collapsed_tasks.iter().cloned().collect();
         let focused_task_uid = session.focused_task_uid.clone();
         drop(session);
+        let search_query = effective_search_query(&options);
 
         // Then acquire store lock
-        let store = self.controller.store.lock().await;
+        let mut store = self.controller.store.lock().await;
         let config = Config::load(self.ctx.as_ref()).unwrap_or_default();
-        let mut hidden: HashSet<String> = config.hidden_calendars.into_iter().collect();
-        hidden.extend(config.disabled_calendars);
-
-        let expanded_set: HashSet<String> = options.expanded_groups.into_iter().collect();
-        let expanded_tags_set: HashSet<String> = options.expanded_tags.into_iter().collect();
-        let expanded_locations_set: HashSet<String> =
-            options.expanded_locations.into_iter().collect();
-
-        let cutoff_date = config
-            .sort_cutoff_days
-            .map(|d| Utc::now() + chrono::Duration::days(d as i64));
-        let filtered = store.filter(FilterOptions {
-            active_cal_href: None,
-            hidden_calendars: &hidden,
-            selected_categories: &options.filter_tags.into_iter().collect(),
-            selected_locations: &options.filter_locations.into_iter().collect(),
+        store.apply_archive_setting(&config, &search_query);
+        let view = ViewFilter {
+            tags: options.filter_tags.into_iter().collect(),
+            locations: options.filter_locations.into_iter().collect(),
             match_all_categories: options.match_all_categories,
-            search_term: &options.search_query,
-            hide_completed_global: config.hide_completed,
-            hide_fully_completed_tags: config.hide_fully_completed_tags,
-            hide_aliases_in_sidebar: config.hide_aliases_in_sidebar,
-            cutoff_date,
-            min_duration: None,
-            max_duration: None,
-            include_unset_duration: true,
-            urgent_days: config.urgent_days_horizon,
-            urgent_prio: config.urgent_priority_threshold,
-            default_priority: config.default_priority,
-            start_grace_period_days: config.start_grace_period_days,
-            sort_standard_by_priority: config.sort_standard_by_priority,
-            sort_preset: config.sort_preset,
-            expanded_done_groups: &expanded_set,
-            expanded_tags: &expanded_tags_set,
-            expanded_locations: &expanded_locations_set,
-            max_done_roots: config.max_done_roots,
-            max_done_subtasks: config.max_done_subtasks,
-            tag_aliases: &config.tag_aliases,
-            search_collapsed_tasks: &search_collapsed_set,
-            focused_task_uid: focused_task_uid.as_deref(),
-        });
+            search: &search_query,
+            expanded_groups: options.expanded_groups.into_iter().collect(),
+            expanded_tags: options.expanded_tags.into_iter().collect(),
+            expanded_locations: options.expanded_locations.into_iter().collect(),
+            search_collapsed,
+            focused_task_uid: focused_task_uid.clone(),
+        };
+        let filtered = filter_view(&store, &config, &view);
+
+        let estimated_total = crate::store::TaskStore::estimated_duration_total(&filtered.items)
+            .map(|(min, max)| crate::model::parser::format_estimate_range(min, max));
 
         let mut last_calendar_href = String::new();
         let tasks = filtered
@@ -1969,7 +2412,7 @@ impl CfaitMobile {
             .into_iter()
             .filter_map(|item| {
                 if let crate::store::TaskListItem::Task(t) = item {
-                    let mt = task_to_mobile(&t, &store);
+                    let mt = task_to_mobile(&t, &store, &config);
                     last_calendar_href = mt.calendar_href.clone();
                     Some(mt)
                 } else if let crate::store::TaskListItem::ExpandGroup(p_uid, depth) = item {
@@ -1998,31 +2441,12 @@ impl CfaitMobile {
             })
             .collect();
 
-        let tags = filtered
-            .categories
-            .into_iter()
-            .map(|item| MobileTag {
-                name: item.full_key.clone(),
-                display_name: item.display_name,
-                count: item.count,
-                depth: item.depth,
-                has_children: item.has_children,
-                is_expanded: item.is_expanded,
-                is_uncategorized: item.full_key == UNCATEGORIZED_ID,
-            })
-            .collect();
+        let tags = filtered.categories.into_iter().map(tag_to_mobile).collect();
 
         let locations = filtered
             .locations
             .into_iter()
-            .map(|item| MobileLocation {
-                name: item.full_key.clone(),
-                display_name: item.display_name,
-                count: item.count,
-                depth: item.depth,
-                has_children: item.has_children,
-                is_expanded: item.is_expanded,
-            })
+            .map(location_to_mobile)
             .collect();
 
         let mut evaluated_goals = Vec::new();
@@ -2053,6 +2477,7 @@ impl CfaitMobile {
 
         if config.show_task_goals_in_sidebar {
             let _now = chrono::Utc::now();
+            let hidden = hidden_calendars(&config);
             let mut task_goals = Vec::new();
             for (href, map) in store.calendars.iter() {
                 if hidden.contains(href)
@@ -2110,48 +2535,43 @@ impl CfaitMobile {
             locations,
             goals: evaluated_goals,
             focused_task_uid,
+            estimated_total,
+            inbox_count: filtered.inbox_count as u32,
         }
     }
 
-    pub async fn dispatch(&self, intent: crate::model::AppIntent) -> Result<(), MobileError> {
-        let mut session = self.session.lock().await;
-        let mut store = self.controller.store.lock().await;
-        let config = crate::config::Config::load(self.ctx.as_ref()).unwrap_or_default();
-
-        session.apply_session_intent(&intent);
-
-        let mut config_to_save = config.clone();
-        config_to_save.expanded_tags = session.expanded_tags.clone();
-        config_to_save.expanded_locations = session.expanded_locations.clone();
-        let _ = config_to_save.save(self.ctx.as_ref());
-
-        let actions = store.apply_task_intent(&intent, &config);
-
-        drop(store);
+    /// Renders the currently filtered view as a Markdown checklist (for sharing/printing).
+    pub async fn get_view_markdown(&self, options: MobileFilterOptions) -> String {
+        // Acquire session lock first to match the order in dispatch()
+        let session = self.session.lock().await;
+        let search_collapsed: HashSet<String> =
+            session.search_collapsed_tasks.iter().cloned().collect();
+        let focused_task_uid = session.focused_task_uid.clone();
         drop(session);
+        let search_query = effective_search_query(&options);
 
-        if !actions.is_empty() {
-            // Await disk persistence synchronously so the app doesn't suspend
-            // before the user's modifications are safely queued to disk.
-            let _ = self.controller.persist_changes(actions).await;
-        }
+        // Then acquire store lock
+        let mut store = self.controller.store.lock().await;
+        let config = Config::load(self.ctx.as_ref()).unwrap_or_default();
+        store.apply_archive_setting(&config, &search_query);
+        let view = ViewFilter {
+            tags: options.filter_tags.into_iter().collect(),
+            locations: options.filter_locations.into_iter().collect(),
+            match_all_categories: options.match_all_categories,
+            search: &search_query,
+            expanded_groups: options.expanded_groups.into_iter().collect(),
+            expanded_tags: options.expanded_tags.into_iter().collect(),
+            expanded_locations: options.expanded_locations.into_iter().collect(),
+            search_collapsed,
+            focused_task_uid,
+        };
+        let filtered = filter_view(&store, &config, &view);
 
-        let store_arc = self.controller.store.clone();
-        let alarm_cache = self.alarm_index_cache.clone();
-        let ctx_clone = self.ctx.clone();
-        tokio::spawn(async move {
-            let index = {
-                let s = store_arc.lock().await;
-                crate::alarm_index::AlarmIndex::rebuild_from_tasks(
-                    &s.calendars,
-                    config.auto_reminders,
-                    &config.default_reminder_time,
-                )
-            }; // Lock is dropped here
-            let _ = index.save(ctx_clone.as_ref());
-            *alarm_cache.lock().await = Some(index);
-        });
+        crate::model::extractor::serialize_view_checklist(&filtered.items)
+    }
 
+    pub async fn dispatch(&self, intent: crate::model::AppIntent) -> Result<(), MobileError> {
+        self.dispatch_collecting_created(intent).await?;
         Ok(())
     }
 
@@ -2163,40 +2583,13 @@ impl CfaitMobile {
     ) -> Option<String> {
         let store = self.controller.store.lock().await;
         let config = Config::load(self.ctx.as_ref()).unwrap_or_default();
-        let mut hidden: HashSet<String> = config.hidden_calendars.into_iter().collect();
-        hidden.extend(config.disabled_calendars);
-        let cutoff_date = config
-            .sort_cutoff_days
-            .map(|d| Utc::now() + chrono::Duration::days(d as i64));
-        let filter_res = store.filter(FilterOptions {
-            active_cal_href: None,
-            hidden_calendars: &hidden,
-            selected_categories: &filter_tags.into_iter().collect(),
-            selected_locations: &filter_locations.into_iter().collect(),
-            match_all_categories: false,
-            search_term: &search_query,
-            hide_completed_global: config.hide_completed,
-            hide_fully_completed_tags: config.hide_fully_completed_tags,
-            hide_aliases_in_sidebar: config.hide_aliases_in_sidebar,
-            cutoff_date,
-            min_duration: None,
-            max_duration: None,
-            include_unset_duration: true,
-            urgent_days: config.urgent_days_horizon,
-            urgent_prio: config.urgent_priority_threshold,
-            default_priority: config.default_priority,
-            start_grace_period_days: config.start_grace_period_days,
-            sort_standard_by_priority: config.sort_standard_by_priority,
-            sort_preset: config.sort_preset,
-            expanded_done_groups: &HashSet::new(),
-            expanded_tags: &HashSet::new(),
-            expanded_locations: &HashSet::new(),
-            max_done_roots: config.max_done_roots,
-            max_done_subtasks: config.max_done_subtasks,
-            tag_aliases: &config.tag_aliases,
-            search_collapsed_tasks: &HashSet::new(),
-            focused_task_uid: None,
-        });
+        let view = ViewFilter {
+            tags: filter_tags.into_iter().collect(),
+            locations: filter_locations.into_iter().collect(),
+            search: &search_query,
+            ..Default::default()
+        };
+        let filter_res = filter_view(&store, &config, &view);
         let filtered: Vec<crate::model::Task> = filter_res
             .items
             .iter()
@@ -2212,6 +2605,109 @@ impl CfaitMobile {
         filtered.get(idx).map(|t| t.uid.clone())
     }
 
+    /// Completes every open, unblocked task in the view described by `filter_tag` and
+    /// `search`. Returns how many tasks were marked done.
+    pub async fn complete_all(
+        &self,
+        filter_tag: Option<String>,
+        search: String,
+    ) -> Result<u32, MobileError> {
+        let uids = {
+            let store = self.controller.store.lock().await;
+            let config = Config::load(self.ctx.as_ref()).unwrap_or_default();
+            let view = ViewFilter {
+                tags: filter_tag.into_iter().collect(),
+                search: &search,
+                ..Default::default()
+            };
+            let filter_res = filter_view(&store, &config, &view);
+            store.completable_uids(&filter_res.items)
+        }; // Lock is dropped before dispatch re-acquires it
+
+        let count = uids.len() as u32;
+        if count > 0 {
+            self.dispatch(crate::model::AppIntent::CompleteTasks { uids })
+                .await?;
+        }
+        Ok(count)
+    }
+
+    /// Adds (`add`) or removes `tag` on every task in the view described by `filter_tag`
+    /// and `search`. Returns how many tasks changed.
+    pub async fn bulk_tag(
+        &self,
+        add: bool,
+        tag: String,
+        filter_tag: Option<String>,
+        search: String,
+    ) -> Result<u32, MobileError> {
+        let tag = crate::model::parser::strip_quotes(tag.trim().trim_start_matches('#'));
+        if tag.is_empty() {
+            return Ok(0);
+        }
+        let uids = {
+            let store = self.controller.store.lock().await;
+            let config = Config::load(self.ctx.as_ref()).unwrap_or_default();
+            let view = ViewFilter {
+                tags: filter_tag.into_iter().collect(),
+                search: &search,
+                ..Default::default()
+            };
+            let filter_res = filter_view(&store, &config, &view);
+            filter_res
+                .items
+                .iter()
+                .filter_map(|item| match item {
+                    crate::store::TaskListItem::Task(t) if t.categories.contains(&tag) != add => {
+                        Some(t.uid.clone())
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<String>>()
+        }; // Lock is dropped before dispatch re-acquires it
+
+        let count = uids.len() as u32;
+        if count > 0 {
+            self.dispatch(crate::model::AppIntent::SetTagOnTasks { uids, tag, add })
+                .await?;
+        }
+        Ok(count)
+    }
+
+    /// Renames tag `from` (and its subtags) to `to` on every task and in the aliases.
+    pub async fn rename_tag(
+        &self,
+        from: String,
+        to: String,
+    ) -> Result<MobileTagRename, MobileError> {
+        let from = crate::model::parser::strip_quotes(from.trim().trim_start_matches('#'));
+        let to = crate::model::parser::strip_quotes(to.trim().trim_start_matches('#'));
+        if from.is_empty() || to.is_empty() {
+            return Ok(MobileTagRename {
+                tasks: 0,
+                aliases: 0,
+            });
+        }
+        let mut c = Config::load(self.ctx.as_ref()).unwrap_or_default();
+        let mut store = self.controller.store.lock().await;
+        let (modified, alias_count) = store.rename_tag_everywhere(&mut c.tag_aliases, &from, &to);
+        drop(store);
+        if alias_count > 0 {
+            c.save(self.ctx.as_ref()).map_err(MobileError::from)?;
+        }
+        let result = MobileTagRename {
+            tasks: modified.len() as u32,
+            aliases: alias_count as u32,
+        };
+        for t in modified {
+            self.controller
+                .update_task(t)
+                .await
+                .map_err(MobileError::from)?;
+        }
+        Ok(result)
+    }
+
     pub async fn yank_task(&self, _uid: String) -> Result<(), MobileError> {
         Ok(())
     }
@@ -2271,7 +2767,13 @@ impl CfaitMobile {
             return Ok("".to_string());
         }
         let def_time = NaiveTime::parse_from_str(&config.default_reminder_time, "%H:%M").ok();
-        let mut task = Task::new(&clean_input, &config.tag_aliases, def_time);
+        let mut task = Task::new_with_date_settings(
+            &clean_input,
+            &config.tag_aliases,
+            def_time,
+            &config.holidays,
+            config.week_start,
+        );
 
         let store = self.controller.store.lock().await;
         if let Err(e) = store.resolve_dependencies(&mut task) {
@@ -2383,7 +2885,13 @@ impl CfaitMobile {
         let (cleaned_desc, extracted_subtasks) =
             crate::model::extractor::extract_markdown_tasks(&description);
 
-        let mut task = Task::new(&clean_input, &config.tag_aliases, def_time);
+        let mut task = Task::new_with_date_settings(
+            &clean_input,
+            &config.tag_aliases,
+            def_time,
+            &config.holidays,
+            config.week_start,
+        );
 
         let store = self.controller.store.lock().await;
         if let Err(e) = store.resolve_dependencies(&mut task) {
@@ -2429,7 +2937,13 @@ impl CfaitMobile {
         resolved_props.insert(parent_uid.clone(), parent_props);
 
         for ext in extracted_subtasks {
-            let mut sub = Task::new(&ext.raw_text, &config.tag_aliases, def_time);
+            let mut sub = Task::new_with_date_settings(
+                &ext.raw_text,
+                &config.tag_aliases,
+                def_time,
+                &config.holidays,
+                config.week_start,
+            );
             sub.uid = ext.uid.clone();
 
             let p_uid_str = ext.parent_uid.clone().unwrap_or_else(|| parent_uid.clone());
@@ -2521,6 +3035,31 @@ impl CfaitMobile {
         Ok(())
     }
 
+    pub async fn skip_occurrence(&self, uid: String) -> Result<(), MobileError> {
+        self.dispatch(crate::model::AppIntent::SkipOccurrence { uid })
+            .await?;
+        Ok(())
+    }
+
+    /// Remote tasks still unsynced past `unsynced_warning_hours` ("failed to sync"), oldest first.
+    /// Discard them with `delete_task`.
+    pub async fn get_stale_unsynced_tasks(&self) -> Vec<MobileTask> {
+        let store = self.controller.store.lock().await;
+        let config = Config::load(self.ctx.as_ref()).unwrap_or_default();
+        store
+            .stale_unsynced_tasks(config.unsynced_warning_hours)
+            .into_iter()
+            .map(|t| task_to_mobile(t, &store, &config))
+            .collect()
+    }
+
+    /// Re-queues the creation of a stale unsynced task; call `sync` afterwards.
+    pub async fn retry_unsynced(&self, uid: String) -> Result<(), MobileError> {
+        self.dispatch(crate::model::AppIntent::RetryUnsynced { uid })
+            .await?;
+        Ok(())
+    }
+
     pub async fn pause_task(&self, uid: String) -> Result<(), MobileError> {
         self.dispatch(crate::model::AppIntent::PauseTask { uid })
             .await?;
@@ -2536,6 +3075,11 @@ impl CfaitMobile {
             .await?;
         Ok(())
     }
+    pub async fn set_progress(&self, uid: String, percent: u8) -> Result<(), MobileError> {
+        self.dispatch(crate::model::AppIntent::SetProgress { uid, percent })
+            .await?;
+        Ok(())
+    }
 
     pub async fn update_task_smart(
         &self,
@@ -2546,7 +3090,16 @@ impl CfaitMobile {
         let def_time = NaiveTime::parse_from_str(&config.default_reminder_time, "%H:%M").ok();
         self.apply_store_mutation(&uid, |t, id| {
             if let Some((task, _)) = t.get_task_mut(id) {
-                task.apply_smart_input(&smart_input, &config.tag_aliases, def_time);
+                task.apply_smart_input(
+                    &smart_input,
+                    &config.tag_aliases,
+                    def_time,
+                    &config.holidays,
+                    config.week_start,
+                );
+                task.apply_default_reminder(
+                    config.default_reminder_minutes_for(&task.calendar_href),
+                );
                 task.sequence += 1;
                 Some(task.clone())
             } else {
@@ -2590,7 +3143,13 @@ impl CfaitMobile {
         };
 
         for ext in extracted {
-            let mut sub = crate::model::Task::new(&ext.raw_text, &config.tag_aliases, def_time);
+            let mut sub = crate::model::Task::new_with_date_settings(
+                &ext.raw_text,
+                &config.tag_aliases,
+                def_time,
+                &config.holidays,
+                config.week_start,
+            );
             sub.uid = ext.uid;
 
             let p_uid_str = ext.parent_uid.clone().unwrap_or_else(|| uid.clone());
@@ -2686,10 +3245,12 @@ impl CfaitMobile {
         Ok(())
     }
 
-    pub async fn duplicate_task_tree(&self, uid: String) -> Result<(), MobileError> {
-        self.dispatch(crate::model::AppIntent::DuplicateTaskTree { uid })
+    /// Copies a task (and its subtasks) and returns the copy's UID so it can be opened for editing.
+    pub async fn duplicate_task_tree(&self, uid: String) -> Result<Option<String>, MobileError> {
+        let created = self
+            .dispatch_collecting_created(crate::model::AppIntent::DuplicateTaskTree { uid })
             .await?;
-        Ok(())
+        Ok(created.into_iter().next())
     }
 
     pub async fn delete_task_tree(&self, uid: String) -> Result<(), MobileError> {
@@ -2704,6 +3265,12 @@ impl CfaitMobile {
         Ok(())
     }
 
+    pub async fn toggle_waiting(&self, uid: String) -> Result<(), MobileError> {
+        self.dispatch(crate::model::AppIntent::ToggleWaiting { uid })
+            .await?;
+        Ok(())
+    }
+
     pub async fn sync_task_tree_from_markdown(
         &self,
         uid: String,
@@ -2725,7 +3292,7 @@ impl CfaitMobile {
             &markdown,
             &config.tag_aliases,
             def_time,
-            config.trash_retention_days,
+            &config,
             &cals,
         ) {
             Ok(actions) => {
@@ -2809,16 +3376,7 @@ impl CfaitMobile {
             .create_calendar(&name, color.as_deref())
             .await
             .map_err(|e| MobileError::from(e.to_string()))?;
-
-        // Optimistic update to prevent Android UI jitter
-        if let Ok(mut cals) = crate::cache::Cache::load_calendars(self.ctx.as_ref()) {
-            cals.push(crate::model::CalendarListEntry {
-                name,
-                href: href.clone(),
-                color,
-            });
-            let _ = crate::cache::Cache::save_calendars(self.ctx.as_ref(), &cals);
-        }
+        // `create_calendar` already appended the entry to the cached calendar list.
         Ok(href)
     }
 
@@ -2839,16 +3397,33 @@ impl CfaitMobile {
             .update_calendar(&href, &name, color.as_deref())
             .await
             .map_err(|e| MobileError::from(e.to_string()))?;
+        // `update_calendar` already refreshed the cached calendar list.
+        Ok(())
+    }
 
-        // Optimistic update to prevent Android UI jitter
-        if let Ok(mut cals) = crate::cache::Cache::load_calendars(self.ctx.as_ref())
-            && let Some(c) = cals.iter_mut().find(|c| c.href == href)
-        {
-            c.name = name;
-            c.color = color;
-            let _ = crate::cache::Cache::save_calendars(self.ctx.as_ref(), &cals);
+    /// Changes only a remote calendar's color. Returns `false` when the server does not
+    /// store `calendar-color` (or the app is offline); the color is then kept in the local
+    /// `calendar_overrides` config instead.
+    pub async fn set_calendar_color(
+        &self,
+        href: String,
+        color: Option<String>,
+    ) -> Result<bool, MobileError> {
+        let client = self.controller.client.lock().await.clone();
+        let stored = match client {
+            Some(client) => client
+                .set_calendar_color(&href, color.as_deref())
+                .await
+                .map_err(|e| MobileError::from(e.to_string()))?,
+            None => false,
+        };
+
+        let mut config = Config::load(self.ctx.as_ref()).unwrap_or_default();
+        let local_color = if stored { None } else { color };
+        if config.set_calendar_override(&href, None, Some(local_color)) {
+            config.save(self.ctx.as_ref()).map_err(MobileError::from)?;
         }
-        Ok(())
+        Ok(stored)
     }
 
     pub async fn update_local_calendar(
@@ -2940,6 +3515,30 @@ impl CfaitMobile {
         Ok(())
     }
 
+    /// Adds a reminder from a `rem:` value (e.g. `10m`, `9am`, `tomorrow 8:00`).
+    pub async fn add_reminder(&self, uid: String, spec: String) -> Result<(), MobileError> {
+        let config = Config::load(self.ctx.as_ref()).unwrap_or_default();
+        let def_time = NaiveTime::parse_from_str(&config.default_reminder_time, "%H:%M").ok();
+        let mut invalid = false;
+        let result = self
+            .apply_store_mutation(&uid, |store, id| {
+                let (task, _) = store.get_task_mut(id)?;
+                if !task.add_reminder(&spec, def_time) {
+                    invalid = true;
+                    return None;
+                }
+                task.sequence += 1;
+                Some(task.clone())
+            })
+            .await;
+        if invalid {
+            return Err(MobileError::from(
+                rust_i18n::t!("error_invalid_reminder", val = spec).to_string(),
+            ));
+        }
+        result
+    }
+
     pub async fn get_next_global_alarm_time(&self) -> Option<i64> {
         let store = self.controller.store.lock().await;
         let mut earliest: Option<i64> = None;
@@ -3159,6 +3758,59 @@ impl CfaitMobile {
 }
 
 impl CfaitMobile {
+    /// `dispatch`, returning the UIDs of the tasks the intent created.
+    async fn dispatch_collecting_created(
+        &self,
+        intent: crate::model::AppIntent,
+    ) -> Result<Vec<String>, MobileError> {
+        let mut session = self.session.lock().await;
+        let mut store = self.controller.store.lock().await;
+        let config = crate::config::Config::load(self.ctx.as_ref()).unwrap_or_default();
+
+        session.apply_session_intent(&intent);
+
+        let mut config_to_save = config.clone();
+        config_to_save.expanded_tags = session.expanded_tags.clone();
+        config_to_save.expanded_locations = session.expanded_locations.clone();
+        let _ = config_to_save.save(self.ctx.as_ref());
+
+        let actions = store.apply_task_intent(&intent, &config);
+        let created: Vec<String> = actions
+            .iter()
+            .filter_map(|a| match a {
+                crate::journal::Action::Create(t) => Some(t.uid.clone()),
+                _ => None,
+            })
+            .collect();
+
+        drop(store);
+        drop(session);
+
+        if !actions.is_empty() {
+            // Await disk persistence synchronously so the app doesn't suspend
+            // before the user's modifications are safely queued to disk.
+            let _ = self.controller.persist_changes(actions).await;
+        }
+
+        let store_arc = self.controller.store.clone();
+        let alarm_cache = self.alarm_index_cache.clone();
+        let ctx_clone = self.ctx.clone();
+        tokio::spawn(async move {
+            let index = {
+                let s = store_arc.lock().await;
+                crate::alarm_index::AlarmIndex::rebuild_from_tasks(
+                    &s.calendars,
+                    config.auto_reminders,
+                    &config.default_reminder_time,
+                )
+            }; // Lock is dropped here
+            let _ = index.save(ctx_clone.as_ref());
+            *alarm_cache.lock().await = Some(index);
+        });
+
+        Ok(created)
+    }
+
     async fn apply_store_mutation<F>(&self, uid: &str, mutator: F) -> Result<(), MobileError>
     where
         F: FnOnce(&mut TaskStore, &str) -> Option<Task>,
@@ -3260,6 +3912,20 @@ impl CfaitMobile {
     }
 
     async fn rebuild_alarm_index(&self) {
+        let actor_tx = self.alarm_actor.lock().unwrap().clone();
+        if let Some(tx) = actor_tx {
+            let all_tasks: Vec<Task> = {
+                let store = self.controller.store.lock().await;
+                store
+                    .calendars
+                    .values()
+                    .flat_map(|m| m.values())
+                    .cloned()
+                    .collect()
+            };
+            let _ = tx.send(SystemEvent::UpdateTasks(all_tasks)).await;
+        }
+
         let config = Config::load(self.ctx.as_ref()).unwrap_or_default();
         let index = {
             let store = self.controller.store.lock().await;
@@ -3367,6 +4033,8 @@ impl CfaitMobile {
 mod tests {
     use super::apply_mobile_credentials_update;
     use crate::config::Config;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
 
     #[test]
     fn preserves_existing_password_when_android_ui_leaves_password_blank() {
@@ -3409,4 +4077,41 @@ mod tests {
         assert_eq!(config.username, "alice");
         assert_eq!(config.password, "new-secret");
     }
+
+    struct CountingListener(Arc<AtomicUsize>);
+
+    impl super::MobileAlarmListener for CountingListener {
+        fn on_alarm(&self, _alarm: super::MobileAlarmInfo) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+        fn on_sync_requested(&self) {}
+    }
+
+    #[test]
+    fn restarting_the_alarm_actor_delivers_each_alarm_once() {
+        use crate::model::{Alarm, Task};
+        use std::collections::HashMap;
+
+        super::init_tokio_runtime().unwrap();
+        let dir = std::env::temp_dir().join(format!("cfait_alarm_actor_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let mobile = super::CfaitMobile::new(dir.to_string_lossy().to_string());
+
+        let mut task = Task::new("Water plants", &HashMap::new(), None);
+        task.calendar_href = "local://default".to_string();
+        task.alarms.push(Alarm::new_absolute(
+            chrono::Utc::now() - chrono::Duration::minutes(1),
+        ));
+        mobile.controller.store.blocking_lock().add_task(task);
+
+        let fired = Arc::new(AtomicUsize::new(0));
+        mobile.start_alarm_actor(Arc::new(CountingListener(fired.clone())));
+        mobile.start_alarm_actor(Arc::new(CountingListener(fired.clone())));
+
+        // The actor waits up to 3s for a just-in-time sync before firing
+        std::thread::sleep(std::time::Duration::from_secs(5));
+        assert_eq!(fired.load(Ordering::SeqCst), 1);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
 }
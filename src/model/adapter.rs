@@ -10,7 +10,7 @@ It primarily contains:
 */
 
 use crate::model::item::{Alarm, AlarmTrigger, DateType, RawProperty, Task, TaskStatus};
-use chrono::{Datelike, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, TimeZone, Utc};
 use icalendar::{Calendar, CalendarComponent, Component, Event, Todo, TodoStatus};
 use uuid::Uuid;
 
@@ -48,6 +48,7 @@ const HANDLED_KEYS: &[&str] = &[
     "X-CFAIT-FUZZY-DUE",
     "X-CFAIT-COLLAPSED",
     "X-CFAIT-PINNED",
+    "X-CFAIT-WAITING",
     "X-CFAIT-KIND",
     "X-CFAIT-BLOCKED",
     "X-CFAIT-PERMANENT",
@@ -64,6 +65,9 @@ impl IcsAdapter {
                 if is_time {
                     if until_val.len() == 8 && !until_val.contains('T') {
                         new_parts.push(format!("UNTIL={}T235959Z", until_val));
+                    } else if let Some(date) = until_val.strip_suffix("T000000Z") {
+                        // Midnight UTC is the smart input's date-only form: keep the whole day
+                        new_parts.push(format!("UNTIL={}T235959Z", date));
                     } else if until_val.contains('T') && !until_val.ends_with('Z') {
                         new_parts.push(format!("UNTIL={}Z", until_val));
                     } else {
@@ -99,6 +103,9 @@ impl IcsAdapter {
         if let Some(u) = &task.url {
             todo.add_property("URL", u);
         }
+        for link in &task.attachments {
+            todo.append_multi_property(icalendar::Property::new("ATTACH", link));
+        }
         if let Some(g) = &task.geo {
             let geo_val: String = g.replace(',', ";");
             todo.add_property("GEO", &geo_val);
@@ -152,6 +159,39 @@ impl IcsAdapter {
             chrono::NaiveTime::from_hms_opt(0, 0, 0).unwrap()
         };
 
+        // Zoned input (`DTSTART;TZID=...`) is written back in its zone, provided the matching
+        // VTIMEZONE came along in `raw_components` (RFC 5545 requires one per TZID).
+        // Everything else is written as UTC.
+        let source_tz = task
+            .unmapped_properties
+            .iter()
+            .find(|p| p.key == "TZID")
+            .filter(|p| {
+                task.raw_components
+                    .iter()
+                    .any(|c| defines_tzid(c, &p.value))
+            })
+            .and_then(|p| {
+                let tz = p
+                    .value
+                    .trim_start_matches('/')
+                    .parse::<chrono_tz::Tz>()
+                    .ok()?;
+                Some((p.value.as_str(), tz))
+            });
+        let specific_prop = |key: &str, t: &DateTime<Utc>| -> icalendar::Property {
+            match &source_tz {
+                Some((tzid, tz)) => {
+                    let local = t.with_timezone(tz);
+                    let mut p =
+                        icalendar::Property::new(key, local.format("%Y%m%dT%H%M%S").to_string());
+                    p.add_parameter("TZID", tzid);
+                    p
+                }
+                None => icalendar::Property::new(key, t.format("%Y%m%dT%H%M%SZ").to_string()),
+            }
+        };
+
         if let Some(dt) = &emit_dtstart {
             match dt {
                 DateType::AllDay(d) => {
@@ -160,7 +200,7 @@ impl IcsAdapter {
                     todo.append_property(p);
                 }
                 DateType::Specific(t) => {
-                    todo.add_property("DTSTART", t.format("%Y%m%dT%H%M%SZ").to_string());
+                    todo.append_property(specific_prop("DTSTART", t));
                 }
                 DateType::Month(y, m) => {
                     let d = NaiveDate::from_ymd_opt(*y, *m, 1).unwrap();
@@ -179,6 +219,43 @@ impl IcsAdapter {
             }
         }
 
+        // Tasks read as DTSTART + DURATION keep that form (see `from_ics`).
+        let original_duration = task
+            .unmapped_properties
+            .iter()
+            .find(|p| p.key == "DURATION");
+        let scheduled_duration = original_duration.and_then(|orig| {
+            let span = match (&emit_dtstart, &emit_due) {
+                (Some(DateType::AllDay(s)), Some(DateType::AllDay(d))) => (*d - *s).num_minutes(),
+                (Some(DateType::Specific(s)), Some(DateType::Specific(d))) => {
+                    (*d - *s).num_minutes()
+                }
+                _ => return None,
+            };
+            if span <= 0 {
+                None
+            } else if parse_ics_duration_minutes(&orig.value) == Some(span) {
+                Some(orig.value.clone())
+            } else if span % (24 * 60) == 0 {
+                Some(format!("P{}D", span / (24 * 60)))
+            } else {
+                Some(format!("PT{}M", span))
+            }
+        });
+        if let Some(dur) = &scheduled_duration {
+            todo.add_property("DURATION", dur);
+            emit_due = None;
+        } else if emit_due.is_none()
+            && let Some(start) = &emit_dtstart
+            && let Some(orig) = original_duration
+            && parse_ics_duration_minutes(&orig.value)
+                .and_then(|m| due_after_duration(start, m))
+                .is_none()
+        {
+            // Never mapped to a due date (see `from_ics`), so it goes back unchanged
+            todo.add_property("DURATION", &orig.value);
+        }
+
         if let Some(dt) = &emit_due {
             match dt {
                 DateType::AllDay(d) => {
@@ -187,7 +264,7 @@ impl IcsAdapter {
                     todo.append_property(p);
                 }
                 DateType::Specific(t) => {
-                    todo.add_property("DUE", t.format("%Y%m%dT%H%M%SZ").to_string());
+                    todo.append_property(specific_prop("DUE", t));
                 }
                 DateType::Month(y, m) => {
                     let next_m = if *m == 12 { 1 } else { *m + 1 };
@@ -212,15 +289,18 @@ impl IcsAdapter {
         }
 
         if let Some(mins) = task.estimated_duration {
-            let is_all_day_start = emit_dtstart
-                .as_ref()
-                .map(|dt| matches!(dt, DateType::AllDay(_)))
-                .unwrap_or(false);
-
-            if emit_due.is_some() || is_all_day_start {
-                todo.add_property("X-ESTIMATED-DURATION", format!("PT{}M", mins));
-            } else {
-                todo.add_property("DURATION", format!("PT{}M", mins));
+            // DTSTART + DURATION implies a due date, so with any start the estimate goes in
+            // the X- property. A scheduled DURATION already carries an equal estimate.
+            let from_schedule = scheduled_duration
+                .as_deref()
+                .and_then(parse_ics_duration_minutes)
+                == Some(mins as i64);
+            if !from_schedule {
+                if emit_due.is_some() || emit_dtstart.is_some() {
+                    todo.add_property("X-ESTIMATED-DURATION", format!("PT{}M", mins));
+                } else {
+                    todo.add_property("DURATION", format!("PT{}M", mins));
+                }
             }
 
             if let Some(max) = task.estimated_duration_max {
@@ -312,6 +392,9 @@ impl IcsAdapter {
         if task.pinned {
             todo.add_property("X-CFAIT-PINNED", "TRUE");
         }
+        if task.waiting {
+            todo.add_property("X-CFAIT-WAITING", "TRUE");
+        }
         if task.is_note {
             todo.add_property("X-CFAIT-KIND", "NOTE");
         }
@@ -343,6 +426,13 @@ impl IcsAdapter {
             );
         }
 
+        if let Some(created) = task.created {
+            todo.add_property("CREATED", created.format("%Y%m%dT%H%M%SZ").to_string());
+        }
+        if let Some(completed) = task.completed {
+            todo.add_property("COMPLETED", completed.format("%Y%m%dT%H%M%SZ").to_string());
+        }
+
         // Emit time-tracking properties (if present)
         if task.time_spent_seconds > 0 {
             todo.add_property("X-TIME-SPENT", task.time_spent_seconds.to_string());
@@ -352,14 +442,24 @@ impl IcsAdapter {
         }
 
         for raw in &task.unmapped_properties {
-            if raw.key == "DTSTAMP" {
+            if raw.key == "DTSTAMP" || raw.key == "DURATION" || raw.key == "TZID" {
+                continue;
+            }
+            if (raw.key == "CREATED" && task.created.is_some())
+                || (raw.key == "COMPLETED" && task.completed.is_some())
+            {
                 continue;
             }
             let mut prop = icalendar::Property::new(&raw.key, &raw.value);
             for (k, v) in &raw.params {
                 prop.add_parameter(k, v);
             }
-            todo.append_property(prop);
+            // Kept relations (CHILD, X- types) may repeat and share the key with the mapped ones
+            if raw.key == "RELATED-TO" {
+                todo.append_multi_property(prop);
+            } else {
+                todo.append_property(prop);
+            }
         }
 
         let mut calendar = Calendar::new();
@@ -427,10 +527,13 @@ impl IcsAdapter {
                 if let Some(desc) = &alarm.description {
                     let safe_desc = desc
                         .replace('\\', "\\\\")
+                        .replace(',', "\\,")
+                        .replace(';', "\\;")
                         .replace('\n', "\\n")
                         .replace('\r', "");
                     buffer.push_str(&format!("DESCRIPTION:{}\r\n", safe_desc));
-                } else {
+                } else if !alarm.action.eq_ignore_ascii_case("AUDIO") {
+                    // RFC 5545 requires DESCRIPTION on DISPLAY/EMAIL alarms but has none for AUDIO
                     buffer.push_str("DESCRIPTION:Reminder\r\n");
                 }
 
@@ -447,6 +550,10 @@ impl IcsAdapter {
                     }
                 }
 
+                if alarm.is_default {
+                    buffer.push_str("X-CFAIT-DEFAULT:TRUE\r\n");
+                }
+
                 if let Some(ack) = alarm.acknowledged {
                     let ack_str: String = ack.format("%Y%m%dT%H%M%SZ").to_string();
                     buffer.push_str(&format!("ACKNOWLEDGED:{}\r\n", ack_str));
@@ -465,12 +572,34 @@ impl IcsAdapter {
                     }
                 }
 
+                for extra in &alarm.extra_lines {
+                    buffer.push_str(extra);
+                    buffer.push_str("\r\n");
+                }
+
                 buffer.push_str("END:VALARM\r\n");
             }
             buffer.push_str(end);
             ics = buffer;
         }
 
+        if !task.raw_subcomponents.is_empty()
+            && let Some(idx) = ics.rfind("END:VTODO")
+        {
+            let (start, end) = ics.split_at(idx);
+            let extra_len: usize = task.raw_subcomponents.iter().map(|s| s.len() + 2).sum();
+            let mut buffer = String::with_capacity(ics.len() + extra_len);
+            buffer.push_str(start);
+            for raw in &task.raw_subcomponents {
+                buffer.push_str(raw);
+                if !raw.ends_with('\n') {
+                    buffer.push_str("\r\n");
+                }
+            }
+            buffer.push_str(end);
+            ics = buffer;
+        }
+
         if !task.raw_components.is_empty() {
             let extra_len: usize = task
                 .raw_components
@@ -505,6 +634,7 @@ impl IcsAdapter {
 
         let mut master_todo: Option<&Todo> = None;
         let mut raw_components: Vec<String> = Vec::new();
+        let mut has_vtimezone = false;
 
         for component in &calendar.components {
             match component {
@@ -519,10 +649,20 @@ impl IcsAdapter {
                 }
                 CalendarComponent::Event(e) => raw_components.push(e.to_string()),
                 CalendarComponent::Venue(v) => raw_components.push(v.to_string()),
+                CalendarComponent::Other(o)
+                    if o.component_kind().eq_ignore_ascii_case("VTIMEZONE") =>
+                {
+                    has_vtimezone = true
+                }
                 CalendarComponent::Other(o) => raw_components.push(o.to_string()),
                 _ => {}
             }
         }
+        // Time zone definitions are kept verbatim: serializing them through icalendar would
+        // add the DTSTAMP and UID lines a VTIMEZONE must not have.
+        if has_vtimezone {
+            raw_components.extend(vtimezone_blocks(raw_ics));
+        }
 
         let todo = master_todo.ok_or("No Master VTODO found in ICS".to_string())?;
 
@@ -645,6 +785,10 @@ impl IcsAdapter {
             .map(|v| v.trim().to_uppercase() == "TRUE")
             .unwrap_or(false);
 
+        let waiting = get_prop("X-CFAIT-WAITING")
+            .map(|v| v.trim().to_uppercase() == "TRUE")
+            .unwrap_or(false);
+
         let is_note = get_prop("X-CFAIT-KIND")
             .map(|v| v.trim().to_uppercase() == "NOTE")
             .unwrap_or(false);
@@ -730,18 +874,21 @@ impl IcsAdapter {
                         .ok()
                         .map(|d| DateType::Specific(Utc.from_utc_datetime(&d)))
                 } else {
+                    let tz = parse_tzid(prop);
                     NaiveDateTime::parse_from_str(val, "%Y%m%dT%H%M%S")
                         .ok()
                         .map(|d| {
-                            DateType::Specific(crate::model::item::safe_local_to_utc(
-                                d.date(),
-                                d.time(),
-                            ))
+                            DateType::Specific(match &tz {
+                                Some(tz) => {
+                                    crate::model::item::safe_zoned_to_utc(d.date(), d.time(), tz)
+                                }
+                                None => crate::model::item::safe_local_to_utc(d.date(), d.time()),
+                            })
                         })
                 }
             };
 
-        let due = todo
+        let mut due = todo
             .properties()
             .get("DUE")
             .and_then(|p| parse_date_type(p, fuzzy_due));
@@ -749,6 +896,27 @@ impl IcsAdapter {
             .properties()
             .get("DTSTART")
             .and_then(|p| parse_date_type(p, fuzzy_start));
+
+        // Remember the zone the times were written in (the TZID as written, to match its
+        // VTIMEZONE) so `to_ics` can write them back the same way. DTSTART wins when both
+        // carry a TZID.
+        let source_tzid = ["DTSTART", "DUE"]
+            .iter()
+            .filter_map(|k| todo.properties().get(*k))
+            .find(|p| parse_tzid(p).is_some())
+            .and_then(|p| p.params().get("TZID").map(|v| v.value().to_string()));
+
+        // RFC 5545: DTSTART + DURATION stands in for DUE. Derive the due date and keep the
+        // original DURATION in `unmapped_properties` so `to_ics` writes the same form back.
+        // A DURATION that gives no usable due date (out of range, negative) is kept as is.
+        let mut scheduling_duration = None;
+        if due.is_none()
+            && let Some(start) = &dtstart
+            && let Some(raw) = get_prop("DURATION")
+        {
+            due = parse_ics_duration_minutes(&raw).and_then(|m| due_after_duration(start, m));
+            scheduling_duration = Some(raw);
+        }
         let rrule = get_prop("RRULE");
 
         let mut exdates = Vec::new();
@@ -759,6 +927,7 @@ impl IcsAdapter {
                     .get("VALUE")
                     .map(|v| v.value() == "DATE")
                     .unwrap_or(false);
+                let ex_tz = parse_tzid(prop);
                 let val_str = prop.value();
                 for part in val_str.split(',') {
                     let part = part.trim();
@@ -775,10 +944,12 @@ impl IcsAdapter {
                             exdates.push(DateType::Specific(Utc.from_utc_datetime(&dt)));
                         }
                     } else if let Ok(dt) = NaiveDateTime::parse_from_str(part, "%Y%m%dT%H%M%S") {
-                        exdates.push(DateType::Specific(crate::model::item::safe_local_to_utc(
-                            dt.date(),
-                            dt.time(),
-                        )));
+                        exdates.push(DateType::Specific(match &ex_tz {
+                            Some(tz) => {
+                                crate::model::item::safe_zoned_to_utc(dt.date(), dt.time(), tz)
+                            }
+                            None => crate::model::item::safe_local_to_utc(dt.date(), dt.time()),
+                        }));
                     }
                 }
             }
@@ -826,13 +997,14 @@ impl IcsAdapter {
                     num_buf.push(c);
                 } else if !num_buf.is_empty() {
                     let n = num_buf.parse::<u32>().unwrap_or(0);
-                    match c {
-                        'D' => minutes += n * 24 * 60,
-                        'H' if in_time => minutes += n * 60,
-                        'M' if in_time => minutes += n,
-                        'W' => minutes += n * 7 * 24 * 60,
-                        _ => {}
-                    }
+                    let per_unit = match c {
+                        'D' => 24 * 60,
+                        'H' if in_time => 60,
+                        'M' if in_time => 1,
+                        'W' => 7 * 24 * 60,
+                        _ => 0,
+                    };
+                    minutes = minutes.checked_add(n.checked_mul(per_unit)?)?;
                     num_buf.clear();
                 }
             }
@@ -886,14 +1058,18 @@ impl IcsAdapter {
         let mut parent_uid = None;
         let mut dependencies = Vec::new();
         let mut related_to = Vec::new();
+        // Every URL line and every link-valued ATTACH; the primary `url` is removed below.
+        let mut attachments: Vec<String> = Vec::new();
 
         // Manually parse sessions from unfolded text to ensure we catch all of them
         // regardless of how icalendar crate groups X- properties.
         let mut manual_sessions = Vec::new();
 
         let unfolded = icalendar::parser::unfold(raw_ics);
+        let mut other_relations: Vec<RawProperty> = Vec::new();
         let mut in_vtodo = false;
-        let mut in_valarm = false;
+        // Depth of VALARM (or other) blocks nested in the VTODO; their lines are not task properties.
+        let mut nested_depth = 0usize;
 
         for line in unfolded.lines() {
             let line = line.trim();
@@ -907,16 +1083,16 @@ impl IcsAdapter {
                 in_vtodo = false;
                 continue;
             }
-            if line_upper == "BEGIN:VALARM" {
-                in_valarm = true;
+            if in_vtodo && line_upper.starts_with("BEGIN:") {
+                nested_depth += 1;
                 continue;
             }
-            if line_upper == "END:VALARM" {
-                in_valarm = false;
+            if in_vtodo && nested_depth > 0 && line_upper.starts_with("END:") {
+                nested_depth -= 1;
                 continue;
             }
 
-            if in_vtodo && !in_valarm {
+            if in_vtodo && nested_depth == 0 {
                 // Case-insensitive checks
                 if line_upper.starts_with("RELATED-TO")
                     && let Some((raw_key, val)) = line.split_once(':')
@@ -924,20 +1100,34 @@ impl IcsAdapter {
                     let parts: Vec<&str> = raw_key.split(';').collect();
                     let mut is_dep = false;
                     let mut is_sibling = false;
+                    let mut is_other = false;
 
                     for param in parts.iter().skip(1) {
-                        let param_u = param.to_uppercase();
-                        if param_u.contains("RELTYPE") {
-                            if param_u.contains("DEPENDS-ON") {
-                                is_dep = true;
-                            } else if param_u.contains("SIBLING") {
-                                is_sibling = true;
-                            }
+                        let param_u = param.trim().to_uppercase();
+                        if param_u == "RELTYPE=DEPENDS-ON" {
+                            is_dep = true;
+                        } else if param_u == "RELTYPE=SIBLING" {
+                            is_sibling = true;
+                        } else if param_u.starts_with("RELTYPE=") && param_u != "RELTYPE=PARENT" {
+                            is_other = true;
                         }
                     }
 
                     let value = val.trim().to_string();
-                    if is_dep {
+                    if is_other {
+                        // CHILD and experimental X- types have no field; keep them verbatim.
+                        let params = parts
+                            .iter()
+                            .skip(1)
+                            .filter_map(|p| p.split_once('='))
+                            .map(|(k, v)| (k.to_string(), v.to_string()))
+                            .collect();
+                        other_relations.push(RawProperty {
+                            key: "RELATED-TO".to_string(),
+                            value,
+                            params,
+                        });
+                    } else if is_dep {
                         if !dependencies.contains(&value) {
                             dependencies.push(value);
                         }
@@ -957,6 +1147,22 @@ impl IcsAdapter {
                     }
                 }
 
+                // Extra links: repeated URL lines and ATTACH by reference (not inline binary)
+                if (line_upper.starts_with("URL:")
+                    || line_upper.starts_with("URL;")
+                    || line_upper.starts_with("ATTACH:")
+                    || line_upper.starts_with("ATTACH;"))
+                    && let Some((raw_key, val)) = line.split_once(':')
+                {
+                    let key_upper = raw_key.to_uppercase();
+                    let is_binary =
+                        key_upper.contains("ENCODING=BASE64") || key_upper.contains("VALUE=BINARY");
+                    let value = unescape_ics(val.trim());
+                    if !is_binary && !value.is_empty() && !attachments.contains(&value) {
+                        attachments.push(value);
+                    }
+                }
+
                 // Manual session parsing
                 if line_upper.starts_with("X-CFAIT-SESSION:")
                     && let Some((_, val)) = line.split_once(':')
@@ -979,6 +1185,11 @@ impl IcsAdapter {
             }
         }
 
+        if let Some(primary) = &url {
+            attachments
+                .retain(|a| a != primary && primary.strip_prefix("https://") != Some(a.as_str()));
+        }
+
         let mut unmapped_properties = Vec::new();
         let to_raw = |prop: &icalendar::Property| -> RawProperty {
             let mut params = Vec::new();
@@ -995,99 +1206,170 @@ impl IcsAdapter {
             }
         };
 
+        // Link-valued ATTACH lines are carried by `attachments`; inline binary ones stay raw.
+        let is_link_attach = |key: &str, prop: &icalendar::Property| -> bool {
+            key.eq_ignore_ascii_case("ATTACH")
+                && prop.params().get("ENCODING").is_none()
+                && !prop
+                    .params()
+                    .get("VALUE")
+                    .is_some_and(|v| v.value().eq_ignore_ascii_case("BINARY"))
+        };
+
         for (key, prop) in todo.properties() {
-            if !HANDLED_KEYS.contains(&key.to_uppercase().as_str()) {
+            if !HANDLED_KEYS.contains(&key.to_uppercase().as_str()) && !is_link_attach(key, prop) {
                 unmapped_properties.push(to_raw(prop));
             }
         }
         for (key, props) in todo.multi_properties() {
             if !HANDLED_KEYS.contains(&key.to_uppercase().as_str()) {
                 for prop in props {
-                    unmapped_properties.push(to_raw(prop));
+                    if !is_link_attach(key, prop) {
+                        unmapped_properties.push(to_raw(prop));
+                    }
                 }
             }
         }
+        unmapped_properties.extend(other_relations);
+        if let Some(raw) = scheduling_duration {
+            unmapped_properties.push(RawProperty {
+                key: "DURATION".to_string(),
+                value: raw,
+                params: vec![],
+            });
+        }
+        if let Some(tzid) = source_tzid {
+            unmapped_properties.push(RawProperty {
+                key: "TZID".to_string(),
+                value: tzid,
+                params: vec![],
+            });
+        }
+        // Parseable CREATED/COMPLETED stamps move to their fields; malformed ones stay raw.
+        let mut take_stamp = |key: &str| -> Option<DateTime<Utc>> {
+            let idx = unmapped_properties
+                .iter()
+                .position(|p| p.key == key && Task::parse_ics_stamp(p.value.trim()).is_some())?;
+            Task::parse_ics_stamp(unmapped_properties.remove(idx).value.trim())
+        };
+        let created = take_stamp("CREATED");
+        let completed = take_stamp("COMPLETED");
+
         if !unmapped_properties.is_empty() {
             unmapped_properties
                 .sort_unstable_by(|a, b| a.key.cmp(&b.key).then(a.value.cmp(&b.value)));
         }
 
         let mut alarms = Vec::new();
+        let mut raw_subcomponents: Vec<String> = Vec::new();
         let mut in_alarm = false;
-        let mut current_alarm_lines: Vec<String> = Vec::new();
-
-        for line in raw_ics.lines() {
+        // (line, is_inside_a_block_nested_in_the_alarm)
+        let mut current_alarm_lines: Vec<(String, bool)> = Vec::new();
+        let mut current_block: Vec<String> = Vec::new();
+        let mut depth = 0usize;
+
+        // Walk the unfolded text of the master VTODO so long DESCRIPTION/RELATED-TO lines survive
+        // server folding and alarms of override instances are not merged into the master.
+        for line in master_vtodo_lines(&unfolded) {
             let trim = line.trim();
-            if trim == "BEGIN:VALARM" {
-                in_alarm = true;
+            let upper = trim.to_uppercase();
+            if depth == 0 {
+                if upper == "BEGIN:VALARM" {
+                    in_alarm = true;
+                    depth = 1;
+                } else if upper.starts_with("BEGIN:") {
+                    current_block.push(trim.to_string());
+                    depth = 1;
+                }
                 continue;
             }
-            if trim == "END:VALARM" {
-                in_alarm = false;
-                let mut alarm = Alarm {
-                    uid: Uuid::new_v4().to_string(),
-                    action: "DISPLAY".to_string(),
-                    trigger: AlarmTrigger::Relative(0),
-                    description: None,
-                    acknowledged: None,
-                    related_to_uid: None,
-                    relation_type: None,
-                };
 
-                for l in &current_alarm_lines {
-                    if let Some((key, val)) = l.split_once(':') {
-                        let k_upper = key.split(';').next().unwrap_or(key).to_uppercase();
-                        match k_upper.as_str() {
-                            "UID" => alarm.uid = val.trim().to_string(),
-                            "ACTION" => alarm.action = val.trim().to_string(),
-                            "DESCRIPTION" => alarm.description = Some(val.trim().to_string()),
-                            "TRIGGER" => {
-                                if val.contains('T') && !val.contains('P') {
-                                    if let Ok(dt) =
-                                        NaiveDateTime::parse_from_str(val.trim(), "%Y%m%dT%H%M%SZ")
-                                    {
-                                        alarm.trigger =
-                                            AlarmTrigger::Absolute(Utc.from_utc_datetime(&dt));
-                                    }
-                                } else {
-                                    let v_trim = val.trim();
-                                    let is_neg = v_trim.starts_with('-');
-                                    let abs_mins = parse_ics_duration(if is_neg {
-                                        &v_trim[1..]
-                                    } else {
-                                        v_trim
-                                    });
-                                    alarm.trigger = AlarmTrigger::Relative(if is_neg {
-                                        -abs_mins
-                                    } else {
-                                        abs_mins
-                                    });
-                                }
-                            }
-                            "ACKNOWLEDGED" => {
-                                if let Ok(dt) =
-                                    NaiveDateTime::parse_from_str(val.trim(), "%Y%m%dT%H%M%SZ")
-                                {
-                                    alarm.acknowledged = Some(Utc.from_utc_datetime(&dt));
-                                }
-                            }
-                            "RELATED-TO" => {
-                                alarm.related_to_uid = Some(val.trim().to_string());
-                                if key.contains("RELTYPE=SNOOZE") {
-                                    alarm.relation_type = Some("SNOOZE".to_string());
-                                }
+            let depth_before = depth;
+            if upper.starts_with("BEGIN:") {
+                depth += 1;
+            } else if upper.starts_with("END:") {
+                depth -= 1;
+            }
+
+            if !in_alarm {
+                current_block.push(trim.to_string());
+                if depth == 0 {
+                    raw_subcomponents.push(current_block.join("\r\n"));
+                    current_block.clear();
+                }
+                continue;
+            }
+
+            if depth > 0 {
+                current_alarm_lines.push((trim.to_string(), depth_before.max(depth) > 1));
+                continue;
+            }
+
+            in_alarm = false;
+            let mut alarm = Alarm {
+                uid: Uuid::new_v4().to_string(),
+                action: "DISPLAY".to_string(),
+                trigger: AlarmTrigger::Relative(0),
+                description: None,
+                acknowledged: None,
+                related_to_uid: None,
+                relation_type: None,
+                is_default: false,
+                extra_lines: Vec::new(),
+            };
+
+            for (l, nested) in &current_alarm_lines {
+                if *nested {
+                    alarm.extra_lines.push(l.clone());
+                    continue;
+                }
+                let Some((key, val)) = l.split_once(':') else {
+                    continue;
+                };
+                let k_upper = key.split(';').next().unwrap_or(key).to_uppercase();
+                match k_upper.as_str() {
+                    "UID" => alarm.uid = val.trim().to_string(),
+                    "ACTION" => alarm.action = val.trim().to_string(),
+                    "DESCRIPTION" => alarm.description = Some(unescape_ics(val.trim())),
+                    "TRIGGER" => {
+                        if val.contains('T') && !val.contains('P') {
+                            if let Ok(dt) =
+                                NaiveDateTime::parse_from_str(val.trim(), "%Y%m%dT%H%M%SZ")
+                            {
+                                alarm.trigger = AlarmTrigger::Absolute(Utc.from_utc_datetime(&dt));
                             }
-                            _ => {}
+                        } else {
+                            let v_trim = val.trim();
+                            let is_neg = v_trim.starts_with('-');
+                            let abs_mins =
+                                parse_ics_duration(if is_neg { &v_trim[1..] } else { v_trim });
+                            alarm.trigger =
+                                AlarmTrigger::Relative(if is_neg { -abs_mins } else { abs_mins });
                         }
                     }
+                    "ACKNOWLEDGED" => {
+                        if let Ok(dt) = NaiveDateTime::parse_from_str(val.trim(), "%Y%m%dT%H%M%SZ")
+                        {
+                            alarm.acknowledged = Some(Utc.from_utc_datetime(&dt));
+                        }
+                    }
+                    "X-CFAIT-DEFAULT" => {
+                        alarm.is_default = val.trim().eq_ignore_ascii_case("TRUE");
+                    }
+                    "RELATED-TO" => {
+                        alarm.related_to_uid = Some(val.trim().to_string());
+                        alarm.relation_type = key.split(';').skip(1).find_map(|param| {
+                            let (name, value) = param.split_once('=')?;
+                            name.trim()
+                                .eq_ignore_ascii_case("RELTYPE")
+                                .then(|| value.trim().trim_matches('"').to_uppercase())
+                        });
+                    }
+                    _ => alarm.extra_lines.push(l.clone()),
                 }
-                alarms.push(alarm);
-                current_alarm_lines.clear();
-                continue;
-            }
-            if in_alarm {
-                current_alarm_lines.push(line.to_string());
             }
+            alarms.push(alarm);
+            current_alarm_lines.clear();
         }
 
         // Parse time-tracking fields from properties
@@ -1125,9 +1407,11 @@ impl IcsAdapter {
             rrule,
             location,
             url,
+            attachments,
             geo,
             collapsed,
             pinned,
+            waiting,
             is_note,
             manual_block,
             permanent,
@@ -1136,7 +1420,9 @@ impl IcsAdapter {
             sessions: manual_sessions, // Use manual parsing result
             unmapped_properties,
             sequence,
-            raw_alarms: Vec::new(),
+            created,
+            completed,
+            raw_subcomponents,
             raw_components,
             create_event,
             goal,
@@ -1721,3 +2007,155 @@ impl IcsAdapter {
         calendar.to_string()
     }
 }
+
+/// The IANA zone named by a property's `TZID` parameter, if it is one chrono-tz knows.
+/// Some producers prefix the name with `/` (RFC 5545 globally unique TZIDs).
+fn parse_tzid(prop: &icalendar::Property) -> Option<chrono_tz::Tz> {
+    let tzid = prop.params().get("TZID")?.value();
+    tzid.trim_start_matches('/').parse().ok()
+}
+
+/// The due date a `DTSTART` of `start` plus a DURATION of `minutes` stands for. `None` for
+/// non-positive spans, fuzzy starts and dates past the end of the calendar.
+fn due_after_duration(start: &DateType, minutes: i64) -> Option<DateType> {
+    if minutes <= 0 {
+        return None;
+    }
+    match start {
+        DateType::AllDay(d) if minutes % (24 * 60) == 0 => {
+            chrono::Duration::try_days(minutes / (24 * 60))
+                .and_then(|span| d.checked_add_signed(span))
+                .map(DateType::AllDay)
+        }
+        DateType::AllDay(_) | DateType::Specific(_) => chrono::Duration::try_minutes(minutes)
+            .and_then(|span| start.to_start_comparison_time().checked_add_signed(span))
+            .map(DateType::Specific),
+        // Fuzzy starts were never written with a scheduling DURATION
+        DateType::Month(..) | DateType::Year(..) => None,
+    }
+}
+
+/// The VTIMEZONE blocks of `raw_ics`, unfolded, each ending in CRLF.
+fn vtimezone_blocks(raw_ics: &str) -> Vec<String> {
+    let unfolded = icalendar::parser::unfold(raw_ics);
+    let mut blocks = Vec::new();
+    let mut current: Option<String> = None;
+    for line in unfolded.lines() {
+        let upper = line.trim().to_uppercase();
+        if upper == "BEGIN:VTIMEZONE" {
+            current = Some(String::new());
+        }
+        if let Some(block) = current.as_mut() {
+            block.push_str(line.trim_end());
+            block.push_str("\r\n");
+        }
+        if upper == "END:VTIMEZONE" {
+            blocks.extend(current.take());
+        }
+    }
+    blocks
+}
+
+/// Whether a raw component is the VTIMEZONE defining `tzid`.
+fn defines_tzid(component: &str, tzid: &str) -> bool {
+    let mut lines = component.lines().map(str::trim);
+    lines
+        .next()
+        .is_some_and(|l| l.eq_ignore_ascii_case("BEGIN:VTIMEZONE"))
+        && lines.any(|l| {
+            l.split_once(':').is_some_and(|(name, value)| {
+                name.split(';')
+                    .next()
+                    .is_some_and(|n| n.eq_ignore_ascii_case("TZID"))
+                    && value == tzid
+            })
+        })
+}
+
+/// Minutes in an RFC 5545 DURATION value (`PT2H`, `P1D`, `P1W`, `-PT15M`).
+/// Seconds are dropped; returns `None` when nothing parses or the value overflows.
+fn parse_ics_duration_minutes(val: &str) -> Option<i64> {
+    let val = val.trim();
+    let (sign, body) = match val.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, val.strip_prefix('+').unwrap_or(val)),
+    };
+    let body = body.strip_prefix(['P', 'p'])?;
+    let mut minutes: i64 = 0;
+    let mut num_buf = String::new();
+    let mut in_time = false;
+    let mut any = false;
+    for c in body.chars() {
+        match c.to_ascii_uppercase() {
+            'T' => in_time = true,
+            d if d.is_ascii_digit() => num_buf.push(d),
+            unit => {
+                let n: i64 = num_buf.parse().ok()?;
+                num_buf.clear();
+                any = true;
+                let per_unit = match unit {
+                    'W' => 7 * 24 * 60,
+                    'D' => 24 * 60,
+                    'H' if in_time => 60,
+                    'M' if in_time => 1,
+                    'S' if in_time => 0,
+                    _ => return None,
+                };
+                minutes = minutes.checked_add(n.checked_mul(per_unit)?)?;
+            }
+        }
+    }
+    (any && num_buf.is_empty()).then_some(sign * minutes)
+}
+
+/// Returns the inner lines (without `BEGIN`/`END:VTODO`) of the master VTODO in unfolded ICS text:
+/// the first top-level VTODO without a `RECURRENCE-ID`, falling back to the first VTODO.
+fn master_vtodo_lines(unfolded: &str) -> Vec<&str> {
+    let mut blocks: Vec<Vec<&str>> = Vec::new();
+    let mut current: Option<Vec<&str>> = None;
+    let mut depth = 0usize;
+
+    for line in unfolded.lines() {
+        let upper = line.trim().to_uppercase();
+        match current.as_mut() {
+            None => {
+                if upper == "BEGIN:VTODO" {
+                    current = Some(Vec::new());
+                    depth = 0;
+                }
+            }
+            Some(block) => {
+                if upper.starts_with("BEGIN:") {
+                    depth += 1;
+                } else if upper.starts_with("END:") {
+                    if depth == 0 {
+                        blocks.extend(current.take());
+                        continue;
+                    }
+                    depth -= 1;
+                }
+                block.push(line);
+            }
+        }
+    }
+
+    let is_override = |block: &Vec<&str>| {
+        let mut depth = 0usize;
+        block.iter().any(|l| {
+            let upper = l.trim().to_uppercase();
+            if upper.starts_with("BEGIN:") {
+                depth += 1;
+            } else if upper.starts_with("END:") {
+                depth = depth.saturating_sub(1);
+            }
+            depth == 0 && upper.starts_with("RECURRENCE-ID")
+        })
+    };
+
+    let idx = blocks.iter().position(|b| !is_override(b)).unwrap_or(0);
+    if blocks.is_empty() {
+        Vec::new()
+    } else {
+        blocks.swap_remove(idx)
+    }
+}
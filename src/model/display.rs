@@ -45,6 +45,9 @@ impl TaskDisplay for Task {
     }
 
     fn checkbox_symbol(&self) -> &'static str {
+        if self.is_waiting() {
+            return "[⧖]";
+        }
         if self.is_paused() {
             return "[‖]";
         }
@@ -88,15 +91,7 @@ impl TaskDisplay for Task {
                 };
                 format!("{} / {}", c_str, est_display)
             } else {
-                if max > min {
-                    format!(
-                        "~{}-{}",
-                        crate::model::parser::format_duration_compact(min),
-                        crate::model::parser::format_duration_compact(max)
-                    )
-                } else {
-                    format!("~{}", crate::model::parser::format_duration_compact(min))
-                }
+                crate::model::parser::format_estimate_range(min, max)
             }
         } else {
             if total_mins > 0 || self.last_started_at.is_some() {
@@ -149,6 +144,9 @@ impl TaskDisplay for Task {
         if let Some(u) = &self.url {
             s.push_str(&format!(" url:{}", crate::model::parser::quote_value(u)));
         }
+        for a in &self.attachments {
+            s.push_str(&format!(" url:{}", crate::model::parser::quote_value(a)));
+        }
         if let Some(g) = &self.geo {
             s.push_str(&format!(" geo:{}", crate::model::parser::quote_value(g)));
         }
@@ -194,7 +192,7 @@ impl TaskDisplay for Task {
         }
 
         for alarm in &self.alarms {
-            if alarm.is_snooze() || alarm.acknowledged.is_some() {
+            if alarm.is_snooze() || alarm.is_default || alarm.acknowledged.is_some() {
                 continue;
             }
             match alarm.trigger {
@@ -250,6 +248,10 @@ impl TaskDisplay for Task {
             s.push_str(" is:pinned");
         }
 
+        if self.waiting {
+            s.push_str(" is:waiting");
+        }
+
         if self.manual_block {
             let block_str = rust_i18n::t!("search_is_blocked");
             if block_str == "search_is_blocked" || block_str.is_empty() {
@@ -335,3 +337,138 @@ impl TaskDisplay for Task {
         s
     }
 }
+
+/// What the smart input would produce, without creating anything. Shared by the GUI
+/// preview line, `cfait parse` and tests, so a string like `@next friday 2pm` can be
+/// checked field by field.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsePreview {
+    pub summary: String,
+    pub description: String,
+    pub due: Option<crate::model::DateType>,
+    pub dtstart: Option<crate::model::DateType>,
+    pub rrule: Option<String>,
+    pub estimated_duration: Option<u32>,
+    pub estimated_duration_max: Option<u32>,
+    pub priority: u8,
+    pub categories: Vec<String>,
+    pub location: Option<String>,
+    pub url: Option<String>,
+    pub geo: Option<String>,
+    pub alarms: Vec<crate::model::Alarm>,
+    pub collection: Option<String>,
+    /// Inline `:=` alias definitions found in the input (applied to this preview only).
+    pub new_aliases: std::collections::HashMap<String, Vec<String>>,
+    /// Directive-like tokens that were not understood and stayed in the summary.
+    pub unrecognized: Vec<String>,
+}
+
+impl ParsePreview {
+    /// Runs the same pipeline as task creation: inline goals/aliases are split off, then
+    /// `Task::new` parses the rest with `aliases` plus any alias defined in the input.
+    pub fn parse(
+        input: &str,
+        aliases: &std::collections::HashMap<String, Vec<String>>,
+        default_time: Option<chrono::NaiveTime>,
+        holidays: &[chrono::NaiveDate],
+        week_start: chrono::Weekday,
+    ) -> Self {
+        let (without_goals, _) = crate::model::parser::extract_inline_goals(input);
+        let (clean, new_aliases) = crate::model::parser::extract_inline_aliases(&without_goals);
+        let mut all_aliases = aliases.clone();
+        all_aliases.extend(new_aliases.clone());
+
+        let task =
+            Task::new_with_date_settings(&clean, &all_aliases, default_time, holidays, week_start);
+        let unrecognized = crate::model::parser::unrecognized_directives(&clean, &task.summary);
+        Self {
+            summary: task.summary,
+            description: task.description,
+            due: task.due,
+            dtstart: task.dtstart,
+            rrule: task.rrule,
+            estimated_duration: task.estimated_duration,
+            estimated_duration_max: task.estimated_duration_max,
+            priority: task.priority,
+            categories: task.categories,
+            location: task.location,
+            url: task.url,
+            geo: task.geo,
+            alarms: task.alarms,
+            collection: task.target_collection,
+            new_aliases,
+            unrecognized,
+        }
+    }
+
+    /// Parsed fields as `(name, value)` rows, skipping empty ones. Absolute reminders
+    /// are shown in `date_fmt` / `time_fmt`.
+    pub fn fields(
+        &self,
+        date_fmt: crate::config::DateFormat,
+        time_fmt: crate::config::TimeFormat,
+    ) -> Vec<(&'static str, String)> {
+        let mut rows = vec![("summary", self.summary.clone())];
+        if !self.description.is_empty() {
+            rows.push(("description", self.description.clone()));
+        }
+        if let Some(d) = &self.due {
+            rows.push(("due", d.format_smart()));
+        }
+        if let Some(d) = &self.dtstart {
+            rows.push(("start", d.format_smart()));
+        }
+        if let Some(r) = &self.rrule {
+            rows.push(("rrule", r.clone()));
+        }
+        if let Some(min) = self.estimated_duration {
+            let mut value = crate::model::parser::format_duration_human(min);
+            if let Some(max) = self.estimated_duration_max {
+                value.push_str(&format!(
+                    " - {}",
+                    crate::model::parser::format_duration_human(max)
+                ));
+            }
+            rows.push(("duration", value));
+        }
+        if self.priority > 0 {
+            rows.push(("priority", self.priority.to_string()));
+        }
+        if !self.categories.is_empty() {
+            let tags: Vec<String> = self.categories.iter().map(|c| format!("#{}", c)).collect();
+            rows.push(("tags", tags.join(" ")));
+        }
+        if let Some(l) = &self.location {
+            rows.push(("location", l.clone()));
+        }
+        if let Some(u) = &self.url {
+            rows.push(("url", u.clone()));
+        }
+        if let Some(g) = &self.geo {
+            rows.push(("geo", g.clone()));
+        }
+        if !self.alarms.is_empty() {
+            let alarms: Vec<String> = self
+                .alarms
+                .iter()
+                .map(|a| a.describe(date_fmt, time_fmt))
+                .collect();
+            rows.push(("alarms", alarms.join(", ")));
+        }
+        if let Some(c) = &self.collection {
+            rows.push(("collection", c.clone()));
+        }
+        let mut alias_keys: Vec<&String> = self.new_aliases.keys().collect();
+        alias_keys.sort();
+        for key in alias_keys {
+            rows.push((
+                "alias",
+                format!("{} := {}", key, self.new_aliases[key].join(",")),
+            ));
+        }
+        if !self.unrecognized.is_empty() {
+            rows.push(("unrecognized", self.unrecognized.join(" ")));
+        }
+        rows
+    }
+}
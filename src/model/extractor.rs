@@ -119,6 +119,62 @@ pub fn extract_list_prefix(line: &str) -> String {
     prefix
 }
 
+/// One line of multi-line smart input (see `parse_input_outline`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutlineLine {
+    /// Smart-input text with indentation, bullet and empty checkbox removed.
+    pub text: String,
+    /// Index of the parent line in the returned list.
+    pub parent: Option<usize>,
+}
+
+/// Splits pasted smart input into one entry per non-empty line. Leading whitespace (a tab
+/// counts as 4 spaces) and a `-`/`*`/`+` bullet deepen a line, which then becomes the child
+/// of the closest previous line that is less deep.
+pub fn parse_input_outline(input: &str) -> Vec<OutlineLine> {
+    let mut out: Vec<OutlineLine> = Vec::new();
+    // (depth, index in `out`) of the open ancestors
+    let mut stack: Vec<(usize, usize)> = Vec::new();
+
+    for line in input.lines() {
+        let mut depth = 0;
+        let mut byte_offset = 0;
+        for c in line.chars() {
+            match c {
+                ' ' => depth += 1,
+                '\t' => depth += 4,
+                _ => break,
+            }
+            byte_offset += c.len_utf8();
+        }
+        let mut rest = line[byte_offset..].trim_end();
+        if let Some(stripped) = rest
+            .strip_prefix("- ")
+            .or_else(|| rest.strip_prefix("* "))
+            .or_else(|| rest.strip_prefix("+ "))
+        {
+            depth += 1;
+            rest = stripped
+                .strip_prefix("[ ] ")
+                .unwrap_or(stripped)
+                .trim_start();
+        }
+        if rest.is_empty() {
+            continue;
+        }
+
+        while stack.last().is_some_and(|(d, _)| *d >= depth) {
+            stack.pop();
+        }
+        out.push(OutlineLine {
+            text: rest.to_string(),
+            parent: stack.last().map(|(_, idx)| *idx),
+        });
+        stack.push((depth, out.len() - 1));
+    }
+    out
+}
+
 pub fn has_extractable_subtasks(input: &str) -> bool {
     for line in input.lines() {
         let mut byte_offset = 0;
@@ -474,7 +530,13 @@ pub fn serialize_task_tree(store: &crate::store::TaskStore, root_uid: &str) -> S
     // This perfectly preserves sequence ordering (1., 2., 3.) when re-extracting markdown.
     for list in children_map.values_mut() {
         list.sort_by(|a, b| {
-            a.compare_for_sort(b, 5, false, crate::config::SortPreset::UrgentStartedDue)
+            a.compare_for_sort(
+                b,
+                5,
+                false,
+                crate::config::SortPreset::UrgentStartedDue,
+                crate::config::AllDaySort::default(),
+            )
         });
 
         if list.len() <= 1 {
@@ -718,3 +780,34 @@ pub fn serialize_task_tree(store: &crate::store::TaskStore, root_uid: &str) -> S
 
     out.trim_end().to_string()
 }
+
+/// Renders an already filtered, hierarchy-ordered view as a Markdown checklist for sharing.
+/// Indentation follows `task.depth`, done tasks are checked, notes get no checkbox, and
+/// expand/collapse placeholders are skipped.
+pub fn serialize_view_checklist(items: &[crate::store::TaskListItem]) -> String {
+    let mut out = String::new();
+    for item in items {
+        let crate::store::TaskListItem::Task(task) = item else {
+            continue;
+        };
+        let checkbox = if task.is_note {
+            ""
+        } else if task.status.is_done() {
+            "[x] "
+        } else {
+            "[ ] "
+        };
+        out.push_str(&"    ".repeat(task.depth));
+        out.push_str("- ");
+        out.push_str(checkbox);
+        out.push_str(&task.summary);
+        if let Some(due) = &task.due {
+            out.push_str(&format!(
+                " ({})",
+                rust_i18n::t!("checklist_due", date = due.format_smart())
+            ));
+        }
+        out.push('\n');
+    }
+    out.trim_end().to_string()
+}
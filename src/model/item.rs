@@ -16,7 +16,10 @@ Notes / rationale:
   modules so higher-level logic stays testable and encapsulated.
 */
 
-use chrono::{DateTime, Datelike, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use crate::config::{AllDaySort, DateFormat, TimeFormat};
+use chrono::{
+    DateTime, Datelike, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc, Weekday,
+};
 use serde::{Deserialize, Deserializer, Serialize};
 use std::cmp::Ordering;
 use std::collections::{HashMap, HashSet};
@@ -33,6 +36,27 @@ pub struct CalendarListEntry {
     pub color: Option<String>,
 }
 
+/// A time of day in the given clock (`14:30` or `2:30 PM`).
+pub fn format_clock(time: NaiveTime, time_fmt: TimeFormat) -> String {
+    match time_fmt {
+        TimeFormat::H24 => time.format("%H:%M").to_string(),
+        TimeFormat::H12 => time.format("%-I:%M %p").to_string(),
+    }
+}
+
+/// A full local timestamp (completion, creation, ...) in the given formats.
+pub fn format_timestamp(
+    local: &DateTime<Local>,
+    date_fmt: DateFormat,
+    time_fmt: TimeFormat,
+) -> String {
+    format!(
+        "{} {}",
+        DateType::AllDay(local.date_naive()).format_with(date_fmt, time_fmt),
+        format_clock(local.time(), time_fmt)
+    )
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
 pub enum TaskStatus {
     NeedsAction,
@@ -47,6 +71,13 @@ impl TaskStatus {
     }
 }
 
+/// X- property holding how many times a recurring task has been completed.
+pub const COMPLETION_COUNT_KEY: &str = "X-CFAIT-COMPLETION-COUNT";
+/// X- property holding the most recent completion timestamps, space separated.
+pub const COMPLETION_LOG_KEY: &str = "X-CFAIT-COMPLETION-LOG";
+/// How many completion timestamps a recurring task keeps.
+pub const COMPLETION_LOG_LIMIT: usize = 10;
+
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct RawProperty {
     pub key: String,
@@ -72,15 +103,20 @@ pub enum DateType {
 }
 
 pub fn safe_local_to_utc(date: NaiveDate, time: NaiveTime) -> DateTime<Utc> {
+    safe_zoned_to_utc(date, time, &Local)
+}
+
+/// Like `safe_local_to_utc`, but for a wall-clock time in an explicit zone (e.g. an ICS `TZID`).
+pub fn safe_zoned_to_utc<Tz: TimeZone>(date: NaiveDate, time: NaiveTime, tz: &Tz) -> DateTime<Utc> {
     let ndt = date.and_time(time);
-    match ndt.and_local_timezone(Local) {
+    match tz.from_local_datetime(&ndt) {
         chrono::LocalResult::Single(dt) => dt.with_timezone(&Utc),
         chrono::LocalResult::Ambiguous(dt1, _) => dt1.with_timezone(&Utc), // prefer standard time over daylight
         chrono::LocalResult::None => {
             // Time falls in a DST gap. Resolve by advancing the time by 1 hour (which is usually valid),
             // then subtracting 1 hour in UTC. This maps the invalid local time to the UTC time just before the jump.
             let shifted = ndt + chrono::Duration::hours(1);
-            match shifted.and_local_timezone(Local) {
+            match tz.from_local_datetime(&shifted) {
                 chrono::LocalResult::Single(dt2) | chrono::LocalResult::Ambiguous(dt2, _) => {
                     dt2.with_timezone(&Utc) - chrono::Duration::hours(1)
                 }
@@ -126,6 +162,19 @@ impl DateType {
         }
     }
 
+    /// For ordering: like `to_comparison_time`, except that all-day dates move to
+    /// the start of their day under `AllDaySort::StartOfDay`. Overdue and urgency
+    /// checks keep using `to_comparison_time`, so a task due today is never overdue
+    /// before the day ends.
+    pub fn to_sort_time(&self, all_day_sort: AllDaySort) -> DateTime<Utc> {
+        match self {
+            DateType::AllDay(_) if all_day_sort == AllDaySort::StartOfDay => {
+                self.to_start_comparison_time()
+            }
+            _ => self.to_comparison_time(),
+        }
+    }
+
     /// For start date logic: snap fuzzy dates to the FIRST second of the period
     pub fn to_start_comparison_time(&self) -> DateTime<Utc> {
         match self {
@@ -161,6 +210,38 @@ impl DateType {
         }
     }
 
+    /// Read-only rendering following `Config::date_format` / `Config::time_format`.
+    /// Anything that gets re-parsed (the smart string) must use `format_smart` instead.
+    pub fn format_with(&self, date_fmt: DateFormat, time_fmt: TimeFormat) -> String {
+        use chrono::Timelike;
+        let date_pattern = match date_fmt {
+            DateFormat::Iso => "%Y-%m-%d",
+            DateFormat::Us => "%m/%d/%Y",
+            DateFormat::Eu => "%d/%m/%Y",
+            DateFormat::IsoWeek => "%G-W%V-%u",
+        };
+        match self {
+            DateType::AllDay(d) => d.format(date_pattern).to_string(),
+            DateType::Specific(dt) => {
+                let local = dt.with_timezone(&Local);
+                if local.hour() == 0 && local.minute() == 0 && local.second() == 0 {
+                    local.format(date_pattern).to_string()
+                } else {
+                    format!(
+                        "{} {}",
+                        local.format(date_pattern),
+                        format_clock(local.time(), time_fmt)
+                    )
+                }
+            }
+            DateType::Month(y, m) => match date_fmt {
+                DateFormat::Us | DateFormat::Eu => format!("{:02}/{:04}", m, y),
+                DateFormat::Iso | DateFormat::IsoWeek => format!("{:04}-{:02}", y, m),
+            },
+            DateType::Year(y) => format!("{:04}", y),
+        }
+    }
+
     pub fn to_utc_with_default_time(&self, default_time: NaiveTime) -> DateTime<Utc> {
         match self {
             DateType::Specific(dt) => *dt,
@@ -204,6 +285,12 @@ pub struct Alarm {
     pub acknowledged: Option<DateTime<Utc>>,
     pub related_to_uid: Option<String>,
     pub relation_type: Option<String>,
+    /// Auto-added from `default_reminder_minutes`; never written back into smart input.
+    #[serde(default)]
+    pub is_default: bool,
+    /// Unmodelled lines of the VALARM (e.g. `ATTACH`, `REPEAT`, nested blocks), re-emitted verbatim.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extra_lines: Vec<String>,
 }
 
 impl Alarm {
@@ -216,6 +303,8 @@ impl Alarm {
             acknowledged: None,
             related_to_uid: None,
             relation_type: None,
+            is_default: false,
+            extra_lines: Vec::new(),
         }
     }
 
@@ -228,12 +317,40 @@ impl Alarm {
             acknowledged: None,
             related_to_uid: None,
             relation_type: None,
+            is_default: false,
+            extra_lines: Vec::new(),
+        }
+    }
+
+    /// A relative alarm generated from the configured default lead time.
+    pub fn new_default(minutes_before: u32) -> Self {
+        Self {
+            is_default: true,
+            ..Self::new_relative(minutes_before)
         }
     }
 
     pub fn is_snooze(&self) -> bool {
         self.relation_type.as_deref() == Some("SNOOZE")
     }
+
+    /// Human-readable trigger: "15 min before" / "1 h after" for relative alarms, the local
+    /// date and time for absolute ones.
+    pub fn describe(&self, date_fmt: DateFormat, time_fmt: TimeFormat) -> String {
+        match &self.trigger {
+            AlarmTrigger::Relative(mins) => {
+                let duration = crate::model::parser::format_duration_human(mins.unsigned_abs());
+                if *mins <= 0 {
+                    rust_i18n::t!("reminder_before", duration = duration).to_string()
+                } else {
+                    rust_i18n::t!("reminder_after", duration = duration).to_string()
+                }
+            }
+            AlarmTrigger::Absolute(dt) => {
+                format_timestamp(&dt.with_timezone(&Local), date_fmt, time_fmt)
+            }
+        }
+    }
 }
 
 fn deserialize_date_option<'de, D>(deserializer: D) -> Result<Option<DateType>, D::Error>
@@ -301,12 +418,20 @@ pub struct Task {
     pub rrule: Option<String>,
     pub location: Option<String>,
     pub url: Option<String>,
+    /// Additional links beyond the primary `url` (repeated `url:` tokens, extra URL and
+    /// ATTACH properties). Written back as ATTACH.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<String>,
     pub geo: Option<String>,
     #[serde(default)]
     pub collapsed: bool,
     /// When `true`, task is pinned to the top of the list, overriding normal sorting.
     #[serde(default)]
     pub pinned: bool,
+    /// When `true`, the task is waiting on someone else (`is:waiting`). A display/filter
+    /// pseudo-status stored as `X-CFAIT-WAITING`; `status` stays `NeedsAction` for interop.
+    #[serde(default)]
+    pub waiting: bool,
     /// When `true`, task acts as a structural note or header (hides checkbox).
     #[serde(default)]
     pub is_note: bool,
@@ -332,8 +457,16 @@ pub struct Task {
     pub unmapped_properties: Vec<RawProperty>,
     #[serde(default)]
     pub sequence: u32,
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub raw_alarms: Vec<String>,
+    /// CREATED stamp. Set by `Task::new` and restamped on duplicates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created: Option<DateTime<Utc>>,
+    /// COMPLETED stamp. Set and cleared by the status toggle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed: Option<DateTime<Utc>>,
+    /// Nested blocks of the VTODO other than VALARM (e.g. `BEGIN:X-FOO`), kept verbatim.
+    /// Read from `raw_alarms` in caches written before the rename.
+    #[serde(default, alias = "raw_alarms", skip_serializing_if = "Vec::is_empty")]
+    pub raw_subcomponents: Vec<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub raw_components: Vec<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -401,6 +534,7 @@ pub struct CompareOptions {
     pub start_grace_period_days: u32,
     pub sort_standard_by_priority: bool,
     pub sort_preset: crate::config::SortPreset,
+    pub all_day_sort: AllDaySort,
 }
 
 /// Comparison helper for sort policies. The ordering decision tree is centralized here
@@ -416,6 +550,7 @@ pub fn compare_sortkeys(
     default_prio: u8,
     sort_standard_by_priority: bool,
     sort_preset: crate::config::SortPreset,
+    all_day_sort: AllDaySort,
 ) -> Ordering {
     let effective_rank = |rank: u8| {
         if sort_standard_by_priority && rank == 5 {
@@ -433,7 +568,9 @@ pub fn compare_sortkeys(
     let norm_prio = |p: u8| if p == 0 { default_prio } else { p };
     let compare_dates = |d1: &Option<DateType>, d2: &Option<DateType>| -> Ordering {
         match (d1, d2) {
-            (Some(a), Some(b)) => a.cmp(b),
+            (Some(a), Some(b)) => a
+                .to_sort_time(all_day_sort)
+                .cmp(&b.to_sort_time(all_day_sort)),
             (Some(_), None) => Ordering::Less,
             (None, Some(_)) => Ordering::Greater,
             (None, None) => Ordering::Equal,
@@ -479,6 +616,22 @@ pub fn compare_sortkeys(
     }
 }
 
+/// Returns `(is_urgent, is_due_soon)`: priority set and within `urgent_prio`,
+/// and due no later than `urgent_days` from `now`.
+fn urgency_flags(
+    priority: u8,
+    due: Option<&DateType>,
+    urgent_days: u32,
+    urgent_prio: u8,
+    now: DateTime<Utc>,
+) -> (bool, bool) {
+    let is_urgent = priority > 0 && priority <= urgent_prio;
+    let is_due_soon = due.is_some_and(|due| {
+        due.to_comparison_time() <= now + chrono::Duration::days(urgent_days as i64)
+    });
+    (is_urgent, is_due_soon)
+}
+
 // Helper context used by hierarchy organization routines.
 // Bundles the children map, result vector and other parameters so recursive helpers
 impl Task {
@@ -545,11 +698,41 @@ impl Task {
         }
     }
 
-    pub fn created_date(&self) -> Option<DateTime<Utc>> {
+    /// A task in a remote collection that the server never accepted: it has no ETag.
+    /// `pending_refresh` means the upload went through and only the ETag is missing.
+    pub fn is_unsynced_remote(&self) -> bool {
+        !self.calendar_href.is_empty()
+            && !self.calendar_href.starts_with("local://")
+            && self.etag.is_empty()
+    }
+
+    /// Files written before `created`/`completed` existed kept these stamps among the
+    /// unmapped properties, so those are still read when the field is unset.
+    fn legacy_stamp(&self, key: &str) -> Option<DateTime<Utc>> {
         self.unmapped_properties
             .iter()
-            .find(|p| p.key == "CREATED")
-            .and_then(|p| Self::parse_ics_datetime(p.value.trim()))
+            .find(|p| p.key == key)
+            .and_then(|p| Self::parse_ics_stamp(p.value.trim()))
+    }
+
+    /// Parse a CREATED/COMPLETED value, accepting the date-only form some clients write.
+    pub(crate) fn parse_ics_stamp(v: &str) -> Option<DateTime<Utc>> {
+        Self::parse_ics_datetime(v).or_else(|| {
+            NaiveDate::parse_from_str(v, "%Y%m%d")
+                .ok()
+                .and_then(|nd| nd.and_hms_opt(0, 0, 0))
+                .map(|ndt| Utc.from_utc_datetime(&ndt))
+        })
+    }
+
+    pub fn created_date(&self) -> Option<DateTime<Utc>> {
+        self.created.or_else(|| self.legacy_stamp("CREATED"))
+    }
+
+    /// Stamp (or restamp) the creation date.
+    pub fn set_created_date(&mut self, dt: DateTime<Utc>) {
+        self.unmapped_properties.retain(|p| p.key != "CREATED");
+        self.created = Some(dt);
     }
 
     pub fn last_modified_date(&self) -> Option<DateTime<Utc>> {
@@ -560,40 +743,91 @@ impl Task {
             .and_then(|p| Self::parse_ics_datetime(p.value.trim()))
     }
 
-    /// Return the explicit COMPLETED date parsed from unmapped properties, if present.
+    /// Return the explicit COMPLETED date, if present.
     pub fn completion_date(&self) -> Option<DateTime<Utc>> {
-        self.unmapped_properties
-            .iter()
-            .find(|p| p.key == "COMPLETED")
-            .and_then(|p| {
-                let v = p.value.trim();
-                Self::parse_ics_datetime(v).or_else(|| {
-                    NaiveDate::parse_from_str(v, "%Y%m%d")
-                        .ok()
-                        .and_then(|nd| nd.and_hms_opt(0, 0, 0))
-                        .map(|ndt| Utc.from_utc_datetime(&ndt))
-                })
-            })
+        self.completed.or_else(|| self.legacy_stamp("COMPLETED"))
     }
 
-    /// Set (or clear) the COMPLETED date property and ensure status aligns.
+    /// Set (or clear) the COMPLETED date and ensure status aligns.
     pub fn set_completion_date(&mut self, dt: Option<DateTime<Utc>>) {
-        // Remove existing COMPLETED prop
+        // Drop a stamp carried over from the old unmapped-property storage
         self.unmapped_properties.retain(|p| p.key != "COMPLETED");
 
-        if let Some(date) = dt {
-            // If we're setting a completion date, ensure task is in a done state.
-            if !self.status.is_done() {
-                self.status = TaskStatus::Completed;
-            }
-
-            let val = date.format("%Y%m%dT%H%M%SZ").to_string();
-            self.unmapped_properties.push(RawProperty {
-                key: "COMPLETED".to_string(),
-                value: val,
-                params: vec![],
-            });
+        // If we're setting a completion date, ensure task is in a done state.
+        if dt.is_some() && !self.status.is_done() {
+            self.status = TaskStatus::Completed;
         }
+        self.completed = dt;
+    }
+
+    /// Records a completion of a recurring task on the task itself, so the
+    /// series keeps its own record after `recycle` advances it. Bumps
+    /// `X-CFAIT-COMPLETION-COUNT` and prepends the timestamp to
+    /// `X-CFAIT-COMPLETION-LOG`, which keeps only the newest `COMPLETION_LOG_LIMIT`
+    /// entries. Both are single X- properties so they survive other clients and
+    /// the per-key merge of unmapped properties.
+    pub fn record_completion(&mut self, at: DateTime<Utc>) {
+        let (count, mut log) = self.completion_log();
+        log.insert(0, at);
+        log.truncate(COMPLETION_LOG_LIMIT);
+        self.unmapped_properties
+            .retain(|p| p.key != COMPLETION_COUNT_KEY && p.key != COMPLETION_LOG_KEY);
+        self.unmapped_properties.push(RawProperty {
+            key: COMPLETION_COUNT_KEY.to_string(),
+            value: (count + 1).to_string(),
+            params: vec![],
+        });
+        self.unmapped_properties.push(RawProperty {
+            key: COMPLETION_LOG_KEY.to_string(),
+            value: log
+                .iter()
+                .map(|d| d.format("%Y%m%dT%H%M%SZ").to_string())
+                .collect::<Vec<_>>()
+                .join(" "),
+            params: vec![],
+        });
+    }
+
+    /// Total completions recorded by `record_completion` and the retained
+    /// timestamps, newest first.
+    pub fn completion_log(&self) -> (u32, Vec<DateTime<Utc>>) {
+        let mut log: Vec<DateTime<Utc>> = self
+            .unmapped_properties
+            .iter()
+            .find(|p| p.key == COMPLETION_LOG_KEY)
+            .map(|p| {
+                p.value
+                    .split_whitespace()
+                    .filter_map(Self::parse_ics_datetime)
+                    .collect()
+            })
+            .unwrap_or_default();
+        log.sort_by(|a, b| b.cmp(a));
+        let count = self
+            .unmapped_properties
+            .iter()
+            .find(|p| p.key == COMPLETION_COUNT_KEY)
+            .and_then(|p| p.value.trim().parse::<u32>().ok())
+            .unwrap_or(0)
+            .max(log.len() as u32);
+        (count, log)
+    }
+
+    /// Localized "done N times, last on X" line for the task details, or
+    /// `None` when no completion has been recorded.
+    pub fn completion_log_summary(
+        &self,
+        date_fmt: DateFormat,
+        time_fmt: TimeFormat,
+    ) -> Option<String> {
+        let (count, log) = self.completion_log();
+        let last = log.first()?;
+        let date = format_timestamp(&last.with_timezone(&Local), date_fmt, time_fmt);
+        Some(if count == 1 {
+            rust_i18n::t!("habit_completion_log.one", date = date).to_string()
+        } else {
+            rust_i18n::t!("habit_completion_log.other", count = count, date = date).to_string()
+        })
     }
 
     /// Safely add a work session and update the total tracked time.
@@ -619,6 +853,18 @@ impl Task {
         input: &str,
         aliases: &HashMap<String, Vec<String>>,
         default_reminder_time: Option<NaiveTime>,
+    ) -> Self {
+        Self::new_with_date_settings(input, aliases, default_reminder_time, &[], Weekday::Mon)
+    }
+
+    /// `new`, with `holidays` (`Config::holidays`) skipped by `@in N bd` and `eow`
+    /// ending on the week that starts on `week_start`.
+    pub fn new_with_date_settings(
+        input: &str,
+        aliases: &HashMap<String, Vec<String>>,
+        default_reminder_time: Option<NaiveTime>,
+        holidays: &[NaiveDate],
+        week_start: Weekday,
     ) -> Self {
         let mut task = Self {
             uid: Uuid::new_v4().to_string(),
@@ -644,9 +890,11 @@ impl Task {
             rrule: None,
             location: None,
             url: None,
+            attachments: Vec::new(),
             geo: None,
             collapsed: false,
             pinned: false,
+            waiting: false,
             is_note: false,
             manual_block: false,
             permanent: false,
@@ -655,7 +903,9 @@ impl Task {
             sessions: Vec::new(),
             unmapped_properties: Vec::new(),
             sequence: 0,
-            raw_alarms: Vec::new(),
+            created: Some(Utc::now()),
+            completed: None,
+            raw_subcomponents: Vec::new(),
             raw_components: Vec::new(),
             create_event: None,
             goal: None,
@@ -678,7 +928,7 @@ impl Task {
             tree_location_count: 0,
             is_search_context: false,
         };
-        task.apply_smart_input(input, aliases, default_reminder_time);
+        task.apply_smart_input(input, aliases, default_reminder_time, holidays, week_start);
         task
     }
 
@@ -687,9 +937,76 @@ impl Task {
         input: &str,
         aliases: &HashMap<String, Vec<String>>,
         default_reminder_time: Option<NaiveTime>,
+        holidays: &[NaiveDate],
+        week_start: Weekday,
     ) {
         // Delegate to parser module to keep the model focused on state.
-        super::parser::apply_smart_input(self, input, aliases, default_reminder_time);
+        super::parser::apply_smart_input(
+            self,
+            input,
+            aliases,
+            default_reminder_time,
+            holidays,
+            week_start,
+        );
+    }
+
+    /// Same as `apply_smart_input`, returning the directive-like tokens that were not
+    /// understood and stayed in the summary as text.
+    pub fn apply_smart_input_with_diagnostics(
+        &mut self,
+        input: &str,
+        aliases: &HashMap<String, Vec<String>>,
+        default_reminder_time: Option<NaiveTime>,
+        holidays: &[NaiveDate],
+        week_start: Weekday,
+    ) -> Vec<String> {
+        super::parser::apply_smart_input_with_diagnostics(
+            self,
+            input,
+            aliases,
+            default_reminder_time,
+            holidays,
+            week_start,
+        )
+    }
+
+    /// Whether the task still needs triage (`is:inbox`): open, with no
+    /// categories, no due date and no parent.
+    pub fn is_inbox(&self) -> bool {
+        !self.status.is_done()
+            && self.categories.is_empty()
+            && self.due.is_none()
+            && self.parent_uid.is_none()
+    }
+
+    /// Whether the task belongs in focus mode (`is:focus`): ongoing or paused
+    /// work, or an open task that is urgent by the same rules the sort ranking
+    /// uses (priority within `urgent_prio` or due within `urgent_days`).
+    /// Tasks that have not started yet are excluded, even when urgent.
+    pub fn is_focus_candidate(&self, urgent_days: u32, urgent_prio: u8) -> bool {
+        if self.status.is_done() {
+            return false;
+        }
+        if self.status == TaskStatus::InProcess || self.is_paused() {
+            return true;
+        }
+        let now = Utc::now();
+        if self
+            .dtstart
+            .as_ref()
+            .is_some_and(|d| d.to_start_comparison_time() > now)
+        {
+            return false;
+        }
+        let (is_urgent, is_due_soon) = urgency_flags(
+            self.priority,
+            self.due.as_ref(),
+            urgent_days,
+            urgent_prio,
+            now,
+        );
+        is_urgent || is_due_soon
     }
 
     /// Calculate a compact base rank used by the multi-stage sort algorithm.
@@ -740,10 +1057,13 @@ impl Task {
             }
         }
 
-        let is_urgent = self.effective_priority > 0 && self.effective_priority <= urgent_prio;
-        let is_due_soon = self.effective_due.as_ref().is_some_and(|due| {
-            due.to_comparison_time() <= now + chrono::Duration::days(urgent_days as i64)
-        });
+        let (is_urgent, is_due_soon) = urgency_flags(
+            self.effective_priority,
+            self.effective_due.as_ref(),
+            urgent_days,
+            urgent_prio,
+            now,
+        );
         let is_in_process = self.status == TaskStatus::InProcess;
 
         match sort_preset {
@@ -803,6 +1123,7 @@ impl Task {
         default_priority: u8,
         sort_standard_by_priority: bool,
         sort_preset: crate::config::SortPreset,
+        all_day_sort: AllDaySort,
     ) -> Ordering {
         // Stable ordering for trash and completed groups uses completion date desc.
         if self.sort_rank == 9 && other.sort_rank == 9 {
@@ -839,10 +1160,60 @@ impl Task {
             default_priority,
             sort_standard_by_priority,
             sort_preset,
+            all_day_sort,
         )
         .then_with(|| self.summary.cmp(&other.summary))
     }
 
+    /// Compare two tasks under a non-`Smart` `SortMode`, using the ranks computed by
+    /// `TaskStore::filter`. Done and trash groups stay at the bottom in completion order.
+    /// With `urgency_boost` the open ranks (pinned, urgent, ...) are kept as groups and
+    /// the mode's key only orders tasks within a rank.
+    pub fn compare_for_mode(
+        &self,
+        other: &Self,
+        mode: crate::config::SortMode,
+        urgency_boost: bool,
+        default_priority: u8,
+        all_day_sort: AllDaySort,
+    ) -> Ordering {
+        let group = |rank: u8| if rank >= 8 || urgency_boost { rank } else { 0 };
+        let by_group = group(self.sort_rank).cmp(&group(other.sort_rank));
+        if by_group != Ordering::Equal {
+            return by_group;
+        }
+        if self.sort_rank >= 8 {
+            return other
+                .completion_date()
+                .cmp(&self.completion_date())
+                .then_with(|| self.summary.cmp(&other.summary));
+        }
+
+        let norm_prio = |p: u8| if p == 0 { default_priority } else { p };
+        let by_due = |a: &Self, b: &Self| match (&a.effective_due, &b.effective_due) {
+            (Some(x), Some(y)) => x
+                .to_sort_time(all_day_sort)
+                .cmp(&y.to_sort_time(all_day_sort)),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        };
+        let by_key = match mode {
+            crate::config::SortMode::DueDate => by_due(self, other),
+            crate::config::SortMode::Priority => norm_prio(self.effective_priority)
+                .cmp(&norm_prio(other.effective_priority))
+                .then_with(|| by_due(self, other)),
+            // Newest first; tasks without a CREATED stamp go last
+            crate::config::SortMode::Created => other.created_date().cmp(&self.created_date()),
+            crate::config::SortMode::Alphabetical => self
+                .summary
+                .to_lowercase()
+                .cmp(&other.summary.to_lowercase()),
+            crate::config::SortMode::Smart => Ordering::Equal,
+        };
+        by_key.then_with(|| self.summary.cmp(&other.summary))
+    }
+
     /// Compare taking into account cutoff and other global settings.
     pub fn compare_with_cutoff(&self, other: &Self, opts: &CompareOptions) -> Ordering {
         let eff_blocked_self = self.is_blocked || self.is_implicitly_blocked;
@@ -884,6 +1255,7 @@ impl Task {
             opts.default_priority,
             opts.sort_standard_by_priority,
             opts.sort_preset,
+            opts.all_day_sort,
         )
         .then_with(|| self.summary.cmp(&other.summary))
     }
@@ -934,6 +1306,34 @@ impl Task {
         self.snooze_alarm(alarm_uid, mins)
     }
 
+    /// Appends the alarms described by a `rem:` value (`10m`, `9am`, `tomorrow 8:00`,
+    /// `in 2h`, ...). A bare time anchors on the due (or start) date like the smart input
+    /// does. Returns `false` when the spec does not describe a reminder.
+    pub fn add_reminder(&mut self, spec: &str, default_reminder_time: Option<NaiveTime>) -> bool {
+        let spec = spec.trim();
+        let spec = spec.strip_prefix("rem:").unwrap_or(spec);
+        if spec.is_empty() {
+            return false;
+        }
+        if let Some(t) = super::parser::parse_time_string(spec)
+            && let Some(anchor) = self.due.as_ref().or(self.dtstart.as_ref())
+        {
+            let dt = safe_local_to_utc(anchor.to_date_naive(), t);
+            self.alarms.push(Alarm::new_absolute(dt));
+            return true;
+        }
+        let probe = Task::new(
+            &format!("rem:{}", spec),
+            &HashMap::new(),
+            default_reminder_time,
+        );
+        if probe.alarms.is_empty() {
+            return false;
+        }
+        self.alarms.extend(probe.alarms);
+        true
+    }
+
     pub fn dismiss_alarm(&mut self, alarm_uid: &str) -> bool {
         if let Some(alarm) = self.alarms.iter_mut().find(|a| a.uid == alarm_uid) {
             alarm.acknowledged = Some(Utc::now());
@@ -943,13 +1343,32 @@ impl Task {
     }
 
     pub fn snooze_alarm(&mut self, alarm_uid: &str, minutes: u32) -> bool {
+        let trigger_time = Utc::now() + chrono::Duration::minutes(minutes as i64);
+        self.snooze_alarm_to(alarm_uid, trigger_time, format!("Snoozed for {}m", minutes))
+    }
+
+    /// Snoozes an alarm to an absolute time (e.g. "9am tomorrow"). Builds the same
+    /// SNOOZE chain as `snooze_alarm`. Returns `false` if `until` is not in the future.
+    pub fn snooze_alarm_until(&mut self, alarm_uid: &str, until: DateTime<Utc>) -> bool {
+        if until <= Utc::now() {
+            return false;
+        }
+        let label = until.with_timezone(&Local).format("%Y-%m-%d %H:%M");
+        self.snooze_alarm_to(alarm_uid, until, format!("Snoozed until {}", label))
+    }
+
+    fn snooze_alarm_to(
+        &mut self,
+        alarm_uid: &str,
+        trigger_time: DateTime<Utc>,
+        description: String,
+    ) -> bool {
         let now = Utc::now();
         let mut new_alarm_opt = None;
 
         if let Some(parent_alarm) = self.alarms.iter_mut().find(|a| a.uid == alarm_uid) {
             parent_alarm.acknowledged = Some(now);
 
-            let trigger_time = now + chrono::Duration::minutes(minutes as i64);
             let mut snooze = Alarm::new_absolute(trigger_time);
 
             let root_uid = if parent_alarm.is_snooze() {
@@ -963,7 +1382,7 @@ impl Task {
 
             snooze.related_to_uid = Some(root_uid);
             snooze.relation_type = Some("SNOOZE".to_string());
-            snooze.description = Some(format!("Snoozed for {}m", minutes));
+            snooze.description = Some(description);
             snooze.action = parent_alarm.action.clone();
 
             new_alarm_opt = Some(snooze);
@@ -1024,6 +1443,34 @@ impl Task {
         earliest
     }
 
+    /// Attaches (or refreshes) the configured default lead-time reminder.
+    /// Only timed due dates qualify, and any user-specified alarm suppresses it,
+    /// so re-running this after an edit never double-adds. `None` removes it.
+    pub fn apply_default_reminder(&mut self, minutes_before: Option<u32>) {
+        let has_user_alarm = self.alarms.iter().any(|a| !a.is_default && !a.is_snooze());
+        let wanted = match minutes_before {
+            Some(mins) if !has_user_alarm && matches!(self.due, Some(DateType::Specific(_))) => {
+                Some(mins)
+            }
+            _ => None,
+        };
+
+        let current = self.alarms.iter().find(|a| a.is_default);
+        let up_to_date = match (current, wanted) {
+            (Some(a), Some(mins)) => a.trigger == AlarmTrigger::Relative(-(mins as i32)),
+            (None, None) => true,
+            _ => false,
+        };
+        if up_to_date {
+            return;
+        }
+
+        self.alarms.retain(|a| !a.is_default);
+        if let Some(mins) = wanted {
+            self.alarms.push(Alarm::new_default(mins));
+        }
+    }
+
     pub fn has_alarm_at(&self, dt: DateTime<Utc>) -> bool {
         self.alarms.iter().any(|a| match a.trigger {
             AlarmTrigger::Absolute(t) => t == dt,
@@ -1199,7 +1646,7 @@ impl Task {
             let mut updated = base_task.clone();
             updated.status = TaskStatus::NeedsAction;
             updated.percent_complete = None;
-            updated.unmapped_properties.retain(|p| p.key != "COMPLETED");
+            updated.set_completion_date(None);
             return (updated, None);
         }
 
@@ -1216,6 +1663,9 @@ impl Task {
             history.rrule = None; // History is a non-recurring snapshot
             history.alarms.clear(); // History does not ring
             history.create_event = None; // Do not attempt to create a calendar event for history
+            history
+                .unmapped_properties
+                .retain(|p| p.key != COMPLETION_COUNT_KEY && p.key != COMPLETION_LOG_KEY);
 
             // Mark explicitly as history to avoid heuristics
             history.unmapped_properties.push(RawProperty {
@@ -1227,13 +1677,7 @@ impl Task {
             history.related_to.push(base_task.uid.clone()); // Link history back to master
 
             // Stamp COMPLETED (or CANCELLED) date for the history item.
-            let now_str = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
-            history.unmapped_properties.retain(|p| p.key != "COMPLETED");
-            history.unmapped_properties.push(RawProperty {
-                key: "COMPLETED".to_string(),
-                value: now_str,
-                params: vec![],
-            });
+            history.set_completion_date(Some(Utc::now()));
 
             if target_status == TaskStatus::Completed {
                 history.percent_complete = Some(100);
@@ -1274,6 +1718,11 @@ impl Task {
                 next_task.exdates.dedup();
             }
 
+            // Keep the completion on the series itself (bounded log).
+            if target_status == TaskStatus::Completed {
+                next_task.record_completion(Utc::now());
+            }
+
             // Reset time-tracking for next occurrence.
             next_task.time_spent_seconds = 0;
             next_task.last_started_at = None;
@@ -1289,19 +1738,13 @@ impl Task {
         let mut updated = base_task.clone();
         updated.status = target_status;
         if target_status.is_done() {
-            let now_str = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
-            updated.unmapped_properties.retain(|p| p.key != "COMPLETED");
-            updated.unmapped_properties.push(RawProperty {
-                key: "COMPLETED".to_string(),
-                value: now_str,
-                params: vec![],
-            });
+            updated.set_completion_date(Some(Utc::now()));
             if target_status == TaskStatus::Completed {
                 updated.percent_complete = Some(100);
             }
         } else {
             updated.percent_complete = None;
-            updated.unmapped_properties.retain(|p| p.key != "COMPLETED");
+            updated.set_completion_date(None);
         }
 
         (updated, None)
@@ -1335,8 +1778,50 @@ impl Task {
     }
 
     /// Advance recurrence in-place (delegates to RecurrenceEngine).
+    /// When the series is exhausted (COUNT/UNTIL reached), the task is marked
+    /// Completed instead of advancing past the boundary, and `false` is returned.
     pub fn advance_recurrence(&mut self) -> bool {
-        crate::model::RecurrenceEngine::advance(self)
+        if crate::model::RecurrenceEngine::advance(self) {
+            return true;
+        }
+        let is_bounded = self
+            .rrule
+            .as_ref()
+            .is_some_and(|r| r.contains("COUNT=") || r.contains("UNTIL="));
+        if is_bounded && (self.dtstart.is_some() || self.due.is_some()) {
+            self.set_completion_date(Some(Utc::now()));
+            self.status = TaskStatus::Completed;
+            self.percent_complete = Some(100);
+        }
+        false
+    }
+
+    /// Skips the current occurrence of a recurring task in place: the schedule
+    /// (DTSTART/DUE, alarms, COUNT remainder) moves to the next instance and the
+    /// skipped seed date is recorded as an EXDATE, while status, progress, time
+    /// tracking and dependencies are left untouched. Returns `false` when the task
+    /// does not recur or the series is exhausted (COUNT/UNTIL reached); the task is
+    /// not modified in that case.
+    pub fn skip_occurrence(&mut self) -> bool {
+        if self.rrule.is_none() {
+            return false;
+        }
+        let mut probe = self.clone();
+        // Exclude the exact seed the engine uses so it cannot be yielded again.
+        let Some(seed) = probe.dtstart.as_ref().or(probe.due.as_ref()).cloned() else {
+            return false;
+        };
+        probe.exdates.push(seed);
+        let Some(next) = crate::model::RecurrenceEngine::next_occurrence(&probe) else {
+            return false;
+        };
+        self.dtstart = next.dtstart;
+        self.due = next.due;
+        self.rrule = next.rrule;
+        self.exdates = next.exdates;
+        self.alarms = next.alarms;
+        self.sequence += 1;
+        true
     }
 
     // Display-related helpers delegated to TaskDisplay trait implementation.
@@ -1356,6 +1841,11 @@ impl Task {
         crate::model::TaskDisplay::is_paused(self)
     }
 
+    /// Waiting pseudo-status: flagged `waiting` and otherwise still open and not started.
+    pub fn is_waiting(&self) -> bool {
+        self.waiting && self.status == TaskStatus::NeedsAction
+    }
+
     pub fn inherit_properties(
         &mut self,
         parent_categories: &[String],
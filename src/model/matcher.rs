@@ -16,7 +16,8 @@
 // (e.g. #tag, @date, is:done) and substring matching.
 
 use crate::model::item::{Task, TaskStatus};
-use chrono::NaiveDate;
+use chrono::{Datelike, Local, NaiveDate, Utc, Weekday};
+use std::collections::HashSet;
 
 #[derive(Debug, Clone)]
 enum SearchExpr {
@@ -28,6 +29,8 @@ enum SearchExpr {
 
 pub struct Query {
     expr: SearchExpr,
+    fuzzy: bool,
+    week_start: Weekday,
 }
 
 impl Query {
@@ -36,27 +39,64 @@ impl Query {
         let mut parser = Parser::new(tokens);
         Self {
             expr: parser.parse(),
+            fuzzy: false,
+            week_start: Weekday::Mon,
         }
     }
 
+    /// Plain text terms match summaries as subsequences (see `fuzzy_score`).
+    /// Tag, location, status and date tokens stay exact.
+    pub fn with_fuzzy(mut self, fuzzy: bool) -> Self {
+        self.fuzzy = fuzzy;
+        self
+    }
+
+    /// First day of the week for `is:week`, `completed:this-week` and date terms like `eow`.
+    pub fn with_week_start(mut self, week_start: Weekday) -> Self {
+        self.week_start = week_start;
+        self
+    }
+
     pub fn matches(&self, task: &Task, lex: &crate::model::parser::ParserLexicon) -> bool {
-        self.expr.matches(task, lex)
+        self.expr.matches(task, lex, self.fuzzy, self.week_start)
     }
 }
 
 impl SearchExpr {
-    fn matches(&self, task: &Task, lex: &crate::model::parser::ParserLexicon) -> bool {
+    fn matches(
+        &self,
+        task: &Task,
+        lex: &crate::model::parser::ParserLexicon,
+        fuzzy: bool,
+        week_start: Weekday,
+    ) -> bool {
         match self {
             SearchExpr::Term(s) => {
                 if s.is_empty() {
                     true
                 } else {
-                    task.matches_primitive(s, lex)
+                    task.matches_primitive(s, lex, fuzzy, week_start)
                 }
             }
-            SearchExpr::And(a, b) => a.matches(task, lex) && b.matches(task, lex),
-            SearchExpr::Or(a, b) => a.matches(task, lex) || b.matches(task, lex),
-            SearchExpr::Not(a) => !a.matches(task, lex),
+            SearchExpr::And(a, b) => {
+                a.matches(task, lex, fuzzy, week_start) && b.matches(task, lex, fuzzy, week_start)
+            }
+            SearchExpr::Or(a, b) => {
+                a.matches(task, lex, fuzzy, week_start) || b.matches(task, lex, fuzzy, week_start)
+            }
+            // Ready/blocked need store context; negating the placeholder `true` would
+            // hide everything, so the store applies those exclusions instead.
+            SearchExpr::Not(a) => match a.as_ref() {
+                SearchExpr::Term(s)
+                    if matches!(
+                        StatusFilter::from_term(&s.to_lowercase(), lex),
+                        Some(StatusFilter::Ready | StatusFilter::Blocked)
+                    ) =>
+                {
+                    true
+                }
+                _ => !a.matches(task, lex, fuzzy, week_start),
+            },
         }
     }
 }
@@ -170,6 +210,320 @@ fn tokenize_query(input: &str) -> Vec<Token> {
     tokens
 }
 
+/// Scores `needle` as a subsequence of `haystack` (both already lowercase), or
+/// `None` if some character is missing. Consecutive matches and matches at word
+/// starts score higher, so `bmlk` ranks "buy milk" above "book a mailing kit".
+pub fn fuzzy_score(haystack: &str, needle: &str) -> Option<u32> {
+    let mut score = 0;
+    let mut hay = haystack.chars();
+    let mut prev: Option<char> = None;
+    let mut prev_matched = false;
+    for n in needle.chars() {
+        loop {
+            let h = hay.next()?;
+            let at_word_start = prev.is_none_or(|p| !p.is_alphanumeric());
+            prev = Some(h);
+            if h == n {
+                score += 1;
+                if prev_matched {
+                    score += 3;
+                }
+                if at_word_start {
+                    score += 2;
+                }
+                prev_matched = true;
+                break;
+            }
+            prev_matched = false;
+        }
+    }
+    Some(score)
+}
+
+/// Plain text words of a search query (no tag, location, status, date or operator
+/// tokens), used to rank fuzzy results.
+pub fn plain_search_words(query: &str) -> Vec<String> {
+    query
+        .split_whitespace()
+        .filter(|w| {
+            !w.starts_with(['#', '@', '!', '~', '-', '^', '(', ')', '|', '"', '+'])
+                && !w.contains([':', '<', '>', '='])
+        })
+        .map(|w| w.to_lowercase())
+        .collect()
+}
+
+/// Byte ranges of `text` matching any of `words` (lowercase) case-insensitively,
+/// sorted and merged. Used to highlight why a task matched a search.
+pub fn match_ranges(text: &str, words: &[String]) -> Vec<std::ops::Range<usize>> {
+    let mut ranges: Vec<std::ops::Range<usize>> = Vec::new();
+    for word in words.iter().filter(|w| !w.is_empty()) {
+        for (start, _) in text.char_indices() {
+            let mut needle = word.chars();
+            let mut pending = needle.next();
+            let mut end = start;
+            for (i, c) in text[start..].char_indices() {
+                let mut lowered = c.to_lowercase();
+                let mut ok = true;
+                for lc in lowered.by_ref() {
+                    if pending == Some(lc) {
+                        pending = needle.next();
+                    } else {
+                        ok = false;
+                        break;
+                    }
+                }
+                if !ok {
+                    break;
+                }
+                end = start + i + c.len_utf8();
+                if pending.is_none() {
+                    break;
+                }
+            }
+            if pending.is_none() {
+                ranges.push(start..end);
+            }
+        }
+    }
+
+    ranges.sort_by_key(|r| r.start);
+    let mut merged: Vec<std::ops::Range<usize>> = Vec::new();
+    for r in ranges {
+        match merged.last_mut() {
+            Some(last) if r.start <= last.end => last.end = last.end.max(r.end),
+            _ => merged.push(r),
+        }
+    }
+    merged
+}
+
+/// Adds `token` to the front of `query`, or removes it if already present.
+/// Backs the one-key search toggles (quick filter, focus mode).
+pub fn toggle_search_token(query: &str, token: &str) -> String {
+    if query.contains(token) {
+        query.replace(token, "").trim().to_string()
+    } else if query.is_empty() {
+        token.to_string()
+    } else {
+        format!("{} {}", token, query)
+    }
+}
+
+/// Exact due-date search word (`@2025-03-14`), as set by the GUI due-date heat map.
+fn exact_due_date(word: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(word.strip_prefix('@')?, "%Y-%m-%d").ok()
+}
+
+/// The first exact due date in `query`, if any.
+pub fn due_date_in_query(query: &str) -> Option<NaiveDate> {
+    query.split_whitespace().find_map(exact_due_date)
+}
+
+/// `query` without exact due-date words (`@2025-03-14`).
+pub fn without_due_date_tokens(query: &str) -> String {
+    query
+        .split_whitespace()
+        .filter(|w| exact_due_date(w).is_none())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Narrows `query` to tasks due on `date`, replacing any other exact due date in it.
+/// Picking the date already in the query removes it instead.
+pub fn toggle_due_date_token(query: &str, date: NaiveDate) -> String {
+    let had = query
+        .split_whitespace()
+        .any(|w| exact_due_date(w) == Some(date));
+    let rest = without_due_date_tokens(query);
+    if had {
+        rest
+    } else if rest.is_empty() {
+        format!("@{}", date.format("%Y-%m-%d"))
+    } else {
+        format!("@{} {}", date.format("%Y-%m-%d"), rest)
+    }
+}
+
+/// Extracts the structured shortcuts `!=N` (exact priority) and `~none` (unset duration)
+/// from a plain AND query so they can be applied as `FilterOptions` before text matching.
+/// Queries using OR or grouping are left entirely to the expression matcher.
+pub fn extract_filter_shortcuts(query: &str) -> (Option<u8>, bool) {
+    let mut exact_priority = None;
+    let mut require_unset_duration = false;
+
+    let tokens = tokenize_query(query);
+    if tokens
+        .iter()
+        .any(|t| matches!(t, Token::Or | Token::LParen | Token::RParen))
+    {
+        return (None, false);
+    }
+
+    let mut negated = false;
+    for token in &tokens {
+        match token {
+            Token::NotPrefix => {
+                negated = true;
+                continue;
+            }
+            Token::Text(term) if !negated => {
+                let lower = term.to_lowercase();
+                if let Some(p) = lower.strip_prefix("!=").and_then(|v| v.parse::<u8>().ok()) {
+                    exact_priority = Some(p);
+                } else if lower == "~none" {
+                    require_unset_duration = true;
+                }
+            }
+            _ => {}
+        }
+        negated = false;
+    }
+
+    (exact_priority, require_unset_duration)
+}
+
+/// The calendar name of the first `cal:` token (`cal:Work`, `cal:"Shared Family"`), unquoted
+/// and with its case kept. The client resolves it to an href for `FilterOptions::active_cal_href`.
+/// Like the other shortcuts it only applies to plain AND queries and is not negatable.
+pub fn extract_calendar_filter(query: &str) -> Option<String> {
+    let tokens = tokenize_query(query);
+    if tokens
+        .iter()
+        .any(|t| matches!(t, Token::Or | Token::LParen | Token::RParen))
+    {
+        return None;
+    }
+
+    let mut negated = false;
+    for token in &tokens {
+        match token {
+            Token::NotPrefix => {
+                negated = true;
+                continue;
+            }
+            Token::Text(term)
+                if !negated
+                    && term
+                        .get(..4)
+                        .is_some_and(|p| p.eq_ignore_ascii_case("cal:")) =>
+            {
+                let name = term[4..].trim_matches('"').trim();
+                if !name.is_empty() {
+                    return Some(name.to_string());
+                }
+            }
+            _ => {}
+        }
+        negated = false;
+    }
+    None
+}
+
+/// Status keywords that can be subtracted from the visible set with a leading `-`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatusFilter {
+    Done,
+    Active,
+    Started,
+    Ready,
+    Blocked,
+}
+
+impl StatusFilter {
+    /// Classifies an `is:` keyword (including localized aliases) into a status filter.
+    pub fn from_term(term: &str, lex: &crate::model::parser::ParserLexicon) -> Option<Self> {
+        let has =
+            |list: &[String], canonical: &str| term == canonical || list.iter().any(|x| x == term);
+        if has(&lex.search_is_done, "is:done") {
+            Some(Self::Done)
+        } else if has(&lex.search_is_active, "is:active") {
+            Some(Self::Active)
+        } else if has(&lex.search_is_started, "is:started")
+            || has(&lex.search_is_ongoing, "is:ongoing")
+        {
+            Some(Self::Started)
+        } else if has(&lex.search_is_ready, "is:ready") {
+            Some(Self::Ready)
+        } else if has(&lex.search_is_blocked, "is:blocked") {
+            Some(Self::Blocked)
+        } else {
+            None
+        }
+    }
+}
+
+/// Negated tokens (`-#tag`, `-@@loc`, `-!1`, `-is:done`) lifted out of a plain AND query.
+/// `TaskStore::filter` subtracts these from the already-visible set, which matters for
+/// states the expression matcher cannot compute on its own (`-is:ready`, `-is:blocked`)
+/// and for hierarchical tag/location matching (`-#work` also hides `#work:meetings`).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FilterExclusions {
+    pub categories: HashSet<String>,
+    pub locations: HashSet<String>,
+    pub priorities: HashSet<u8>,
+    pub statuses: HashSet<StatusFilter>,
+}
+
+/// Strips the leading `-` of every negated term in a plain AND query and classifies the
+/// remainder into tag, location, priority, or status exclusions.
+/// Queries using OR or grouping are left entirely to the expression matcher.
+pub fn extract_filter_exclusions(query: &str) -> FilterExclusions {
+    let mut out = FilterExclusions::default();
+
+    let tokens = tokenize_query(query);
+    if tokens
+        .iter()
+        .any(|t| matches!(t, Token::Or | Token::LParen | Token::RParen))
+    {
+        return out;
+    }
+
+    let lex = crate::model::parser::LEXICON.read().unwrap();
+    let mut negated = false;
+    for token in &tokens {
+        match token {
+            Token::NotPrefix => {
+                negated = true;
+                continue;
+            }
+            Token::Text(term) if negated => {
+                let lower = term.to_lowercase();
+                let loc_prefix = lex
+                    .extract_prefix(term, &lower)
+                    .filter(|(p, _, _)| *p == crate::model::parser::PrefixToken::Loc)
+                    .map(|(_, r, _)| r.to_string());
+
+                if let Some(tag) = lower.strip_prefix('#') {
+                    if !tag.is_empty() {
+                        out.categories.insert(tag.to_string());
+                    }
+                } else if let Some(loc) = lower.strip_prefix("@@") {
+                    if !loc.is_empty() {
+                        out.locations.insert(loc.to_string());
+                    }
+                } else if let Some(loc) = loc_prefix {
+                    if !loc.is_empty() {
+                        out.locations.insert(loc);
+                    }
+                } else if let Some(p) = lower
+                    .strip_prefix("!=")
+                    .or_else(|| lower.strip_prefix('!'))
+                    .and_then(|v| v.parse::<u8>().ok())
+                {
+                    out.priorities.insert(p);
+                } else if let Some(status) = StatusFilter::from_term(&lower, &lex) {
+                    out.statuses.insert(status);
+                }
+            }
+            _ => {}
+        }
+        negated = false;
+    }
+
+    out
+}
+
 struct Parser {
     tokens: Vec<Token>,
     pos: usize,
@@ -270,7 +624,13 @@ impl Task {
 
     /// Evaluates a single primitive search term (e.g., "#tag", "is:done", or "text").
     /// Returns true if the task matches this specific term.
-    fn matches_primitive(&self, part: &str, lex: &crate::model::parser::ParserLexicon) -> bool {
+    fn matches_primitive(
+        &self,
+        part: &str,
+        lex: &crate::model::parser::ParserLexicon,
+        fuzzy: bool,
+        week_start: Weekday,
+    ) -> bool {
         if part.is_empty() {
             return true;
         }
@@ -312,6 +672,11 @@ impl Task {
                 rem
             };
 
+            // `~none` selects tasks without any estimated duration
+            if content == "none" {
+                return self.estimated_duration.is_none();
+            }
+
             let (op, val_str) = if let Some(stripped) = content.strip_prefix("<=") {
                 ("<=", stripped)
             } else if let Some(stripped) = content.strip_prefix(">=") {
@@ -358,6 +723,8 @@ impl Task {
                 ("<", stripped)
             } else if let Some(stripped) = part_lower.strip_prefix("!>") {
                 (">", stripped)
+            } else if let Some(stripped) = part_lower.strip_prefix("!=") {
+                ("=", stripped)
             } else if let Some(stripped) = part_lower.strip_prefix('!') {
                 ("=", stripped)
             } else {
@@ -412,8 +779,9 @@ impl Task {
                 ("=", val_str_full)
             };
 
-            let target_date = crate::model::parser::parse_smart_date_with_lex(date_str, lex)
-                .map(|d| d.to_date_naive());
+            let target_date =
+                crate::model::parser::parse_smart_date_with_lex(date_str, lex, week_start)
+                    .map(|d| d.to_date_naive());
 
             if let Some(target) = target_date {
                 match task_date {
@@ -466,6 +834,10 @@ impl Task {
         if part_lower == "is:done" || lex.search_is_done.contains(&part_lower) {
             return self.status.is_done();
         }
+        // The completion-age half of `is:archived` is applied by `TaskStore::filter`.
+        if part_lower == "is:archived" || lex.search_is_archived.contains(&part_lower) {
+            return self.status.is_done();
+        }
         if part_lower == "is:started"
             || part_lower == "is:ongoing"
             || lex.search_is_started.contains(&part_lower)
@@ -486,6 +858,18 @@ impl Task {
             // (Note: full filtering support for these requires Context from store)
             return true;
         }
+        // Focus mode needs the urgency thresholds and ancestry; applied by `TaskStore::filter`.
+        if lex.exact.get(&part_lower) == Some(&crate::model::parser::ExactToken::IsFocus) {
+            return true;
+        }
+        // Inbox is applied by `TaskStore::filter` together with the live inbox count.
+        if part_lower == "is:inbox" {
+            return true;
+        }
+        // Calendar names are resolved by the client (see `extract_calendar_filter`).
+        if part_lower.starts_with("cal:") {
+            return true;
+        }
 
         if part_lower == "is:note" || lex.search_is_note.contains(&part_lower) {
             return self.is_note;
@@ -493,13 +877,66 @@ impl Task {
         if part_lower == "is:permanent" || lex.search_is_permanent.contains(&part_lower) {
             return self.permanent;
         }
+        // Deferred: starts in the future. Ancestor deferral is applied by `TaskStore::filter`.
+        if part_lower == "is:deferred" || lex.search_is_deferred.contains(&part_lower) {
+            return !self.status.is_done()
+                && self
+                    .dtstart
+                    .as_ref()
+                    .is_some_and(|d| d.to_start_comparison_time() > Utc::now());
+        }
+        // --- Relative Date Windows ---
+        if part_lower == "is:overdue" || lex.search_is_overdue.contains(&part_lower) {
+            return !self.status.is_done()
+                && self
+                    .due
+                    .as_ref()
+                    .is_some_and(|d| d.to_comparison_time() < Utc::now());
+        }
+        if part_lower == "is:today" || lex.search_is_today.contains(&part_lower) {
+            let today = Local::now().date_naive();
+            return self
+                .due
+                .as_ref()
+                .is_some_and(|d| d.to_date_naive() == today);
+        }
+        if part_lower == "is:week" || lex.search_is_week.contains(&part_lower) {
+            let today = Local::now().date_naive();
+            let end = crate::model::parser::end_of_week(today, week_start);
+            return self.due.as_ref().is_some_and(|d| {
+                let day = d.to_date_naive();
+                day >= today && day <= end
+            });
+        }
+        // Completion windows read the COMPLETED stamp in local time
+        if let Some(window) = part_lower.strip_prefix("completed:") {
+            let today = Local::now().date_naive();
+            let start = match window {
+                "today" => today,
+                "this-week" => crate::model::parser::start_of_week(today, week_start),
+                "this-month" => today.with_day(1).unwrap_or(today),
+                _ => return false,
+            };
+            return self.completion_date().is_some_and(|d| {
+                let day = d.with_timezone(&Local).date_naive();
+                day >= start && day <= today
+            });
+        }
         if lex.exact.get(&part_lower) == Some(&crate::model::parser::ExactToken::IsPinned) {
             return self.pinned;
         }
+        if lex.exact.get(&part_lower) == Some(&crate::model::parser::ExactToken::IsWaiting) {
+            return self.is_waiting();
+        }
 
         // --- Fallback: Text Search ---
         // Matches summary, description, categories, or location.
-        let summary_match = self.summary.to_lowercase().contains(&part_lower);
+        let summary_lower = self.summary.to_lowercase();
+        let summary_match = if fuzzy {
+            fuzzy_score(&summary_lower, &part_lower).is_some()
+        } else {
+            summary_lower.contains(&part_lower)
+        };
         let desc_match = self.description.to_lowercase().contains(&part_lower);
         let cat_match = self
             .categories
@@ -547,6 +984,24 @@ mod tests {
         assert!(!t.matches_search_term("(work | urgent) -today"));
     }
 
+    #[test]
+    fn test_match_ranges_for_highlighting() {
+        let words = super::plain_search_words("milk #groceries is:active @@store BU");
+        assert_eq!(words, vec!["milk".to_string(), "bu".to_string()]);
+
+        let text = "Buy MILK and butter";
+        let ranges = super::match_ranges(text, &words);
+        let hits: Vec<&str> = ranges.iter().map(|r| &text[r.clone()]).collect();
+        assert_eq!(hits, vec!["Bu", "MILK", "bu"]);
+
+        // Overlapping hits merge; multi-byte text keeps valid boundaries
+        let words = vec!["caf".to_string(), "afé".to_string()];
+        let text = "Le Café";
+        let ranges = super::match_ranges(text, &words);
+        assert_eq!(ranges.len(), 1);
+        assert_eq!(&text[ranges[0].clone()], "Café");
+    }
+
     #[test]
     fn test_quotes_and_term() {
         let aliases: HashMap<String, Vec<String>> = HashMap::new();
@@ -1,5 +1,6 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 // File: ./src/model/merge.rs
+use crate::config::ConflictStrategy;
 use crate::model::Task;
 use std::collections::HashSet;
 
@@ -56,9 +57,11 @@ pub fn three_way_merge(base: &Task, local: &Task, server: &Task) -> Option<Task>
         rrule: _,
         location: _,
         url: _,
+        attachments: _,
         geo: _,
         collapsed: _,
         pinned: _,
+        waiting: _,
         is_note: _,
         manual_block: _,
         permanent: _,
@@ -67,7 +70,9 @@ pub fn three_way_merge(base: &Task, local: &Task, server: &Task) -> Option<Task>
         sessions: _,
         unmapped_properties: _,
         sequence: _,
-        raw_alarms: _,
+        created: _,
+        completed: _,
+        raw_subcomponents: _,
         raw_components: _,
         create_event: _,
         goal: _,
@@ -122,12 +127,15 @@ pub fn three_way_merge(base: &Task, local: &Task, server: &Task) -> Option<Task>
     merge_field!(create_event);
     merge_field!(collapsed);
     merge_field!(pinned);
+    merge_field!(waiting);
     merge_field!(is_note);
     merge_field!(manual_block);
     merge_field!(permanent);
     merge_field!(goal);
     merge_field!(last_started_at);
     merge_field!(parent_uid);
+    merge_field!(created);
+    merge_field!(completed);
 
     // List properties (Set-based 3-way merge to handle deletions correctly)
     merged.categories = merge_lists(&base.categories, &local.categories, &server.categories);
@@ -139,6 +147,7 @@ pub fn three_way_merge(base: &Task, local: &Task, server: &Task) -> Option<Task>
         &server.dependencies,
     );
     merged.related_to = merge_lists(&base.related_to, &local.related_to, &server.related_to);
+    merged.attachments = merge_lists(&base.attachments, &local.attachments, &server.attachments);
     merged.exdates = merge_lists(&base.exdates, &local.exdates, &server.exdates);
 
     merged.sessions = merge_lists(&base.sessions, &local.sessions, &server.sessions);
@@ -310,6 +319,47 @@ pub fn three_way_merge(base: &Task, local: &Task, server: &Task) -> Option<Task>
     Some(merged)
 }
 
+/// Outcome of `resolve_conflict`.
+#[derive(Debug, Clone)]
+pub enum ConflictResolution {
+    /// Both edits combined; push this version.
+    Merged(Task),
+    /// Overwrite the server with the local version (carries the server ETag).
+    KeepLocal(Task),
+    /// Drop the local edit and adopt the server version.
+    KeepServer,
+    /// Keep both by uploading the local version as a "(Conflict Copy)".
+    Copy,
+}
+
+/// Resolves an edit conflict according to `strategy`. Every strategy except
+/// `CreateCopy` tries `three_way_merge` first (which needs the cached `base`);
+/// the strategy decides what happens when that fails.
+pub fn resolve_conflict(
+    base: Option<&Task>,
+    local: &Task,
+    server: &Task,
+    strategy: ConflictStrategy,
+) -> ConflictResolution {
+    if strategy != ConflictStrategy::CreateCopy
+        && let Some(base) = base
+        && let Some(merged) = three_way_merge(base, local, server)
+    {
+        return ConflictResolution::Merged(merged);
+    }
+
+    match strategy {
+        ConflictStrategy::PreferLocal => {
+            let mut kept = local.clone();
+            kept.etag = server.etag.clone();
+            kept.href = server.href.clone();
+            ConflictResolution::KeepLocal(kept)
+        }
+        ConflictStrategy::PreferServer => ConflictResolution::KeepServer,
+        ConflictStrategy::Merge | ConflictStrategy::CreateCopy => ConflictResolution::Copy,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -341,4 +391,70 @@ mod tests {
             "Failed to keep local's location change"
         );
     }
+
+    #[test]
+    fn test_three_way_merge_independent_location_url_geo_edits() {
+        let mut base = Task::new("Base Task", &HashMap::new(), None);
+        base.location = Some("Office".to_string());
+        base.url = Some("https://example.com/old".to_string());
+        base.geo = None;
+
+        // Local changed the location and added coordinates
+        let mut local = base.clone();
+        local.location = Some("Home".to_string());
+        local.geo = Some("48.85,2.35".to_string());
+
+        // Server changed the URL
+        let mut server = base.clone();
+        server.url = Some("https://example.com/new".to_string());
+
+        let merged = three_way_merge(&base, &local, &server).expect("Should merge successfully");
+
+        assert_eq!(merged.location.as_deref(), Some("Home"));
+        assert_eq!(merged.url.as_deref(), Some("https://example.com/new"));
+        assert_eq!(merged.geo.as_deref(), Some("48.85,2.35"));
+
+        // Divergent edits to the same field are still a hard conflict
+        server.location = Some("Gym".to_string());
+        assert!(three_way_merge(&base, &local, &server).is_none());
+    }
+
+    #[test]
+    fn test_resolve_conflict_strategies() {
+        let base = Task::new("Base", &HashMap::new(), None);
+        let mut local = base.clone();
+        local.summary = "Local".to_string();
+        let mut server = base.clone();
+        server.summary = "Server".to_string();
+        server.etag = "\"v2\"".to_string();
+
+        // Same field edited on both sides: only the fallback differs
+        assert!(matches!(
+            resolve_conflict(Some(&base), &local, &server, ConflictStrategy::Merge),
+            ConflictResolution::Copy
+        ));
+        assert!(matches!(
+            resolve_conflict(Some(&base), &local, &server, ConflictStrategy::PreferServer),
+            ConflictResolution::KeepServer
+        ));
+        match resolve_conflict(Some(&base), &local, &server, ConflictStrategy::PreferLocal) {
+            ConflictResolution::KeepLocal(kept) => {
+                assert_eq!(kept.summary, "Local");
+                assert_eq!(kept.etag, "\"v2\"");
+            }
+            other => panic!("expected KeepLocal, got {:?}", other),
+        }
+
+        // Mergeable edits still merge, except under CreateCopy
+        let mut local = base.clone();
+        local.location = Some("Home".to_string());
+        assert!(matches!(
+            resolve_conflict(Some(&base), &local, &server, ConflictStrategy::PreferServer),
+            ConflictResolution::Merged(_)
+        ));
+        assert!(matches!(
+            resolve_conflict(Some(&base), &local, &server, ConflictStrategy::CreateCopy),
+            ConflictResolution::Copy
+        ));
+    }
 }
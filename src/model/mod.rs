@@ -21,11 +21,11 @@ pub use parser::{
 };
 
 // Re-export extractor for markdown task extraction
-pub use extractor::{ExtractedTask, extract_markdown_tasks};
+pub use extractor::{ExtractedTask, OutlineLine, extract_markdown_tasks, parse_input_outline};
 
 // Re-export adapter/display/recurrence helpers for external use.
 pub use adapter::IcsAdapter;
-pub use display::TaskDisplay;
+pub use display::{ParsePreview, TaskDisplay};
 pub use recurrence::RecurrenceEngine;
 
 // Re-export session model for UI state management
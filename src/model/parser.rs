@@ -9,7 +9,7 @@ date-only form with an optional separate time token following it.
 */
 
 use crate::model::{Alarm, DateType, Task};
-use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, NaiveTime, Utc};
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, NaiveTime, Utc, Weekday};
 use std::collections::{HashMap, HashSet};
 use std::sync::RwLock;
 
@@ -50,6 +50,10 @@ pub enum ExactToken {
     Today,
     Tomorrow,
     Yesterday,
+    EndOfDay,
+    EndOfWeek,
+    EndOfMonth,
+    EndOfYear,
     Now,
     Next,
     In,
@@ -59,10 +63,19 @@ pub enum ExactToken {
     Except,
     Unit(LexiconUnit),
     Weekday(&'static str),
+    /// Monday through Friday (`@weekdays`).
+    Weekdays,
     Month(u32),
     Number(u32),
+    /// Position within a month (`first` = 1 … `fourth` = 4, `last` = -1).
+    Ordinal(i8),
+    /// `other`, as in `every other week`.
+    Other,
     IsNote,
     IsPinned,
+    IsWaiting,
+    /// `is:focus`, a search-only filter applied by `TaskStore::filter`.
+    IsFocus,
     IsBlocked,
     IsPermanent,
 }
@@ -104,9 +117,16 @@ pub struct ParserLexicon {
     pub search_is_ongoing: Vec<String>,
     pub search_is_ready: Vec<String>,
     pub search_is_blocked: Vec<String>,
+    pub search_is_archived: Vec<String>,
+    pub search_is_deferred: Vec<String>,
     pub search_is_note: Vec<String>,
     pub search_is_permanent: Vec<String>,
+    pub search_is_overdue: Vec<String>,
+    pub search_is_today: Vec<String>,
+    pub search_is_week: Vec<String>,
     pub parser_collection: Vec<String>,
+    pub business_days: Vec<String>,
+    pub business_word: Vec<String>,
 }
 
 impl ParserLexicon {
@@ -249,6 +269,10 @@ impl ParserLexicon {
         add_exact("parser_tomorrow", "tomorrow,tmr", ExactToken::Tomorrow);
         add_exact("parser_yesterday", "yesterday,yst", ExactToken::Yesterday);
         add_exact("parser_now", "now", ExactToken::Now);
+        add_exact("parser_eod", "eod", ExactToken::EndOfDay);
+        add_exact("parser_eow", "eow", ExactToken::EndOfWeek);
+        add_exact("parser_eom", "eom", ExactToken::EndOfMonth);
+        add_exact("parser_eoy", "eoy", ExactToken::EndOfYear);
         add_exact("parser_next", "next", ExactToken::Next);
         add_exact("parser_in", "in", ExactToken::In);
         add_exact("parser_every", "every", ExactToken::Every);
@@ -322,6 +346,11 @@ impl ParserLexicon {
             "su,sun,sunday,sundays",
             ExactToken::Weekday("SU"),
         );
+        add_exact(
+            "parser_weekdays_shortcut",
+            "weekdays,weekday",
+            ExactToken::Weekdays,
+        );
 
         add_exact("parser_months_jan", "jan,january", ExactToken::Month(1));
         add_exact("parser_months_feb", "feb,february", ExactToken::Month(2));
@@ -342,6 +371,8 @@ impl ParserLexicon {
 
         add_exact("parser_is_note", "is:note", ExactToken::IsNote);
         add_exact("parser_is_pinned", "is:pinned", ExactToken::IsPinned);
+        add_exact("parser_is_waiting", "is:waiting", ExactToken::IsWaiting);
+        add_exact("parser_is_focus", "is:focus", ExactToken::IsFocus);
         add_exact(
             "parser_is_permanent",
             "is:permanent",
@@ -351,6 +382,9 @@ impl ParserLexicon {
         // Map the existing search translation to the exact token so they match perfectly
         add_exact("search_is_blocked", "is:blocked", ExactToken::IsBlocked);
 
+        add_exact("parser_last", "last", ExactToken::Ordinal(-1));
+        add_exact("parser_other", "other", ExactToken::Other);
+
         let nums_en = "one,two,three,four,five,six,seven,eight,nine,ten,eleven,twelve";
         let nums_loc = rust_i18n::t!("parser_numbers_1_to_12");
         for (i, w) in nums_en.split(',').enumerate() {
@@ -366,6 +400,17 @@ impl ParserLexicon {
             }
         }
 
+        let ords_en = "first,second,third,fourth";
+        let ords_loc = rust_i18n::t!("parser_ordinals_1_to_4");
+        for (i, w) in ords_en.split(',').enumerate() {
+            exact_en.insert(w.trim().to_lowercase(), ExactToken::Ordinal((i + 1) as i8));
+        }
+        if ords_loc != "parser_ordinals_1_to_4" && !ords_loc.is_empty() {
+            for (i, w) in ords_loc.split(',').enumerate().take(4) {
+                exact_loc.insert(w.trim().to_lowercase(), ExactToken::Ordinal((i + 1) as i8));
+            }
+        }
+
         add_prefix("parser_due", "@,due:", PrefixToken::Due);
         add_prefix("parser_start", "^,start:", PrefixToken::Start);
         add_prefix("parser_start_due", "^@", PrefixToken::StartDue);
@@ -430,9 +475,16 @@ impl ParserLexicon {
             search_is_ongoing: get_all("search_is_ongoing", "is:ongoing"),
             search_is_ready: get_all("search_is_ready", "is:ready"),
             search_is_blocked: get_all("search_is_blocked", "is:blocked"),
+            search_is_archived: get_all("search_is_archived", "is:archived"),
+            search_is_deferred: get_all("search_is_deferred", "is:deferred"),
             search_is_note: get_all("search_is_note", "is:note"),
             search_is_permanent: get_all("parser_is_permanent", "is:permanent"),
+            search_is_overdue: get_all("search_is_overdue", "is:overdue"),
+            search_is_today: get_all("search_is_today", "is:today"),
+            search_is_week: get_all("search_is_week", "is:week"),
             parser_collection: get_all("parser_collection", "col:"),
+            business_days: get_all("parser_business_days", "bd,bday,bdays"),
+            business_word: get_all("parser_business", "business,working,work"),
         }
     }
 }
@@ -811,7 +863,36 @@ pub fn is_valid_geo(val: &str) -> bool {
                 .all(|c| c.is_ascii_digit() || " .-°NSEWnsew".contains(c))
     };
 
-    valid_part(parts[0]) && valid_part(parts[1])
+    valid_part(parts[0])
+        && valid_part(parts[1])
+        && parse_geo_coordinates(&normalize_geo(s.to_string())).is_some()
+}
+
+/// Parses a normalized `lat,lon` pair, rejecting non-numeric parts and
+/// coordinates outside [-90, 90] / [-180, 180]. `here` has no coordinates.
+pub fn parse_geo_coordinates(val: &str) -> Option<(f64, f64)> {
+    let (lat_str, lon_str) = val.split_once(',')?;
+    let lat: f64 = lat_str.trim().parse().ok()?;
+    let lon: f64 = lon_str.trim().parse().ok()?;
+    if !lat.is_finite() || !lon.is_finite() {
+        return None;
+    }
+    if !(-90.0..=90.0).contains(&lat) || !(-180.0..=180.0).contains(&lon) {
+        return None;
+    }
+    Some((lat, lon))
+}
+
+/// Builds the link opened for a task's `geo:` value. `{lat}` and `{lon}` in `template`
+/// are substituted; an empty template, or a value without coordinates, yields a plain
+/// `geo:` URI.
+pub fn geo_to_map_url(geo: &str, template: &str) -> String {
+    match parse_geo_coordinates(geo) {
+        Some((lat, lon)) if !template.trim().is_empty() => template
+            .replace("{lat}", &lat.to_string())
+            .replace("{lon}", &lon.to_string()),
+        _ => format!("geo:{}", geo),
+    }
 }
 
 pub fn validate_alias_integrity(
@@ -890,7 +971,86 @@ pub fn validate_alias_integrity(
     Ok(())
 }
 
-fn parse_time_string(s: &str) -> Option<NaiveTime> {
+/// Runs `validate_alias_integrity` over every alias in `aliases`, returning
+/// `(key, problem)` pairs sorted by key. Used to catch cycles introduced by
+/// editing `config.toml` by hand or by a synced settings payload.
+pub fn validate_all_aliases(aliases: &HashMap<String, Vec<String>>) -> Vec<(String, String)> {
+    let mut problems: Vec<(String, String)> = aliases
+        .iter()
+        .filter_map(|(key, values)| {
+            validate_alias_integrity(key, values, aliases)
+                .err()
+                .map(|err| (key.clone(), err))
+        })
+        .collect();
+    problems.sort();
+    problems
+}
+
+/// `tag` with its `from` prefix replaced by `to`: `from` itself and its subtags
+/// (`from:child`) are renamed, anything else yields `None`.
+pub fn rename_tag_path(tag: &str, from: &str, to: &str) -> Option<String> {
+    if tag == from {
+        Some(to.to_string())
+    } else {
+        tag.strip_prefix(from)
+            .and_then(|rest| rest.strip_prefix(':'))
+            .map(|rest| format!("{}:{}", to, rest))
+    }
+}
+
+/// Renames tag `from` (and its subtags) to `to` in alias keys and in the `#tag` values
+/// aliases expand to, using the same `:` hierarchy as alias expansion. A renamed key that
+/// collides with an existing alias is merged into it, and a value that would make an
+/// alias expand to itself is dropped. Returns how many aliases changed.
+pub fn rename_tag_in_aliases(
+    aliases: &mut HashMap<String, Vec<String>>,
+    from: &str,
+    to: &str,
+) -> usize {
+    let mut changed: HashSet<String> = HashSet::new();
+
+    let renamed_keys: Vec<(String, String)> = aliases
+        .keys()
+        .filter_map(|k| rename_tag_path(k, from, to).map(|new_k| (k.clone(), new_k)))
+        .collect();
+    for (old_k, new_k) in renamed_keys {
+        if let Some(values) = aliases.remove(&old_k) {
+            aliases.entry(new_k.clone()).or_default().extend(values);
+            changed.insert(new_k);
+        }
+    }
+
+    for (key, values) in aliases.iter_mut() {
+        let before = values.clone();
+        let mut seen = HashSet::new();
+        let mut rewritten = Vec::with_capacity(values.len());
+        for val in values.drain(..) {
+            let val = match val.strip_prefix('#') {
+                Some(tag) => {
+                    let clean = strip_quotes(tag);
+                    match rename_tag_path(&clean, from, to) {
+                        Some(new_tag) if new_tag == *key => continue,
+                        Some(new_tag) => format!("#{}", quote_value(&new_tag)),
+                        None => val,
+                    }
+                }
+                None => val,
+            };
+            if seen.insert(val.clone()) {
+                rewritten.push(val);
+            }
+        }
+        *values = rewritten;
+        if *values != before {
+            changed.insert(key.clone());
+        }
+    }
+
+    changed.len()
+}
+
+pub(crate) fn parse_time_string(s: &str) -> Option<NaiveTime> {
     let lower = s.to_lowercase();
 
     // Helper for 12h
@@ -926,6 +1086,15 @@ fn parse_time_string(s: &str) -> Option<NaiveTime> {
         return NaiveTime::from_hms_opt(h, m, 0);
     }
 
+    // Compact 24h form without a colon (HHMM or HMM), e.g. 0930, 1830, 900.
+    // Bare 1-2 digit numbers are never treated as times.
+    if (lower.len() == 3 || lower.len() == 4) && lower.chars().all(|c| c.is_ascii_digit()) {
+        let (h_str, m_str) = lower.split_at(lower.len() - 2);
+        let h = h_str.parse::<u32>().ok()?;
+        let m = m_str.parse::<u32>().ok()?;
+        return NaiveTime::from_hms_opt(h, m, 0);
+    }
+
     None
 }
 
@@ -947,6 +1116,7 @@ pub fn tokenize_smart_input(input: &str, is_search_query: bool) -> Vec<SyntaxTok
     let mut cursor = 0;
     let mut i = 0;
     let mut has_recurrence = false;
+    let mut bare_url_seen = false;
 
     let lex_guard = LEXICON.read().unwrap();
     let lex = &*lex_guard;
@@ -971,7 +1141,12 @@ pub fn tokenize_smart_input(input: &str, is_search_query: bool) -> Vec<SyntaxTok
         let rem_original = extracted.map(|(_, _, r)| r).unwrap_or(word.as_str());
         let pref = extracted.map(|(p, _, _)| p);
 
-        let exact = lex.exact.get(rem);
+        let (rem_base, rec_count) = split_recurrence_count(rem);
+        let exact = lex.exact.get(rem).or_else(|| {
+            rec_count
+                .and_then(|_| lex.exact.get(rem_base))
+                .filter(|e| matches!(e, ExactToken::Unit(_) | ExactToken::Weekdays))
+        });
 
         if is_search_query {
             if word == "|"
@@ -984,6 +1159,8 @@ pub fn tokenize_smart_input(input: &str, is_search_query: bool) -> Vec<SyntaxTok
                 matched_kind = Some(SyntaxType::Operator);
             } else if word.starts_with('-') && word.len() > 1
                 || word_lower.starts_with("is:")
+                || word_lower.starts_with("!=")
+                || word_lower == "~none"
                 || lex.search_prefix.iter().any(|p| word_lower.starts_with(p))
                 || ((word.starts_with('!')
                     || word.starts_with('~')
@@ -1003,9 +1180,9 @@ pub fn tokenize_smart_input(input: &str, is_search_query: bool) -> Vec<SyntaxTok
         let is_after = pref == Some(PrefixToken::Due) && exact == Some(&ExactToken::After);
 
         if matched_kind.is_none() && (is_every || is_after) && i + 1 < words.len() {
-            let next_token_str = words[i + 1].2.as_str();
+            let (next_token_str, _) = split_recurrence_count(words[i + 1].2.as_str());
             let next_next = if i + 2 < words.len() {
-                Some(words[i + 2].2.as_str())
+                Some(split_recurrence_count(words[i + 2].2.as_str()).0)
             } else {
                 None
             };
@@ -1015,19 +1192,23 @@ pub fn tokenize_smart_input(input: &str, is_search_query: bool) -> Vec<SyntaxTok
             {
                 matched_kind = Some(SyntaxType::Recurrence);
                 words_consumed = 1 + 1 + consumed;
+            } else if next_next
+                .is_some_and(|nn| parse_every_phrase_with_lex(next_token_str, nn, lex).is_some())
+            {
+                matched_kind = Some(SyntaxType::Recurrence);
+                words_consumed = 3;
             } else {
-                let parts: Vec<&str> = next_token_str.split(',').map(|s| s.trim()).collect();
-                let all_weekdays = parts
-                    .iter()
-                    .all(|part| parse_weekday_code_with_lex(part, lex).is_some());
-
-                if all_weekdays && !parts.is_empty() {
+                if parse_weekday_list_with_lex(next_token_str, lex).is_some() {
                     matched_kind = Some(SyntaxType::Recurrence);
                     words_consumed = 2;
                 }
             }
         } else if matched_kind.is_none()
-            && ((is_due_or_recur && matches!(exact, Some(&ExactToken::Unit(_))))
+            && ((is_due_or_recur
+                && matches!(
+                    exact,
+                    Some(&ExactToken::Unit(_)) | Some(&ExactToken::Weekdays)
+                ))
                 || pref == Some(PrefixToken::Recur))
         {
             matched_kind = Some(SyntaxType::Recurrence);
@@ -1054,7 +1235,7 @@ pub fn tokenize_smart_input(input: &str, is_search_query: bool) -> Vec<SyntaxTok
 
             let is_list = if next_token_str.contains(',') {
                 true
-            } else if parse_smart_date_with_lex(next_token_str, lex).is_some()
+            } else if is_smart_date_with_lex(next_token_str, lex)
                 || parse_next_date_with_lex(next_token_str, lex).is_some()
             {
                 if i + 2 < words.len() && is_time_format(&words[i + 2].2) {
@@ -1110,9 +1291,20 @@ pub fn tokenize_smart_input(input: &str, is_search_query: bool) -> Vec<SyntaxTok
                     } else {
                         None
                     };
-
-                    if let Some((_, _, consumed)) =
-                        parse_amount_and_unit_with_lex(next_token_str, next_next, false, lex)
+                    let third = words.get(i + 3).map(|w| w.2.as_str());
+
+                    if let Some(consumed) =
+                        parse_business_days_with_lex(next_token_str, next_next, third, lex)
+                            .map(|(_, c)| c)
+                            .or_else(|| {
+                                parse_amount_and_unit_with_lex(
+                                    next_token_str,
+                                    next_next,
+                                    false,
+                                    lex,
+                                )
+                                .map(|(_, _, c)| c)
+                            })
                     {
                         matched_kind = Some(if is_start {
                             SyntaxType::StartDate
@@ -1127,7 +1319,7 @@ pub fn tokenize_smart_input(input: &str, is_search_query: bool) -> Vec<SyntaxTok
                         }
                     }
                 } else {
-                    if parse_smart_date_with_lex(clean_word, lex).is_some()
+                    if is_smart_date_with_lex(clean_word, lex)
                         || parse_next_date_with_lex(clean_word, lex).is_some()
                         || is_time_format(clean_word)
                     {
@@ -1220,7 +1412,7 @@ pub fn tokenize_smart_input(input: &str, is_search_query: bool) -> Vec<SyntaxTok
                     }
                 }
             } else if !clean_val.is_empty()
-                && (parse_smart_date_with_lex(clean_val, lex).is_some()
+                && (is_smart_date_with_lex(clean_val, lex)
                     || parse_next_date_with_lex(clean_val, lex).is_some())
             {
                 if let Some(next_idx) = find_next_token(i + words_consumed)
@@ -1232,7 +1424,7 @@ pub fn tokenize_smart_input(input: &str, is_search_query: bool) -> Vec<SyntaxTok
                 && let Some(next_idx) = find_next_token(i + words_consumed)
             {
                 let next_word = &words[next_idx].2;
-                if parse_smart_date_with_lex(next_word, lex).is_some()
+                if is_smart_date_with_lex(next_word, lex)
                     || parse_next_date_with_lex(next_word, lex).is_some()
                 {
                     words_consumed = next_idx - i + 1;
@@ -1261,10 +1453,15 @@ pub fn tokenize_smart_input(input: &str, is_search_query: bool) -> Vec<SyntaxTok
                 } else {
                     matched_kind = Some(SyntaxType::WikiLink);
                 }
+            } else if !is_search_query && !bare_url_seen && is_bare_http_url(word) {
+                bare_url_seen = true;
+                matched_kind = Some(SyntaxType::Url);
             } else if word_lower == "+cal" || word_lower == "-cal" {
                 matched_kind = Some(SyntaxType::Calendar);
             } else if exact == Some(&ExactToken::IsPinned) {
                 matched_kind = Some(SyntaxType::Pin);
+            } else if exact == Some(&ExactToken::IsWaiting) || exact == Some(&ExactToken::IsFocus) {
+                matched_kind = Some(SyntaxType::Filter);
             } else if exact == Some(&ExactToken::IsNote) {
                 matched_kind = Some(SyntaxType::Note);
             } else if exact == Some(&ExactToken::IsBlocked) {
@@ -1681,6 +1878,36 @@ fn collect_alias_expansions(
     results
 }
 
+/// Resolves the alias expansions the smart input would inject for `input`,
+/// without parsing the rest of the task. Used to preview aliases while typing.
+/// Tokens already present in the input are left out; order follows first appearance.
+pub fn preview_alias_expansions(
+    input: &str,
+    aliases: &HashMap<String, Vec<String>>,
+) -> Vec<String> {
+    if aliases.is_empty() {
+        return Vec::new();
+    }
+
+    let user_tokens: Vec<String> = split_input_respecting_quotes(input)
+        .into_iter()
+        .map(|(_, _, s)| s)
+        .collect();
+
+    let mut visited = HashSet::new();
+    let mut seen: HashSet<String> = user_tokens.iter().cloned().collect();
+    let mut results = Vec::new();
+
+    for token in &user_tokens {
+        for expanded in collect_alias_expansions(token, aliases, &mut visited, 0) {
+            if seen.insert(expanded.clone()) {
+                results.push(expanded);
+            }
+        }
+    }
+    results
+}
+
 pub fn parse_amount_and_unit(
     first: &str,
     second: Option<&str>,
@@ -1717,6 +1944,39 @@ pub fn parse_amount_and_unit_with_lex(
     None
 }
 
+/// Recognises a business-day amount after `in`: `3bd`, `3 bdays`, `three business days`.
+/// Returns the amount and how many words after `first` were consumed.
+pub fn parse_business_days_with_lex(
+    first: &str,
+    second: Option<&str>,
+    third: Option<&str>,
+    lex: &ParserLexicon,
+) -> Option<(u32, usize)> {
+    let is_bd = |w: &str| lex.business_days.contains(&w.to_lowercase());
+
+    let lower = first.to_lowercase();
+    if let Some(idx) = lower.find(|c: char| !c.is_ascii_digit())
+        && idx > 0
+        && is_bd(&lower[idx..])
+        && let Ok(amt) = lower[..idx].parse::<u32>()
+    {
+        return Some((amt, 0));
+    }
+
+    let amt = parse_english_number_with_lex(first, lex)?;
+    let second = second?;
+    if is_bd(second) {
+        return Some((amt, 1));
+    }
+    let is_days = third.is_some_and(|t| {
+        lex.exact.get(&t.to_lowercase()) == Some(&ExactToken::Unit(LexiconUnit::Days))
+    });
+    if lex.business_word.contains(&second.to_lowercase()) && is_days {
+        return Some((amt, 2));
+    }
+    None
+}
+
 fn parse_english_number_with_lex(s: &str, lex: &ParserLexicon) -> Option<u32> {
     if let Ok(n) = s.parse::<u32>() {
         return Some(n);
@@ -1724,7 +1984,58 @@ fn parse_english_number_with_lex(s: &str, lex: &ParserLexicon) -> Option<u32> {
     if let Some(ExactToken::Number(n)) = lex.exact.get(&s.to_lowercase()) {
         return Some(*n);
     }
-    None
+    // Ordinals count too, so `every second week` means every 2 weeks.
+    match parse_ordinal_with_lex(s, lex) {
+        Some(n) if n > 0 => Some(n as u32),
+        _ => None,
+    }
+}
+
+/// Month positions: `first`…`fourth`, `1st`…`4th`, and `last` (-1).
+fn parse_ordinal_with_lex(s: &str, lex: &ParserLexicon) -> Option<i8> {
+    let lower = s.to_lowercase();
+    if let Some(ExactToken::Ordinal(n)) = lex.exact.get(&lower) {
+        return Some(*n);
+    }
+    let digits = ["st", "nd", "rd", "th"]
+        .iter()
+        .find_map(|suffix| lower.strip_suffix(suffix))?;
+    match digits.parse::<i8>() {
+        Ok(n) if (1..=4).contains(&n) => Some(n),
+        _ => None,
+    }
+}
+
+/// Phrases after `@every` that are not an amount and unit:
+/// `other <unit>` (`INTERVAL=2`), `<ordinal> <weekday>` (`FREQ=MONTHLY;BYDAY=1MO`,
+/// `last friday` gives `BYDAY=-1FR`) and `<ordinal> weekday`
+/// (`BYDAY=MO,TU,WE,TH,FR;BYSETPOS=n`). Both words are consumed; the returned
+/// RRULE has no `COUNT`.
+pub fn parse_every_phrase_with_lex(
+    first: &str,
+    second: &str,
+    lex: &ParserLexicon,
+) -> Option<String> {
+    let second_lower = second.to_lowercase();
+    if lex.exact.get(&first.to_lowercase()) == Some(&ExactToken::Other) {
+        if let Some(ExactToken::Unit(u)) = lex.exact.get(&second_lower) {
+            let freq = u.to_freq();
+            if !freq.is_empty() {
+                return Some(format!("FREQ={};INTERVAL=2", freq));
+            }
+        }
+        return None;
+    }
+    let pos = parse_ordinal_with_lex(first, lex)?;
+    match lex.exact.get(&second_lower) {
+        Some(ExactToken::Weekday(code)) => Some(format!("FREQ=MONTHLY;BYDAY={}{}", pos, code)),
+        Some(ExactToken::Weekdays) => Some(format!(
+            "FREQ=MONTHLY;BYDAY={};BYSETPOS={}",
+            WORKWEEK_CODES.join(","),
+            pos
+        )),
+        _ => None,
+    }
 }
 
 fn parse_freq_from_unit(u: &str) -> &'static str {
@@ -1775,10 +2086,14 @@ pub fn prettify_recurrence(rrule: &str, is_relative: bool) -> String {
     let mut byday = "";
     let mut bymonth = "";
     let mut until = "";
+    let mut count = "";
+    let mut bysetpos = "";
 
     // Parse RRULE components
     for part in rrule.split(';') {
-        if let Some(v) = part.strip_prefix("FREQ=") {
+        if let Some(v) = part.strip_prefix("BYSETPOS=") {
+            bysetpos = v;
+        } else if let Some(v) = part.strip_prefix("FREQ=") {
             freq = v;
         } else if let Some(v) = part.strip_prefix("INTERVAL=") {
             interval = v;
@@ -1788,22 +2103,27 @@ pub fn prettify_recurrence(rrule: &str, is_relative: bool) -> String {
             bymonth = v;
         } else if let Some(v) = part.strip_prefix("UNTIL=") {
             until = v;
+        } else if let Some(v) = part.strip_prefix("COUNT=") {
+            count = v;
         }
     }
 
-    // Format UNTIL if present
+    // Format COUNT (as a `*N` suffix) and UNTIL if present
     let mut until_str = String::new();
+    if !count.is_empty() {
+        until_str = format!("*{}", count);
+    }
     if !until.is_empty() {
         let date_part = until.split('T').next().unwrap_or(until);
         if date_part.len() >= 8 {
-            until_str = format!(
+            until_str.push_str(&format!(
                 " until {}-{}-{}",
                 &date_part[0..4],
                 &date_part[4..6],
                 &date_part[6..8]
-            );
+            ));
         } else {
-            until_str = format!(" until {}", until);
+            until_str.push_str(&format!(" until {}", until));
         }
     }
 
@@ -1836,7 +2156,17 @@ pub fn prettify_recurrence(rrule: &str, is_relative: bool) -> String {
             }
         }
 
-        // C. Majority Days (4+) -> @daily except ...
+        // C. Monday to Friday -> @weekdays
+        if days.len() == 5 && bymonth.is_empty() && WORKWEEK_CODES.iter().all(|c| days.contains(c))
+        {
+            return if is_relative {
+                format!("@after weekdays{}", until_str)
+            } else {
+                format!("@weekdays{}", until_str)
+            };
+        }
+
+        // D. Majority Days (4+) -> @daily except ...
         // If we have >= 4 days, listing exclusions is cleaner than listing inclusions
         if days.len() >= 4 {
             let missing_days: Vec<String> = all_codes
@@ -1878,6 +2208,43 @@ pub fn prettify_recurrence(rrule: &str, is_relative: bool) -> String {
         }
     }
 
+    // 1b. Monthly positions (@every last friday, @every first weekday)
+    let is_positional =
+        !bysetpos.is_empty() || byday.starts_with(|c: char| c == '-' || c.is_ascii_digit());
+    if freq == "MONTHLY" && !byday.is_empty() && is_positional {
+        let ordinal_word = |pos: &str| match pos {
+            "1" | "+1" => "first",
+            "2" | "+2" => "second",
+            "3" | "+3" => "third",
+            "4" | "+4" => "fourth",
+            "-1" => "last",
+            _ => "",
+        };
+        let phrase = if bysetpos.is_empty() {
+            let split = byday
+                .find(|c: char| c.is_ascii_alphabetic())
+                .unwrap_or(byday.len());
+            let (pos, code) = byday.split_at(split);
+            let (ord, day) = (ordinal_word(pos), code_to_full_day(code));
+            (!ord.is_empty() && !day.is_empty()).then(|| format!("{} {}", ord, day))
+        } else {
+            let is_workweek = byday.split(',').count() == WORKWEEK_CODES.len()
+                && WORKWEEK_CODES
+                    .iter()
+                    .all(|c| byday.split(',').any(|d| d == *c));
+            let ord = ordinal_word(bysetpos);
+            (is_workweek && !ord.is_empty()).then(|| format!("{} weekday", ord))
+        };
+        return match phrase {
+            Some(p) if (interval.is_empty() || interval == "1") && bymonth.is_empty() => {
+                let prefix = if is_relative { "@after" } else { "@every" };
+                format!("{} {}{}", prefix, p, until_str)
+            }
+            // Anything else would lose the position if shown as @monthly
+            _ => format!("rec:{}", rrule),
+        };
+    }
+
     // 2. Handle Month Logic (Exclusions only)
     // Show except format whenever BYMONTH is present (more user-friendly than raw RRULE)
     // Works for DAILY, WEEKLY, MONTHLY, and YEARLY frequencies
@@ -2113,6 +2480,48 @@ pub fn format_duration_compact(mins: u32) -> String {
     }
 }
 
+/// Formats a running session as a stopwatch: `MM:SS`, or `H:MM:SS` past an hour.
+pub fn format_elapsed_clock(seconds: u64) -> String {
+    let (h, m, s) = (seconds / 3600, (seconds % 3600) / 60, seconds % 60);
+    if h > 0 {
+        format!("{}:{:02}:{:02}", h, m, s)
+    } else {
+        format!("{:02}:{:02}", m, s)
+    }
+}
+
+/// Formats an estimate as `~X`, or `~X-Y` when the range is open.
+pub fn format_estimate_range(min: u32, max: u32) -> String {
+    if max > min {
+        format!(
+            "~{}-{}",
+            format_duration_compact(min),
+            format_duration_compact(max)
+        )
+    } else {
+        format!("~{}", format_duration_compact(min))
+    }
+}
+
+/// Splits a trailing occurrence count off a recurrence word (`daily*5` -> `daily`, 5).
+pub fn split_recurrence_count(word: &str) -> (&str, Option<u32>) {
+    if let Some((base, n)) = word.rsplit_once('*')
+        && !base.is_empty()
+        && let Ok(count) = n.parse::<u32>()
+        && count > 0
+    {
+        return (base, Some(count));
+    }
+    (word, None)
+}
+
+fn with_recurrence_count(rrule: String, count: Option<u32>) -> String {
+    match count {
+        Some(n) if !rrule.contains("COUNT=") => format!("{};COUNT={}", rrule, n),
+        _ => rrule,
+    }
+}
+
 fn parse_recurrence(val: &str) -> Option<String> {
     let upper = val.to_uppercase();
     match upper.as_str() {
@@ -2130,12 +2539,58 @@ fn parse_recurrence(val: &str) -> Option<String> {
     }
 }
 
-pub fn parse_smart_date(val: &str) -> Option<DateType> {
+/// `parse_smart_date_with_lex` with the global lexicon.
+pub fn parse_smart_date(val: &str, week_start: Weekday) -> Option<DateType> {
     let lex_guard = LEXICON.read().unwrap();
-    parse_smart_date_with_lex(val, &lex_guard)
+    parse_smart_date_with_lex(val, &lex_guard, week_start)
+}
+
+/// Whether `val` reads as a smart date. The week start only moves where `eow` lands,
+/// so it plays no part here.
+pub fn is_smart_date_with_lex(val: &str, lex: &ParserLexicon) -> bool {
+    parse_smart_date_with_lex(val, lex, Weekday::Mon).is_some()
 }
 
-pub fn parse_smart_date_with_lex(val: &str, lex: &ParserLexicon) -> Option<DateType> {
+/// Parses a "snooze until" target: `9am`, `14:30`, `tomorrow 9am`, `2025-06-01 8:00`
+/// or just a date (which uses `default_time`). A bare time that already passed today
+/// means tomorrow.
+pub fn parse_snooze_until(
+    val: &str,
+    default_time: NaiveTime,
+    week_start: Weekday,
+) -> Option<DateTime<Utc>> {
+    let words: Vec<&str> = val.split_whitespace().collect();
+    let (last, rest) = words.split_last()?;
+    let (date_part, time) = match parse_time_string(last) {
+        Some(t) => (rest, Some(t)),
+        None => (&words[..], None),
+    };
+    if date_part.is_empty() {
+        let time = time?;
+        let now = Local::now();
+        let mut date = now.date_naive();
+        if time <= now.time() {
+            date += Duration::days(1);
+        }
+        return Some(crate::model::item::safe_local_to_utc(date, time));
+    }
+    let date = match parse_smart_date(&date_part.join(" "), week_start)? {
+        DateType::Specific(dt) if time.is_none() => return Some(dt),
+        other => other.to_date_naive(),
+    };
+    Some(crate::model::item::safe_local_to_utc(
+        date,
+        time.unwrap_or(default_time),
+    ))
+}
+
+/// Parses a smart date (`2025-06-01`, `tomorrow`, `eow`, `3d`, ...). `eow` ends the week
+/// that begins on `week_start` (`Config::week_start`).
+pub fn parse_smart_date_with_lex(
+    val: &str,
+    lex: &ParserLexicon,
+    week_start: Weekday,
+) -> Option<DateType> {
     if let Ok(date) = NaiveDate::parse_from_str(val, "%Y-%m-%d") {
         return Some(DateType::AllDay(date));
     }
@@ -2174,6 +2629,14 @@ pub fn parse_smart_date_with_lex(val: &str, lex: &ParserLexicon) -> Option<DateT
         Some(ExactToken::Today) => return Some(DateType::AllDay(now)),
         Some(ExactToken::Tomorrow) => return Some(DateType::AllDay(now + Duration::days(1))),
         Some(ExactToken::Yesterday) => return Some(DateType::AllDay(now - Duration::days(1))),
+        Some(ExactToken::EndOfDay) => return Some(DateType::AllDay(now)),
+        Some(ExactToken::EndOfWeek) => {
+            return Some(DateType::AllDay(end_of_week(now, week_start)));
+        }
+        Some(ExactToken::EndOfMonth) => return Some(DateType::AllDay(end_of_month(now))),
+        Some(ExactToken::EndOfYear) => {
+            return NaiveDate::from_ymd_opt(now.year(), 12, 31).map(DateType::AllDay);
+        }
         _ => {}
     }
 
@@ -2189,11 +2652,8 @@ pub fn parse_smart_date_with_lex(val: &str, lex: &ParserLexicon) -> Option<DateT
             Some(ExactToken::Unit(LexiconUnit::Weeks)) => {
                 return Some(DateType::AllDay(now + Duration::days(n * 7)));
             }
-            Some(ExactToken::Unit(LexiconUnit::Months)) => {
-                return Some(DateType::AllDay(now + Duration::days(n * 30)));
-            }
-            Some(ExactToken::Unit(LexiconUnit::Years)) => {
-                return Some(DateType::AllDay(now + Duration::days(n * 365)));
+            Some(ExactToken::Unit(u @ (LexiconUnit::Months | LexiconUnit::Years))) => {
+                return add_calendar_offset(now, n as u32, *u).map(DateType::AllDay);
             }
             _ => {}
         }
@@ -2201,6 +2661,54 @@ pub fn parse_smart_date_with_lex(val: &str, lex: &ParserLexicon) -> Option<DateT
     None
 }
 
+/// Largest business-day offset `add_business_days` walks (about 400 years).
+const MAX_BUSINESS_DAYS: u32 = 100_000;
+
+/// Moves `base` forward by `amount` working days, skipping weekends and `holidays`
+/// (`Config::holidays`). Returns `None` past `MAX_BUSINESS_DAYS` or the end of the calendar.
+pub fn add_business_days(
+    base: NaiveDate,
+    amount: u32,
+    holidays: &[NaiveDate],
+) -> Option<NaiveDate> {
+    if amount > MAX_BUSINESS_DAYS {
+        return None;
+    }
+    let mut d = base;
+    let mut left = amount;
+    while left > 0 {
+        d = d.succ_opt()?;
+        if !matches!(d.weekday(), Weekday::Sat | Weekday::Sun) && !holidays.contains(&d) {
+            left -= 1;
+        }
+    }
+    Some(d)
+}
+
+/// First day of the week containing `date`, for weeks beginning on `week_start`
+/// (`Config::week_start`).
+pub fn start_of_week(date: NaiveDate, week_start: Weekday) -> NaiveDate {
+    date.week(week_start).first_day()
+}
+
+/// Last day of the week containing `date`, for weeks beginning on `week_start`
+/// (`Config::week_start`).
+pub fn end_of_week(date: NaiveDate, week_start: Weekday) -> NaiveDate {
+    date.week(week_start).last_day()
+}
+
+/// Last calendar day of the month containing `date`.
+pub fn end_of_month(date: NaiveDate) -> NaiveDate {
+    let (y, m) = if date.month() == 12 {
+        (date.year() + 1, 1)
+    } else {
+        (date.year(), date.month() + 1)
+    };
+    NaiveDate::from_ymd_opt(y, m, 1)
+        .and_then(|d| d.pred_opt())
+        .unwrap_or(date)
+}
+
 pub fn parse_weekday_code(s: &str) -> Option<&'static str> {
     let lex_guard = LEXICON.read().unwrap();
     parse_weekday_code_with_lex(s, &lex_guard)
@@ -2214,6 +2722,82 @@ pub fn parse_weekday_code_with_lex(s: &str, lex: &ParserLexicon) -> Option<&'sta
     }
 }
 
+const WORKWEEK_CODES: [&str; 5] = ["MO", "TU", "WE", "TH", "FR"];
+
+/// Parses the weekday list of an `@every` rule into BYDAY codes.
+/// Accepts comma lists (`mon,wed,fri`), the `weekdays` shortcut (Monday to Friday),
+/// and compact single-letter runs (`mwf`, `tth`, `mtwrf`; `r` = Thursday, `u` = Sunday).
+pub fn parse_weekday_list_with_lex(s: &str, lex: &ParserLexicon) -> Option<Vec<&'static str>> {
+    if s.is_empty() {
+        return None;
+    }
+
+    let mut codes: Vec<&'static str> = Vec::new();
+    let mut push = |code: &'static str| {
+        if !codes.contains(&code) {
+            codes.push(code);
+        }
+    };
+
+    let mut all_parsed = true;
+    for part in s.split(',').map(|p| p.trim()) {
+        match lex.exact.get(&part.to_lowercase()) {
+            Some(ExactToken::Weekday(c)) => push(c),
+            Some(ExactToken::Weekdays) => WORKWEEK_CODES.iter().for_each(|c| push(c)),
+            _ => {
+                all_parsed = false;
+                break;
+            }
+        }
+    }
+    if all_parsed {
+        return Some(codes);
+    }
+
+    if s.contains(',') {
+        return None;
+    }
+    parse_compact_weekdays(&s.to_lowercase())
+}
+
+/// Compact weekday runs like `mwf`. Two-letter `th`/`sa`/`su` win over their single letters.
+fn parse_compact_weekdays(s: &str) -> Option<Vec<&'static str>> {
+    if s.len() < 2 || !s.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    let mut codes = Vec::new();
+    let mut rest = s;
+    while !rest.is_empty() {
+        let (code, len) = if rest.starts_with("th") {
+            ("TH", 2)
+        } else if rest.starts_with("sa") {
+            ("SA", 2)
+        } else if rest.starts_with("su") {
+            ("SU", 2)
+        } else {
+            let code = match rest.as_bytes()[0] {
+                b'm' => "MO",
+                b't' => "TU",
+                b'w' => "WE",
+                b'r' => "TH",
+                b'f' => "FR",
+                b's' => "SA",
+                b'u' => "SU",
+                _ => return None,
+            };
+            (code, 1)
+        };
+        if codes.contains(&code) {
+            return None;
+        }
+        codes.push(code);
+        rest = &rest[len..];
+    }
+
+    if codes.len() < 2 { None } else { Some(codes) }
+}
+
 pub fn parse_month_code(s: &str) -> Option<u32> {
     let lex_guard = LEXICON.read().unwrap();
     parse_month_code_with_lex(s, &lex_guard)
@@ -2249,8 +2833,7 @@ pub fn parse_next_date_with_lex(unit: &str, lex: &ParserLexicon) -> Option<Naive
         match u {
             LexiconUnit::Days => return Some(now + Duration::days(1)),
             LexiconUnit::Weeks => return Some(now + Duration::days(7)),
-            LexiconUnit::Months => return Some(now + Duration::days(30)),
-            LexiconUnit::Years => return Some(now + Duration::days(365)),
+            LexiconUnit::Months | LexiconUnit::Years => return add_calendar_offset(now, 1, *u),
             _ => {}
         }
     }
@@ -2317,26 +2900,45 @@ pub fn parse_day_of_month(val: &str, lex: &ParserLexicon) -> Option<NaiveDate> {
     None
 }
 
+/// `parse_in_date_with_lex` with the global lexicon and no holidays.
 pub fn parse_in_date(amount: u32, unit: &str) -> Option<NaiveDate> {
     let lex_guard = LEXICON.read().unwrap();
-    parse_in_date_with_lex(amount, unit, &lex_guard)
+    parse_in_date_with_lex(amount, unit, &lex_guard, &[])
 }
 
-pub fn parse_in_date_with_lex(amount: u32, unit: &str, lex: &ParserLexicon) -> Option<NaiveDate> {
+/// Canonical unit string understood by `parse_in_date` for business days.
+pub const BUSINESS_DAYS_UNIT: &str = "bd";
+
+pub fn parse_in_date_with_lex(
+    amount: u32,
+    unit: &str,
+    lex: &ParserLexicon,
+    holidays: &[NaiveDate],
+) -> Option<NaiveDate> {
     let now = Local::now().date_naive();
+    let unit_lower = unit.to_lowercase();
+    if unit_lower == BUSINESS_DAYS_UNIT || lex.business_days.contains(&unit_lower) {
+        return add_business_days(now, amount, holidays);
+    }
     if let Some(ExactToken::Unit(u)) = lex.exact.get(&unit.to_lowercase()) {
-        let days = match u {
-            LexiconUnit::Days => amount as i64,
-            LexiconUnit::Weeks => amount as i64 * 7,
-            LexiconUnit::Months => amount as i64 * 30,
-            LexiconUnit::Years => amount as i64 * 365,
-            _ => return None,
-        };
-        return Some(now + Duration::days(days));
+        return add_calendar_offset(now, amount, *u);
     }
     None
 }
 
+/// Moves `base` forward by `amount` calendar units. Months and years use real calendar
+/// arithmetic, clamping the day to the end of shorter months (Jan 31 + 1mo = Feb 28/29,
+/// Feb 29 + 1y = Feb 28). Sub-day units are not date offsets and return `None`.
+pub fn add_calendar_offset(base: NaiveDate, amount: u32, unit: LexiconUnit) -> Option<NaiveDate> {
+    match unit {
+        LexiconUnit::Days => base.checked_add_signed(Duration::days(amount as i64)),
+        LexiconUnit::Weeks => base.checked_add_signed(Duration::days(amount as i64 * 7)),
+        LexiconUnit::Months => base.checked_add_months(chrono::Months::new(amount)),
+        LexiconUnit::Years => base.checked_add_months(chrono::Months::new(amount.checked_mul(12)?)),
+        _ => None,
+    }
+}
+
 fn next_weekday(from: NaiveDate, target: chrono::Weekday) -> Option<NaiveDate> {
     let mut d = from + Duration::days(1);
     while d.weekday() != target {
@@ -2345,11 +2947,76 @@ fn next_weekday(from: NaiveDate, target: chrono::Weekday) -> Option<NaiveDate> {
     Some(d)
 }
 
+/// The date a monthly positional rule selects in `year`/`month`: either
+/// ordinal BYDAY entries (`1MO`, `-1FR`) or plain days picked by `BYSETPOS`.
+fn monthly_position_date(year: i32, month: u32, byday: &str, bysetpos: &str) -> Option<NaiveDate> {
+    let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+    let days_in_month: Vec<NaiveDate> = first
+        .iter_days()
+        .take_while(|d| d.month() == month)
+        .collect();
+    let pick = |candidates: Vec<NaiveDate>, pos: i32| -> Option<NaiveDate> {
+        match pos {
+            p if p > 0 => candidates.get(p as usize - 1).copied(),
+            p if p < 0 => candidates
+                .len()
+                .checked_sub(p.unsigned_abs() as usize)
+                .and_then(|i| candidates.get(i).copied()),
+            _ => None,
+        }
+    };
+
+    let mut matches = Vec::new();
+    let mut plain_codes = Vec::new();
+    for entry in byday.split(',') {
+        let split = entry
+            .find(|c: char| c.is_ascii_alphabetic())
+            .unwrap_or(entry.len());
+        let (pos, code) = entry.split_at(split);
+        let Some(weekday) = weekday_from_code(code) else {
+            continue;
+        };
+        if pos.is_empty() {
+            plain_codes.push(weekday);
+        } else if let Ok(n) = pos.parse::<i32>() {
+            let candidates: Vec<NaiveDate> = days_in_month
+                .iter()
+                .copied()
+                .filter(|d| d.weekday() == weekday)
+                .collect();
+            matches.extend(pick(candidates, n));
+        }
+    }
+    if let Ok(n) = bysetpos.parse::<i32>() {
+        let candidates: Vec<NaiveDate> = days_in_month
+            .iter()
+            .copied()
+            .filter(|d| plain_codes.contains(&d.weekday()))
+            .collect();
+        matches.extend(pick(candidates, n));
+    }
+    matches.into_iter().min()
+}
+
+fn weekday_from_code(code: &str) -> Option<chrono::Weekday> {
+    match code {
+        "MO" => Some(chrono::Weekday::Mon),
+        "TU" => Some(chrono::Weekday::Tue),
+        "WE" => Some(chrono::Weekday::Wed),
+        "TH" => Some(chrono::Weekday::Thu),
+        "FR" => Some(chrono::Weekday::Fri),
+        "SA" => Some(chrono::Weekday::Sat),
+        "SU" => Some(chrono::Weekday::Sun),
+        _ => None,
+    }
+}
+
 fn calculate_first_occurrence(rrule: &str, today: NaiveDate) -> NaiveDate {
     // Parse RRULE to determine first occurrence date
     let mut freq = "";
     let mut byday = "";
     let mut bymonth = "";
+    let mut bysetpos = "";
 
     for part in rrule.split(';') {
         if let Some(v) = part.strip_prefix("FREQ=") {
@@ -2358,6 +3025,26 @@ fn calculate_first_occurrence(rrule: &str, today: NaiveDate) -> NaiveDate {
             byday = v;
         } else if let Some(v) = part.strip_prefix("BYMONTH=") {
             bymonth = v;
+        } else if let Some(v) = part.strip_prefix("BYSETPOS=") {
+            bysetpos = v;
+        }
+    }
+
+    // For MONTHLY positions (last friday, first weekday), take this month's date
+    // unless it already passed, else the next month that has one.
+    if freq == "MONTHLY" && !byday.is_empty() {
+        let mut month_start = today.with_day(1).unwrap_or(today);
+        for _ in 0..12 {
+            if let Some(date) =
+                monthly_position_date(month_start.year(), month_start.month(), byday, bysetpos)
+                && date >= today
+            {
+                return date;
+            }
+            match month_start.checked_add_months(chrono::Months::new(1)) {
+                Some(next) => month_start = next,
+                None => break,
+            }
         }
     }
 
@@ -2552,11 +3239,28 @@ pub fn is_special_token(word: &str) -> bool {
     is_special_token_with_lex(word, &lex_guard)
 }
 
+/// True for a pasted absolute link (`http://...` / `https://...`) with a non-empty host.
+/// The first one in a smart input is taken as the task URL.
+pub fn is_bare_http_url(word: &str) -> bool {
+    let lower = word.to_lowercase();
+    let rest = lower
+        .strip_prefix("https://")
+        .or_else(|| lower.strip_prefix("http://"));
+    rest.is_some_and(|r| {
+        r.split(['/', '?', '#'])
+            .next()
+            .is_some_and(|host| !host.is_empty() && !host.starts_with(':'))
+    })
+}
+
 pub fn is_special_token_with_lex(word: &str, lex: &ParserLexicon) -> bool {
     let lower = word.to_lowercase();
     if word.starts_with('#') || word.starts_with('!') || lower == "+cal" || lower == "-cal" {
         return true;
     }
+    if is_bare_http_url(word) {
+        return true;
+    }
     if lex.extract_prefix(word, &lower).is_some() {
         return true;
     }
@@ -2568,6 +3272,7 @@ pub fn is_special_token_with_lex(word: &str, lex: &ParserLexicon) -> bool {
         exact,
         Some(ExactToken::IsNote)
             | Some(ExactToken::IsPinned)
+            | Some(ExactToken::IsWaiting)
             | Some(ExactToken::IsBlocked)
             | Some(ExactToken::IsPermanent)
     ) {
@@ -2576,11 +3281,82 @@ pub fn is_special_token_with_lex(word: &str, lex: &ParserLexicon) -> bool {
     false
 }
 
+/// The first link becomes the task `url`; later distinct ones go to `attachments`.
+fn add_task_link(task: &mut Task, link: String) {
+    match &task.url {
+        None => task.url = Some(link),
+        Some(primary) if *primary == link => {}
+        Some(_) => {
+            if !task.attachments.contains(&link) {
+                task.attachments.push(link);
+            }
+        }
+    }
+}
+
+/// `apply_smart_input` that also reports the tokens it could not interpret (see
+/// `unrecognized_directives`), so callers can warn "rem:foo was not recognized".
+pub fn apply_smart_input_with_diagnostics(
+    task: &mut Task,
+    input: &str,
+    aliases: &HashMap<String, Vec<String>>,
+    default_reminder_time: Option<NaiveTime>,
+    holidays: &[NaiveDate],
+    week_start: Weekday,
+) -> Vec<String> {
+    apply_smart_input(
+        task,
+        input,
+        aliases,
+        default_reminder_time,
+        holidays,
+        week_start,
+    );
+    unrecognized_directives(input, &task.summary)
+}
+
+/// Tokens of `input` that look like directives (`@`, `^`, `~`, `rem:`, `due:`, `!N`...) but
+/// were kept in `summary` as plain text, typically a typo in a date or duration.
+/// Escaped tokens (`\@home`) are deliberate text and are not reported.
+pub fn unrecognized_directives(input: &str, summary: &str) -> Vec<String> {
+    let mut summary_words: Vec<&str> = summary.split_whitespace().collect();
+    let lex_guard = LEXICON.read().unwrap();
+    let lex = &*lex_guard;
+
+    let mut result = Vec::new();
+    for (_, _, token) in split_input_respecting_quotes(input) {
+        if token.starts_with('\\') {
+            continue;
+        }
+        let lower = token.to_lowercase();
+        let directive_like = match lex.extract_prefix(&token, &lower) {
+            Some((_, rest, _)) => !rest.is_empty(),
+            None => {
+                let mut chars = token.chars();
+                chars.next() == Some('!') && chars.next().is_some_and(|c| c.is_ascii_digit())
+            }
+        };
+        if !directive_like {
+            continue;
+        }
+        let word = unescape(&token);
+        if let Some(pos) = summary_words.iter().position(|w| *w == word) {
+            summary_words.remove(pos);
+            result.push(token);
+        }
+    }
+    result
+}
+
+/// Parses `input` into `task`. `holidays` (`Config::holidays`) are skipped by `@in N bd`,
+/// and `eow` ends the week that begins on `week_start` (`Config::week_start`).
 pub fn apply_smart_input(
     task: &mut Task,
     input: &str,
     aliases: &HashMap<String, Vec<String>>,
     default_reminder_time: Option<NaiveTime>,
+    holidays: &[NaiveDate],
+    week_start: Weekday,
 ) {
     let mut summary_words = Vec::new();
     // Reset fields
@@ -2594,11 +3370,13 @@ pub fn apply_smart_input(
     task.estimated_duration_max = None;
     task.location = None;
     task.url = None;
+    task.attachments.clear();
     task.geo = None;
     task.create_event = None;
     task.goal = None;
     task.is_note = false;
     task.pinned = false; // Reset pinned state so deleting the token unpins
+    task.waiting = false;
     task.permanent = false;
     task.categories.clear();
     task.alarms.clear();
@@ -2642,6 +3420,17 @@ pub fn apply_smart_input(
     let lex_guard = LEXICON.read().unwrap();
     let lex = &*lex_guard;
 
+    // A pasted link only becomes the task URL when no explicit `url:` / `[[...]]` is given.
+    let mut bare_url_available = !stream.iter().any(|t| {
+        let lower = t.to_lowercase();
+        matches!(
+            lex.extract_prefix(t, &lower),
+            Some((PrefixToken::Url, _, _))
+        ) || (t.starts_with("[[")
+            && t.ends_with("]]")
+            && (t.contains("://") || t.contains("mailto:")))
+    });
+
     let mut i = 0;
     while i < stream.len() {
         let is_bg = i < bg_len;
@@ -2653,7 +3442,12 @@ pub fn apply_smart_input(
         let rem = extracted.map(|(_, r, _)| r).unwrap_or(token_lower.as_str());
         let rem_original = extracted.map(|(_, _, r)| r).unwrap_or(token.as_str());
         let pref = extracted.map(|(p, _, _)| p);
-        let exact = lex.exact.get(rem);
+        let (rem_base, rec_count) = split_recurrence_count(rem);
+        let exact = lex.exact.get(rem).or_else(|| {
+            rec_count
+                .and_then(|_| lex.exact.get(rem_base))
+                .filter(|e| matches!(e, ExactToken::Unit(_) | ExactToken::Weekdays))
+        });
 
         let is_due_or_recur = pref == Some(PrefixToken::Due) || pref == Some(PrefixToken::Recur);
 
@@ -2663,18 +3457,27 @@ pub fn apply_smart_input(
 
         if is_every || is_after {
             if i + 1 < stream.len() {
-                let next_token_str = stream[i + 1].as_str();
-                let next_next = if i + 2 < stream.len() {
-                    Some(stream[i + 2].as_str())
+                let (next_token_str, next_count) = split_recurrence_count(stream[i + 1].as_str());
+                let (next_next, next_next_count) = if i + 2 < stream.len() {
+                    let (base, count) = split_recurrence_count(stream[i + 2].as_str());
+                    (Some(base), count)
                 } else {
-                    None
+                    (None, None)
                 };
                 if let Some((interval, unit, extra_consumed)) =
                     parse_amount_and_unit_with_lex(next_token_str, next_next, false, lex)
                 {
                     let freq = parse_freq_from_unit(&unit);
                     if !freq.is_empty() {
-                        task.rrule = Some(format!("FREQ={};INTERVAL={}", freq, interval));
+                        let count = if extra_consumed > 0 {
+                            next_next_count
+                        } else {
+                            next_count
+                        };
+                        task.rrule = Some(with_recurrence_count(
+                            format!("FREQ={};INTERVAL={}", freq, interval),
+                            count,
+                        ));
                         if is_after
                             && !task
                                 .unmapped_properties
@@ -2692,18 +3495,32 @@ pub fn apply_smart_input(
                     } else if !is_bg {
                         summary_words.push(unescape(token));
                     }
+                } else if let Some(rule) =
+                    next_next.and_then(|nn| parse_every_phrase_with_lex(next_token_str, nn, lex))
+                {
+                    // `every other week`, `every last friday`, `every first weekday`
+                    task.rrule = Some(with_recurrence_count(rule, next_next_count));
+                    if is_after
+                        && !task
+                            .unmapped_properties
+                            .iter()
+                            .any(|p| p.key == "X-CFAIT-RECUR-FROM-COMPLETION")
+                    {
+                        task.unmapped_properties.push(crate::model::RawProperty {
+                            key: "X-CFAIT-RECUR-FROM-COMPLETION".to_string(),
+                            value: "TRUE".to_string(),
+                            params: vec![],
+                        });
+                    }
+                    has_recurrence = true;
+                    consumed = 3;
                 } else {
                     // Weekdays
-                    let parts: Vec<&str> = next_token_str.split(',').map(|s| s.trim()).collect();
-                    let weekday_codes: Vec<String> = parts
-                        .iter()
-                        .filter_map(|part| {
-                            parse_weekday_code_with_lex(part, lex).map(|s| s.to_string())
-                        })
-                        .collect();
-
-                    if !weekday_codes.is_empty() && weekday_codes.len() == parts.len() {
-                        task.rrule = Some(format!("FREQ=WEEKLY;BYDAY={}", weekday_codes.join(",")));
+                    if let Some(weekday_codes) = parse_weekday_list_with_lex(next_token_str, lex) {
+                        task.rrule = Some(with_recurrence_count(
+                            format!("FREQ=WEEKLY;BYDAY={}", weekday_codes.join(",")),
+                            next_count,
+                        ));
                         if is_after
                             && !task
                                 .unmapped_properties
@@ -2740,10 +3557,29 @@ pub fn apply_smart_input(
             } else if !is_bg {
                 summary_words.push(unescape(token));
             }
+        } else if is_due_or_recur && exact == Some(&ExactToken::Weekdays) {
+            let rrule = with_recurrence_count(
+                format!("FREQ=WEEKLY;BYDAY={}", WORKWEEK_CODES.join(",")),
+                rec_count,
+            );
+            task.rrule = Some(rrule.clone());
+            has_recurrence = true;
+
+            if i + consumed < stream.len()
+                && let Some(t) = parse_time_string(&stream[i + consumed])
+            {
+                let today = Local::now().date_naive();
+                let first_date = calculate_first_occurrence(&rrule, today);
+                let dt_specific = crate::model::item::safe_local_to_utc(first_date, t);
+                let date_val = DateType::Specific(dt_specific);
+                task.due = Some(date_val.clone());
+                task.dtstart = Some(date_val);
+                consumed += 1;
+            }
         } else if is_due_or_recur && matches!(exact, Some(&ExactToken::Unit(_))) {
             if let Some(ExactToken::Unit(u)) = exact {
                 let freq = u.to_freq();
-                task.rrule = Some(format!("FREQ={}", freq));
+                task.rrule = Some(with_recurrence_count(format!("FREQ={}", freq), rec_count));
                 has_recurrence = true;
 
                 if i + consumed < stream.len() {
@@ -2761,7 +3597,8 @@ pub fn apply_smart_input(
                 }
             }
         } else if pref == Some(PrefixToken::Recur) {
-            if let Some(rrule) = parse_recurrence(rem) {
+            if let Some(rrule) = parse_recurrence(rem_base) {
+                let rrule = with_recurrence_count(rrule, rec_count);
                 task.rrule = Some(rrule.clone());
                 has_recurrence = true;
                 if i + consumed < stream.len() {
@@ -2777,11 +3614,14 @@ pub fn apply_smart_input(
                     }
                 }
             } else if let Some((interval, unit, _)) =
-                parse_amount_and_unit_with_lex(rem, None, false, lex)
+                parse_amount_and_unit_with_lex(rem_base, None, false, lex)
             {
                 let freq = parse_freq_from_unit(&unit);
                 if !freq.is_empty() {
-                    task.rrule = Some(format!("FREQ={};INTERVAL={}", freq, interval));
+                    task.rrule = Some(with_recurrence_count(
+                        format!("FREQ={};INTERVAL={}", freq, interval),
+                        rec_count,
+                    ));
                     has_recurrence = true;
                 } else if !is_bg {
                     summary_words.push(unescape(token));
@@ -2791,16 +3631,17 @@ pub fn apply_smart_input(
             }
         } else if has_recurrence && exact == Some(&ExactToken::Until) && i + 1 < stream.len() {
             let next_token = &stream[i + 1];
-            if let Some(d) = parse_smart_date_with_lex(next_token, lex) {
+            if let Some(d) = parse_smart_date_with_lex(next_token, lex, week_start) {
                 if let Some(mut rr) = task.rrule.take() {
-                    if !rr.contains("UNTIL=") {
+                    // RFC 5545 forbids COUNT and UNTIL in the same rule: the first limit wins.
+                    if !rr.contains("UNTIL=") && !rr.contains("COUNT=") {
                         let date_str = match d {
                             DateType::AllDay(nd) => nd.format("%Y%m%d").to_string(),
                             DateType::Specific(dt) => dt.format("%Y%m%d").to_string(),
                             DateType::Month(y, m) => format!("{:04}{:02}01", y, m),
                             DateType::Year(y) => format!("{:04}0101", y),
                         };
-                        rr.push_str(&format!(";UNTIL={}", date_str));
+                        rr.push_str(&format!(";UNTIL={}T000000Z", date_str));
                     }
                     task.rrule = Some(rr);
                 }
@@ -2816,7 +3657,7 @@ pub fn apply_smart_input(
             if parts.len() == 1 {
                 let part = parts[0];
                 let mut temp_consumed = 1;
-                if let Some(d) = parse_smart_date_with_lex(part, lex) {
+                if let Some(d) = parse_smart_date_with_lex(part, lex, week_start) {
                     let (dt, _) = finalize_date_token(d, &stream, i + 2, &mut temp_consumed);
                     task.exdates.push(dt);
                     matched_any = true;
@@ -2832,7 +3673,7 @@ pub fn apply_smart_input(
                 }
             } else {
                 for part in parts {
-                    if let Some(d) = parse_smart_date_with_lex(part, lex) {
+                    if let Some(d) = parse_smart_date_with_lex(part, lex, week_start) {
                         let all_day_date = match d {
                             DateType::AllDay(nd) => DateType::AllDay(nd),
                             DateType::Specific(dt) => DateType::AllDay(dt.date_naive()),
@@ -2921,7 +3762,7 @@ pub fn apply_smart_input(
                     pending_alarms.push(PendingAlarm::Relative(d));
                 } else if let Some(t) = parse_time_string(clean_val) {
                     pending_alarms.push(PendingAlarm::TimeOnly(t));
-                } else if let Some(d) = parse_smart_date_with_lex(clean_val, lex)
+                } else if let Some(d) = parse_smart_date_with_lex(clean_val, lex, week_start)
                     .or_else(|| parse_next_date_with_lex(clean_val, lex).map(DateType::AllDay))
                 {
                     let mut time_part = None;
@@ -2971,7 +3812,7 @@ pub fn apply_smart_input(
                     let utc_dt = crate::model::item::safe_local_to_utc(ndt.date(), ndt.time());
                     task.set_completion_date(Some(utc_dt));
                     matched = true;
-                } else if let Some(d) = parse_smart_date_with_lex(clean_val, lex) {
+                } else if let Some(d) = parse_smart_date_with_lex(clean_val, lex, week_start) {
                     let mut temp_consumed = 1;
                     let (dt, _) =
                         finalize_date_token(d, &stream, i + temp_consumed, &mut temp_consumed);
@@ -3095,9 +3936,9 @@ pub fn apply_smart_input(
             let val = strip_quotes(rem_original);
             if !val.is_empty() {
                 if !val.contains("://") && !val.starts_with("mailto:") {
-                    task.url = Some(format!("https://{}", val));
+                    add_task_link(task, format!("https://{}", val));
                 } else {
-                    task.url = Some(val);
+                    add_task_link(task, val);
                 }
             } else if !is_bg {
                 summary_words.push(unescape(token));
@@ -3105,16 +3946,21 @@ pub fn apply_smart_input(
         } else if token.starts_with("[[") && token.ends_with("]]") {
             let inner = &token[2..token.len() - 2];
             if inner.contains("://") || inner.starts_with("mailto:") {
-                task.url = Some(inner.to_string());
+                add_task_link(task, inner.to_string());
             } else if !is_bg {
                 summary_words.push(unescape(token));
             }
+        } else if bare_url_available && is_bare_http_url(token) {
+            task.url = Some(token.to_string());
+            bare_url_available = false;
         } else if token_lower == "+cal" {
             task.create_event = Some(true);
         } else if token_lower == "-cal" {
             task.create_event = Some(false);
         } else if exact == Some(&ExactToken::IsPinned) {
             task.pinned = true;
+        } else if exact == Some(&ExactToken::IsWaiting) {
+            task.waiting = true;
         } else if exact == Some(&ExactToken::IsNote) {
             explicit_note_flag = Some(true);
         } else if exact == Some(&ExactToken::IsBlocked) {
@@ -3271,9 +4117,14 @@ pub fn apply_smart_input(
                 } else {
                     None
                 };
+                let third = stream.get(i + 3).map(|s| s.as_str());
                 if let Some((amount, unit, extra)) =
-                    parse_amount_and_unit_with_lex(next_token_str, next_next, false, lex)
-                    && let Some(d) = parse_in_date_with_lex(amount, &unit, lex)
+                    parse_business_days_with_lex(next_token_str, next_next, third, lex)
+                        .map(|(amt, extra)| (amt, BUSINESS_DAYS_UNIT.to_string(), extra))
+                        .or_else(|| {
+                            parse_amount_and_unit_with_lex(next_token_str, next_next, false, lex)
+                        })
+                    && let Some(d) = parse_in_date_with_lex(amount, &unit, lex, holidays)
                 {
                     let mut temp_consumed = 1 + 1 + extra;
                     let (dt, dt_end) = finalize_date_token(
@@ -3298,7 +4149,7 @@ pub fn apply_smart_input(
                 }
             }
             if !matched_date {
-                if let Some(d) = parse_smart_date_with_lex(clean, lex) {
+                if let Some(d) = parse_smart_date_with_lex(clean, lex, week_start) {
                     let mut temp_consumed = 1;
                     let (dt, dt_end) =
                         finalize_date_token(d, &stream, i + temp_consumed, &mut temp_consumed);
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 // File: ./src/model/recurrence.rs
-use crate::model::item::{Alarm, AlarmTrigger, DateType, RawProperty, Task, TaskStatus};
+use crate::model::item::{Alarm, AlarmTrigger, DateType, Task, TaskStatus};
 use chrono::{Datelike, Local, NaiveDate};
 use rrule::RRuleSet;
 use std::collections::HashSet; // Import HashSet for deduplication
@@ -59,8 +59,12 @@ impl RecurrenceEngine {
 
         for part in parts {
             if let Some(until_val) = part.strip_prefix("UNTIL=") {
+                // Date-only boundaries (bare dates, or the midnight UTC form written by the
+                // smart input parser) include every occurrence on that day.
                 if until_val.len() == 8 && !until_val.contains('T') {
                     new_parts.push(format!("UNTIL={}T235959", until_val));
+                } else if let Some(date) = until_val.strip_suffix("T000000Z") {
+                    new_parts.push(format!("UNTIL={}T235959", date));
                 } else if let Some(stripped) = until_val.strip_suffix('Z') {
                     new_parts.push(format!("UNTIL={}", stripped));
                 } else {
@@ -100,10 +104,22 @@ impl RecurrenceEngine {
                 }
             }
 
-            let rrule_next_naive = rrule_set
+            // COUNT is relative to the current seed, so track how many occurrences are
+            // consumed by this advance and carry the remainder over to the next instance.
+            let count = final_rule_part
+                .split(';')
+                .find_map(|p| p.strip_prefix("COUNT="))
+                .and_then(|v| v.parse::<u32>().ok());
+
+            let (consumed, rrule_next_naive) = match rrule_set
                 .into_iter()
                 .map(|d| d.naive_local())
-                .find(|d| *d > search_floor_local && !exclusion_dates.contains(&d.date()));
+                .enumerate()
+                .find(|(_, d)| *d > search_floor_local && !exclusion_dates.contains(&d.date()))
+            {
+                Some((idx, d)) => (idx as u32, Some(d)),
+                None => (0, None),
+            };
 
             let is_simple_monthly =
                 final_rule_part.contains("FREQ=MONTHLY") && !final_rule_part.contains("BY");
@@ -160,9 +176,24 @@ impl RecurrenceEngine {
                 next_task.dependencies.clear();
                 next_task.sequence = 0;
 
-                next_task
-                    .unmapped_properties
-                    .retain(|p| p.key != "COMPLETED");
+                if let Some(total) = count {
+                    let remaining = total.saturating_sub(consumed).max(1);
+                    next_task.rrule = Some(
+                        rule_str
+                            .split(';')
+                            .map(|p| {
+                                if p.starts_with("COUNT=") {
+                                    format!("COUNT={}", remaining)
+                                } else {
+                                    p.to_string()
+                                }
+                            })
+                            .collect::<Vec<_>>()
+                            .join(";"),
+                    );
+                }
+
+                next_task.set_completion_date(None);
 
                 next_task
                     .alarms
@@ -259,11 +290,7 @@ impl RecurrenceEngine {
             let href = task.href.clone();
             let etag = task.etag.clone();
             let calendar_href = task.calendar_href.clone();
-            let created_at = task
-                .unmapped_properties
-                .iter()
-                .find(|p| p.key == "CREATED")
-                .map(|p| p.value.clone());
+            let created_at = task.created_date();
 
             *task = next;
 
@@ -273,13 +300,8 @@ impl RecurrenceEngine {
             task.etag = etag;
             task.calendar_href = calendar_href;
 
-            if let Some(created_val) = created_at {
-                task.unmapped_properties.retain(|p| p.key != "CREATED");
-                task.unmapped_properties.push(RawProperty {
-                    key: "CREATED".to_string(),
-                    value: created_val,
-                    params: vec![],
-                });
+            if let Some(created) = created_at {
+                task.set_created_date(created);
             }
 
             task.sequence += 1;
@@ -25,6 +25,16 @@ pub struct SessionState {
 impl SessionState {
     /// The single source of truth for building the UI view based on current session state.
     pub fn get_filtered_view(&self, store: &TaskStore, config: &Config) -> FilterResult {
+        self.get_filtered_view_in(store, config, None)
+    }
+
+    /// `get_filtered_view` restricted to one calendar (a resolved `cal:` search token).
+    pub fn get_filtered_view_in(
+        &self,
+        store: &TaskStore,
+        config: &Config,
+        calendar_href: Option<&str>,
+    ) -> FilterResult {
         let mut hidden = config
             .hidden_calendars
             .iter()
@@ -45,27 +55,41 @@ impl SessionState {
         let expanded_locations: HashSet<String> = self.expanded_locations.iter().cloned().collect();
         let search_collapsed_tasks: HashSet<String> =
             self.search_collapsed_tasks.iter().cloned().collect();
+        let (exact_priority, require_unset_duration) =
+            crate::model::matcher::extract_filter_shortcuts(&self.search_term);
+        let exclusions = crate::model::matcher::extract_filter_exclusions(&self.search_term);
 
         store.filter(FilterOptions {
-            active_cal_href: None, // Logic handled by hidden_calendars
+            active_cal_href: calendar_href, // Sidebar selection is handled by hidden_calendars
             hidden_calendars: &hidden,
             selected_categories: &selected_categories,
             selected_locations: &selected_locations,
             match_all_categories: self.match_all_categories,
             search_term: &self.search_term,
             hide_completed_global: config.hide_completed,
+            hide_deferred: config.hide_deferred,
             hide_fully_completed_tags: config.hide_fully_completed_tags,
             hide_aliases_in_sidebar: config.hide_aliases_in_sidebar,
             cutoff_date: cutoff,
             min_duration: None,
             max_duration: None,
             include_unset_duration: true,
+            exact_priority,
+            require_unset_duration,
+            excluded_categories: &exclusions.categories,
+            excluded_locations: &exclusions.locations,
+            excluded_priorities: &exclusions.priorities,
+            excluded_statuses: &exclusions.statuses,
             urgent_days: config.urgent_days_horizon,
             urgent_prio: config.urgent_priority_threshold,
             default_priority: config.default_priority,
             start_grace_period_days: config.start_grace_period_days,
             sort_standard_by_priority: config.sort_standard_by_priority,
             sort_preset: config.sort_preset,
+            sort_mode: config.sort_mode,
+            all_day_sort: config.all_day_sort,
+            week_start: config.week_start,
+            sort_urgency_boost: config.sort_urgency_boost,
             expanded_done_groups: &expanded_done_groups,
             expanded_tags: &expanded_tags,
             expanded_locations: &expanded_locations,
@@ -74,6 +98,7 @@ impl SessionState {
             tag_aliases: &config.tag_aliases,
             search_collapsed_tasks: &search_collapsed_tasks,
             focused_task_uid: self.focused_task_uid.as_deref(),
+            fuzzy_search: config.fuzzy_search,
         })
     }
 
@@ -160,38 +185,142 @@ impl SessionState {
 #[cfg_attr(feature = "mobile", derive(uniffi::Enum))]
 #[derive(Clone, Debug)]
 pub enum AppIntent {
-    ToggleTask { uid: String },
-    ToggleTaskShift { uid: String },
-    DeleteTask { uid: String },
-    DeleteTaskTree { uid: String },
-    TogglePin { uid: String },
-    CancelTask { uid: String },
-    ChangePriority { uid: String, delta: i8 },
-    StartTask { uid: String },
-    PauseTask { uid: String },
-    StopTask { uid: String },
-    MoveTask { uid: String, target_href: String },
-    DuplicateTaskTree { uid: String },
-    RemoveParent { uid: String },
-    MakeChild { uid: String, parent_uid: String },
-    AddDependency { uid: String, blocker_uid: String },
-    RemoveDependency { uid: String, blocker_uid: String },
-    AddRelatedTo { uid: String, related_uid: String },
-    RemoveRelatedTo { uid: String, related_uid: String },
+    ToggleTask {
+        uid: String,
+    },
+    ToggleTaskShift {
+        uid: String,
+    },
+    DeleteTask {
+        uid: String,
+    },
+    DeleteTaskTree {
+        uid: String,
+    },
+    TogglePin {
+        uid: String,
+    },
+    ToggleWaiting {
+        uid: String,
+    },
+    CancelTask {
+        uid: String,
+    },
+    ChangePriority {
+        uid: String,
+        delta: i8,
+    },
+    StartTask {
+        uid: String,
+    },
+    PauseTask {
+        uid: String,
+    },
+    StopTask {
+        uid: String,
+    },
+    SetProgress {
+        uid: String,
+        percent: u8,
+    },
+    /// Changes only the summary; the smart-string fields are left as they are.
+    RenameTask {
+        uid: String,
+        summary: String,
+    },
+    /// Moves the due date to a smart-date expression (`tomorrow`, `1w`, ...); `None` clears it.
+    RescheduleDue {
+        uid: String,
+        when: Option<String>,
+    },
+    /// Moves a recurring task to its next occurrence without completing the current one.
+    SkipOccurrence {
+        uid: String,
+    },
+    /// Re-queues the creation of a remote task that never reached the server.
+    RetryUnsynced {
+        uid: String,
+    },
+    CompleteTasks {
+        uids: Vec<String>,
+    },
+    /// Adds (`add`) or removes `tag` on each listed task (batch tag edit).
+    SetTagOnTasks {
+        uids: Vec<String>,
+        tag: String,
+        add: bool,
+    },
+    MoveTask {
+        uid: String,
+        target_href: String,
+    },
+    DuplicateTaskTree {
+        uid: String,
+    },
+    RemoveParent {
+        uid: String,
+    },
+    /// Detaches a task from its parent chain entirely (unlike `RemoveParent`, which moves up one level).
+    PromoteToRoot {
+        uid: String,
+    },
+    MakeChild {
+        uid: String,
+        parent_uid: String,
+    },
+    AddDependency {
+        uid: String,
+        blocker_uid: String,
+    },
+    RemoveDependency {
+        uid: String,
+        blocker_uid: String,
+    },
+    AddRelatedTo {
+        uid: String,
+        related_uid: String,
+    },
+    RemoveRelatedTo {
+        uid: String,
+        related_uid: String,
+    },
 
-    SetSearchTerm { term: String },
-    ToggleTagFilter { tag: String },
-    ToggleLocationFilter { location: String },
+    SetSearchTerm {
+        term: String,
+    },
+    ToggleTagFilter {
+        tag: String,
+    },
+    ToggleLocationFilter {
+        location: String,
+    },
     ClearFilters,
     ToggleMatchAllCategories,
-    SetSidebarCalendar { href: String },
+    SetSidebarCalendar {
+        href: String,
+    },
     ClearTagFilters,
     ClearLocationFilters,
-    ToggleTreeCollapse { uid: String },
-    SetTreeCollapse { uid: String, collapsed: bool },
-    ToggleDoneGroup { key: String },
-    ToggleTagCollapse { tag: String },
-    ToggleLocationCollapse { location: String },
-    FocusTaskTree { uid: Option<String> },
-    CompleteTree { uid: String },
+    ToggleTreeCollapse {
+        uid: String,
+    },
+    SetTreeCollapse {
+        uid: String,
+        collapsed: bool,
+    },
+    ToggleDoneGroup {
+        key: String,
+    },
+    ToggleTagCollapse {
+        tag: String,
+    },
+    ToggleLocationCollapse {
+        location: String,
+    },
+    FocusTaskTree {
+        uid: Option<String>,
+    },
+    CompleteTree {
+        uid: String,
+    },
 }
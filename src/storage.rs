@@ -33,6 +33,61 @@ struct LocalStorageData {
     tasks: Vec<Task>,
 }
 
+/// How a bulk import treats tasks whose UID already exists in the target store.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DuplicatePolicy {
+    /// Leave the existing task untouched and drop the imported one.
+    #[default]
+    Skip,
+    /// Keep both, giving the imported task a fresh UID and a "(Conflict Copy)" suffix,
+    /// mirroring what `sync_journal` does on a 412.
+    ConflictCopy,
+}
+
+/// Outcome of a bulk import. Malformed items are reported in `errors` instead of
+/// aborting the whole batch.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImportSummary {
+    pub imported: usize,
+    pub skipped: usize,
+    pub conflict_copies: usize,
+    pub errors: Vec<String>,
+}
+
+impl ImportSummary {
+    /// Human-readable, localized one-liner for status bars and toasts.
+    pub fn message(&self) -> String {
+        let mut msg = if self.imported == 1 {
+            rust_i18n::t!("import_success.one").to_string()
+        } else {
+            rust_i18n::t!("import_success.other", count = self.imported).to_string()
+        };
+        if self.skipped > 0 {
+            msg.push_str(&format!(
+                " · {}",
+                rust_i18n::t!("import_skipped_duplicates", count = self.skipped)
+            ));
+        }
+        if self.conflict_copies > 0 {
+            msg.push_str(&format!(
+                " · {}",
+                rust_i18n::t!("import_conflict_copies", count = self.conflict_copies)
+            ));
+        }
+        if !self.errors.is_empty() {
+            msg.push_str(&format!(
+                " · {}",
+                rust_i18n::t!(
+                    "import_item_errors",
+                    count = self.errors.len(),
+                    first = self.errors[0]
+                )
+            ));
+        }
+        msg
+    }
+}
+
 #[cfg(target_os = "android")]
 static ANDROID_FILE_LOCKS: OnceLock<Mutex<HashMap<PathBuf, Arc<Mutex<()>>>>> = OnceLock::new();
 static LOAD_STATE_MAP: OnceLock<Mutex<HashMap<String, LoadState>>> = OnceLock::new();
@@ -158,64 +213,108 @@ impl LocalStorage {
                 calendar_href
             };
 
-        let mut imported_tasks = Vec::new();
-        // Normalize line endings to \r\n for consistent parsing
-        let normalized_content = ics_content.replace("\r\n", "\n").replace('\n', "\r\n");
+        let (imported_tasks, _errors) = Self::parse_import_payload(ics_content, calendar_href);
 
-        // Split by VTODO blocks and parse each
-        let parts: Vec<&str> = normalized_content.split("BEGIN:VTODO").collect();
+        if imported_tasks.is_empty() {
+            anyhow::bail!("No valid tasks found in ICS file");
+        }
+
+        let count = imported_tasks.len();
+
+        // Safely upsert tasks using the unified lock
+        Self::modify_for_href(ctx, calendar_href, |existing_tasks| {
+            for imported in imported_tasks {
+                if let Some(idx) = existing_tasks.iter().position(|t| t.uid == imported.uid) {
+                    existing_tasks[idx] = imported;
+                } else {
+                    existing_tasks.push(imported);
+                }
+            }
+        })?;
+
+        Ok(count)
+    }
 
-        for component in parts.iter().skip(1) {
-            if !component.contains("END:VTODO") {
-                continue;
+    /// Parses an `.ics` file or a JSON array of tasks (as produced by `export --all --json`)
+    /// into tasks bound to `calendar_href`. Each item is parsed independently; failures are
+    /// collected as `"#<n>: <reason>"` strings so one bad VTODO doesn't sink the batch.
+    pub fn parse_import_payload(content: &str, calendar_href: &str) -> (Vec<Task>, Vec<String>) {
+        let mut tasks = Vec::new();
+        let mut errors = Vec::new();
+
+        let fresh_href = |uid: &str| -> String {
+            if calendar_href.starts_with("local://") {
+                format!("{}.ics", uid)
+            } else {
+                format!("{}/{}.ics", calendar_href.trim_end_matches('/'), uid)
             }
+        };
+
+        if content.trim_start().starts_with('[') {
+            let items: Vec<serde_json::Value> = match serde_json::from_str(content) {
+                Ok(items) => items,
+                Err(e) => {
+                    errors.push(e.to_string());
+                    return (tasks, errors);
+                }
+            };
+            for (i, item) in items.into_iter().enumerate() {
+                match serde_json::from_value::<Task>(item) {
+                    Ok(mut task) => {
+                        task.calendar_href = calendar_href.to_string();
+                        task.href = fresh_href(&task.uid);
+                        task.etag = String::new();
+                        tasks.push(task);
+                    }
+                    Err(e) => errors.push(format!("#{}: {}", i + 1, e)),
+                }
+            }
+            return (tasks, errors);
+        }
 
+        // Normalize line endings to \r\n for consistent parsing
+        let normalized_content = content.replace("\r\n", "\n").replace('\n', "\r\n");
+
+        // Split by VTODO blocks and parse each
+        for (i, component) in normalized_content.split("BEGIN:VTODO").skip(1).enumerate() {
             // Extract just the VTODO content (everything up to and including END:VTODO)
             let vtodo_end = match component.find("END:VTODO") {
                 Some(pos) => pos + "END:VTODO".len(),
-                None => continue,
+                None => {
+                    errors.push(format!("#{}: missing END:VTODO", i + 1));
+                    continue;
+                }
             };
             let vtodo_content = &component[..vtodo_end];
 
-            // Reconstruct a valid VTODO block
-            let vtodo = format!("BEGIN:VTODO{}", vtodo_content);
-
             // Always wrap in a proper VCALENDAR for parsing
             let full_ics = format!(
-                "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:-//cfait//cfait//EN\r\n{}\r\nEND:VCALENDAR",
-                vtodo
+                "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:-//cfait//cfait//EN\r\nBEGIN:VTODO{}\r\nEND:VCALENDAR",
+                vtodo_content
             );
-            if let Ok(task) = IcsAdapter::from_ics(
+            match IcsAdapter::from_ics(
                 &full_ics,
                 String::new(),
-                format!("{}.ics", uuid::Uuid::new_v4()),
+                String::new(),
                 calendar_href.to_string(),
             ) {
-                imported_tasks.push(task);
-            }
-        }
-
-        if imported_tasks.is_empty() {
-            anyhow::bail!("No valid tasks found in ICS file");
-        }
-
-        let count = imported_tasks.len();
-
-        // Safely upsert tasks using the unified lock
-        Self::modify_for_href(ctx, calendar_href, |existing_tasks| {
-            for imported in imported_tasks {
-                if let Some(idx) = existing_tasks.iter().position(|t| t.uid == imported.uid) {
-                    existing_tasks[idx] = imported;
-                } else {
-                    existing_tasks.push(imported);
+                Ok(mut task) => {
+                    task.href = fresh_href(&task.uid);
+                    tasks.push(task);
                 }
+                Err(e) => errors.push(format!("#{}: {}", i + 1, e)),
             }
-        })?;
+        }
 
-        Ok(count)
+        (tasks, errors)
     }
 
     pub fn to_ics_string(tasks: &[Task]) -> String {
+        Self::tasks_to_ics_string(tasks)
+    }
+
+    /// Like [`Self::to_ics_string`], but accepts borrowed tasks from any source.
+    pub fn tasks_to_ics_string<'a>(tasks: impl IntoIterator<Item = &'a Task>) -> String {
         let mut output =
             String::from("BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:-//Cfait//Export//EN\r\n");
         for task in tasks {
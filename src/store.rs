@@ -50,16 +50,19 @@ Implementation notes:
      transient attributes are computed).
 */
 
-use crate::config::Config;
+use crate::config::{AgendaGrouping, Config, ParentProgress};
 use crate::context::AppContext;
 use crate::journal::Action as JournalAction;
+use crate::model::matcher::StatusFilter;
 use crate::model::{AppIntent, DateType, Task, TaskStatus};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Local, NaiveDate, Utc, Weekday};
 use fastrand;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 pub const UNCATEGORIZED_ID: &str = ":::uncategorized:::";
+/// Sidebar key of the "(no location)" bucket, the location counterpart of `UNCATEGORIZED_ID`.
+pub const NO_LOCATION_ID: &str = ":::no-location:::";
 
 /// Enum representing items in the task list - either real tasks or UI control elements
 #[derive(Debug, Clone)]
@@ -79,11 +82,168 @@ pub struct AggregateItem {
     pub is_expanded: bool,
 }
 
+/// Agenda section of a top-level task when the list is grouped (`agenda_grouping`).
+/// The variant order is the section order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AgendaGroup {
+    Overdue,
+    Today,
+    Tomorrow,
+    ThisWeek,
+    Later,
+    NoDate,
+    Done,
+}
+
+impl AgendaGroup {
+    /// Section for `task` on `today`. Done tasks get their own trailing section so they
+    /// don't fill up *Overdue*. "This week" ends on the last day of the configured week.
+    pub fn of(task: &Task, mode: AgendaGrouping, today: NaiveDate, week_start: Weekday) -> Self {
+        if task.status.is_done() {
+            return AgendaGroup::Done;
+        }
+        // Fuzzy month/year dates count from the end of their period
+        let day = |d: &DateType| match d {
+            DateType::Month(..) | DateType::Year(..) => {
+                d.to_comparison_time().with_timezone(&Local).date_naive()
+            }
+            _ => d.to_date_naive(),
+        };
+        let due = task.due.as_ref().map(day);
+        let date = match (mode, task.dtstart.as_ref().map(day)) {
+            (AgendaGrouping::Start, Some(start)) if start < today => {
+                due.filter(|d| *d < today).or(Some(today))
+            }
+            (AgendaGrouping::Start, Some(start)) => Some(start),
+            _ => due,
+        };
+
+        match date {
+            None => AgendaGroup::NoDate,
+            Some(d) if d < today => AgendaGroup::Overdue,
+            Some(d) if d == today => AgendaGroup::Today,
+            Some(d) if today.succ_opt() == Some(d) => AgendaGroup::Tomorrow,
+            Some(d) if d <= crate::model::parser::end_of_week(today, week_start) => {
+                AgendaGroup::ThisWeek
+            }
+            Some(_) => AgendaGroup::Later,
+        }
+    }
+
+    pub fn label(self) -> String {
+        match self {
+            AgendaGroup::Overdue => rust_i18n::t!("agenda_overdue"),
+            AgendaGroup::Today => rust_i18n::t!("agenda_today"),
+            AgendaGroup::Tomorrow => rust_i18n::t!("agenda_tomorrow"),
+            AgendaGroup::ThisWeek => rust_i18n::t!("agenda_this_week"),
+            AgendaGroup::Later => rust_i18n::t!("agenda_later"),
+            AgendaGroup::NoDate => rust_i18n::t!("agenda_no_date"),
+            AgendaGroup::Done => rust_i18n::t!("agenda_done"),
+        }
+        .to_string()
+    }
+}
+
+// Section of each top-level block (a root task with its subtasks and group rows)
+fn agenda_blocks(
+    items: &[TaskListItem],
+    mode: AgendaGrouping,
+    today: NaiveDate,
+    week_start: Weekday,
+) -> Vec<(usize, AgendaGroup)> {
+    let mut starts: Vec<(usize, AgendaGroup)> = Vec::new();
+    for (i, item) in items.iter().enumerate() {
+        let group = match item {
+            TaskListItem::Task(t) if t.depth == 0 => AgendaGroup::of(t, mode, today, week_start),
+            // Root-level rows only fold completed tasks
+            TaskListItem::ExpandGroup(_, 0) | TaskListItem::CollapseGroup(_, 0) => {
+                AgendaGroup::Done
+            }
+            _ if starts.is_empty() => AgendaGroup::NoDate,
+            _ => continue,
+        };
+        starts.push((i, group));
+    }
+    starts
+}
+
+/// Reorders a filtered view into agenda sections, moving whole subtrees with their root.
+/// Inside a section the existing order is kept. `Off` returns the list untouched.
+pub fn group_by_agenda(
+    items: Vec<TaskListItem>,
+    mode: AgendaGrouping,
+    today: NaiveDate,
+    week_start: Weekday,
+) -> Vec<TaskListItem> {
+    if mode == AgendaGrouping::Off {
+        return items;
+    }
+    let starts = agenda_blocks(&items, mode, today, week_start);
+    let mut blocks: Vec<(AgendaGroup, Vec<TaskListItem>)> =
+        starts.iter().map(|(_, g)| (*g, Vec::new())).collect();
+    let mut block = 0;
+    for (i, item) in items.into_iter().enumerate() {
+        if starts.get(block + 1).is_some_and(|(start, _)| *start == i) {
+            block += 1;
+        }
+        blocks[block].1.push(item);
+    }
+    blocks.sort_by_key(|(group, _)| *group);
+    blocks.into_iter().flat_map(|(_, b)| b).collect()
+}
+
+/// Open tasks per due day in a filtered view, for the GUI's due-date heat map.
+/// Fuzzy month/year due dates have no single day and are left out.
+pub fn due_date_counts(items: &[TaskListItem]) -> HashMap<NaiveDate, usize> {
+    let mut counts = HashMap::new();
+    for item in items {
+        if let TaskListItem::Task(t) = item
+            && !t.status.is_done()
+            && let Some(due @ (DateType::AllDay(_) | DateType::Specific(_))) = &t.due
+        {
+            *counts.entry(due.to_date_naive()).or_insert(0) += 1;
+        }
+    }
+    counts
+}
+
+/// Indices in an agenda-grouped view where a new section begins, with that section.
+pub fn agenda_headers(
+    items: &[TaskListItem],
+    mode: AgendaGrouping,
+    today: NaiveDate,
+    week_start: Weekday,
+) -> Vec<(usize, AgendaGroup)> {
+    if mode == AgendaGrouping::Off {
+        return Vec::new();
+    }
+    let mut headers: Vec<(usize, AgendaGroup)> = Vec::new();
+    for (i, group) in agenda_blocks(items, mode, today, week_start) {
+        if headers.last().is_none_or(|(_, last)| *last != group) {
+            headers.push((i, group));
+        }
+    }
+    headers
+}
+
+/// Whether a task's tag or location `value` falls under the sidebar selection `selected`:
+/// the same path or one of its children (`home` selects `home:office`), ignoring case.
+pub fn matches_hierarchy(value: &str, selected: &str) -> bool {
+    let value = value.to_lowercase();
+    let selected = selected.to_lowercase();
+    value == selected
+        || value
+            .strip_prefix(&selected)
+            .is_some_and(|rest| rest.starts_with(':'))
+}
+
 /// Result container returned by the `filter` pipeline.
 pub struct FilterResult {
     pub items: Vec<TaskListItem>,
     pub categories: Vec<AggregateItem>,
     pub locations: Vec<AggregateItem>,
+    /// Open tasks in the allowed calendars that still need triage (see `Task::is_inbox`).
+    pub inbox_count: usize,
 }
 
 /// Context structure used during hierarchy organization
@@ -106,10 +266,74 @@ pub struct HierarchyOptions<'a> {
     pub max_done_subtasks: usize,
     pub search_active: bool,
     pub sort_preset: crate::config::SortPreset,
+    pub sort_mode: crate::config::SortMode,
+    pub all_day_sort: crate::config::AllDaySort,
+    pub sort_urgency_boost: bool,
     pub search_collapsed_tasks: &'a HashSet<String>,
     pub focused_task_uid: Option<&'a str>,
 }
 
+/// Reorders the root blocks (a depth-0 row and everything under it) of an organized
+/// list by their best `fuzzy_score` against the plain search words, best first.
+/// Blocks keep their inner order, and ties keep the normal sort order.
+fn rank_by_fuzzy_score(items: &mut Vec<TaskListItem>, search_term: &str) {
+    let words = crate::model::matcher::plain_search_words(search_term);
+    if words.is_empty() {
+        return;
+    }
+
+    let mut blocks: Vec<(u32, Vec<TaskListItem>)> = Vec::new();
+    for item in items.drain(..) {
+        let starts_block = match &item {
+            TaskListItem::Task(t) => t.depth == 0,
+            _ => false,
+        };
+        if starts_block || blocks.is_empty() {
+            blocks.push((0, Vec::new()));
+        }
+        if let TaskListItem::Task(t) = &item
+            && let Some(block) = blocks.last_mut()
+        {
+            let summary = t.summary.to_lowercase();
+            let score: u32 = words
+                .iter()
+                .filter_map(|w| crate::model::matcher::fuzzy_score(&summary, w))
+                .sum();
+            block.0 = block.0.max(score);
+        }
+        if let Some(block) = blocks.last_mut() {
+            block.1.push(item);
+        }
+    }
+
+    blocks.sort_by_key(|block| std::cmp::Reverse(block.0));
+    items.extend(blocks.into_iter().flat_map(|(_, block)| block));
+}
+
+/// Sorts a flat task list with the comparator selected by `options.sort_mode`.
+fn sort_for_display(tasks: &mut [Task], options: &HierarchyOptions<'_>) {
+    match options.sort_mode {
+        crate::config::SortMode::Smart => tasks.sort_by(|a, b| {
+            a.compare_for_sort(
+                b,
+                options.default_priority,
+                options.sort_standard_by_priority,
+                options.sort_preset,
+                options.all_day_sort,
+            )
+        }),
+        mode => tasks.sort_by(|a, b| {
+            a.compare_for_mode(
+                b,
+                mode,
+                options.sort_urgency_boost,
+                options.default_priority,
+                options.all_day_sort,
+            )
+        }),
+    }
+}
+
 /// Organize tasks into a hierarchy with proper parent/child relationships and inject
 /// expand/collapse control items for large groups of completed tasks.
 /// This function handles:
@@ -125,15 +349,9 @@ pub fn organize_hierarchy(
     let mut children_map: HashMap<String, Vec<Task>> = HashMap::new();
     let mut roots: Vec<Task> = Vec::new();
 
-    // Sort by the canonical comparator before building hierarchy
-    tasks.sort_by(|a, b| {
-        a.compare_for_sort(
-            b,
-            options.default_priority,
-            options.sort_standard_by_priority,
-            options.sort_preset,
-        )
-    });
+    // Sort by the selected comparator before building hierarchy; children keep this
+    // order under their parent.
+    sort_for_display(&mut tasks, &options);
 
     for mut task in tasks {
         let is_orphan = match &task.parent_uid {
@@ -436,14 +654,7 @@ pub fn organize_hierarchy(
     }
 
     if !unvisited.is_empty() {
-        unvisited.sort_by(|a, b| {
-            a.compare_for_sort(
-                b,
-                options.default_priority,
-                options.sort_standard_by_priority,
-                options.sort_preset,
-            )
-        });
+        sort_for_display(&mut unvisited, &options);
         process_group(
             unvisited,
             "".to_string(),
@@ -533,10 +744,98 @@ pub struct TaskStore {
     pub blocking_index: HashMap<String, Vec<String>>,
     /// Reverse lookup for parent_uid: parent_uid -> Vec<child_uid>
     pub children_index: HashMap<String, Vec<String>>,
+    /// Archive bucket: calendar_href -> (uid -> Task) for completed tasks older than
+    /// `archive_after_days`. These are kept out of `calendars` and `index`, so `filter`,
+    /// sorting and tag/location aggregation never visit them.
+    pub archive: HashMap<String, HashMap<String, Task>>,
+    /// Age (days since completion) after which completed tasks are archived, taken from
+    /// the config by `apply_archive_setting`. `None` disables.
+    archive_after_days: Option<u32>,
+    /// Whether archived tasks are currently held in `archive` (false while merged back
+    /// for an `is:archived` search or when archiving is disabled).
+    archive_shelved: bool,
     /// AppContext used for persistence operations (if store needs to save).
     pub ctx: Arc<dyn AppContext>,
 }
 
+/// Serialization format for [`TaskStore::export_all`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// A single VCALENDAR containing every VTODO (re-importable).
+    Ics,
+    /// A JSON array of tasks, matching the on-disk `LocalStorage` schema.
+    Json,
+}
+
+impl ExportFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Ics => "ics",
+            ExportFormat::Json => "json",
+        }
+    }
+}
+
+/// Maximum number of actions kept in an [`UndoHistory`].
+pub const UNDO_HISTORY_LIMIT: usize = 20;
+
+/// State needed to revert one delete/complete/move intent.
+#[derive(Debug, Clone)]
+pub struct UndoEntry {
+    /// Tasks as they were before the intent ran (only those the intent touched).
+    pub before: Vec<Task>,
+    /// UIDs the intent created from scratch, e.g. recurrence history copies.
+    pub created: Vec<String>,
+}
+
+/// Bounded, in-memory stack of recent undoable actions. Not persisted.
+#[derive(Debug, Clone, Default)]
+pub struct UndoHistory {
+    entries: std::collections::VecDeque<UndoEntry>,
+}
+
+impl UndoHistory {
+    /// Records an entry from a pre-intent snapshot and the actions the intent produced.
+    /// Intents that changed nothing are not recorded.
+    pub fn record(&mut self, before: Vec<Task>, actions: &[JournalAction]) {
+        let mut touched = HashSet::new();
+        let mut created = Vec::new();
+        let known: HashSet<&str> = before.iter().map(|t| t.uid.as_str()).collect();
+        for action in actions {
+            let task = match action {
+                JournalAction::Create(t)
+                | JournalAction::Update(t)
+                | JournalAction::Delete(t)
+                | JournalAction::Move(t, _) => t,
+            };
+            if matches!(action, JournalAction::Create(_)) && !known.contains(task.uid.as_str()) {
+                created.push(task.uid.clone());
+            }
+            touched.insert(task.uid.clone());
+        }
+        if touched.is_empty() {
+            return;
+        }
+
+        let before: Vec<Task> = before
+            .into_iter()
+            .filter(|t| touched.contains(&t.uid))
+            .collect();
+        if self.entries.len() == UNDO_HISTORY_LIMIT {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(UndoEntry { before, created });
+    }
+
+    pub fn pop(&mut self) -> Option<UndoEntry> {
+        self.entries.pop_back()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
 /// Options to parameterize a filter operation. Using a struct keeps the signature
 /// manageable as the filter logic supports many toggles.
 pub struct FilterOptions<'a> {
@@ -547,18 +846,38 @@ pub struct FilterOptions<'a> {
     pub match_all_categories: bool,
     pub search_term: &'a str,
     pub hide_completed_global: bool,
+    /// Hide tasks (and subtasks of tasks) whose start date is in the future,
+    /// unless the search asks for them with `is:deferred`.
+    pub hide_deferred: bool,
     pub hide_fully_completed_tags: bool,
     pub hide_aliases_in_sidebar: bool,
     pub cutoff_date: Option<DateTime<Utc>>,
     pub min_duration: Option<u32>,
     pub max_duration: Option<u32>,
     pub include_unset_duration: bool,
+    /// Only keep tasks whose priority is exactly this value (search token `!=N`).
+    pub exact_priority: Option<u8>,
+    /// Only keep tasks without an estimated duration, even when a duration range is set
+    /// (search token `~none`).
+    pub require_unset_duration: bool,
+    /// Tags subtracted by negated search tokens (`-#tag`), matched hierarchically and lowercase.
+    pub excluded_categories: &'a HashSet<String>,
+    /// Locations subtracted by negated search tokens (`-@@loc`), matched hierarchically and lowercase.
+    pub excluded_locations: &'a HashSet<String>,
+    /// Priorities subtracted by negated search tokens (`-!1`).
+    pub excluded_priorities: &'a HashSet<u8>,
+    /// States subtracted by negated search tokens (`-is:done`, `-is:ready`, ...).
+    pub excluded_statuses: &'a HashSet<StatusFilter>,
     pub urgent_days: u32,
     pub urgent_prio: u8,
     pub default_priority: u8,
     pub start_grace_period_days: u32,
     pub sort_standard_by_priority: bool,
     pub sort_preset: crate::config::SortPreset,
+    pub sort_mode: crate::config::SortMode,
+    pub all_day_sort: crate::config::AllDaySort,
+    pub week_start: chrono::Weekday,
+    pub sort_urgency_boost: bool,
     pub expanded_done_groups: &'a HashSet<String>,
     pub expanded_tags: &'a HashSet<String>,
     pub expanded_locations: &'a HashSet<String>,
@@ -567,6 +886,9 @@ pub struct FilterOptions<'a> {
     pub tag_aliases: &'a HashMap<String, Vec<String>>,
     pub search_collapsed_tasks: &'a HashSet<String>,
     pub focused_task_uid: Option<&'a str>,
+    /// Match plain search words against summaries as subsequences and rank the
+    /// root blocks of the result by match score (`fuzzy_search` setting).
+    pub fuzzy_search: bool,
 }
 
 impl TaskStore {
@@ -578,16 +900,202 @@ impl TaskStore {
             related_from_index: HashMap::new(),
             blocking_index: HashMap::new(),
             children_index: HashMap::new(),
+            archive: HashMap::new(),
+            archive_after_days: None,
+            archive_shelved: false,
             ctx,
         }
     }
 
+    /// Applies the persisted `archive_completed` setting for the current view: shelves
+    /// completed tasks older than `sort_cutoff_days`, or merges them back when the search
+    /// asks for `is:archived` (or archiving is off) so they can be listed and edited.
+    /// No-op when the state is unchanged.
+    pub fn apply_archive_setting(&mut self, config: &Config, search_term: &str) {
+        let days = if config.archive_completed {
+            config.sort_cutoff_days
+        } else {
+            None
+        };
+        let lex = crate::model::parser::LEXICON.read().unwrap();
+        let include_archived = search_term
+            .to_lowercase()
+            .split_whitespace()
+            .any(|w| w == "is:archived" || lex.search_is_archived.iter().any(|x| x.as_str() == w));
+        drop(lex);
+
+        let shelve = days.is_some() && !include_archived;
+        if days == self.archive_after_days && shelve == self.archive_shelved {
+            return;
+        }
+        self.archive_after_days = days;
+        self.archive_shelved = shelve;
+
+        if shelve {
+            let cutoff = Self::archive_cutoff(days);
+            for (href, map) in self.calendars.iter_mut() {
+                let shelved = Self::take_archivable(href, map, cutoff);
+                for uid in shelved.keys() {
+                    self.index.remove(uid);
+                }
+                if !shelved.is_empty() {
+                    self.archive
+                        .entry(href.clone())
+                        .or_default()
+                        .extend(shelved);
+                }
+            }
+        } else {
+            for (href, bucket) in self.archive.drain() {
+                let map = self.calendars.entry(href.clone()).or_default();
+                for (uid, task) in bucket {
+                    self.index.insert(uid.clone(), href.clone());
+                    map.insert(uid, task);
+                }
+            }
+        }
+        self.rebuild_relation_index();
+    }
+
+    /// Whether `uid` is currently shelved in the archive bucket.
+    pub fn is_archived(&self, uid: &str) -> bool {
+        self.archive.values().any(|m| m.contains_key(uid))
+    }
+
+    /// Number of tasks currently held in the archive bucket.
+    pub fn archived_count(&self) -> usize {
+        self.archive.values().map(|m| m.len()).sum()
+    }
+
+    fn archive_cutoff(days: Option<u32>) -> Option<DateTime<Utc>> {
+        days.map(|d| Utc::now() - chrono::Duration::days(d as i64))
+    }
+
+    /// Whether `task` belongs in the archive for the given cutoff: done, with a COMPLETED
+    /// date before the cutoff. Used both for shelving and for the `is:archived` view.
+    fn is_archivable(task: &Task, cutoff: Option<DateTime<Utc>>) -> bool {
+        let Some(cutoff) = cutoff else {
+            return false;
+        };
+        task.status.is_done() && task.completion_date().is_some_and(|d| d < cutoff)
+    }
+
+    /// Removes archivable tasks from `map` and returns them. Tasks that still have open
+    /// children stay put so the children keep their parent in the hot set, and system
+    /// collections (trash, recovery) are never archived.
+    fn take_archivable(
+        href: &str,
+        map: &mut HashMap<String, Task>,
+        cutoff: Option<DateTime<Utc>>,
+    ) -> HashMap<String, Task> {
+        if cutoff.is_none()
+            || href == crate::storage::LOCAL_TRASH_HREF
+            || href == "local://recovery"
+        {
+            return HashMap::new();
+        }
+        let open_parents: HashSet<String> = map
+            .values()
+            .filter(|t| !t.status.is_done())
+            .filter_map(|t| t.parent_uid.clone())
+            .collect();
+        let uids: Vec<String> = map
+            .values()
+            .filter(|t| Self::is_archivable(t, cutoff) && !open_parents.contains(&t.uid))
+            .map(|t| t.uid.clone())
+            .collect();
+        uids.into_iter()
+            .filter_map(|uid| map.remove_entry(&uid))
+            .collect()
+    }
+
     /// Whether the store contains any tasks (fast O(1) via index map).
     /// This is used to differentiate between a truly-empty app vs filters hiding items.
     pub fn has_any_tasks(&self) -> bool {
         !self.index.is_empty()
     }
 
+    /// Serializes every task across all calendars (trash excluded) for backup.
+    /// Output is ordered by calendar then UID so repeated exports diff cleanly.
+    pub fn export_all(&self, format: ExportFormat) -> String {
+        // Archived tasks are part of the backup even while shelved out of `calendars`
+        let mut hrefs: Vec<&String> = self
+            .calendars
+            .keys()
+            .chain(self.archive.keys())
+            .filter(|h| h.as_str() != crate::storage::LOCAL_TRASH_HREF)
+            .collect();
+        hrefs.sort();
+        hrefs.dedup();
+
+        let mut tasks: Vec<&Task> = Vec::with_capacity(self.index.len() + self.archived_count());
+        for href in hrefs {
+            let mut cal_tasks: Vec<&Task> = self
+                .calendars
+                .get(href)
+                .into_iter()
+                .chain(self.archive.get(href))
+                .flat_map(|m| m.values())
+                .collect();
+            cal_tasks.sort_by(|a, b| a.uid.cmp(&b.uid));
+            tasks.extend(cal_tasks);
+        }
+
+        match format {
+            ExportFormat::Ics => crate::storage::LocalStorage::tasks_to_ics_string(tasks),
+            ExportFormat::Json => serde_json::to_string_pretty(&tasks).unwrap_or_default(),
+        }
+    }
+
+    /// Parses an import payload (`.ics` or JSON array) into `target_href`, resolving UID
+    /// collisions per `policy`, and inserts the new tasks. Returns the `Create` actions to
+    /// persist plus a summary; malformed items are reported rather than aborting the batch.
+    pub fn import_tasks(
+        &mut self,
+        data: &str,
+        target_href: &str,
+        policy: crate::storage::DuplicatePolicy,
+    ) -> (Vec<JournalAction>, crate::storage::ImportSummary) {
+        let target_href = if target_href == crate::storage::LOCAL_TRASH_HREF
+            || target_href == "local://recovery"
+        {
+            crate::storage::LOCAL_CALENDAR_HREF
+        } else {
+            target_href
+        };
+
+        let (parsed, errors) =
+            crate::storage::LocalStorage::parse_import_payload(data, target_href);
+        let mut summary = crate::storage::ImportSummary {
+            errors,
+            ..Default::default()
+        };
+
+        let mut actions = Vec::with_capacity(parsed.len());
+        for mut task in parsed {
+            if self.index.contains_key(&task.uid) || self.is_archived(&task.uid) {
+                match policy {
+                    crate::storage::DuplicatePolicy::Skip => {
+                        summary.skipped += 1;
+                        continue;
+                    }
+                    crate::storage::DuplicatePolicy::ConflictCopy => {
+                        let new_uid = uuid::Uuid::new_v4().to_string();
+                        task.href = task.href.replace(&task.uid, &new_uid);
+                        task.uid = new_uid;
+                        task.summary = format!("{} (Conflict Copy)", task.summary);
+                        summary.conflict_copies += 1;
+                    }
+                }
+            }
+            summary.imported += 1;
+            self.add_task(task.clone());
+            actions.push(JournalAction::Create(task));
+        }
+
+        (actions, summary)
+    }
+
     pub fn has_tasks_blocking(&self, uid: &str) -> bool {
         self.blocking_index
             .get(uid)
@@ -731,14 +1239,8 @@ impl TaskStore {
 
                 if !is_history_snapshot {
                     // Heuristic 1 (most reliable): Check for matching CREATED timestamp.
-                    let task_created = task.unmapped_properties.iter().find(|p| p.key == "CREATED");
-                    let parent_created = parent
-                        .unmapped_properties
-                        .iter()
-                        .find(|p| p.key == "CREATED");
-
-                    if let (Some(tc), Some(pc)) = (task_created, parent_created)
-                        && tc.value == pc.value
+                    if let (Some(tc), Some(pc)) = (task.created_date(), parent.created_date())
+                        && tc == pc
                     {
                         is_history_snapshot = true;
                     }
@@ -767,6 +1269,14 @@ impl TaskStore {
         }
         // --- END ADDED ---
 
+        // Re-shelve this calendar's old completed tasks (the fetched list replaces both sets)
+        if self.archive_shelved {
+            let cutoff = Self::archive_cutoff(self.archive_after_days);
+            let shelved = Self::take_archivable(&calendar_href, &mut new_map, cutoff);
+            uids_to_add.retain(|uid| !shelved.contains_key(uid));
+            self.archive.insert(calendar_href.clone(), shelved);
+        }
+
         // Cleanup index for tasks that were in this calendar but are now gone
         if let Some(old_map) = self.calendars.get(&calendar_href) {
             for uid in old_map.keys() {
@@ -973,6 +1483,7 @@ impl TaskStore {
     /// Remove all tasks and indices from the store.
     pub fn clear(&mut self) {
         self.calendars.clear();
+        self.archive.clear();
         self.index.clear();
         self.related_from_index.clear();
         self.blocking_index.clear();
@@ -981,6 +1492,7 @@ impl TaskStore {
 
     /// Remove an entire calendar from the store and drop related index entries.
     pub fn remove(&mut self, calendar_href: &str) {
+        self.archive.remove(calendar_href);
         if let Some(tasks_map) = self.calendars.remove(calendar_href) {
             for uid in tasks_map.keys() {
                 self.index.remove(uid);
@@ -1023,6 +1535,92 @@ impl TaskStore {
         None
     }
 
+    /// UIDs with an action still waiting in the offline journal.
+    fn journal_uids(&self) -> HashSet<String> {
+        crate::journal::Journal::load(self.ctx.as_ref())
+            .queue
+            .iter()
+            .map(|action| action.task().uid.clone())
+            .collect()
+    }
+
+    /// Remote tasks still unsynced `max_age_hours` after their `CREATED` stamp, oldest first.
+    /// Tasks with a journal entry are skipped: the next sync still uploads those.
+    /// Returns nothing when `max_age_hours` is 0.
+    pub fn stale_unsynced_tasks(&self, max_age_hours: u32) -> Vec<&Task> {
+        if max_age_hours == 0 {
+            return Vec::new();
+        }
+        let cutoff = Utc::now() - chrono::Duration::hours(max_age_hours as i64);
+        let queued = self.journal_uids();
+        let mut stale: Vec<&Task> = self
+            .calendars
+            .values()
+            .flat_map(|map| map.values())
+            .filter(|t| t.is_unsynced_remote() && !queued.contains(&t.uid))
+            .filter(|t| t.created_date().is_some_and(|c| c <= cutoff))
+            .collect();
+        stale.sort_by_key(|t| t.created_date());
+        stale
+    }
+
+    /// Sums the estimated durations of the open tasks in a filtered view, as
+    /// `(min, max)` minutes. Tasks without an estimate are skipped; returns
+    /// `None` when none of them has one.
+    pub fn estimated_duration_total(items: &[TaskListItem]) -> Option<(u32, u32)> {
+        let mut total: Option<(u32, u32)> = None;
+        for item in items {
+            if let TaskListItem::Task(t) = item
+                && !t.status.is_done()
+                && let Some(min) = t.estimated_duration
+            {
+                let max = t.estimated_duration_max.unwrap_or(min).max(min);
+                let (sum_min, sum_max) = total.unwrap_or((0, 0));
+                total = Some((sum_min.saturating_add(min), sum_max.saturating_add(max)));
+            }
+        }
+        total
+    }
+
+    /// Tasks that belong in focus mode (see `Task::is_focus_candidate`), ignoring
+    /// the trash. Tasks whose ancestors have not started yet are left out unless ongoing.
+    pub fn focus_tasks(&self, urgent_days: u32, urgent_prio: u8) -> Vec<&Task> {
+        let now = Utc::now();
+        self.calendars
+            .iter()
+            .filter(|(href, _)| *href != crate::storage::LOCAL_TRASH_HREF)
+            .flat_map(|(_, map)| map.values())
+            .filter(|t| t.is_focus_candidate(urgent_days, urgent_prio))
+            .filter(|t| {
+                t.status == TaskStatus::InProcess
+                    || t.is_paused()
+                    || !self.has_future_ancestor(t, now)
+            })
+            .collect()
+    }
+
+    fn has_future_ancestor(&self, task: &Task, now: DateTime<Utc>) -> bool {
+        let mut visited = HashSet::new();
+        let mut current = task;
+        while let Some(p_uid) = &current.parent_uid {
+            if !visited.insert(p_uid.clone()) {
+                break;
+            }
+            let Some(parent) = self.get_task_ref(p_uid) else {
+                break;
+            };
+            if parent
+                .dtstart
+                .as_ref()
+                .is_some_and(|d| d.to_start_comparison_time() > now)
+            {
+                return true;
+            }
+            current = parent;
+        }
+        false
+    }
+
     /// Evaluates retention settings and safely moves a task to the local trash.
     /// Returns (OriginalDeletedTask, Option<NewTrashedTask>).
     pub fn soft_delete_task(
@@ -1138,9 +1736,7 @@ impl TaskStore {
 
             task_copy.status = TaskStatus::NeedsAction;
             task_copy.percent_complete = None;
-            task_copy
-                .unmapped_properties
-                .retain(|p| p.key.to_uppercase() != "COMPLETED");
+            task_copy.set_completion_date(None);
 
             self.update_or_add_task(task_copy.clone());
             return Some((task_copy, None, vec![]));
@@ -1194,9 +1790,7 @@ impl TaskStore {
                 {
                     child.status = TaskStatus::NeedsAction;
                     child.percent_complete = None;
-                    child
-                        .unmapped_properties
-                        .retain(|p| p.key.to_uppercase() != "COMPLETED");
+                    child.set_completion_date(None);
 
                     let child_copy = child.clone();
                     self.update_or_add_task(child_copy.clone());
@@ -1244,6 +1838,167 @@ impl TaskStore {
         updated
     }
 
+    /// Sets an explicit completion percentage (clamped to 100).
+    /// Reaching 100 completes the task through `set_status` (so recurring tasks advance),
+    /// and dropping below 100 on a completed task reopens it as NeedsAction.
+    pub fn set_progress(
+        &mut self,
+        uid: &str,
+        percent: u8,
+    ) -> Option<(Task, Option<Task>, Vec<Task>)> {
+        let percent = percent.min(100);
+        let task = self.get_task_ref(uid)?;
+        let was_done = task.status.is_done();
+
+        if percent == 100 {
+            if was_done {
+                return None;
+            }
+            return self.set_status(uid, TaskStatus::Completed, false);
+        }
+
+        let mut children = Vec::new();
+        if was_done {
+            let (_, _, reset) = self.set_status(uid, TaskStatus::NeedsAction, false)?;
+            children = reset;
+        }
+
+        let (task, _) = self.get_task_mut(uid)?;
+        let new_pc = if percent == 0 { None } else { Some(percent) };
+        if !was_done && task.percent_complete == new_pc {
+            return None;
+        }
+        task.percent_complete = new_pc;
+        if !was_done {
+            task.sequence += 1;
+        }
+        Some((task.clone(), None, children))
+    }
+
+    /// UIDs from a filtered view that a bulk completion would actually touch:
+    /// open tasks not held back by an unfinished dependency.
+    pub fn completable_uids(&self, items: &[TaskListItem]) -> Vec<String> {
+        items
+            .iter()
+            .filter_map(|item| match item {
+                TaskListItem::Task(t) if !t.status.is_done() && !self.is_blocked(t) => {
+                    Some(t.uid.clone())
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Completes every listed task that is still open and not blocked by an
+    /// unfinished dependency. Blocked state is evaluated up front, so finishing a
+    /// blocker in the same batch does not sweep its dependents along.
+    pub fn complete_tasks(&mut self, uids: &[String]) -> Vec<(Task, Option<Task>, Vec<Task>)> {
+        let eligible: Vec<String> = uids
+            .iter()
+            .filter(|uid| {
+                self.get_task_ref(uid)
+                    .is_some_and(|t| !t.status.is_done() && !self.is_blocked(t))
+            })
+            .cloned()
+            .collect();
+
+        eligible
+            .iter()
+            .filter_map(|uid| self.set_status(uid, TaskStatus::Completed, false))
+            .collect()
+    }
+
+    /// Counts (completed, total) subtasks of `uid`, ignoring cancelled subtasks and
+    /// recurrence history copies. `None` when nothing is left to count.
+    pub fn subtask_completion(&self, uid: &str) -> Option<(usize, usize)> {
+        let mut done = 0;
+        let mut total = 0;
+        for child in self
+            .children_index
+            .get(uid)?
+            .iter()
+            .filter_map(|c| self.get_task_ref(c))
+        {
+            let is_history = child
+                .unmapped_properties
+                .iter()
+                .any(|p| p.key == "X-CFAIT-HISTORY-OF");
+            if is_history || child.status == TaskStatus::Cancelled {
+                continue;
+            }
+            total += 1;
+            if child.status == TaskStatus::Completed {
+                done += 1;
+            }
+        }
+        (total > 0).then_some((done, total))
+    }
+
+    /// Recomputes the ancestors of tasks whose status just changed, following `mode`.
+    /// `Percent` sets an open parent's `percent_complete` to the share of completed
+    /// subtasks; `Complete` goes through `set_progress`, so the parent completes at 100%
+    /// (recurring parents advance) and reopens when a subtask is reopened. Cancelled
+    /// parents are left alone. Each ancestor is visited at most once, which also stops
+    /// at parent cycles.
+    pub fn update_parent_progress(
+        &mut self,
+        changed: &[String],
+        mode: ParentProgress,
+    ) -> Vec<(Task, Option<Task>, Vec<Task>)> {
+        let mut results = Vec::new();
+        if mode == ParentProgress::Off {
+            return results;
+        }
+
+        let mut visited: HashSet<String> = changed.iter().cloned().collect();
+        let mut queue: Vec<String> = changed
+            .iter()
+            .filter_map(|uid| self.get_task_ref(uid)?.parent_uid.clone())
+            .collect();
+
+        while let Some(parent_uid) = queue.pop() {
+            if !visited.insert(parent_uid.clone()) {
+                continue;
+            }
+            let Some(parent) = self.get_task_ref(&parent_uid) else {
+                continue;
+            };
+            if parent.status == TaskStatus::Cancelled {
+                continue;
+            }
+            let grandparent = parent.parent_uid.clone();
+            let was_done = parent.status.is_done();
+            let Some((done, total)) = self.subtask_completion(&parent_uid) else {
+                continue;
+            };
+            let percent = (done * 100 / total) as u8;
+
+            let result = match mode {
+                ParentProgress::Complete => self.set_progress(&parent_uid, percent),
+                _ if was_done => None,
+                _ => {
+                    let new_pc = (percent > 0).then_some(percent);
+                    match self.get_task_mut(&parent_uid) {
+                        Some((task, _)) if task.percent_complete != new_pc => {
+                            task.percent_complete = new_pc;
+                            task.sequence += 1;
+                            Some((task.clone(), None, Vec::new()))
+                        }
+                        _ => None,
+                    }
+                }
+            };
+
+            if let Some(result) = result {
+                results.push(result);
+                if let Some(gp) = grandparent {
+                    queue.push(gp);
+                }
+            }
+        }
+        results
+    }
+
     /// Pause a task and all descendants (stop timing and record a session as appropriate).
     pub fn pause_task(&mut self, uid: &str) -> Vec<Task> {
         let mut updated = Vec::new();
@@ -1364,6 +2119,62 @@ impl TaskStore {
         None
     }
 
+    /// Replaces only the summary of a task, leaving every other smart field untouched.
+    /// Returns `None` when the trimmed title is empty or unchanged.
+    pub fn rename_task(&mut self, uid: &str, summary: &str) -> Option<Task> {
+        let summary = summary.trim();
+        if summary.is_empty() {
+            return None;
+        }
+        let (task, _) = self.get_task_mut(uid)?;
+        if task.summary == summary {
+            return None;
+        }
+        task.summary = summary.to_string();
+        task.sequence += 1;
+        Some(task.clone())
+    }
+
+    /// Sets the due date to the smart-date expression `when` (same grammar as `@`), or
+    /// clears it when `None`. A timed due date keeps its local time of day on the new date.
+    pub fn reschedule_due(
+        &mut self,
+        uid: &str,
+        when: Option<&str>,
+        week_start: Weekday,
+    ) -> Option<Task> {
+        let target = match when {
+            Some(expr) => Some(crate::model::parser::parse_smart_date(expr, week_start)?),
+            None => None,
+        };
+        let (task, _) = self.get_task_mut(uid)?;
+        let new_due = match (target, &task.due) {
+            (Some(DateType::AllDay(date)), Some(DateType::Specific(old))) => {
+                let time = old.with_timezone(&chrono::Local).time();
+                Some(DateType::Specific(crate::model::item::safe_local_to_utc(
+                    date, time,
+                )))
+            }
+            (target, _) => target,
+        };
+        if task.due == new_due {
+            return None;
+        }
+        task.due = new_due;
+        task.sequence += 1;
+        Some(task.clone())
+    }
+
+    /// Skips the current occurrence of a recurring task without completing it.
+    /// Returns the updated task, or `None` when it does not recur or the series is over.
+    pub fn skip_occurrence(&mut self, uid: &str) -> Option<Task> {
+        let (task, _) = self.get_task_mut(uid)?;
+        if !task.skip_occurrence() {
+            return None;
+        }
+        Some(task.clone())
+    }
+
     /// Synchronizes a modified markdown tree back into the database.
     pub fn sync_tree_from_markdown(
         &mut self,
@@ -1371,7 +2182,7 @@ impl TaskStore {
         markdown: &str,
         aliases: &std::collections::HashMap<String, Vec<String>>,
         default_reminder_time: Option<chrono::NaiveTime>,
-        trash_retention_days: u32,
+        config: &crate::config::Config,
         calendars: &[crate::model::CalendarListEntry],
     ) -> Result<Vec<crate::journal::Action>, String> {
         let mut actions = Vec::new();
@@ -1486,14 +2297,25 @@ impl TaskStore {
                 let mut actually_changed = false;
 
                 if expected_raw_text.trim() != ext.raw_text.trim() {
-                    clone.apply_smart_input(&ext.raw_text, aliases, default_reminder_time);
+                    clone.apply_smart_input(
+                        &ext.raw_text,
+                        aliases,
+                        default_reminder_time,
+                        &config.holidays,
+                        config.week_start,
+                    );
                     if !ext.dependencies.is_empty() {
                         clone.dependencies.extend(ext.dependencies.clone());
                     }
                     actually_changed = true;
                 } else {
-                    let dummy =
-                        crate::model::Task::new(&ext.raw_text, aliases, default_reminder_time);
+                    let dummy = crate::model::Task::new_with_date_settings(
+                        &ext.raw_text,
+                        aliases,
+                        default_reminder_time,
+                        &config.holidays,
+                        config.week_start,
+                    );
                     let mut new_deps = dummy.dependencies;
                     new_deps.extend(ext.dependencies.clone());
                     clone.dependencies = new_deps;
@@ -1528,7 +2350,7 @@ impl TaskStore {
                             clone.status = crate::model::TaskStatus::Completed;
                         } else {
                             clone.percent_complete = None;
-                            clone.unmapped_properties.retain(|p| p.key != "COMPLETED");
+                            clone.set_completion_date(None);
                         }
                     }
                 }
@@ -1562,10 +2384,12 @@ impl TaskStore {
                     || test_existing.categories != test_clone.categories
                     || test_existing.location != test_clone.location
                     || test_existing.url != test_clone.url
+                    || test_existing.attachments != test_clone.attachments
                     || test_existing.geo != test_clone.geo
                     || test_existing.percent_complete != test_clone.percent_complete
                     || test_existing.is_note != test_clone.is_note
                     || test_existing.pinned != test_clone.pinned
+                    || test_existing.waiting != test_clone.waiting
                     || test_existing.manual_block != test_clone.manual_block
                     || test_existing.permanent != test_clone.permanent
                     || test_existing.parent_uid != test_clone.parent_uid
@@ -1599,8 +2423,13 @@ impl TaskStore {
                     );
                 }
             } else {
-                let mut new_task =
-                    crate::model::Task::new(&ext.raw_text, aliases, default_reminder_time);
+                let mut new_task = crate::model::Task::new_with_date_settings(
+                    &ext.raw_text,
+                    aliases,
+                    default_reminder_time,
+                    &config.holidays,
+                    config.week_start,
+                );
                 new_task.uid = task_uid.clone();
                 new_task.description = ext.description;
 
@@ -1690,7 +2519,7 @@ impl TaskStore {
         for old_uid in old_descendants {
             if !active_uids.contains(&old_uid)
                 && let Some((deleted, trashed_opt)) =
-                    self.soft_delete_task(&old_uid, trash_retention_days)
+                    self.soft_delete_task(&old_uid, config.trash_retention_days)
             {
                 actions.push(crate::journal::Action::Delete(deleted));
                 if let Some(trashed) = trashed_opt {
@@ -1724,6 +2553,10 @@ impl TaskStore {
         descendants
     }
 
+    /// Copies a task and its subtasks under fresh UIDs, as new unsynced open tasks in the
+    /// same calendar. Links inside the copied tree are remapped; the root's parent and any
+    /// dependency on a task outside the tree are dropped so the copy doesn't double up on
+    /// the original's links. The root copy comes first in the returned list.
     pub fn duplicate_task_tree(&mut self, root_uid: &str) -> Vec<Task> {
         let mut new_tasks = Vec::new();
         let mut uid_map = HashMap::new(); // old -> new
@@ -1755,9 +2588,8 @@ impl TaskStore {
                 clone.time_spent_seconds = 0;
                 clone.last_started_at = None;
                 clone.sessions.clear();
-                clone
-                    .unmapped_properties
-                    .retain(|p| p.key != "COMPLETED" && p.key != "CREATED");
+                clone.set_completion_date(None);
+                clone.set_created_date(chrono::Utc::now());
                 clone
                     .alarms
                     .retain(|a| !a.is_snooze() && a.acknowledged.is_none());
@@ -1773,19 +2605,12 @@ impl TaskStore {
 
         // Second pass: remap intra-tree relations
         for t in &mut new_tasks {
-            if let Some(p) = &t.parent_uid
-                && let Some(new_p) = uid_map.get(p)
-            {
-                t.parent_uid = Some(new_p.clone());
-            }
+            t.parent_uid = t.parent_uid.as_ref().and_then(|p| uid_map.get(p).cloned());
             let old_deps = std::mem::take(&mut t.dependencies);
-            for d in old_deps {
-                if let Some(new_d) = uid_map.get(&d) {
-                    t.dependencies.push(new_d.clone());
-                } else {
-                    t.dependencies.push(d); // Maintain external blockers
-                }
-            }
+            t.dependencies = old_deps
+                .iter()
+                .filter_map(|d| uid_map.get(d).cloned())
+                .collect();
             let old_rels = std::mem::take(&mut t.related_to);
             for r in old_rels {
                 if let Some(new_r) = uid_map.get(&r) {
@@ -1803,6 +2628,25 @@ impl TaskStore {
         new_tasks
     }
 
+    /// Whether `parent_uid` can become the parent of `child_uid`: both exist and the target
+    /// is neither the child itself nor one of its descendants. Walks up the target's parent
+    /// chain with a visited set, as `organize_hierarchy` does, so an already corrupted
+    /// hierarchy is refused instead of looping.
+    pub fn can_set_parent(&self, child_uid: &str, parent_uid: &str) -> bool {
+        if self.get_task_ref(child_uid).is_none() || self.get_task_ref(parent_uid).is_none() {
+            return false;
+        }
+        let mut visited = HashSet::new();
+        let mut current = Some(parent_uid.to_string());
+        while let Some(uid) = current {
+            if uid == child_uid || !visited.insert(uid.clone()) {
+                return false;
+            }
+            current = self.get_task_ref(&uid).and_then(|t| t.parent_uid.clone());
+        }
+        true
+    }
+
     /// Set or unset a parent relationship for a task.
     pub fn set_parent(
         &mut self,
@@ -1818,7 +2662,11 @@ impl TaskStore {
                 ));
             }
             if self.get_descendant_uids(child_uid).contains(p_uid) {
-                return Err("Cycle detected: Cannot set a task as a child of its own descendant");
+                return Err(Box::leak(
+                    rust_i18n::t!("error_parent_cycle")
+                        .into_owned()
+                        .into_boxed_str(),
+                ));
             }
         }
 
@@ -1862,7 +2710,26 @@ impl TaskStore {
     }
 
     /// Add a dependency (task_uid depends on dep_uid). Maintain reverse blocking index.
-    pub fn add_dependency(&mut self, task_uid: &str, dep_uid: String) -> Option<Task> {
+    /// Rejects self-dependencies and edges that would close a dependency cycle.
+    pub fn add_dependency(
+        &mut self,
+        task_uid: &str,
+        dep_uid: String,
+    ) -> Result<Option<Task>, &'static str> {
+        if task_uid == dep_uid {
+            return Err(Box::leak(
+                rust_i18n::t!("error_cannot_depend_on_self")
+                    .into_owned()
+                    .into_boxed_str(),
+            ));
+        }
+        if self.would_create_dependency_cycle(task_uid, &dep_uid) {
+            return Err(Box::leak(
+                rust_i18n::t!("error_dependency_cycle")
+                    .into_owned()
+                    .into_boxed_str(),
+            ));
+        }
         if let Some((task, _)) = self.get_task_mut(task_uid)
             && !task.dependencies.contains(&dep_uid)
         {
@@ -1873,9 +2740,31 @@ impl TaskStore {
                 .entry(dep_uid)
                 .or_default()
                 .push(task_uid.to_string());
-            return Some(task_clone);
+            return Ok(Some(task_clone));
         }
-        None
+        Ok(None)
+    }
+
+    /// Whether making `task_uid` depend on `dep_uid` would close a loop, i.e.
+    /// `dep_uid` already depends on `task_uid` directly or transitively.
+    pub fn would_create_dependency_cycle(&self, task_uid: &str, dep_uid: &str) -> bool {
+        if task_uid == dep_uid {
+            return true;
+        }
+        let mut visited = HashSet::new();
+        let mut stack = vec![dep_uid.to_string()];
+        while let Some(uid) = stack.pop() {
+            if uid == task_uid {
+                return true;
+            }
+            if !visited.insert(uid.clone()) {
+                continue;
+            }
+            if let Some(t) = self.get_task_ref(&uid) {
+                stack.extend(t.dependencies.iter().cloned());
+            }
+        }
+        false
     }
 
     /// Remove a dependency and update reverse index.
@@ -2049,6 +2938,107 @@ impl TaskStore {
         modified_tasks
     }
 
+    /// Adds (`add`) or removes `tag` on each of `uids`, returning the tasks that changed.
+    pub fn set_tag_on_tasks(&mut self, uids: &[String], tag: &str, add: bool) -> Vec<Task> {
+        let mut modified_tasks = Vec::new();
+        for uid in uids {
+            if let Some((task, _)) = self.get_task_mut(uid) {
+                let has_tag = task.categories.iter().any(|c| c == tag);
+                if has_tag == add {
+                    continue;
+                }
+                if add {
+                    task.categories.push(tag.to_string());
+                    task.categories.sort();
+                } else {
+                    task.categories.retain(|c| c != tag);
+                }
+                task.sequence += 1;
+                modified_tasks.push(task.clone());
+            }
+        }
+        modified_tasks
+    }
+
+    /// Renames tag `from` (and its subtags) on every task and in `aliases` (the config's
+    /// `tag_aliases`). Tasks that end up with both the old and the new tag keep one copy.
+    /// Returns the changed tasks, for callers to persist, and how many aliases changed,
+    /// so callers know whether the config needs saving.
+    pub fn rename_tag_everywhere(
+        &mut self,
+        aliases: &mut HashMap<String, Vec<String>>,
+        from: &str,
+        to: &str,
+    ) -> (Vec<Task>, usize) {
+        if from == to {
+            return (Vec::new(), 0);
+        }
+        let alias_count = crate::model::parser::rename_tag_in_aliases(aliases, from, to);
+        (self.rename_tag(from, to), alias_count)
+    }
+
+    /// Same as [`Self::rename_tag_everywhere`], but pushes an [`UndoEntry`] onto `history`
+    /// holding the renamed tasks' previous content. Alias changes are not undone.
+    pub fn rename_tag_everywhere_with_undo(
+        &mut self,
+        aliases: &mut HashMap<String, Vec<String>>,
+        from: &str,
+        to: &str,
+        history: &mut UndoHistory,
+    ) -> (Vec<Task>, usize) {
+        let before: Vec<Task> = self
+            .calendars
+            .values()
+            .flat_map(|map| map.values())
+            .filter(|t| {
+                t.categories
+                    .iter()
+                    .any(|c| crate::model::parser::rename_tag_path(c, from, to).is_some())
+            })
+            .cloned()
+            .collect();
+        let (modified, alias_count) = self.rename_tag_everywhere(aliases, from, to);
+        let actions: Vec<JournalAction> = modified
+            .iter()
+            .cloned()
+            .map(JournalAction::Update)
+            .collect();
+        history.record(before, &actions);
+        (modified, alias_count)
+    }
+
+    /// Renames tag `from` to `to` on every task, subtags (`from:child`) included.
+    /// Returns the tasks that changed for callers to persist.
+    pub fn rename_tag(&mut self, from: &str, to: &str) -> Vec<Task> {
+        let uids: Vec<String> = self
+            .calendars
+            .values()
+            .flat_map(|map| map.values())
+            .filter(|t| {
+                t.categories
+                    .iter()
+                    .any(|c| crate::model::parser::rename_tag_path(c, from, to).is_some())
+            })
+            .map(|t| t.uid.clone())
+            .collect();
+
+        let mut modified_tasks = Vec::new();
+        for uid in uids {
+            if let Some((task, _)) = self.get_task_mut(&uid) {
+                for cat in task.categories.iter_mut() {
+                    if let Some(new_cat) = crate::model::parser::rename_tag_path(cat, from, to) {
+                        *cat = new_cat;
+                    }
+                }
+                task.categories.sort();
+                task.categories.dedup();
+                task.sequence += 1;
+                modified_tasks.push(task.clone());
+            }
+        }
+        modified_tasks
+    }
+
     /// Convenience: is task done by uid.
     pub fn is_task_done(&self, uid: &str) -> Option<bool> {
         self.get_task_ref(uid).map(|t| t.status.is_done())
@@ -2111,6 +3101,16 @@ impl TaskStore {
         }
     }
 
+    /// Number of open tasks that depend on `uid` (the "blocks N" count).
+    pub fn open_blocked_count(&self, uid: &str) -> usize {
+        self.blocking_index.get(uid).map_or(0, |blocked| {
+            blocked
+                .iter()
+                .filter(|b| self.is_task_done(b) == Some(false))
+                .count()
+        })
+    }
+
     /// Retrieves the completion history stats for a recurring task, using dynamically
     /// scaled windows based on the task's recurrence frequency.
     pub fn get_completion_history_stats(&self, uid: &str, rrule: &str) -> (u32, u32, &'static str) {
@@ -2421,13 +3421,31 @@ impl TaskStore {
         let mut is_ready_mode = false;
         let mut is_blocked_mode = false;
         let mut has_status_filter = false;
+        let mut is_archived_mode = false;
+        let mut is_deferred_mode = false;
+        let mut is_focus_mode = false;
+        let mut is_inbox_mode = false;
 
         for word in search_lower.split_whitespace() {
-            let w = word.trim_start_matches('-'); // Support negated status searches
+            // Negated status tokens only subtract from the visible set (see `excluded_statuses`),
+            // so they must not switch on a mode or reveal completed tasks.
+            if word.starts_with('-') {
+                continue;
+            }
+            let w = word;
             if w == "is:ready" || lex.search_is_ready.iter().any(|x| x.as_str() == w) {
                 is_ready_mode = true;
             } else if w == "is:blocked" || lex.search_is_blocked.iter().any(|x| x.as_str() == w) {
                 is_blocked_mode = true;
+            } else if w == "is:archived" || lex.search_is_archived.iter().any(|x| x.as_str() == w) {
+                is_archived_mode = true;
+                has_status_filter = true;
+            } else if w == "is:deferred" || lex.search_is_deferred.iter().any(|x| x.as_str() == w) {
+                is_deferred_mode = true;
+            } else if lex.exact.get(w) == Some(&crate::model::parser::ExactToken::IsFocus) {
+                is_focus_mode = true;
+            } else if w == "is:inbox" {
+                is_inbox_mode = true;
             } else if w == "is:done"
                 || lex.search_is_done.iter().any(|x| x.as_str() == w)
                 || w == "is:active"
@@ -2442,6 +3460,7 @@ impl TaskStore {
         }
 
         let now = Utc::now();
+        let archive_cutoff = Self::archive_cutoff(self.archive_after_days);
 
         // Helper: determine whether a task is effectively in the future by checking ancestors
         let check_is_effectively_future = |t: &Task| -> bool {
@@ -2506,6 +3525,15 @@ impl TaskStore {
             eff_future_map.insert(t.uid.clone(), check_is_effectively_future(t));
         }
 
+        let inbox_count = all_allowed_refs
+            .iter()
+            .filter(|t| {
+                t.is_inbox()
+                    && t.uid != "cfait-global-settings-v1"
+                    && !t.summary.starts_with("⚙ Cfait Settings")
+            })
+            .count();
+
         // 3) Define the filtering pipeline as a reusable closure.
         // This allows us to calculate the final tasks, and recalculate aggregates ignoring specific filters for OR modes.
         let run_pipeline = |ignore_categories: bool,
@@ -2532,6 +3560,24 @@ impl TaskStore {
                         return false;
                     }
 
+                    // Deferred tasks stay out of sight until they start, unless asked for.
+                    // Ongoing tasks are never deferred: work has already begun on them.
+                    if options.hide_deferred
+                        && !is_deferred_mode
+                        && !t.status.is_done()
+                        && t.status != TaskStatus::InProcess
+                        && *eff_future_map.get(&t.uid).unwrap_or(&false)
+                    {
+                        return false;
+                    }
+
+                    // Archived tasks only show up for `is:archived` (and only they do then)
+                    if archive_cutoff.is_some()
+                        && is_archived_mode != Self::is_archivable(t, archive_cutoff)
+                    {
+                        return false;
+                    }
+
                     if is_ready_mode {
                         if t.status.is_done() {
                             return false;
@@ -2548,12 +3594,82 @@ impl TaskStore {
                         }
                     }
 
+                    // Focus mode keeps only ongoing, paused or urgent work that has started
+                    if is_focus_mode {
+                        let started = t.status == TaskStatus::InProcess || t.is_paused();
+                        if !t.is_focus_candidate(options.urgent_days, options.urgent_prio)
+                            || (!started && *eff_future_map.get(&t.uid).unwrap_or(&false))
+                        {
+                            return false;
+                        }
+                    }
+
+                    if is_inbox_mode && !t.is_inbox() {
+                        return false;
+                    }
+
                     if is_blocked_mode && !eff_blocked_map.get(&t.uid).unwrap_or(&false) {
                         return false;
                     }
 
+                    if let Some(prio) = options.exact_priority
+                        && t.priority != prio
+                    {
+                        return false;
+                    }
+
+                    // Negated tokens subtract from what would otherwise be visible
+                    if options.excluded_priorities.contains(&t.priority) {
+                        return false;
+                    }
+                    for status in options.excluded_statuses {
+                        let hit = match status {
+                            StatusFilter::Done => t.status.is_done(),
+                            StatusFilter::Active => !t.status.is_done(),
+                            StatusFilter::Started => t.status == TaskStatus::InProcess,
+                            StatusFilter::Ready => {
+                                !t.status.is_done()
+                                    && (t.status == TaskStatus::InProcess
+                                        || (!*eff_future_map.get(&t.uid).unwrap_or(&false)
+                                            && !*eff_blocked_map.get(&t.uid).unwrap_or(&false)))
+                            }
+                            StatusFilter::Blocked => *eff_blocked_map.get(&t.uid).unwrap_or(&false),
+                        };
+                        if hit {
+                            return false;
+                        }
+                    }
+                    let in_hierarchy = |value: &str, sel: &str| -> bool {
+                        let v = value.to_lowercase();
+                        v == sel
+                            || v.strip_prefix(sel)
+                                .is_some_and(|rest| rest.starts_with(':'))
+                    };
+                    if !options.excluded_categories.is_empty()
+                        && t.categories.iter().any(|c| {
+                            options
+                                .excluded_categories
+                                .iter()
+                                .any(|sel| in_hierarchy(c, sel))
+                        })
+                    {
+                        return false;
+                    }
+                    if let Some(loc) = &t.location
+                        && options
+                            .excluded_locations
+                            .iter()
+                            .any(|sel| in_hierarchy(loc, sel))
+                    {
+                        return false;
+                    }
+
                     // Duration filters
-                    if let Some(mins) = t.estimated_duration {
+                    if options.require_unset_duration {
+                        if t.estimated_duration.is_some() {
+                            return false;
+                        }
+                    } else if let Some(mins) = t.estimated_duration {
                         if let Some(min) = options.min_duration
                             && mins < min
                         {
@@ -2572,24 +3688,15 @@ impl TaskStore {
                 })
                 .collect();
 
-            let query = crate::model::matcher::Query::new(options.search_term);
+            let query = crate::model::matcher::Query::new(options.search_term)
+                .with_fuzzy(options.fuzzy_search)
+                .with_week_start(options.week_start);
 
             let is_match = |t: &Task| -> bool {
                 // Category matching
                 if !ignore_categories && !options.selected_categories.is_empty() {
                     let filter_uncategorized =
                         options.selected_categories.contains(UNCATEGORIZED_ID);
-                    let check_match = |task_cat: &str, selected: &str| -> bool {
-                        let tc_lower = task_cat.to_lowercase();
-                        let sel_lower = selected.to_lowercase();
-                        if tc_lower == sel_lower {
-                            return true;
-                        }
-                        if let Some(stripped) = tc_lower.strip_prefix(&sel_lower) {
-                            return stripped.starts_with(':');
-                        }
-                        false
-                    };
 
                     if options.match_all_categories {
                         for sel in options.selected_categories {
@@ -2600,7 +3707,7 @@ impl TaskStore {
                             } else {
                                 let mut has = false;
                                 for c in &t.categories {
-                                    if check_match(c, sel) {
+                                    if matches_hierarchy(c, sel) {
                                         has = true;
                                         break;
                                     }
@@ -2618,7 +3725,7 @@ impl TaskStore {
                             for sel in options.selected_categories {
                                 if sel != UNCATEGORIZED_ID {
                                     for c in &t.categories {
-                                        if check_match(c, sel) {
+                                        if matches_hierarchy(c, sel) {
                                             hit = true;
                                             break;
                                         }
@@ -2638,19 +3745,13 @@ impl TaskStore {
                 // Location matching
                 if !ignore_locations && !options.selected_locations.is_empty() {
                     if let Some(loc) = &t.location {
-                        let mut hit = false;
-                        for sel in options.selected_locations {
-                            if loc == sel
-                                || (loc.starts_with(sel) && loc.chars().nth(sel.len()) == Some(':'))
-                            {
-                                hit = true;
-                                break;
-                            }
-                        }
+                        let hit = options.selected_locations.iter().any(|sel| {
+                            matches_hierarchy(loc, sel.strip_prefix("@@").unwrap_or(sel))
+                        });
                         if !hit {
                             return false;
                         }
-                    } else {
+                    } else if !options.selected_locations.contains(NO_LOCATION_ID) {
                         return false;
                     }
                 }
@@ -2759,6 +3860,8 @@ impl TaskStore {
 
         let mut loc_active_counts: HashMap<String, u32> = HashMap::new();
         let mut loc_present: HashSet<String> = HashSet::new();
+        let mut noloc_active_count: u32 = 0;
+        let mut noloc_any = false;
 
         // Process tag refs
         for t in &tag_refs {
@@ -2799,7 +3902,8 @@ impl TaskStore {
             }
         }
 
-        // Process location refs
+        // Process location refs. Every level of `home:office` is counted, so `home`
+        // rolls up its children the same way parent tags do.
         for t in &loc_refs {
             let is_active = !t.status.is_done();
 
@@ -2819,6 +3923,11 @@ impl TaskStore {
                     }
                     loc_present.insert(current_hierarchy.clone());
                 }
+            } else {
+                noloc_any = true;
+                if is_active {
+                    noloc_active_count += 1;
+                }
             }
         }
 
@@ -2931,7 +4040,7 @@ impl TaskStore {
         );
 
         let empty_names = HashMap::new(); // Locations use self-display names naturally
-        let locations = build_aggregates(
+        let mut locations = build_aggregates(
             loc_active_counts,
             empty_names,
             options.expanded_locations,
@@ -2955,6 +4064,18 @@ impl TaskStore {
             );
         }
 
+        // Add the no-location bucket, after the real locations so it doesn't crowd them
+        if noloc_any && !locations.is_empty() {
+            locations.push(AggregateItem {
+                full_key: NO_LOCATION_ID.to_string(),
+                display_name: rust_i18n::t!("no_location").to_string(),
+                count: noloc_active_count,
+                depth: 0,
+                has_children: false,
+                is_expanded: false,
+            });
+        }
+
         // duplicate aggregates removed (handled above)
 
         // 5) Clone final results into owned Task structs and compute transient fields.
@@ -3168,6 +4289,7 @@ impl TaskStore {
                         options.default_priority,
                         options.sort_standard_by_priority,
                         options.sort_preset,
+                        options.all_day_sort,
                     );
                     if ordering == std::cmp::Ordering::Less {
                         best = child_eff;
@@ -3212,7 +4334,7 @@ impl TaskStore {
         // Delegate to the hierarchy organizer which handles parent/child relationships,
         // indentation depth, and injecting expand/collapse control items for completed groups.
         // Note: organize_hierarchy applies `compare_for_sort` internally before building the tree.
-        let organized_items = organize_hierarchy(
+        let mut organized_items = organize_hierarchy(
             final_tasks_processed,
             HierarchyOptions {
                 default_priority: options.default_priority,
@@ -3222,15 +4344,23 @@ impl TaskStore {
                 max_done_subtasks: options.max_done_subtasks,
                 search_active: !options.search_term.is_empty(),
                 sort_preset: options.sort_preset,
+                sort_mode: options.sort_mode,
+                all_day_sort: options.all_day_sort,
+                sort_urgency_boost: options.sort_urgency_boost,
                 search_collapsed_tasks: options.search_collapsed_tasks,
                 focused_task_uid: options.focused_task_uid,
             },
         );
 
+        if options.fuzzy_search {
+            rank_by_fuzzy_score(&mut organized_items, options.search_term);
+        }
+
         FilterResult {
             items: organized_items,
             categories: final_categories,
             locations,
+            inbox_count,
         }
     }
 
@@ -3308,6 +4438,114 @@ impl TaskStore {
         actions
     }
 
+    /// Clones every task an undoable intent (delete, complete, move) may touch: the
+    /// targets, their subtrees, and tasks whose parent/dependency/relation links
+    /// point at them. Returns `None` for intents that are not undoable.
+    pub fn snapshot_for_undo(&self, intent: &AppIntent) -> Option<Vec<Task>> {
+        let roots: Vec<&String> = match intent {
+            AppIntent::DeleteTask { uid }
+            | AppIntent::DeleteTaskTree { uid }
+            | AppIntent::ToggleTask { uid }
+            | AppIntent::ToggleTaskShift { uid }
+            | AppIntent::CancelTask { uid }
+            | AppIntent::CompleteTree { uid }
+            | AppIntent::MoveTask { uid, .. } => vec![uid],
+            AppIntent::CompleteTasks { uids } | AppIntent::SetTagOnTasks { uids, .. } => {
+                uids.iter().collect()
+            }
+            _ => return None,
+        };
+
+        let mut uids = HashSet::new();
+        for root in roots {
+            // Ancestors may be updated by `parent_progress`
+            let mut ancestor = self.get_task_ref(root).and_then(|t| t.parent_uid.clone());
+            while let Some(uid) = ancestor {
+                if !uids.insert(uid.clone()) {
+                    break;
+                }
+                ancestor = self.get_task_ref(&uid).and_then(|t| t.parent_uid.clone());
+            }
+            let mut subtree = self.get_descendant_uids(root);
+            subtree.push(root.clone());
+            for uid in subtree {
+                for index in [
+                    &self.blocking_index,
+                    &self.related_from_index,
+                    &self.children_index,
+                ] {
+                    if let Some(linked) = index.get(&uid) {
+                        uids.extend(linked.iter().cloned());
+                    }
+                }
+                uids.insert(uid);
+            }
+        }
+
+        Some(
+            uids.iter()
+                .filter_map(|uid| self.get_task_ref(uid).cloned())
+                .collect(),
+        )
+    }
+
+    /// Same as [`Self::apply_task_intent`], but pushes an [`UndoEntry`] onto `history`
+    /// when the intent is undoable and changed something.
+    pub fn apply_task_intent_with_undo(
+        &mut self,
+        intent: &AppIntent,
+        config: &Config,
+        history: &mut UndoHistory,
+    ) -> Vec<JournalAction> {
+        let snapshot = self.snapshot_for_undo(intent);
+        let actions = self.apply_task_intent(intent, config);
+        if let Some(before) = snapshot {
+            history.record(before, &actions);
+        }
+        actions
+    }
+
+    /// Reverts an [`UndoEntry`]. Tasks created by the original action are removed;
+    /// tasks that left their calendar (deleted to trash, moved) are re-created there
+    /// under their original UID so parent, dependency and relation links resolve again;
+    /// everything else is rewritten in place with its previous content.
+    pub fn undo(&mut self, entry: UndoEntry) -> Vec<JournalAction> {
+        let mut actions = Vec::new();
+
+        for uid in &entry.created {
+            if let Some((removed, _)) = self.delete_task(uid) {
+                actions.push(JournalAction::Delete(removed));
+            }
+        }
+
+        for prev in entry.before {
+            let mut restored = prev;
+            match self.get_task_ref(&restored.uid).cloned() {
+                Some(current) if current.calendar_href == restored.calendar_href => {
+                    restored.href = current.href;
+                    restored.etag = current.etag;
+                    restored.sequence = current.sequence + 1;
+                    self.update_or_add_task(restored.clone());
+                    actions.push(JournalAction::Update(restored));
+                }
+                current => {
+                    if let Some(current) = current
+                        && let Some((removed, _)) = self.delete_task(&current.uid)
+                    {
+                        actions.push(JournalAction::Delete(removed));
+                    }
+                    restored.href = String::new();
+                    restored.etag = String::new();
+                    restored.sequence += 1;
+                    self.update_or_add_task(restored.clone());
+                    actions.push(JournalAction::Create(restored));
+                }
+            }
+        }
+
+        actions
+    }
+
     /// Applies a Task-related AppIntent to the in-memory store and returns the list of
     /// persistence Actions that should be written to the journal/server.
     /// This method ignores Session-related intents (like SetSearchTerm).
@@ -3432,6 +4670,57 @@ impl TaskStore {
                 let updated = self.stop_task(uid);
                 actions.extend(updated.into_iter().map(JournalAction::Update));
             }
+            AppIntent::SetProgress { uid, percent } => {
+                if let Some((primary, secondary, children)) = self.set_progress(uid, *percent) {
+                    if let Some(sec) = secondary {
+                        actions.push(JournalAction::Create(primary));
+                        actions.push(JournalAction::Update(sec));
+                    } else {
+                        actions.push(JournalAction::Update(primary));
+                    }
+                    for c in children {
+                        actions.push(JournalAction::Update(c));
+                    }
+                }
+            }
+            AppIntent::RenameTask { uid, summary } => {
+                if let Some(updated) = self.rename_task(uid, summary) {
+                    actions.push(JournalAction::Update(updated));
+                }
+            }
+            AppIntent::RescheduleDue { uid, when } => {
+                if let Some(updated) = self.reschedule_due(uid, when.as_deref(), config.week_start)
+                {
+                    actions.push(JournalAction::Update(updated));
+                }
+            }
+            AppIntent::SkipOccurrence { uid } => {
+                if let Some(updated) = self.skip_occurrence(uid) {
+                    actions.push(JournalAction::Update(updated));
+                }
+            }
+            AppIntent::RetryUnsynced { uid } => {
+                // An update without an ETag uploads the task whether or not the server has it
+                if let Some(task) = self.get_task_ref(uid)
+                    && task.is_unsynced_remote()
+                    && !self.journal_uids().contains(uid)
+                {
+                    actions.push(JournalAction::Update(task.clone()));
+                }
+            }
+            AppIntent::CompleteTasks { uids } => {
+                for (primary, secondary, children) in self.complete_tasks(uids) {
+                    if let Some(sec) = secondary {
+                        actions.push(JournalAction::Create(primary));
+                        actions.push(JournalAction::Update(sec));
+                    } else {
+                        actions.push(JournalAction::Update(primary));
+                    }
+                    for c in children {
+                        actions.push(JournalAction::Update(c));
+                    }
+                }
+            }
             AppIntent::MoveTask { uid, target_href } => {
                 let safe_target = if target_href == crate::storage::LOCAL_TRASH_HREF
                     || target_href == "local://recovery"
@@ -3459,6 +4748,13 @@ impl TaskStore {
                     }
                 }
             }
+            AppIntent::SetTagOnTasks { uids, tag, add } => {
+                actions.extend(
+                    self.set_tag_on_tasks(uids, tag, *add)
+                        .into_iter()
+                        .map(JournalAction::Update),
+                );
+            }
             AppIntent::DuplicateTaskTree { uid } => {
                 let new_tasks = self.duplicate_task_tree(uid);
                 actions.extend(new_tasks.into_iter().map(JournalAction::Create));
@@ -3474,13 +4770,18 @@ impl TaskStore {
                     actions.push(JournalAction::Update(updated));
                 }
             }
+            AppIntent::PromoteToRoot { uid } => {
+                if let Ok(updated) = self.set_parent(uid, None) {
+                    actions.push(JournalAction::Update(updated));
+                }
+            }
             AppIntent::MakeChild { uid, parent_uid } => {
                 if let Ok(updated) = self.set_parent(uid, Some(parent_uid.clone())) {
                     actions.push(JournalAction::Update(updated));
                 }
             }
             AppIntent::AddDependency { uid, blocker_uid } => {
-                if let Some(updated) = self.add_dependency(uid, blocker_uid.clone()) {
+                if let Ok(Some(updated)) = self.add_dependency(uid, blocker_uid.clone()) {
                     actions.push(JournalAction::Update(updated));
                 }
             }
@@ -3507,6 +4808,14 @@ impl TaskStore {
                     actions.push(JournalAction::Update(updated));
                 }
             }
+            AppIntent::ToggleWaiting { uid } => {
+                if let Some((task, _)) = self.get_task_mut(uid) {
+                    task.waiting = !task.waiting;
+                    task.sequence += 1;
+                    let updated = task.clone();
+                    actions.push(JournalAction::Update(updated));
+                }
+            }
             AppIntent::ToggleTreeCollapse { uid } => {
                 let is_parent = self.children_index.contains_key(uid);
 
@@ -3534,6 +4843,31 @@ impl TaskStore {
             }
             _ => {} // Ignore session intents
         }
+
+        let status_changed: Vec<String> = match intent {
+            AppIntent::ToggleTask { uid }
+            | AppIntent::ToggleTaskShift { uid }
+            | AppIntent::CancelTask { uid }
+            | AppIntent::CompleteTree { uid }
+            | AppIntent::SetProgress { uid, .. } => vec![uid.clone()],
+            AppIntent::CompleteTasks { uids } => uids.clone(),
+            _ => Vec::new(),
+        };
+        if !actions.is_empty() && !status_changed.is_empty() {
+            for (primary, secondary, children) in
+                self.update_parent_progress(&status_changed, config.parent_progress)
+            {
+                if let Some(sec) = secondary {
+                    actions.push(JournalAction::Create(primary));
+                    actions.push(JournalAction::Update(sec));
+                } else {
+                    actions.push(JournalAction::Update(primary));
+                }
+                for c in children {
+                    actions.push(JournalAction::Update(c));
+                }
+            }
+        }
         actions
     }
 }
@@ -3568,9 +4902,11 @@ mod tests {
             rrule: None,
             location: None,
             url: None,
+            attachments: vec![],
             geo: None,
             collapsed,
             pinned: false,
+            waiting: false,
             is_note: false,
             manual_block: false,
             permanent: false,
@@ -3579,7 +4915,9 @@ mod tests {
             sessions: vec![],
             unmapped_properties: vec![],
             sequence: 0,
-            raw_alarms: vec![],
+            raw_subcomponents: vec![],
+            created: None,
+            completed: None,
             raw_components: vec![],
             create_event: None,
             goal: None,
@@ -3625,6 +4963,9 @@ mod tests {
                 max_done_subtasks: 10,
                 search_active: false,
                 sort_preset: crate::config::SortPreset::UrgentStartedDue,
+                sort_mode: crate::config::SortMode::default(),
+                all_day_sort: crate::config::AllDaySort::default(),
+                sort_urgency_boost: false,
                 search_collapsed_tasks: &HashSet::new(),
                 focused_task_uid: None,
             },
@@ -3675,6 +5016,9 @@ mod tests {
                 max_done_subtasks: 1, // max_done_subtasks = 1, so only 1 done child shown, 1 hidden
                 search_active: false,
                 sort_preset: crate::config::SortPreset::UrgentStartedDue,
+                sort_mode: crate::config::SortMode::default(),
+                all_day_sort: crate::config::AllDaySort::default(),
+                sort_urgency_boost: false,
                 search_collapsed_tasks: &HashSet::new(),
                 focused_task_uid: None,
             },
@@ -3726,6 +5070,9 @@ mod tests {
                 max_done_subtasks: 10,
                 search_active: false,
                 sort_preset: crate::config::SortPreset::UrgentStartedDue,
+                sort_mode: crate::config::SortMode::default(),
+                all_day_sort: crate::config::AllDaySort::default(),
+                sort_urgency_boost: false,
                 search_collapsed_tasks: &HashSet::new(),
                 focused_task_uid: None,
             },
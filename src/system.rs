@@ -414,12 +414,22 @@ impl keyring_core::api::CredentialApi for Oo7Cred {
 /// returns: Sender to update the task list or change state.
 pub fn spawn_alarm_actor(
     ui_sender: Option<mpsc::Sender<AlarmMessage>>,
+) -> mpsc::Sender<SystemEvent> {
+    // Load config once at startup using a fresh standard context (no global state)
+    spawn_alarm_actor_with(&StandardContext::new(None), ui_sender, true)
+}
+
+/// Spawns the alarm manager with an explicit context. With `desktop_notifications`
+/// off, no notification is shown here: every due alarm (implicit ones included) is
+/// sent as `AlarmMessage::Fire` and the UI posts it itself (used by mobile).
+pub fn spawn_alarm_actor_with(
+    ctx: &dyn AppContext,
+    ui_sender: Option<mpsc::Sender<AlarmMessage>>,
+    desktop_notifications: bool,
 ) -> mpsc::Sender<SystemEvent> {
     let (tx, mut rx) = mpsc::channel(100);
 
-    // Load config once at startup using a fresh standard context (no global state)
-    let ctx = StandardContext::new(None);
-    let config = Config::load(&ctx).unwrap_or_default();
+    let config = Config::load(ctx).unwrap_or_default();
 
     // Parse default time (e.g., "08:00")
     let default_time = NaiveTime::parse_from_str(&config.default_reminder_time, "%H:%M")
@@ -481,6 +491,8 @@ pub fn spawn_alarm_actor(
                                     acknowledged: None,
                                     related_to_uid: None,
                                     relation_type: None,
+                                    is_default: false,
+                                    extra_lines: Vec::new(),
                                 };
                                 check_list.push((implicit_alarm, true));
                             }
@@ -502,6 +514,8 @@ pub fn spawn_alarm_actor(
                                     acknowledged: None,
                                     related_to_uid: None,
                                     relation_type: None,
+                                    is_default: false,
+                                    extra_lines: Vec::new(),
                                 };
                                 check_list.push((implicit_alarm, true));
                             }
@@ -588,11 +602,16 @@ pub fn spawn_alarm_actor(
                 for (task, alarm, is_implicit, history_key) in ready_to_fire {
                     fired_history.insert(history_key.clone(), now.timestamp());
 
-                    if !is_implicit && let Some(ui_tx) = &ui_sender {
+                    if (!is_implicit || !desktop_notifications)
+                        && let Some(ui_tx) = &ui_sender
+                    {
                         let _ = ui_tx
                             .send(AlarmMessage::Fire(task.uid.clone(), alarm.uid.clone()))
                             .await;
                     }
+                    if !desktop_notifications {
+                        continue;
+                    }
 
                     let summary = task.summary.clone();
                     let body = alarm
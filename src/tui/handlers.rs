@@ -16,7 +16,7 @@ use crate::system::SystemEvent;
 use crate::tui::action::{Action, AppEvent, SidebarMode};
 use crate::tui::state::{AppState, Focus, InputMode};
 use chrono::NaiveTime;
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use std::collections::HashMap;
 use tokio::sync::mpsc::Sender;
 
@@ -58,6 +58,7 @@ fn get_available_actions(state: &AppState, task: &Task) -> Vec<crate::config::Ta
                     | crate::config::TaskAction::StopTimer
                     | crate::config::TaskAction::AddSession
                     | crate::config::TaskAction::CompleteAndShift
+                    | crate::config::TaskAction::SkipOccurrence
             )
         {
             continue;
@@ -80,8 +81,14 @@ fn get_available_actions(state: &AppState, task: &Task) -> Vec<crate::config::Ta
             TaskAction::CompleteAndShift => {
                 task.rrule.is_some() && !is_done_or_cancelled && !task.is_relative_recurrence()
             }
+            TaskAction::SkipOccurrence => {
+                task.rrule.is_some()
+                    && !is_done_or_cancelled
+                    && (task.dtstart.is_some() || task.due.is_some())
+            }
             TaskAction::EditTree => true,
             TaskAction::TogglePin => true,
+            TaskAction::ToggleWaiting => task.status == crate::model::TaskStatus::NeedsAction,
             TaskAction::Promote => task.parent_uid.is_some(),
             TaskAction::Yank => state.yanked_uid.is_none(),
             TaskAction::StopTimer => {
@@ -114,6 +121,7 @@ fn update_action_menu_filter(state: &mut AppState) {
             let matches_alias = match a {
                 ToggleDetails => filter == "l" || filter == "details",
                 CompleteAndShift => filter == "r" || filter == "rep" || filter == "repeat",
+                SkipOccurrence => filter == "skip" || filter == "next",
                 ToggleTimer => filter == "s" || filter == "start" || filter == "pause",
                 StopTimer => filter == "stop",
                 AddSession => filter == "t" || filter == "log",
@@ -123,6 +131,7 @@ fn update_action_menu_filter(state: &mut AppState) {
                 EditTree => filter == "tree" || filter == "edit",
                 Yank => filter == "y" || filter == "copy",
                 TogglePin => filter == "p" || filter == "pin",
+                ToggleWaiting => filter == "w" || filter == "wait",
                 CreateSubtask => filter == "c" || filter == "sub",
                 DuplicateTree => filter == "d" || filter == "dup",
                 CompleteTree => filter == "tree" || filter == "complete",
@@ -154,6 +163,39 @@ fn open_action_menu(state: &mut AppState) {
     }
 }
 
+/// Mouse input: the wheel moves the selection in every mode. In the main view a left
+/// click selects the row under the pointer, and a right click selects it and opens the
+/// action menu; other modes (popups, editors) ignore clicks.
+pub fn handle_mouse_event(mouse: MouseEvent, state: &mut AppState) {
+    match mouse.kind {
+        MouseEventKind::ScrollDown => state.next(),
+        MouseEventKind::ScrollUp => state.previous(),
+        MouseEventKind::Down(button @ (MouseButton::Left | MouseButton::Right))
+            if state.mode == InputMode::Normal =>
+        {
+            let Some(idx) = state
+                .task_row_rects
+                .iter()
+                .find(|(_, rect)| {
+                    rect.contains(ratatui::layout::Position::new(mouse.column, mouse.row))
+                })
+                .map(|(idx, _)| *idx)
+            else {
+                return;
+            };
+            state.active_focus = Focus::Main;
+            if state.list_state.selected() != Some(idx) {
+                state.list_state.select(Some(idx));
+                state.details_scroll = 0;
+            }
+            if button == MouseButton::Right {
+                open_action_menu(state);
+            }
+        }
+        _ => {}
+    }
+}
+
 async fn execute_task_action(
     state: &mut AppState,
     action: crate::config::TaskAction,
@@ -191,7 +233,8 @@ async fn execute_task_action(
             if let Some(geo) = &task.geo {
                 #[cfg(not(target_os = "android"))]
                 {
-                    let target_url = format!("geo:{}", geo);
+                    let target_url =
+                        crate::model::parser::geo_to_map_url(geo, &state.map_url_template);
                     std::thread::spawn(move || {
                         #[cfg(target_os = "linux")]
                         let _ = std::process::Command::new("xdg-open")
@@ -259,6 +302,9 @@ async fn execute_task_action(
         CompleteAndShift => {
             intent = Some(AppIntent::ToggleTaskShift { uid });
         }
+        SkipOccurrence => {
+            intent = Some(AppIntent::SkipOccurrence { uid });
+        }
         ToggleTimer => {
             if task.status == crate::model::TaskStatus::InProcess {
                 intent = Some(AppIntent::PauseTask { uid });
@@ -308,7 +354,7 @@ async fn execute_task_action(
                             &new_desc,
                             &state.tag_aliases,
                             def_time,
-                            config.trash_retention_days,
+                            &config,
                             &state.calendars,
                         ) {
                             Ok(actions) => {
@@ -361,6 +407,9 @@ async fn execute_task_action(
         TogglePin => {
             intent = Some(AppIntent::TogglePin { uid });
         }
+        ToggleWaiting => {
+            intent = Some(AppIntent::ToggleWaiting { uid });
+        }
         CreateSubtask => {
             let mut initial_input = String::new();
             for cat in &task.categories {
@@ -420,8 +469,11 @@ async fn execute_task_action(
     }
 
     if let Some(i) = intent {
-        let actions = state.store.apply_task_intent(&i, &config);
+        let actions = state.apply_task_intent(&i, &config);
         state.refresh_filtered_view();
+        if matches!(i, AppIntent::DuplicateTaskTree { .. }) {
+            edit_created_copy(state, &actions);
+        }
         if !actions.is_empty() {
             let tx = action_tx.clone();
             tokio::spawn(async move {
@@ -431,6 +483,25 @@ async fn execute_task_action(
     }
 }
 
+/// Selects the copy made by a duplicate action and opens it in the editor.
+fn edit_created_copy(state: &mut AppState, actions: &[crate::journal::Action]) {
+    let Some(new_uid) = actions.iter().find_map(|a| match a {
+        crate::journal::Action::Create(t) => Some(t.uid.clone()),
+        _ => None,
+    }) else {
+        return;
+    };
+    if let Some(idx) = state.find_task_index_by_uid(&new_uid) {
+        state.list_state.select(Some(idx));
+    }
+    if let Some(task) = state.store.get_task_ref(&new_uid) {
+        state.input_buffer = task.to_smart_string();
+        state.cursor_position = state.input_buffer.chars().count();
+        state.editing_uid = Some(new_uid);
+        state.mode = InputMode::Editing;
+    }
+}
+
 fn run_external_editor(
     initial_content: &str,
     ctx: &dyn crate::context::AppContext,
@@ -505,6 +576,56 @@ fn run_external_editor(
     Ok(None)
 }
 
+/// Strips inline `:=` goal and alias definitions from `input`, applying them the way the
+/// GUI does: aliases are validated, stored, expanded onto existing tasks and saved to config.
+/// Returns the remaining text for `Task::new`/`apply_smart_input`, or an error message.
+fn absorb_inline_definitions(
+    state: &mut AppState,
+    input: &str,
+    action_tx: &Sender<Action>,
+) -> Result<String, String> {
+    let (clean_input_1, new_goals) = crate::model::parser::extract_inline_goals(input);
+    let (clean_input, new_aliases): (String, HashMap<String, Vec<String>>) =
+        extract_inline_aliases(&clean_input_1);
+
+    let mut config_changed = false;
+
+    if !new_goals.is_empty() {
+        for (k, v) in new_goals {
+            state.goals.insert(k, v);
+        }
+        config_changed = true;
+    }
+
+    for (key, tags) in &new_aliases {
+        if let Err(e) = validate_alias_integrity(key, tags, &state.tag_aliases) {
+            return Err(rust_i18n::t!("error_general", error = e.to_string()).to_string());
+        }
+
+        state.tag_aliases.insert(key.clone(), tags.clone());
+        let modified = state.store.apply_alias_retroactively(key, tags);
+        if !modified.is_empty() {
+            let _ = action_tx.try_send(Action::PersistBatch(
+                modified
+                    .into_iter()
+                    .map(crate::journal::Action::Update)
+                    .collect(),
+            ));
+        }
+        config_changed = true;
+    }
+
+    if config_changed && let Ok(mut cfg) = Config::load(state.ctx.as_ref()) {
+        let old = cfg.clone();
+        cfg.tag_aliases = state.tag_aliases.clone();
+        cfg.goals = state.goals.clone();
+        cfg.update_sync_timestamp_if_changed(&old);
+        let _ = cfg.save(state.ctx.as_ref());
+    }
+
+    Ok(clean_input)
+}
+
 fn save_description(state: &mut AppState, action_tx: &Sender<Action>) {
     if let InputMode::EditingTree(ref uid) = state.mode {
         let uid = uid.clone();
@@ -516,7 +637,7 @@ fn save_description(state: &mut AppState, action_tx: &Sender<Action>) {
             &state.input_buffer,
             &state.tag_aliases,
             def_time,
-            config.trash_retention_days,
+            &config,
             &state.calendars,
         ) {
             Ok(actions) => {
@@ -536,40 +657,26 @@ fn save_description(state: &mut AppState, action_tx: &Sender<Action>) {
         let desc_text = state.input_buffer.clone();
         let (clean_desc, extracted) = crate::model::extractor::extract_markdown_tasks(&desc_text);
 
-        let (clean_input_1, new_goals) =
-            crate::model::parser::extract_inline_goals(&state.new_task_title);
-        let (clean_input, new_aliases) =
-            crate::model::parser::extract_inline_aliases(&clean_input_1);
-
-        let mut config_changed = false;
-
-        if !new_goals.is_empty() {
-            for (k, v) in new_goals {
-                state.goals.insert(k, v);
-            }
-            config_changed = true;
-        }
-
-        if !new_aliases.is_empty() {
-            for (k, v) in &new_aliases {
-                state.tag_aliases.insert(k.clone(), v.clone());
+        let title = state.new_task_title.clone();
+        let clean_input = match absorb_inline_definitions(state, &title, action_tx) {
+            Ok(clean) => clean,
+            Err(e) => {
+                state.message = e;
+                return;
             }
-            config_changed = true;
-        }
-
-        if config_changed && let Ok(mut cfg) = Config::load(state.ctx.as_ref()) {
-            let old = cfg.clone();
-            cfg.tag_aliases = state.tag_aliases.clone();
-            cfg.goals = state.goals.clone();
-            cfg.update_sync_timestamp_if_changed(&old);
-            let _ = cfg.save(state.ctx.as_ref());
-        }
+        };
 
         let config = Config::load(state.ctx.as_ref()).unwrap_or_default();
         let def_time =
             chrono::NaiveTime::parse_from_str(&config.default_reminder_time, "%H:%M").ok();
 
-        let mut parent = Task::new(&clean_input, &state.tag_aliases, def_time);
+        let mut parent = Task::new_with_date_settings(
+            &clean_input,
+            &state.tag_aliases,
+            def_time,
+            &config.holidays,
+            config.week_start,
+        );
 
         if let Err(e) = state.store.resolve_dependencies(&mut parent) {
             state.message = e;
@@ -589,8 +696,11 @@ fn save_description(state: &mut AppState, action_tx: &Sender<Action>) {
         parent.parent_uid = state.creating_child_of.clone();
 
         let Some(target_href) = state
-            .active_cal_href
-            .clone()
+            .creating_child_of
+            .as_deref()
+            .and_then(|p| state.store.get_task_ref(p))
+            .map(|p| p.calendar_href.clone())
+            .or_else(|| state.active_cal_href.clone())
             .filter(|href| state.local_mode_enabled || !href.starts_with("local://"))
             .or_else(|| {
                 state
@@ -612,6 +722,7 @@ fn save_description(state: &mut AppState, action_tx: &Sender<Action>) {
             return;
         };
         parent.calendar_href = target_href.clone();
+        parent.apply_default_reminder(config.default_reminder_minutes_for(&parent.calendar_href));
 
         let parent_uid = parent.uid.clone();
 
@@ -639,7 +750,13 @@ fn save_description(state: &mut AppState, action_tx: &Sender<Action>) {
         });
 
         for ext in extracted {
-            let mut sub = Task::new(&ext.raw_text, &state.tag_aliases, def_time);
+            let mut sub = Task::new_with_date_settings(
+                &ext.raw_text,
+                &state.tag_aliases,
+                def_time,
+                &config.holidays,
+                config.week_start,
+            );
             sub.uid = ext.uid;
 
             let p_uid_str = ext.parent_uid.clone().unwrap_or_else(|| parent_uid.clone());
@@ -756,7 +873,13 @@ fn save_description(state: &mut AppState, action_tx: &Sender<Action>) {
             }
 
             for ext in extracted {
-                let mut sub = Task::new(&ext.raw_text, &state.tag_aliases, def_time);
+                let mut sub = Task::new_with_date_settings(
+                    &ext.raw_text,
+                    &state.tag_aliases,
+                    def_time,
+                    &config.holidays,
+                    config.week_start,
+                );
                 sub.uid = ext.uid;
 
                 let p_uid_str = ext.parent_uid.clone().unwrap_or_else(|| uid.clone());
@@ -840,6 +963,9 @@ pub fn handle_app_event(state: &mut AppState, event: AppEvent, default_cal: &Opt
             if !state.local_mode_enabled {
                 cals.retain(|c| !c.href.starts_with("local://"));
             }
+            if let Ok(cfg) = Config::load(state.ctx.as_ref()) {
+                cfg.apply_calendar_overrides(&mut cals);
+            }
             state.calendars = cals; // let refresh_filtered_view handle sorting
 
             if let Some(def) = default_cal
@@ -902,6 +1028,7 @@ pub fn handle_app_event(state: &mut AppState, event: AppEvent, default_cal: &Opt
                 state.sidebar_mode = SidebarMode::Calendars;
             }
             state.hide_completed = cfg.hide_completed;
+            state.hide_deferred = cfg.hide_deferred;
             state.hide_fully_completed_tags = cfg.hide_fully_completed_tags;
             state.hide_aliases_in_sidebar = cfg.hide_aliases_in_sidebar;
             state.show_inline_descriptions = cfg.show_inline_descriptions;
@@ -912,12 +1039,17 @@ pub fn handle_app_event(state: &mut AppState, event: AppEvent, default_cal: &Opt
             state.default_priority = cfg.default_priority;
             state.start_grace_period_days = cfg.start_grace_period_days;
             state.snooze_short_mins = cfg.snooze_short_mins;
+            state.map_url_template = cfg.map_url_template.clone();
             state.snooze_long_mins = cfg.snooze_long_mins;
             state.show_priority_numbers = cfg.show_priority_numbers;
             state.quick_filter_term = cfg.quick_filter_term.clone();
             state.quick_filter_icon = cfg.quick_filter_icon.clone();
             state.show_quick_filter = cfg.show_quick_filter;
             state.theme = cfg.theme;
+            state.custom_theme_background = cfg.custom_theme_background.clone();
+            state.date_format = cfg.date_format;
+            state.time_format = cfg.time_format;
+            state.tag_color_mode = cfg.tag_color_mode;
             state.refresh_filtered_view();
         }
     }
@@ -936,6 +1068,23 @@ pub fn handle_app_event(state: &mut AppState, event: AppEvent, default_cal: &Opt
     }
 }
 
+/// Alt-key bindings that reschedule the selected task: (key, smart date expression).
+/// `None` clears the due date. Expressions go through `parse_smart_date`.
+const RESCHEDULE_BINDINGS: &[(char, Option<&str>)] =
+    &[('t', Some("tomorrow")), ('w', Some("1w")), ('d', None)];
+
+fn is_reschedule_key(c: char) -> bool {
+    RESCHEDULE_BINDINGS.iter().any(|(k, _)| *k == c)
+}
+
+fn reschedule_intent(c: char, state: &AppState) -> Option<AppIntent> {
+    let (_, when) = RESCHEDULE_BINDINGS.iter().find(|(k, _)| *k == c)?;
+    Some(AppIntent::RescheduleDue {
+        uid: state.get_selected_task()?.uid.clone(),
+        when: when.map(str::to_string),
+    })
+}
+
 // Helper to notify the alarm system of local changes immediately
 fn update_alarms(state: &AppState) {
     if let Some(tx) = &state.alarm_actor_tx {
@@ -973,7 +1122,7 @@ pub async fn handle_key_event(
                         // Push update via centralized logic
                         let config = Config::load(state.ctx.as_ref()).unwrap_or_default();
                         let intent = AppIntent::ToggleTask { uid: uid.clone() };
-                        let actions = state.store.apply_task_intent(&intent, &config);
+                        let actions = state.apply_task_intent(&intent, &config);
                         // Push update to alarm actor
                         update_alarms(state);
 
@@ -995,7 +1144,7 @@ pub async fn handle_key_event(
                         // Push update via centralized logic
                         let config = Config::load(state.ctx.as_ref()).unwrap_or_default();
                         let intent = AppIntent::ToggleTask { uid: uid.clone() };
-                        let actions = state.store.apply_task_intent(&intent, &config);
+                        let actions = state.apply_task_intent(&intent, &config);
                         update_alarms(state);
 
                         let tx = action_tx.clone();
@@ -1016,7 +1165,7 @@ pub async fn handle_key_event(
                         // Push update via centralized logic
                         let config = Config::load(state.ctx.as_ref()).unwrap_or_default();
                         let intent = AppIntent::ToggleTask { uid: uid.clone() };
-                        let actions = state.store.apply_task_intent(&intent, &config);
+                        let actions = state.apply_task_intent(&intent, &config);
                         update_alarms(state);
 
                         let tx = action_tx.clone();
@@ -1037,7 +1186,7 @@ pub async fn handle_key_event(
                         // Compute changes via centralized logic
                         let config = Config::load(state.ctx.as_ref()).unwrap_or_default();
                         let intent = AppIntent::ToggleTask { uid: uid.clone() };
-                        let actions = state.store.apply_task_intent(&intent, &config);
+                        let actions = state.apply_task_intent(&intent, &config);
                         state.refresh_filtered_view();
                         update_alarms(state);
 
@@ -1058,7 +1207,7 @@ pub async fn handle_key_event(
                         // Apply cancel logic via centralized logic
                         let config = Config::load(state.ctx.as_ref()).unwrap_or_default();
                         let intent = AppIntent::CancelTask { uid: uid.clone() };
-                        let actions = state.store.apply_task_intent(&intent, &config);
+                        let actions = state.apply_task_intent(&intent, &config);
                         state.refresh_filtered_view();
                         update_alarms(state);
 
@@ -1087,6 +1236,13 @@ pub async fn handle_key_event(
         state.cursor_position = char_count;
     }
 
+    // User `[keybindings]` apply to the main view only; text inputs and popups keep their keys
+    let key = if state.mode == InputMode::Normal {
+        state.keymap.translate(key)?
+    } else {
+        key
+    };
+
     match state.mode {
         InputMode::Creating => match key.code {
             // NEW: Enter description mode during creation (Ctrl+E)
@@ -1146,47 +1302,14 @@ pub async fn handle_key_event(
                     }
                 }
 
-                let (clean_input_1, new_goals) =
-                    crate::model::parser::extract_inline_goals(&state.input_buffer);
-                let (clean_input, new_aliases): (String, HashMap<String, Vec<String>>) =
-                    extract_inline_aliases(&clean_input_1);
-
-                let mut config_changed = false;
-
-                if !new_goals.is_empty() {
-                    for (k, v) in new_goals {
-                        state.goals.insert(k, v);
-                    }
-                    config_changed = true;
-                }
-
-                if !new_aliases.is_empty() {
-                    for (key, tags) in &new_aliases {
-                        if let Err(e) = validate_alias_integrity(key, tags, &state.tag_aliases) {
-                            state.message =
-                                rust_i18n::t!("error_general", error = e.to_string()).to_string();
-                            return None;
-                        }
-
-                        state.tag_aliases.insert(key.clone(), tags.clone());
-                        let modified = state.store.apply_alias_retroactively(key, tags);
-
-                        for t in modified {
-                            let _ = action_tx.try_send(Action::PersistBatch(vec![
-                                crate::journal::Action::Update(t),
-                            ]));
-                        }
-                        config_changed = true;
+                let input = state.input_buffer.clone();
+                let clean_input = match absorb_inline_definitions(state, &input, action_tx) {
+                    Ok(clean) => clean,
+                    Err(e) => {
+                        state.message = e;
+                        return None;
                     }
-                }
-
-                if config_changed && let Ok(mut cfg) = Config::load(state.ctx.as_ref()) {
-                    let old = cfg.clone();
-                    cfg.tag_aliases = state.tag_aliases.clone();
-                    cfg.goals = state.goals.clone();
-                    cfg.update_sync_timestamp_if_changed(&old);
-                    let _ = cfg.save(state.ctx.as_ref());
-                }
+                };
 
                 let trimmed = clean_input.trim();
                 let is_alias_only = trimmed.is_empty()
@@ -1200,9 +1323,13 @@ pub async fn handle_key_event(
                     return None;
                 }
 
+                // A new child lives next to its parent, whichever calendar is active
                 let target_href = state
-                    .active_cal_href
-                    .clone()
+                    .creating_child_of
+                    .as_deref()
+                    .and_then(|p| state.store.get_task_ref(p))
+                    .map(|p| p.calendar_href.clone())
+                    .or_else(|| state.active_cal_href.clone())
                     .filter(|href| state.local_mode_enabled || !href.starts_with("local://"))
                     .or_else(|| {
                         state
@@ -1217,7 +1344,15 @@ pub async fn handle_key_event(
                     let def_time =
                         NaiveTime::parse_from_str(&config.default_reminder_time, "%H:%M").ok();
 
-                    let mut task = Task::new(&clean_input, &state.tag_aliases, def_time);
+                    let mut task = Task::new_with_date_settings(
+                        &clean_input,
+                        &state.tag_aliases,
+                        def_time,
+                        &config.holidays,
+                        config.week_start,
+                    );
+                    let unrecognized =
+                        crate::model::parser::unrecognized_directives(&clean_input, &task.summary);
 
                     if let Err(e) = state.store.resolve_dependencies(&mut task) {
                         state.message = e;
@@ -1245,6 +1380,9 @@ pub async fn handle_key_event(
                             &task.calendar_href,
                         );
                     }
+                    task.apply_default_reminder(
+                        config.default_reminder_minutes_for(&task.calendar_href),
+                    );
 
                     let new_uid = task.uid.clone();
                     state.store.add_task(task.clone());
@@ -1258,6 +1396,11 @@ pub async fn handle_key_event(
                     state.mode = InputMode::Normal;
                     state.reset_input();
                     state.creating_child_of = None;
+                    if !unrecognized.is_empty() {
+                        state.message =
+                            t!("unrecognized_tokens_kept", tokens = unrecognized.join(" "))
+                                .to_string();
+                    }
                     return Some(Action::PersistBatch(vec![crate::journal::Action::Create(
                         task,
                     )]));
@@ -1285,46 +1428,14 @@ pub async fn handle_key_event(
         },
         InputMode::Editing => match key.code {
             KeyCode::Enter => {
-                let (clean_input_1, new_goals) =
-                    crate::model::parser::extract_inline_goals(&state.input_buffer);
-                let (clean_input, new_aliases): (String, HashMap<String, Vec<String>>) =
-                    extract_inline_aliases(&clean_input_1);
-
-                let mut config_changed = false;
-
-                if !new_goals.is_empty() {
-                    for (k, v) in new_goals {
-                        state.goals.insert(k, v);
-                    }
-                    config_changed = true;
-                }
-
-                if !new_aliases.is_empty() {
-                    for (k, v) in new_aliases {
-                        if let Err(e) = validate_alias_integrity(&k, &v, &state.tag_aliases) {
-                            state.message =
-                                rust_i18n::t!("error_general", error = e.to_string()).to_string();
-                            return None;
-                        }
-
-                        state.tag_aliases.insert(k.clone(), v.clone());
-                        let modified = state.store.apply_alias_retroactively(&k, &v);
-                        for mod_t in modified {
-                            let _ = action_tx.try_send(Action::PersistBatch(vec![
-                                crate::journal::Action::Update(mod_t),
-                            ]));
-                        }
-                        config_changed = true;
+                let input = state.input_buffer.clone();
+                let clean_input = match absorb_inline_definitions(state, &input, action_tx) {
+                    Ok(clean) => clean,
+                    Err(e) => {
+                        state.message = e;
+                        return None;
                     }
-                }
-
-                if config_changed && let Ok(mut cfg) = Config::load(state.ctx.as_ref()) {
-                    let old = cfg.clone();
-                    cfg.tag_aliases = state.tag_aliases.clone();
-                    cfg.goals = state.goals.clone();
-                    cfg.update_sync_timestamp_if_changed(&old);
-                    let _ = cfg.save(state.ctx.as_ref());
-                }
+                };
 
                 let target_uid: Option<String> = state.editing_uid.clone();
 
@@ -1335,7 +1446,13 @@ pub async fn handle_key_event(
                     let config = Config::load(state.ctx.as_ref()).unwrap_or_default();
                     let def_time =
                         NaiveTime::parse_from_str(&config.default_reminder_time, "%H:%M").ok();
-                    t.apply_smart_input(&clean_input, &state.tag_aliases, def_time);
+                    let unrecognized = t.apply_smart_input_with_diagnostics(
+                        &clean_input,
+                        &state.tag_aliases,
+                        def_time,
+                        &config.holidays,
+                        config.week_start,
+                    );
 
                     if let Err(e) = state.store.resolve_dependencies(&mut t) {
                         state.message = e;
@@ -1349,6 +1466,7 @@ pub async fn handle_key_event(
                             &t.calendar_href,
                         );
                     }
+                    t.apply_default_reminder(config.default_reminder_minutes_for(&t.calendar_href));
                     t.sequence += 1;
                     let clone = t.clone();
                     state.store.update_or_add_task(t);
@@ -1356,6 +1474,11 @@ pub async fn handle_key_event(
                     update_alarms(state);
                     state.mode = InputMode::Normal;
                     state.reset_input();
+                    if !unrecognized.is_empty() {
+                        state.message =
+                            t!("unrecognized_tokens_kept", tokens = unrecognized.join(" "))
+                                .to_string();
+                    }
                     let _ = action_tx.try_send(Action::PersistBatch(vec![
                         crate::journal::Action::Update(clone),
                     ]));
@@ -1559,7 +1682,7 @@ pub async fn handle_key_event(
                         // Push update via centralized logic
                         let config = Config::load(state.ctx.as_ref()).unwrap_or_default();
                         let intent = AppIntent::ToggleTask { uid: uid.clone() };
-                        let actions = state.store.apply_task_intent(&intent, &config);
+                        let actions = state.apply_task_intent(&intent, &config);
                         update_alarms(state);
 
                         let tx = action_tx.clone();
@@ -1773,6 +1896,23 @@ pub async fn handle_key_event(
             _ => {}
         },
         InputMode::Normal => match key.code {
+            KeyCode::Char(c)
+                if key.modifiers.contains(KeyModifiers::ALT) && is_reschedule_key(c) =>
+            {
+                if let Some(intent) = reschedule_intent(c, state) {
+                    let config = Config::load(state.ctx.as_ref()).unwrap_or_default();
+                    let actions = state.apply_task_intent(&intent, &config);
+                    state.refresh_filtered_view();
+                    update_alarms(state);
+                    if !actions.is_empty() {
+                        state.message = rust_i18n::t!("due_rescheduled").to_string();
+                        let tx = action_tx.clone();
+                        tokio::spawn(async move {
+                            let _ = tx.send(Action::PersistBatch(actions)).await;
+                        });
+                    }
+                }
+            }
             KeyCode::Char('n') | KeyCode::Char('N')
                 if key.modifiers.contains(KeyModifiers::CONTROL) =>
             {
@@ -1802,7 +1942,7 @@ pub async fn handle_key_event(
                                     &new_desc,
                                     &state.tag_aliases,
                                     def_time,
-                                    config.trash_retention_days,
+                                    &config,
                                     &state.calendars,
                                 ) {
                                     Ok(actions) => {
@@ -1866,25 +2006,26 @@ pub async fn handle_key_event(
             KeyCode::Up if key.modifiers.contains(KeyModifiers::SHIFT) => {
                 state.details_scroll = state.details_scroll.saturating_sub(1);
             }
+            KeyCode::Char('i') => {
+                state.show_details_pane = !state.show_details_pane;
+                state.details_scroll = 0;
+            }
             KeyCode::Char('?') => {
                 state.mode = InputMode::Help(crate::help::HelpTab::Shortcuts);
                 state.edit_scroll_offset = 0;
             }
             KeyCode::Char('w') => {
-                if state.active_search_query.contains(&state.quick_filter_term) {
-                    state.active_search_query = state
-                        .active_search_query
-                        .replace(&state.quick_filter_term, "")
-                        .trim()
-                        .to_string();
-                } else {
-                    if state.active_search_query.is_empty() {
-                        state.active_search_query = state.quick_filter_term.clone();
-                    } else {
-                        state.active_search_query =
-                            format!("{} {}", state.quick_filter_term, state.active_search_query);
-                    }
-                }
+                state.active_search_query = crate::model::matcher::toggle_search_token(
+                    &state.active_search_query,
+                    &state.quick_filter_term,
+                );
+                state.refresh_filtered_view();
+            }
+            KeyCode::Char('F') => {
+                state.active_search_query = crate::model::matcher::toggle_search_token(
+                    &state.active_search_query,
+                    "is:focus",
+                );
                 state.refresh_filtered_view();
             }
             KeyCode::Char('q') => return Some(Action::Quit),
@@ -1985,7 +2126,7 @@ pub async fn handle_key_event(
                             AppIntent::ToggleTask { uid: uid.clone() }
                         };
 
-                        let actions = state.store.apply_task_intent(&intent, &config);
+                        let actions = state.apply_task_intent(&intent, &config);
                         state.refresh_filtered_view();
                         update_alarms(state);
 
@@ -2027,7 +2168,7 @@ pub async fn handle_key_event(
                         AppIntent::StartTask { uid: uid.clone() }
                     };
 
-                    let actions = state.store.apply_task_intent(&intent, &config);
+                    let actions = state.apply_task_intent(&intent, &config);
                     if !actions.is_empty() {
                         state.refresh_filtered_view();
                         let tx = action_tx.clone();
@@ -2043,7 +2184,7 @@ pub async fn handle_key_event(
                     let config = Config::load(state.ctx.as_ref()).unwrap_or_default();
                     let intent = AppIntent::StopTask { uid: uid.clone() };
 
-                    let actions = state.store.apply_task_intent(&intent, &config);
+                    let actions = state.apply_task_intent(&intent, &config);
                     if !actions.is_empty() {
                         state.refresh_filtered_view();
                         let tx = action_tx.clone();
@@ -2060,7 +2201,7 @@ pub async fn handle_key_event(
                         let config = Config::load(state.ctx.as_ref()).unwrap_or_default();
                         let intent = AppIntent::CancelTask { uid: uid.clone() };
 
-                        let actions = state.store.apply_task_intent(&intent, &config);
+                        let actions = state.apply_task_intent(&intent, &config);
                         state.refresh_filtered_view();
                         update_alarms(state);
 
@@ -2097,7 +2238,7 @@ pub async fn handle_key_event(
                         delta: 1,
                     };
 
-                    let actions = state.store.apply_task_intent(&intent, &config);
+                    let actions = state.apply_task_intent(&intent, &config);
                     state.refresh_filtered_view();
                     if !actions.is_empty() {
                         let tx = action_tx.clone();
@@ -2115,7 +2256,7 @@ pub async fn handle_key_event(
                         delta: -1,
                     };
 
-                    let actions = state.store.apply_task_intent(&intent, &config);
+                    let actions = state.apply_task_intent(&intent, &config);
                     state.refresh_filtered_view();
                     if !actions.is_empty() {
                         let tx = action_tx.clone();
@@ -2135,7 +2276,7 @@ pub async fn handle_key_event(
                             AppIntent::DeleteTask { uid: uid.clone() }
                         };
 
-                        let actions = state.store.apply_task_intent(&intent, &config);
+                        let actions = state.apply_task_intent(&intent, &config);
                         state.refresh_filtered_view();
                         update_alarms(state);
 
@@ -2180,7 +2321,7 @@ pub async fn handle_key_event(
                         parent_uid: parent_uid.clone(),
                     };
 
-                    let actions = state.store.apply_task_intent(&intent, &config);
+                    let actions = state.apply_task_intent(&intent, &config);
                     if !state.yank_lock_active {
                         state.yanked_uid = None;
                     }
@@ -2228,8 +2369,9 @@ pub async fn handle_key_event(
                     let config = Config::load(state.ctx.as_ref()).unwrap_or_default();
                     let intent = AppIntent::DuplicateTaskTree { uid: uid.clone() };
 
-                    let actions = state.store.apply_task_intent(&intent, &config);
+                    let actions = state.apply_task_intent(&intent, &config);
                     state.refresh_filtered_view();
+                    edit_created_copy(state, &actions);
                     if !actions.is_empty() {
                         let tx = action_tx.clone();
                         tokio::spawn(async move {
@@ -2325,7 +2467,8 @@ pub async fn handle_key_event(
                     } else if let Some(_geo) = &task.geo {
                         #[cfg(not(target_os = "android"))]
                         {
-                            let target_url = format!("geo:{}", _geo);
+                            let target_url =
+                                crate::model::parser::geo_to_map_url(_geo, &state.map_url_template);
                             std::thread::spawn(move || {
                                 #[cfg(target_os = "linux")]
                                 let _ = std::process::Command::new("xdg-open")
@@ -2382,6 +2525,11 @@ pub async fn handle_key_event(
                 if let Some((curr_uid, yanked_uid)) = data {
                     if curr_uid == yanked_uid {
                         state.message = rust_i18n::t!("error_cannot_depend_on_self").to_string();
+                    } else if state
+                        .store
+                        .would_create_dependency_cycle(&curr_uid, &yanked_uid)
+                    {
+                        state.message = rust_i18n::t!("error_dependency_cycle").to_string();
                     } else {
                         let config = Config::load(state.ctx.as_ref()).unwrap_or_default();
                         let intent = AppIntent::AddDependency {
@@ -2389,7 +2537,7 @@ pub async fn handle_key_event(
                             blocker_uid: yanked_uid.clone(),
                         };
 
-                        let actions = state.store.apply_task_intent(&intent, &config);
+                        let actions = state.apply_task_intent(&intent, &config);
                         if !state.yank_lock_active {
                             state.yanked_uid = None;
                         }
@@ -2422,7 +2570,7 @@ pub async fn handle_key_event(
                             related_uid: yanked_uid.clone(),
                         };
 
-                        let actions = state.store.apply_task_intent(&intent, &config);
+                        let actions = state.apply_task_intent(&intent, &config);
                         if !state.yank_lock_active {
                             state.yanked_uid = None;
                         }
@@ -2454,7 +2602,7 @@ pub async fn handle_key_event(
                         parent_uid: parent_uid.clone(),
                     };
 
-                    let actions = state.store.apply_task_intent(&intent, &config);
+                    let actions = state.apply_task_intent(&intent, &config);
                     state.refresh_filtered_view();
                     if !actions.is_empty() {
                         let tx = action_tx.clone();
@@ -2473,7 +2621,7 @@ pub async fn handle_key_event(
                     let config = Config::load(state.ctx.as_ref()).unwrap_or_default();
                     let intent = AppIntent::RemoveParent { uid: uid.clone() };
 
-                    let actions = state.store.apply_task_intent(&intent, &config);
+                    let actions = state.apply_task_intent(&intent, &config);
                     state.refresh_filtered_view();
                     if !actions.is_empty() {
                         let tx = action_tx.clone();
@@ -2535,6 +2683,22 @@ pub async fn handle_key_event(
                 state.sidebar_mode = SidebarMode::Categories;
                 state.refresh_filtered_view();
             }
+            KeyCode::Char('z') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                if let Some(entry) = state.undo_history.pop() {
+                    let actions = state.store.undo(entry);
+                    state.refresh_filtered_view();
+                    update_alarms(state);
+                    state.message = rust_i18n::t!("undone").to_string();
+                    if !actions.is_empty() {
+                        let tx = action_tx.clone();
+                        tokio::spawn(async move {
+                            let _ = tx.send(Action::PersistBatch(actions)).await;
+                        });
+                    }
+                } else {
+                    state.message = rust_i18n::t!("nothing_to_undo").to_string();
+                }
+            }
             KeyCode::Char('z') => {
                 if state.active_focus == Focus::Main {
                     if let Some(task) = state.get_selected_task() {
@@ -2557,7 +2721,7 @@ pub async fn handle_key_event(
                             uid: uid.clone(),
                             collapsed: new_state,
                         };
-                        let actions = state.store.apply_task_intent(&intent, &config);
+                        let actions = state.apply_task_intent(&intent, &config);
                         state.refresh_filtered_view();
                         if !actions.is_empty() {
                             let tx = action_tx.clone();
@@ -3206,7 +3370,7 @@ pub async fn handle_key_event(
                         target_href: target_href.clone(),
                     };
 
-                    let actions = state.store.apply_task_intent(&intent, &config);
+                    let actions = state.apply_task_intent(&intent, &config);
                     state.refresh_filtered_view();
                     // Update alarms immediately if needed (task moved, though move doesn't clear completion)
                     // Moving a task keeps its alarms but might change visibility.
@@ -3343,7 +3507,7 @@ pub async fn handle_key_event(
                     }
 
                     if let Some(i) = intent {
-                        let actions = state.store.apply_task_intent(&i, &config);
+                        let actions = state.apply_task_intent(&i, &config);
                         state.refresh_filtered_view();
                         if !actions.is_empty() {
                             let tx = action_tx.clone();
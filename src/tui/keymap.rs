@@ -0,0 +1,250 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+/*
+File: ./src/tui/keymap.rs
+
+User-remappable keys for the main TUI view (`[keybindings]` in config.toml).
+
+Each action maps to a space-separated list of keys such as `q`, `ctrl+x`, `shift+space`
+or `down`. A remapped action answers only to its new keys; everything else keeps the
+hardcoded bindings in `handlers.rs`. Remapped keys are translated back to the action's
+default key before dispatch, so the handlers themselves stay unchanged.
+*/
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyAction {
+    Quit,
+    Next,
+    Previous,
+    Complete,
+    Edit,
+    Delete,
+    Refresh,
+}
+
+impl KeyAction {
+    pub const ALL: [KeyAction; 7] = [
+        KeyAction::Quit,
+        KeyAction::Next,
+        KeyAction::Previous,
+        KeyAction::Complete,
+        KeyAction::Edit,
+        KeyAction::Delete,
+        KeyAction::Refresh,
+    ];
+
+    /// Name used in the `[keybindings]` table.
+    pub fn name(self) -> &'static str {
+        match self {
+            KeyAction::Quit => "quit",
+            KeyAction::Next => "next",
+            KeyAction::Previous => "previous",
+            KeyAction::Complete => "complete",
+            KeyAction::Edit => "edit",
+            KeyAction::Delete => "delete",
+            KeyAction::Refresh => "refresh",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|a| a.name().eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// Built-in keys, as written in the config. The first one is what remapped keys dispatch as.
+    pub fn default_keys(self) -> &'static str {
+        match self {
+            KeyAction::Quit => "q",
+            KeyAction::Next => "j down",
+            KeyAction::Previous => "k up",
+            KeyAction::Complete => "space",
+            KeyAction::Edit => "e",
+            KeyAction::Delete => "delete",
+            KeyAction::Refresh => "r",
+        }
+    }
+
+    fn defaults(self) -> Vec<KeyEvent> {
+        self.default_keys()
+            .split_whitespace()
+            .filter_map(|k| parse_key(k).ok())
+            .collect()
+    }
+}
+
+/// Parses `ctrl+alt+x`, `shift+space`, `f5`, `pagedown`... into a key event.
+/// Modifier and key names are case-insensitive, except that a single letter keeps its case
+/// (`D` and `shift+d` are the same key).
+pub fn parse_key(spec: &str) -> Result<KeyEvent, String> {
+    let spec = spec.trim();
+    if spec.is_empty() {
+        return Err("empty key".to_string());
+    }
+    // `ctrl++` binds the plus key itself
+    let (mods_part, key_part) = match spec.strip_suffix("++") {
+        Some(rest) => (rest, "+"),
+        None => match spec.rsplit_once('+') {
+            Some((m, k)) if !k.is_empty() => (m, k),
+            _ => ("", spec),
+        },
+    };
+
+    let mut modifiers = KeyModifiers::NONE;
+    for m in mods_part.split('+').filter(|m| !m.is_empty()) {
+        modifiers |= match m.to_lowercase().as_str() {
+            "ctrl" | "control" => KeyModifiers::CONTROL,
+            "alt" | "meta" => KeyModifiers::ALT,
+            "shift" => KeyModifiers::SHIFT,
+            other => return Err(format!("unknown modifier '{}'", other)),
+        };
+    }
+
+    let mut chars = key_part.chars();
+    let code = match (chars.next(), chars.next()) {
+        (Some(c), None) => KeyCode::Char(c),
+        _ => match key_part.to_lowercase().as_str() {
+            "space" => KeyCode::Char(' '),
+            "enter" | "return" => KeyCode::Enter,
+            "esc" | "escape" => KeyCode::Esc,
+            "tab" => KeyCode::Tab,
+            "backspace" => KeyCode::Backspace,
+            "delete" | "del" => KeyCode::Delete,
+            "insert" | "ins" => KeyCode::Insert,
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" | "pgup" => KeyCode::PageUp,
+            "pagedown" | "pgdn" => KeyCode::PageDown,
+            name => match name.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+                Some(n) if (1..=24).contains(&n) => KeyCode::F(n),
+                _ => return Err(format!("unknown key '{}'", key_part)),
+            },
+        },
+    };
+
+    Ok(normalize(KeyEvent::new(code, modifiers)))
+}
+
+// Uppercase letters arrive with SHIFT set on most terminals and without it on some, so
+// letters compare on the character alone (`shift+d` and `D` are the same key).
+fn normalize(key: KeyEvent) -> KeyEvent {
+    let mut code = key.code;
+    let mut modifiers =
+        key.modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT);
+    if let KeyCode::Char(c) = code
+        && c.is_alphabetic()
+    {
+        if modifiers.contains(KeyModifiers::SHIFT) {
+            code = KeyCode::Char(c.to_uppercase().next().unwrap_or(c));
+        }
+        if code != KeyCode::Char(c) || c.is_uppercase() {
+            modifiers.remove(KeyModifiers::SHIFT);
+        }
+    }
+    KeyEvent::new(code, modifiers)
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Keymap {
+    // Only remapped actions are stored: (action, its configured keys)
+    custom: Vec<(KeyAction, Vec<KeyEvent>)>,
+}
+
+impl Keymap {
+    /// Builds the keymap from the `[keybindings]` table. Unknown action names, unparsable keys
+    /// and keys claimed by two actions are skipped (the first action keeps the key) and
+    /// reported in the returned warnings.
+    pub fn from_config(bindings: &HashMap<String, String>) -> (Self, Vec<String>) {
+        let mut warnings = Vec::new();
+        let mut custom: Vec<(KeyAction, Vec<KeyEvent>)> = Vec::new();
+
+        // Walk in a fixed order so conflict resolution doesn't depend on HashMap iteration
+        for action in KeyAction::ALL {
+            let Some((name, spec)) = bindings
+                .iter()
+                .find(|(name, _)| KeyAction::from_name(name) == Some(action))
+            else {
+                continue;
+            };
+            let mut keys = Vec::new();
+            for part in spec.split_whitespace() {
+                match parse_key(part) {
+                    Ok(key) => {
+                        if let Some((other, _)) = custom.iter().find(|(_, ks)| ks.contains(&key)) {
+                            warnings.push(format!(
+                                "keybindings: '{}' is bound to both '{}' and '{}', keeping '{}'",
+                                part,
+                                other.name(),
+                                name,
+                                other.name()
+                            ));
+                        } else if !keys.contains(&key) {
+                            keys.push(key);
+                        }
+                    }
+                    Err(e) => warnings.push(format!("keybindings.{}: {}", name, e)),
+                }
+            }
+            if keys.is_empty() {
+                warnings.push(format!(
+                    "keybindings.{}: no usable keys, keeping the default '{}'",
+                    name,
+                    action.default_keys()
+                ));
+            } else {
+                custom.push((action, keys));
+            }
+        }
+
+        // A new key can also take over the default of an action that wasn't remapped
+        for (action, keys) in &custom {
+            for other in KeyAction::ALL {
+                if custom.iter().any(|(a, _)| *a == other) {
+                    continue;
+                }
+                if keys.iter().any(|k| other.defaults().contains(k)) {
+                    warnings.push(format!(
+                        "keybindings: '{}' now takes a default key of '{}'",
+                        action.name(),
+                        other.name()
+                    ));
+                }
+            }
+        }
+
+        for name in bindings.keys() {
+            if KeyAction::from_name(name).is_none() {
+                warnings.push(format!("keybindings: unknown action '{}'", name));
+            }
+        }
+
+        (Self { custom }, warnings)
+    }
+
+    /// Maps a key pressed in the main view to the key the handlers expect: a remapped key
+    /// becomes its action's default key, the old default of a remapped action is swallowed
+    /// (`None`), anything else passes through untouched.
+    pub fn translate(&self, key: KeyEvent) -> Option<KeyEvent> {
+        if self.custom.is_empty() {
+            return Some(key);
+        }
+        let pressed = normalize(key);
+        if let Some((action, _)) = self.custom.iter().find(|(_, ks)| ks.contains(&pressed)) {
+            return action.defaults().into_iter().next();
+        }
+        if self
+            .custom
+            .iter()
+            .any(|(action, _)| action.defaults().contains(&pressed))
+        {
+            return None;
+        }
+        Some(key)
+    }
+}
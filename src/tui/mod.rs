@@ -3,6 +3,7 @@
 // Entry point and main loop for the TUI application.
 pub mod action;
 pub mod handlers;
+pub mod keymap;
 pub mod network;
 pub mod state;
 pub mod view;
@@ -17,7 +18,7 @@ use chrono::NaiveDate;
 
 use anyhow::Result;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, MouseEventKind},
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event},
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
@@ -210,6 +211,7 @@ pub async fn run(ctx: Arc<dyn AppContext>) -> Result<()> {
         expanded_tags,
         expanded_locations,
         theme,
+        custom_theme_background,
     ) = (
         cfg.url,
         cfg.username,
@@ -233,6 +235,7 @@ pub async fn run(ctx: Arc<dyn AppContext>) -> Result<()> {
         cfg.expanded_tags,
         cfg.expanded_locations,
         cfg.theme,
+        cfg.custom_theme_background,
     );
 
     // --- 2. TERMINAL SETUP ---
@@ -245,6 +248,8 @@ pub async fn run(ctx: Arc<dyn AppContext>) -> Result<()> {
     // --- 3. STATE INIT ---
     let mut app_state = AppState::new_with_ctx(ctx.clone());
     app_state.hide_completed = hide_completed;
+    app_state.hide_deferred = cfg.hide_deferred;
+    app_state.map_url_template = cfg.map_url_template.clone();
     app_state.strikethrough_completed = cfg.strikethrough_completed;
     app_state.hide_fully_completed_tags = hide_fully_completed_tags;
     app_state.hide_aliases_in_sidebar = hide_aliases_in_sidebar;
@@ -264,6 +269,16 @@ pub async fn run(ctx: Arc<dyn AppContext>) -> Result<()> {
     app_state.expanded_locations = expanded_locations.into_iter().collect();
     app_state.expanded_done_groups = HashSet::new();
     app_state.theme = theme;
+    app_state.custom_theme_background = custom_theme_background;
+
+    let (keymap, keymap_warnings) = keymap::Keymap::from_config(&cfg.keybindings);
+    for warning in &keymap_warnings {
+        log::warn!("{}", warning);
+    }
+    if let Some(first) = keymap_warnings.first() {
+        app_state.message = first.clone();
+    }
+    app_state.keymap = keymap;
 
     // --- START ALARM ACTOR ---
     let (gui_alarm_tx, mut gui_alarm_rx) = tokio::sync::mpsc::channel(100);
@@ -478,11 +493,7 @@ pub async fn run(ctx: Arc<dyn AppContext>) -> Result<()> {
         if crossterm::event::poll(Duration::from_millis(50))? {
             let event = event::read()?;
             match event {
-                Event::Mouse(mouse) => match mouse.kind {
-                    MouseEventKind::ScrollDown => app_state.next(),
-                    MouseEventKind::ScrollUp => app_state.previous(),
-                    _ => {}
-                },
+                Event::Mouse(mouse) => handlers::handle_mouse_event(mouse, &mut app_state),
                 Event::Key(key) => {
                     // Filter out KeyRelease events to prevent double input on Windows
                     if key.kind == event::KeyEventKind::Release {
@@ -6,7 +6,9 @@ use crate::model::{AppIntent, CalendarListEntry, Task};
 use crate::store::{FilterOptions, TaskListItem, TaskStore};
 use crate::system::SystemEvent;
 use crate::tui::action::SidebarMode;
+use crate::tui::keymap::Keymap;
 use fastrand;
+use ratatui::layout::Rect;
 use ratatui::widgets::ListState;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
@@ -62,11 +64,14 @@ pub struct AppState {
     // Data
     pub ctx: Arc<dyn AppContext>,
     pub store: TaskStore,
+    pub undo_history: crate::store::UndoHistory,
     pub tasks: Vec<TaskListItem>,
     pub calendars: Vec<CalendarListEntry>,
 
     // UI State
     pub list_state: ListState,
+    /// Screen rectangle of each visible task row (`tasks` index, area), refreshed by every `draw`.
+    pub task_row_rects: Vec<(usize, Rect)>,
     pub cal_state: ListState,
     pub active_focus: Focus,
     pub mode: InputMode,
@@ -83,6 +88,7 @@ pub struct AppState {
     pub selected_locations: HashSet<String>, // NEW
     pub match_all_categories: bool,
     pub hide_completed: bool,
+    pub hide_deferred: bool,
     pub hide_fully_completed_tags: bool,
     pub hide_aliases_in_sidebar: bool,
     pub show_inline_descriptions: bool,
@@ -92,6 +98,10 @@ pub struct AppState {
     pub sort_standard_by_priority: bool,
 
     pub theme: crate::config::AppTheme,
+    pub custom_theme_background: String,
+    pub date_format: crate::config::DateFormat,
+    pub time_format: crate::config::TimeFormat,
+    pub tag_color_mode: crate::config::TagColorMode,
 
     pub quick_filter_term: String,
     pub quick_filter_icon: String,
@@ -110,6 +120,7 @@ pub struct AppState {
 
     // Snooze configuration
     pub snooze_short_mins: u32,
+    pub map_url_template: String,
     pub snooze_long_mins: u32,
 
     // Input Buffers
@@ -120,6 +131,8 @@ pub struct AppState {
     pub edit_scroll_offset: u16,
     pub edit_scroll_x: u16,
     pub details_scroll: u16,
+    pub show_details_pane: bool,
+    pub keymap: Keymap,
     pub editing_uid: Option<String>,
     pub move_selection_state: ListState,
     pub move_targets: Vec<CalendarListEntry>,
@@ -195,9 +208,11 @@ impl AppState {
         Self {
             ctx: ctx.clone(),
             store: TaskStore::new(ctx.clone()),
+            undo_history: crate::store::UndoHistory::default(),
             tasks: vec![],
             calendars: vec![],
             list_state: l_state,
+            task_row_rects: Vec::new(),
             cal_state: c_state,
             active_focus: Focus::Main,
             mode: InputMode::Normal,
@@ -213,6 +228,7 @@ impl AppState {
             selected_locations: HashSet::new(), // Init
             match_all_categories: true,
             hide_completed: false,
+            hide_deferred: false,
             strikethrough_completed: false,
             hide_fully_completed_tags: false,
             hide_aliases_in_sidebar: true,
@@ -226,6 +242,10 @@ impl AppState {
             sort_cutoff_days: Some(30),
             sort_standard_by_priority: false,
             theme: crate::config::AppTheme::default(),
+            custom_theme_background: String::new(),
+            date_format: config.date_format,
+            time_format: config.time_format,
+            tag_color_mode: config.tag_color_mode,
             // Initialize sidebar caches as empty; they will be populated by refresh_filtered_view()
             cached_categories: Vec::new(),
             cached_locations: Vec::new(),
@@ -235,6 +255,7 @@ impl AppState {
             start_grace_period_days: 1,
 
             snooze_short_mins: 60,
+            map_url_template: String::new(),
             snooze_long_mins: 1440,
 
             input_buffer: String::new(),
@@ -244,6 +265,8 @@ impl AppState {
             edit_scroll_offset: 0,
             edit_scroll_x: 0,
             details_scroll: 0,
+            show_details_pane: true,
+            keymap: Keymap::default(),
             editing_uid: None,
             move_selection_state: ListState::default(),
             move_targets: Vec::new(),
@@ -360,6 +383,11 @@ impl AppState {
 
         // Load config to get limits
         let config = crate::config::Config::load(self.ctx.as_ref()).unwrap_or_default();
+        self.store.apply_archive_setting(&config, search_term);
+
+        let (exact_priority, require_unset_duration) =
+            crate::model::matcher::extract_filter_shortcuts(search_term);
+        let exclusions = crate::model::matcher::extract_filter_exclusions(search_term);
 
         // Use the store.filter() that returns a FilterResult so we can populate
         // both the task list and the sidebar caches for categories/locations.
@@ -371,18 +399,29 @@ impl AppState {
             match_all_categories: self.match_all_categories,
             search_term,
             hide_completed_global: self.hide_completed,
+            hide_deferred: self.hide_deferred,
             hide_fully_completed_tags: self.hide_fully_completed_tags,
             hide_aliases_in_sidebar: self.hide_aliases_in_sidebar,
             cutoff_date,
             min_duration: None,
             max_duration: None,
             include_unset_duration: true,
+            exact_priority,
+            require_unset_duration,
+            excluded_categories: &exclusions.categories,
+            excluded_locations: &exclusions.locations,
+            excluded_priorities: &exclusions.priorities,
+            excluded_statuses: &exclusions.statuses,
             urgent_days: self.urgent_days,
             urgent_prio: self.urgent_prio,
             default_priority: self.default_priority,
             start_grace_period_days: self.start_grace_period_days,
             sort_standard_by_priority: self.sort_standard_by_priority,
             sort_preset: config.sort_preset,
+            sort_mode: config.sort_mode,
+            all_day_sort: config.all_day_sort,
+            week_start: config.week_start,
+            sort_urgency_boost: config.sort_urgency_boost,
             expanded_done_groups: &self.expanded_done_groups,
             expanded_tags: &self.expanded_tags,
             expanded_locations: &self.expanded_locations,
@@ -391,6 +430,7 @@ impl AppState {
             tag_aliases: &config.tag_aliases,
             search_collapsed_tasks: &self.search_collapsed_tasks,
             focused_task_uid: self.focused_task_uid.as_deref(),
+            fuzzy_search: config.fuzzy_search,
         });
 
         self.tasks = filter_res.items;
@@ -496,7 +536,8 @@ impl AppState {
             self.focused_task_uid = uid.clone();
             return Vec::new();
         }
-        self.store.apply_task_intent(intent, config)
+        self.store
+            .apply_task_intent_with_undo(intent, config, &mut self.undo_history)
     }
 
     /// Get all real tasks (excluding control items)
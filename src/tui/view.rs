@@ -12,7 +12,7 @@
 
 use crate::color_utils;
 use crate::model::parser::{SyntaxType, tokenize_smart_input};
-use crate::store::{TaskListItem, UNCATEGORIZED_ID};
+use crate::store::{NO_LOCATION_ID, TaskListItem, UNCATEGORIZED_ID};
 use crate::tui::action::SidebarMode;
 use crate::tui::state::{AppState, Focus, InputMode};
 
@@ -310,7 +310,8 @@ fn format_description_for_markdown(raw: &str) -> String {
 }
 
 pub fn draw(f: &mut Frame, state: &mut AppState) {
-    let is_dark_theme = state.theme.is_dark();
+    let is_dark_theme = state.theme.is_dark(&state.custom_theme_background);
+    let (date_fmt, time_fmt) = (state.date_format, state.time_format);
     let footer_height = if state.mode == InputMode::EditingDescription
         || matches!(state.mode, InputMode::EditingTree(_))
     {
@@ -447,8 +448,11 @@ pub fn draw(f: &mut Frame, state: &mut AppState) {
                         ];
                         ListItem::new(Line::from(spans))
                     } else {
-                        let (r, g, b) =
-                            color_utils::generate_tui_color(&item.full_key, is_dark_theme);
+                        let (r, g, b) = color_utils::tag_color(
+                            &item.full_key,
+                            state.tag_color_mode,
+                            is_dark_theme,
+                        );
                         let color =
                             Color::Rgb((r * 255.0) as u8, (g * 255.0) as u8, (b * 255.0) as u8);
                         let prefix = if item.display_name.contains('=') {
@@ -511,12 +515,17 @@ pub fn draw(f: &mut Frame, state: &mut AppState) {
                     } else {
                         Span::raw("")
                     };
+                    let loc_prefix = if item.full_key == NO_LOCATION_ID {
+                        " "
+                    } else {
+                        " @@"
+                    };
                     let spans = vec![
                         Span::raw(indent),
                         Span::raw(selected),
                         tree_icon_span,
                         Span::styled(
-                            " @@",
+                            loc_prefix,
                             Style::default().fg(if is_dark_theme {
                                 Color::LightCyan
                             } else {
@@ -800,7 +809,14 @@ pub fn draw(f: &mut Frame, state: &mut AppState) {
                             let local_done = done_dt.with_timezone(&chrono::Local);
                             let color = Color::DarkGray;
                             (
-                                format!(" 🗓️ {}", local_done.format("%Y-%m-%d %H:%M")),
+                                format!(
+                                    " 🗓️ {}",
+                                    crate::model::item::format_timestamp(
+                                        &local_done,
+                                        date_fmt,
+                                        time_fmt
+                                    )
+                                ),
                                 Style::default().fg(color),
                             )
                         } else {
@@ -808,24 +824,33 @@ pub fn draw(f: &mut Frame, state: &mut AppState) {
                         }
                     } else if is_future_start {
                         let start_ref = t.dtstart.as_ref().unwrap();
-                        let start_str = start_ref.format_smart();
+                        let start_str = start_ref.format_with(date_fmt, time_fmt);
 
                         if let Some(due) = &t.due {
                             let is_same_day = start_ref.to_date_naive() == due.to_date_naive();
                             let due_str = if is_same_day {
                                 match due {
                                     crate::model::DateType::Specific(dt) => {
-                                        dt.with_timezone(&chrono::Local).format("%H:%M").to_string()
+                                        crate::model::item::format_clock(
+                                            dt.with_timezone(&chrono::Local).time(),
+                                            time_fmt,
+                                        )
+                                    }
+                                    crate::model::DateType::AllDay(_) => {
+                                        due.format_with(date_fmt, time_fmt)
+                                    }
+                                    crate::model::DateType::Month(_, _) => {
+                                        due.format_with(date_fmt, time_fmt)
+                                    }
+                                    crate::model::DateType::Year(_) => {
+                                        due.format_with(date_fmt, time_fmt)
                                     }
-                                    crate::model::DateType::AllDay(_) => due.format_smart(),
-                                    crate::model::DateType::Month(_, _) => due.format_smart(),
-                                    crate::model::DateType::Year(_) => due.format_smart(),
                                 }
                             } else {
-                                due.format_smart()
+                                due.format_with(date_fmt, time_fmt)
                             };
 
-                            if start_str == due.format_smart() {
+                            if start_str == due.format_with(date_fmt, time_fmt) {
                                 (
                                     format!(" ►{}⌛", start_str),
                                     Style::default().fg(Color::DarkGray),
@@ -853,7 +878,7 @@ pub fn draw(f: &mut Frame, state: &mut AppState) {
                             })
                         };
 
-                        (format!(" @{}⌛", d.format_smart()), style)
+                        (format!(" @{}⌛", d.format_with(date_fmt, time_fmt)), style)
                     } else {
                         (String::new(), Style::default())
                     };
@@ -996,7 +1021,8 @@ pub fn draw(f: &mut Frame, state: &mut AppState) {
                     }
 
                     for cat in visible_tags {
-                        let (r, g, b) = color_utils::generate_tui_color(cat, is_dark_theme);
+                        let (r, g, b) =
+                            color_utils::tag_color(cat, state.tag_color_mode, is_dark_theme);
                         if !right_spans.is_empty() {
                             right_spans.push(Span::raw(" "));
                         }
@@ -1139,12 +1165,50 @@ pub fn draw(f: &mut Frame, state: &mut AppState) {
         if let Some(url) = &task.url {
             meta.push(format!("- **URL:** {}", url));
         }
+        for link in &task.attachments {
+            meta.push(format!("- **Link:** {}", link));
+        }
         if let Some(geo) = &task.geo {
             meta.push(format!("- **Geo:** {}", geo));
         }
         if let Some(loc) = &task.location {
             meta.push(format!("- **Location:** {}", loc));
         }
+        if let Some(due) = &task.due {
+            meta.push(format!(
+                "- **{}:** {}",
+                rust_i18n::t!("due_label"),
+                due.format_with(date_fmt, time_fmt)
+            ));
+        }
+        if let Some(start) = &task.dtstart {
+            meta.push(format!(
+                "- **{}:** {}",
+                rust_i18n::t!("start"),
+                start.format_with(date_fmt, time_fmt)
+            ));
+        }
+        if !task.categories.is_empty() {
+            let tags: Vec<String> = task.categories.iter().map(|c| format!("#{}", c)).collect();
+            meta.push(format!(
+                "- **{}:** {}",
+                rust_i18n::t!("tags"),
+                tags.join(" ")
+            ));
+        }
+        let reminders: Vec<String> = task
+            .alarms
+            .iter()
+            .filter(|a| a.acknowledged.is_none())
+            .map(|a| a.describe(date_fmt, time_fmt))
+            .collect();
+        if !reminders.is_empty() {
+            meta.push(format!(
+                "- **{}:** {}",
+                rust_i18n::t!("reminders_label"),
+                reminders.join(", ")
+            ));
+        }
         let mut date_infos = Vec::new();
         let created_opt = task.created_date();
         let modified_opt = task.last_modified_date();
@@ -1154,7 +1218,7 @@ pub fn draw(f: &mut Frame, state: &mut AppState) {
             date_infos.push(format!(
                 "**{}**: {}",
                 rust_i18n::t!("created_label"),
-                local.format("%Y-%m-%d %H:%M")
+                crate::model::item::format_timestamp(&local, date_fmt, time_fmt)
             ));
         }
         if let Some(modified) = modified_opt
@@ -1164,7 +1228,7 @@ pub fn draw(f: &mut Frame, state: &mut AppState) {
             date_infos.push(format!(
                 "**{}**: {}",
                 rust_i18n::t!("last_modified_label"),
-                local.format("%Y-%m-%d %H:%M")
+                crate::model::item::format_timestamp(&local, date_fmt, time_fmt)
             ));
         }
         if !date_infos.is_empty() {
@@ -1211,7 +1275,11 @@ pub fn draw(f: &mut Frame, state: &mut AppState) {
                     if rel_task.status.is_done() {
                         if let Some(comp_date) = rel_task.completion_date() {
                             let local = comp_date.with_timezone(&chrono::Local);
-                            name = format!("{} (✓ {})", name, local.format("%Y-%m-%d %H:%M"));
+                            name = format!(
+                                "{} (✓ {})",
+                                name,
+                                crate::model::item::format_timestamp(&local, date_fmt, time_fmt)
+                            );
                         } else {
                             name = format!("{} (✓)", name);
                         }
@@ -1231,8 +1299,11 @@ pub fn draw(f: &mut Frame, state: &mut AppState) {
                 {
                     if let Some(comp_date) = rel_task.completion_date() {
                         let local = comp_date.with_timezone(&chrono::Local);
-                        related_name =
-                            format!("{} (✓ {})", related_name, local.format("%Y-%m-%d %H:%M"));
+                        related_name = format!(
+                            "{} (✓ {})",
+                            related_name,
+                            crate::model::item::format_timestamp(&local, date_fmt, time_fmt)
+                        );
                     } else {
                         related_name = format!("{} (✓)", related_name);
                     }
@@ -1266,6 +1337,9 @@ pub fn draw(f: &mut Frame, state: &mut AppState) {
                         ));
                     }
                 }
+                if let Some(summary) = task.completion_log_summary(date_fmt, time_fmt) {
+                    details_md.push_str(&format!("- {}\n", summary));
+                }
             }
 
             if let Some(goal) = &task.goal {
@@ -1385,7 +1459,11 @@ pub fn draw(f: &mut Frame, state: &mut AppState) {
     let calculated_height = required_lines + 2;
     let available_height = v_chunks[0].height;
     let max_details_height = available_height / 2;
-    let final_details_height = calculated_height.clamp(3, max_details_height);
+    let final_details_height = if state.show_details_pane {
+        calculated_height.clamp(3, max_details_height)
+    } else {
+        0
+    };
 
     // Recalculate layout with final details height
     let main_chunks = Layout::default()
@@ -1450,6 +1528,7 @@ pub fn draw(f: &mut Frame, state: &mut AppState) {
         Style::default()
     };
 
+    let item_heights: Vec<u16> = task_items.iter().map(|i| i.height() as u16).collect();
     let task_list = List::new(task_items)
         .block(
             Block::default()
@@ -1469,7 +1548,29 @@ pub fn draw(f: &mut Frame, state: &mut AppState) {
         );
     f.render_stateful_widget(task_list, main_chunks[0], &mut state.list_state);
 
+    // Remember where each row landed so mouse clicks can be mapped back to tasks.
+    let list_inner = main_chunks[0].inner(ratatui::layout::Margin::new(1, 1));
+    let list_bottom = list_inner.y + list_inner.height;
+    let mut row_y = list_inner.y;
+    state.task_row_rects.clear();
+    for (idx, height) in item_heights
+        .iter()
+        .enumerate()
+        .skip(state.list_state.offset())
+    {
+        if row_y >= list_bottom {
+            break;
+        }
+        let visible = (*height).min(list_bottom - row_y);
+        state.task_row_rects.push((
+            idx,
+            Rect::new(list_inner.x, row_y, list_inner.width, visible),
+        ));
+        row_y += visible;
+    }
+
     // Details rendering (markdown)
+    // A pane hidden with `i` gets a zero-height area here.
     if !matches!(
         state.mode,
         InputMode::EditingDescription | InputMode::EditingTree(_)
@@ -1645,8 +1746,11 @@ pub fn draw(f: &mut Frame, state: &mut AppState) {
                         SyntaxType::Duration => Style::default().fg(Color::DarkGray),
                         SyntaxType::Tag => {
                             let tag_name = text.trim_start_matches('#');
-                            let (r, g, b) =
-                                color_utils::generate_tui_color(tag_name, is_dark_theme);
+                            let (r, g, b) = color_utils::tag_color(
+                                tag_name,
+                                state.tag_color_mode,
+                                is_dark_theme,
+                            );
                             Style::default().fg(Color::Rgb(
                                 (r * 255.0) as u8,
                                 (g * 255.0) as u8,
@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 //! Tests for basic alarm functionality.
 use cfait::model::{AlarmTrigger, DateType, Task};
-use chrono::{Duration, Local, Timelike};
+use chrono::{Duration, Local, Timelike, Weekday};
 use std::collections::HashMap;
 
 fn mock_aliases() -> HashMap<String, Vec<String>> {
@@ -194,3 +194,218 @@ fn test_ics_roundtrip_alarms() {
         _ => panic!("Failed roundtrip"),
     }
 }
+
+#[test]
+fn test_default_reminder_added_once_and_hidden_from_smart_string() {
+    let mut t = Task::new("Call dentist @tomorrow 14:00", &mock_aliases(), None);
+    t.apply_default_reminder(Some(15));
+    t.apply_default_reminder(Some(15));
+
+    assert_eq!(t.alarms.len(), 1);
+    assert!(t.alarms[0].is_default);
+    assert_eq!(t.alarms[0].trigger, AlarmTrigger::Relative(-15));
+    assert!(!t.to_smart_string().contains("rem:"));
+
+    // Survives an edit round-trip through smart input
+    let smart = t.to_smart_string();
+    t.apply_smart_input(&smart, &mock_aliases(), None, &[], Weekday::Mon);
+    t.apply_default_reminder(Some(15));
+    assert_eq!(t.alarms.len(), 1);
+
+    // Disabling removes it
+    t.apply_default_reminder(None);
+    assert!(t.alarms.is_empty());
+}
+
+#[test]
+fn test_default_reminder_skipped_for_explicit_rem_or_undated() {
+    let mut explicit = Task::new("Call dentist @tomorrow 14:00 rem:1h", &mock_aliases(), None);
+    explicit.apply_default_reminder(Some(15));
+    assert_eq!(explicit.alarms.len(), 1);
+    assert!(!explicit.alarms[0].is_default);
+
+    let mut undated = Task::new("Someday maybe", &mock_aliases(), None);
+    undated.apply_default_reminder(Some(15));
+    assert!(undated.alarms.is_empty());
+
+    let mut all_day = Task::new("Pay rent @tomorrow", &mock_aliases(), None);
+    all_day.apply_default_reminder(Some(15));
+    assert!(all_day.alarms.is_empty());
+}
+
+#[test]
+fn test_default_reminder_per_calendar_override() {
+    let mut config = cfait::config::Config {
+        default_reminder_minutes: Some(30),
+        ..Default::default()
+    };
+    config
+        .calendar_reminder_minutes
+        .insert("local://work".to_string(), 10);
+    config
+        .calendar_reminder_minutes
+        .insert("local://quiet".to_string(), 0);
+
+    assert_eq!(
+        config.default_reminder_minutes_for("local://default"),
+        Some(30)
+    );
+    assert_eq!(
+        config.default_reminder_minutes_for("local://work"),
+        Some(10)
+    );
+    assert_eq!(config.default_reminder_minutes_for("local://quiet"), None);
+}
+
+#[test]
+fn test_default_reminder_flag_survives_ics() {
+    use cfait::model::IcsAdapter;
+
+    let mut t = Task::new("Standup @tomorrow 09:30", &mock_aliases(), None);
+    t.apply_default_reminder(Some(5));
+    let ics = IcsAdapter::to_ics(&t);
+    assert!(ics.contains("X-CFAIT-DEFAULT:TRUE"));
+
+    let back = IcsAdapter::from_ics(&ics, String::new(), String::new(), String::new()).unwrap();
+    assert_eq!(back.alarms.len(), 1);
+    assert!(back.alarms[0].is_default);
+}
+
+#[test]
+fn test_add_reminder_reuses_rem_parsing() {
+    let mut t = Task::new("Call mom @tomorrow 18:00", &mock_aliases(), None);
+    assert!(t.alarms.is_empty());
+
+    assert!(t.add_reminder("30m", None));
+    assert!(matches!(t.alarms[0].trigger, AlarmTrigger::Relative(-30)));
+
+    // A bare time lands on the due date rather than the next wall-clock match.
+    assert!(t.add_reminder("rem:9:15", None));
+    let due_date = t.due.as_ref().unwrap().to_date_naive();
+    match t.alarms[1].trigger {
+        AlarmTrigger::Absolute(dt) => {
+            let local = dt.with_timezone(&Local);
+            assert_eq!(local.date_naive(), due_date);
+            assert_eq!((local.hour(), local.minute()), (9, 15));
+        }
+        _ => panic!("Expected absolute alarm"),
+    }
+
+    assert!(!t.add_reminder("gibberish", None));
+    assert!(!t.add_reminder("", None));
+    assert_eq!(t.alarms.len(), 2);
+}
+
+#[test]
+fn test_snooze_until_keeps_chain_rules() {
+    let mut t = Task::new("Wake up rem:8am", &mock_aliases(), None);
+    let original_uid = t.alarms[0].uid.clone();
+
+    // Past targets are rejected and leave the alarms untouched.
+    assert!(!t.snooze_alarm_until(&original_uid, chrono::Utc::now() - Duration::minutes(1)));
+    assert_eq!(t.alarms.len(), 1);
+    assert!(t.alarms[0].acknowledged.is_none());
+
+    t.snooze_alarm(&original_uid, 10);
+    let snooze1_uid = t
+        .alarms
+        .iter()
+        .find(|a| a.uid != original_uid)
+        .unwrap()
+        .uid
+        .clone();
+
+    let until = chrono::Utc::now() + Duration::hours(20);
+    assert!(t.snooze_alarm_until(&snooze1_uid, until));
+    assert_eq!(t.alarms.len(), 2);
+
+    let snooze2 = t
+        .alarms
+        .iter()
+        .find(|a| a.uid != original_uid && a.uid != snooze1_uid)
+        .expect("Snooze 2 missing");
+    assert_eq!(snooze2.trigger, AlarmTrigger::Absolute(until));
+    assert_eq!(snooze2.related_to_uid, Some(original_uid));
+    assert_eq!(snooze2.relation_type.as_deref(), Some("SNOOZE"));
+}
+
+#[test]
+fn test_parse_snooze_until() {
+    use cfait::model::parser::parse_snooze_until;
+    let nine = chrono::NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+
+    let bare = parse_snooze_until("9am", nine, Weekday::Mon).unwrap();
+    assert!(bare > chrono::Utc::now());
+    assert!(bare <= chrono::Utc::now() + Duration::days(1));
+    assert_eq!(bare.with_timezone(&Local).hour(), 9);
+
+    let tomorrow = parse_snooze_until("tomorrow 14:30", nine, Weekday::Mon).unwrap();
+    let local = tomorrow.with_timezone(&Local);
+    assert_eq!(
+        local.date_naive(),
+        Local::now().date_naive() + Duration::days(1)
+    );
+    assert_eq!((local.hour(), local.minute()), (14, 30));
+
+    let date_only = parse_snooze_until("tomorrow", nine, Weekday::Mon).unwrap();
+    assert_eq!(date_only.with_timezone(&Local).hour(), 9);
+
+    assert!(parse_snooze_until("", nine, Weekday::Mon).is_none());
+    assert!(parse_snooze_until("whenever", nine, Weekday::Mon).is_none());
+}
+
+#[test]
+fn test_ics_roundtrip_snoozed_and_acknowledged_alarm() {
+    let mut t_in = Task::new("Ping @14:00 rem:15m", &mock_aliases(), None);
+    let original_uid = t_in.alarms[0].uid.clone();
+    t_in.alarms[0].description = Some("Call back, then; file notes\nsecond line".to_string());
+    assert!(t_in.snooze_alarm(&original_uid, 10));
+    let snooze_uid = t_in
+        .alarms
+        .iter()
+        .find(|a| a.is_snooze())
+        .unwrap()
+        .uid
+        .clone();
+    // Dismissing the snooze acknowledges it too; both states must reach the server.
+    assert!(t_in.dismiss_alarm(&snooze_uid));
+
+    let ics = t_in.to_ics();
+    assert!(ics.contains("ACKNOWLEDGED:"));
+    assert!(ics.contains("RELTYPE=SNOOZE"));
+
+    let t_out = Task::from_ics(&ics, "etag".into(), "href".into(), "cal".into()).unwrap();
+    assert_eq!(t_out.alarms.len(), 2);
+
+    for before in &t_in.alarms {
+        let after = t_out
+            .alarms
+            .iter()
+            .find(|a| a.uid == before.uid)
+            .expect("alarm UID must round-trip");
+        assert_eq!(after.trigger, {
+            match &before.trigger {
+                // ICS stores whole seconds
+                AlarmTrigger::Absolute(dt) => {
+                    AlarmTrigger::Absolute(dt.with_nanosecond(0).unwrap())
+                }
+                other => other.clone(),
+            }
+        });
+        assert_eq!(after.description, before.description);
+        assert_eq!(after.related_to_uid, before.related_to_uid);
+        assert_eq!(after.relation_type, before.relation_type);
+        assert_eq!(
+            after.acknowledged.map(|d| d.timestamp()),
+            before.acknowledged.map(|d| d.timestamp())
+        );
+        assert!(after.acknowledged.is_some());
+    }
+
+    let snooze = t_out.alarms.iter().find(|a| a.uid == snooze_uid).unwrap();
+    assert!(snooze.is_snooze());
+    assert_eq!(
+        snooze.related_to_uid.as_deref(),
+        Some(original_uid.as_str())
+    );
+}
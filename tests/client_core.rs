@@ -190,6 +190,101 @@ async fn test_calendar_creation_and_updating() {
         .unwrap();
 }
 
+#[tokio::test]
+#[serial]
+async fn test_set_calendar_color_reports_unsupported_property() {
+    let ctx = std::sync::Arc::new(cfait::context::TestContext::new());
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = RustyClient::new(ctx.clone(), &url, "u", "p", true, None).unwrap();
+
+    cfait::cache::Cache::save_calendars(
+        ctx.as_ref(),
+        &[CalendarListEntry {
+            name: "Work".to_string(),
+            href: "/cal/work/".to_string(),
+            color: None,
+        }],
+    )
+    .unwrap();
+
+    let _ok = server
+        .mock("PROPPATCH", "/cal/work/")
+        .with_status(207)
+        .with_body(r#"<d:multistatus xmlns:d="DAV:"><d:response><d:href>/cal/work/</d:href><d:propstat><d:prop><x:calendar-color xmlns:x="http://apple.com/ns/ical/"/></d:prop><d:status>HTTP/1.1 200 OK</d:status></d:propstat></d:response></d:multistatus>"#)
+        .create_async()
+        .await;
+    let _rejected = server
+        .mock("PROPPATCH", "/cal/other/")
+        .with_status(207)
+        .with_body(r#"<d:multistatus xmlns:d="DAV:"><d:response><d:href>/cal/other/</d:href><d:propstat><d:prop><x:calendar-color xmlns:x="http://apple.com/ns/ical/"/></d:prop><d:status>HTTP/1.1 403 Forbidden</d:status></d:propstat></d:response></d:multistatus>"#)
+        .create_async()
+        .await;
+
+    assert!(
+        client
+            .set_calendar_color("/cal/work/", Some("#00FF00"))
+            .await
+            .unwrap()
+    );
+    let cached = cfait::cache::Cache::load_calendars(ctx.as_ref()).unwrap();
+    assert_eq!(cached[0].color.as_deref(), Some("#00FF00"));
+
+    assert!(
+        !client
+            .set_calendar_color("/cal/other/", Some("#0000FF"))
+            .await
+            .unwrap()
+    );
+
+    let mut config = cfait::config::Config::default();
+    config.set_calendar_override("/cal/other/", None, Some(Some("#0000FF".to_string())));
+    let mut cals = vec![CalendarListEntry {
+        name: "Other".to_string(),
+        href: "/cal/other/".to_string(),
+        color: Some("#123456".to_string()),
+    }];
+    config.apply_calendar_overrides(&mut cals);
+    assert_eq!(cals[0].color.as_deref(), Some("#0000FF"));
+}
+
+#[test]
+fn test_calendar_overrides_fall_back_to_server_values() {
+    let mut config = cfait::config::Config::default();
+    assert!(config.set_calendar_override("/cal/shared/", Some(Some("Team".to_string())), None));
+    // Setting the same value again is not a change
+    assert!(!config.set_calendar_override("/cal/shared/", Some(Some("Team".to_string())), None));
+
+    let server = || {
+        vec![
+            CalendarListEntry {
+                name: "cal-7f3a".to_string(),
+                href: "/cal/shared/".to_string(),
+                color: Some("#111111".to_string()),
+            },
+            CalendarListEntry {
+                name: "Home".to_string(),
+                href: "/cal/home/".to_string(),
+                color: None,
+            },
+        ]
+    };
+
+    let mut cals = server();
+    config.apply_calendar_overrides(&mut cals);
+    assert_eq!(cals[0].name, "Team");
+    // No color override: the server color is kept
+    assert_eq!(cals[0].color.as_deref(), Some("#111111"));
+    assert_eq!(cals[1].name, "Home");
+
+    // Clearing every field drops the entry entirely
+    assert!(config.set_calendar_override("/cal/shared/", Some(Some("  ".to_string())), None));
+    assert!(config.calendar_overrides.is_empty());
+    let mut cals = server();
+    config.apply_calendar_overrides(&mut cals);
+    assert_eq!(cals[0].name, "cal-7f3a");
+}
+
 // --- MOVE TASK TESTS (updated to use TaskController) ---
 
 #[tokio::test]
@@ -253,3 +348,131 @@ async fn test_controller_move_local_to_remote() {
         other => panic!("Expected action to be Create(remote), got: {:?}", other),
     }
 }
+
+#[tokio::test]
+#[serial]
+async fn test_sync_journal_retries_transient_errors_with_backoff() {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    let ctx = Arc::new(cfait::context::TestContext::new());
+
+    let mut task = Task::new("Flaky", &HashMap::new(), None);
+    task.uid = "sync-flaky-1".to_string();
+    task.calendar_href = "https://example.com/cal/".to_string();
+    Journal::push(ctx.as_ref(), Action::Create(task)).unwrap();
+
+    let calls = Arc::new(AtomicU32::new(0));
+    let calls_hook = calls.clone();
+    let hook = TEST_FORCE_SYNC_ERROR.get_or_init(|| {
+        Mutex::new(None::<Box<dyn Fn(&Action) -> Option<anyhow::Error> + Send + Sync + 'static>>)
+    });
+    *hook.lock().unwrap() = Some(Box::new(move |action: &Action| match action {
+        Action::Create(t) if t.uid == "sync-flaky-1" => {
+            calls_hook.fetch_add(1, Ordering::SeqCst);
+            Some(anyhow::Error::new(libdav::dav::WebDavError::<
+                std::convert::Infallible,
+            >::BadStatusCode(
+                http::StatusCode::SERVICE_UNAVAILABLE
+            )))
+        }
+        _ => None,
+    }));
+
+    let client = RustyClient::new(
+        ctx.clone(),
+        "http://dummy.test",
+        "user",
+        "pass",
+        false,
+        None,
+    )
+    .unwrap();
+    let err = client.sync_journal().await.unwrap_err();
+
+    assert_eq!(
+        calls.load(Ordering::SeqCst),
+        cfait::client::sync::SYNC_MAX_RETRIES + 1,
+        "Initial attempt plus every retry should hit the server"
+    );
+    assert!(
+        err.contains("503"),
+        "Exhaustion error should carry the cause: {}",
+        err
+    );
+
+    // The action must stay queued for the next sync
+    let journal = Journal::load(ctx.as_ref());
+    assert!(
+        journal
+            .queue
+            .iter()
+            .any(|a| matches!(a, Action::Create(t) if t.uid == "sync-flaky-1"))
+    );
+
+    if let Some(h) = TEST_FORCE_SYNC_ERROR.get() {
+        *h.lock().unwrap() = None;
+    }
+}
+
+#[tokio::test]
+#[serial]
+async fn test_sync_journal_does_not_retry_status_like_text() {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    let ctx = Arc::new(cfait::context::TestContext::new());
+
+    let mut task = Task::new("Receipt", &HashMap::new(), None);
+    task.uid = "sync-500-1".to_string();
+    task.calendar_href = "https://example.com/cal/".to_string();
+    Journal::push(ctx.as_ref(), Action::Create(task)).unwrap();
+
+    let calls = Arc::new(AtomicU32::new(0));
+    let calls_hook = calls.clone();
+    let hook = TEST_FORCE_SYNC_ERROR.get_or_init(|| {
+        Mutex::new(None::<Box<dyn Fn(&Action) -> Option<anyhow::Error> + Send + Sync + 'static>>)
+    });
+    // A 5xx-looking number in the text (here a path) is not an HTTP status
+    *hook.lock().unwrap() = Some(Box::new(move |action: &Action| match action {
+        Action::Create(t) if t.uid == "sync-500-1" => {
+            calls_hook.fetch_add(1, Ordering::SeqCst);
+            Some(anyhow::anyhow!(
+                "invalid response for /cal/502/sync-500-1.ics"
+            ))
+        }
+        _ => None,
+    }));
+
+    let client = RustyClient::new(
+        ctx.clone(),
+        "http://dummy.test",
+        "user",
+        "pass",
+        false,
+        None,
+    )
+    .unwrap();
+    let _ = client.sync_journal().await;
+
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    if let Some(h) = TEST_FORCE_SYNC_ERROR.get() {
+        *h.lock().unwrap() = None;
+    }
+}
+
+#[tokio::test]
+async fn test_diagnose_offline_client_reports_failed_first_step() {
+    let ctx = std::sync::Arc::new(cfait::context::TestContext::new());
+    let client = RustyClient {
+        client: None,
+        ctx: ctx.clone(),
+    };
+
+    let steps = client.diagnose().await;
+    assert_eq!(steps.len(), 1);
+    assert_eq!(steps[0].step, "principal");
+    assert!(!steps[0].ok);
+    assert_eq!(steps[0].status, None);
+}
@@ -1,8 +1,9 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 //! Tests for calendar discovery.
+use cfait::cache::{Cache, DiscoveryCache};
 use cfait::client::core::RustyClient;
 use cfait::context::TestContext;
-use mockito::Server;
+use mockito::{Matcher, Server};
 use std::sync::Arc;
 
 #[tokio::test]
@@ -169,6 +170,291 @@ async fn test_discover_calendar_full_fallback_success() {
     mock_calendars.assert();
 }
 
+#[tokio::test]
+async fn test_get_calendars_reuses_cached_home_set() {
+    let mut server = Server::new_async().await;
+    let url = server.url();
+    let home_set_path = "/calendars/testuser/";
+
+    // Principal discovery must not run when a home-set is cached for this server.
+    let mock_principal = server
+        .mock("PROPFIND", "/")
+        .match_body(mockito::Matcher::Regex(
+            "current-user-principal".to_string(),
+        ))
+        .expect(0)
+        .create_async()
+        .await;
+
+    let mock_calendars = server
+        .mock("PROPFIND", home_set_path)
+        .match_body(mockito::Matcher::Regex("resourcetype".to_string()))
+        .with_status(207)
+        .with_body(
+            r#"
+            <d:multistatus xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav">
+                <d:response>
+                    <d:href>/calendars/testuser/tasks/</d:href>
+                    <d:propstat>
+                        <d:prop>
+                            <d:resourcetype><c:calendar/></d:resourcetype>
+                            <d:displayname>Tasks</d:displayname>
+                            <c:supported-calendar-component-set>
+                                <c:comp name="VTODO"/>
+                            </c:supported-calendar-component-set>
+                        </d:prop>
+                        <d:status>HTTP/1.1 200 OK</d:status>
+                    </d:propstat>
+                </d:response>
+            </d:multistatus>
+        "#,
+        )
+        .create_async()
+        .await;
+
+    let ctx = Arc::new(TestContext::new());
+    let client = RustyClient::new(ctx.clone(), &format!("{}/", url), "u", "p", false, None)
+        .expect("Client creation failed");
+    let server_url = client.client.as_ref().unwrap().base_url().to_string();
+
+    Cache::save_discovery(
+        ctx.as_ref(),
+        &DiscoveryCache {
+            server_url: server_url.clone(),
+            principal: "/principals/users/testuser/".to_string(),
+            home_set: home_set_path.to_string(),
+        },
+    )
+    .unwrap();
+
+    // The entry is only returned for the server it was recorded for.
+    assert!(Cache::load_discovery(ctx.as_ref(), &server_url).is_some());
+    assert!(Cache::load_discovery(ctx.as_ref(), "https://elsewhere.example/").is_none());
+
+    let (calendars, _) = client.get_calendars().await.expect("get_calendars failed");
+    assert!(
+        calendars
+            .iter()
+            .any(|c| c.href == "/calendars/testuser/tasks/")
+    );
+
+    mock_principal.assert();
+    mock_calendars.assert();
+
+    Cache::clear_discovery(ctx.as_ref()).unwrap();
+    assert!(Cache::load_discovery(ctx.as_ref(), &server_url).is_none());
+}
+
+#[tokio::test]
+async fn test_get_calendars_rediscovers_when_cached_home_set_is_gone() {
+    let mut server = Server::new_async().await;
+    let url = server.url();
+    let stale_home_set = "/old/calendars/testuser/";
+    let principal_path = "/principals/users/testuser/";
+    let home_set_path = "/calendars/testuser/";
+
+    // The cached home-set no longer exists on the server.
+    let mock_stale = server
+        .mock("PROPFIND", stale_home_set)
+        .with_status(404)
+        .create_async()
+        .await;
+
+    let mock_principal = server
+        .mock("PROPFIND", "/")
+        .match_body(mockito::Matcher::Regex(
+            "current-user-principal".to_string(),
+        ))
+        .with_status(207)
+        .with_body(format!(
+            r#"
+            <d:multistatus xmlns:d="DAV:">
+                <d:response>
+                    <d:href>/</d:href>
+                    <d:propstat>
+                        <d:prop>
+                            <d:current-user-principal>
+                                <d:href>{}</d:href>
+                            </d:current-user-principal>
+                        </d:prop>
+                        <d:status>HTTP/1.1 200 OK</d:status>
+                    </d:propstat>
+                </d:response>
+            </d:multistatus>
+        "#,
+            principal_path
+        ))
+        .create_async()
+        .await;
+
+    let mock_home_set = server
+        .mock("PROPFIND", principal_path)
+        .match_body(mockito::Matcher::Regex("calendar-home-set".to_string()))
+        .with_status(207)
+        .with_body(format!(
+            r#"
+            <d:multistatus xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav">
+                <d:response>
+                    <d:href>{}</d:href>
+                    <d:propstat>
+                        <d:prop>
+                            <c:calendar-home-set>
+                                <d:href>{}</d:href>
+                            </c:calendar-home-set>
+                        </d:prop>
+                        <d:status>HTTP/1.1 200 OK</d:status>
+                    </d:propstat>
+                </d:response>
+            </d:multistatus>
+        "#,
+            principal_path, home_set_path
+        ))
+        .create_async()
+        .await;
+
+    let mock_calendars = server
+        .mock("PROPFIND", home_set_path)
+        .match_body(mockito::Matcher::Regex("resourcetype".to_string()))
+        .with_status(207)
+        .with_body(
+            r#"
+            <d:multistatus xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav">
+                <d:response>
+                    <d:href>/calendars/testuser/tasks/</d:href>
+                    <d:propstat>
+                        <d:prop>
+                            <d:resourcetype><c:calendar/></d:resourcetype>
+                            <d:displayname>Tasks</d:displayname>
+                            <c:supported-calendar-component-set>
+                                <c:comp name="VTODO"/>
+                            </c:supported-calendar-component-set>
+                        </d:prop>
+                        <d:status>HTTP/1.1 200 OK</d:status>
+                    </d:propstat>
+                </d:response>
+            </d:multistatus>
+        "#,
+        )
+        .create_async()
+        .await;
+
+    let ctx = Arc::new(TestContext::new());
+    let client = RustyClient::new(ctx.clone(), &format!("{}/", url), "u", "p", false, None)
+        .expect("Client creation failed");
+    let server_url = client.client.as_ref().unwrap().base_url().to_string();
+
+    Cache::save_discovery(
+        ctx.as_ref(),
+        &DiscoveryCache {
+            server_url: server_url.clone(),
+            principal: principal_path.to_string(),
+            home_set: stale_home_set.to_string(),
+        },
+    )
+    .unwrap();
+
+    let (calendars, _) = client.get_calendars().await.expect("get_calendars failed");
+    assert!(
+        calendars
+            .iter()
+            .any(|c| c.href == "/calendars/testuser/tasks/")
+    );
+
+    mock_stale.assert();
+    mock_principal.assert();
+    mock_home_set.assert();
+    mock_calendars.assert();
+
+    // The fresh home-set replaces the stale one in the cache.
+    let cached = Cache::load_discovery(ctx.as_ref(), &server_url).expect("discovery cached");
+    assert_eq!(cached.home_set, home_set_path);
+}
+
+#[tokio::test]
+async fn test_get_calendars_single_propfind_keeps_order_and_missing_color() {
+    let mut server = Server::new_async().await;
+    let url = server.url();
+    let home_set_path = "/calendars/testuser/";
+
+    let calendar = |href: &str, name: &str, color: Option<&str>| {
+        format!(
+            r#"<d:response>
+                <d:href>{href}</d:href>
+                <d:propstat>
+                    <d:prop>
+                        <d:resourcetype><c:calendar/></d:resourcetype>
+                        <d:displayname>{name}</d:displayname>
+                        {}
+                        <c:supported-calendar-component-set>
+                            <c:comp name="VTODO"/>
+                        </c:supported-calendar-component-set>
+                    </d:prop>
+                    <d:status>HTTP/1.1 200 OK</d:status>
+                </d:propstat>
+            </d:response>"#,
+            color
+                .map(|c| format!("<ic:calendar-color>{c}</ic:calendar-color>"))
+                .unwrap_or_default()
+        )
+    };
+
+    // Names and colors for every calendar come from one Depth: 1 PROPFIND on the
+    // home-set; no per-calendar property requests are made.
+    let mock_home = server
+        .mock("PROPFIND", home_set_path)
+        .with_status(207)
+        .with_body(format!(
+            r#"<d:multistatus xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav" xmlns:ic="http://apple.com/ns/ical/">
+                {}{}{}
+            </d:multistatus>"#,
+            calendar("/calendars/testuser/work/", "Work", Some("#FF0000FF")),
+            calendar("/calendars/testuser/home/", "Home", None),
+            calendar("/calendars/testuser/misc/", "Misc", Some("#00FF00FF")),
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+    let mock_other = server
+        .mock(
+            "PROPFIND",
+            Matcher::Regex("^/calendars/testuser/.+".to_string()),
+        )
+        .expect(0)
+        .create_async()
+        .await;
+
+    let ctx = Arc::new(TestContext::new());
+    let client = RustyClient::new(ctx.clone(), &format!("{}/", url), "u", "p", false, None)
+        .expect("Client creation failed");
+    Cache::save_discovery(
+        ctx.as_ref(),
+        &DiscoveryCache {
+            server_url: client.client.as_ref().unwrap().base_url().to_string(),
+            principal: "/principals/users/testuser/".to_string(),
+            home_set: home_set_path.to_string(),
+        },
+    )
+    .unwrap();
+
+    let (calendars, _) = client.get_calendars().await.expect("get_calendars failed");
+    let remote: Vec<_> = calendars
+        .iter()
+        .filter(|c| !c.href.starts_with("local://"))
+        .map(|c| (c.name.as_str(), c.color.as_deref()))
+        .collect();
+    assert_eq!(
+        remote,
+        vec![
+            ("Work", Some("#FF0000FF")),
+            ("Home", None),
+            ("Misc", Some("#00FF00FF")),
+        ]
+    );
+
+    mock_home.assert();
+    mock_other.assert();
+}
+
 // Helper: mock a single-collection PROPFIND response carrying the given
 // supported-calendar-component-set and current-user-privilege-set bodies,
 // then return what get_supported_components() parsed out of it. Shared by the
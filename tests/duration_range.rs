@@ -484,3 +484,12 @@ fn test_format_duration_short_none() {
 
     assert_eq!(formatted, "");
 }
+
+#[test]
+fn test_format_elapsed_clock() {
+    use cfait::model::parser::format_elapsed_clock;
+
+    assert_eq!(format_elapsed_clock(0), "00:00");
+    assert_eq!(format_elapsed_clock(754), "12:34");
+    assert_eq!(format_elapsed_clock(3_725), "1:02:05");
+}
@@ -65,6 +65,39 @@ fn test_round_trip_escaping() {
     assert_eq!(task.to_smart_string(), "\\@@home");
 }
 
+#[test]
+fn test_bare_url_round_trip() {
+    let aliases = HashMap::new();
+
+    // The first pasted link becomes the URL, later ones stay in the summary
+    let task = Task::new(
+        "Read https://example.com/a and https://example.org #reading",
+        &aliases,
+        None,
+    );
+    assert_eq!(task.url, Some("https://example.com/a".to_string()));
+    assert_eq!(task.summary, "Read and https://example.org");
+    assert_eq!(task.categories, vec!["reading".to_string()]);
+
+    // Re-serialized as `url:` with the leftover link escaped, so parsing is stable
+    let smart = task.to_smart_string();
+    assert!(smart.contains("url:https://example.com/a"));
+    assert!(smart.contains("\\https://example.org"));
+    let reparsed = Task::new(&smart, &aliases, None);
+    assert_eq!(reparsed.url, task.url);
+    assert_eq!(reparsed.summary, task.summary);
+
+    // An explicit url: wins and the pasted link is left alone
+    let task = Task::new("See http://a.example url:b.example", &aliases, None);
+    assert_eq!(task.url, Some("https://b.example".to_string()));
+    assert_eq!(task.summary, "See http://a.example");
+
+    // Not a link: no host, or another scheme
+    let task = Task::new("Broken https:// and geo:50.1,4.2", &aliases, None);
+    assert!(task.url.is_none());
+    assert_eq!(task.geo, Some("50.1,4.2".to_string()));
+}
+
 #[test]
 fn test_mixed_escaping() {
     let aliases = HashMap::new();
@@ -341,3 +341,285 @@ fn test_export_then_merge_import() {
     let tasks_b = LocalStorage::load_for_href(&ctx, calendar_b).unwrap();
     assert_eq!(tasks_b.len(), 3);
 }
+
+#[test]
+fn test_tzid_times_across_dst_boundary_round_trip() {
+    // Brussels springs forward at 02:00 CET (01:00Z) on 2025-03-30.
+    let vtimezone = "BEGIN:VTIMEZONE\r\nTZID:Europe/Brussels\r\nBEGIN:STANDARD\r\nDTSTART:19701025T030000\r\nRRULE:FREQ=YEARLY;BYMONTH=10;BYDAY=-1SU\r\nTZOFFSETFROM:+0200\r\nTZOFFSETTO:+0100\r\nEND:STANDARD\r\nBEGIN:DAYLIGHT\r\nDTSTART:19700329T020000\r\nRRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=-1SU\r\nTZOFFSETFROM:+0100\r\nTZOFFSETTO:+0200\r\nEND:DAYLIGHT\r\nEND:VTIMEZONE\r\n";
+    let ics = "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:-//other//EN\r\nVTIMEZONE_HERE\r\nBEGIN:VTODO\r\nUID:tz-1\r\nSUMMARY:Night shift\r\nDTSTART;TZID=Europe/Brussels:20250329T230000\r\nDUE;TZID=Europe/Brussels:20250330T090000\r\nEXDATE;TZID=/Europe/Brussels:20250406T230000\r\nEND:VTODO\r\nEND:VCALENDAR\r\n"
+        .replace("VTIMEZONE_HERE\r\n", vtimezone);
+
+    let task = Task::from_ics(&ics, String::new(), String::new(), String::new()).unwrap();
+    // Before the switch: UTC+1. After: UTC+2. No hour lost or gained either side.
+    assert_eq!(
+        task.dtstart,
+        Some(DateType::Specific(
+            Utc.with_ymd_and_hms(2025, 3, 29, 22, 0, 0).unwrap()
+        ))
+    );
+    assert_eq!(
+        task.due,
+        Some(DateType::Specific(
+            Utc.with_ymd_and_hms(2025, 3, 30, 7, 0, 0).unwrap()
+        ))
+    );
+    assert_eq!(
+        task.exdates,
+        vec![DateType::Specific(
+            Utc.with_ymd_and_hms(2025, 4, 6, 21, 0, 0).unwrap()
+        )]
+    );
+
+    // The zone is written back with the same wall-clock times
+    let out = task.to_ics();
+    assert!(
+        out.contains("DTSTART;TZID=Europe/Brussels:20250329T230000"),
+        "Got:\n{}",
+        out
+    );
+    assert!(
+        out.contains("DUE;TZID=Europe/Brussels:20250330T090000"),
+        "Got:\n{}",
+        out
+    );
+    // ...next to the original VTIMEZONE, unchanged (no DTSTAMP/UID added to it)
+    assert!(out.contains(vtimezone), "Got:\n{}", out);
+    assert_eq!(out.matches("BEGIN:VTIMEZONE").count(), 1);
+    let back = Task::from_ics(&out, String::new(), String::new(), String::new()).unwrap();
+    assert_eq!(back.dtstart, task.dtstart);
+    assert_eq!(back.due, task.due);
+
+    // A time in the spring-forward gap resolves to the hour before the jump
+    let gap = ics.replace("20250330T090000", "20250330T023000");
+    let task = Task::from_ics(&gap, String::new(), String::new(), String::new()).unwrap();
+    assert_eq!(
+        task.due,
+        Some(DateType::Specific(
+            Utc.with_ymd_and_hms(2025, 3, 30, 0, 30, 0).unwrap()
+        ))
+    );
+
+    // Without a VTIMEZONE to reference, the TZID is not written back
+    let bare = ics.replace(vtimezone, "");
+    let out = Task::from_ics(&bare, String::new(), String::new(), String::new())
+        .unwrap()
+        .to_ics();
+    assert!(out.contains("DUE:20250330T070000Z"), "Got:\n{}", out);
+    assert!(!out.contains("TZID="), "Got:\n{}", out);
+
+    // Tasks without a TZID are still written in UTC
+    let mut plain = Task::new("Plain", &HashMap::new(), None);
+    plain.due = task.due.clone();
+    assert!(plain.to_ics().contains("DUE:20250330T003000Z"));
+}
+
+// ==================== Whole-Store Backup ====================
+
+#[test]
+#[serial]
+fn test_export_all_ics_roundtrip_preserves_alarms_and_unmapped() {
+    use cfait::model::RawProperty;
+    use cfait::store::{ExportFormat, TaskStore};
+    use std::sync::Arc;
+
+    let ctx = Arc::new(TestContext::new());
+    let mut store = TaskStore::new(ctx.clone());
+
+    let mut home = Task::new("Water plants", &HashMap::new(), None);
+    home.due = Some(DateType::Specific(
+        Utc.with_ymd_and_hms(2026, 5, 1, 9, 0, 0).unwrap(),
+    ));
+    home.alarms.push(Alarm::new_relative(30));
+    home.unmapped_properties.push(RawProperty {
+        key: "X-CUSTOM-FIELD".to_string(),
+        value: "keep me".to_string(),
+        params: vec![],
+    });
+    let work = Task::new("Send report", &HashMap::new(), None);
+    let trashed = Task::new("Old junk", &HashMap::new(), None);
+
+    store.insert("local://home".to_string(), vec![home.clone()]);
+    store.insert("local://work".to_string(), vec![work.clone()]);
+    store.insert(
+        cfait::storage::LOCAL_TRASH_HREF.to_string(),
+        vec![trashed.clone()],
+    );
+
+    let ics = store.export_all(ExportFormat::Ics);
+    assert_eq!(ics.matches("BEGIN:VCALENDAR").count(), 1);
+    assert_eq!(ics.matches("BEGIN:VTODO").count(), 2);
+    assert!(!ics.contains("Old junk"));
+
+    LocalStorage::import_from_ics(ctx.as_ref(), "local://restored", &ics).unwrap();
+    let restored = LocalStorage::load_for_href(ctx.as_ref(), "local://restored").unwrap();
+    assert_eq!(restored.len(), 2);
+
+    let r_home = restored.iter().find(|t| t.uid == home.uid).unwrap();
+    assert_eq!(r_home.summary, "Water plants");
+    assert_eq!(r_home.due, home.due);
+    assert_eq!(r_home.alarms.len(), 1);
+    assert_eq!(r_home.alarms[0].trigger, home.alarms[0].trigger);
+    assert!(
+        r_home
+            .unmapped_properties
+            .iter()
+            .any(|p| p.key == "X-CUSTOM-FIELD" && p.value == "keep me")
+    );
+    assert!(restored.iter().any(|t| t.uid == work.uid));
+}
+
+#[test]
+#[serial]
+fn test_export_all_json_is_array_of_tasks() {
+    use cfait::store::{ExportFormat, TaskStore};
+    use std::sync::Arc;
+
+    let ctx = Arc::new(TestContext::new());
+    let mut store = TaskStore::new(ctx);
+    let a = Task::new("Alpha", &HashMap::new(), None);
+    let b = Task::new("Beta", &HashMap::new(), None);
+    store.insert("local://a".to_string(), vec![a.clone()]);
+    store.insert("local://b".to_string(), vec![b.clone()]);
+
+    let json = store.export_all(ExportFormat::Json);
+    let parsed: Vec<Task> = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.len(), 2);
+    assert!(parsed.iter().any(|t| t.uid == a.uid));
+    assert!(parsed.iter().any(|t| t.uid == b.uid));
+}
+
+#[test]
+fn test_multiple_links_roundtrip() {
+    let aliases = HashMap::new();
+
+    // Repeated url: tokens keep the first as the primary link
+    let task = Task::new(
+        "Research url:a.example url:https://b.example [[https://c.example]]",
+        &aliases,
+        None,
+    );
+    assert_eq!(task.url.as_deref(), Some("https://a.example"));
+    assert_eq!(
+        task.attachments,
+        vec![
+            "https://b.example".to_string(),
+            "https://c.example".to_string()
+        ]
+    );
+    let reparsed = Task::new(&task.to_smart_string(), &aliases, None);
+    assert_eq!(reparsed.url, task.url);
+    assert_eq!(reparsed.attachments, task.attachments);
+
+    // ICS: extra URL lines and ATTACH links are read; inline binary ATTACH stays raw
+    let ics = "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:-//test//EN\r\nBEGIN:VTODO\r\nUID:links-1\r\nSUMMARY:Links\r\nURL:https://primary.example\r\nATTACH:https://doc.example/spec.pdf\r\nATTACH;FMTTYPE=text/plain;ENCODING=BASE64;VALUE=BINARY:aGVsbG8=\r\nEND:VTODO\r\nEND:VCALENDAR\r\n";
+    let imported = Task::from_ics(
+        ics,
+        "etag".to_string(),
+        "href".to_string(),
+        "cal".to_string(),
+    )
+    .unwrap();
+    assert_eq!(imported.url.as_deref(), Some("https://primary.example"));
+    assert_eq!(
+        imported.attachments,
+        vec!["https://doc.example/spec.pdf".to_string()]
+    );
+    assert!(
+        imported
+            .unmapped_properties
+            .iter()
+            .any(|p| p.key == "ATTACH" && p.value == "aGVsbG8=")
+    );
+
+    let exported = imported.to_ics();
+    assert_eq!(exported.matches("https://doc.example/spec.pdf").count(), 1);
+    let again = Task::from_ics(
+        &exported,
+        "etag".to_string(),
+        "href".to_string(),
+        "cal".to_string(),
+    )
+    .unwrap();
+    assert_eq!(again.url, imported.url);
+    assert_eq!(again.attachments, imported.attachments);
+}
+
+#[test]
+fn test_unknown_alarm_and_subcomponent_data_survive_edit() {
+    let ics = "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:-//other//EN\r\nBEGIN:VTODO\r\nUID:raw-1\r\nSUMMARY:Call Bob\r\nX-OTHER-CLIENT:keep-me\r\nBEGIN:VALARM\r\nUID:alarm-audio\r\nACTION:AUDIO\r\nTRIGGER:-PT15M\r\nATTACH;FMTTYPE=audio/basic:ftp://example.com/pub/sounds/bell-01.aud\r\nREPEAT:4\r\nDURATION:PT5M\r\nEND:VALARM\r\nBEGIN:X-VENDOR-DATA\r\nX-PAYLOAD:opaque\r\nEND:X-VENDOR-DATA\r\nEND:VTODO\r\nBEGIN:VTODO\r\nUID:raw-1\r\nRECURRENCE-ID:20250102T090000Z\r\nSUMMARY:Override\r\nBEGIN:VALARM\r\nACTION:DISPLAY\r\nTRIGGER:-PT5M\r\nEND:VALARM\r\nEND:VTODO\r\nEND:VCALENDAR\r\n";
+    let mut task = Task::from_ics(
+        ics,
+        "etag".to_string(),
+        "href".to_string(),
+        "cal".to_string(),
+    )
+    .unwrap();
+
+    // The override's alarm belongs to the override, not the master
+    assert_eq!(task.alarms.len(), 1);
+    let alarm = &task.alarms[0];
+    assert_eq!(alarm.action, "AUDIO");
+    assert!(alarm.extra_lines.contains(
+        &"ATTACH;FMTTYPE=audio/basic:ftp://example.com/pub/sounds/bell-01.aud".to_string()
+    ));
+    assert!(task.attachments.is_empty());
+    assert_eq!(task.raw_subcomponents.len(), 1);
+
+    // Edit, serialize, and read back
+    task.summary = "Call Bob back".to_string();
+    let out = task.to_ics();
+    assert!(out.contains("ATTACH;FMTTYPE=audio/basic:ftp://example.com/pub/sounds/bell-01.aud"));
+    assert!(out.contains("REPEAT:4"));
+    assert!(out.contains("BEGIN:X-VENDOR-DATA\r\nX-PAYLOAD:opaque\r\nEND:X-VENDOR-DATA"));
+    assert!(out.contains("X-OTHER-CLIENT:keep-me"));
+    assert!(out.contains("RECURRENCE-ID:20250102T090000Z"));
+
+    let reparsed = Task::from_ics(
+        &out,
+        "etag".to_string(),
+        "href".to_string(),
+        "cal".to_string(),
+    )
+    .unwrap();
+    assert_eq!(reparsed.summary, "Call Bob back");
+    assert_eq!(reparsed.alarms, task.alarms);
+    assert_eq!(reparsed.raw_subcomponents, task.raw_subcomponents);
+    assert_eq!(reparsed.raw_components.len(), 1);
+}
+
+#[test]
+fn test_created_and_completed_stamps_roundtrip() {
+    let ics = "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:-//other//EN\r\nBEGIN:VTODO\r\nUID:stamps-1\r\nSUMMARY:Ship\r\nSTATUS:COMPLETED\r\nCREATED:20260101T080000Z\r\nCOMPLETED:20260105\r\nEND:VTODO\r\nEND:VCALENDAR\r\n";
+    let task = Task::from_ics(
+        ics,
+        "etag".to_string(),
+        "href".to_string(),
+        "cal".to_string(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        task.created,
+        Some(Utc.with_ymd_and_hms(2026, 1, 1, 8, 0, 0).unwrap())
+    );
+    assert_eq!(
+        task.completed,
+        Some(Utc.with_ymd_and_hms(2026, 1, 5, 0, 0, 0).unwrap())
+    );
+    assert!(task.unmapped_properties.is_empty());
+
+    let out = task.to_ics();
+    assert_eq!(out.matches("CREATED:20260101T080000Z").count(), 1);
+    assert_eq!(out.matches("COMPLETED:20260105T000000Z").count(), 1);
+
+    // Local JSON from before the typed fields kept the stamps as raw properties
+    let mut legacy = serde_json::to_value(&task).unwrap();
+    legacy.as_object_mut().unwrap().remove("created");
+    legacy.as_object_mut().unwrap().remove("completed");
+    legacy["unmapped_properties"] = serde_json::json!([
+        { "key": "CREATED", "value": "20260101T080000Z", "params": [] }
+    ]);
+    let old: Task = serde_json::from_value(legacy).unwrap();
+    assert_eq!(old.created, None);
+    assert_eq!(old.created_date(), task.created);
+}
@@ -0,0 +1,122 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+//! Tests for the opt-in `limit_fetch_to_cutoff` fetch mode.
+use cfait::client::core::RustyClient;
+use cfait::config::Config;
+use cfait::context::TestContext;
+use mockito::{Matcher, Server};
+use std::sync::Arc;
+
+fn multistatus(entries: &[(&str, &str)]) -> String {
+    let mut body =
+        String::from(r#"<d:multistatus xmlns:d="DAV:" xmlns:cal="urn:ietf:params:xml:ns:caldav">"#);
+    for (href, uid) in entries {
+        body.push_str(&format!(
+            r#"<d:response>
+                <d:href>{href}</d:href>
+                <d:propstat>
+                    <d:prop>
+                        <d:getetag>"etag-{uid}"</d:getetag>
+                        <cal:calendar-data>BEGIN:VCALENDAR
+VERSION:2.0
+BEGIN:VTODO
+UID:{uid}
+SUMMARY:{uid}
+END:VTODO
+END:VCALENDAR</cal:calendar-data>
+                    </d:prop>
+                    <d:status>HTTP/1.1 200 OK</d:status>
+                </d:propstat>
+            </d:response>"#
+        ));
+    }
+    body.push_str("</d:multistatus>");
+    body
+}
+
+fn limited_client(url: &str) -> RustyClient {
+    let ctx = Arc::new(TestContext::new());
+    Config {
+        limit_fetch_to_cutoff: true,
+        sort_cutoff_days: Some(30),
+        sync_settings: false,
+        ..Default::default()
+    }
+    .save(ctx.as_ref())
+    .unwrap();
+    RustyClient::new(ctx, url, "u", "p", false, None).expect("Client creation failed")
+}
+
+#[tokio::test]
+async fn test_limited_fetch_merges_open_and_recent_queries() {
+    let mut server = Server::new_async().await;
+
+    let m_open = server
+        .mock("REPORT", "/cal/")
+        .match_body(Matcher::Regex("is-not-defined".to_string()))
+        .with_status(207)
+        .with_body(multistatus(&[("/cal/open.ics", "open")]))
+        .expect(1)
+        .create_async()
+        .await;
+    let m_recent = server
+        .mock("REPORT", "/cal/")
+        .match_body(Matcher::Regex("time-range".to_string()))
+        .with_status(207)
+        .with_body(multistatus(&[("/cal/recent.ics", "recent")]))
+        .expect(1)
+        .create_async()
+        .await;
+    let _m_multiget = server
+        .mock("REPORT", "/cal/")
+        .match_body(Matcher::Regex("calendar-multiget".to_string()))
+        .with_status(207)
+        .with_body(multistatus(&[
+            ("/cal/open.ics", "open"),
+            ("/cal/recent.ics", "recent"),
+        ]))
+        .create_async()
+        .await;
+
+    let client = limited_client(&server.url());
+    let tasks = client.get_tasks("/cal/").await.unwrap();
+
+    let mut uids: Vec<_> = tasks.iter().map(|t| t.uid.as_str()).collect();
+    uids.sort();
+    assert_eq!(uids, vec!["open", "recent"]);
+    m_open.assert();
+    m_recent.assert();
+}
+
+#[tokio::test]
+async fn test_limited_fetch_falls_back_when_report_rejected() {
+    let mut server = Server::new_async().await;
+
+    let _m_open = server
+        .mock("REPORT", "/cal/")
+        .match_body(Matcher::Regex("is-not-defined".to_string()))
+        .with_status(501)
+        .create_async()
+        .await;
+    let m_full = server
+        .mock("REPORT", "/cal/")
+        .match_body(Matcher::Regex(r#"name="VTODO"/>"#.to_string()))
+        .with_status(207)
+        .with_body(multistatus(&[("/cal/old.ics", "old")]))
+        .expect(1)
+        .create_async()
+        .await;
+    let _m_multiget = server
+        .mock("REPORT", "/cal/")
+        .match_body(Matcher::Regex("calendar-multiget".to_string()))
+        .with_status(207)
+        .with_body(multistatus(&[("/cal/old.ics", "old")]))
+        .create_async()
+        .await;
+
+    let client = limited_client(&server.url());
+    let tasks = client.get_tasks("/cal/").await.unwrap();
+
+    assert_eq!(tasks.len(), 1);
+    assert_eq!(tasks[0].uid, "old");
+    m_full.assert();
+}
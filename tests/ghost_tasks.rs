@@ -191,3 +191,83 @@ async fn test_ghost_is_pruned_on_full_sync_with_ctag_mismatch() {
     mock_list.assert();
     assert!(tasks.is_empty(), "Ghost task should be pruned on full sync");
 }
+
+#[test]
+fn test_stale_unsynced_tasks_are_listed_and_retryable() {
+    use cfait::model::AppIntent;
+    use cfait::store::TaskStore;
+    use chrono::{Duration, Utc};
+
+    let ctx = Arc::new(TestContext::new());
+    let mut store = TaskStore::new(ctx.clone());
+    let cal = "https://example.com/cal/".to_string();
+
+    let mut stale = Task::new("Stuck", &HashMap::new(), None);
+    stale.uid = "stuck".to_string();
+    stale.calendar_href = cal.clone();
+    stale.set_created_date(Utc::now() - Duration::hours(30));
+
+    // The server took this one; only its ETag still has to be fetched
+    let mut accepted = Task::new("Accepted", &HashMap::new(), None);
+    accepted.uid = "accepted".to_string();
+    accepted.calendar_href = cal.clone();
+    accepted.etag = "pending_refresh".to_string();
+    accepted.set_created_date(Utc::now() - Duration::hours(30));
+
+    // Still queued, so the next sync uploads it
+    let mut queued = Task::new("Queued", &HashMap::new(), None);
+    queued.uid = "queued".to_string();
+    queued.calendar_href = cal.clone();
+    queued.set_created_date(Utc::now() - Duration::hours(30));
+    Journal::push(ctx.as_ref(), Action::Create(queued.clone())).unwrap();
+
+    let mut fresh = Task::new("Just added", &HashMap::new(), None);
+    fresh.uid = "fresh".to_string();
+    fresh.calendar_href = cal.clone();
+
+    let mut synced = Task::new("Synced", &HashMap::new(), None);
+    synced.uid = "synced".to_string();
+    synced.calendar_href = cal.clone();
+    synced.etag = "\"abc\"".to_string();
+    synced.set_created_date(Utc::now() - Duration::hours(30));
+
+    let mut local = Task::new("Local", &HashMap::new(), None);
+    local.uid = "local".to_string();
+    local.calendar_href = cfait::storage::LOCAL_CALENDAR_HREF.to_string();
+    local.set_created_date(Utc::now() - Duration::hours(30));
+
+    store.add_task(stale);
+    store.add_task(accepted);
+    store.add_task(queued);
+    store.add_task(fresh);
+    store.add_task(synced);
+    store.add_task(local);
+
+    let uids: Vec<&str> = store
+        .stale_unsynced_tasks(24)
+        .iter()
+        .map(|t| t.uid.as_str())
+        .collect();
+    assert_eq!(uids, vec!["stuck"]);
+    assert!(store.stale_unsynced_tasks(0).is_empty());
+
+    let actions = store.apply_task_intent(
+        &AppIntent::RetryUnsynced {
+            uid: "stuck".to_string(),
+        },
+        &cfait::config::Config::default(),
+    );
+    assert!(
+        matches!(actions.as_slice(), [Action::Update(t)] if t.uid == "stuck" && t.etag.is_empty())
+    );
+
+    for uid in ["synced", "accepted", "queued"] {
+        let none = store.apply_task_intent(
+            &AppIntent::RetryUnsynced {
+                uid: uid.to_string(),
+            },
+            &cfait::config::Config::default(),
+        );
+        assert!(none.is_empty(), "{uid} must not be retried");
+    }
+}
@@ -244,3 +244,105 @@ fn test_cli_import_from_file() {
 
     fs::remove_file(&test_file).ok();
 }
+
+// ==================== Bulk Restore ====================
+
+#[test]
+#[serial]
+fn test_bulk_import_skip_duplicates_and_collect_errors() {
+    use cfait::storage::DuplicatePolicy;
+    use cfait::store::TaskStore;
+    use std::sync::Arc;
+
+    let ctx = Arc::new(TestContext::new());
+    let mut store = TaskStore::new(ctx);
+
+    let mut existing = Task::new("Already here", &std::collections::HashMap::new(), None);
+    existing.uid = "test-task-1".to_string();
+    existing.calendar_href = "local://default".to_string();
+    store.add_task(existing);
+
+    // Second VTODO is truncated; third is fine.
+    let ics = "BEGIN:VCALENDAR\nVERSION:2.0\n\
+        BEGIN:VTODO\nUID:test-task-1\nSUMMARY:Dup\nEND:VTODO\n\
+        BEGIN:VTODO\nUID:broken\nSUMMARY:No end\n\
+        BEGIN:VTODO\nUID:fresh\nSUMMARY:Fresh\nEND:VTODO\nEND:VCALENDAR";
+
+    let (actions, summary) = store.import_tasks(ics, "local://default", DuplicatePolicy::Skip);
+    assert_eq!(summary.imported, 1);
+    assert_eq!(summary.skipped, 1);
+    assert_eq!(summary.errors.len(), 1);
+    assert_eq!(actions.len(), 1);
+    assert!(store.get_task_ref("fresh").is_some());
+    assert_eq!(
+        store.get_task_ref("test-task-1").unwrap().summary,
+        "Already here"
+    );
+}
+
+#[test]
+#[serial]
+fn test_bulk_import_conflict_copies_from_json() {
+    use cfait::storage::DuplicatePolicy;
+    use cfait::store::TaskStore;
+    use std::sync::Arc;
+
+    let ctx = Arc::new(TestContext::new());
+    let mut store = TaskStore::new(ctx);
+
+    let mut original = Task::new("Shared", &std::collections::HashMap::new(), None);
+    original.calendar_href = "local://default".to_string();
+    store.add_task(original.clone());
+
+    let json = format!(
+        "[{}, {{\"not\": \"a task\"}}]",
+        serde_json::to_string(&original).unwrap()
+    );
+    let (actions, summary) =
+        store.import_tasks(&json, "local://default", DuplicatePolicy::ConflictCopy);
+
+    assert_eq!(summary.imported, 1);
+    assert_eq!(summary.conflict_copies, 1);
+    assert_eq!(summary.errors.len(), 1);
+    assert!(summary.errors[0].starts_with("#2"));
+    assert_eq!(actions.len(), 1);
+
+    let copies: Vec<_> = store.calendars["local://default"]
+        .values()
+        .filter(|t| t.summary == "Shared (Conflict Copy)")
+        .collect();
+    assert_eq!(copies.len(), 1);
+    assert_ne!(copies[0].uid, original.uid);
+}
+
+#[tokio::test]
+#[serial]
+async fn test_bulk_import_to_remote_is_journaled() {
+    use cfait::controller::TaskController;
+    use cfait::journal::{Action, Journal};
+    use cfait::storage::DuplicatePolicy;
+    use cfait::store::TaskStore;
+    use std::sync::Arc;
+
+    let ctx: Arc<dyn cfait::context::AppContext> = Arc::new(TestContext::new());
+    let store = Arc::new(tokio::sync::Mutex::new(TaskStore::new(ctx.clone())));
+    let client = Arc::new(tokio::sync::Mutex::new(None));
+    let controller = TaskController::new(store, client, ctx.clone());
+
+    let summary = controller
+        .import_tasks(&create_simple_ics(), "/cal/work/", DuplicatePolicy::Skip)
+        .await
+        .unwrap();
+    assert_eq!(summary.imported, 1);
+
+    let journal = Journal::load(ctx.as_ref());
+    assert_eq!(journal.queue.len(), 1);
+    match &journal.queue[0] {
+        Action::Create(t) => {
+            assert_eq!(t.uid, "test-task-1");
+            assert_eq!(t.calendar_href, "/cal/work/");
+            assert_eq!(t.href, "/cal/work/test-task-1.ics");
+        }
+        other => panic!("expected Create, got {:?}", other),
+    }
+}
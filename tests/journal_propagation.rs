@@ -83,3 +83,59 @@ async fn test_move_propagates_href_to_pending_update() {
     let j = Journal::load(ctx.as_ref());
     assert!(j.is_empty(), "Journal should be empty");
 }
+
+#[test]
+fn test_drop_action_checks_uid_at_index() {
+    let ctx = Arc::new(TestContext::new());
+
+    let mut a = Task::new("Stuck", &HashMap::new(), None);
+    a.uid = "a".to_string();
+    let mut b = Task::new("Fine", &HashMap::new(), None);
+    b.uid = "b".to_string();
+
+    Journal::push(ctx.as_ref(), Action::Create(a)).unwrap();
+    Journal::push(ctx.as_ref(), Action::Update(b)).unwrap();
+
+    // Stale index/uid pair is rejected
+    assert!(!Journal::drop_action(ctx.as_ref(), 0, "b").unwrap());
+    assert_eq!(Journal::load(ctx.as_ref()).queue.len(), 2);
+
+    assert!(Journal::drop_action(ctx.as_ref(), 0, "a").unwrap());
+    let queue = Journal::load(ctx.as_ref()).queue;
+    assert_eq!(queue.len(), 1);
+    assert_eq!(queue[0].task().uid, "b");
+}
+
+#[test]
+fn test_compact_on_disk_merges_updates_to_one_uid() {
+    let ctx = Arc::new(TestContext::new());
+
+    let mut t = Task::new("Draft", &HashMap::new(), None);
+    t.uid = "same".to_string();
+    t.etag = "\"e1\"".to_string();
+    for summary in ["One", "Two", "Three"] {
+        let mut u = t.clone();
+        u.summary = summary.to_string();
+        Journal::push(ctx.as_ref(), Action::Update(u)).unwrap();
+    }
+    let mut other = Task::new("Other", &HashMap::new(), None);
+    other.uid = "other".to_string();
+    Journal::push(ctx.as_ref(), Action::Update(other)).unwrap();
+
+    assert_eq!(Journal::compact_on_disk(ctx.as_ref()).unwrap(), 2);
+
+    let queue = Journal::load(ctx.as_ref()).queue;
+    assert_eq!(queue.len(), 2);
+    match &queue[0] {
+        Action::Update(t) => {
+            assert_eq!(t.uid, "same");
+            assert_eq!(t.summary, "Three");
+            assert_eq!(t.etag, "\"e1\"");
+        }
+        other => panic!("expected update, got {:?}", other),
+    }
+    assert_eq!(queue[1].task().uid, "other");
+
+    // Idempotent
+    assert_eq!(Journal::compact_on_disk(ctx.as_ref()).unwrap(), 0);
+}
@@ -1,10 +1,11 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 //! Tests for local duplication bug.
-use cfait::config::SortPreset;
+use cfait::config::{AllDaySort, SortMode, SortPreset};
 use cfait::context::TestContext;
 use cfait::model::{Task, TaskStatus};
 use cfait::storage::{LOCAL_CALENDAR_HREF, LocalStorage};
 use cfait::store::{FilterOptions, TaskStore};
+use chrono::Weekday;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
@@ -58,12 +59,19 @@ fn test_reproduce_android_local_revert_bug() {
         match_all_categories: false,
         search_term: "",
         hide_completed_global: false, // Don't hide so we can check
+        hide_deferred: false,
         hide_fully_completed_tags: false,
         hide_aliases_in_sidebar: false,
         cutoff_date: None,
         min_duration: None,
         max_duration: None,
         include_unset_duration: true,
+        exact_priority: None,
+        require_unset_duration: false,
+        excluded_categories: &HashSet::new(),
+        excluded_locations: &HashSet::new(),
+        excluded_priorities: &HashSet::new(),
+        excluded_statuses: &HashSet::new(),
         urgent_days: 1,
         urgent_prio: 1,
         default_priority: 5,
@@ -71,6 +79,10 @@ fn test_reproduce_android_local_revert_bug() {
         // Newly required fields in FilterOptions:
         sort_standard_by_priority: false,
         sort_preset: SortPreset::default(),
+        sort_mode: SortMode::default(),
+        all_day_sort: AllDaySort::default(),
+        week_start: Weekday::Mon,
+        sort_urgency_boost: false,
         expanded_done_groups: &HashSet::new(),
         expanded_tags: &HashSet::new(),
         expanded_locations: &HashSet::new(),
@@ -80,6 +92,7 @@ fn test_reproduce_android_local_revert_bug() {
         tag_aliases: &HashMap::new(),
         search_collapsed_tasks: &HashSet::new(),
         focused_task_uid: None,
+        fuzzy_search: false,
     });
 
     let visible_task = filter_res
@@ -201,3 +201,44 @@ fn test_cancel_single_occurrence_daily() {
     assert_eq!(advanced_task.status, TaskStatus::NeedsAction);
     assert!(advanced_task.due.as_ref().unwrap().to_comparison_time() > Utc::now());
 }
+
+#[test]
+fn test_skip_occurrence_keeps_status_and_progress() {
+    let mut t = create_task_due_in_days(2, "FREQ=DAILY");
+    t.status = TaskStatus::InProcess;
+    t.percent_complete = Some(40);
+    t.time_spent_seconds = 120;
+    let original_due = t.due.clone().unwrap();
+
+    assert!(t.skip_occurrence());
+    assert_eq!(t.status, TaskStatus::InProcess);
+    assert_eq!(t.percent_complete, Some(40));
+    assert_eq!(t.time_spent_seconds, 120);
+    assert!(t.due.as_ref().unwrap() > &original_due);
+    assert!(t.exdates.contains(&original_due));
+}
+
+#[test]
+fn test_skip_occurrence_dtstart_only() {
+    let mut t = Task::new("Task", &HashMap::new(), None);
+    let start = Utc::now() + Duration::days(3);
+    t.dtstart = Some(DateType::Specific(start));
+    t.rrule = Some("FREQ=WEEKLY".to_string());
+
+    assert!(t.skip_occurrence());
+    assert!(t.due.is_none());
+    match t.dtstart.as_ref().unwrap() {
+        DateType::Specific(d) => assert!(*d > start + Duration::days(6)),
+        _ => panic!("Expected specific dtstart"),
+    }
+}
+
+#[test]
+fn test_skip_occurrence_exhausted_series_is_noop() {
+    let mut t = create_task_due_in_days(2, "FREQ=DAILY;COUNT=1");
+    t.status = TaskStatus::NeedsAction;
+    let before = t.clone();
+
+    assert!(!t.skip_occurrence());
+    assert_eq!(t, before);
+}
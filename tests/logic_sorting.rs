@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 //! Tests for task sorting logic.
-use cfait::config::SortPreset;
+use cfait::config::{AllDaySort, SortMode, SortPreset};
 use cfait::model::item::{CompareOptions, SortKey, compare_sortkeys};
 use cfait::model::{DateType, Task, TaskStatus};
 use cfait::store::organize_hierarchy;
@@ -33,7 +33,8 @@ fn test_sorting_priority_basic() {
                 default_priority: 5,
                 start_grace_period_days: 1,
                 sort_standard_by_priority: false,
-                sort_preset: SortPreset::default()
+                sort_preset: SortPreset::default(),
+                all_day_sort: AllDaySort::default(),
             }
         ), // Pass defaults
         std::cmp::Ordering::Less
@@ -50,7 +51,8 @@ fn test_sorting_priority_basic() {
                 default_priority: 5,
                 start_grace_period_days: 1,
                 sort_standard_by_priority: false,
-                sort_preset: SortPreset::default()
+                sort_preset: SortPreset::default(),
+                all_day_sort: AllDaySort::default(),
             }
         ), // Pass defaults
         std::cmp::Ordering::Less
@@ -83,7 +85,8 @@ fn test_sorting_status_trumps_everything() {
                 default_priority: 5,
                 start_grace_period_days: 1,
                 sort_standard_by_priority: false,
-                sort_preset: SortPreset::default()
+                sort_preset: SortPreset::default(),
+                all_day_sort: AllDaySort::default(),
             }
         ),
         std::cmp::Ordering::Less
@@ -111,7 +114,8 @@ fn test_sorting_completed_sinks() {
                 default_priority: 5,
                 start_grace_period_days: 1,
                 sort_standard_by_priority: false,
-                sort_preset: SortPreset::default()
+                sort_preset: SortPreset::default(),
+                all_day_sort: AllDaySort::default(),
             }
         ),
         std::cmp::Ordering::Less
@@ -142,7 +146,8 @@ fn test_sorting_due_dates() {
                 default_priority: 5,
                 start_grace_period_days: 1,
                 sort_standard_by_priority: false,
-                sort_preset: SortPreset::default()
+                sort_preset: SortPreset::default(),
+                all_day_sort: AllDaySort::default(),
             }
         ),
         std::cmp::Ordering::Less
@@ -159,7 +164,8 @@ fn test_sorting_due_dates() {
                 default_priority: 5,
                 start_grace_period_days: 1,
                 sort_standard_by_priority: false,
-                sort_preset: SortPreset::default()
+                sort_preset: SortPreset::default(),
+                all_day_sort: AllDaySort::default(),
             }
         ),
         std::cmp::Ordering::Less
@@ -190,6 +196,9 @@ fn test_hierarchy_organization() {
             max_done_subtasks: usize::MAX,
             search_active: false,
             sort_preset: SortPreset::default(),
+            sort_mode: SortMode::default(),
+            all_day_sort: AllDaySort::default(),
+            sort_urgency_boost: false,
             search_collapsed_tasks: &HashSet::new(),
             focused_task_uid: None,
         },
@@ -234,7 +243,8 @@ fn test_sort_standard_date_first() {
             &high_prio_late,
             5,
             false,
-            SortPreset::default()
+            SortPreset::default(),
+            AllDaySort::default()
         ),
         std::cmp::Ordering::Less,
         "date-first: soon task should sort before late task regardless of priority"
@@ -266,7 +276,8 @@ fn test_sort_standard_priority_first() {
             &low_prio_soon,
             5,
             true,
-            SortPreset::default()
+            SortPreset::default(),
+            AllDaySort::default()
         ),
         std::cmp::Ordering::Less,
         "priority-first: high-priority task should sort before low-priority task regardless of date"
@@ -299,7 +310,8 @@ fn test_sort_merged_rank4_rank5_priority_wins() {
             &rank4_low_prio,
             5,
             true,
-            SortPreset::default()
+            SortPreset::default(),
+            AllDaySort::default()
         ),
         std::cmp::Ordering::Less,
         "priority-first: rank-5 high-priority task should sort before rank-4 low-priority task"
@@ -336,7 +348,8 @@ fn test_sort_merged_same_priority_date_wins() {
             &rank5_no_date,
             5,
             true,
-            SortPreset::default()
+            SortPreset::default(),
+            AllDaySort::default()
         ),
         std::cmp::Ordering::Less,
         "merged (flag=true): same priority → task with date before task without date"
@@ -348,7 +361,8 @@ fn test_sort_merged_same_priority_date_wins() {
             &rank5_no_date,
             5,
             false,
-            SortPreset::default()
+            SortPreset::default(),
+            AllDaySort::default()
         ),
         std::cmp::Ordering::Less,
         "non-merged (flag=false): rank-5 is already priority-first+date, so ordering is the same"
@@ -379,7 +393,8 @@ fn test_sort_rank4_before_rank5_when_flag_off() {
             &rank5_high_prio,
             5,
             false,
-            SortPreset::default()
+            SortPreset::default(),
+            AllDaySort::default()
         ),
         std::cmp::Ordering::Less,
         "flag off: rank-4 task must always sort before rank-5 task"
@@ -411,9 +426,113 @@ fn test_sort_urgent_ranks_always_date_first() {
             &high_prio_late,
             5,
             true,
-            SortPreset::default()
+            SortPreset::default(),
+            AllDaySort::default()
         ),
         std::cmp::Ordering::Less,
         "rank-2 must remain date-first even when sort_standard_by_priority is true"
     );
 }
+
+#[test]
+fn test_sort_modes_ignore_urgency_unless_boosted() {
+    let mut urgent = task("Zebra");
+    urgent.sort_rank = 1;
+    urgent.effective_priority = 1;
+    urgent.set_created_date(Utc::now() - Duration::days(30));
+
+    let mut plain = task("apple");
+    plain.sort_rank = 5;
+    plain.set_created_date(Utc::now() - Duration::hours(1));
+
+    let mut legacy = task("Mango");
+    legacy.sort_rank = 5;
+    legacy.created = None;
+
+    let mut done = task("Aardvark");
+    done.sort_rank = 8;
+
+    // Pure alphabetical: case-insensitive, urgency ignored, done stays at the bottom
+    assert_eq!(
+        plain.compare_for_mode(
+            &urgent,
+            SortMode::Alphabetical,
+            false,
+            5,
+            AllDaySort::default()
+        ),
+        std::cmp::Ordering::Less
+    );
+    assert_eq!(
+        urgent.compare_for_mode(
+            &done,
+            SortMode::Alphabetical,
+            false,
+            5,
+            AllDaySort::default()
+        ),
+        std::cmp::Ordering::Less
+    );
+    // The boost keeps the urgent task on top
+    assert_eq!(
+        urgent.compare_for_mode(
+            &plain,
+            SortMode::Alphabetical,
+            true,
+            5,
+            AllDaySort::default()
+        ),
+        std::cmp::Ordering::Less
+    );
+
+    // Created: newest first, tasks without CREATED last
+    assert_eq!(
+        plain.compare_for_mode(&urgent, SortMode::Created, false, 5, AllDaySort::default()),
+        std::cmp::Ordering::Less
+    );
+    assert_eq!(
+        urgent.compare_for_mode(&legacy, SortMode::Created, false, 5, AllDaySort::default()),
+        std::cmp::Ordering::Less
+    );
+    assert!(task("New").created_date().is_some());
+
+    // Priority: unset priority counts as the default
+    assert_eq!(
+        urgent.compare_for_mode(&plain, SortMode::Priority, false, 5, AllDaySort::default()),
+        std::cmp::Ordering::Less
+    );
+}
+
+#[test]
+fn test_all_day_sort_setting_flips_same_day_order() {
+    use cfait::model::item::safe_local_to_utc;
+
+    let day = chrono::Local::now().date_naive() + Duration::days(10);
+    let mut all_day = task("All day");
+    all_day.due = Some(DateType::AllDay(day));
+    let mut timed = task("Timed");
+    timed.due = Some(DateType::Specific(safe_local_to_utc(
+        day,
+        chrono::NaiveTime::from_hms_opt(12, 0, 0).unwrap(),
+    )));
+
+    let opts = |all_day_sort| CompareOptions {
+        cutoff: None,
+        urgent_days: 1,
+        urgent_prio: 1,
+        default_priority: 5,
+        start_grace_period_days: 1,
+        sort_standard_by_priority: false,
+        sort_preset: SortPreset::default(),
+        all_day_sort,
+    };
+
+    assert_eq!(
+        timed.compare_with_cutoff(&all_day, &opts(AllDaySort::EndOfDay)),
+        std::cmp::Ordering::Less
+    );
+    assert_eq!(
+        timed.compare_with_cutoff(&all_day, &opts(AllDaySort::StartOfDay)),
+        std::cmp::Ordering::Greater
+    );
+}
@@ -1,9 +1,10 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 //! Tests for rank propagation.
-use cfait::config::SortPreset;
+use cfait::config::{AllDaySort, SortMode, SortPreset};
 use cfait::context::TestContext;
 use cfait::model::Task;
 use cfait::store::{FilterOptions, TaskStore};
+use chrono::Weekday;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
@@ -51,18 +52,29 @@ fn parent_inherits_child_priority_and_sorts_before_sibling() {
         match_all_categories: false,
         search_term: "",
         hide_completed_global: false,
+        hide_deferred: false,
         hide_fully_completed_tags: false,
         hide_aliases_in_sidebar: false,
         cutoff_date: None,
         min_duration: None,
         max_duration: None,
         include_unset_duration: true,
+        exact_priority: None,
+        require_unset_duration: false,
+        excluded_categories: &HashSet::new(),
+        excluded_locations: &HashSet::new(),
+        excluded_priorities: &HashSet::new(),
+        excluded_statuses: &HashSet::new(),
         urgent_days: 1,
         urgent_prio: 1,
         default_priority: 5,
         start_grace_period_days: 1,
         sort_standard_by_priority: false,
         sort_preset: SortPreset::default(),
+        sort_mode: SortMode::default(),
+        all_day_sort: AllDaySort::default(),
+        week_start: Weekday::Mon,
+        sort_urgency_boost: false,
         expanded_done_groups: &HashSet::new(),
         expanded_tags: &HashSet::new(),
         expanded_locations: &HashSet::new(),
@@ -72,6 +84,7 @@ fn parent_inherits_child_priority_and_sorts_before_sibling() {
         tag_aliases: &HashMap::new(),
         search_collapsed_tasks: &HashSet::new(),
         focused_task_uid: None,
+        fuzzy_search: false,
     };
 
     let result = store.filter(opts).items;
@@ -145,18 +158,29 @@ fn compare_two_parents_inherited_priorities_determine_order() {
         match_all_categories: false,
         search_term: "",
         hide_completed_global: false,
+        hide_deferred: false,
         hide_fully_completed_tags: false,
         hide_aliases_in_sidebar: false,
         cutoff_date: None,
         min_duration: None,
         max_duration: None,
         include_unset_duration: true,
+        exact_priority: None,
+        require_unset_duration: false,
+        excluded_categories: &HashSet::new(),
+        excluded_locations: &HashSet::new(),
+        excluded_priorities: &HashSet::new(),
+        excluded_statuses: &HashSet::new(),
         urgent_days: 1,
         urgent_prio: 1,
         default_priority: 5,
         start_grace_period_days: 1,
         sort_standard_by_priority: false,
         sort_preset: SortPreset::default(),
+        sort_mode: SortMode::default(),
+        all_day_sort: AllDaySort::default(),
+        week_start: Weekday::Mon,
+        sort_urgency_boost: false,
         expanded_done_groups: &HashSet::new(),
         expanded_tags: &HashSet::new(),
         expanded_locations: &HashSet::new(),
@@ -166,6 +190,7 @@ fn compare_two_parents_inherited_priorities_determine_order() {
         tag_aliases: &HashMap::new(),
         search_collapsed_tasks: &HashSet::new(),
         focused_task_uid: None,
+        fuzzy_search: false,
     };
 
     let result = store.filter(opts).items;
@@ -234,18 +259,29 @@ fn parent_inherits_started_child_over_unset_sibling() {
         match_all_categories: false,
         search_term: "",
         hide_completed_global: false,
+        hide_deferred: false,
         hide_fully_completed_tags: false,
         hide_aliases_in_sidebar: false,
         cutoff_date: None,
         min_duration: None,
         max_duration: None,
         include_unset_duration: true,
+        exact_priority: None,
+        require_unset_duration: false,
+        excluded_categories: &HashSet::new(),
+        excluded_locations: &HashSet::new(),
+        excluded_priorities: &HashSet::new(),
+        excluded_statuses: &HashSet::new(),
         urgent_days: 1,
         urgent_prio: 1,
         default_priority: 5,
         start_grace_period_days: 1,
         sort_standard_by_priority: false,
         sort_preset: SortPreset::default(),
+        sort_mode: SortMode::default(),
+        all_day_sort: AllDaySort::default(),
+        week_start: Weekday::Mon,
+        sort_urgency_boost: false,
         expanded_done_groups: &HashSet::new(),
         expanded_tags: &HashSet::new(),
         expanded_locations: &HashSet::new(),
@@ -255,6 +291,7 @@ fn parent_inherits_started_child_over_unset_sibling() {
         tag_aliases: &HashMap::new(),
         search_collapsed_tasks: &HashSet::new(),
         focused_task_uid: None,
+        fuzzy_search: false,
     };
 
     let result = store.filter(opts).items;
@@ -2,6 +2,7 @@
 //! Tests for extended recurrence features (until/except).
 // Tests for "until" and "except" recurrence extensions
 
+use cfait::config::{DateFormat, TimeFormat};
 use cfait::model::{DateType, Task};
 use chrono::NaiveDate;
 use std::collections::HashMap;
@@ -286,14 +287,11 @@ fn test_prettify_recurrence_with_weekday_and_until() {
 fn test_prettify_recurrence_raw_format_no_until() {
     use cfait::model::parser::prettify_recurrence;
 
-    // Note: The current prettify implementation doesn't check for COUNT,
-    // so it will still use smart format and append until. This is a known limitation.
+    // COUNT is rendered as a `*N` suffix, UNTIL as a trailing "until" clause.
     let rrule = "FREQ=DAILY;COUNT=10;UNTIL=20251231";
     let pretty = prettify_recurrence(rrule, false);
 
-    // Currently outputs "@daily until 2025-12-31" even with COUNT present
-    // This could be improved in the future to detect COUNT and use rec: format
-    assert!(pretty.contains("@daily"));
+    assert!(pretty.contains("@daily*10"));
     assert!(pretty.contains("until 2025-12-31"));
 }
 
@@ -888,3 +886,242 @@ fn test_user_scenario_eight_excluded_months() {
     // The 8 excluded months are: jan,feb,mar,apr,may,oct,nov,dec
     assert_eq!(pretty, "@monthly except jan,feb,mar,apr,may,oct,nov,dec");
 }
+
+#[test]
+fn test_count_suffix_on_presets() {
+    let t = parse("Stretch @daily*5");
+    assert_eq!(t.summary, "Stretch");
+    assert_eq!(t.rrule.as_deref(), Some("FREQ=DAILY;COUNT=5"));
+
+    let t2 = parse("Report rec:weekly*3");
+    assert_eq!(t2.rrule.as_deref(), Some("FREQ=WEEKLY;COUNT=3"));
+}
+
+#[test]
+fn test_count_suffix_on_every_phrases() {
+    let t = parse("Water plants @every 3 days*4");
+    assert_eq!(t.summary, "Water plants");
+    assert_eq!(t.rrule.as_deref(), Some("FREQ=DAILY;INTERVAL=3;COUNT=4"));
+
+    let t2 = parse("Gym @every monday,thursday*6");
+    assert_eq!(t2.summary, "Gym");
+    assert_eq!(t2.rrule.as_deref(), Some("FREQ=WEEKLY;BYDAY=MO,TH;COUNT=6"));
+}
+
+#[test]
+fn test_count_invalid_suffix_is_text() {
+    let t = parse("Odd @daily*0");
+    assert!(t.rrule.is_none());
+    assert!(t.summary.contains("@daily*0"));
+}
+
+#[test]
+fn test_until_emits_utc_midnight() {
+    let t = parse("Sprint @weekly until 2025-06-01");
+    assert_eq!(
+        t.rrule.as_deref(),
+        Some("FREQ=WEEKLY;UNTIL=20250601T000000Z")
+    );
+}
+
+#[test]
+fn test_count_and_until_round_trip() {
+    use cfait::model::parser::prettify_recurrence;
+
+    for input in [
+        "@daily*5",
+        "@every 3 days*4",
+        "@every monday*2",
+        "@weekly until 2025-06-01",
+    ] {
+        let t = parse(&format!("Task {}", input));
+        let pretty = prettify_recurrence(t.rrule.as_ref().unwrap(), false);
+        assert_eq!(pretty, input);
+        let reparsed = parse(&format!("Task {}", pretty));
+        assert_eq!(reparsed.rrule, t.rrule);
+    }
+}
+
+#[test]
+fn test_advance_decrements_count() {
+    let mut t = parse("Pills @daily*3");
+    t.due = Some(DateType::AllDay(chrono::Local::now().date_naive()));
+
+    assert!(t.advance_recurrence());
+    assert_eq!(t.rrule.as_deref(), Some("FREQ=DAILY;COUNT=2"));
+
+    assert!(t.advance_recurrence());
+    assert_eq!(t.rrule.as_deref(), Some("FREQ=DAILY;COUNT=1"));
+
+    // Last occurrence: the series ends instead of advancing.
+    let due_before = t.due.clone();
+    assert!(!t.advance_recurrence());
+    assert_eq!(t.status, cfait::model::TaskStatus::Completed);
+    assert_eq!(t.due, due_before);
+}
+
+#[test]
+fn test_advance_stops_at_until() {
+    let today = chrono::Local::now().date_naive();
+    let mut t = parse("Walk @daily");
+    t.due = Some(DateType::AllDay(today));
+    t.rrule = Some(format!(
+        "FREQ=DAILY;UNTIL={}T000000Z",
+        (today + chrono::Duration::days(1)).format("%Y%m%d")
+    ));
+
+    // Tomorrow is still within the (inclusive) boundary.
+    assert!(t.advance_recurrence());
+    assert_eq!(
+        t.due,
+        Some(DateType::AllDay(today + chrono::Duration::days(1)))
+    );
+
+    assert!(!t.advance_recurrence());
+    assert_eq!(t.status, cfait::model::TaskStatus::Completed);
+}
+
+#[test]
+fn test_recycle_completes_exhausted_series() {
+    let mut t = parse("Once more @daily*1");
+    t.due = Some(DateType::AllDay(chrono::Local::now().date_naive()));
+
+    let (updated, next) = t.recycle(cfait::model::TaskStatus::Completed, false);
+    assert!(next.is_none());
+    assert_eq!(updated.uid, t.uid);
+    assert_eq!(updated.status, cfait::model::TaskStatus::Completed);
+}
+
+#[test]
+fn test_recycle_keeps_bounded_completion_log() {
+    let mut t = parse("Water plants @daily");
+    t.due = Some(DateType::AllDay(chrono::Local::now().date_naive()));
+
+    for _ in 0..12 {
+        let (history, next) = t.recycle(cfait::model::TaskStatus::Completed, false);
+        assert_eq!(history.completion_log().0, 0);
+        t = next.expect("daily series advances");
+    }
+
+    let (count, log) = t.completion_log();
+    assert_eq!(count, 12);
+    assert_eq!(log.len(), cfait::model::item::COMPLETION_LOG_LIMIT);
+    assert!(
+        t.completion_log_summary(DateFormat::Iso, TimeFormat::H24)
+            .is_some()
+    );
+
+    // Survives an ICS round trip as plain X- properties.
+    let ics = t.to_ics();
+    assert!(ics.contains("X-CFAIT-COMPLETION-COUNT:12"));
+    let back = Task::from_ics(&ics, String::new(), String::new(), String::new()).unwrap();
+    assert_eq!(back.completion_log(), (count, log));
+}
+
+#[test]
+fn test_every_abbreviated_and_compact_weekdays() {
+    let t = parse("Gym @every mon,wed,fri");
+    assert_eq!(t.rrule.as_deref(), Some("FREQ=WEEKLY;BYDAY=MO,WE,FR"));
+    assert_eq!(t.summary, "Gym");
+
+    let t = parse("Gym @every mwf");
+    assert_eq!(t.rrule.as_deref(), Some("FREQ=WEEKLY;BYDAY=MO,WE,FR"));
+    assert!(
+        t.to_smart_string()
+            .contains("@every monday,wednesday,friday"),
+        "got: {}",
+        t.to_smart_string()
+    );
+
+    let t = parse("Lab @every tth");
+    assert_eq!(t.rrule.as_deref(), Some("FREQ=WEEKLY;BYDAY=TU,TH"));
+
+    // Unknown letters are not a weekday list
+    let t = parse("Thing @every xyz");
+    assert!(t.rrule.is_none());
+}
+
+#[test]
+fn test_weekdays_shortcut() {
+    let t = parse("Standup @weekdays");
+    assert_eq!(t.rrule.as_deref(), Some("FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,FR"));
+    assert_eq!(t.summary, "Standup");
+    assert!(
+        t.to_smart_string().contains("@weekdays"),
+        "got: {}",
+        t.to_smart_string()
+    );
+
+    // Round-trips through the prettified form, including COUNT
+    let t = parse("Standup @weekdays*10");
+    assert_eq!(
+        t.rrule.as_deref(),
+        Some("FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,FR;COUNT=10")
+    );
+    let again = parse(&t.to_smart_string());
+    assert_eq!(again.rrule, t.rrule);
+
+    let t = parse("Standup @every weekdays");
+    assert_eq!(t.rrule.as_deref(), Some("FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,FR"));
+}
+
+#[test]
+fn test_weekday_list_highlighted_as_one_recurrence() {
+    use cfait::model::parser::{SyntaxType, tokenize_smart_input};
+
+    for input in ["Gym @every mon,wed,fri", "Gym @every mwf", "Gym @weekdays"] {
+        let tokens = tokenize_smart_input(input, false);
+        let rec: Vec<_> = tokens
+            .iter()
+            .filter(|t| t.kind == SyntaxType::Recurrence)
+            .collect();
+        assert_eq!(rec.len(), 1, "{input}: {tokens:?}");
+        assert_eq!(&input[rec[0].start..rec[0].end], &input[4..]);
+    }
+}
+
+#[test]
+fn test_every_other_and_ordinal_intervals() {
+    let t = parse("Review @every other week");
+    assert_eq!(t.rrule.as_deref(), Some("FREQ=WEEKLY;INTERVAL=2"));
+    assert_eq!(t.summary, "Review");
+
+    let t = parse("Review @every second week");
+    assert_eq!(t.rrule.as_deref(), Some("FREQ=WEEKLY;INTERVAL=2"));
+}
+
+#[test]
+fn test_every_last_friday_is_monthly_position() {
+    use cfait::model::parser::prettify_recurrence;
+    use chrono::{Datelike, Weekday};
+
+    let t = parse("Pay rent @every last friday");
+    assert_eq!(t.rrule.as_deref(), Some("FREQ=MONTHLY;BYDAY=-1FR"));
+    assert_eq!(t.summary, "Pay rent");
+    let due = t.due.as_ref().unwrap().to_date_naive();
+    assert_eq!(due.weekday(), Weekday::Fri);
+    assert_ne!((due + chrono::Duration::days(7)).month(), due.month());
+
+    assert_eq!(
+        prettify_recurrence("FREQ=MONTHLY;BYDAY=-1FR", false),
+        "@every last friday"
+    );
+    assert_eq!(parse(&t.to_smart_string()).rrule, t.rrule);
+
+    let t = parse("Team sync @every 1st monday*6");
+    assert_eq!(t.rrule.as_deref(), Some("FREQ=MONTHLY;BYDAY=1MO;COUNT=6"));
+    assert_eq!(parse(&t.to_smart_string()).rrule, t.rrule);
+
+    let t = parse("Invoice @every first weekday");
+    assert_eq!(
+        t.rrule.as_deref(),
+        Some("FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=1")
+    );
+    assert_eq!(parse(&t.to_smart_string()).rrule, t.rrule);
+
+    // Positions without a word form keep the raw rule instead of collapsing to @monthly
+    assert_eq!(
+        prettify_recurrence("FREQ=MONTHLY;BYDAY=-2FR", false),
+        "rec:FREQ=MONTHLY;BYDAY=-2FR"
+    );
+}
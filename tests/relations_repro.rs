@@ -162,3 +162,33 @@ fn test_all_relationship_types() {
     assert_eq!(parsed.related_to.len(), 1);
     assert!(parsed.related_to.contains(&"sibling-z".to_string()));
 }
+
+#[test]
+fn test_other_reltypes_round_trip_unchanged() {
+    let ics = "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nBEGIN:VTODO\r\nUID:t1\r\nSUMMARY:Linked\r\nRELATED-TO;RELTYPE=X-SEE-ALSO:other-1\r\nRELATED-TO;RELTYPE=CHILD:child-1\r\nRELATED-TO;RELTYPE=SIBLING:sib-1\r\nRELATED-TO;RELTYPE=PARENT:parent-1\r\nEND:VTODO\r\nEND:VCALENDAR\r\n";
+    let parsed =
+        Task::from_ics(ics, "etag".into(), "href".into(), "cal".into()).expect("Parse failed");
+
+    assert_eq!(parsed.parent_uid, Some("parent-1".to_string()));
+    assert_eq!(parsed.related_to, vec!["sib-1".to_string()]);
+    assert!(parsed.dependencies.is_empty());
+
+    let out = parsed.to_ics();
+    assert!(out.contains("RELATED-TO;RELTYPE=X-SEE-ALSO:other-1"));
+    assert!(out.contains("RELATED-TO;RELTYPE=CHILD:child-1"));
+    assert!(out.contains("RELATED-TO;RELTYPE=SIBLING:sib-1"));
+
+    let reparsed =
+        Task::from_ics(&out, "etag".into(), "href".into(), "cal".into()).expect("Parse failed");
+    assert_eq!(reparsed.parent_uid, parsed.parent_uid);
+    assert_eq!(reparsed.related_to, parsed.related_to);
+    // DTSTAMP is added on export; the unknown RELTYPEs must come back untouched
+    let related = |t: &Task| -> Vec<_> {
+        t.unmapped_properties
+            .iter()
+            .filter(|p| p.key == "RELATED-TO")
+            .cloned()
+            .collect()
+    };
+    assert_eq!(related(&reparsed), related(&parsed));
+}
@@ -391,3 +391,58 @@ fn test_allday_task_with_duration_uses_x_prop() {
         ics
     );
 }
+
+#[test]
+fn test_parse_dtstart_plus_duration_derives_due_and_round_trips() {
+    let ics = "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:-//other//EN\r\nBEGIN:VTODO\r\nUID:dur-1\r\nSUMMARY:Workshop\r\nDTSTART:20250310T090000Z\r\nDURATION:PT2H\r\nEND:VTODO\r\nEND:VCALENDAR\r\n";
+
+    let task = Task::from_ics(ics, String::new(), String::new(), String::new()).unwrap();
+    assert_eq!(
+        task.due,
+        Some(DateType::Specific(
+            Utc.with_ymd_and_hms(2025, 3, 10, 11, 0, 0).unwrap()
+        ))
+    );
+
+    // Written back as DTSTART + DURATION, not DUE
+    let out = task.to_ics();
+    assert!(out.contains("\nDURATION:PT2H"), "Got:\n{}", out);
+    assert!(!out.contains("\nDUE"), "Got:\n{}", out);
+    assert!(!out.contains("X-ESTIMATED-DURATION"), "Got:\n{}", out);
+
+    // Moving the due date updates the duration
+    let mut moved = task.clone();
+    moved.due = Some(DateType::Specific(
+        Utc.with_ymd_and_hms(2025, 3, 10, 12, 30, 0).unwrap(),
+    ));
+    assert!(moved.to_ics().contains("\nDURATION:PT210M"));
+
+    // A start with an estimate but no due keeps the estimate out of DURATION
+    let mut estimated = parse("Focus block");
+    estimated.dtstart = task.dtstart.clone();
+    estimated.estimated_duration = Some(45);
+    let out = estimated.to_ics();
+    assert!(out.contains("X-ESTIMATED-DURATION:PT45M"), "Got:\n{}", out);
+    let back = Task::from_ics(&out, String::new(), String::new(), String::new()).unwrap();
+    assert!(back.due.is_none());
+}
+
+#[test]
+fn test_out_of_range_duration_is_kept_without_a_due_date() {
+    for dur in ["P99999999999W", "PT999999999999999999M", "P9999999999D"] {
+        let ics = format!(
+            "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:-//other//EN\r\nBEGIN:VTODO\r\nUID:dur-2\r\nSUMMARY:Forever\r\nDTSTART:20250310T090000Z\r\nDURATION:{}\r\nEND:VTODO\r\nEND:VCALENDAR\r\n",
+            dur
+        );
+        let task = Task::from_ics(&ics, String::new(), String::new(), String::new()).unwrap();
+        assert!(task.due.is_none(), "{}", dur);
+
+        let out = task.to_ics();
+        assert!(
+            out.contains(&format!("\nDURATION:{}", dur)),
+            "Got:\n{}",
+            out
+        );
+        assert!(!out.contains("\nDUE"), "Got:\n{}", out);
+    }
+}
@@ -1,9 +1,10 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 //! Tests for search hierarchy functionality.
-use cfait::config::SortPreset;
+use cfait::config::{AllDaySort, SortMode, SortPreset};
 use cfait::context::TestContext;
 use cfait::model::Task;
 use cfait::store::{FilterOptions, TaskStore};
+use chrono::Weekday;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
@@ -33,18 +34,29 @@ fn test_search_includes_non_matching_children() {
         match_all_categories: false,
         search_term: "Project",
         hide_completed_global: false,
+        hide_deferred: false,
         hide_fully_completed_tags: false,
         hide_aliases_in_sidebar: false,
         cutoff_date: None,
         min_duration: None,
         max_duration: None,
         include_unset_duration: true,
+        exact_priority: None,
+        require_unset_duration: false,
+        excluded_categories: &HashSet::new(),
+        excluded_locations: &HashSet::new(),
+        excluded_priorities: &HashSet::new(),
+        excluded_statuses: &HashSet::new(),
         urgent_days: 1,
         urgent_prio: 1,
         default_priority: 5,
         start_grace_period_days: 1,
         sort_standard_by_priority: false,
         sort_preset: SortPreset::default(),
+        sort_mode: SortMode::default(),
+        all_day_sort: AllDaySort::default(),
+        week_start: Weekday::Mon,
+        sort_urgency_boost: false,
         expanded_done_groups: &HashSet::new(),
         expanded_tags: &HashSet::new(),
         expanded_locations: &HashSet::new(),
@@ -54,6 +66,7 @@ fn test_search_includes_non_matching_children() {
         tag_aliases: &HashMap::new(),
         search_collapsed_tasks: &HashSet::new(),
         focused_task_uid: None,
+        fuzzy_search: false,
     });
 
     let results = filter_res.items;
@@ -106,18 +119,29 @@ fn test_search_includes_deep_hierarchy() {
         match_all_categories: false,
         search_term: "Grand",
         hide_completed_global: false,
+        hide_deferred: false,
         hide_fully_completed_tags: false,
         hide_aliases_in_sidebar: false,
         cutoff_date: None,
         min_duration: None,
         max_duration: None,
         include_unset_duration: true,
+        exact_priority: None,
+        require_unset_duration: false,
+        excluded_categories: &HashSet::new(),
+        excluded_locations: &HashSet::new(),
+        excluded_priorities: &HashSet::new(),
+        excluded_statuses: &HashSet::new(),
         urgent_days: 1,
         urgent_prio: 1,
         default_priority: 5,
         start_grace_period_days: 1,
         sort_standard_by_priority: false,
         sort_preset: SortPreset::default(),
+        sort_mode: SortMode::default(),
+        all_day_sort: AllDaySort::default(),
+        week_start: Weekday::Mon,
+        sort_urgency_boost: false,
         expanded_done_groups: &HashSet::new(),
         expanded_tags: &HashSet::new(),
         expanded_locations: &HashSet::new(),
@@ -127,6 +151,7 @@ fn test_search_includes_deep_hierarchy() {
         tag_aliases: &HashMap::new(),
         search_collapsed_tasks: &HashSet::new(),
         focused_task_uid: None,
+        fuzzy_search: false,
     });
 
     let results = filter_res.items;
@@ -159,18 +184,29 @@ fn test_child_match_does_not_force_parent_if_parent_does_not_match() {
         match_all_categories: false,
         search_term: "Match",
         hide_completed_global: false,
+        hide_deferred: false,
         hide_fully_completed_tags: false,
         hide_aliases_in_sidebar: false,
         cutoff_date: None,
         min_duration: None,
         max_duration: None,
         include_unset_duration: true,
+        exact_priority: None,
+        require_unset_duration: false,
+        excluded_categories: &HashSet::new(),
+        excluded_locations: &HashSet::new(),
+        excluded_priorities: &HashSet::new(),
+        excluded_statuses: &HashSet::new(),
         urgent_days: 1,
         urgent_prio: 1,
         default_priority: 5,
         start_grace_period_days: 1,
         sort_standard_by_priority: false,
         sort_preset: SortPreset::default(),
+        sort_mode: SortMode::default(),
+        all_day_sort: AllDaySort::default(),
+        week_start: Weekday::Mon,
+        sort_urgency_boost: false,
         expanded_done_groups: &HashSet::new(),
         expanded_tags: &HashSet::new(),
         expanded_locations: &HashSet::new(),
@@ -180,6 +216,7 @@ fn test_child_match_does_not_force_parent_if_parent_does_not_match() {
         tag_aliases: &HashMap::new(),
         search_collapsed_tasks: &HashSet::new(),
         focused_task_uid: None,
+        fuzzy_search: false,
     });
 
     let results = filter_res.items;
@@ -258,18 +295,29 @@ fn test_multiple_parents_with_children() {
         match_all_categories: false,
         search_term: "Alpha",
         hide_completed_global: false,
+        hide_deferred: false,
         hide_fully_completed_tags: false,
         hide_aliases_in_sidebar: false,
         cutoff_date: None,
         min_duration: None,
         max_duration: None,
         include_unset_duration: true,
+        exact_priority: None,
+        require_unset_duration: false,
+        excluded_categories: &HashSet::new(),
+        excluded_locations: &HashSet::new(),
+        excluded_priorities: &HashSet::new(),
+        excluded_statuses: &HashSet::new(),
         urgent_days: 1,
         urgent_prio: 1,
         default_priority: 5,
         start_grace_period_days: 1,
         sort_standard_by_priority: false,
         sort_preset: SortPreset::default(),
+        sort_mode: SortMode::default(),
+        all_day_sort: AllDaySort::default(),
+        week_start: Weekday::Mon,
+        sort_urgency_boost: false,
         expanded_done_groups: &HashSet::new(),
         expanded_tags: &HashSet::new(),
         expanded_locations: &HashSet::new(),
@@ -279,6 +327,7 @@ fn test_multiple_parents_with_children() {
         tag_aliases: &HashMap::new(),
         search_collapsed_tasks: &HashSet::new(),
         focused_task_uid: None,
+        fuzzy_search: false,
     });
 
     let results = filter_res.items;
@@ -331,18 +380,29 @@ fn test_sibling_match_only_includes_matching_sibling() {
         match_all_categories: false,
         search_term: "Special",
         hide_completed_global: false,
+        hide_deferred: false,
         hide_fully_completed_tags: false,
         hide_aliases_in_sidebar: false,
         cutoff_date: None,
         min_duration: None,
         max_duration: None,
         include_unset_duration: true,
+        exact_priority: None,
+        require_unset_duration: false,
+        excluded_categories: &HashSet::new(),
+        excluded_locations: &HashSet::new(),
+        excluded_priorities: &HashSet::new(),
+        excluded_statuses: &HashSet::new(),
         urgent_days: 1,
         urgent_prio: 1,
         default_priority: 5,
         start_grace_period_days: 1,
         sort_standard_by_priority: false,
         sort_preset: SortPreset::default(),
+        sort_mode: SortMode::default(),
+        all_day_sort: AllDaySort::default(),
+        week_start: Weekday::Mon,
+        sort_urgency_boost: false,
         expanded_done_groups: &HashSet::new(),
         expanded_tags: &HashSet::new(),
         expanded_locations: &HashSet::new(),
@@ -352,6 +412,7 @@ fn test_sibling_match_only_includes_matching_sibling() {
         tag_aliases: &HashMap::new(),
         search_collapsed_tasks: &HashSet::new(),
         focused_task_uid: None,
+        fuzzy_search: false,
     });
 
     let results = filter_res.items;
@@ -429,18 +490,29 @@ fn test_empty_search_shows_all_tasks() {
         match_all_categories: false,
         search_term: "",
         hide_completed_global: false,
+        hide_deferred: false,
         hide_fully_completed_tags: false,
         hide_aliases_in_sidebar: false,
         cutoff_date: None,
         min_duration: None,
         max_duration: None,
         include_unset_duration: true,
+        exact_priority: None,
+        require_unset_duration: false,
+        excluded_categories: &HashSet::new(),
+        excluded_locations: &HashSet::new(),
+        excluded_priorities: &HashSet::new(),
+        excluded_statuses: &HashSet::new(),
         urgent_days: 1,
         urgent_prio: 1,
         default_priority: 5,
         start_grace_period_days: 1,
         sort_standard_by_priority: false,
         sort_preset: SortPreset::default(),
+        sort_mode: SortMode::default(),
+        all_day_sort: AllDaySort::default(),
+        week_start: Weekday::Mon,
+        sort_urgency_boost: false,
         expanded_done_groups: &HashSet::new(),
         expanded_tags: &HashSet::new(),
         expanded_locations: &HashSet::new(),
@@ -450,6 +522,7 @@ fn test_empty_search_shows_all_tasks() {
         tag_aliases: &HashMap::new(),
         search_collapsed_tasks: &HashSet::new(),
         focused_task_uid: None,
+        fuzzy_search: false,
     });
 
     let results = filter_res.items;
@@ -483,18 +556,29 @@ fn test_hierarchy_expansion_with_completed_tasks() {
         match_all_categories: false,
         search_term: "Project",
         hide_completed_global: false,
+        hide_deferred: false,
         hide_fully_completed_tags: false,
         hide_aliases_in_sidebar: false,
         cutoff_date: None,
         min_duration: None,
         max_duration: None,
         include_unset_duration: true,
+        exact_priority: None,
+        require_unset_duration: false,
+        excluded_categories: &HashSet::new(),
+        excluded_locations: &HashSet::new(),
+        excluded_priorities: &HashSet::new(),
+        excluded_statuses: &HashSet::new(),
         urgent_days: 1,
         urgent_prio: 1,
         default_priority: 5,
         start_grace_period_days: 1,
         sort_standard_by_priority: false,
         sort_preset: SortPreset::default(),
+        sort_mode: SortMode::default(),
+        all_day_sort: AllDaySort::default(),
+        week_start: Weekday::Mon,
+        sort_urgency_boost: false,
         expanded_done_groups: &HashSet::new(),
         expanded_tags: &HashSet::new(),
         expanded_locations: &HashSet::new(),
@@ -504,6 +588,7 @@ fn test_hierarchy_expansion_with_completed_tasks() {
         tag_aliases: &HashMap::new(),
         search_collapsed_tasks: &HashSet::new(),
         focused_task_uid: None,
+        fuzzy_search: false,
     });
 
     let results = filter_res.items;
@@ -517,18 +602,29 @@ fn test_hierarchy_expansion_with_completed_tasks() {
         match_all_categories: false,
         search_term: "Project",
         hide_completed_global: true,
+        hide_deferred: false,
         hide_fully_completed_tags: false,
         hide_aliases_in_sidebar: false,
         cutoff_date: None,
         min_duration: None,
         max_duration: None,
         include_unset_duration: true,
+        exact_priority: None,
+        require_unset_duration: false,
+        excluded_categories: &HashSet::new(),
+        excluded_locations: &HashSet::new(),
+        excluded_priorities: &HashSet::new(),
+        excluded_statuses: &HashSet::new(),
         urgent_days: 1,
         urgent_prio: 1,
         default_priority: 5,
         start_grace_period_days: 1,
         sort_standard_by_priority: false,
         sort_preset: SortPreset::default(),
+        sort_mode: SortMode::default(),
+        all_day_sort: AllDaySort::default(),
+        week_start: Weekday::Mon,
+        sort_urgency_boost: false,
         expanded_done_groups: &HashSet::new(),
         expanded_tags: &HashSet::new(),
         expanded_locations: &HashSet::new(),
@@ -538,6 +634,7 @@ fn test_hierarchy_expansion_with_completed_tasks() {
         tag_aliases: &HashMap::new(),
         search_collapsed_tasks: &HashSet::new(),
         focused_task_uid: None,
+        fuzzy_search: false,
     });
 
     let results_hidden = filter_res_hidden.items;
@@ -126,3 +126,103 @@ fn test_combined_filters() {
     // Should fail: duration mismatch
     assert!(!t.matches_search_term("~>2h"));
 }
+
+#[test]
+fn test_relative_date_windows() {
+    let today = Local::now().date_naive();
+
+    let mut overdue = make_task();
+    overdue.due = Some(DateType::Specific(Utc::now() - Duration::hours(2)));
+    overdue.categories.push("work".to_string());
+
+    let mut due_today = make_task();
+    due_today.due = Some(DateType::AllDay(today));
+
+    let mut far = make_task();
+    far.due = Some(DateType::AllDay(today + Duration::days(30)));
+
+    let undated = make_task();
+
+    // is:overdue
+    assert!(overdue.matches_search_term("is:overdue"));
+    assert!(!far.matches_search_term("is:overdue"));
+    assert!(!undated.matches_search_term("is:overdue"));
+
+    // Completed tasks are never overdue
+    let mut done = overdue.clone();
+    done.status = TaskStatus::Completed;
+    assert!(!done.matches_search_term("is:overdue"));
+
+    // is:today
+    assert!(due_today.matches_search_term("is:today"));
+    assert!(!far.matches_search_term("is:today"));
+
+    // is:week
+    assert!(due_today.matches_search_term("is:week"));
+    assert!(!far.matches_search_term("is:week"));
+    assert!(!undated.matches_search_term("is:week"));
+
+    // AND semantics with tags
+    assert!(overdue.matches_search_term("is:overdue #work"));
+    assert!(!overdue.matches_search_term("is:overdue #home"));
+}
+
+#[test]
+fn test_waiting_pseudo_status() {
+    let task = Task::new("Contract reply is:waiting", &HashMap::new(), None);
+    assert!(task.waiting);
+    assert!(task.is_waiting());
+    assert_eq!(task.summary, "Contract reply");
+    assert_eq!(task.checkbox_symbol(), "[⧖]");
+    assert!(task.matches_search_term("is:waiting"));
+    assert!(task.matches_search_term("is:active"));
+    assert!(task.to_smart_string().contains("is:waiting"));
+
+    // Stays a plain NEEDS-ACTION task for other clients
+    let ics = task.to_ics();
+    assert!(ics.contains("STATUS:NEEDS-ACTION"));
+    assert!(ics.contains("X-CFAIT-WAITING:TRUE"));
+    let parsed = Task::from_ics(&ics, "etag".into(), "href".into(), "cal".into()).unwrap();
+    assert!(parsed.is_waiting());
+
+    // A finished task no longer shows as waiting
+    let mut done = task.clone();
+    done.status = TaskStatus::Completed;
+    assert!(!done.is_waiting());
+    assert!(!done.matches_search_term("is:waiting"));
+    assert!(!make_task().matches_search_term("is:waiting"));
+}
+
+#[test]
+fn test_due_date_heatmap_token_and_counts() {
+    use cfait::model::matcher::{due_date_in_query, toggle_due_date_token};
+    use cfait::store::{TaskListItem, due_date_counts};
+    use chrono::NaiveDate;
+
+    let day = NaiveDate::from_ymd_opt(2026, 10, 14).unwrap();
+    let other = NaiveDate::from_ymd_opt(2026, 10, 20).unwrap();
+
+    let query = toggle_due_date_token("#work", day);
+    assert_eq!(query, "@2026-10-14 #work");
+    assert_eq!(due_date_in_query(&query), Some(day));
+    // Another day replaces it, the same day clears it
+    assert_eq!(toggle_due_date_token(&query, other), "@2026-10-20 #work");
+    assert_eq!(toggle_due_date_token(&query, day), "#work");
+
+    let mut due_that_day = make_task();
+    due_that_day.due = Some(DateType::AllDay(day));
+    let mut due_other_day = make_task();
+    due_other_day.due = Some(DateType::AllDay(other));
+    assert!(due_that_day.matches_search_term("@2026-10-14"));
+    assert!(!due_other_day.matches_search_term("@2026-10-14"));
+
+    let mut done = due_that_day.clone();
+    done.status = TaskStatus::Completed;
+    let items: Vec<TaskListItem> = [due_that_day.clone(), due_that_day, due_other_day, done]
+        .into_iter()
+        .map(|t| TaskListItem::Task(Box::new(t)))
+        .collect();
+    let counts = due_date_counts(&items);
+    assert_eq!(counts.get(&day), Some(&2));
+    assert_eq!(counts.get(&other), Some(&1));
+}
@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 //! General tests for search functionality.
 use cfait::model::Task;
+use chrono::{Duration, Utc};
 use std::collections::HashMap;
 
 fn create_task_with_loc(summary: &str, location: &str) -> Task {
@@ -42,3 +43,39 @@ fn test_mixed_filters() {
     // Matches Priority but NOT Location
     assert!(!t.matches_search_term("!1 Home"));
 }
+
+#[test]
+fn test_search_history_dedup_and_cap() {
+    use cfait::config::{SEARCH_HISTORY_LIMIT, push_search_history};
+
+    let mut history = Vec::new();
+    push_search_history(&mut history, "#work");
+    push_search_history(&mut history, "@@home");
+    push_search_history(&mut history, "  ");
+    assert_eq!(history, vec!["@@home", "#work"]);
+
+    // Re-running a search moves it to the front instead of duplicating it
+    push_search_history(&mut history, " #work ");
+    assert_eq!(history, vec!["#work", "@@home"]);
+
+    for i in 0..30 {
+        push_search_history(&mut history, &format!("q{i}"));
+    }
+    assert_eq!(history.len(), SEARCH_HISTORY_LIMIT);
+    assert_eq!(history[0], "q29");
+}
+
+#[test]
+fn test_completed_window_filter() {
+    let mut t = Task::new("Ship release", &HashMap::new(), None);
+    assert!(!t.matches_search_term("completed:this-week"));
+
+    t.set_completion_date(Some(Utc::now()));
+    assert!(t.matches_search_term("completed:today"));
+    assert!(t.matches_search_term("completed:this-week"));
+    assert!(t.matches_search_term("completed:this-month"));
+
+    t.set_completion_date(Some(Utc::now() - Duration::days(40)));
+    assert!(!t.matches_search_term("completed:this-week"));
+    assert!(!t.matches_search_term("completed:this-month"));
+}
@@ -129,6 +129,30 @@ fn test_inline_alias_definition() {
     assert!(values.contains(&"@@home".to_string()));
 }
 
+#[test]
+fn test_alias_preview_follows_hierarchy() {
+    let mut aliases = HashMap::new();
+    aliases.insert(
+        "work".to_string(),
+        vec!["#office".to_string(), "@@hq".to_string()],
+    );
+    aliases.insert("office".to_string(), vec!["#paid".to_string()]);
+
+    // `#work:meeting` falls back to the `work` alias and expands recursively
+    let preview = cfait::model::parser::preview_alias_expansions("Sync #work:meeting", &aliases);
+    assert!(preview.contains(&"#office".to_string()));
+    assert!(preview.contains(&"@@hq".to_string()));
+    assert!(preview.contains(&"#paid".to_string()));
+
+    // Values the user already typed are not repeated
+    let preview = cfait::model::parser::preview_alias_expansions("#work @@hq", &aliases);
+    assert!(!preview.contains(&"@@hq".to_string()));
+
+    assert!(
+        cfait::model::parser::preview_alias_expansions("Plain task #other", &aliases).is_empty()
+    );
+}
+
 #[test]
 fn test_recurrence_with_until() {
     let aliases = HashMap::new();
@@ -252,3 +276,280 @@ fn test_complex_task_with_recurrence_extensions() {
     assert_eq!(t.estimated_duration, Some(60));
     assert!(!t.alarms.is_empty());
 }
+
+#[test]
+fn test_compact_24h_times() {
+    use cfait::model::{AlarmTrigger, DateType};
+    use chrono::{Local, NaiveTime, Timelike};
+
+    let aliases = HashMap::new();
+    let local_time = |d: &Option<DateType>| match d {
+        Some(DateType::Specific(dt)) => dt.with_timezone(&Local).time(),
+        other => panic!("Expected a specific time, got {:?}", other),
+    };
+
+    let t1 = Task::new("Meeting @tomorrow 1430", &aliases, None);
+    assert_eq!(t1.summary, "Meeting");
+    assert_eq!(
+        local_time(&t1.due),
+        NaiveTime::from_hms_opt(14, 30, 0).unwrap()
+    );
+
+    let t2 = Task::new("Gym ^mon 0800", &aliases, None);
+    assert_eq!(t2.summary, "Gym");
+    assert_eq!(local_time(&t2.dtstart).hour(), 8);
+
+    let t3 = Task::new("Call rem:1830", &aliases, None);
+    assert_eq!(t3.summary, "Call");
+    assert_eq!(t3.alarms.len(), 1);
+    if let AlarmTrigger::Absolute(dt) = t3.alarms[0].trigger {
+        let local = dt.with_timezone(&Local);
+        assert_eq!((local.hour(), local.minute()), (18, 30));
+    } else {
+        panic!("Expected an absolute reminder");
+    }
+
+    // Three digits are read as HMM
+    let t4 = Task::new("Standup @tomorrow 930", &aliases, None);
+    assert_eq!(
+        local_time(&t4.due),
+        NaiveTime::from_hms_opt(9, 30, 0).unwrap()
+    );
+}
+
+#[test]
+fn test_compact_time_edge_cases() {
+    use cfait::model::DateType;
+
+    let aliases = HashMap::new();
+
+    // A bare number stays text, while the zero-padded form is a time
+    let bare = Task::new("Lunch @tomorrow 9", &aliases, None);
+    assert!(matches!(bare.due, Some(DateType::AllDay(_))));
+    assert_eq!(bare.summary, "Lunch 9");
+
+    let padded = Task::new("Lunch @tomorrow 0900", &aliases, None);
+    assert!(matches!(padded.due, Some(DateType::Specific(_))));
+    assert_eq!(padded.summary, "Lunch");
+
+    // Out-of-range hours and minutes are rejected
+    let bad_hour = Task::new("Party @tomorrow 2500", &aliases, None);
+    assert!(matches!(bad_hour.due, Some(DateType::AllDay(_))));
+    assert_eq!(bad_hour.summary, "Party 2500");
+
+    let bad_minute = Task::new("Party @tomorrow 1261", &aliases, None);
+    assert!(matches!(bad_minute.due, Some(DateType::AllDay(_))));
+}
+
+#[test]
+fn test_end_of_period_keywords() {
+    use cfait::model::DateType;
+    use cfait::model::parser::{end_of_month, end_of_week, parse_smart_date};
+    use chrono::{Datelike, Duration, Local, NaiveDate, Weekday};
+
+    let today = Local::now().date_naive();
+
+    assert_eq!(
+        parse_smart_date("yesterday", Weekday::Mon),
+        Some(DateType::AllDay(today - Duration::days(1)))
+    );
+    assert_eq!(
+        parse_smart_date("eod", Weekday::Mon),
+        Some(DateType::AllDay(today))
+    );
+
+    let eow = parse_smart_date("eow", Weekday::Mon)
+        .unwrap()
+        .to_date_naive();
+    assert_eq!(eow.weekday(), Weekday::Sun);
+    assert!(eow >= today && eow < today + Duration::days(7));
+
+    let eom = parse_smart_date("eom", Weekday::Mon)
+        .unwrap()
+        .to_date_naive();
+    assert_eq!(eom.month(), today.month());
+    assert_eq!((eom + Duration::days(1)).day(), 1);
+
+    assert_eq!(
+        parse_smart_date("eoy", Weekday::Mon),
+        Some(DateType::AllDay(
+            NaiveDate::from_ymd_opt(today.year(), 12, 31).unwrap()
+        ))
+    );
+
+    // True month ends, not 30-day offsets
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    assert_eq!(end_of_month(d(2024, 2, 10)), d(2024, 2, 29));
+    assert_eq!(end_of_month(d(2025, 2, 10)), d(2025, 2, 28));
+    assert_eq!(end_of_month(d(2025, 12, 31)), d(2025, 12, 31));
+    assert_eq!(end_of_month(d(2025, 4, 1)), d(2025, 4, 30));
+    assert_eq!(end_of_week(d(2025, 6, 2), Weekday::Mon), d(2025, 6, 8)); // Monday -> Sunday
+    assert_eq!(end_of_week(d(2025, 6, 8), Weekday::Mon), d(2025, 6, 8)); // Sunday stays
+}
+
+#[test]
+fn test_end_of_period_keywords_in_smart_input() {
+    use chrono::{Local, Timelike};
+
+    let aliases = HashMap::new();
+
+    let t1 = Task::new("Report @eom", &aliases, None);
+    assert_eq!(t1.summary, "Report");
+    assert!(t1.due.is_some());
+
+    let t2 = Task::new("Plan ^eow", &aliases, None);
+    assert_eq!(t2.summary, "Plan");
+    assert!(t2.dtstart.is_some());
+
+    let t3 = Task::new("Wrap up rem:eod 17:00", &aliases, None);
+    assert_eq!(t3.summary, "Wrap up");
+    assert_eq!(t3.alarms.len(), 1);
+    if let cfait::model::AlarmTrigger::Absolute(dt) = t3.alarms[0].trigger {
+        let local = dt.with_timezone(&Local);
+        assert_eq!(local.date_naive(), Local::now().date_naive());
+        assert_eq!(local.hour(), 17);
+    } else {
+        panic!("Expected an absolute reminder");
+    }
+}
+
+#[test]
+fn test_in_business_days() {
+    use cfait::model::parser::{SyntaxType, add_business_days, tokenize_smart_input};
+    use chrono::{Local, NaiveDate, Weekday};
+
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+
+    // Friday + 1 business day lands on Monday; Wednesday + 3 on the next Monday
+    assert_eq!(
+        add_business_days(d(2025, 3, 14), 1, &[]),
+        Some(d(2025, 3, 17))
+    );
+    assert_eq!(
+        add_business_days(d(2025, 3, 12), 3, &[]),
+        Some(d(2025, 3, 17))
+    );
+    // Holidays are skipped like weekends
+    assert_eq!(
+        add_business_days(d(2025, 3, 14), 1, &[d(2025, 3, 17)]),
+        Some(d(2025, 3, 18))
+    );
+    // Huge offsets are rejected instead of looping for ages or overflowing
+    assert_eq!(add_business_days(d(2025, 3, 14), 99_999_999, &[]), None);
+    assert_eq!(add_business_days(NaiveDate::MAX, 1, &[]), None);
+    let t = Task::new("Report @in 99999999 bd", &HashMap::new(), None);
+    assert!(t.due.is_none());
+
+    let holiday = add_business_days(Local::now().date_naive(), 1, &[]).unwrap();
+    let t = Task::new_with_date_settings(
+        "Report @in 1 bd",
+        &HashMap::new(),
+        None,
+        &[holiday],
+        Weekday::Mon,
+    );
+    assert_eq!(
+        t.due.unwrap().to_date_naive(),
+        add_business_days(Local::now().date_naive(), 1, &[holiday]).unwrap()
+    );
+
+    let aliases = HashMap::new();
+    let expected = add_business_days(Local::now().date_naive(), 3, &[]).unwrap();
+    for input in [
+        "Report @in 3 business days",
+        "Report @in 3 bdays",
+        "Report @in 3bd",
+        "Report @in three business days",
+    ] {
+        let t = Task::new(input, &aliases, None);
+        assert_eq!(t.summary, "Report", "{input}");
+        assert_eq!(t.due.unwrap().to_date_naive(), expected, "{input}");
+    }
+
+    // The whole phrase is highlighted as one date token
+    let input = "Report @in 3 business days";
+    let tokens = tokenize_smart_input(input, false);
+    let due: Vec<_> = tokens
+        .iter()
+        .filter(|t| t.kind == SyntaxType::DueDate)
+        .collect();
+    assert_eq!(due.len(), 1);
+    assert_eq!(&input[due[0].start..due[0].end], "@in 3 business days");
+}
+
+#[test]
+fn test_parse_input_outline_nesting() {
+    use cfait::model::parse_input_outline;
+
+    let input = "Groceries #shop\n- [ ] milk\n\n  - oat\n- eggs\n\tPack bag\nCall mom @tomorrow";
+    let lines = parse_input_outline(input);
+    let pairs: Vec<(&str, Option<usize>)> =
+        lines.iter().map(|l| (l.text.as_str(), l.parent)).collect();
+    assert_eq!(
+        pairs,
+        vec![
+            ("Groceries #shop", None),
+            ("milk", Some(0)),
+            ("oat", Some(1)),
+            ("eggs", Some(0)),
+            ("Pack bag", Some(3)),
+            ("Call mom @tomorrow", None),
+        ]
+    );
+
+    // A single line stays a single entry, so regular entry is untouched
+    assert_eq!(parse_input_outline("  Just one task ").len(), 1);
+}
+
+#[test]
+fn test_display_date_formats_keep_smart_string_iso() {
+    use cfait::config::{DateFormat, TimeFormat};
+    use cfait::model::DateType;
+    use chrono::{Local, NaiveDate, TimeZone, Utc};
+
+    let day = DateType::AllDay(NaiveDate::from_ymd_opt(2025, 12, 31).unwrap());
+    assert_eq!(
+        day.format_with(DateFormat::Iso, TimeFormat::H24),
+        "2025-12-31"
+    );
+    assert_eq!(
+        day.format_with(DateFormat::Us, TimeFormat::H24),
+        "12/31/2025"
+    );
+    assert_eq!(
+        day.format_with(DateFormat::Eu, TimeFormat::H24),
+        "31/12/2025"
+    );
+    // Dec 31 2025 is a Wednesday in ISO week 1 of 2026
+    assert_eq!(
+        day.format_with(DateFormat::IsoWeek, TimeFormat::H24),
+        "2026-W01-3"
+    );
+
+    let at = Local
+        .with_ymd_and_hms(2025, 3, 4, 14, 30, 0)
+        .unwrap()
+        .with_timezone(&Utc);
+    let timed = DateType::Specific(at);
+    assert_eq!(
+        timed.format_with(DateFormat::Us, TimeFormat::H12),
+        "03/04/2025 2:30 PM"
+    );
+    assert_eq!(
+        timed.format_with(DateFormat::Eu, TimeFormat::H24),
+        "04/03/2025 14:30"
+    );
+
+    // The display preference never leaks into the re-parseable smart string
+    let aliases = HashMap::new();
+    let mut task = Task::new("Pay rent", &aliases, None);
+    task.due = Some(day);
+    assert!(task.to_smart_string().contains("@2025-12-31"));
+    assert_eq!(
+        task.due
+            .as_ref()
+            .unwrap()
+            .format_with(DateFormat::Eu, TimeFormat::H12),
+        "31/12/2025"
+    );
+}
@@ -1,12 +1,13 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 //! Tests for smart input processing.
 /* Updated: Pass is_search = false to tokenizer calls in tests so highlighting logic treats these as input mode. */
+use cfait::config::{DateFormat, TimeFormat};
 use cfait::model::{
-    Task,
+    ParsePreview, Task,
     parser::{SyntaxType, expand_braces, tokenize_smart_input},
     validate_alias_integrity,
 };
-use chrono::{Duration, Local};
+use chrono::{Duration, Local, Weekday};
 use std::collections::HashMap;
 
 #[test]
@@ -142,6 +143,41 @@ fn test_geo_and_description() {
     assert_eq!(task.description, "View from top");
 }
 
+#[test]
+fn test_geo_range_validation() {
+    use cfait::model::parser::{geo_to_map_url, parse_geo_coordinates};
+    let aliases = HashMap::new();
+
+    // Boundaries are inclusive
+    assert_eq!(parse_geo_coordinates("90,180"), Some((90.0, 180.0)));
+    assert_eq!(parse_geo_coordinates("-90,-180"), Some((-90.0, -180.0)));
+    assert!(parse_geo_coordinates("90.0001,0").is_none());
+    assert!(parse_geo_coordinates("0,-180.5").is_none());
+    assert!(parse_geo_coordinates("abc,def").is_none());
+    assert!(parse_geo_coordinates("50.1").is_none());
+
+    let task = Task::new("Meet geo:91,10", &aliases, None);
+    assert_eq!(task.geo, None);
+    assert_eq!(task.summary, "Meet geo:91,10");
+
+    let task = Task::new("Meet geo:1.2.3,4", &aliases, None);
+    assert_eq!(task.geo, None);
+
+    let task = Task::new("Meet geo:-90,180", &aliases, None);
+    assert_eq!(task.geo, Some("-90,180".to_string()));
+
+    // Hemisphere letters still normalize before the range check
+    let task = Task::new("Summit geo:45.8N, 6.8E", &aliases, None);
+    assert_eq!(task.geo, Some("45.8,6.8".to_string()));
+
+    assert_eq!(
+        geo_to_map_url("50.5,4.25", "https://maps.example/?q={lat},{lon}"),
+        "https://maps.example/?q=50.5,4.25"
+    );
+    assert_eq!(geo_to_map_url("50.5,4.25", ""), "geo:50.5,4.25");
+    assert_eq!(geo_to_map_url("here", "https://x/{lat}"), "geo:here");
+}
+
 #[test]
 fn test_alias_subtag_expansion() {
     let mut aliases = HashMap::new();
@@ -302,3 +338,69 @@ fn test_reminder_syntax_highlighting_time_only() {
     let t = reminder_token.unwrap();
     assert_eq!(&input[t.start..t.end], "rem:14:30");
 }
+
+#[test]
+fn test_parse_preview_reports_fields_without_creating() {
+    let mut aliases = HashMap::new();
+    aliases.insert("errand".to_string(), vec!["#out".to_string()]);
+
+    let preview = ParsePreview::parse(
+        "Call plumber @2030-01-15 14:00 ~30m #errand !2 rem:10m #home:=#house",
+        &aliases,
+        None,
+        &[],
+        Weekday::Mon,
+    );
+
+    assert_eq!(preview.summary, "Call plumber");
+    assert_eq!(preview.estimated_duration, Some(30));
+    assert_eq!(preview.priority, 2);
+    assert!(preview.categories.contains(&"out".to_string()));
+    assert!(preview.categories.contains(&"house".to_string()));
+    assert_eq!(preview.alarms.len(), 1);
+    assert_eq!(
+        preview.new_aliases.get("home"),
+        Some(&vec!["#house".to_string()])
+    );
+
+    let fields = preview.fields(DateFormat::Iso, TimeFormat::H24);
+    let due = fields.iter().find(|(name, _)| *name == "due");
+    assert_eq!(due.map(|(_, v)| v.as_str()), Some("2030-01-15 14:00"));
+    assert!(fields.iter().any(|(name, _)| *name == "alarms"));
+    assert!(!fields.iter().any(|(name, _)| *name == "location"));
+
+    let recurring = ParsePreview::parse("Standup @daily", &HashMap::new(), None, &[], Weekday::Mon);
+    assert!(
+        recurring
+            .fields(DateFormat::Iso, TimeFormat::H24)
+            .contains(&("rrule", "FREQ=DAILY".to_string()))
+    );
+}
+
+#[test]
+fn test_unrecognized_directives_are_reported() {
+    let aliases = HashMap::new();
+    let mut task = Task::new("placeholder", &aliases, None);
+    let unrecognized = task.apply_smart_input_with_diagnostics(
+        "Email @notaday rem:tomorow \\@home !2 ~30m",
+        &aliases,
+        None,
+        &[],
+        Weekday::Mon,
+    );
+
+    assert_eq!(unrecognized, vec!["@notaday", "rem:tomorow"]);
+    assert!(task.summary.contains("@notaday"));
+    assert!(task.summary.contains("@home"));
+    assert_eq!(task.priority, 2);
+    assert_eq!(task.estimated_duration, Some(30));
+
+    let clean = task.apply_smart_input_with_diagnostics(
+        "Email @tomorrow rem:1h",
+        &aliases,
+        None,
+        &[],
+        Weekday::Mon,
+    );
+    assert!(clean.is_empty());
+}
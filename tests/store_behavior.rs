@@ -1,9 +1,10 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 //! Tests for store behavior.
-use cfait::config::SortPreset;
+use cfait::config::{AllDaySort, SortMode, SortPreset};
 use cfait::context::TestContext;
 use cfait::model::{Task, TaskStatus};
 use cfait::store::{FilterOptions, TaskStore};
+use chrono::Weekday;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
@@ -39,18 +40,29 @@ fn test_filter_by_tag() {
         match_all_categories: false,
         search_term: "",
         hide_completed_global: false,
+        hide_deferred: false,
         hide_fully_completed_tags: false,
         hide_aliases_in_sidebar: false,
         cutoff_date: None,
         min_duration: None,
         max_duration: None,
         include_unset_duration: true,
+        exact_priority: None,
+        require_unset_duration: false,
+        excluded_categories: &HashSet::new(),
+        excluded_locations: &HashSet::new(),
+        excluded_priorities: &HashSet::new(),
+        excluded_statuses: &HashSet::new(),
         urgent_days: 1,
         urgent_prio: 1,
         default_priority: 5,
         start_grace_period_days: 1,
         sort_standard_by_priority: false,
         sort_preset: SortPreset::default(),
+        sort_mode: SortMode::default(),
+        all_day_sort: AllDaySort::default(),
+        week_start: Weekday::Mon,
+        sort_urgency_boost: false,
         expanded_done_groups: &empty_set,
         expanded_tags: &empty_set,
         expanded_locations: &empty_set,
@@ -60,6 +72,7 @@ fn test_filter_by_tag() {
         tag_aliases: &HashMap::new(),
         search_collapsed_tasks: &HashSet::new(),
         focused_task_uid: None,
+        fuzzy_search: false,
     });
     let results = filter_res.items;
 
@@ -92,18 +105,29 @@ fn test_filter_hierarchical_tags() {
         match_all_categories: false,
         search_term: "",
         hide_completed_global: false,
+        hide_deferred: false,
         hide_fully_completed_tags: false,
         hide_aliases_in_sidebar: false,
         cutoff_date: None,
         min_duration: None,
         max_duration: None,
         include_unset_duration: true,
+        exact_priority: None,
+        require_unset_duration: false,
+        excluded_categories: &HashSet::new(),
+        excluded_locations: &HashSet::new(),
+        excluded_priorities: &HashSet::new(),
+        excluded_statuses: &HashSet::new(),
         urgent_days: 1,
         urgent_prio: 1,
         default_priority: 5,
         start_grace_period_days: 1,
         sort_standard_by_priority: false,
         sort_preset: SortPreset::default(),
+        sort_mode: SortMode::default(),
+        all_day_sort: AllDaySort::default(),
+        week_start: Weekday::Mon,
+        sort_urgency_boost: false,
         expanded_done_groups: &empty_set,
         expanded_tags: &empty_set,
         expanded_locations: &empty_set,
@@ -113,6 +137,7 @@ fn test_filter_hierarchical_tags() {
         tag_aliases: &HashMap::new(),
         search_collapsed_tasks: &HashSet::new(),
         focused_task_uid: None,
+        fuzzy_search: false,
     });
     let results = filter_res.items;
 
@@ -124,6 +149,169 @@ fn test_filter_hierarchical_tags() {
     }
 }
 
+#[test]
+fn test_filter_hierarchical_locations() {
+    let mut store = make_store();
+    for (uid, input) in [
+        ("1", "Vacuum @@Home"),
+        ("2", "Print @@Home:Office"),
+        ("3", "Water plants @@home:garden:greenhouse"),
+        ("4", "Meeting @@Homework"),
+        ("5", "Shop @@Store"),
+    ] {
+        let mut t = Task::new(input, &HashMap::new(), None);
+        t.uid = uid.to_string();
+        t.calendar_href = "cal1".to_string();
+        store.add_task(t);
+    }
+
+    let empty_set = HashSet::new();
+    let run = |store: &TaskStore, loc: &str| -> Vec<String> {
+        let locs: HashSet<String> = [loc.to_string()].into_iter().collect();
+        let res = store.filter(FilterOptions {
+            active_cal_href: None,
+            hidden_calendars: &empty_set,
+            selected_categories: &empty_set,
+            selected_locations: &locs,
+            match_all_categories: false,
+            search_term: "",
+            hide_completed_global: false,
+            hide_deferred: false,
+            hide_fully_completed_tags: false,
+            hide_aliases_in_sidebar: false,
+            cutoff_date: None,
+            min_duration: None,
+            max_duration: None,
+            include_unset_duration: true,
+            exact_priority: None,
+            require_unset_duration: false,
+            excluded_categories: &HashSet::new(),
+            excluded_locations: &HashSet::new(),
+            excluded_priorities: &HashSet::new(),
+            excluded_statuses: &HashSet::new(),
+            urgent_days: 1,
+            urgent_prio: 1,
+            default_priority: 5,
+            start_grace_period_days: 1,
+            sort_standard_by_priority: false,
+            sort_preset: SortPreset::default(),
+            sort_mode: SortMode::default(),
+            all_day_sort: AllDaySort::default(),
+            week_start: Weekday::Mon,
+            sort_urgency_boost: false,
+            expanded_done_groups: &empty_set,
+            expanded_tags: &empty_set,
+            expanded_locations: &empty_set,
+            max_done_roots: usize::MAX,
+            max_done_subtasks: usize::MAX,
+            tag_aliases: &HashMap::new(),
+            search_collapsed_tasks: &HashSet::new(),
+            focused_task_uid: None,
+            fuzzy_search: false,
+        });
+        let mut uids: Vec<String> = res
+            .items
+            .iter()
+            .filter_map(|i| match i {
+                cfait::store::TaskListItem::Task(t) => Some(t.uid.clone()),
+                _ => None,
+            })
+            .collect();
+        uids.sort();
+        uids
+    };
+
+    // A parent selects every level below it, case-insensitively, but not a sibling prefix
+    assert_eq!(run(&store, "Home"), vec!["1", "2", "3"]);
+    assert_eq!(run(&store, "@@home"), vec!["1", "2", "3"]);
+    assert_eq!(run(&store, "home:garden"), vec!["3"]);
+    assert_eq!(run(&store, "Home:Office"), vec!["2"]);
+    assert_eq!(run(&store, "home:garden:greenhouse"), vec!["3"]);
+
+    assert!(cfait::store::matches_hierarchy("home:office", "HOME"));
+    assert!(!cfait::store::matches_hierarchy("homework", "home"));
+}
+
+#[test]
+fn test_no_location_bucket() {
+    use cfait::store::NO_LOCATION_ID;
+    let mut store = make_store();
+    for (uid, input, cal) in [
+        ("1", "Vacuum @@home", "cal1"),
+        ("2", "Call bank", "cal1"),
+        ("3", "Think", "cal1"),
+        ("4", "Hidden chore", "hidden"),
+    ] {
+        let mut t = Task::new(input, &HashMap::new(), None);
+        t.uid = uid.to_string();
+        t.calendar_href = cal.to_string();
+        store.add_task(t);
+    }
+    store.get_task_mut("3").unwrap().0.status = TaskStatus::Completed;
+
+    let empty_set = HashSet::new();
+    let hidden: HashSet<String> = ["hidden".to_string()].into_iter().collect();
+    let locs: HashSet<String> = [NO_LOCATION_ID.to_string()].into_iter().collect();
+    let res = store.filter(FilterOptions {
+        active_cal_href: None,
+        hidden_calendars: &hidden,
+        selected_categories: &empty_set,
+        selected_locations: &locs,
+        match_all_categories: false,
+        search_term: "",
+        hide_completed_global: true,
+        hide_deferred: false,
+        hide_fully_completed_tags: false,
+        hide_aliases_in_sidebar: false,
+        cutoff_date: None,
+        min_duration: None,
+        max_duration: None,
+        include_unset_duration: true,
+        exact_priority: None,
+        require_unset_duration: false,
+        excluded_categories: &HashSet::new(),
+        excluded_locations: &HashSet::new(),
+        excluded_priorities: &HashSet::new(),
+        excluded_statuses: &HashSet::new(),
+        urgent_days: 1,
+        urgent_prio: 1,
+        default_priority: 5,
+        start_grace_period_days: 1,
+        sort_standard_by_priority: false,
+        sort_preset: SortPreset::default(),
+        sort_mode: SortMode::default(),
+        all_day_sort: AllDaySort::default(),
+        week_start: Weekday::Mon,
+        sort_urgency_boost: false,
+        expanded_done_groups: &empty_set,
+        expanded_tags: &empty_set,
+        expanded_locations: &empty_set,
+        max_done_roots: usize::MAX,
+        max_done_subtasks: usize::MAX,
+        tag_aliases: &HashMap::new(),
+        search_collapsed_tasks: &HashSet::new(),
+        focused_task_uid: None,
+        fuzzy_search: false,
+    });
+
+    // Only the visible, open task without a location is listed
+    let uids: Vec<String> = res
+        .items
+        .iter()
+        .filter_map(|i| match i {
+            cfait::store::TaskListItem::Task(t) => Some(t.uid.clone()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(uids, vec!["2".to_string()]);
+
+    // The bucket follows the real locations and counts only that task
+    let bucket = res.locations.last().unwrap();
+    assert_eq!(bucket.full_key, NO_LOCATION_ID);
+    assert_eq!(bucket.count, 1);
+    assert_eq!(res.locations[0].full_key, "home");
+}
+
 #[test]
 fn test_hide_hidden_calendars() {
     let mut store = make_store();
@@ -151,18 +339,29 @@ fn test_hide_hidden_calendars() {
         match_all_categories: false,
         search_term: "",
         hide_completed_global: false,
+        hide_deferred: false,
         hide_fully_completed_tags: false,
         hide_aliases_in_sidebar: false,
         cutoff_date: None,
         min_duration: None,
         max_duration: None,
         include_unset_duration: true,
+        exact_priority: None,
+        require_unset_duration: false,
+        excluded_categories: &HashSet::new(),
+        excluded_locations: &HashSet::new(),
+        excluded_priorities: &HashSet::new(),
+        excluded_statuses: &HashSet::new(),
         urgent_days: 1,
         urgent_prio: 1,
         default_priority: 5,
         start_grace_period_days: 1,
         sort_standard_by_priority: false,
         sort_preset: SortPreset::default(),
+        sort_mode: SortMode::default(),
+        all_day_sort: AllDaySort::default(),
+        week_start: Weekday::Mon,
+        sort_urgency_boost: false,
         expanded_done_groups: &empty_set,
         expanded_tags: &empty_set,
         expanded_locations: &empty_set,
@@ -172,6 +371,7 @@ fn test_hide_hidden_calendars() {
         tag_aliases: &HashMap::new(),
         search_collapsed_tasks: &HashSet::new(),
         focused_task_uid: None,
+        fuzzy_search: false,
     });
     let results = filter_res.items;
 
@@ -622,6 +822,67 @@ fn test_task_display_logic() {
     assert!(task.format_duration_short(None).contains("1h"));
 }
 
+#[test]
+fn test_drag_reparent_guards_and_promote_to_root() {
+    let mut store = make_store();
+    let config = cfait::config::Config::default();
+
+    for (uid, parent) in [("a", None), ("b", Some("a")), ("c", Some("b")), ("d", None)] {
+        let mut t = Task::new(uid, &HashMap::new(), None);
+        t.uid = uid.to_string();
+        t.calendar_href = "cal1".to_string();
+        t.parent_uid = parent.map(str::to_string);
+        store.add_task(t);
+    }
+
+    // Self and own descendants are refused, unrelated tasks are accepted
+    assert!(!store.can_set_parent("a", "a"));
+    assert!(!store.can_set_parent("a", "c"));
+    assert!(store.can_set_parent("c", "d"));
+    assert!(store.can_set_parent("d", "c"));
+    assert!(!store.can_set_parent("d", "missing"));
+
+    // PromoteToRoot detaches a grandchild completely, unlike RemoveParent
+    store.apply_task_intent(
+        &cfait::model::AppIntent::PromoteToRoot {
+            uid: "c".to_string(),
+        },
+        &config,
+    );
+    assert_eq!(store.get_task_ref("c").unwrap().parent_uid, None);
+    assert!(store.can_set_parent("a", "c"));
+}
+
+#[test]
+fn test_rename_task_only_touches_summary() {
+    let mut store = make_store();
+    let config = cfait::config::Config::default();
+
+    let mut t = Task::new("Call mom @tomorrow #family !2", &HashMap::new(), None);
+    t.uid = "r1".to_string();
+    t.calendar_href = "cal1".to_string();
+    store.add_task(t.clone());
+
+    let actions = store.apply_task_intent(
+        &cfait::model::AppIntent::RenameTask {
+            uid: "r1".to_string(),
+            summary: "  Call dad  ".to_string(),
+        },
+        &config,
+    );
+    assert_eq!(actions.len(), 1);
+    let renamed = store.get_task_ref("r1").unwrap();
+    assert_eq!(renamed.summary, "Call dad");
+    assert_eq!(renamed.due, t.due);
+    assert_eq!(renamed.categories, t.categories);
+    assert_eq!(renamed.priority, t.priority);
+    assert_eq!(renamed.sequence, t.sequence + 1);
+
+    // Blank or unchanged titles are no-ops
+    assert!(store.rename_task("r1", "   ").is_none());
+    assert!(store.rename_task("r1", "Call dad").is_none());
+}
+
 #[test]
 fn test_color_utils_hex_parsing() {
     let floats = cfait::color_utils::parse_hex_to_floats("#FF0000").unwrap();
@@ -640,6 +901,22 @@ fn test_color_utils_hex_parsing() {
     let _dark = cfait::color_utils::is_dark(color.0, color.1, color.2);
 }
 
+#[test]
+fn test_colorblind_palette_index_is_stable() {
+    use cfait::color_utils::{COLORBLIND_PALETTE, palette_color, palette_index};
+
+    // Pinned values: the index must not drift between runs, platforms or toolchains.
+    assert_eq!(palette_index("work"), 6);
+    assert_eq!(palette_index("home"), 3);
+    assert_eq!(palette_index("work"), palette_index("work"));
+
+    let (r, g, b) = COLORBLIND_PALETTE[palette_index("home")];
+    assert_eq!(
+        palette_color("home", true),
+        (r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0)
+    );
+}
+
 #[test]
 fn test_session_state_intents() {
     let mut session = cfait::model::SessionState::default();
@@ -722,6 +999,61 @@ fn test_config_errors_and_save() {
     assert!(!cfait::config::TaskAction::OpenUrl.label().is_empty());
 }
 
+#[test]
+fn test_config_snooze_presets_validated_on_load() {
+    let ctx = Arc::new(TestContext::new());
+
+    // Unset: the short/long pair
+    let config = cfait::config::Config::default();
+    assert_eq!(config.snooze_options(), vec![60, 1440]);
+
+    let config = cfait::config::Config {
+        snooze_presets: vec![5, 0, 15, 5, 1440],
+        ..Default::default()
+    };
+    config.save(ctx.as_ref()).unwrap();
+
+    let loaded = cfait::config::Config::load(ctx.as_ref()).unwrap();
+    assert_eq!(loaded.snooze_presets, vec![5, 15, 1440]);
+    assert_eq!(loaded.snooze_options(), vec![5, 15, 1440]);
+
+    // Never empty, even with nonsense everywhere
+    assert_eq!(cfait::config::snooze_options(&[0], 0, 0), vec![60, 1440]);
+}
+
+#[test]
+fn test_config_load_disables_alias_cycles() {
+    let ctx = Arc::new(TestContext::new());
+
+    let mut config = cfait::config::Config::default();
+    config
+        .tag_aliases
+        .insert("a".to_string(), vec!["#b".to_string()]);
+    config.tag_aliases.insert(
+        "b".to_string(),
+        vec!["#a".to_string(), "#shared".to_string()],
+    );
+    config
+        .tag_aliases
+        .insert("ok".to_string(), vec!["#fine".to_string()]);
+    config.save(ctx.as_ref()).unwrap();
+
+    let loaded = cfait::config::Config::load(ctx.as_ref()).unwrap();
+    assert_eq!(loaded.tag_aliases.len(), 1);
+    assert!(loaded.tag_aliases.contains_key("ok"));
+    assert_eq!(loaded.disabled_aliases.len(), 2);
+    assert_eq!(loaded.alias_problems.len(), 2);
+
+    // Disabled aliases are not expanded
+    let task = Task::new("Test #a", &loaded.tag_aliases, None);
+    assert!(!task.categories.contains(&"shared".to_string()));
+
+    // ...but saving again keeps them on disk so the user can fix them
+    loaded.save(ctx.as_ref()).unwrap();
+    let reloaded = cfait::config::Config::load(ctx.as_ref()).unwrap();
+    assert_eq!(reloaded.disabled_aliases.len(), 2);
+}
+
 #[test]
 fn test_system_logging_and_keyring() {
     let ctx = Arc::new(TestContext::new());
@@ -777,3 +1109,749 @@ async fn test_companion_events_batching() {
         .unwrap();
     assert_eq!(count, 3); // 3 deletion futures for legacy suffixes
 }
+
+#[test]
+fn test_set_progress_intent_completes_and_reopens() {
+    let ctx = Arc::new(TestContext::new());
+    let mut store = TaskStore::new(ctx);
+    let config = cfait::config::Config::default();
+
+    let mut task = Task::new("Report", &HashMap::new(), None);
+    task.uid = "p1".to_string();
+    task.calendar_href = "cal1".to_string();
+    store.add_task(task);
+
+    let set = |pc: u8| cfait::model::AppIntent::SetProgress {
+        uid: "p1".to_string(),
+        percent: pc,
+    };
+
+    let actions = store.apply_task_intent(&set(40), &config);
+    assert_eq!(actions.len(), 1);
+    let t = store.get_task_ref("p1").unwrap();
+    assert_eq!(t.percent_complete, Some(40));
+    assert_eq!(t.status, TaskStatus::NeedsAction);
+
+    // Same value again is a no-op
+    assert!(store.apply_task_intent(&set(40), &config).is_empty());
+
+    // 100% completes the task
+    store.apply_task_intent(&set(100), &config);
+    let t = store.get_task_ref("p1").unwrap();
+    assert_eq!(t.status, TaskStatus::Completed);
+    assert_eq!(t.percent_complete, Some(100));
+
+    // Dropping below 100 reopens it with the new percentage
+    store.apply_task_intent(&set(90), &config);
+    let t = store.get_task_ref("p1").unwrap();
+    assert_eq!(t.status, TaskStatus::NeedsAction);
+    assert_eq!(t.percent_complete, Some(90));
+    assert!(t.completion_date().is_none());
+
+    // 0% clears the property
+    store.apply_task_intent(&set(0), &config);
+    assert_eq!(store.get_task_ref("p1").unwrap().percent_complete, None);
+}
+
+#[test]
+fn test_complete_tasks_skips_blocked_and_advances_recurring() {
+    let ctx = Arc::new(TestContext::new());
+    let mut store = TaskStore::new(ctx);
+    let config = cfait::config::Config::default();
+
+    let mut blocker = Task::new("Blocker", &HashMap::new(), None);
+    blocker.uid = "a".to_string();
+    blocker.calendar_href = "cal1".to_string();
+
+    let mut blocked = Task::new("Blocked", &HashMap::new(), None);
+    blocked.uid = "b".to_string();
+    blocked.calendar_href = "cal1".to_string();
+    blocked.dependencies.push("a".to_string());
+
+    let original_due = chrono::Utc::now() - chrono::Duration::days(1);
+    let mut recurring = Task::new("Recurring", &HashMap::new(), None);
+    recurring.uid = "r".to_string();
+    recurring.calendar_href = "cal1".to_string();
+    recurring.due = Some(cfait::model::DateType::Specific(original_due));
+    recurring.rrule = Some("FREQ=DAILY".to_string());
+
+    store.add_task(blocker);
+    store.add_task(blocked);
+    store.add_task(recurring);
+
+    let items: Vec<cfait::store::TaskListItem> = ["a", "b", "r"]
+        .iter()
+        .map(|uid| {
+            cfait::store::TaskListItem::Task(Box::new(store.get_task_ref(uid).unwrap().clone()))
+        })
+        .collect();
+
+    let uids = store.completable_uids(&items);
+    assert_eq!(uids, vec!["a".to_string(), "r".to_string()]);
+
+    let actions =
+        store.apply_task_intent(&cfait::model::AppIntent::CompleteTasks { uids }, &config);
+    assert!(!actions.is_empty());
+
+    assert_eq!(
+        store.get_task_ref("a").unwrap().status,
+        TaskStatus::Completed
+    );
+    // Blocked state is evaluated before the batch, so the dependent stays open
+    assert_eq!(
+        store.get_task_ref("b").unwrap().status,
+        TaskStatus::NeedsAction
+    );
+
+    let r = store.get_task_ref("r").unwrap();
+    assert_eq!(r.status, TaskStatus::NeedsAction);
+    match r.due.as_ref().unwrap() {
+        cfait::model::DateType::Specific(d) => assert!(*d > original_due),
+        _ => panic!("recurring task lost its due time"),
+    }
+}
+
+#[test]
+fn test_undo_delete_recreates_with_original_uid_and_links() {
+    let ctx = Arc::new(TestContext::new());
+    let mut store = TaskStore::new(ctx);
+    let config = cfait::config::Config::default();
+    let mut history = cfait::store::UndoHistory::default();
+
+    let mut parent = Task::new("Parent", &HashMap::new(), None);
+    parent.uid = "p".to_string();
+    parent.calendar_href = "cal1".to_string();
+    parent.href = "cal1/p.ics".to_string();
+
+    let mut child = Task::new("Child", &HashMap::new(), None);
+    child.uid = "c".to_string();
+    child.calendar_href = "cal1".to_string();
+    child.parent_uid = Some("p".to_string());
+
+    let mut dependent = Task::new("Dependent", &HashMap::new(), None);
+    dependent.uid = "d".to_string();
+    dependent.calendar_href = "cal1".to_string();
+    dependent.dependencies.push("p".to_string());
+
+    store.add_task(parent);
+    store.add_task(child);
+    store.add_task(dependent);
+
+    let intent = cfait::model::AppIntent::DeleteTask {
+        uid: "p".to_string(),
+    };
+    store.apply_task_intent_with_undo(&intent, &config, &mut history);
+    assert!(store.get_task_ref("c").unwrap().parent_uid.is_none());
+    assert!(store.get_task_ref("d").unwrap().dependencies.is_empty());
+
+    let entry = history.pop().expect("delete should be undoable");
+    assert!(history.is_empty());
+    let actions = store.undo(entry);
+
+    assert!(actions.iter().any(|a| matches!(
+        a,
+        cfait::journal::Action::Create(t) if t.uid == "p" && t.calendar_href == "cal1"
+    )));
+    assert_eq!(store.get_task_ref("p").unwrap().calendar_href, "cal1");
+    assert_eq!(
+        store.get_task_ref("c").unwrap().parent_uid.as_deref(),
+        Some("p")
+    );
+    assert_eq!(store.get_task_ref("d").unwrap().dependencies, vec!["p"]);
+}
+
+#[test]
+fn test_archive_bucket_hides_old_completed_until_requested() {
+    use cfait::config::Config;
+    use cfait::model::session::SessionState;
+    use chrono::{Duration, Utc};
+
+    let mut store = make_store();
+    let mk = |uid: &str, completed_days_ago: Option<i64>| {
+        let mut t = Task::new(uid, &HashMap::new(), None);
+        t.uid = uid.to_string();
+        t.calendar_href = "cal1".to_string();
+        if let Some(days) = completed_days_ago {
+            t.set_completion_date(Some(Utc::now() - Duration::days(days)));
+        }
+        t
+    };
+    store.insert(
+        "cal1".to_string(),
+        vec![mk("open", None), mk("recent", Some(2)), mk("old", Some(90))],
+    );
+
+    let config = Config {
+        archive_completed: true,
+        sort_cutoff_days: Some(30),
+        hide_completed: false,
+        ..Default::default()
+    };
+    let uids = |store: &TaskStore, search: &str| -> Vec<String> {
+        let session = SessionState {
+            search_term: search.to_string(),
+            ..Default::default()
+        };
+        let mut uids: Vec<String> = session
+            .get_filtered_view(store, &config)
+            .items
+            .iter()
+            .filter_map(|i| match i {
+                cfait::store::TaskListItem::Task(t) => Some(t.uid.clone()),
+                _ => None,
+            })
+            .collect();
+        uids.sort();
+        uids
+    };
+
+    store.apply_archive_setting(&config, "");
+    assert_eq!(store.archived_count(), 1);
+    assert!(store.get_task_ref("old").is_none());
+    assert_eq!(uids(&store, ""), vec!["open", "recent"]);
+
+    // A refresh of the calendar keeps the old task shelved.
+    store.insert(
+        "cal1".to_string(),
+        vec![mk("open", None), mk("recent", Some(2)), mk("old", Some(90))],
+    );
+    assert_eq!(store.archived_count(), 1);
+
+    store.apply_archive_setting(&config, "is:archived");
+    assert_eq!(store.archived_count(), 0);
+    assert!(store.get_task_ref("old").is_some());
+    assert_eq!(uids(&store, "is:archived"), vec!["old"]);
+
+    // Disabling the setting brings everything back into the main list.
+    store.apply_archive_setting(&Config::default(), "");
+    assert_eq!(store.archived_count(), 0);
+    assert_eq!(uids(&store, "").len(), 3);
+}
+
+#[test]
+fn test_archived_tasks_are_exported_and_recognised_on_import() {
+    use cfait::config::Config;
+    use cfait::storage::DuplicatePolicy;
+    use cfait::store::ExportFormat;
+    use chrono::{Duration, Utc};
+
+    let mut store = make_store();
+    let mut open = Task::new("Open", &HashMap::new(), None);
+    open.uid = "open".to_string();
+    open.calendar_href = "cal1".to_string();
+    let mut old = Task::new("Old", &HashMap::new(), None);
+    old.uid = "old".to_string();
+    old.calendar_href = "cal1".to_string();
+    old.set_completion_date(Some(Utc::now() - Duration::days(90)));
+    store.insert("cal1".to_string(), vec![open, old]);
+
+    let config = Config {
+        archive_completed: true,
+        sort_cutoff_days: Some(30),
+        ..Default::default()
+    };
+    store.apply_archive_setting(&config, "");
+    assert!(store.is_archived("old"));
+
+    let backup = store.export_all(ExportFormat::Json);
+    let exported: Vec<Task> = serde_json::from_str(&backup).unwrap();
+    assert_eq!(exported.len(), 2);
+    assert!(exported.iter().any(|t| t.uid == "old"));
+
+    // Restoring the backup over the same store finds every task already present
+    let (actions, summary) = store.import_tasks(&backup, "cal1", DuplicatePolicy::Skip);
+    assert!(actions.is_empty());
+    assert_eq!(summary.imported, 0);
+    assert_eq!(summary.skipped, 2);
+    assert_eq!(store.archived_count(), 1);
+}
+
+#[test]
+fn test_reschedule_due_keeps_time_of_day() {
+    use cfait::model::DateType;
+    use chrono::{Duration, Local, TimeZone, Utc};
+
+    let mut store = make_store();
+    let today = Local::now().date_naive();
+    let at_1430 = Local
+        .from_local_datetime(&today.and_hms_opt(14, 30, 0).unwrap())
+        .earliest()
+        .unwrap()
+        .with_timezone(&Utc);
+
+    let mut timed = Task::new("Timed", &HashMap::new(), None);
+    timed.uid = "timed".to_string();
+    timed.calendar_href = "cal1".to_string();
+    timed.due = Some(DateType::Specific(at_1430));
+    let mut allday = Task::new("All day", &HashMap::new(), None);
+    allday.uid = "allday".to_string();
+    allday.calendar_href = "cal1".to_string();
+    store.add_task(timed);
+    store.add_task(allday);
+
+    let updated = store
+        .reschedule_due("timed", Some("tomorrow"), Weekday::Mon)
+        .unwrap();
+    match updated.due {
+        Some(DateType::Specific(dt)) => {
+            let local = dt.with_timezone(&Local);
+            assert_eq!(local.date_naive(), today + Duration::days(1));
+            assert_eq!(local.format("%H:%M").to_string(), "14:30");
+        }
+        other => panic!("expected a timed due date, got {:?}", other),
+    }
+
+    let updated = store
+        .reschedule_due("allday", Some("1w"), Weekday::Mon)
+        .unwrap();
+    assert_eq!(
+        updated.due,
+        Some(DateType::AllDay(today + Duration::days(7)))
+    );
+
+    assert!(
+        store
+            .reschedule_due("allday", None, Weekday::Mon)
+            .unwrap()
+            .due
+            .is_none()
+    );
+    // Clearing an already empty due date is a no-op.
+    assert!(store.reschedule_due("allday", None, Weekday::Mon).is_none());
+}
+
+#[test]
+fn test_view_checklist_export() {
+    use cfait::store::TaskListItem;
+
+    let mut parent = Task::new("Groceries", &HashMap::new(), None);
+    parent.depth = 0;
+    let mut child = Task::new("Milk", &HashMap::new(), None);
+    child.depth = 1;
+    child.status = TaskStatus::Completed;
+    let mut grandchild = Task::new("Oat", &HashMap::new(), None);
+    grandchild.depth = 2;
+
+    let items = vec![
+        TaskListItem::Task(Box::new(parent)),
+        TaskListItem::Task(Box::new(child)),
+        TaskListItem::ExpandGroup(String::new(), 0),
+        TaskListItem::Task(Box::new(grandchild)),
+    ];
+
+    let md = cfait::model::extractor::serialize_view_checklist(&items);
+    assert_eq!(md, "- [ ] Groceries\n    - [x] Milk\n        - [ ] Oat");
+}
+
+#[test]
+fn test_view_estimated_duration_total() {
+    use cfait::store::{TaskListItem, TaskStore};
+
+    let aliases = HashMap::new();
+    let a = Task::new("Write report ~1h", &aliases, None);
+    let b = Task::new("Review ~30m-1h", &aliases, None);
+    let mut done = Task::new("Old ~2h", &aliases, None);
+    done.status = TaskStatus::Completed;
+    let unset = Task::new("No estimate", &aliases, None);
+
+    let items: Vec<TaskListItem> = [a, b, done, unset]
+        .into_iter()
+        .map(|t| TaskListItem::Task(Box::new(t)))
+        .collect();
+
+    assert_eq!(TaskStore::estimated_duration_total(&items), Some((90, 120)));
+    assert_eq!(
+        cfait::model::parser::format_estimate_range(90, 120),
+        "~90m-2h"
+    );
+    assert_eq!(TaskStore::estimated_duration_total(&items[3..]), None);
+}
+
+#[test]
+fn test_duplicate_task_drops_outside_links() {
+    let mut store = make_store();
+
+    let mut parent = Task::new("Project", &HashMap::new(), None);
+    parent.uid = "p".to_string();
+    parent.calendar_href = "cal1".to_string();
+    let mut blocker = Task::new("Blocker", &HashMap::new(), None);
+    blocker.uid = "b".to_string();
+    blocker.calendar_href = "cal1".to_string();
+
+    let mut t = Task::new("Report #work @@office ~1h @weekly", &HashMap::new(), None);
+    t.uid = "t".to_string();
+    t.calendar_href = "cal1".to_string();
+    t.href = "/cal1/t.ics".to_string();
+    t.etag = "\"1\"".to_string();
+    t.status = TaskStatus::InProcess;
+    t.percent_complete = Some(40);
+    t.parent_uid = Some("p".to_string());
+    t.dependencies.push("b".to_string());
+
+    let mut sub = Task::new("Outline", &HashMap::new(), None);
+    sub.uid = "s".to_string();
+    sub.calendar_href = "cal1".to_string();
+    sub.parent_uid = Some("t".to_string());
+
+    store.add_task(parent);
+    store.add_task(blocker);
+    store.add_task(t);
+    store.add_task(sub);
+
+    let copies = store.duplicate_task_tree("t");
+    assert_eq!(copies.len(), 2);
+
+    let root = &copies[0];
+    assert_ne!(root.uid, "t");
+    assert!(root.href.is_empty() && root.etag.is_empty());
+    assert_eq!(root.status, TaskStatus::NeedsAction);
+    assert_eq!(root.percent_complete, None);
+    assert_eq!(root.calendar_href, "cal1");
+    assert_eq!(root.categories, vec!["work".to_string()]);
+    assert_eq!(root.location.as_deref(), Some("office"));
+    assert_eq!(root.estimated_duration, Some(60));
+    assert!(root.rrule.is_some());
+    // Links to tasks outside the copied tree are dropped
+    assert_eq!(root.parent_uid, None);
+    assert!(root.dependencies.is_empty());
+
+    // The copied subtask hangs under the copy, not the original
+    assert_eq!(copies[1].parent_uid.as_deref(), Some(root.uid.as_str()));
+    assert!(store.get_task_ref(&root.uid).is_some());
+}
+
+#[test]
+fn test_batch_tag_edit_and_rename() {
+    let mut store = make_store();
+    for (uid, input) in [
+        ("1", "Spec #proj"),
+        ("2", "Review #proj:docs"),
+        ("3", "Unrelated"),
+    ] {
+        let mut t = Task::new(input, &HashMap::new(), None);
+        t.uid = uid.to_string();
+        t.calendar_href = "cal1".to_string();
+        store.add_task(t);
+    }
+
+    let uids = vec!["1".to_string(), "3".to_string()];
+    let added = store.set_tag_on_tasks(&uids, "urgent", true);
+    assert_eq!(added.len(), 2);
+    // Already tagged tasks are left alone
+    assert!(store.set_tag_on_tasks(&uids, "urgent", true).is_empty());
+    let removed = store.set_tag_on_tasks(&["3".to_string()], "urgent", false);
+    assert_eq!(removed.len(), 1);
+    assert!(
+        !store
+            .get_task_ref("3")
+            .unwrap()
+            .categories
+            .contains(&"urgent".to_string())
+    );
+
+    let renamed = store.rename_tag("proj", "apollo");
+    assert_eq!(renamed.len(), 2);
+    assert_eq!(
+        store.get_task_ref("1").unwrap().categories,
+        vec!["apollo".to_string(), "urgent".to_string()]
+    );
+    assert_eq!(
+        store.get_task_ref("2").unwrap().categories,
+        vec!["apollo:docs".to_string()]
+    );
+
+    let mut aliases: HashMap<String, Vec<String>> = HashMap::new();
+    aliases.insert("proj".to_string(), vec!["#work".to_string()]);
+    aliases.insert("moon".to_string(), vec!["#proj:docs".to_string()]);
+    assert_eq!(
+        cfait::model::parser::rename_tag_in_aliases(&mut aliases, "proj", "apollo"),
+        2
+    );
+    assert_eq!(aliases.get("apollo"), Some(&vec!["#work".to_string()]));
+    assert_eq!(aliases.get("moon"), Some(&vec!["#apollo:docs".to_string()]));
+    assert!(!aliases.contains_key("proj"));
+}
+
+#[test]
+fn test_rename_tag_everywhere_merges_into_existing_tag() {
+    let mut store = make_store();
+    let mut t = Task::new("Both #old #new", &HashMap::new(), None);
+    t.uid = "1".to_string();
+    t.calendar_href = "cal1".to_string();
+    store.add_task(t);
+
+    let mut aliases: HashMap<String, Vec<String>> = HashMap::new();
+    aliases.insert("old".to_string(), vec!["#home".to_string()]);
+    aliases.insert(
+        "new".to_string(),
+        vec!["#old".to_string(), "@@office".to_string()],
+    );
+
+    let (modified, alias_count) = store.rename_tag_everywhere(&mut aliases, "old", "new");
+    assert_eq!(modified.len(), 1);
+    // No duplicate tag after the merge
+    assert_eq!(
+        store.get_task_ref("1").unwrap().categories,
+        vec!["new".to_string()]
+    );
+    // The two aliases merge, and "#new" is not kept as a target of "new" itself
+    assert_eq!(alias_count, 1);
+    let mut merged = aliases.get("new").cloned().unwrap();
+    merged.sort();
+    assert_eq!(merged, vec!["#home".to_string(), "@@office".to_string()]);
+    assert_eq!(aliases.len(), 1);
+}
+
+#[test]
+fn test_rename_tag_everywhere_can_be_undone() {
+    let mut store = make_store();
+    let mut history = cfait::store::UndoHistory::default();
+    let mut t = Task::new("Tagged #old:sub", &HashMap::new(), None);
+    t.uid = "1".to_string();
+    t.calendar_href = "cal1".to_string();
+    store.add_task(t);
+    let mut untouched = Task::new("Other #keep", &HashMap::new(), None);
+    untouched.uid = "2".to_string();
+    untouched.calendar_href = "cal1".to_string();
+    store.add_task(untouched);
+
+    let mut aliases: HashMap<String, Vec<String>> = HashMap::new();
+    let (modified, _) =
+        store.rename_tag_everywhere_with_undo(&mut aliases, "old", "new", &mut history);
+    assert_eq!(modified.len(), 1);
+    assert_eq!(
+        store.get_task_ref("1").unwrap().categories,
+        vec!["new:sub".to_string()]
+    );
+
+    let entry = history.pop().expect("rename should be undoable");
+    let actions = store.undo(entry);
+    assert_eq!(actions.len(), 1);
+    assert_eq!(
+        store.get_task_ref("1").unwrap().categories,
+        vec!["old:sub".to_string()]
+    );
+    assert!(history.pop().is_none());
+}
+
+fn parent_with_children(store: &mut TaskStore, children: usize) {
+    let mut parent = Task::new("Parent", &HashMap::new(), None);
+    parent.uid = "p".to_string();
+    store.add_task(parent);
+    for i in 0..children {
+        let mut child = Task::new("Child", &HashMap::new(), None);
+        child.uid = format!("c{}", i);
+        child.parent_uid = Some("p".to_string());
+        store.add_task(child);
+    }
+}
+
+#[test]
+fn test_parent_progress_percent_follows_subtasks() {
+    let mut store = make_store();
+    parent_with_children(&mut store, 4);
+    let mut config = cfait::config::Config {
+        parent_progress: cfait::config::ParentProgress::Percent,
+        ..Default::default()
+    };
+
+    let toggle = |uid: &str| cfait::model::AppIntent::ToggleTask {
+        uid: uid.to_string(),
+    };
+    let actions = store.apply_task_intent(&toggle("c0"), &config);
+    // The child and the parent are both persisted
+    assert_eq!(actions.len(), 2);
+    assert_eq!(store.get_task_ref("p").unwrap().percent_complete, Some(25));
+
+    for uid in ["c1", "c2", "c3"] {
+        store.apply_task_intent(&toggle(uid), &config);
+    }
+    let parent = store.get_task_ref("p").unwrap();
+    assert_eq!(parent.percent_complete, Some(100));
+    assert!(!parent.status.is_done());
+
+    config.parent_progress = cfait::config::ParentProgress::Off;
+    store.apply_task_intent(&toggle("c3"), &config);
+    assert_eq!(store.get_task_ref("p").unwrap().percent_complete, Some(100));
+}
+
+#[test]
+fn test_parent_progress_complete_closes_and_reopens_parent() {
+    let mut store = make_store();
+    parent_with_children(&mut store, 2);
+    let config = cfait::config::Config {
+        parent_progress: cfait::config::ParentProgress::Complete,
+        ..Default::default()
+    };
+    let toggle = |uid: &str| cfait::model::AppIntent::ToggleTask {
+        uid: uid.to_string(),
+    };
+
+    store.apply_task_intent(&toggle("c0"), &config);
+    assert_eq!(store.get_task_ref("p").unwrap().percent_complete, Some(50));
+
+    store.apply_task_intent(&toggle("c1"), &config);
+    assert_eq!(
+        store.get_task_ref("p").unwrap().status,
+        TaskStatus::Completed
+    );
+
+    // Reopening a subtask reopens the parent
+    store.apply_task_intent(&toggle("c1"), &config);
+    let parent = store.get_task_ref("p").unwrap();
+    assert_eq!(parent.status, TaskStatus::NeedsAction);
+    assert_eq!(parent.percent_complete, Some(50));
+}
+
+#[test]
+fn test_parent_progress_complete_advances_recurring_parent() {
+    let mut store = make_store();
+    parent_with_children(&mut store, 1);
+    {
+        let (parent, _) = store.get_task_mut("p").unwrap();
+        parent.rrule = Some("FREQ=DAILY".to_string());
+        parent.due = Some(cfait::model::DateType::AllDay(
+            chrono::Local::now().date_naive(),
+        ));
+    }
+    let config = cfait::config::Config {
+        parent_progress: cfait::config::ParentProgress::Complete,
+        ..Default::default()
+    };
+
+    let actions = store.apply_task_intent(
+        &cfait::model::AppIntent::ToggleTask {
+            uid: "c0".to_string(),
+        },
+        &config,
+    );
+
+    // The recurring parent advanced instead of staying done, and its subtask was reset
+    let parent = store.get_task_ref("p").unwrap();
+    assert_eq!(parent.status, TaskStatus::NeedsAction);
+    assert_eq!(
+        store.get_task_ref("c0").unwrap().status,
+        TaskStatus::NeedsAction
+    );
+    assert!(
+        actions
+            .iter()
+            .any(|a| matches!(a, cfait::journal::Action::Create(t) if t.uid != "p"))
+    );
+}
+
+#[test]
+fn test_agenda_grouping_buckets_and_keeps_subtrees() {
+    use cfait::config::AgendaGrouping;
+    use cfait::model::DateType;
+    use cfait::store::{AgendaGroup, TaskListItem, agenda_headers, group_by_agenda};
+    use chrono::NaiveDate;
+
+    // A Wednesday; the default week ends on Sunday the 18th
+    let today = NaiveDate::from_ymd_opt(2026, 10, 14).unwrap();
+    let day = |d: u32| {
+        Some(DateType::AllDay(
+            NaiveDate::from_ymd_opt(2026, 10, d).unwrap(),
+        ))
+    };
+    let task = |uid: &str, due: Option<DateType>, depth: usize| {
+        let mut t = Task::new(uid, &HashMap::new(), None);
+        t.uid = uid.to_string();
+        t.due = due;
+        t.depth = depth;
+        TaskListItem::Task(Box::new(t))
+    };
+
+    let items = vec![
+        task("later", day(25), 0),
+        task("later-child", day(13), 1),
+        task("none", None, 0),
+        task("today", day(14), 0),
+        task("overdue", day(13), 0),
+        task("week", day(17), 0),
+        task("tomorrow", day(15), 0),
+        task("today-2", day(14), 0),
+    ];
+    let grouped = group_by_agenda(items, AgendaGrouping::Due, today, Weekday::Mon);
+    let uids: Vec<String> = grouped
+        .iter()
+        .map(|i| match i {
+            TaskListItem::Task(t) => t.uid.clone(),
+            _ => String::new(),
+        })
+        .collect();
+    assert_eq!(
+        uids,
+        vec![
+            "overdue",
+            "today",
+            "today-2",
+            "tomorrow",
+            "week",
+            "later",
+            "later-child",
+            "none"
+        ]
+    );
+
+    let headers = agenda_headers(&grouped, AgendaGrouping::Due, today, Weekday::Mon);
+    assert_eq!(
+        headers,
+        vec![
+            (0, AgendaGroup::Overdue),
+            (1, AgendaGroup::Today),
+            (3, AgendaGroup::Tomorrow),
+            (4, AgendaGroup::ThisWeek),
+            (5, AgendaGroup::Later),
+            (7, AgendaGroup::NoDate),
+        ]
+    );
+    assert!(agenda_headers(&grouped, AgendaGrouping::Off, today, Weekday::Mon).is_empty());
+}
+
+#[test]
+fn test_agenda_group_by_start_date() {
+    use cfait::config::AgendaGrouping;
+    use cfait::model::DateType;
+    use cfait::store::AgendaGroup;
+    use chrono::NaiveDate;
+
+    let today = NaiveDate::from_ymd_opt(2026, 10, 14).unwrap();
+    let day = |d: u32| {
+        Some(DateType::AllDay(
+            NaiveDate::from_ymd_opt(2026, 10, d).unwrap(),
+        ))
+    };
+
+    let mut t = Task::new("Started", &HashMap::new(), None);
+    t.dtstart = day(10);
+    t.due = day(25);
+    // Started and not overdue: on today's agenda, but later by due date
+    assert_eq!(
+        AgendaGroup::of(&t, AgendaGrouping::Start, today, Weekday::Mon),
+        AgendaGroup::Today
+    );
+    assert_eq!(
+        AgendaGroup::of(&t, AgendaGrouping::Due, today, Weekday::Mon),
+        AgendaGroup::Later
+    );
+
+    t.dtstart = day(15);
+    assert_eq!(
+        AgendaGroup::of(&t, AgendaGrouping::Start, today, Weekday::Mon),
+        AgendaGroup::Tomorrow
+    );
+
+    t.dtstart = day(1);
+    t.due = day(12);
+    assert_eq!(
+        AgendaGroup::of(&t, AgendaGrouping::Start, today, Weekday::Mon),
+        AgendaGroup::Overdue
+    );
+
+    t.status = TaskStatus::Completed;
+    assert_eq!(
+        AgendaGroup::of(&t, AgendaGrouping::Start, today, Weekday::Mon),
+        AgendaGroup::Done
+    );
+}
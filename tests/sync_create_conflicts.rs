@@ -72,6 +72,8 @@ async fn test_create_500_persists() {
         .mock("PUT", task_path)
         .match_header("If-None-Match", "*")
         .with_status(500)
+        // A 5xx is transient: the step is retried before the sync gives up
+        .expect(cfait::client::sync::SYNC_MAX_RETRIES as usize + 1)
         .create_async()
         .await;
 
@@ -54,6 +54,8 @@ async fn test_sync_500_keeps_item_in_queue() {
     let mock = server
         .mock("PUT", "/cal/task.ics")
         .with_status(500)
+        // A 5xx is transient: the step is retried before the sync gives up
+        .expect(cfait::client::sync::SYNC_MAX_RETRIES as usize + 1)
         .create_async()
         .await;
 
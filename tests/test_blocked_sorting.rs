@@ -1,10 +1,10 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 //! Tests for blocked sorting functionality.
-use cfait::config::SortPreset;
+use cfait::config::{AllDaySort, SortMode, SortPreset};
 use cfait::context::TestContext;
 use cfait::model::{DateType, Task, TaskStatus};
 use cfait::store::{FilterOptions, TaskStore};
-use chrono::Utc;
+use chrono::{Utc, Weekday};
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
@@ -42,18 +42,29 @@ fn test_blocked_tasks_skip_urgent_rank() {
         match_all_categories: false,
         search_term: "",
         hide_completed_global: false,
+        hide_deferred: false,
         hide_fully_completed_tags: false,
         hide_aliases_in_sidebar: false,
         cutoff_date: None,
         min_duration: None,
         max_duration: None,
         include_unset_duration: true,
+        exact_priority: None,
+        require_unset_duration: false,
+        excluded_categories: &HashSet::new(),
+        excluded_locations: &HashSet::new(),
+        excluded_priorities: &HashSet::new(),
+        excluded_statuses: &HashSet::new(),
         urgent_days: 7,
         urgent_prio: 1,
         default_priority: 5,
         start_grace_period_days: 1,
         sort_standard_by_priority: false,
         sort_preset: SortPreset::default(),
+        sort_mode: SortMode::default(),
+        all_day_sort: AllDaySort::default(),
+        week_start: Weekday::Mon,
+        sort_urgency_boost: false,
         expanded_done_groups: &HashSet::new(),
         expanded_tags: &HashSet::new(),
         expanded_locations: &HashSet::new(),
@@ -63,6 +74,7 @@ fn test_blocked_tasks_skip_urgent_rank() {
         tag_aliases: &HashMap::new(),
         search_collapsed_tasks: &HashSet::new(),
         focused_task_uid: None,
+        fuzzy_search: false,
     };
 
     let filtered = store.filter(options).items;
@@ -128,18 +140,29 @@ fn test_blocked_tasks_skip_due_soon_rank() {
         match_all_categories: false,
         search_term: "",
         hide_completed_global: false,
+        hide_deferred: false,
         hide_fully_completed_tags: false,
         hide_aliases_in_sidebar: false,
         cutoff_date: None,
         min_duration: None,
         max_duration: None,
         include_unset_duration: true,
+        exact_priority: None,
+        require_unset_duration: false,
+        excluded_categories: &HashSet::new(),
+        excluded_locations: &HashSet::new(),
+        excluded_priorities: &HashSet::new(),
+        excluded_statuses: &HashSet::new(),
         urgent_days: 7,
         urgent_prio: 1,
         default_priority: 5,
         start_grace_period_days: 1,
         sort_standard_by_priority: false,
         sort_preset: SortPreset::default(),
+        sort_mode: SortMode::default(),
+        all_day_sort: AllDaySort::default(),
+        week_start: Weekday::Mon,
+        sort_urgency_boost: false,
         expanded_done_groups: &HashSet::new(),
         expanded_tags: &HashSet::new(),
         expanded_locations: &HashSet::new(),
@@ -149,6 +172,7 @@ fn test_blocked_tasks_skip_due_soon_rank() {
         tag_aliases: &HashMap::new(),
         search_collapsed_tasks: &HashSet::new(),
         focused_task_uid: None,
+        fuzzy_search: false,
     };
 
     let filtered = store.filter(options).items;
@@ -212,18 +236,29 @@ fn test_blocked_tasks_skip_started_rank() {
         match_all_categories: false,
         search_term: "",
         hide_completed_global: false,
+        hide_deferred: false,
         hide_fully_completed_tags: false,
         hide_aliases_in_sidebar: false,
         cutoff_date: None,
         min_duration: None,
         max_duration: None,
         include_unset_duration: true,
+        exact_priority: None,
+        require_unset_duration: false,
+        excluded_categories: &HashSet::new(),
+        excluded_locations: &HashSet::new(),
+        excluded_priorities: &HashSet::new(),
+        excluded_statuses: &HashSet::new(),
         urgent_days: 7,
         urgent_prio: 1,
         default_priority: 5,
         start_grace_period_days: 1,
         sort_standard_by_priority: false,
         sort_preset: SortPreset::default(),
+        sort_mode: SortMode::default(),
+        all_day_sort: AllDaySort::default(),
+        week_start: Weekday::Mon,
+        sort_urgency_boost: false,
         expanded_done_groups: &HashSet::new(),
         expanded_tags: &HashSet::new(),
         expanded_locations: &HashSet::new(),
@@ -233,6 +268,7 @@ fn test_blocked_tasks_skip_started_rank() {
         tag_aliases: &HashMap::new(),
         search_collapsed_tasks: &HashSet::new(),
         focused_task_uid: None,
+        fuzzy_search: false,
     };
 
     let filtered = store.filter(options).items;
@@ -297,18 +333,29 @@ fn test_dependency_blocked_tasks_also_skip_ranks() {
         match_all_categories: false,
         search_term: "",
         hide_completed_global: false,
+        hide_deferred: false,
         hide_fully_completed_tags: false,
         hide_aliases_in_sidebar: false,
         cutoff_date: None,
         min_duration: None,
         max_duration: None,
         include_unset_duration: true,
+        exact_priority: None,
+        require_unset_duration: false,
+        excluded_categories: &HashSet::new(),
+        excluded_locations: &HashSet::new(),
+        excluded_priorities: &HashSet::new(),
+        excluded_statuses: &HashSet::new(),
         urgent_days: 7,
         urgent_prio: 1,
         default_priority: 5,
         start_grace_period_days: 1,
         sort_standard_by_priority: false,
         sort_preset: SortPreset::default(),
+        sort_mode: SortMode::default(),
+        all_day_sort: AllDaySort::default(),
+        week_start: Weekday::Mon,
+        sort_urgency_boost: false,
         expanded_done_groups: &HashSet::new(),
         expanded_tags: &HashSet::new(),
         expanded_locations: &HashSet::new(),
@@ -318,6 +365,7 @@ fn test_dependency_blocked_tasks_also_skip_ranks() {
         tag_aliases: &HashMap::new(),
         search_collapsed_tasks: &HashSet::new(),
         focused_task_uid: None,
+        fuzzy_search: false,
     };
 
     let filtered = store.filter(options).items;
@@ -387,18 +435,29 @@ fn test_is_ready_filters_manually_blocked_tasks() {
         match_all_categories: false,
         search_term: "is:ready",
         hide_completed_global: true,
+        hide_deferred: false,
         hide_fully_completed_tags: false,
         hide_aliases_in_sidebar: false,
         cutoff_date: None,
         min_duration: None,
         max_duration: None,
         include_unset_duration: true,
+        exact_priority: None,
+        require_unset_duration: false,
+        excluded_categories: &HashSet::new(),
+        excluded_locations: &HashSet::new(),
+        excluded_priorities: &HashSet::new(),
+        excluded_statuses: &HashSet::new(),
         urgent_days: 7,
         urgent_prio: 5,
         default_priority: 5,
         start_grace_period_days: 1,
         sort_standard_by_priority: false,
         sort_preset: SortPreset::default(),
+        sort_mode: SortMode::default(),
+        all_day_sort: AllDaySort::default(),
+        week_start: Weekday::Mon,
+        sort_urgency_boost: false,
         expanded_done_groups: &HashSet::new(),
         expanded_tags: &HashSet::new(),
         expanded_locations: &HashSet::new(),
@@ -408,6 +467,7 @@ fn test_is_ready_filters_manually_blocked_tasks() {
         tag_aliases: &HashMap::new(),
         search_collapsed_tasks: &HashSet::new(),
         focused_task_uid: None,
+        fuzzy_search: false,
     };
 
     let filtered = store.filter(options).items;
@@ -463,18 +523,29 @@ fn test_is_blocked_filter_shows_only_blocked() {
         match_all_categories: false,
         search_term: "is:blocked",
         hide_completed_global: false,
+        hide_deferred: false,
         hide_fully_completed_tags: false,
         hide_aliases_in_sidebar: false,
         cutoff_date: None,
         min_duration: None,
         max_duration: None,
         include_unset_duration: true,
+        exact_priority: None,
+        require_unset_duration: false,
+        excluded_categories: &HashSet::new(),
+        excluded_locations: &HashSet::new(),
+        excluded_priorities: &HashSet::new(),
+        excluded_statuses: &HashSet::new(),
         urgent_days: 7,
         urgent_prio: 5,
         default_priority: 5,
         start_grace_period_days: 1,
         sort_standard_by_priority: false,
         sort_preset: SortPreset::default(),
+        sort_mode: SortMode::default(),
+        all_day_sort: AllDaySort::default(),
+        week_start: Weekday::Mon,
+        sort_urgency_boost: false,
         expanded_done_groups: &HashSet::new(),
         expanded_tags: &HashSet::new(),
         expanded_locations: &HashSet::new(),
@@ -484,6 +555,7 @@ fn test_is_blocked_filter_shows_only_blocked() {
         tag_aliases: &HashMap::new(),
         search_collapsed_tasks: &HashSet::new(),
         focused_task_uid: None,
+        fuzzy_search: false,
     };
 
     let filtered = store.filter(options).items;
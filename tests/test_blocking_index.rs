@@ -73,7 +73,7 @@ fn test_blocking_index_basic() {
     // Add dependency D -> A (D depends on A => A blocks D)
     let added = store.add_dependency("D", "A".to_string());
     assert!(
-        added.is_some(),
+        matches!(added, Ok(Some(_))),
         "add_dependency should return Some when adding new dependency"
     );
 
@@ -103,3 +103,72 @@ fn test_blocking_index_basic() {
     assert!(final_uids.contains("C"), "C should still be blocked by A");
     assert!(final_uids.contains("D"), "D should still be blocked by A");
 }
+
+fn chain_store(uids: &[&str]) -> TaskStore {
+    let mut store = TaskStore::new(Arc::new(TestContext::new()));
+    for uid in uids {
+        let mut t = Task::new(uid, &HashMap::new(), None);
+        t.uid = uid.to_string();
+        t.calendar_href = "cal1".to_string();
+        store.add_task(t);
+    }
+    store
+}
+
+#[test]
+fn test_add_dependency_rejects_cycles() {
+    // 2 nodes: A depends on B, so B cannot depend on A
+    let mut store = chain_store(&["A", "B"]);
+    assert!(matches!(
+        store.add_dependency("A", "B".to_string()),
+        Ok(Some(_))
+    ));
+    assert!(store.add_dependency("B", "A".to_string()).is_err());
+    assert!(store.add_dependency("A", "A".to_string()).is_err());
+
+    // 3 nodes: A -> B -> C, so C cannot depend on A
+    let mut store = chain_store(&["A", "B", "C"]);
+    assert!(store.add_dependency("A", "B".to_string()).is_ok());
+    assert!(store.add_dependency("B", "C".to_string()).is_ok());
+    assert!(store.would_create_dependency_cycle("C", "A"));
+    assert!(store.add_dependency("C", "A".to_string()).is_err());
+    assert!(
+        store.get_task_ref("C").unwrap().dependencies.is_empty(),
+        "rejected edge must not be stored"
+    );
+    // A parallel edge that closes no loop is still fine
+    assert!(matches!(
+        store.add_dependency("A", "C".to_string()),
+        Ok(Some(_))
+    ));
+}
+
+#[test]
+fn test_set_parent_rejects_cycles() {
+    // 2 nodes: B is a child of A, so A cannot become B's child
+    let mut store = chain_store(&["A", "B"]);
+    assert!(store.set_parent("B", Some("A".to_string())).is_ok());
+    assert!(store.set_parent("A", Some("B".to_string())).is_err());
+    assert!(store.set_parent("A", Some("A".to_string())).is_err());
+
+    // 3 nodes: C under B under A, so A cannot become C's child
+    let mut store = chain_store(&["A", "B", "C"]);
+    assert!(store.set_parent("B", Some("A".to_string())).is_ok());
+    assert!(store.set_parent("C", Some("B".to_string())).is_ok());
+    assert!(store.set_parent("A", Some("C".to_string())).is_err());
+    assert_eq!(store.get_task_ref("A").unwrap().parent_uid, None);
+}
+
+#[test]
+fn test_open_blocked_count_skips_done_dependents() {
+    let mut store = chain_store(&["A", "B", "C"]);
+    assert!(store.add_dependency("B", "A".to_string()).is_ok());
+    assert!(store.add_dependency("C", "A".to_string()).is_ok());
+    assert_eq!(store.open_blocked_count("A"), 2);
+    assert_eq!(store.open_blocked_count("B"), 0);
+
+    let mut c = store.get_task_ref("C").unwrap().clone();
+    c.status = cfait::model::TaskStatus::Completed;
+    store.update_or_add_task(c);
+    assert_eq!(store.open_blocked_count("A"), 1);
+}
@@ -1,10 +1,10 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 //! Tests for newer filter features (relative dates, etc.).
-use cfait::config::SortPreset;
+use cfait::config::{AllDaySort, SortMode, SortPreset};
 use cfait::context::TestContext;
 use cfait::model::{Task, TaskStatus};
 use cfait::store::{FilterOptions, TaskStore};
-use chrono::{Datelike, Duration, Local};
+use chrono::{Datelike, Duration, Local, Weekday};
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
@@ -161,18 +161,29 @@ fn test_is_ready_filters_future_start_dates() {
         match_all_categories: false,
         search_term: "is:ready",
         hide_completed_global: true,
+        hide_deferred: false,
         hide_fully_completed_tags: false,
         hide_aliases_in_sidebar: false,
         cutoff_date: None,
         min_duration: None,
         max_duration: None,
         include_unset_duration: true,
+        exact_priority: None,
+        require_unset_duration: false,
+        excluded_categories: &HashSet::new(),
+        excluded_locations: &HashSet::new(),
+        excluded_priorities: &HashSet::new(),
+        excluded_statuses: &HashSet::new(),
         urgent_days: 7,
         urgent_prio: 5,
         default_priority: 5,
         start_grace_period_days: 1,
         sort_standard_by_priority: false,
         sort_preset: SortPreset::default(),
+        sort_mode: SortMode::default(),
+        all_day_sort: AllDaySort::default(),
+        week_start: Weekday::Mon,
+        sort_urgency_boost: false,
         expanded_done_groups: &HashSet::new(),
         expanded_tags: &HashSet::new(),
         expanded_locations: &HashSet::new(),
@@ -182,6 +193,7 @@ fn test_is_ready_filters_future_start_dates() {
         tag_aliases: &HashMap::new(),
         search_collapsed_tasks: &HashSet::new(),
         focused_task_uid: None,
+        fuzzy_search: false,
     };
 
     let filtered = store.filter(options).items;
@@ -246,18 +258,29 @@ fn test_is_ready_filters_blocked_tasks() {
         match_all_categories: false,
         search_term: "is:ready",
         hide_completed_global: true,
+        hide_deferred: false,
         hide_fully_completed_tags: false,
         hide_aliases_in_sidebar: false,
         cutoff_date: None,
         min_duration: None,
         max_duration: None,
         include_unset_duration: true,
+        exact_priority: None,
+        require_unset_duration: false,
+        excluded_categories: &HashSet::new(),
+        excluded_locations: &HashSet::new(),
+        excluded_priorities: &HashSet::new(),
+        excluded_statuses: &HashSet::new(),
         urgent_days: 7,
         urgent_prio: 5,
         default_priority: 5,
         start_grace_period_days: 1,
         sort_standard_by_priority: false,
         sort_preset: SortPreset::default(),
+        sort_mode: SortMode::default(),
+        all_day_sort: AllDaySort::default(),
+        week_start: Weekday::Mon,
+        sort_urgency_boost: false,
         expanded_done_groups: &HashSet::new(),
         expanded_tags: &HashSet::new(),
         expanded_locations: &HashSet::new(),
@@ -267,6 +290,7 @@ fn test_is_ready_filters_blocked_tasks() {
         tag_aliases: &HashMap::new(),
         search_collapsed_tasks: &HashSet::new(),
         focused_task_uid: None,
+        fuzzy_search: false,
     };
 
     let filtered = store.filter(options).items;
@@ -337,18 +361,29 @@ fn test_is_ready_combines_with_other_filters() {
         match_all_categories: false,
         search_term: "is:ready #work",
         hide_completed_global: true,
+        hide_deferred: false,
         hide_fully_completed_tags: false,
         hide_aliases_in_sidebar: false,
         cutoff_date: None,
         min_duration: None,
         max_duration: None,
         include_unset_duration: true,
+        exact_priority: None,
+        require_unset_duration: false,
+        excluded_categories: &HashSet::new(),
+        excluded_locations: &HashSet::new(),
+        excluded_priorities: &HashSet::new(),
+        excluded_statuses: &HashSet::new(),
         urgent_days: 7,
         urgent_prio: 5,
         default_priority: 5,
         start_grace_period_days: 1,
         sort_standard_by_priority: false,
         sort_preset: SortPreset::default(),
+        sort_mode: SortMode::default(),
+        all_day_sort: AllDaySort::default(),
+        week_start: Weekday::Mon,
+        sort_urgency_boost: false,
         expanded_done_groups: &HashSet::new(),
         expanded_tags: &HashSet::new(),
         expanded_locations: &HashSet::new(),
@@ -358,6 +393,7 @@ fn test_is_ready_combines_with_other_filters() {
         tag_aliases: &HashMap::new(),
         search_collapsed_tasks: &HashSet::new(),
         focused_task_uid: None,
+        fuzzy_search: false,
     };
 
     let filtered = store.filter(options).items;
@@ -416,17 +452,28 @@ fn test_is_ready_filters_implicitly_future_tasks() {
         match_all_categories: false,
         search_term: "is:ready",
         hide_completed_global: true,
+        hide_deferred: false,
         hide_fully_completed_tags: false,
         hide_aliases_in_sidebar: false,
         cutoff_date: None,
         min_duration: None,
         max_duration: None,
         include_unset_duration: true,
+        exact_priority: None,
+        require_unset_duration: false,
+        excluded_categories: &HashSet::new(),
+        excluded_locations: &HashSet::new(),
+        excluded_priorities: &HashSet::new(),
+        excluded_statuses: &HashSet::new(),
         urgent_days: 7,
         urgent_prio: 5,
         default_priority: 5,
         sort_standard_by_priority: false,
         sort_preset: SortPreset::default(),
+        sort_mode: SortMode::default(),
+        all_day_sort: AllDaySort::default(),
+        week_start: Weekday::Mon,
+        sort_urgency_boost: false,
         start_grace_period_days: 1,
         expanded_done_groups: &HashSet::new(),
         expanded_tags: &HashSet::new(),
@@ -436,6 +483,7 @@ fn test_is_ready_filters_implicitly_future_tasks() {
         tag_aliases: &HashMap::new(),
         search_collapsed_tasks: &HashSet::new(),
         focused_task_uid: None,
+        fuzzy_search: false,
     };
 
     let filtered = store.filter(options).items;
@@ -477,17 +525,28 @@ fn test_is_ready_filters_implicitly_future_tasks() {
         match_all_categories: false,
         search_term: "",
         hide_completed_global: true,
+        hide_deferred: false,
         hide_fully_completed_tags: false,
         hide_aliases_in_sidebar: false,
         cutoff_date: None,
         min_duration: None,
         max_duration: None,
         include_unset_duration: true,
+        exact_priority: None,
+        require_unset_duration: false,
+        excluded_categories: &HashSet::new(),
+        excluded_locations: &HashSet::new(),
+        excluded_priorities: &HashSet::new(),
+        excluded_statuses: &HashSet::new(),
         urgent_days: 7,
         urgent_prio: 5,
         default_priority: 5,
         sort_standard_by_priority: false,
         sort_preset: SortPreset::default(),
+        sort_mode: SortMode::default(),
+        all_day_sort: AllDaySort::default(),
+        week_start: Weekday::Mon,
+        sort_urgency_boost: false,
         start_grace_period_days: 1,
         expanded_done_groups: &HashSet::new(),
         expanded_tags: &HashSet::new(),
@@ -497,6 +556,7 @@ fn test_is_ready_filters_implicitly_future_tasks() {
         tag_aliases: &HashMap::new(),
         search_collapsed_tasks: &HashSet::new(),
         focused_task_uid: None,
+        fuzzy_search: false,
     };
 
     let all_tasks = store.filter(options_all).items;
@@ -585,3 +645,657 @@ fn test_random_selector_skips_implicitly_future_tasks() {
         "Should have selected ready task every time"
     );
 }
+
+#[test]
+fn test_exact_priority_and_unset_duration_tokens() {
+    let aliases = HashMap::new();
+    let t1 = Task::new("Prio three ~30m !3", &aliases, None);
+    let t2 = Task::new("Prio two !2", &aliases, None);
+
+    assert!(t1.matches_search_term("!=3"));
+    assert!(!t2.matches_search_term("!=3"));
+
+    assert!(!t1.matches_search_term("~none"));
+    assert!(t2.matches_search_term("~none"));
+    assert!(t1.matches_search_term("-~none"));
+
+    use cfait::model::matcher::extract_filter_shortcuts;
+    assert_eq!(extract_filter_shortcuts("!=3 ~none foo"), (Some(3), true));
+    assert_eq!(extract_filter_shortcuts("-!=3 -~none"), (None, false));
+    assert_eq!(extract_filter_shortcuts("!=3 | ~none"), (None, false));
+}
+
+#[test]
+fn test_filter_options_exact_priority_and_require_unset_duration() {
+    let ctx = Arc::new(TestContext::new());
+    let mut store = TaskStore::new(ctx.clone());
+    let aliases = HashMap::new();
+
+    for input in ["A !3 ~1h", "B !3", "C !1", "D"] {
+        let mut t = Task::new(input, &aliases, None);
+        t.calendar_href = "cal1".to_string();
+        store.add_task(t);
+    }
+
+    let empty = HashSet::new();
+    let no_aliases = HashMap::new();
+    let run = |exact_priority: Option<u8>, require_unset_duration: bool| -> Vec<String> {
+        let mut names: Vec<String> = store
+            .filter(FilterOptions {
+                active_cal_href: None,
+                hidden_calendars: &empty,
+                selected_categories: &empty,
+                selected_locations: &empty,
+                match_all_categories: false,
+                search_term: "",
+                hide_completed_global: true,
+                hide_deferred: false,
+                hide_fully_completed_tags: false,
+                hide_aliases_in_sidebar: false,
+                cutoff_date: None,
+                // An active range must not override the unset-duration requirement
+                min_duration: Some(30),
+                max_duration: Some(120),
+                include_unset_duration: true,
+                exact_priority,
+                require_unset_duration,
+                excluded_categories: &HashSet::new(),
+                excluded_locations: &HashSet::new(),
+                excluded_priorities: &HashSet::new(),
+                excluded_statuses: &HashSet::new(),
+                urgent_days: 7,
+                urgent_prio: 1,
+                default_priority: 5,
+                start_grace_period_days: 1,
+                sort_standard_by_priority: false,
+                sort_preset: SortPreset::default(),
+                sort_mode: SortMode::default(),
+                all_day_sort: AllDaySort::default(),
+                week_start: Weekday::Mon,
+                sort_urgency_boost: false,
+                expanded_done_groups: &empty,
+                expanded_tags: &empty,
+                expanded_locations: &empty,
+                max_done_roots: usize::MAX,
+                max_done_subtasks: usize::MAX,
+                tag_aliases: &no_aliases,
+                search_collapsed_tasks: &empty,
+                focused_task_uid: None,
+                fuzzy_search: false,
+            })
+            .items
+            .into_iter()
+            .filter_map(|item| match item {
+                cfait::store::TaskListItem::Task(t) => Some(t.summary.clone()),
+                _ => None,
+            })
+            .collect();
+        names.sort();
+        names
+    };
+
+    assert_eq!(run(Some(3), false), vec!["A", "B"]);
+    assert_eq!(run(None, true), vec!["B", "C", "D"]);
+    assert_eq!(run(Some(3), true), vec!["B"]);
+}
+
+#[test]
+fn test_calendar_month_and_year_offsets() {
+    use cfait::model::parser::{LexiconUnit, add_calendar_offset};
+    use chrono::NaiveDate;
+
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+
+    // Month arithmetic clamps to the end of shorter months
+    assert_eq!(
+        add_calendar_offset(d(2025, 1, 31), 1, LexiconUnit::Months),
+        Some(d(2025, 2, 28))
+    );
+    assert_eq!(
+        add_calendar_offset(d(2024, 1, 31), 1, LexiconUnit::Months),
+        Some(d(2024, 2, 29))
+    );
+    assert_eq!(
+        add_calendar_offset(d(2025, 1, 31), 2, LexiconUnit::Months),
+        Some(d(2025, 3, 31))
+    );
+    assert_eq!(
+        add_calendar_offset(d(2025, 11, 15), 3, LexiconUnit::Months),
+        Some(d(2026, 2, 15))
+    );
+
+    // Year arithmetic handles Feb 29
+    assert_eq!(
+        add_calendar_offset(d(2024, 2, 29), 1, LexiconUnit::Years),
+        Some(d(2025, 2, 28))
+    );
+    assert_eq!(
+        add_calendar_offset(d(2024, 2, 29), 4, LexiconUnit::Years),
+        Some(d(2028, 2, 29))
+    );
+    assert_eq!(
+        add_calendar_offset(d(2023, 3, 1), 1, LexiconUnit::Years),
+        Some(d(2024, 3, 1))
+    );
+
+    // Days and weeks are unchanged; sub-day units are not date offsets
+    assert_eq!(
+        add_calendar_offset(d(2025, 2, 27), 2, LexiconUnit::Days),
+        Some(d(2025, 3, 1))
+    );
+    assert_eq!(
+        add_calendar_offset(d(2025, 12, 29), 1, LexiconUnit::Weeks),
+        Some(d(2026, 1, 5))
+    );
+    assert_eq!(
+        add_calendar_offset(d(2025, 1, 1), 3, LexiconUnit::Hours),
+        None
+    );
+}
+
+#[test]
+fn test_relative_month_dates_use_calendar_months() {
+    use cfait::model::parser::{parse_duration, parse_in_date, parse_smart_date};
+    use chrono::Months;
+
+    let today = Local::now().date_naive();
+    let in_two_months = today.checked_add_months(Months::new(2)).unwrap();
+    let in_one_year = today.checked_add_months(Months::new(12)).unwrap();
+
+    assert_eq!(parse_in_date(2, "months"), Some(in_two_months));
+    assert_eq!(parse_in_date(1, "y"), Some(in_one_year));
+    assert_eq!(
+        parse_smart_date("2mo", Weekday::Mon).map(|d| d.to_date_naive()),
+        Some(in_two_months)
+    );
+    assert_eq!(
+        parse_smart_date("1y", Weekday::Mon).map(|d| d.to_date_naive()),
+        Some(in_one_year)
+    );
+
+    let t = Task::new("Renew @in 2 months", &HashMap::new(), None);
+    assert_eq!(t.due.map(|d| d.to_date_naive()), Some(in_two_months));
+
+    // Durations remain fixed spans
+    assert_eq!(parse_duration("1mo"), Some(30 * 24 * 60));
+    assert_eq!(parse_duration("1y"), Some(365 * 24 * 60));
+}
+
+#[test]
+fn test_negated_tokens_subtract_from_visible_set() {
+    use cfait::model::matcher::{StatusFilter, extract_filter_exclusions};
+
+    let ex = extract_filter_exclusions("#home -#Work -@@home -!1 -is:done plain");
+    assert!(ex.categories.contains("work"));
+    assert!(ex.locations.contains("home"));
+    assert!(ex.priorities.contains(&1));
+    assert!(ex.statuses.contains(&StatusFilter::Done));
+    assert_eq!(
+        extract_filter_exclusions("-#work | #home"),
+        Default::default()
+    );
+
+    let ctx = Arc::new(TestContext::new());
+    let mut store = TaskStore::new(ctx.clone());
+    let aliases = HashMap::new();
+
+    for (input, status, blocked) in [
+        ("Work #work !1", TaskStatus::NeedsAction, false),
+        (
+            "Meeting #work:meetings @@office !3",
+            TaskStatus::NeedsAction,
+            false,
+        ),
+        ("Chore #home @@home !2", TaskStatus::NeedsAction, false),
+        ("Started #home", TaskStatus::InProcess, false),
+        ("Finished #work", TaskStatus::Completed, false),
+        ("Waiting #home", TaskStatus::NeedsAction, true),
+    ] {
+        let mut t = Task::new(input, &aliases, None);
+        t.calendar_href = "cal1".to_string();
+        t.status = status;
+        t.manual_block = blocked;
+        store.add_task(t);
+    }
+
+    let empty = HashSet::new();
+    let no_aliases = HashMap::new();
+    let run = |query: &str| -> Vec<String> {
+        let ex = extract_filter_exclusions(query);
+        let mut names: Vec<String> = store
+            .filter(FilterOptions {
+                active_cal_href: None,
+                hidden_calendars: &empty,
+                selected_categories: &empty,
+                selected_locations: &empty,
+                match_all_categories: false,
+                search_term: query,
+                hide_completed_global: true,
+                hide_deferred: false,
+                hide_fully_completed_tags: false,
+                hide_aliases_in_sidebar: false,
+                cutoff_date: None,
+                min_duration: None,
+                max_duration: None,
+                include_unset_duration: true,
+                exact_priority: None,
+                require_unset_duration: false,
+                excluded_categories: &ex.categories,
+                excluded_locations: &ex.locations,
+                excluded_priorities: &ex.priorities,
+                excluded_statuses: &ex.statuses,
+                urgent_days: 7,
+                urgent_prio: 1,
+                default_priority: 5,
+                start_grace_period_days: 1,
+                sort_standard_by_priority: false,
+                sort_preset: SortPreset::default(),
+                sort_mode: SortMode::default(),
+                all_day_sort: AllDaySort::default(),
+                week_start: Weekday::Mon,
+                sort_urgency_boost: false,
+                expanded_done_groups: &empty,
+                expanded_tags: &empty,
+                expanded_locations: &empty,
+                max_done_roots: usize::MAX,
+                max_done_subtasks: usize::MAX,
+                tag_aliases: &no_aliases,
+                search_collapsed_tasks: &empty,
+                focused_task_uid: None,
+                fuzzy_search: false,
+            })
+            .items
+            .into_iter()
+            .filter_map(|item| match item {
+                cfait::store::TaskListItem::Task(t) => Some(t.summary.clone()),
+                _ => None,
+            })
+            .collect();
+        names.sort();
+        names
+    };
+
+    // Positive tag combined with a negated location
+    assert_eq!(run("#home -@@home"), vec!["Started", "Waiting"]);
+    // Negated tag hides its children too, negated priority stacks on top
+    assert_eq!(run("-#work -!2"), vec!["Started", "Waiting"]);
+    // Negating a status never reveals completed tasks hidden by hide_completed
+    assert_eq!(
+        run("-is:started"),
+        vec!["Chore", "Meeting", "Waiting", "Work"]
+    );
+    // Ready/blocked are computed by the store, so their negation is too
+    assert_eq!(run("-is:ready"), vec!["Waiting"]);
+    assert_eq!(run("#home -is:blocked"), vec!["Chore", "Started"]);
+}
+
+#[test]
+fn test_week_start_groups_sunday_into_correct_week() {
+    use cfait::model::parser::{end_of_week, start_of_week};
+    use chrono::NaiveDate;
+
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    // Sunday 2025-03-16
+    let aliases = HashMap::new();
+    let task = Task::new("Review @2025-03-16", &aliases, None);
+    let due = task.due.unwrap().to_date_naive();
+    assert_eq!(due.weekday(), Weekday::Sun);
+
+    // Monday weeks: Sunday closes the week that started on the 10th
+    assert_eq!(start_of_week(due, Weekday::Mon), d(2025, 3, 10));
+    assert_eq!(end_of_week(due, Weekday::Mon), due);
+
+    // Sunday weeks: Sunday opens the week running to Saturday the 22nd
+    assert_eq!(start_of_week(due, Weekday::Sun), due);
+    assert_eq!(end_of_week(due, Weekday::Sun), d(2025, 3, 22));
+
+    // A Saturday falls in the same week as the Sunday only when weeks start on Monday
+    let saturday = d(2025, 3, 15);
+    assert_eq!(
+        end_of_week(saturday, Weekday::Mon),
+        end_of_week(due, Weekday::Mon)
+    );
+    assert_ne!(
+        end_of_week(saturday, Weekday::Sun),
+        end_of_week(due, Weekday::Sun)
+    );
+
+    assert_eq!(cfait::config::Config::default().week_start, Weekday::Mon);
+}
+
+#[test]
+fn test_is_focus_keeps_only_ongoing_paused_and_urgent() {
+    let ctx = Arc::new(TestContext::new());
+    let mut store = TaskStore::new(ctx.clone());
+    let aliases = HashMap::new();
+    let now = chrono::Utc::now().date_naive();
+
+    let mut ongoing = Task::new("Ongoing", &aliases, None);
+    ongoing.status = TaskStatus::InProcess;
+    let mut paused = Task::new("Paused", &aliases, None);
+    paused.percent_complete = Some(40);
+    let urgent = Task::new("Urgent !1", &aliases, None);
+    let due_soon = Task::new(
+        &format!("Due soon @{}", (now + Duration::days(2)).format("%Y-%m-%d")),
+        &aliases,
+        None,
+    );
+    let future_urgent = Task::new(
+        &format!(
+            "Future urgent !1 ^{}",
+            (now + Duration::days(5)).format("%Y-%m-%d")
+        ),
+        &aliases,
+        None,
+    );
+    let plain = Task::new("Plain !5", &aliases, None);
+    let mut done_urgent = Task::new("Done urgent !1", &aliases, None);
+    done_urgent.status = TaskStatus::Completed;
+
+    for mut t in [
+        ongoing,
+        paused,
+        urgent,
+        due_soon,
+        future_urgent,
+        plain,
+        done_urgent,
+    ] {
+        t.calendar_href = "cal1".to_string();
+        store.add_task(t);
+    }
+
+    let options = FilterOptions {
+        active_cal_href: None,
+        hidden_calendars: &HashSet::new(),
+        selected_categories: &HashSet::new(),
+        selected_locations: &HashSet::new(),
+        match_all_categories: false,
+        search_term: "is:focus",
+        hide_completed_global: false,
+        hide_deferred: false,
+        hide_fully_completed_tags: false,
+        hide_aliases_in_sidebar: false,
+        cutoff_date: None,
+        min_duration: None,
+        max_duration: None,
+        include_unset_duration: true,
+        exact_priority: None,
+        require_unset_duration: false,
+        excluded_categories: &HashSet::new(),
+        excluded_locations: &HashSet::new(),
+        excluded_priorities: &HashSet::new(),
+        excluded_statuses: &HashSet::new(),
+        urgent_days: 3,
+        urgent_prio: 1,
+        default_priority: 5,
+        start_grace_period_days: 1,
+        sort_standard_by_priority: false,
+        sort_preset: SortPreset::default(),
+        sort_mode: SortMode::default(),
+        all_day_sort: AllDaySort::default(),
+        week_start: Weekday::Mon,
+        sort_urgency_boost: false,
+        expanded_done_groups: &HashSet::new(),
+        expanded_tags: &HashSet::new(),
+        expanded_locations: &HashSet::new(),
+        max_done_roots: usize::MAX,
+        max_done_subtasks: usize::MAX,
+        tag_aliases: &HashMap::new(),
+        search_collapsed_tasks: &HashSet::new(),
+        focused_task_uid: None,
+        fuzzy_search: false,
+    };
+
+    let mut visible: Vec<String> = store
+        .filter(options)
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            cfait::store::TaskListItem::Task(t) => Some(t.summary.clone()),
+            _ => None,
+        })
+        .collect();
+    visible.sort();
+    assert_eq!(visible, vec!["Due soon", "Ongoing", "Paused", "Urgent"]);
+
+    let mut focus: Vec<&str> = store
+        .focus_tasks(3, 1)
+        .iter()
+        .map(|t| t.summary.as_str())
+        .collect();
+    focus.sort();
+    assert_eq!(focus, vec!["Due soon", "Ongoing", "Paused", "Urgent"]);
+
+    assert_eq!(
+        cfait::model::matcher::toggle_search_token("#work", "is:focus"),
+        "is:focus #work"
+    );
+    assert_eq!(
+        cfait::model::matcher::toggle_search_token("is:focus #work", "is:focus"),
+        "#work"
+    );
+}
+
+#[test]
+fn test_is_inbox_matches_untriaged_tasks_and_counts_live() {
+    let ctx = Arc::new(TestContext::new());
+    let mut store = TaskStore::new(ctx.clone());
+    let aliases = HashMap::new();
+
+    let loose = Task::new("Loose idea", &aliases, None);
+    let tagged = Task::new("Tagged #home", &aliases, None);
+    let dated = Task::new("Dated @tomorrow", &aliases, None);
+    let mut child = Task::new("Child", &aliases, None);
+    child.parent_uid = Some(loose.uid.clone());
+    let mut done = Task::new("Done", &aliases, None);
+    done.status = TaskStatus::Completed;
+    let loose_uid = loose.uid.clone();
+
+    for mut t in [loose, tagged, dated, child, done] {
+        t.calendar_href = "cal1".to_string();
+        store.add_task(t);
+    }
+
+    let run = |store: &TaskStore| {
+        let options = FilterOptions {
+            active_cal_href: None,
+            hidden_calendars: &HashSet::new(),
+            selected_categories: &HashSet::new(),
+            selected_locations: &HashSet::new(),
+            match_all_categories: false,
+            search_term: "is:inbox",
+            hide_completed_global: false,
+            hide_deferred: false,
+            hide_fully_completed_tags: false,
+            hide_aliases_in_sidebar: false,
+            cutoff_date: None,
+            min_duration: None,
+            max_duration: None,
+            include_unset_duration: true,
+            exact_priority: None,
+            require_unset_duration: false,
+            excluded_categories: &HashSet::new(),
+            excluded_locations: &HashSet::new(),
+            excluded_priorities: &HashSet::new(),
+            excluded_statuses: &HashSet::new(),
+            urgent_days: 3,
+            urgent_prio: 1,
+            default_priority: 5,
+            start_grace_period_days: 1,
+            sort_standard_by_priority: false,
+            sort_preset: SortPreset::default(),
+            sort_mode: SortMode::default(),
+            all_day_sort: AllDaySort::default(),
+            week_start: Weekday::Mon,
+            sort_urgency_boost: false,
+            expanded_done_groups: &HashSet::new(),
+            expanded_tags: &HashSet::new(),
+            expanded_locations: &HashSet::new(),
+            max_done_roots: usize::MAX,
+            max_done_subtasks: usize::MAX,
+            tag_aliases: &HashMap::new(),
+            search_collapsed_tasks: &HashSet::new(),
+            focused_task_uid: None,
+            fuzzy_search: false,
+        };
+        let res = store.filter(options);
+        let visible: Vec<String> = res
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                cfait::store::TaskListItem::Task(t) => Some(t.summary.clone()),
+                _ => None,
+            })
+            .collect();
+        (visible, res.inbox_count)
+    };
+
+    let (visible, count) = run(&store);
+    assert_eq!(visible, vec!["Loose idea"]);
+    assert_eq!(count, 1);
+
+    // Tagging the task takes it out of the inbox immediately.
+    let mut triaged = store.get_task_ref(&loose_uid).unwrap().clone();
+    triaged.categories.push("work".to_string());
+    store.update_or_add_task(triaged);
+    let (visible, count) = run(&store);
+    assert!(visible.is_empty());
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn test_fuzzy_search_matches_subsequences_and_ranks() {
+    let ctx = Arc::new(TestContext::new());
+    let mut store = TaskStore::new(ctx.clone());
+    let aliases = HashMap::new();
+
+    for title in ["Book a mailing kit", "Buy milk #work", "Call mom"] {
+        let mut t = Task::new(title, &aliases, None);
+        t.calendar_href = "cal1".to_string();
+        store.add_task(t);
+    }
+
+    let run = |store: &TaskStore, term: &str, fuzzy: bool| -> Vec<String> {
+        let options = FilterOptions {
+            active_cal_href: None,
+            hidden_calendars: &HashSet::new(),
+            selected_categories: &HashSet::new(),
+            selected_locations: &HashSet::new(),
+            match_all_categories: false,
+            search_term: term,
+            hide_completed_global: false,
+            hide_deferred: false,
+            hide_fully_completed_tags: false,
+            hide_aliases_in_sidebar: false,
+            cutoff_date: None,
+            min_duration: None,
+            max_duration: None,
+            include_unset_duration: true,
+            exact_priority: None,
+            require_unset_duration: false,
+            excluded_categories: &HashSet::new(),
+            excluded_locations: &HashSet::new(),
+            excluded_priorities: &HashSet::new(),
+            excluded_statuses: &HashSet::new(),
+            urgent_days: 1,
+            urgent_prio: 1,
+            default_priority: 5,
+            start_grace_period_days: 1,
+            sort_standard_by_priority: false,
+            sort_preset: SortPreset::default(),
+            sort_mode: SortMode::default(),
+            all_day_sort: AllDaySort::default(),
+            week_start: Weekday::Mon,
+            sort_urgency_boost: false,
+            expanded_done_groups: &HashSet::new(),
+            expanded_tags: &HashSet::new(),
+            expanded_locations: &HashSet::new(),
+            max_done_roots: usize::MAX,
+            max_done_subtasks: usize::MAX,
+            tag_aliases: &HashMap::new(),
+            search_collapsed_tasks: &HashSet::new(),
+            focused_task_uid: None,
+            fuzzy_search: fuzzy,
+        };
+        store
+            .filter(options)
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                cfait::store::TaskListItem::Task(t) => Some(t.summary.clone()),
+                _ => None,
+            })
+            .collect()
+    };
+
+    // Substring search stays the default
+    assert!(run(&store, "bmlk", false).is_empty());
+
+    // Fuzzy: subsequence match, best score first
+    assert_eq!(
+        run(&store, "bmlk", true),
+        vec!["Buy milk", "Book a mailing kit"]
+    );
+
+    // Tag tokens stay exact in fuzzy mode
+    assert!(run(&store, "#wrk", true).is_empty());
+    assert_eq!(run(&store, "#work", true), vec!["Buy milk"]);
+
+    assert!(cfait::model::matcher::fuzzy_score("buy milk", "bmlk").is_some());
+    assert!(cfait::model::matcher::fuzzy_score("buy milk", "klm").is_none());
+}
+
+#[test]
+fn test_cal_token_restricts_to_named_calendar() {
+    use cfait::model::SessionState;
+    use cfait::model::matcher::extract_calendar_filter;
+
+    assert_eq!(
+        extract_calendar_filter("cal:Work #urgent"),
+        Some("Work".to_string())
+    );
+    assert_eq!(
+        extract_calendar_filter("#x CAL:\"Shared Family\""),
+        Some("Shared Family".to_string())
+    );
+    assert_eq!(extract_calendar_filter("-cal:Work"), None);
+    assert_eq!(extract_calendar_filter("cal:Work | #home"), None);
+    assert_eq!(extract_calendar_filter("calendar review"), None);
+
+    let ctx = Arc::new(TestContext::new());
+    let mut store = TaskStore::new(ctx.clone());
+    let aliases = HashMap::new();
+    for (summary, href) in [
+        ("Report #urgent", "cal-work"),
+        ("Slides", "cal-work"),
+        ("Groceries #urgent", "cal-family"),
+    ] {
+        let mut t = Task::new(summary, &aliases, None);
+        t.calendar_href = href.to_string();
+        store.add_task(t);
+    }
+
+    let session = SessionState {
+        search_term: "cal:Work #urgent".to_string(),
+        ..Default::default()
+    };
+    let config = cfait::config::Config::default();
+    let summaries = |href: Option<&str>| -> Vec<String> {
+        session
+            .get_filtered_view_in(&store, &config, href)
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                cfait::store::TaskListItem::Task(t) => Some(t.summary.clone()),
+                _ => None,
+            })
+            .collect()
+    };
+
+    // The token itself is neutral; the resolved href does the narrowing
+    assert_eq!(summaries(Some("cal-work")), vec!["Report".to_string()]);
+    assert_eq!(summaries(None).len(), 2);
+    // Unknown names resolve to nothing
+    assert!(summaries(Some("")).is_empty());
+}
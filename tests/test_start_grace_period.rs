@@ -1,10 +1,10 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 //! Tests for start grace period functionality.
-use cfait::config::SortPreset;
+use cfait::config::{AllDaySort, SortMode, SortPreset};
 use cfait::context::TestContext;
 use cfait::model::{Alarm, DateType, Task};
 use cfait::store::{FilterOptions, TaskStore};
-use chrono::{Duration, Utc};
+use chrono::{Duration, Utc, Weekday};
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
@@ -44,18 +44,29 @@ fn test_start_grace_period_keeps_tasks_in_active_section() {
         match_all_categories: false,
         search_term: "",
         hide_completed_global: false,
+        hide_deferred: false,
         hide_fully_completed_tags: false,
         hide_aliases_in_sidebar: false,
         cutoff_date: None,
         min_duration: None,
         max_duration: None,
         include_unset_duration: true,
+        exact_priority: None,
+        require_unset_duration: false,
+        excluded_categories: &HashSet::new(),
+        excluded_locations: &HashSet::new(),
+        excluded_priorities: &HashSet::new(),
+        excluded_statuses: &HashSet::new(),
         urgent_days: 1,
         urgent_prio: 1,
         default_priority: 5,
         start_grace_period_days: 2, // 2-day grace period
         sort_standard_by_priority: false,
         sort_preset: SortPreset::default(),
+        sort_mode: SortMode::default(),
+        all_day_sort: AllDaySort::default(),
+        week_start: Weekday::Mon,
+        sort_urgency_boost: false,
         expanded_done_groups: &HashSet::new(),
         expanded_tags: &HashSet::new(),
         expanded_locations: &HashSet::new(),
@@ -65,6 +76,7 @@ fn test_start_grace_period_keeps_tasks_in_active_section() {
         tag_aliases: &HashMap::new(),
         search_collapsed_tasks: &HashSet::new(),
         focused_task_uid: None,
+        fuzzy_search: false,
     };
 
     let filtered = store.filter(options).items;
@@ -133,18 +145,29 @@ fn test_grace_period_zero_pushes_all_future_starts() {
         match_all_categories: false,
         search_term: "",
         hide_completed_global: false,
+        hide_deferred: false,
         hide_fully_completed_tags: false,
         hide_aliases_in_sidebar: false,
         cutoff_date: None,
         min_duration: None,
         max_duration: None,
         include_unset_duration: true,
+        exact_priority: None,
+        require_unset_duration: false,
+        excluded_categories: &HashSet::new(),
+        excluded_locations: &HashSet::new(),
+        excluded_priorities: &HashSet::new(),
+        excluded_statuses: &HashSet::new(),
         urgent_days: 1,
         urgent_prio: 1,
         default_priority: 5,
         start_grace_period_days: 0, // No grace period
         sort_standard_by_priority: false,
         sort_preset: SortPreset::default(),
+        sort_mode: SortMode::default(),
+        all_day_sort: AllDaySort::default(),
+        week_start: Weekday::Mon,
+        sort_urgency_boost: false,
         expanded_done_groups: &HashSet::new(),
         expanded_tags: &HashSet::new(),
         expanded_locations: &HashSet::new(),
@@ -154,6 +177,7 @@ fn test_grace_period_zero_pushes_all_future_starts() {
         tag_aliases: &HashMap::new(),
         search_collapsed_tasks: &HashSet::new(),
         focused_task_uid: None,
+        fuzzy_search: false,
     };
 
     let filtered = store.filter(options).items;
@@ -224,18 +248,29 @@ fn test_acknowledged_alarm_keeps_task_in_active_section() {
         match_all_categories: false,
         search_term: "",
         hide_completed_global: false,
+        hide_deferred: false,
         hide_fully_completed_tags: false,
         hide_aliases_in_sidebar: false,
         cutoff_date: None,
         min_duration: None,
         max_duration: None,
         include_unset_duration: true,
+        exact_priority: None,
+        require_unset_duration: false,
+        excluded_categories: &HashSet::new(),
+        excluded_locations: &HashSet::new(),
+        excluded_priorities: &HashSet::new(),
+        excluded_statuses: &HashSet::new(),
         urgent_days: 1,
         urgent_prio: 1,
         default_priority: 5,
         start_grace_period_days: 1, // 1-day grace period
         sort_standard_by_priority: false,
         sort_preset: SortPreset::default(),
+        sort_mode: SortMode::default(),
+        all_day_sort: AllDaySort::default(),
+        week_start: Weekday::Mon,
+        sort_urgency_boost: false,
         expanded_done_groups: &HashSet::new(),
         expanded_tags: &HashSet::new(),
         expanded_locations: &HashSet::new(),
@@ -245,6 +280,7 @@ fn test_acknowledged_alarm_keeps_task_in_active_section() {
         tag_aliases: &HashMap::new(),
         search_collapsed_tasks: &HashSet::new(),
         focused_task_uid: None,
+        fuzzy_search: false,
     };
 
     let filtered = store.filter(options).items;
@@ -324,18 +360,29 @@ fn test_any_acknowledged_alarm_keeps_task_active() {
         match_all_categories: false,
         search_term: "",
         hide_completed_global: false,
+        hide_deferred: false,
         hide_fully_completed_tags: false,
         hide_aliases_in_sidebar: false,
         cutoff_date: None,
         min_duration: None,
         max_duration: None,
         include_unset_duration: true,
+        exact_priority: None,
+        require_unset_duration: false,
+        excluded_categories: &HashSet::new(),
+        excluded_locations: &HashSet::new(),
+        excluded_priorities: &HashSet::new(),
+        excluded_statuses: &HashSet::new(),
         urgent_days: 1,
         urgent_prio: 1,
         default_priority: 5,
         start_grace_period_days: 1, // 1-day grace period
         sort_standard_by_priority: false,
         sort_preset: SortPreset::default(),
+        sort_mode: SortMode::default(),
+        all_day_sort: AllDaySort::default(),
+        week_start: Weekday::Mon,
+        sort_urgency_boost: false,
         expanded_done_groups: &HashSet::new(),
         expanded_tags: &HashSet::new(),
         expanded_locations: &HashSet::new(),
@@ -345,6 +392,7 @@ fn test_any_acknowledged_alarm_keeps_task_active() {
         tag_aliases: &HashMap::new(),
         search_collapsed_tasks: &HashSet::new(),
         focused_task_uid: None,
+        fuzzy_search: false,
     };
 
     let filtered = store.filter(options).items;
@@ -414,18 +462,29 @@ fn test_recurring_task_with_fresh_dates_goes_to_future() {
         match_all_categories: false,
         search_term: "",
         hide_completed_global: false,
+        hide_deferred: false,
         hide_fully_completed_tags: false,
         hide_aliases_in_sidebar: false,
         cutoff_date: None,
         min_duration: None,
         max_duration: None,
         include_unset_duration: true,
+        exact_priority: None,
+        require_unset_duration: false,
+        excluded_categories: &HashSet::new(),
+        excluded_locations: &HashSet::new(),
+        excluded_priorities: &HashSet::new(),
+        excluded_statuses: &HashSet::new(),
         urgent_days: 1,
         urgent_prio: 1,
         default_priority: 5,
         start_grace_period_days: 1,
         sort_standard_by_priority: false,
         sort_preset: SortPreset::default(),
+        sort_mode: SortMode::default(),
+        all_day_sort: AllDaySort::default(),
+        week_start: Weekday::Mon,
+        sort_urgency_boost: false,
         expanded_done_groups: &HashSet::new(),
         expanded_tags: &HashSet::new(),
         expanded_locations: &HashSet::new(),
@@ -435,6 +494,7 @@ fn test_recurring_task_with_fresh_dates_goes_to_future() {
         tag_aliases: &HashMap::new(),
         search_collapsed_tasks: &HashSet::new(),
         focused_task_uid: None,
+        fuzzy_search: false,
     };
 
     let filtered = store.filter(options).items;
@@ -463,3 +523,91 @@ fn test_recurring_task_with_fresh_dates_goes_to_future() {
         "Recurring task with future start date should go to future section"
     );
 }
+
+#[test]
+fn test_hide_deferred_hides_future_starts_until_searched() {
+    let ctx = Arc::new(TestContext::new());
+    let mut store = TaskStore::new(ctx);
+    let aliases = HashMap::new();
+    let now = Utc::now();
+
+    let mut deferred = Task::new("Deferred parent", &aliases, None);
+    deferred.dtstart = Some(DateType::Specific(now + Duration::days(3)));
+    deferred.calendar_href = "cal1".to_string();
+
+    // Children of a deferred task are deferred with it
+    let mut child = Task::new("Child of deferred", &aliases, None);
+    child.parent_uid = Some(deferred.uid.clone());
+    child.calendar_href = "cal1".to_string();
+
+    let mut started = Task::new("Already started", &aliases, None);
+    started.dtstart = Some(DateType::Specific(now - Duration::days(1)));
+    started.calendar_href = "cal1".to_string();
+
+    store.add_task(deferred);
+    store.add_task(child);
+    store.add_task(started);
+
+    let visible = |search_term: &str, hide_deferred: bool| -> Vec<String> {
+        let options = FilterOptions {
+            active_cal_href: None,
+            hidden_calendars: &HashSet::new(),
+            selected_categories: &HashSet::new(),
+            selected_locations: &HashSet::new(),
+            match_all_categories: false,
+            search_term,
+            hide_completed_global: false,
+            hide_deferred,
+            hide_fully_completed_tags: false,
+            hide_aliases_in_sidebar: false,
+            cutoff_date: None,
+            min_duration: None,
+            max_duration: None,
+            include_unset_duration: true,
+            exact_priority: None,
+            require_unset_duration: false,
+            excluded_categories: &HashSet::new(),
+            excluded_locations: &HashSet::new(),
+            excluded_priorities: &HashSet::new(),
+            excluded_statuses: &HashSet::new(),
+            urgent_days: 1,
+            urgent_prio: 1,
+            default_priority: 5,
+            start_grace_period_days: 0,
+            sort_standard_by_priority: false,
+            sort_preset: SortPreset::default(),
+            sort_mode: SortMode::default(),
+            all_day_sort: AllDaySort::default(),
+            week_start: Weekday::Mon,
+            sort_urgency_boost: false,
+            expanded_done_groups: &HashSet::new(),
+            expanded_tags: &HashSet::new(),
+            expanded_locations: &HashSet::new(),
+            max_done_roots: usize::MAX,
+            max_done_subtasks: usize::MAX,
+            tag_aliases: &HashMap::new(),
+            search_collapsed_tasks: &HashSet::new(),
+            focused_task_uid: None,
+            fuzzy_search: false,
+        };
+        store
+            .filter(options)
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                cfait::store::TaskListItem::Task(t) => Some(t.summary.clone()),
+                _ => None,
+            })
+            .collect()
+    };
+
+    // Without the toggle, deferred tasks are only sorted lower
+    assert_eq!(visible("", false).len(), 3);
+
+    let shown = visible("", true);
+    assert_eq!(shown, vec!["Already started".to_string()]);
+
+    let revealed = visible("is:deferred", true);
+    assert!(revealed.contains(&"Deferred parent".to_string()));
+    assert!(!revealed.contains(&"Already started".to_string()));
+}
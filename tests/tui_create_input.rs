@@ -108,3 +108,64 @@ async fn test_tui_creating_task_and_alias_simultaneously() {
     // 3. Verify UI returned to Normal mode
     assert!(matches!(state.mode, InputMode::Normal));
 }
+
+#[tokio::test]
+async fn test_tui_inline_alias_definition_expands_new_and_existing_tasks() {
+    let ctx = Arc::new(TestContext::new());
+    let mut state = AppState::new_with_ctx(ctx);
+    let cal_href = "https://example.test/cal/".to_string();
+
+    state.calendars.push(CalendarListEntry {
+        name: "Remote".to_string(),
+        href: cal_href.clone(),
+        color: None,
+    });
+    state.active_cal_href = Some(cal_href.clone());
+
+    let mut existing =
+        cfait::model::Task::new("Older #foo", &std::collections::HashMap::new(), None);
+    existing.calendar_href = cal_href;
+    let existing_uid = existing.uid.clone();
+    state.store.add_task(existing);
+
+    // Defining the alias inline stores it, retags the existing task and tags the new one
+    state.mode = InputMode::Creating;
+    state.input_buffer = "Newer #foo:=#bar".to_string();
+    state.cursor_position = state.input_buffer.chars().count();
+
+    let (action_tx, mut action_rx) = mpsc::channel(4);
+    let action = handle_key_event(
+        KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE),
+        &mut state,
+        &action_tx,
+    )
+    .await;
+
+    assert_eq!(
+        state.tag_aliases.get("foo"),
+        Some(&vec!["#bar".to_string()])
+    );
+
+    match action {
+        Some(Action::PersistBatch(actions)) => match &actions[0] {
+            cfait::journal::Action::Create(task) => {
+                assert_eq!(task.summary, "Newer");
+                assert!(task.categories.contains(&"foo".to_string()));
+                assert!(task.categories.contains(&"bar".to_string()));
+            }
+            _ => panic!("Expected Create action in PersistBatch"),
+        },
+        other => panic!("Expected PersistBatch, got {:?}", other),
+    }
+
+    match action_rx.try_recv() {
+        Ok(Action::PersistBatch(actions)) => {
+            assert!(actions.iter().any(|a| matches!(
+                a,
+                cfait::journal::Action::Update(t)
+                    if t.uid == existing_uid && t.categories.contains(&"bar".to_string())
+            )));
+        }
+        other => panic!("Expected retroactive PersistBatch, got {:?}", other),
+    }
+}
@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+//! Tests for TUI keybinding overrides.
+use cfait::context::TestContext;
+use cfait::tui::action::Action;
+use cfait::tui::handlers::handle_key_event;
+use cfait::tui::keymap::{KeyAction, Keymap, parse_key};
+use cfait::tui::state::AppState;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+fn bindings(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_keymap_translates_remapped_keys_and_reports_problems() {
+    let (keymap, warnings) = Keymap::from_config(&bindings(&[
+        ("quit", "ctrl+q"),
+        ("refresh", "ctrl+q F5"),
+        ("delete", "hyper+x"),
+        ("jump", "g"),
+    ]));
+
+    // Remapped quit dispatches as the default `q`, and plain `q` no longer quits
+    let ctrl_q = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL);
+    assert_eq!(
+        keymap.translate(ctrl_q),
+        Some(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE))
+    );
+    assert_eq!(
+        keymap.translate(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE)),
+        None
+    );
+    // The conflicting ctrl+q stays with quit; refresh still gets F5
+    assert_eq!(
+        keymap.translate(KeyEvent::new(KeyCode::F(5), KeyModifiers::NONE)),
+        Some(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::NONE))
+    );
+    // Unmapped actions keep their defaults
+    let j = KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE);
+    assert_eq!(keymap.translate(j), Some(j));
+
+    assert!(warnings.iter().any(|w| w.contains("bound to both")));
+    assert!(warnings.iter().any(|w| w.contains("hyper")));
+    assert!(warnings.iter().any(|w| w.contains("unknown action 'jump'")));
+
+    assert_eq!(parse_key("shift+d"), parse_key("D"));
+    assert_eq!(KeyAction::from_name("Next"), Some(KeyAction::Next));
+}
+
+#[tokio::test]
+async fn test_tui_remapped_quit_key() {
+    let mut state = AppState::new_with_ctx(Arc::new(TestContext::new()));
+    state.keymap = Keymap::from_config(&bindings(&[("quit", "x")])).0;
+    let (action_tx, _action_rx) = mpsc::channel(1);
+
+    let action = handle_key_event(
+        KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE),
+        &mut state,
+        &action_tx,
+    )
+    .await;
+    assert!(matches!(action, Some(Action::Quit)));
+}
@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+//! Tests for TUI mouse selection.
+use cfait::context::TestContext;
+use cfait::model::Task;
+use cfait::store::TaskListItem;
+use cfait::tui::handlers::handle_mouse_event;
+use cfait::tui::state::{AppState, InputMode};
+use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::Rect;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+fn click(kind: MouseEventKind, column: u16, row: u16) -> MouseEvent {
+    MouseEvent {
+        kind,
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    }
+}
+
+#[test]
+fn test_tui_click_selects_row_and_right_click_opens_actions() {
+    let mut state = AppState::new_with_ctx(Arc::new(TestContext::new()));
+    state.tasks = ["First", "Second", "Third"]
+        .iter()
+        .map(|s| TaskListItem::Task(Box::new(Task::new(s, &HashMap::new(), None))))
+        .collect();
+    state.list_state.select(Some(0));
+    // As laid out by `draw`: one line per row, the second row spanning two lines.
+    state.task_row_rects = vec![
+        (0, Rect::new(21, 1, 50, 1)),
+        (1, Rect::new(21, 2, 50, 2)),
+        (2, Rect::new(21, 4, 50, 1)),
+    ];
+
+    handle_mouse_event(
+        click(MouseEventKind::Down(MouseButton::Left), 30, 3),
+        &mut state,
+    );
+    assert_eq!(state.list_state.selected(), Some(1));
+    assert!(matches!(state.mode, InputMode::Normal));
+
+    // Clicks outside the list leave the selection alone
+    handle_mouse_event(
+        click(MouseEventKind::Down(MouseButton::Left), 5, 4),
+        &mut state,
+    );
+    assert_eq!(state.list_state.selected(), Some(1));
+
+    handle_mouse_event(
+        click(MouseEventKind::Down(MouseButton::Right), 30, 4),
+        &mut state,
+    );
+    assert_eq!(state.list_state.selected(), Some(2));
+    assert!(matches!(state.mode, InputMode::ActionMenu));
+}